@@ -0,0 +1,176 @@
+//! Parse and emit throughput of the frame representations and of the `Message` dispatch, with
+//! the `std` feature: `cargo bench --features std`.
+//!
+//! The single frame benchmarks cover a small frame (x0b6) and one of the largest frames (the
+//! AEE2004 x260), whose code is generated by the frame macros. The soak benchmarks replay the
+//! captures of `tests/fixtures/corpus` many times through the bus dispatchers, parsing and
+//! emitting back every frame, to catch regressions spread over the whole frame list.
+//!
+//! The enum benchmarks compare the `match` based `From<u8>` conversion generated by
+//! `enum_with_unknown!` with a 256-entry lookup table, over every raw value of the x0b6 speed
+//! validity (a single variant) and of the x036 network state (five contiguous variants).
+
+use std::hint::black_box;
+
+use canpsa::{
+    aee2004::conf::{self, x0b6, x260},
+    aee2010::infodiv,
+    replay,
+    transport::slcan::Id,
+    vehicle::{NetworkState, SpeedValidity},
+    Result,
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+/// Number of passes over a capture in a soak iteration.
+const SOAK_PASSES: usize = 100;
+
+static AEE2004_LOG: &str = include_str!("../tests/fixtures/corpus/aee2004.log");
+static AEE2010_LOG: &str = include_str!("../tests/fixtures/corpus/aee2010.log");
+
+/// Return the standard identifier data frames of a `candump -L` capture.
+fn capture(log: &str) -> Vec<(u16, Vec<u8>)> {
+    log.lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| replay::parse(line).ok())
+        .filter_map(|record| match record.frame.id {
+            Id::Standard(id) if !record.frame.remote => Some((id, record.frame.payload().to_vec())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn bench_frames(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame");
+    group.throughput(Throughput::Elements(1));
+
+    let frame = x0b6::Frame::new_unchecked(&x0b6::IDLE_FRAME_BYTES);
+    group.bench_function("x0b6/parse", |b| {
+        b.iter(|| x0b6::Repr::parse(black_box(&frame)))
+    });
+    let repr = x0b6::Repr::parse(&frame).unwrap();
+    group.bench_function("x0b6/emit", |b| {
+        let mut buffer = [0u8; x0b6::FRAME_LEN];
+        b.iter(|| black_box(&repr).emit(&mut x0b6::Frame::new_unchecked(&mut buffer[..])))
+    });
+
+    let frame = x260::Frame::new_unchecked(&x260::IDLE_FRAME_BYTES);
+    group.bench_function("x260/parse", |b| {
+        b.iter(|| x260::Repr::parse(black_box(&frame)))
+    });
+    let repr = x260::Repr::parse(&frame).unwrap();
+    group.bench_function("x260/emit", |b| {
+        let mut buffer = [0u8; x260::FRAME_LEN];
+        b.iter(|| black_box(&repr).emit(&mut x260::Frame::new_unchecked(&mut buffer[..])))
+    });
+
+    group.finish();
+}
+
+/// Convert every raw value of `raws` with `convert`.
+fn convert_all<E: Copy>(raws: &[u8], convert: impl Fn(u8) -> E) -> Option<E> {
+    raws.iter().map(|&raw| black_box(convert(raw))).last()
+}
+
+fn bench_enum_group<E: Copy>(c: &mut Criterion, name: &str, from_raw: fn(u8) -> E) {
+    let raws: Vec<u8> = (0..=u8::MAX).collect();
+    let lut: [E; 256] = core::array::from_fn(|raw| from_raw(raw as u8));
+
+    let mut group = c.benchmark_group(format!("enum/{name}"));
+    group.throughput(Throughput::Elements(raws.len() as u64));
+    group.bench_function("match", |b| {
+        b.iter(|| convert_all(black_box(&raws), from_raw))
+    });
+    group.bench_function("lut", |b| {
+        b.iter(|| convert_all(black_box(&raws), |raw| lut[usize::from(raw)]))
+    });
+    group.finish();
+}
+
+fn bench_enums(c: &mut Criterion) {
+    bench_enum_group(c, "speed_validity", SpeedValidity::from);
+    bench_enum_group(c, "network_state", NetworkState::from);
+}
+
+/// Parse every frame of `frames` with `parse`, and emit the recognized ones back with `emit`.
+fn dispatch<M>(
+    frames: &[(u16, Vec<u8>)],
+    parse: fn(u16, &[u8]) -> Result<M>,
+    emit: fn(&M, &mut [u8]) -> Result<usize>,
+) -> usize {
+    let mut buffer = [0u8; 8];
+    let mut emitted = 0;
+    for (id, payload) in frames {
+        if let Ok(message) = parse(*id, payload) {
+            emitted += emit(&message, &mut buffer).unwrap_or(0);
+        }
+    }
+    emitted
+}
+
+fn bench_dispatch(c: &mut Criterion) {
+    let aee2004 = capture(AEE2004_LOG);
+    let aee2010 = capture(AEE2010_LOG);
+
+    let mut group = c.benchmark_group("dispatch");
+    group.throughput(Throughput::Elements(aee2004.len() as u64));
+    group.bench_function("aee2004", |b| {
+        b.iter(|| {
+            dispatch(
+                black_box(&aee2004),
+                conf::Message::parse,
+                conf::Message::emit,
+            )
+        })
+    });
+    group.throughput(Throughput::Elements(aee2010.len() as u64));
+    group.bench_function("aee2010", |b| {
+        b.iter(|| {
+            dispatch(
+                black_box(&aee2010),
+                infodiv::Message::parse,
+                infodiv::Message::emit,
+            )
+        })
+    });
+    group.finish();
+}
+
+fn bench_soak(c: &mut Criterion) {
+    let aee2004 = capture(AEE2004_LOG);
+    let aee2010 = capture(AEE2010_LOG);
+
+    let mut group = c.benchmark_group("soak");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(
+        ((aee2004.len() + aee2010.len()) * SOAK_PASSES) as u64,
+    ));
+    group.bench_function("replay", |b| {
+        b.iter(|| {
+            let mut emitted = 0;
+            for _ in 0..SOAK_PASSES {
+                emitted += dispatch(
+                    black_box(&aee2004),
+                    conf::Message::parse,
+                    conf::Message::emit,
+                );
+                emitted += dispatch(
+                    black_box(&aee2010),
+                    infodiv::Message::parse,
+                    infodiv::Message::emit,
+                );
+            }
+            emitted
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_frames,
+    bench_enums,
+    bench_dispatch,
+    bench_soak
+);
+criterion_main!(benches);
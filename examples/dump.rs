@@ -0,0 +1,79 @@
+//! Decode live CAN traffic to text.
+//!
+//! Reads `candump -L` lines from the standard input and prints every frame known to the
+//! dispatcher of the bus given as argument, `aee2004` (the default) or `aee2010`:
+//!
+//! ```text
+//! candump -L can0 | cargo run --features std --example dump -- aee2010
+//! ```
+//!
+//! Captures recorded with `candump -L` can be decoded the same way.
+
+use std::{
+    env,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+};
+
+use canpsa::{aee2004::conf, aee2010::infodiv, replay, transport::slcan::CanFrame, Error, Result};
+
+/// Decode the frame of `record` with `decode` and print it after its capture timestamp,
+/// interface and identifier.
+fn dump<M: std::fmt::Display>(
+    out: &mut impl Write,
+    record: &replay::Record,
+    decode: fn(&CanFrame) -> Result<M>,
+) -> io::Result<()> {
+    let timestamp = record.timestamp;
+    let prefix = format!(
+        "({}.{:06}) {} {}",
+        timestamp.as_secs(),
+        timestamp.subsec_micros(),
+        record.interface,
+        record.frame.id,
+    );
+    match decode(&record.frame) {
+        Ok(message) => writeln!(out, "{prefix} {message}"),
+        Err(Error::Unrecognized) => Ok(()),
+        Err(err) => writeln!(out, "{prefix} error: {err} ({})", record.frame),
+    }
+}
+
+fn main() -> ExitCode {
+    let bus = env::args().nth(1);
+    let decode_2004 = match bus.as_deref() {
+        None | Some("aee2004") => true,
+        Some("aee2010") => false,
+        Some(bus) => {
+            eprintln!("unknown bus {bus}, expected aee2004 or aee2010");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let Ok(record) = replay::parse(line.trim()) else {
+            continue;
+        };
+
+        let written = if decode_2004 {
+            dump(&mut out, &record, conf::Message::from_can_frame)
+        } else {
+            dump(&mut out, &record, infodiv::Message::from_can_frame)
+        };
+        // Stop quietly when the output is closed, e.g. piped into `head`.
+        if written.is_err() {
+            break;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
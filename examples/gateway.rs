@@ -0,0 +1,182 @@
+//! Bridge an AEE2004 comfort bus and an AEE2010 infotainment bus.
+//!
+//! Reads `candump -L` lines of both buses from the standard input and prints the translated
+//! frames as `candump -L` lines for the other bus, which `canplayer` sends to the adapters.
+//! The arguments name the AEE2004 and AEE2010 interfaces, `can0` and `can1` by default:
+//!
+//! ```text
+//! candump -L can0 can1 | cargo run --features std --example gateway -- can0 can1 | canplayer -t
+//! ```
+//!
+//! `candump` also receives the frames `canplayer` sends, these echoes are recognized and
+//! not translated back. The periodic AEE2010 frames translated from non-periodic AEE2004
+//! frames are repeated at the pace of the received traffic timestamps.
+
+use std::{
+    collections::VecDeque,
+    env,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+    time::Duration,
+};
+
+use canpsa::{
+    aee2004::conf,
+    aee2010::infodiv,
+    gateway::{Gateway, Translation, UnmappedPolicy},
+    replay::{self, Record},
+    transport::slcan::CanFrame,
+    Result,
+};
+
+/// Number of sent frames remembered per interface to recognize their echoes.
+const MAX_ECHOES: usize = 64;
+
+/// An interface the gateway sends frames to.
+struct Output {
+    interface: String,
+    echoes: VecDeque<CanFrame>,
+}
+
+impl Output {
+    fn new(interface: String) -> Output {
+        Output {
+            interface,
+            echoes: VecDeque::with_capacity(MAX_ECHOES),
+        }
+    }
+
+    /// Return whether `frame` is the echo of a frame sent to this interface, forgetting it.
+    fn is_echo(&mut self, frame: &CanFrame) -> bool {
+        match self.echoes.iter().position(|echo| echo == frame) {
+            Some(index) => {
+                self.echoes.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Print `frame` as a `candump -L` line for this interface.
+    fn send(
+        &mut self,
+        out: &mut impl Write,
+        timestamp: Duration,
+        frame: CanFrame,
+    ) -> io::Result<()> {
+        if self.echoes.len() == MAX_ECHOES {
+            self.echoes.pop_front();
+        }
+        self.echoes.push_back(frame);
+        let record = Record {
+            timestamp,
+            interface: &self.interface,
+            frame,
+        };
+        writeln!(out, "{record}")
+    }
+
+    /// Send the message translated from `frame`, and `frame` itself if `translation`
+    /// forwards it.
+    fn send_translation<M>(
+        &mut self,
+        out: &mut impl Write,
+        timestamp: Duration,
+        frame: &CanFrame,
+        translation: Translation<M>,
+        emit: fn(&M) -> Result<CanFrame>,
+    ) -> io::Result<()> {
+        let message = match translation {
+            Translation::Converted(message) => message,
+            Translation::ConvertedAndForward(message) => {
+                self.send(out, timestamp, *frame)?;
+                message
+            }
+            Translation::Forward => return self.send(out, timestamp, *frame),
+            Translation::Drop => return Ok(()),
+        };
+        match emit(&message) {
+            Ok(converted) => self.send(out, timestamp, converted),
+            Err(err) => {
+                eprintln!("cannot emit translation of {frame}: {err}");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Send the repeated AEE2010 frames due at `now`.
+fn repeat(
+    gateway: &mut Gateway,
+    aee2010: &mut Output,
+    out: &mut impl Write,
+    now: Duration,
+) -> io::Result<()> {
+    while let Some(message) = gateway.poll_aee2010(now) {
+        if let Ok(frame) = message.to_can_frame() {
+            aee2010.send(out, now, frame)?;
+        }
+    }
+    out.flush()
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let mut aee2004 = Output::new(args.next().unwrap_or_else(|| "can0".into()));
+    let mut aee2010 = Output::new(args.next().unwrap_or_else(|| "can1".into()));
+    let mut gateway = Gateway::new(UnmappedPolicy::Drop);
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let Ok(record) = replay::parse(line.trim()) else {
+            continue;
+        };
+        let (now, frame) = (record.timestamp, &record.frame);
+
+        let sent = if record.interface == aee2004.interface {
+            if aee2004.is_echo(frame) {
+                continue;
+            }
+            match conf::Message::from_can_frame(frame) {
+                Ok(message) => {
+                    let translation = gateway.to_aee2010(&message, now);
+                    let emit = infodiv::Message::to_can_frame;
+                    aee2010.send_translation(&mut out, now, frame, translation, emit)
+                }
+                Err(_) => Ok(()),
+            }
+        } else if record.interface == aee2010.interface {
+            if aee2010.is_echo(frame) {
+                continue;
+            }
+            match infodiv::Message::from_can_frame(frame) {
+                Ok(message) => {
+                    let translation = gateway.to_aee2004(&message);
+                    let emit = conf::Message::to_can_frame;
+                    aee2004.send_translation(&mut out, now, frame, translation, emit)
+                }
+                Err(_) => Ok(()),
+            }
+        } else {
+            Ok(())
+        };
+
+        // Stop quietly when the output is closed.
+        if sent
+            .and_then(|_| repeat(&mut gateway, &mut aee2010, &mut out, now))
+            .is_err()
+        {
+            break;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
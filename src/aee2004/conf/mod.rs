@@ -43,6 +43,9 @@ pub use x1e1 as DONNEES_ETAT_ROUES;
 pub mod x1e5;
 pub use x1e5 as ETAT_RADIO_GEN_AUD;
 
+pub mod x21f;
+pub use x21f as COMMANDES_VOLANT;
+
 pub mod x220;
 pub use x220 as DONNEES_ETATS_OUVRANTS;
 
@@ -84,9 +87,84 @@ pub use x376 as DATE_CONFIG_2;
 
 pub mod x3a7;
 pub use x3a7 as INFOS_MAINTENANCE;
-
 pub mod x3e1;
 pub use x3e1 as INFOS_STT_ET_HY;
 
 pub mod x3f6;
 pub use x3f6 as DATE_CONFIG;
+
+frame_message! {
+    /// A high-level representation of any supported AEE2004 conf CAN frame.
+    pub enum Message {
+        X036(x036),
+        X0b6(x0b6),
+        X0e6(x0e6),
+        X0f6(x0f6),
+        X128(x128),
+        X136(x136),
+        X15b(x15b),
+        X167(x167),
+        X168(x168),
+        X1a5(x1a5),
+        X1a8(x1a8),
+        X1d0(x1d0),
+        X1db(x1db),
+        X1e1(x1e1),
+        X1e5(x1e5),
+        X21f(x21f),
+        X220(x220),
+        X221(x221),
+        X227(x227),
+        X228(x228),
+        X260(x260),
+        X261(x261),
+        X2a1(x2a1),
+        X2b6(x2b6),
+        X2e1(x2e1),
+        X3b6(x3b6),
+        X336(x336),
+        X361(x361),
+        X376(x376),
+        X3a7(x3a7),
+        X3e1(x3e1),
+        X3f6(x3f6),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{x0e6, Message};
+
+    use crate::Error;
+
+    static X0E6_FRAME_BYTES: [u8; 7] = [0x55, 0x2c, 0x15, 0x82, 0x26, 0x7c, 0x80];
+
+    #[test]
+    fn test_parse_valid() {
+        let message = Message::parse(x0e6::FRAME_ID, &X0E6_FRAME_BYTES).unwrap();
+        let repr = x0e6::Repr::parse(&x0e6::Frame::new_unchecked(&X0E6_FRAME_BYTES)).unwrap();
+        assert_eq!(message, Message::X0e6(repr));
+        assert_eq!(message.frame_id(), x0e6::FRAME_ID);
+        assert_eq!(message.buffer_len(), x0e6::FRAME_LEN);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Message::parse(0x7ff, &X0E6_FRAME_BYTES),
+            Err(Error::Unrecognized)
+        );
+        assert_eq!(
+            Message::parse(x0e6::FRAME_ID, &X0E6_FRAME_BYTES[..6]),
+            Err(Error::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_emit() {
+        let message = Message::parse(x0e6::FRAME_ID, &X0E6_FRAME_BYTES).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(message.emit(&mut buf), Ok(7));
+        assert_eq!(&buf[..7], &X0E6_FRAME_BYTES);
+    }
+}
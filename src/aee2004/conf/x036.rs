@@ -2,6 +2,8 @@ use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
     config::UserProfile,
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
         ConvertibleRoofPosition, DayNightStatus, HybridPowertrainMode, HybridPowertrainState,
         MainStatusValidity, NetworkState, RheostatMode,
@@ -70,6 +72,8 @@ pub const FRAME_LEN: usize = field::FLAGS_MAIN_STATE + 1;
 
 /// Periodicity of a x036 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(100);
+/// Transmission of a x036 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -231,7 +235,7 @@ impl<T: AsRef<[u8]>> Frame<T> {
     pub fn network_state(&self) -> NetworkState {
         let data = self.buffer.as_ref();
         let raw = data[field::NET_FLAGS] & 0x07;
-        NetworkState::from(raw)
+        NetworkState::lookup(raw)
     }
 
     /// Return the fault logging forbidden flag.
@@ -641,10 +645,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x036 ({})", err)?;
                 Ok(())
@@ -659,9 +663,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x036 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub driver_memory_setting: u8,
     pub driver_memory_setting_write: bool,
@@ -698,10 +708,16 @@ pub struct Repr {
 }
 
 impl Repr {
+    #[inline]
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             driver_memory_setting: frame.driver_memory_setting(),
             driver_memory_setting_write: frame.driver_memory_setting_write(),
             driver_memory_setting_recall: frame.driver_memory_setting_recall(),
@@ -735,15 +751,17 @@ impl Repr {
             convertible_roof_position: frame.convertible_roof_position(),
             audio_inviolability_request: frame.audio_inviolability_request(),
             vehicle_main_status_validity: frame.vehicle_main_status_validity(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
+    #[inline]
     pub fn buffer_len(&self) -> usize {
         FRAME_LEN
     }
 
     /// Emit a high-level representation into a x036 CAN frame.
+    #[inline]
     pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
         frame.set_driver_memory_setting(self.driver_memory_setting);
         frame.set_driver_memory_setting_write(self.driver_memory_setting_write);
@@ -782,116 +800,277 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x51, 0x51, 0x88, 0xc8, 0xa1, 0xb0, 0x0a, 0xa2],
+    parked: [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa0],
+    with_driver_memory_setting => driver_memory_setting: u8,
+    with_driver_memory_setting_write => driver_memory_setting_write: bool,
+    with_driver_memory_setting_recall => driver_memory_setting_recall: bool,
+    with_driver_profile_number => driver_profile_number: UserProfile,
+    with_passenger_memory_setting => passenger_memory_setting: u8,
+    with_passenger_memory_setting_write => passenger_memory_setting_write: bool,
+    with_passenger_memory_setting_recall => passenger_memory_setting_recall: bool,
+    with_passenger_profile_number => passenger_profile_number: UserProfile,
+    with_delestage_level => delestage_level: u8,
+    with_economy_mode_enabled => economy_mode_enabled: bool,
+    with_lighting_level => lighting_level: u8,
+    with_black_panel_enabled => black_panel_enabled: bool,
+    with_day_night => day_night: DayNightStatus,
+    with_rheostat_mode => rheostat_mode: RheostatMode,
+    with_lighting_reset_to_reference_level_request => lighting_reset_to_reference_level_request: bool,
+    with_network_state => network_state: NetworkState,
+    with_fault_logging_forbidden => fault_logging_forbidden: bool,
+    with_network_supervision_authorization => network_supervision_authorization: bool,
+    with_fault_erase_request => fault_erase_request: bool,
+    with_sport_mode_enable => sport_mode_enable: bool,
+    with_hybrid_powertrain_mode_updated_data => hybrid_powertrain_mode_updated_data: bool,
+    with_hybrid_powertrain_mode => hybrid_powertrain_mode: HybridPowertrainMode,
+    with_hybrid_powertrain_state_updated_data => hybrid_powertrain_state_updated_data: bool,
+    with_hybrid_powertrain_state => hybrid_powertrain_state: HybridPowertrainState,
+    with_radio_on_off_synchronization => radio_on_off_synchronization: bool,
+    with_radio_on_off_toggle => radio_on_off_toggle: bool,
+    with_preconditioning_menu_presence => preconditioning_menu_presence: bool,
+    with_visual_parking_assistance_enable => visual_parking_assistance_enable: bool,
+    with_media_shutdown_request => media_shutdown_request: bool,
+    with_convertible_roof_position => convertible_roof_position: ConvertibleRoofPosition,
+    with_audio_inviolability_request => audio_inviolability_request: bool,
+    with_vehicle_main_status_validity => vehicle_main_status_validity: MainStatusValidity,
+}
+
+/// Signals of a x036 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("driver_memory_setting", 0, 3, 4),
+    Signal::new("driver_memory_setting_write", 0, 4, 1),
+    Signal::new("driver_memory_setting_recall", 0, 5, 1),
+    Signal::new("driver_profile_number", 0, 7, 2).values(&[
+        (0, "None"),
+        (1, "Profile1"),
+        (2, "Profile2"),
+    ]),
+    Signal::new("passenger_memory_setting", 1, 3, 4),
+    Signal::new("passenger_memory_setting_write", 1, 4, 1),
+    Signal::new("passenger_memory_setting_recall", 1, 5, 1),
+    Signal::new("passenger_profile_number", 1, 7, 2).values(&[
+        (0, "None"),
+        (1, "Profile1"),
+        (2, "Profile2"),
+    ]),
+    Signal::new("delestage_level", 2, 4, 5),
+    Signal::new("economy_mode_enabled", 2, 7, 1),
+    Signal::new("lighting_level", 3, 3, 4),
+    Signal::new("black_panel_enabled", 3, 4, 1),
+    Signal::new("day_night", 3, 5, 1).values(&[(0, "Day"), (1, "Night")]),
+    Signal::new("rheostat_mode", 3, 6, 1).values(&[(0, "Manual"), (1, "Automatic")]),
+    Signal::new("lighting_reset_to_reference_level_request", 3, 7, 1),
+    Signal::new("network_state", 4, 2, 3).values(&[
+        (0, "Sleep"),
+        (1, "Normal"),
+        (2, "GoingToSleep"),
+        (3, "WakeUp"),
+        (4, "Off"),
+    ]),
+    Signal::new("fault_logging_forbidden", 4, 3, 1),
+    Signal::new("network_supervision_authorization", 4, 5, 1),
+    Signal::new("fault_erase_request", 4, 6, 1),
+    Signal::new("sport_mode_enable", 4, 7, 1),
+    Signal::new("hybrid_powertrain_mode_updated_data", 5, 0, 1),
+    Signal::new("hybrid_powertrain_mode", 5, 3, 3).values(&[
+        (0, "FourWheelDrive"),
+        (1, "Sport"),
+        (2, "Hybrid"),
+        (3, "ZeroEmission"),
+        (7, "Invalid"),
+    ]),
+    Signal::new("hybrid_powertrain_state_updated_data", 5, 4, 1),
+    Signal::new("hybrid_powertrain_state", 5, 7, 3).values(&[
+        (0, "Indefinite"),
+        (1, "Inactive"),
+        (2, "ActivatedAtStop"),
+        (3, "FrontWheelDrive"),
+        (4, "RearWheelDrive"),
+        (5, "Hybrid"),
+    ]),
+    Signal::new("radio_on_off_synchronization", 6, 0, 1),
+    Signal::new("radio_on_off_toggle", 6, 1, 1),
+    Signal::new("preconditioning_menu_presence", 6, 2, 1),
+    Signal::new("visual_parking_assistance_enable", 6, 3, 1),
+    Signal::new("media_shutdown_request", 6, 7, 1),
+    Signal::new("convertible_roof_position", 7, 0, 1).values(&[(0, "Coupe"), (1, "Convertible")]),
+    Signal::new("audio_inviolability_request", 7, 1, 1),
+    Signal::new("vehicle_main_status_validity", 7, 7, 4).values(&[(10, "Valid")]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x036")?;
-        writeln!(f, " driver_memory_setting={}", self.driver_memory_setting)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x036")?;
+        write!(
+            f,
+            "{}driver_memory_setting={}",
+            sep, self.driver_memory_setting
+        )?;
+        write!(
             f,
-            " driver_memory_setting_write={}",
-            self.driver_memory_setting_write
+            "{}driver_memory_setting_write={}",
+            sep, self.driver_memory_setting_write
         )?;
-        writeln!(
+        write!(
             f,
-            " driver_memory_setting_recall={}",
-            self.driver_memory_setting_recall
+            "{}driver_memory_setting_recall={}",
+            sep, self.driver_memory_setting_recall
         )?;
-        writeln!(f, " driver_profile_number={}", self.driver_profile_number)?;
-        writeln!(
+        write!(
             f,
-            " passenger_memory_setting={}",
-            self.passenger_memory_setting
+            "{}driver_profile_number={}",
+            sep, self.driver_profile_number
         )?;
-        writeln!(
+        write!(
             f,
-            " passenger_memory_setting_write={}",
-            self.driver_memory_setting_write
+            "{}passenger_memory_setting={}",
+            sep, self.passenger_memory_setting
         )?;
-        writeln!(
+        write!(
             f,
-            " passenger_memory_setting_recall={}",
-            self.passenger_memory_setting_recall
+            "{}passenger_memory_setting_write={}",
+            sep, self.driver_memory_setting_write
         )?;
-        writeln!(
+        write!(
             f,
-            " passenger_profile_number={}",
-            self.passenger_profile_number
+            "{}passenger_memory_setting_recall={}",
+            sep, self.passenger_memory_setting_recall
         )?;
-        writeln!(f, " delestage_level={}", self.delestage_level)?;
-        writeln!(f, " economy_mode_enabled={}", self.economy_mode_enabled)?;
-        writeln!(f, " lighting_level={}", self.lighting_level)?;
-        writeln!(f, " black_panel_enabled={}", self.black_panel_enabled)?;
-        writeln!(f, " day_night={}", self.day_night)?;
-        writeln!(f, " rheostat_mode={}", self.rheostat_mode)?;
-        writeln!(
+        write!(
             f,
-            " lighting_reset_to_reference_level_request={}",
-            self.lighting_reset_to_reference_level_request
+            "{}passenger_profile_number={}",
+            sep, self.passenger_profile_number
         )?;
-        writeln!(f, " network_state={}", self.network_state)?;
-        writeln!(
+        write!(f, "{}delestage_level={}", sep, self.delestage_level)?;
+        write!(
             f,
-            " fault_logging_forbidden={}",
-            self.fault_logging_forbidden
+            "{}economy_mode_enabled={}",
+            sep, self.economy_mode_enabled
         )?;
-        writeln!(
+        write!(f, "{}lighting_level={}", sep, self.lighting_level)?;
+        write!(f, "{}black_panel_enabled={}", sep, self.black_panel_enabled)?;
+        write!(f, "{}day_night={}", sep, self.day_night)?;
+        write!(f, "{}rheostat_mode={}", sep, self.rheostat_mode)?;
+        write!(
             f,
-            " network_supervision_authorization={}",
-            self.network_supervision_authorization
+            "{}lighting_reset_to_reference_level_request={}",
+            sep, self.lighting_reset_to_reference_level_request
         )?;
-        writeln!(f, " fault_erase_request={}", self.fault_erase_request)?;
-        writeln!(f, " sport_mode_enable={}", self.sport_mode_enable)?;
-        writeln!(
+        write!(f, "{}network_state={}", sep, self.network_state)?;
+        write!(
             f,
-            " hybrid_powertrain_mode_updated_data={}",
-            self.hybrid_powertrain_mode_updated_data
+            "{}fault_logging_forbidden={}",
+            sep, self.fault_logging_forbidden
         )?;
-        writeln!(f, " hybrid_powertrain_mode={}", self.hybrid_powertrain_mode)?;
-        writeln!(
+        write!(
             f,
-            " hybrid_powertrain_state_updated_data={}",
-            self.hybrid_powertrain_state_updated_data
+            "{}network_supervision_authorization={}",
+            sep, self.network_supervision_authorization
         )?;
-        writeln!(
+        write!(f, "{}fault_erase_request={}", sep, self.fault_erase_request)?;
+        write!(f, "{}sport_mode_enable={}", sep, self.sport_mode_enable)?;
+        write!(
             f,
-            " hybrid_powertrain_state={}",
-            self.hybrid_powertrain_state
+            "{}hybrid_powertrain_mode_updated_data={}",
+            sep, self.hybrid_powertrain_mode_updated_data
         )?;
-        writeln!(
+        write!(
             f,
-            " radio_on_off_synchronization={}",
-            self.radio_on_off_synchronization
+            "{}hybrid_powertrain_mode={}",
+            sep, self.hybrid_powertrain_mode
         )?;
-        writeln!(f, " radio_on_off_toggle={}", self.radio_on_off_toggle)?;
-        writeln!(
+        write!(
             f,
-            " preconditioning_menu_presence={}",
-            self.preconditioning_menu_presence
+            "{}hybrid_powertrain_state_updated_data={}",
+            sep, self.hybrid_powertrain_state_updated_data
         )?;
-        writeln!(
+        write!(
             f,
-            " visual_parking_assistance_enable={}",
-            self.visual_parking_assistance_enable
+            "{}hybrid_powertrain_state={}",
+            sep, self.hybrid_powertrain_state
         )?;
-        writeln!(f, " media_shutdown_request={}", self.media_shutdown_request)?;
-        writeln!(
+        write!(
             f,
-            " convertible_roof_position={}",
-            self.convertible_roof_position
+            "{}radio_on_off_synchronization={}",
+            sep, self.radio_on_off_synchronization
         )?;
-        writeln!(
+        write!(f, "{}radio_on_off_toggle={}", sep, self.radio_on_off_toggle)?;
+        write!(
             f,
-            " audio_inviolability_request={}",
-            self.audio_inviolability_request
+            "{}preconditioning_menu_presence={}",
+            sep, self.preconditioning_menu_presence
         )?;
-        writeln!(
+        write!(
             f,
-            " vehicle_main_status_validity={}",
-            self.vehicle_main_status_validity
+            "{}visual_parking_assistance_enable={}",
+            sep, self.visual_parking_assistance_enable
+        )?;
+        write!(
+            f,
+            "{}media_shutdown_request={}",
+            sep, self.media_shutdown_request
+        )?;
+        write!(
+            f,
+            "{}convertible_roof_position={}",
+            sep, self.convertible_roof_position
+        )?;
+        write!(
+            f,
+            "{}audio_inviolability_request={}",
+            sep, self.audio_inviolability_request
+        )?;
+        write!(
+            f,
+            "{}vehicle_main_status_validity={}",
+            sep, self.vehicle_main_status_validity
         )
     }
 }
 
+impl From<&crate::aee2010::infodiv::x036::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x036::Repr) -> Self {
+        Repr {
+            driver_memory_setting: repr_2010.driver_memory_setting,
+            driver_memory_setting_write: repr_2010.driver_memory_setting_write,
+            driver_memory_setting_recall: repr_2010.driver_memory_setting_recall,
+            driver_profile_number: UserProfile::None, // No equivalent on AEE2010.
+            passenger_memory_setting: 0,              // No equivalent on AEE2010.
+            passenger_memory_setting_write: false,    // No equivalent on AEE2010.
+            passenger_memory_setting_recall: false,   // No equivalent on AEE2010.
+            passenger_profile_number: UserProfile::None, // No equivalent on AEE2010.
+            delestage_level: 0,                       // No equivalent on AEE2010.
+            economy_mode_enabled: repr_2010.economy_mode_enabled,
+            lighting_level: repr_2010.lighting_level,
+            black_panel_enabled: repr_2010.black_panel_enabled,
+            day_night: repr_2010.day_night,
+            rheostat_mode: repr_2010.rheostat_mode,
+            lighting_reset_to_reference_level_request: repr_2010
+                .lighting_reset_to_reference_level_request,
+            network_state: repr_2010.network_state,
+            fault_logging_forbidden: repr_2010.fault_logging_forbidden,
+            network_supervision_authorization: repr_2010.network_supervision_authorization,
+            fault_erase_request: repr_2010.fault_erase_request,
+            sport_mode_enable: repr_2010.sport_mode_enable,
+            hybrid_powertrain_mode_updated_data: repr_2010.hybrid_powertrain_mode_updated_data,
+            hybrid_powertrain_mode: repr_2010.hybrid_powertrain_mode,
+            hybrid_powertrain_state_updated_data: repr_2010.hybrid_powertrain_state_updated_data,
+            hybrid_powertrain_state: repr_2010.hybrid_powertrain_state,
+            radio_on_off_synchronization: repr_2010.radio_on_off_synchronization,
+            radio_on_off_toggle: repr_2010.radio_on_off_toggle,
+            preconditioning_menu_presence: repr_2010.preconditioning_menu_presence,
+            visual_parking_assistance_enable: repr_2010.visual_parking_assistance_enable,
+            media_shutdown_request: repr_2010.media_shutdown_request,
+            convertible_roof_position: repr_2010.convertible_roof_position,
+            audio_inviolability_request: repr_2010.audio_inviolability_request,
+            vehicle_main_status_validity: repr_2010.vehicle_main_status_validity,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
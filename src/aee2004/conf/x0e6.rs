@@ -3,10 +3,15 @@ use core::{cmp::Ordering, fmt, time::Duration};
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
-    vehicle::{SlopeType, StopAndStartBrakeRequirement},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    vehicle::{SlopeType, StopAndStartBrakeRequirement, WheelData},
     Error, Result,
 };
 
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
+
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -48,6 +53,8 @@ pub const FRAME_LEN: usize = field::FLAGS_2 + 1;
 
 /// Periodicity of a x0e6 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(100);
+/// Transmission of a x0e6 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -372,10 +379,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x0e6 ({})", err)?;
                 Ok(())
@@ -390,9 +397,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x0e6 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub abs_failure_lamp_request: bool,
     pub low_level_brake_fluid: bool,
@@ -407,7 +420,7 @@ pub struct Repr {
     pub rear_right_wheel_counter: u16,
     pub rear_right_wheel_counter_failure: bool,
     #[cfg(feature = "float")]
-    pub battery_voltage: f32,
+    pub battery_voltage: Physical,
     #[cfg(not(feature = "float"))]
     pub battery_voltage: u8,
     pub slope_type: SlopeType,
@@ -417,10 +430,16 @@ pub struct Repr {
 }
 
 impl Repr {
+    #[inline]
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             abs_failure_lamp_request: frame.abs_failure_lamp_request(),
             low_level_brake_fluid: frame.low_level_brake_fluid(),
             worn_brake_pad: frame.worn_brake_pad(),
@@ -434,22 +453,24 @@ impl Repr {
             rear_right_wheel_counter: frame.rear_right_wheel_counter(),
             rear_right_wheel_counter_failure: frame.rear_right_wheel_counter_failure(),
             #[cfg(feature = "float")]
-            battery_voltage: ((frame.battery_voltage() as f32) + 144.0) / 20.0,
+            battery_voltage: physical::from_raw(frame.battery_voltage().into(), 144, 20),
             #[cfg(not(feature = "float"))]
             battery_voltage: frame.battery_voltage(),
             slope_type: frame.slope_type(),
             stop_start_brake_req: frame.stop_start_brake_req(),
             elec_power_management_failure: frame.elec_pwr_mgmt_failure(),
             ebw_managed_by_bcu: frame.ebw_managed_by_bcu(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
+    #[inline]
     pub fn buffer_len(&self) -> usize {
         FRAME_LEN
     }
 
     /// Emit a high-level representation into a x0e6 CAN frame.
+    #[inline]
     pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
         frame.set_abs_failure_lamp_request(self.abs_failure_lamp_request);
         frame.set_low_level_brake_fluid(self.low_level_brake_fluid);
@@ -464,7 +485,7 @@ impl Repr {
         frame.set_rear_right_wheel_counter(self.rear_right_wheel_counter);
         frame.set_rear_right_wheel_counter_failure(self.rear_right_wheel_counter_failure);
         #[cfg(feature = "float")]
-        frame.set_battery_voltage(((self.battery_voltage * 20.0) - 144.0) as u8);
+        frame.set_battery_voltage(physical::to_u8(self.battery_voltage, 144, 20));
         #[cfg(not(feature = "float"))]
         frame.set_battery_voltage(self.battery_voltage);
         frame.set_slope_type(self.slope_type);
@@ -472,68 +493,177 @@ impl Repr {
         frame.set_elec_pwr_mgmt_failure(self.elec_power_management_failure);
         frame.set_ebw_managed_by_bcu(self.ebw_managed_by_bcu);
     }
+
+    /// Return the rear left wheel counter and its validity.
+    pub fn rear_left_wheel(&self) -> WheelData {
+        WheelData {
+            counter: self.rear_left_wheel_counter,
+            valid: !self.rear_left_wheel_counter_failure,
+        }
+    }
+
+    /// Return the rear right wheel counter and its validity.
+    pub fn rear_right_wheel(&self) -> WheelData {
+        WheelData {
+            counter: self.rear_right_wheel_counter,
+            valid: !self.rear_right_wheel_counter_failure,
+        }
+    }
 }
 
+repr_builder! {
+    idle: [0x55, 0x2c, 0x15, 0x82, 0x26, 0x7c, 0x80],
+    parked: [0x00, 0x00, 0x00, 0x00, 0x00, 0x6a, 0x00],
+    with_abs_failure_lamp_request => abs_failure_lamp_request: bool,
+    with_low_level_brake_fluid => low_level_brake_fluid: bool,
+    with_worn_brake_pad => worn_brake_pad: bool,
+    with_ebd_in_regulation => ebd_in_regulation: bool,
+    with_auto_hazard_lamps_managed_by_bcu => auto_hazard_lamps_managed_by_bcu: bool,
+    with_abs_in_regulation => abs_in_regulation: bool,
+    with_abs_failure => abs_failure: bool,
+    with_ebd_failure_lamp_request => ebd_failure_lamp_request: bool,
+    with_rear_left_wheel_counter => rear_left_wheel_counter: u16,
+    with_rear_left_wheel_counter_failure => rear_left_wheel_counter_failure: bool,
+    with_rear_right_wheel_counter => rear_right_wheel_counter: u16,
+    with_rear_right_wheel_counter_failure => rear_right_wheel_counter_failure: bool,
+    #[cfg(feature = "float")]
+    with_battery_voltage => battery_voltage: Physical,
+    #[cfg(not(feature = "float"))]
+    with_battery_voltage => battery_voltage: u8,
+    with_slope_type => slope_type: SlopeType,
+    with_stop_start_brake_req => stop_start_brake_req: StopAndStartBrakeRequirement,
+    with_elec_power_management_failure => elec_power_management_failure: bool,
+    with_ebw_managed_by_bcu => ebw_managed_by_bcu: bool,
+}
+
+/// Signals of a x0e6 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("abs_failure_lamp_request", 0, 0, 1),
+    Signal::new("low_level_brake_fluid", 0, 1, 1),
+    Signal::new("worn_brake_pad", 0, 2, 1),
+    Signal::new("ebd_in_regulation", 0, 3, 1),
+    Signal::new("auto_hazard_lamps_managed_by_bcu", 0, 4, 1),
+    Signal::new("abs_in_regulation", 0, 5, 1),
+    Signal::new("abs_failure", 0, 6, 1),
+    Signal::new("ebd_failure_lamp_request", 0, 7, 1),
+    Signal::new("rear_left_wheel_counter", 1, 6, 15),
+    Signal::new("rear_left_wheel_counter_failure", 1, 7, 1),
+    Signal::new("rear_right_wheel_counter", 3, 6, 15),
+    Signal::new("rear_right_wheel_counter_failure", 3, 7, 1),
+    Signal::new("battery_voltage", 5, 7, 8)
+        .scaled(0.05, 7.2)
+        .unit("V"),
+    Signal::new("slope_type", 6, 3, 2).values(&[
+        (0, "Light"),
+        (1, "SteepUpward"),
+        (2, "SteepDownward"),
+        (3, "Undefined"),
+    ]),
+    Signal::new("stop_start_brake_req", 6, 5, 2).values(&[
+        (0, "Nothing"),
+        (1, "StopInhibit"),
+        (2, "Restart"),
+        (3, "StopAndRestartInhibit"),
+    ]),
+    Signal::new("elec_power_management_failure", 6, 6, 1),
+    Signal::new("ebw_managed_by_bcu", 6, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x0e6")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x0e6")?;
+        write!(
             f,
-            " abs_failure_lamp_request={}",
-            self.abs_failure_lamp_request
+            "{}abs_failure_lamp_request={}",
+            sep, self.abs_failure_lamp_request
         )?;
-        writeln!(f, " low_level_brake_fluid={}", self.low_level_brake_fluid)?;
-        writeln!(f, " worn_brake_pad={}", self.worn_brake_pad)?;
-        writeln!(f, " ebd_in_regulation={}", self.ebd_in_regulation)?;
-        writeln!(
+        write!(
             f,
-            " auto_hazard_lamps_managed_by_bcu={}",
-            self.auto_hazard_lamps_managed_by_bcu
+            "{}low_level_brake_fluid={}",
+            sep, self.low_level_brake_fluid
         )?;
-        writeln!(f, " abs_in_regulation={}", self.abs_in_regulation)?;
-        writeln!(f, " abs_failure={}", self.abs_failure)?;
-        writeln!(
+        write!(f, "{}worn_brake_pad={}", sep, self.worn_brake_pad)?;
+        write!(f, "{}ebd_in_regulation={}", sep, self.ebd_in_regulation)?;
+        write!(
             f,
-            " ebd_failure_lamp_request={}",
-            self.ebd_failure_lamp_request
+            "{}auto_hazard_lamps_managed_by_bcu={}",
+            sep, self.auto_hazard_lamps_managed_by_bcu
         )?;
-        writeln!(
+        write!(f, "{}abs_in_regulation={}", sep, self.abs_in_regulation)?;
+        write!(f, "{}abs_failure={}", sep, self.abs_failure)?;
+        write!(
             f,
-            " rear_left_wheel_counter={}",
-            self.rear_left_wheel_counter
+            "{}ebd_failure_lamp_request={}",
+            sep, self.ebd_failure_lamp_request
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_left_wheel_counter_failure={}",
-            self.rear_left_wheel_counter_failure
+            "{}rear_left_wheel_counter={}",
+            sep, self.rear_left_wheel_counter
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_wheel_counter={}",
-            self.rear_right_wheel_counter
+            "{}rear_left_wheel_counter_failure={}",
+            sep, self.rear_left_wheel_counter_failure
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_wheel_counter_failure={}",
-            self.rear_right_wheel_counter_failure
+            "{}rear_right_wheel_counter={}",
+            sep, self.rear_right_wheel_counter
         )?;
-        writeln!(f, " battery_voltage={}", self.battery_voltage)?;
-        writeln!(f, " slope_type={}", self.slope_type)?;
-        writeln!(f, " stop_start_brake_req={}", self.stop_start_brake_req)?;
-        writeln!(
+        write!(
             f,
-            " elec_power_management_failure={}",
-            self.elec_power_management_failure
+            "{}rear_right_wheel_counter_failure={}",
+            sep, self.rear_right_wheel_counter_failure
         )?;
-        writeln!(f, " ebw_managed_by_bcu={}", self.ebw_managed_by_bcu)
+        write!(f, "{}battery_voltage={}", sep, self.battery_voltage)?;
+        write!(f, "{}slope_type={}", sep, self.slope_type)?;
+        write!(
+            f,
+            "{}stop_start_brake_req={}",
+            sep, self.stop_start_brake_req
+        )?;
+        write!(
+            f,
+            "{}elec_power_management_failure={}",
+            sep, self.elec_power_management_failure
+        )?;
+        write!(f, "{}ebw_managed_by_bcu={}", sep, self.ebw_managed_by_bcu)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x0e6::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x0e6::Repr) -> Self {
+        Repr {
+            abs_failure_lamp_request: repr_2010.abs_failure_lamp_request,
+            low_level_brake_fluid: repr_2010.low_level_brake_fluid,
+            worn_brake_pad: repr_2010.worn_brake_pad,
+            ebd_in_regulation: repr_2010.ebd_in_regulation,
+            auto_hazard_lamps_managed_by_bcu: repr_2010.auto_hazard_lamps_managed_by_bcu,
+            abs_in_regulation: repr_2010.abs_in_regulation,
+            abs_failure: false, // No equivalent on AEE2010.
+            ebd_failure_lamp_request: repr_2010.ebd_failure_lamp_request,
+            rear_left_wheel_counter: repr_2010.rear_left_wheel_counter,
+            rear_left_wheel_counter_failure: repr_2010.rear_left_wheel_counter_failure,
+            rear_right_wheel_counter: repr_2010.rear_right_wheel_counter,
+            rear_right_wheel_counter_failure: repr_2010.rear_right_wheel_counter_failure,
+            battery_voltage: repr_2010.battery_voltage,
+            slope_type: repr_2010.slope_type,
+            stop_start_brake_req: repr_2010.stop_start_brake_req,
+            elec_power_management_failure: repr_2010.elec_power_management_failure,
+            ebw_managed_by_bcu: repr_2010.ebw_managed_by_bcu,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
     use crate::{
-        vehicle::{SlopeType, StopAndStartBrakeRequirement},
+        vehicle::{SlopeType, StopAndStartBrakeRequirement, WheelData},
         Error,
     };
 
@@ -554,7 +684,7 @@ mod test {
             rear_left_wheel_counter_failure: false,
             rear_right_wheel_counter: 550,
             rear_right_wheel_counter_failure: true,
-            battery_voltage: 13.4,
+            battery_voltage: physical::decimal(134, 10),
             slope_type: SlopeType::Light,
             stop_start_brake_req: StopAndStartBrakeRequirement::Nothing,
             elec_power_management_failure: false,
@@ -576,7 +706,7 @@ mod test {
             rear_left_wheel_counter_failure: true,
             rear_right_wheel_counter: 8561,
             rear_right_wheel_counter_failure: false,
-            battery_voltage: 14.1,
+            battery_voltage: physical::decimal(141, 10),
             slope_type: SlopeType::SteepUpward,
             stop_start_brake_req: StopAndStartBrakeRequirement::Restart,
             elec_power_management_failure: true,
@@ -734,4 +864,35 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_wheel_data() {
+        let repr = frame_1_repr();
+        assert_eq!(
+            repr.rear_left_wheel(),
+            WheelData {
+                counter: 11285,
+                valid: true,
+            }
+        );
+        assert_eq!(
+            repr.rear_right_wheel(),
+            WheelData {
+                counter: 550,
+                valid: false,
+            }
+        );
+
+        let previous = WheelData {
+            counter: 0x7ffe,
+            valid: true,
+        };
+        let current = WheelData {
+            counter: 0x0003,
+            valid: true,
+        };
+        assert_eq!(current.ticks_since(&previous), Some(5));
+        assert_eq!(repr.rear_left_wheel().ticks_since(&previous), Some(11287));
+        assert_eq!(repr.rear_right_wheel().ticks_since(&previous), None);
+    }
 }
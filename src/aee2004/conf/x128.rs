@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
         AutoGearboxMode, GearEfficiencyArrowType, GearboxDriveModeGear, GearboxGear, GearboxType,
         IndicatorState,
@@ -85,6 +87,9 @@ pub const FRAME_ID: u16 = 0x128;
 /// Length of a x128 CAN frame.
 pub const FRAME_LEN: usize = field::FLAGS_8 + 1;
 
+/// Transmission of a x128 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -253,10 +258,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x128 ({})", err)?;
                 Ok(())
@@ -271,9 +276,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x128 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub service_indicator_relaunch: bool,
     pub passenger_seat_belt_indicator: bool,
@@ -335,7 +346,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             service_indicator_relaunch: frame.read_bit::<{ field::FLAGS_1 }, 0>(),
             passenger_seat_belt_indicator: frame.read_bit::<{ field::FLAGS_1 }, 1>(),
             diesel_pre_heating: frame.read_bit::<{ field::FLAGS_1 }, 2>(),
@@ -391,7 +407,7 @@ impl Repr {
             gear_efficiency_indicator_arrow_type: frame.gear_efficiency_indicator_arrow_type(),
             automatic_gearbox_mode: frame.automatic_gearbox_mode(),
             gear_efficiency_indicator_blinking: frame.read_bit::<{ field::FLAGS_8 }, 7>(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -460,174 +476,455 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x55, 0x55, 0x55, 0x53, 0x55, 0x55, 0x35, 0x00],
+    with_service_indicator_relaunch => service_indicator_relaunch: bool,
+    with_passenger_seat_belt_indicator => passenger_seat_belt_indicator: bool,
+    with_diesel_pre_heating => diesel_pre_heating: bool,
+    with_fuel_cutoff => fuel_cutoff: bool,
+    with_low_fuel => low_fuel: bool,
+    with_parking_brake_applied => parking_brake_applied: bool,
+    with_driver_seat_belt_indicator => driver_seat_belt_indicator: bool,
+    with_passenger_airbag_inhibited => passenger_airbag_inhibited: bool,
+    with_unfastened_rear_seat_belt => unfastened_rear_seat_belt: bool,
+    with_abs_indicator => abs_indicator: bool,
+    with_passenger_protection => passenger_protection: bool,
+    with_opened_door_more_10kph => opened_door_more_10kph: bool,
+    with_opened_door_less_10kph => opened_door_less_10kph: bool,
+    with_stop_indicator_relaunch => stop_indicator_relaunch: bool,
+    with_stop_indicator => stop_indicator: bool,
+    with_service_indicator => service_indicator: bool,
+    with_ready_indicator => ready_indicator: bool,
+    with_hazard_warning_lights => hazard_warning_lights: bool,
+    with_suspension_indicator => suspension_indicator: bool,
+    with_esp_indicator => esp_indicator: bool,
+    with_esp_inhibited => esp_inhibited: bool,
+    with_child_lock_security => child_lock_security: bool,
+    with_customization_request => customization_request: bool,
+    with_color_change_request => color_change_request: bool,
+    with_rear_seat_belt_indicator_blinking => rear_seat_belt_indicator_blinking: bool,
+    with_foot_on_brake_pedal_indicator => foot_on_brake_pedal_indicator: IndicatorState,
+    with_available_space_measurement_indicator_blinking => available_space_measurement_indicator_blinking: bool,
+    with_available_space_measurement_indicator => available_space_measurement_indicator: bool,
+    with_hill_assist_indicator => hill_assist_indicator: bool,
+    with_passenger_seat_belt_indicator_blinking => passenger_seat_belt_indicator_blinking: bool,
+    with_driver_seat_belt_indicator_blinking => driver_seat_belt_indicator_blinking: bool,
+    with_daytime_running_lamps_indicator => daytime_running_lamps_indicator: bool,
+    with_left_blinker_indicator => left_blinker_indicator: bool,
+    with_right_blinker_indicator => right_blinker_indicator: bool,
+    with_rear_anti_fog_light_indicator => rear_anti_fog_light_indicator: bool,
+    with_front_anti_fog_light_indicator => front_anti_fog_light_indicator: bool,
+    with_main_beam_indicator => main_beam_indicator: bool,
+    with_headlamps_indicator => headlamps_indicator: bool,
+    with_sidelights_indicator => sidelights_indicator: bool,
+    with_automatic_parking_brake_inhibited => automatic_parking_brake_inhibited: bool,
+    with_rear_right_seat_belt_indicator_blinking => rear_right_seat_belt_indicator_blinking: bool,
+    with_rear_right_seat_belt_indicator => rear_right_seat_belt_indicator: bool,
+    with_rear_middle_seat_belt_indicator_blinking => rear_middle_seat_belt_indicator_blinking: bool,
+    with_rear_middle_seat_belt_indicator => rear_middle_seat_belt_indicator: bool,
+    with_rear_left_seat_belt_indicator_blinking => rear_left_seat_belt_indicator_blinking: bool,
+    with_rear_left_seat_belt_indicator => rear_left_seat_belt_indicator: bool,
+    with_instrument_cluster_on => instrument_cluster_on: bool,
+    with_displayed_gear_blinking => displayed_gear_blinking: bool,
+    with_gearbox_drive_mode_gear => gearbox_drive_mode_gear: GearboxDriveModeGear,
+    with_gearbox_gear => gearbox_gear: GearboxGear,
+    with_gearbox_type => gearbox_type: GearboxType,
+    with_gear_efficiency_indicator_arrow_type => gear_efficiency_indicator_arrow_type: GearEfficiencyArrowType,
+    with_automatic_gearbox_mode => automatic_gearbox_mode: AutoGearboxMode,
+    with_gear_efficiency_indicator_blinking => gear_efficiency_indicator_blinking: bool,
+}
+
+/// Signals of a x128 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("service_indicator_relaunch", 0, 0, 1),
+    Signal::new("passenger_seat_belt_indicator", 0, 1, 1),
+    Signal::new("diesel_pre_heating", 0, 2, 1),
+    Signal::new("fuel_cutoff", 0, 3, 1),
+    Signal::new("low_fuel", 0, 4, 1),
+    Signal::new("parking_brake_applied", 0, 5, 1),
+    Signal::new("driver_seat_belt_indicator", 0, 6, 1),
+    Signal::new("passenger_airbag_inhibited", 0, 7, 1),
+    Signal::new("unfastened_rear_seat_belt", 1, 0, 1),
+    Signal::new("abs_indicator", 1, 1, 1),
+    Signal::new("passenger_protection", 1, 2, 1),
+    Signal::new("opened_door_more_10kph", 1, 3, 1),
+    Signal::new("opened_door_less_10kph", 1, 4, 1),
+    Signal::new("stop_indicator_relaunch", 1, 5, 1),
+    Signal::new("stop_indicator", 1, 6, 1),
+    Signal::new("service_indicator", 1, 7, 1),
+    Signal::new("ready_indicator", 2, 0, 1),
+    Signal::new("hazard_warning_lights", 2, 1, 1),
+    Signal::new("suspension_indicator", 2, 2, 1),
+    Signal::new("esp_indicator", 2, 3, 1),
+    Signal::new("esp_inhibited", 2, 4, 1),
+    Signal::new("child_lock_security", 2, 5, 1),
+    Signal::new("customization_request", 2, 6, 1),
+    Signal::new("color_change_request", 2, 7, 1),
+    Signal::new("rear_seat_belt_indicator_blinking", 3, 0, 1),
+    Signal::new("foot_on_brake_pedal_indicator", 3, 2, 2).values(&[
+        (0, "Off"),
+        (1, "On"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("available_space_measurement_indicator_blinking", 3, 3, 1),
+    Signal::new("available_space_measurement_indicator", 3, 4, 1),
+    Signal::new("hill_assist_indicator", 3, 5, 1),
+    Signal::new("passenger_seat_belt_indicator_blinking", 3, 6, 1),
+    Signal::new("driver_seat_belt_indicator_blinking", 3, 7, 1),
+    Signal::new("daytime_running_lamps_indicator", 4, 0, 1),
+    Signal::new("left_blinker_indicator", 4, 1, 1),
+    Signal::new("right_blinker_indicator", 4, 2, 1),
+    Signal::new("rear_anti_fog_light_indicator", 4, 3, 1),
+    Signal::new("front_anti_fog_light_indicator", 4, 4, 1),
+    Signal::new("main_beam_indicator", 4, 5, 1),
+    Signal::new("headlamps_indicator", 4, 6, 1),
+    Signal::new("sidelights_indicator", 4, 7, 1),
+    Signal::new("automatic_parking_brake_inhibited", 5, 0, 1),
+    Signal::new("rear_right_seat_belt_indicator_blinking", 5, 1, 1),
+    Signal::new("rear_right_seat_belt_indicator", 5, 2, 1),
+    Signal::new("rear_middle_seat_belt_indicator_blinking", 5, 3, 1),
+    Signal::new("rear_middle_seat_belt_indicator", 5, 4, 1),
+    Signal::new("rear_left_seat_belt_indicator_blinking", 5, 5, 1),
+    Signal::new("rear_left_seat_belt_indicator", 5, 6, 1),
+    Signal::new("instrument_cluster_on", 5, 7, 1),
+    Signal::new("displayed_gear_blinking", 6, 0, 1),
+    Signal::new("gearbox_drive_mode_gear", 6, 3, 3).values(&[
+        (0, "Disengaged"),
+        (1, "Gear1"),
+        (2, "Gear2"),
+        (3, "Gear3"),
+        (4, "Gear4"),
+        (5, "Gear5"),
+        (6, "Gear6"),
+        (7, "Gear7"),
+    ]),
+    Signal::new("gearbox_gear", 6, 7, 4).values(&[
+        (0, "P"),
+        (1, "R"),
+        (2, "N"),
+        (3, "D"),
+        (4, "Gear6"),
+        (5, "Gear5"),
+        (6, "Gear4"),
+        (7, "Gear3"),
+        (8, "Gear2"),
+        (9, "Gear1"),
+        (11, "Nothing"),
+        (12, "Gear7"),
+        (13, "Gear8"),
+        (14, "Gear9"),
+        (15, "B"),
+    ]),
+    Signal::new("gearbox_type", 7, 1, 2).values(&[
+        (0, "Automatic"),
+        (1, "Manual"),
+        (2, "ManualRobotized"),
+        (3, "AutomaticDualClutch"),
+    ]),
+    Signal::new("gear_efficiency_indicator_arrow_type", 7, 3, 2).values(&[
+        (0, "Nothing"),
+        (1, "Up"),
+        (2, "Down"),
+        (3, "UpAndDown"),
+    ]),
+    Signal::new("automatic_gearbox_mode", 7, 6, 3).values(&[
+        (0, "Automatic"),
+        (2, "AutomaticSport"),
+        (4, "Sequential"),
+        (5, "SequentialSport"),
+        (6, "AutomaticSnow"),
+        (7, "ASM"),
+    ]),
+    Signal::new("gear_efficiency_indicator_blinking", 7, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x128")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x128")?;
+        write!(
             f,
-            " service_indicator_relaunch={}",
-            self.service_indicator_relaunch
+            "{}service_indicator_relaunch={}",
+            sep, self.service_indicator_relaunch
         )?;
-        writeln!(
+        write!(
             f,
-            " passenger_seat_belt_indicator={}",
-            self.passenger_seat_belt_indicator
+            "{}passenger_seat_belt_indicator={}",
+            sep, self.passenger_seat_belt_indicator
         )?;
-        writeln!(f, " diesel_pre_heating={}", self.diesel_pre_heating)?;
-        writeln!(f, " fuel_cutoff={}", self.fuel_cutoff)?;
-        writeln!(f, " low_fuel={}", self.low_fuel)?;
-        writeln!(f, " parking_brake_applied={}", self.parking_brake_applied)?;
-        writeln!(
+        write!(f, "{}diesel_pre_heating={}", sep, self.diesel_pre_heating)?;
+        write!(f, "{}fuel_cutoff={}", sep, self.fuel_cutoff)?;
+        write!(f, "{}low_fuel={}", sep, self.low_fuel)?;
+        write!(
             f,
-            " driver_seat_belt_indicator={}",
-            self.driver_seat_belt_indicator
+            "{}parking_brake_applied={}",
+            sep, self.parking_brake_applied
         )?;
-        writeln!(
+        write!(
             f,
-            " passenger_airbag_inhibited={}",
-            self.passenger_airbag_inhibited
+            "{}driver_seat_belt_indicator={}",
+            sep, self.driver_seat_belt_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " unfastened_rear_seat_belt={}",
-            self.unfastened_rear_seat_belt
+            "{}passenger_airbag_inhibited={}",
+            sep, self.passenger_airbag_inhibited
         )?;
-        writeln!(f, " abs_indicator={}", self.abs_indicator)?;
-        writeln!(f, " passenger_protection={}", self.passenger_protection)?;
-        writeln!(f, " opened_door_more_10kph={}", self.opened_door_more_10kph)?;
-        writeln!(f, " opened_door_less_10kph={}", self.opened_door_less_10kph)?;
-        writeln!(
+        write!(
             f,
-            " stop_indicator_relaunch={}",
-            self.stop_indicator_relaunch
+            "{}unfastened_rear_seat_belt={}",
+            sep, self.unfastened_rear_seat_belt
         )?;
-        writeln!(f, " stop_indicator={}", self.stop_indicator)?;
-        writeln!(f, " service_indicator={}", self.service_indicator)?;
-        writeln!(f, " ready_indicator={}", self.ready_indicator)?;
-        writeln!(f, " hazard_warning_lights={}", self.hazard_warning_lights)?;
-        writeln!(f, " suspension_indicator={}", self.suspension_indicator)?;
-        writeln!(f, " esp_indicator={}", self.esp_indicator)?;
-        writeln!(f, " esp_inhibited={}", self.esp_inhibited)?;
-        writeln!(f, " child_lock_security={}", self.child_lock_security)?;
-        writeln!(f, " customization_request={}", self.customization_request)?;
-        writeln!(f, " color_change_request={}", self.color_change_request)?;
-        writeln!(
+        write!(f, "{}abs_indicator={}", sep, self.abs_indicator)?;
+        write!(
             f,
-            " rear_seat_belt_indicator_blinking={}",
-            self.rear_seat_belt_indicator_blinking
+            "{}passenger_protection={}",
+            sep, self.passenger_protection
         )?;
-        writeln!(
+        write!(
             f,
-            " foot_on_brake_pedal_indicator={}",
-            self.foot_on_brake_pedal_indicator
+            "{}opened_door_more_10kph={}",
+            sep, self.opened_door_more_10kph
         )?;
-        writeln!(
+        write!(
             f,
-            " available_space_measurement_indicator_blinking={}",
-            self.available_space_measurement_indicator_blinking
+            "{}opened_door_less_10kph={}",
+            sep, self.opened_door_less_10kph
         )?;
-        writeln!(
+        write!(
             f,
-            " available_space_measurement_indicator={}",
-            self.available_space_measurement_indicator
+            "{}stop_indicator_relaunch={}",
+            sep, self.stop_indicator_relaunch
         )?;
-        writeln!(f, " hill_assist_indicator={}", self.hill_assist_indicator)?;
-        writeln!(
+        write!(f, "{}stop_indicator={}", sep, self.stop_indicator)?;
+        write!(f, "{}service_indicator={}", sep, self.service_indicator)?;
+        write!(f, "{}ready_indicator={}", sep, self.ready_indicator)?;
+        write!(
             f,
-            " passenger_seat_belt_indicator_blinking={}",
-            self.passenger_seat_belt_indicator_blinking
+            "{}hazard_warning_lights={}",
+            sep, self.hazard_warning_lights
         )?;
-        writeln!(
+        write!(
             f,
-            " driver_seat_belt_indicator_blinking={}",
-            self.driver_seat_belt_indicator_blinking
+            "{}suspension_indicator={}",
+            sep, self.suspension_indicator
         )?;
-        writeln!(
+        write!(f, "{}esp_indicator={}", sep, self.esp_indicator)?;
+        write!(f, "{}esp_inhibited={}", sep, self.esp_inhibited)?;
+        write!(f, "{}child_lock_security={}", sep, self.child_lock_security)?;
+        write!(
             f,
-            " daytime_running_lamps_indicator={}",
-            self.daytime_running_lamps_indicator
+            "{}customization_request={}",
+            sep, self.customization_request
         )?;
-        writeln!(f, " left_blinker_indicator={}", self.left_blinker_indicator)?;
-        writeln!(
+        write!(
             f,
-            " right_blinker_indicator={}",
-            self.right_blinker_indicator
+            "{}color_change_request={}",
+            sep, self.color_change_request
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_anti_fog_light_indicator={}",
-            self.rear_anti_fog_light_indicator
+            "{}rear_seat_belt_indicator_blinking={}",
+            sep, self.rear_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " front_anti_fog_light_indicator={}",
-            self.front_anti_fog_light_indicator
+            "{}foot_on_brake_pedal_indicator={}",
+            sep, self.foot_on_brake_pedal_indicator
         )?;
-        writeln!(f, " main_beam_indicator={}", self.main_beam_indicator)?;
-        writeln!(f, " headlamps_indicator={}", self.headlamps_indicator)?;
-        writeln!(f, " sidelights_indicator={}", self.sidelights_indicator)?;
-        writeln!(
+        write!(
             f,
-            " automatic_parking_brake_inhibited={}",
-            self.automatic_parking_brake_inhibited
+            "{}available_space_measurement_indicator_blinking={}",
+            sep, self.available_space_measurement_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_seat_belt_indicator_blinking={}",
-            self.rear_right_seat_belt_indicator_blinking
+            "{}available_space_measurement_indicator={}",
+            sep, self.available_space_measurement_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_seat_belt_indicator={}",
-            self.rear_right_seat_belt_indicator
+            "{}hill_assist_indicator={}",
+            sep, self.hill_assist_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_middle_seat_belt_indicator_blinking={}",
-            self.rear_middle_seat_belt_indicator_blinking
+            "{}passenger_seat_belt_indicator_blinking={}",
+            sep, self.passenger_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_middle_seat_belt_indicator={}",
-            self.rear_middle_seat_belt_indicator
+            "{}driver_seat_belt_indicator_blinking={}",
+            sep, self.driver_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_left_seat_belt_indicator_blinking={}",
-            self.rear_left_seat_belt_indicator_blinking
+            "{}daytime_running_lamps_indicator={}",
+            sep, self.daytime_running_lamps_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_left_seat_belt_indicator={}",
-            self.rear_left_seat_belt_indicator
+            "{}left_blinker_indicator={}",
+            sep, self.left_blinker_indicator
         )?;
-        writeln!(f, " instrument_cluster_on={}", self.instrument_cluster_on)?;
-        writeln!(
+        write!(
             f,
-            " displayed_gear_blinking={}",
-            self.displayed_gear_blinking
+            "{}right_blinker_indicator={}",
+            sep, self.right_blinker_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " gearbox_drive_mode_gear={}",
-            self.gearbox_drive_mode_gear
+            "{}rear_anti_fog_light_indicator={}",
+            sep, self.rear_anti_fog_light_indicator
         )?;
-        writeln!(f, " gearbox_gear={}", self.gearbox_gear)?;
-        writeln!(f, " gearbox_type={}", self.gearbox_type)?;
-        writeln!(
+        write!(
             f,
-            " gear_efficiency_indicator_arrow_type={}",
-            self.gear_efficiency_indicator_arrow_type
+            "{}front_anti_fog_light_indicator={}",
+            sep, self.front_anti_fog_light_indicator
         )?;
-        writeln!(f, " automatic_gearbox_mode={}", self.automatic_gearbox_mode)?;
-        writeln!(
+        write!(f, "{}main_beam_indicator={}", sep, self.main_beam_indicator)?;
+        write!(f, "{}headlamps_indicator={}", sep, self.headlamps_indicator)?;
+        write!(
             f,
-            " gear_efficiency_indicator_blinking={}",
-            self.gear_efficiency_indicator_blinking
+            "{}sidelights_indicator={}",
+            sep, self.sidelights_indicator
+        )?;
+        write!(
+            f,
+            "{}automatic_parking_brake_inhibited={}",
+            sep, self.automatic_parking_brake_inhibited
+        )?;
+        write!(
+            f,
+            "{}rear_right_seat_belt_indicator_blinking={}",
+            sep, self.rear_right_seat_belt_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}rear_right_seat_belt_indicator={}",
+            sep, self.rear_right_seat_belt_indicator
+        )?;
+        write!(
+            f,
+            "{}rear_middle_seat_belt_indicator_blinking={}",
+            sep, self.rear_middle_seat_belt_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}rear_middle_seat_belt_indicator={}",
+            sep, self.rear_middle_seat_belt_indicator
+        )?;
+        write!(
+            f,
+            "{}rear_left_seat_belt_indicator_blinking={}",
+            sep, self.rear_left_seat_belt_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}rear_left_seat_belt_indicator={}",
+            sep, self.rear_left_seat_belt_indicator
+        )?;
+        write!(
+            f,
+            "{}instrument_cluster_on={}",
+            sep, self.instrument_cluster_on
+        )?;
+        write!(
+            f,
+            "{}displayed_gear_blinking={}",
+            sep, self.displayed_gear_blinking
+        )?;
+        write!(
+            f,
+            "{}gearbox_drive_mode_gear={}",
+            sep, self.gearbox_drive_mode_gear
+        )?;
+        write!(f, "{}gearbox_gear={}", sep, self.gearbox_gear)?;
+        write!(f, "{}gearbox_type={}", sep, self.gearbox_type)?;
+        write!(
+            f,
+            "{}gear_efficiency_indicator_arrow_type={}",
+            sep, self.gear_efficiency_indicator_arrow_type
+        )?;
+        write!(
+            f,
+            "{}automatic_gearbox_mode={}",
+            sep, self.automatic_gearbox_mode
+        )?;
+        write!(
+            f,
+            "{}gear_efficiency_indicator_blinking={}",
+            sep, self.gear_efficiency_indicator_blinking
         )
     }
 }
 
+impl From<&crate::aee2010::infodiv::x128::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x128::Repr) -> Self {
+        Repr {
+            service_indicator_relaunch: false, // No equivalent on AEE2010.
+            passenger_seat_belt_indicator: repr_2010.passenger_seat_belt_indicator,
+            diesel_pre_heating: repr_2010.diesel_pre_heating,
+            fuel_cutoff: false, // No equivalent on AEE2010.
+            low_fuel: repr_2010.low_fuel,
+            parking_brake_applied: repr_2010.parking_brake_applied,
+            driver_seat_belt_indicator: repr_2010.driver_seat_belt_indicator,
+            passenger_airbag_inhibited: repr_2010.passenger_airbag_inhibited,
+            unfastened_rear_seat_belt: false, // No equivalent on AEE2010.
+            abs_indicator: false,             // No equivalent on AEE2010.
+            passenger_protection: repr_2010.passenger_protection,
+            opened_door_more_10kph: false,  // No equivalent on AEE2010.
+            opened_door_less_10kph: false,  // No equivalent on AEE2010.
+            stop_indicator_relaunch: false, // No equivalent on AEE2010.
+            stop_indicator: repr_2010.stop_indicator,
+            service_indicator: repr_2010.service_indicator,
+            ready_indicator: false, // No equivalent on AEE2010.
+            hazard_warning_lights: repr_2010.hazard_warning_lights,
+            suspension_indicator: repr_2010.suspension_indicator,
+            esp_indicator: repr_2010.esp_indicator,
+            esp_inhibited: repr_2010.esp_inhibited,
+            child_lock_security: repr_2010.child_lock_security,
+            customization_request: false, // No equivalent on AEE2010.
+            color_change_request: false,  // No equivalent on AEE2010.
+            rear_seat_belt_indicator_blinking: false, // No equivalent on AEE2010.
+            foot_on_brake_pedal_indicator: repr_2010.foot_on_brake_pedal_indicator,
+            available_space_measurement_indicator_blinking: repr_2010
+                .available_space_measurement_indicator_blinking,
+            available_space_measurement_indicator: repr_2010.available_space_measurement_indicator,
+            hill_assist_indicator: false, // No equivalent on AEE2010.
+            passenger_seat_belt_indicator_blinking: repr_2010
+                .passenger_seat_belt_indicator_blinking,
+            driver_seat_belt_indicator_blinking: repr_2010.driver_seat_belt_indicator_blinking,
+            daytime_running_lamps_indicator: repr_2010.daytime_running_lamps_indicator,
+            left_blinker_indicator: repr_2010.left_blinker_indicator,
+            right_blinker_indicator: repr_2010.right_blinker_indicator,
+            rear_anti_fog_light_indicator: repr_2010.rear_anti_fog_light_indicator,
+            front_anti_fog_light_indicator: repr_2010.front_anti_fog_light_indicator,
+            main_beam_indicator: repr_2010.main_beam_indicator,
+            headlamps_indicator: repr_2010.headlamps_indicator,
+            sidelights_indicator: repr_2010.sidelights_indicator,
+            automatic_parking_brake_inhibited: repr_2010.automatic_parking_brake_inhibited,
+            rear_right_seat_belt_indicator_blinking: repr_2010
+                .rear_right_seat_belt_indicator_blinking,
+            rear_right_seat_belt_indicator: repr_2010.rear_right_seat_belt_indicator,
+            rear_middle_seat_belt_indicator_blinking: repr_2010
+                .rear_middle_seat_belt_indicator_blinking,
+            rear_middle_seat_belt_indicator: repr_2010.rear_middle_seat_belt_indicator,
+            rear_left_seat_belt_indicator_blinking: repr_2010
+                .rear_left_seat_belt_indicator_blinking,
+            rear_left_seat_belt_indicator: repr_2010.rear_left_seat_belt_indicator,
+            instrument_cluster_on: repr_2010.instrument_cluster_on,
+            displayed_gear_blinking: repr_2010.displayed_gear_blinking,
+            gearbox_drive_mode_gear: repr_2010.gearbox_drive_mode_gear,
+            gearbox_gear: repr_2010.gearbox_gear,
+            gearbox_type: repr_2010.gearbox_type,
+            gear_efficiency_indicator_arrow_type: repr_2010.gear_efficiency_indicator_arrow_type,
+            automatic_gearbox_mode: repr_2010.automatic_gearbox_mode,
+            gear_efficiency_indicator_blinking: repr_2010.gear_efficiency_indicator_blinking,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{field, Frame, Repr};
@@ -2,7 +2,11 @@ use core::{cmp::Ordering, fmt, time::Duration};
 
 use byteorder::{ByteOrder, NetworkEndian};
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -26,6 +30,8 @@ pub const FRAME_LEN: usize = field::FLAGS_ADBLUE_AUTONOMY.end;
 
 /// Periodicity of a x136 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x136 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -109,10 +115,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x136 ({})", err)?;
                 Ok(())
@@ -127,9 +133,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x136 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub adblue_autonomy: u16,
     pub adblue_autonomy_display_request: bool,
@@ -139,10 +151,15 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             adblue_autonomy: frame.adblue_autonomy(),
             adblue_autonomy_display_request: frame.adblue_autonomy_display_request(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -157,13 +174,27 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x3f, 0xfe],
+    with_adblue_autonomy => adblue_autonomy: u16,
+    with_adblue_autonomy_display_request => adblue_autonomy_display_request: bool,
+}
+
+/// Signals of a x136 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("adblue_autonomy", 0, 5, 14).unit("km"),
+    Signal::new("adblue_autonomy_display_request", 0, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x136 adblue_autonomy={}", self.adblue_autonomy)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x136")?;
+        write!(f, "{}adblue_autonomy={}", sep, self.adblue_autonomy)?;
+        write!(
             f,
-            " adblue_autonomy_display_request={}",
-            self.adblue_autonomy_display_request
+            "{}adblue_autonomy_display_request={}",
+            sep, self.adblue_autonomy_display_request
         )
     }
 }
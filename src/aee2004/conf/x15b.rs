@@ -1,7 +1,9 @@
 use core::{cmp::Ordering, fmt};
 
 use crate::{
-    config::{ConfigurableKeyAction2004, LightingDuration2004, UserProfile},
+    config::{ConfigurableKeyAction2004, LightingDuration2004, ProfileNumber, UserProfile},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result,
 };
 
@@ -64,6 +66,9 @@ pub const FRAME_ID: u16 = 0x15b;
 /// Length of a x15b CAN frame.
 pub const FRAME_LEN: usize = field::OPT_7 + 1;
 
+/// Transmission of a x15b CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnRequest;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -596,10 +601,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x15b ({})", err)?;
                 Ok(())
@@ -614,9 +619,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x15b CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub profile_number: UserProfile,
     pub parameters_validity: bool,
@@ -654,7 +665,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             profile_number: frame.profile_number(),
             parameters_validity: frame.parameters_validity(),
             auto_elec_parking_brake_application_enabled: frame
@@ -686,7 +702,7 @@ impl Repr {
             blind_spot_monitoring_status: frame.blind_spot_monitoring_status(),
             secu_enabled: frame.secu_enable(),
             configurable_key_mode: frame.configurable_key_mode(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -717,6 +733,7 @@ impl Repr {
         frame.set_adaptive_lamps_enable(self.adaptive_lamps_enabled);
         frame.set_ceiling_light_out_delay(self.ceiling_light_out_delay);
         frame.set_daytime_running_lamps_enable(self.daytime_running_lamps_enabled);
+        frame.set_mood_lighting_enable(self.mood_lighting_enabled);
         frame.set_low_fuel_level_alert_enable(self.low_fuel_level_alert_enabled);
         frame.set_key_left_in_car_alert_enable(self.key_left_in_car_alert_enabled);
         frame.set_lighting_left_on_alert_enable(self.lighting_left_on_alert_enabled);
@@ -732,123 +749,228 @@ impl Repr {
         frame.set_secu_enable(self.secu_enabled);
         frame.set_configurable_key_mode(self.configurable_key_mode);
     }
+
+    /// Return the driver profile these settings belong to, if any.
+    pub fn active_profile(&self) -> Option<ProfileNumber> {
+        ProfileNumber::try_from(self.profile_number).ok()
+    }
 }
 
+repr_builder! {
+    idle: [0x01, 0x03, 0xb4, 0x00, 0x00, 0xd0, 0x00, 0x20],
+    parked: [0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x20],
+    with_profile_number => profile_number: UserProfile,
+    with_parameters_validity => parameters_validity: bool,
+    with_auto_elec_parking_brake_application_enabled => auto_elec_parking_brake_application_enabled: bool,
+    with_welcome_function_enabled => welcome_function_enabled: bool,
+    with_partial_window_opening_enabled => partial_window_opening_enabled: bool,
+    with_locking_mode_on_coe_enabled => locking_mode_on_coe_enabled: bool,
+    with_auto_door_locking_when_leaving_enabled => auto_door_locking_when_leaving_enabled: bool,
+    with_boot_permanent_locking_enabled => boot_permanent_locking_enabled: bool,
+    with_auto_door_locking_when_driving_enabled => auto_door_locking_when_driving_enabled: bool,
+    with_selective_unlocking_enabled => selective_unlocking_enabled: bool,
+    with_follow_me_home_lighting_duration => follow_me_home_lighting_duration: LightingDuration2004,
+    with_automatic_headlamps_enabled => automatic_headlamps_enabled: bool,
+    with_follow_me_home_enabled => follow_me_home_enabled: bool,
+    with_motorway_lighting_enabled => motorway_lighting_enabled: bool,
+    with_adaptive_lamps_enabled => adaptive_lamps_enabled: bool,
+    with_ceiling_light_out_delay => ceiling_light_out_delay: u8,
+    with_daytime_running_lamps_enabled => daytime_running_lamps_enabled: bool,
+    with_mood_lighting_enabled => mood_lighting_enabled: bool,
+    with_low_fuel_level_alert_enabled => low_fuel_level_alert_enabled: bool,
+    with_key_left_in_car_alert_enabled => key_left_in_car_alert_enabled: bool,
+    with_lighting_left_on_alert_enabled => lighting_left_on_alert_enabled: bool,
+    with_alt_gen_enabled => alt_gen_enabled: bool,
+    with_esp_in_regulation_alert_enabled => esp_in_regulation_alert_enabled: bool,
+    with_auto_mirrors_folding_enabled => auto_mirrors_folding_enabled: bool,
+    with_rear_wiper_in_reverse_gear_enabled => rear_wiper_in_reverse_gear_enabled: bool,
+    with_mirrors_tilting_in_reverse_gear_enabled => mirrors_tilting_in_reverse_gear_enabled: bool,
+    with_park_sensors_status => park_sensors_status: u8,
+    with_blind_spot_monitoring_status => blind_spot_monitoring_status: u8,
+    with_secu_enabled => secu_enabled: bool,
+    with_configurable_key_mode => configurable_key_mode: ConfigurableKeyAction2004,
+}
+
+/// Signals of a x15b CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("profile_number", 0, 2, 3).values(&[
+        (0, "None"),
+        (1, "Profile1"),
+        (2, "Profile2"),
+        (4, "Profile3"),
+        (7, "Default"),
+    ]),
+    Signal::new("parameters_validity", 0, 3, 1),
+    Signal::new("auto_elec_parking_brake_application_enabled", 1, 0, 1),
+    Signal::new("welcome_function_enabled", 1, 1, 1),
+    Signal::new("partial_window_opening_enabled", 1, 2, 1),
+    Signal::new("locking_mode_on_coe_enabled", 1, 3, 1),
+    Signal::new("auto_door_locking_when_leaving_enabled", 1, 4, 1),
+    Signal::new("boot_permanent_locking_enabled", 1, 5, 1),
+    Signal::new("auto_door_locking_when_driving_enabled", 1, 6, 1),
+    Signal::new("selective_unlocking_enabled", 1, 7, 1),
+    Signal::new("follow_me_home_lighting_duration", 2, 3, 4).values(&[
+        (1, "FifteenSeconds"),
+        (2, "ThirtySeconds"),
+        (4, "SixtySeconds"),
+    ]),
+    Signal::new("automatic_headlamps_enabled", 2, 4, 1),
+    Signal::new("follow_me_home_enabled", 2, 5, 1),
+    Signal::new("motorway_lighting_enabled", 2, 6, 1),
+    Signal::new("adaptive_lamps_enabled", 2, 7, 1),
+    Signal::new("ceiling_light_out_delay", 3, 3, 4),
+    Signal::new("daytime_running_lamps_enabled", 3, 6, 1),
+    Signal::new("mood_lighting_enabled", 3, 7, 1),
+    Signal::new("low_fuel_level_alert_enabled", 4, 0, 1),
+    Signal::new("key_left_in_car_alert_enabled", 4, 1, 1),
+    Signal::new("lighting_left_on_alert_enabled", 4, 2, 1),
+    Signal::new("alt_gen_enabled", 4, 3, 1),
+    Signal::new("esp_in_regulation_alert_enabled", 4, 4, 1),
+    Signal::new("auto_mirrors_folding_enabled", 5, 3, 1),
+    Signal::new("rear_wiper_in_reverse_gear_enabled", 5, 4, 1),
+    Signal::new("mirrors_tilting_in_reverse_gear_enabled", 5, 5, 1),
+    Signal::new("park_sensors_status", 5, 7, 2),
+    Signal::new("blind_spot_monitoring_status", 6, 6, 2),
+    Signal::new("secu_enabled", 6, 7, 1),
+    Signal::new("configurable_key_mode", 7, 7, 4).values(&[
+        (1, "BlackPanel"),
+        (2, "CeilingLight"),
+        (5, "FaultLog"),
+        (6, "FunctionState"),
+        (8, "ClusterCustomization"),
+        (9, "ClusterColor"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x15b profile_number={}", self.profile_number)?;
-        writeln!(f, " parameters_validity={}", self.parameters_validity)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x15b")?;
+        write!(f, "{}profile_number={}", sep, self.profile_number)?;
+        write!(f, "{}parameters_validity={}", sep, self.parameters_validity)?;
+        write!(
+            f,
+            "{}auto_elec_parking_brake_application_enabled={}",
+            sep, self.auto_elec_parking_brake_application_enabled
+        )?;
+        write!(
+            f,
+            "{}welcome_function_enabled={}",
+            sep, self.welcome_function_enabled
+        )?;
+        write!(
             f,
-            " auto_elec_parking_brake_application_enabled={}",
-            self.auto_elec_parking_brake_application_enabled
+            "{}partial_window_opening_enabled={}",
+            sep, self.partial_window_opening_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " welcome_function_enabled={}",
-            self.welcome_function_enabled
+            "{}locking_mode_on_coe_enabled={}",
+            sep, self.locking_mode_on_coe_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " partial_window_opening_enabled={}",
-            self.partial_window_opening_enabled
+            "{}auto_door_locking_when_leaving_enabled={}",
+            sep, self.auto_door_locking_when_leaving_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " locking_mode_on_coe_enabled={}",
-            self.locking_mode_on_coe_enabled
+            "{}boot_permanent_locking_enabled={}",
+            sep, self.boot_permanent_locking_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " auto_door_locking_when_leaving_enabled={}",
-            self.auto_door_locking_when_leaving_enabled
+            "{}auto_door_locking_when_driving_enabled={}",
+            sep, self.auto_door_locking_when_driving_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " boot_permanent_locking_enabled={}",
-            self.boot_permanent_locking_enabled
+            "{}selective_unlocking_enabled={}",
+            sep, self.selective_unlocking_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " auto_door_locking_when_driving_enabled={}",
-            self.auto_door_locking_when_driving_enabled
+            "{}follow_me_home_lighting_duration={}",
+            sep, self.follow_me_home_lighting_duration
         )?;
-        writeln!(
+        write!(
             f,
-            " selective_unlocking_enabled={}",
-            self.selective_unlocking_enabled
+            "{}automatic_headlamps_enabled={}",
+            sep, self.automatic_headlamps_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " follow_me_home_lighting_duration={}",
-            self.follow_me_home_lighting_duration
+            "{}follow_me_home_enabled={}",
+            sep, self.follow_me_home_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_headlamps_enabled={}",
-            self.automatic_headlamps_enabled
+            "{}motorway_lighting_enabled={}",
+            sep, self.motorway_lighting_enabled
         )?;
-        writeln!(f, " follow_me_home_enabled={}", self.follow_me_home_enabled)?;
-        writeln!(
+        write!(
             f,
-            " motorway_lighting_enabled={}",
-            self.motorway_lighting_enabled
+            "{}adaptive_lamps_enabled={}",
+            sep, self.adaptive_lamps_enabled
         )?;
-        writeln!(f, " adaptive_lamps_enabled={}", self.adaptive_lamps_enabled)?;
-        writeln!(
+        write!(
             f,
-            " ceiling_light_out_delay={}",
-            self.ceiling_light_out_delay
+            "{}ceiling_light_out_delay={}",
+            sep, self.ceiling_light_out_delay
         )?;
-        writeln!(
+        write!(
             f,
-            " daytime_running_lamps_enabled={}",
-            self.daytime_running_lamps_enabled
+            "{}daytime_running_lamps_enabled={}",
+            sep, self.daytime_running_lamps_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " low_fuel_level_alert_enabled={}",
-            self.low_fuel_level_alert_enabled
+            "{}low_fuel_level_alert_enabled={}",
+            sep, self.low_fuel_level_alert_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " key_left_in_car_alert_enabled={}",
-            self.key_left_in_car_alert_enabled
+            "{}key_left_in_car_alert_enabled={}",
+            sep, self.key_left_in_car_alert_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " lighting_left_on_alert_enabled={}",
-            self.lighting_left_on_alert_enabled
+            "{}lighting_left_on_alert_enabled={}",
+            sep, self.lighting_left_on_alert_enabled
         )?;
-        writeln!(f, " alt_gen_enabled={}", self.alt_gen_enabled)?;
-        writeln!(
+        write!(f, "{}alt_gen_enabled={}", sep, self.alt_gen_enabled)?;
+        write!(
             f,
-            " esp_in_regulation_alert_enabled={}",
-            self.esp_in_regulation_alert_enabled
+            "{}esp_in_regulation_alert_enabled={}",
+            sep, self.esp_in_regulation_alert_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " auto_mirrors_folding_enabled={}",
-            self.auto_mirrors_folding_enabled
+            "{}auto_mirrors_folding_enabled={}",
+            sep, self.auto_mirrors_folding_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_wiper_in_reverse_gear_enabled={}",
-            self.rear_wiper_in_reverse_gear_enabled
+            "{}rear_wiper_in_reverse_gear_enabled={}",
+            sep, self.rear_wiper_in_reverse_gear_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " mirrors_tilting_in_reverse_gear_enabled={}",
-            self.mirrors_tilting_in_reverse_gear_enabled
+            "{}mirrors_tilting_in_reverse_gear_enabled={}",
+            sep, self.mirrors_tilting_in_reverse_gear_enabled
         )?;
-        writeln!(f, " park_sensors_status={}", self.park_sensors_status)?;
-        writeln!(
+        write!(f, "{}park_sensors_status={}", sep, self.park_sensors_status)?;
+        write!(
             f,
-            " blind_spot_monitoring_status={}",
-            self.blind_spot_monitoring_status
+            "{}blind_spot_monitoring_status={}",
+            sep, self.blind_spot_monitoring_status
         )?;
-        writeln!(f, " secu_enabled={}", self.secu_enabled)?;
-        writeln!(f, " configurable_key_mode={}", self.configurable_key_mode)
+        write!(f, "{}secu_enabled={}", sep, self.secu_enabled)?;
+        write!(
+            f,
+            "{}configurable_key_mode={}",
+            sep, self.configurable_key_mode
+        )
     }
 }
 
@@ -1,9 +1,10 @@
 use core::{cmp::Ordering, fmt};
 
-use byteorder::{ByteOrder, NetworkEndian};
-
 use crate::{
+    field::{read_be, write_be},
+    frame::{field_separator, TransmissionKind},
     mfd::{TripComputerPage, UserAction2004},
+    signal::Signal,
     Error, Result,
 };
 
@@ -49,6 +50,9 @@ pub const FRAME_ID: u16 = 0x167;
 /// Length of a x167 CAN frame.
 pub const FRAME_LEN: usize = field::VALUE + 1;
 
+/// Transmission of a x167 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnRequest;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -178,14 +182,14 @@ impl<T: AsRef<[u8]>> Frame<T> {
     #[inline]
     pub fn total_trip_distance(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::TOTAL_TRIP_DISTANCE])
+        read_be(data, field::TOTAL_TRIP_DISTANCE.start, 7, 16) as u16
     }
 
     /// Return the interactive message field.
     #[inline]
     pub fn interactive_message(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::INTERACTIVE_MSG]) & 0x7fff
+        read_be(data, field::INTERACTIVE_MSG.start, 6, 15) as u16
     }
 
     /// Return the stop and start push button state flag.
@@ -330,14 +334,14 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_total_trip_distance(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::TOTAL_TRIP_DISTANCE], value);
+        write_be(data, field::TOTAL_TRIP_DISTANCE.start, 7, 16, value.into());
     }
 
     /// Set the interactive message field.
     #[inline]
     pub fn set_interactive_message(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::INTERACTIVE_MSG], value & 0x7fff);
+        write_be(data, field::INTERACTIVE_MSG.start, 6, 15, value.into());
     }
 
     /// Set the stop and start push button state flag.
@@ -371,8 +375,8 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_user_action_on_mfd(&mut self, value: UserAction2004) {
         let data = self.buffer.as_mut();
-        let raw = data[field::PUSHS_ACTION] & !0x80;
-        let raw = raw | ((u8::from(value) & 0x80) << 4);
+        let raw = data[field::PUSHS_ACTION] & !0xf0;
+        let raw = raw | ((u8::from(value) & 0x0f) << 4);
         data[field::PUSHS_ACTION] = raw;
     }
 
@@ -384,10 +388,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x167 ({})", err)?;
                 Ok(())
@@ -402,9 +406,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x167 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub mfd_trip_computer_page: TripComputerPage,
     pub maintenance_reset_request: bool,
@@ -430,7 +440,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             mfd_trip_computer_page: frame.mfd_trip_computer_page(),
             maintenance_reset_request: frame.maintenance_reset_request(),
             emergency_call_in_progress: frame.emergency_call_in_progress(),
@@ -455,7 +470,7 @@ impl Repr {
             parking_sensors_button_state: frame.parking_sensors_button_state(),
             user_action_on_mfd: frame.user_action_on_mfd(),
             user_value: frame.user_value(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -475,14 +490,19 @@ impl Repr {
         frame.set_trip_computer_primary_trip_reset_request(
             self.trip_computer_primary_trip_reset_request,
         );
-        frame.set_pre_conditioning_time(self.pre_conditioning_time * 5);
+        // The field only holds its 0x08 bit, parsed as 8 / 5 = 1.
+        frame.set_pre_conditioning_time(if self.pre_conditioning_time != 0 {
+            0x08
+        } else {
+            0x00
+        });
         frame.set_telematics_enabled(self.telematics_enabled);
         frame.set_black_panel_enabled(self.black_panel_enabled);
         frame.set_indirect_under_inflation_reset_request(
             self.indirect_under_inflation_reset_request,
         );
         frame.set_pre_conditioning_request(self.pre_conditioning_request);
-        frame.set_total_trip_distance(if self.total_trip_distance > 0x7fff {
+        frame.set_total_trip_distance(if self.total_trip_distance == 0xffff {
             0xffff
         } else {
             self.total_trip_distance / 2
@@ -496,66 +516,165 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x08, 0x00, 0xff, 0xff, 0x7f, 0xff, 0x00, 0x00],
+    parked: [0x08, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00],
+    with_mfd_trip_computer_page => mfd_trip_computer_page: TripComputerPage,
+    with_maintenance_reset_request => maintenance_reset_request: bool,
+    with_emergency_call_in_progress => emergency_call_in_progress: bool,
+    with_fault_recall_request => fault_recall_request: bool,
+    with_trip_computer_secondary_trip_reset_request => trip_computer_secondary_trip_reset_request: bool,
+    with_trip_computer_primary_trip_reset_request => trip_computer_primary_trip_reset_request: bool,
+    with_pre_conditioning_time => pre_conditioning_time: u8,
+    with_telematics_enabled => telematics_enabled: bool,
+    with_black_panel_enabled => black_panel_enabled: bool,
+    with_indirect_under_inflation_reset_request => indirect_under_inflation_reset_request: bool,
+    with_pre_conditioning_request => pre_conditioning_request: bool,
+    with_total_trip_distance => total_trip_distance: u16,
+    with_interactive_message => interactive_message: u16,
+    with_stop_and_start_button_state => stop_and_start_button_state: bool,
+    with_lane_centering_button_state => lane_centering_button_state: bool,
+    with_parking_sensors_button_state => parking_sensors_button_state: bool,
+    with_user_action_on_mfd => user_action_on_mfd: UserAction2004,
+    with_user_value => user_value: u8,
+}
+
+/// Signals of a x167 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("mfd_trip_computer_page", 0, 2, 3).values(&[
+        (0, "Nothing"),
+        (1, "GeneralParameters"),
+        (2, "Trip1"),
+        (4, "Trip2"),
+        (5, "Page4"),
+        (7, "NotManagedByMFD"),
+    ]),
+    Signal::new("maintenance_reset_request", 0, 3, 1).values(&[(0, "true"), (1, "false")]),
+    Signal::new("emergency_call_in_progress", 0, 4, 1),
+    Signal::new("fault_recall_request", 0, 5, 1),
+    Signal::new("trip_computer_secondary_trip_reset_request", 0, 6, 1),
+    Signal::new("trip_computer_primary_trip_reset_request", 0, 7, 1),
+    Signal::new("pre_conditioning_time", 1, 3, 1),
+    Signal::new("telematics_enabled", 1, 4, 1),
+    Signal::new("black_panel_enabled", 1, 5, 1),
+    Signal::new("indirect_under_inflation_reset_request", 1, 6, 1),
+    Signal::new("pre_conditioning_request", 1, 7, 1),
+    Signal::new("total_trip_distance", 2, 7, 16)
+        .scaled(2.0, 0.0)
+        .unit("km"),
+    Signal::new("interactive_message", 4, 6, 15),
+    Signal::new("stop_and_start_button_state", 6, 0, 1),
+    Signal::new("lane_centering_button_state", 6, 1, 1),
+    Signal::new("parking_sensors_button_state", 6, 2, 1),
+    Signal::new("user_action_on_mfd", 6, 7, 4).values(&[
+        (0, "NoAction"),
+        (1, "Yes"),
+        (2, "No"),
+        (4, "Esc"),
+        (5, "ValueReturn"),
+        (15, "Timeout"),
+    ]),
+    Signal::new("user_value", 7, 7, 8),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x167")?;
+        write!(
             f,
-            "x167 mfd trip computer page={}",
-            self.mfd_trip_computer_page
+            "{}mfd_trip_computer_page={}",
+            sep, self.mfd_trip_computer_page
         )?;
-        writeln!(
+        write!(
             f,
-            " maintenance reset request={}",
-            self.maintenance_reset_request
+            "{}maintenance_reset_request={}",
+            sep, self.maintenance_reset_request
         )?;
-        writeln!(
+        write!(
             f,
-            " emergency call in progress={}",
-            self.emergency_call_in_progress
+            "{}emergency_call_in_progress={}",
+            sep, self.emergency_call_in_progress
         )?;
-        writeln!(f, "fault recall request={}", self.fault_recall_request)?;
-        writeln!(
+        write!(
             f,
-            " trip computer secondary trip reset_request={}",
-            self.trip_computer_secondary_trip_reset_request
+            "{}fault_recall_request={}",
+            sep, self.fault_recall_request
         )?;
-        writeln!(
+        write!(
             f,
-            " trip computer primary trip reset_request={}",
-            self.trip_computer_primary_trip_reset_request
+            "{}trip_computer_secondary_trip_reset_request={}",
+            sep, self.trip_computer_secondary_trip_reset_request
         )?;
-        writeln!(f, " preconditioning time={}", self.pre_conditioning_time)?;
-        writeln!(f, " telematics enabled={}", self.telematics_enabled)?;
-        writeln!(f, " black panel enabled={}", self.black_panel_enabled)?;
-        writeln!(
+        write!(
             f,
-            " indirect under inflation reset_request={}",
-            self.indirect_under_inflation_reset_request
+            "{}trip_computer_primary_trip_reset_request={}",
+            sep, self.trip_computer_primary_trip_reset_request
         )?;
-        writeln!(
+        write!(
             f,
-            " pre conditioning request={}",
-            self.pre_conditioning_request
+            "{}pre_conditioning_time={}",
+            sep, self.pre_conditioning_time
         )?;
-        writeln!(f, " total trip distance={}", self.total_trip_distance)?;
-        writeln!(f, " interactive message={}", self.interactive_message)?;
-        writeln!(
+        write!(f, "{}telematics_enabled={}", sep, self.telematics_enabled)?;
+        write!(f, "{}black_panel_enabled={}", sep, self.black_panel_enabled)?;
+        write!(
             f,
-            " stop and start button state={}",
-            self.stop_and_start_button_state
+            "{}indirect_under_inflation_reset_request={}",
+            sep, self.indirect_under_inflation_reset_request
         )?;
-        writeln!(
+        write!(
             f,
-            " lane centering button state={}",
-            self.lane_centering_button_state
+            "{}pre_conditioning_request={}",
+            sep, self.pre_conditioning_request
         )?;
-        writeln!(
+        write!(f, "{}total_trip_distance={}", sep, self.total_trip_distance)?;
+        write!(f, "{}interactive_message={}", sep, self.interactive_message)?;
+        write!(
             f,
-            " parking sensors button state={}",
-            self.parking_sensors_button_state
+            "{}stop_and_start_button_state={}",
+            sep, self.stop_and_start_button_state
         )?;
-        writeln!(f, " user_action on mfd={}", self.user_action_on_mfd)?;
-        writeln!(f, " user value={}", self.user_value)
+        write!(
+            f,
+            "{}lane_centering_button_state={}",
+            sep, self.lane_centering_button_state
+        )?;
+        write!(
+            f,
+            "{}parking_sensors_button_state={}",
+            sep, self.parking_sensors_button_state
+        )?;
+        write!(f, "{}user_action_on_mfd={}", sep, self.user_action_on_mfd)?;
+        write!(f, "{}user_value={}", sep, self.user_value)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x167::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x167::Repr) -> Self {
+        Repr {
+            mfd_trip_computer_page: repr_2010.mfd_trip_computer_page,
+            maintenance_reset_request: repr_2010.maintenance_reset_request,
+            emergency_call_in_progress: repr_2010.emergency_call_in_progress,
+            fault_recall_request: repr_2010.fault_recall_request,
+            trip_computer_secondary_trip_reset_request: repr_2010
+                .trip_computer_secondary_trip_reset_request,
+            trip_computer_primary_trip_reset_request: repr_2010
+                .trip_computer_primary_trip_reset_request,
+            pre_conditioning_time: repr_2010.pre_conditioning_time,
+            telematics_enabled: repr_2010.telematics_enabled,
+            black_panel_enabled: repr_2010.black_panel_enabled,
+            indirect_under_inflation_reset_request: repr_2010
+                .indirect_under_inflation_reset_request,
+            pre_conditioning_request: repr_2010.pre_conditioning_request,
+            total_trip_distance: repr_2010.total_trip_distance,
+            interactive_message: repr_2010.interactive_message,
+            stop_and_start_button_state: false, // No equivalent on AEE2010.
+            lane_centering_button_state: false, // No equivalent on AEE2010.
+            parking_sensors_button_state: false, // No equivalent on AEE2010.
+            user_action_on_mfd: repr_2010.user_action_on_mfd.into(),
+            user_value: 0, // No equivalent on AEE2010.
+        }
     }
 }
 
@@ -563,7 +682,7 @@ impl fmt::Display for Repr {
 mod test {
     use super::{Frame, Repr};
     use crate::{
-        mfd::{TripComputerPage, UserAction2004},
+        mfd::{TripComputerPage, UserAction2004, UserAction2010},
         Error,
     };
 
@@ -718,6 +837,38 @@ mod test {
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
 
+    #[test]
+    fn test_total_trip_distance() {
+        let mut buf = [0u8; 8];
+        let repr = Repr {
+            total_trip_distance: 40000,
+            ..frame_1_repr()
+        };
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        let frame = Frame::new_unchecked(&buf);
+        assert_eq!(frame.total_trip_distance(), 20000);
+        assert_eq!(Repr::parse(&frame), Ok(repr));
+
+        // Raw values above 0x7fff are all decoded as unavailable.
+        Frame::new_unchecked(&mut buf).set_total_trip_distance(0x8000);
+        let repr = Repr::parse(&Frame::new_unchecked(&buf)).unwrap();
+        assert_eq!(repr.total_trip_distance, 0xffff);
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        assert_eq!(Frame::new_unchecked(&buf).total_trip_distance(), 0xffff);
+    }
+
+    #[test]
+    fn test_pre_conditioning_time() {
+        let mut buf = [0u8; 8];
+        Frame::new_unchecked(&mut buf).set_pre_conditioning_time(0x08);
+        let repr = Repr::parse(&Frame::new_unchecked(&buf)).unwrap();
+        assert_eq!(repr.pre_conditioning_time, 1);
+
+        let mut buf = [0u8; 8];
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        assert_eq!(Frame::new_unchecked(&buf).pre_conditioning_time(), 0x08);
+    }
+
     #[test]
     fn test_overlong() {
         let bytes: [u8; 9] = [0x08, 0x00, 0x00, 0x00, 0x7f, 0xff, 0x00, 0x00, 0xff];
@@ -764,4 +915,14 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2010() {
+        let repr_2010 = crate::aee2010::infodiv::x167::Repr::builder()
+            .with_user_action_on_mfd(UserAction2010::Dismiss)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2010);
+        assert_eq!(repr.user_action_on_mfd, UserAction2004::Esc);
+    }
 }
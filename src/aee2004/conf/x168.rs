@@ -3,6 +3,8 @@ use core::{cmp::Ordering, fmt};
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{AdBlueIndicatorState, GearboxDriveModeGear, IndicatorState},
     Error, Result,
 };
@@ -82,6 +84,9 @@ pub const FRAME_ID: u16 = 0x168;
 /// Length of a x168 CAN frame.
 pub const FRAME_LEN: usize = field::FLAGS_8 + 1;
 
+/// Transmission of a x168 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -173,7 +178,7 @@ impl<T: AsRef<[u8]>> Frame<T> {
     pub fn gearbox_drive_mode_gear(&self) -> GearboxDriveModeGear {
         let data = self.buffer.as_ref();
         let raw = NetworkEndian::read_u16(&data[field::FLAGS_5_6]);
-        let raw = (raw & 0x01e8) >> 5;
+        let raw = (raw & 0x01e0) >> 5;
         GearboxDriveModeGear::from(raw as u8)
     }
 
@@ -287,9 +292,9 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_gearbox_drive_mode_gear(&mut self, value: GearboxDriveModeGear) {
         let data = self.buffer.as_mut();
-        let raw = NetworkEndian::read_u16(&data[field::FLAGS_5_6]) & !0x01e8;
+        let raw = NetworkEndian::read_u16(&data[field::FLAGS_5_6]) & !0x01e0;
         let value = (u8::from(value) as u16) << 5;
-        let raw = raw | (value & 0x01e8);
+        let raw = raw | (value & 0x01e0);
         NetworkEndian::write_u16(&mut data[field::FLAGS_5_6], raw);
     }
 
@@ -358,10 +363,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x168 ({})", err)?;
                 Ok(())
@@ -376,9 +381,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x168 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub under_inflation_failure: bool,
     pub cold_engine_alert: bool,
@@ -433,7 +444,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             under_inflation_failure: frame.read_bit::<{ field::FLAGS_1 }, 0>(),
             cold_engine_alert: frame.read_bit::<{ field::FLAGS_1 }, 1>(),
             low_brake_fluid_level_alert: frame.read_bit::<{ field::FLAGS_1 }, 2>(),
@@ -481,7 +497,7 @@ impl Repr {
             zev_indicator: frame.zev_indicator(),
             obd_code_readiness: frame.read_bit::<{ field::FLAGS_8 }, 6>(),
             fuse_fault: frame.read_bit::<{ field::FLAGS_8 }, 7>(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -541,148 +557,352 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x55, 0x55, 0x55, 0x55, 0x93, 0x11, 0x16, 0x80],
+    with_under_inflation_failure => under_inflation_failure: bool,
+    with_cold_engine_alert => cold_engine_alert: bool,
+    with_low_brake_fluid_level_alert => low_brake_fluid_level_alert: bool,
+    with_low_oil_pressure_alert => low_oil_pressure_alert: bool,
+    with_low_oil_level_alert => low_oil_level_alert: bool,
+    with_low_coolant_level_alert => low_coolant_level_alert: bool,
+    with_oil_temperature_alert => oil_temperature_alert: bool,
+    with_coolant_temperature_alert => coolant_temperature_alert: bool,
+    with_max_engine_rpm_level2_indicator => max_engine_rpm_level2_indicator: bool,
+    with_low_fuel_level_alert => low_fuel_level_alert: bool,
+    with_max_engine_rpm_level1_indicator => max_engine_rpm_level1_indicator: bool,
+    with_automatic_wipers_enabled => automatic_wipers_enabled: bool,
+    with_particulate_filter_indicator => particulate_filter_indicator: bool,
+    with_automatic_stop_indicator => automatic_stop_indicator: bool,
+    with_tyre_puncture_alert => tyre_puncture_alert: bool,
+    with_under_inflation_alert_flag => under_inflation_alert_flag: bool,
+    with_foot_on_clutch_pedal_indicator => foot_on_clutch_pedal_indicator: IndicatorState,
+    with_rear_right_seat_belt_indicator_blinking => rear_right_seat_belt_indicator_blinking: bool,
+    with_rear_right_seat_belt_indicator => rear_right_seat_belt_indicator: bool,
+    with_rear_middle_seat_belt_indicator_blinking => rear_middle_seat_belt_indicator_blinking: bool,
+    with_rear_middle_seat_belt_indicator => rear_middle_seat_belt_indicator: bool,
+    with_rear_left_seat_belt_indicator_blinking => rear_left_seat_belt_indicator_blinking: bool,
+    with_rear_left_seat_belt_indicator => rear_left_seat_belt_indicator: bool,
+    with_water_in_diesel => water_in_diesel: bool,
+    with_obd_fault => obd_fault: bool,
+    with_worn_brake_pad_fault => worn_brake_pad_fault: bool,
+    with_gearbox_fault => gearbox_fault: bool,
+    with_esp_asr_fault => esp_asr_fault: bool,
+    with_abs_fault => abs_fault: bool,
+    with_suspension_fault => suspension_fault: bool,
+    with_ebd_fault => ebd_fault: bool,
+    with_engine_fault => engine_fault: bool,
+    with_turn_lights_fault => turn_lights_fault: bool,
+    with_automatic_levelling_indicator => automatic_levelling_indicator: IndicatorState,
+    with_gearbox_drive_mode_gear => gearbox_drive_mode_gear: GearboxDriveModeGear,
+    with_electrical_generator_fault => electrical_generator_fault: bool,
+    with_battery_charge_fault => battery_charge_fault: bool,
+    with_anti_emission_fault => anti_emission_fault: bool,
+    with_passive_safety_fault => passive_safety_fault: bool,
+    with_adblue_indicator => adblue_indicator: AdBlueIndicatorState,
+    with_stop_start_indicator => stop_start_indicator: IndicatorState,
+    with_engine_fault_indicator_blinking => engine_fault_indicator_blinking: bool,
+    with_electrical_parking_brake_fault => electrical_parking_brake_fault: bool,
+    with_steering_assistance_fault => steering_assistance_fault: bool,
+    with_zev_indicator => zev_indicator: IndicatorState,
+    with_obd_code_readiness => obd_code_readiness: bool,
+    with_fuse_fault => fuse_fault: bool,
+}
+
+/// Signals of a x168 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("under_inflation_failure", 0, 0, 1),
+    Signal::new("cold_engine_alert", 0, 1, 1),
+    Signal::new("low_brake_fluid_level_alert", 0, 2, 1),
+    Signal::new("low_oil_pressure_alert", 0, 3, 1),
+    Signal::new("low_oil_level_alert", 0, 4, 1),
+    Signal::new("low_coolant_level_alert", 0, 5, 1),
+    Signal::new("oil_temperature_alert", 0, 6, 1),
+    Signal::new("coolant_temperature_alert", 0, 7, 1),
+    Signal::new("max_engine_rpm_level2_indicator", 1, 0, 1),
+    Signal::new("low_fuel_level_alert", 1, 1, 1),
+    Signal::new("max_engine_rpm_level1_indicator", 1, 2, 1),
+    Signal::new("automatic_wipers_enabled", 1, 3, 1),
+    Signal::new("particulate_filter_indicator", 1, 4, 1),
+    Signal::new("automatic_stop_indicator", 1, 5, 1),
+    Signal::new("tyre_puncture_alert", 1, 6, 1),
+    Signal::new("under_inflation_alert_flag", 1, 7, 1),
+    Signal::new("foot_on_clutch_pedal_indicator", 2, 1, 2).values(&[
+        (0, "Off"),
+        (1, "On"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("rear_right_seat_belt_indicator_blinking", 2, 2, 1),
+    Signal::new("rear_right_seat_belt_indicator", 2, 3, 1),
+    Signal::new("rear_middle_seat_belt_indicator_blinking", 2, 4, 1),
+    Signal::new("rear_middle_seat_belt_indicator", 2, 5, 1),
+    Signal::new("rear_left_seat_belt_indicator_blinking", 2, 6, 1),
+    Signal::new("rear_left_seat_belt_indicator", 2, 7, 1),
+    Signal::new("water_in_diesel", 3, 0, 1),
+    Signal::new("obd_fault", 3, 1, 1),
+    Signal::new("worn_brake_pad_fault", 3, 2, 1),
+    Signal::new("gearbox_fault", 3, 3, 1),
+    Signal::new("esp_asr_fault", 3, 4, 1),
+    Signal::new("abs_fault", 3, 5, 1),
+    Signal::new("suspension_fault", 3, 6, 1),
+    Signal::new("ebd_fault", 3, 7, 1),
+    Signal::new("engine_fault", 5, 0, 1),
+    Signal::new("turn_lights_fault", 5, 2, 1),
+    Signal::new("automatic_levelling_indicator", 5, 4, 2).values(&[
+        (0, "Off"),
+        (1, "On"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("gearbox_drive_mode_gear", 4, 0, 4).values(&[
+        (0, "Disengaged"),
+        (1, "Gear1"),
+        (2, "Gear2"),
+        (3, "Gear3"),
+        (4, "Gear4"),
+        (5, "Gear5"),
+        (6, "Gear6"),
+        (7, "Gear7"),
+        (8, "Gear8"),
+        (9, "Gear9"),
+    ]),
+    Signal::new("electrical_generator_fault", 4, 1, 1),
+    Signal::new("battery_charge_fault", 4, 2, 1),
+    Signal::new("anti_emission_fault", 4, 4, 1),
+    Signal::new("passive_safety_fault", 4, 5, 1),
+    Signal::new("adblue_indicator", 4, 7, 2).values(&[(0, "Off"), (1, "Blinking"), (2, "On")]),
+    Signal::new("stop_start_indicator", 6, 1, 2).values(&[(0, "Off"), (1, "On"), (2, "Blinking")]),
+    Signal::new("engine_fault_indicator_blinking", 6, 2, 1),
+    Signal::new("electrical_parking_brake_fault", 6, 3, 1),
+    Signal::new("steering_assistance_fault", 6, 4, 1),
+    Signal::new("zev_indicator", 7, 3, 2).values(&[(0, "Off"), (1, "On"), (2, "Blinking")]),
+    Signal::new("obd_code_readiness", 7, 6, 1),
+    Signal::new("fuse_fault", 7, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x168")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x168")?;
+        write!(
+            f,
+            "{}under_inflation_failure={}",
+            sep, self.under_inflation_failure
+        )?;
+        write!(f, "{}cold_engine_alert={}", sep, self.cold_engine_alert)?;
+        write!(
+            f,
+            "{}low_brake_fluid_level_alert={}",
+            sep, self.low_brake_fluid_level_alert
+        )?;
+        write!(
+            f,
+            "{}low_oil_pressure_alert={}",
+            sep, self.low_oil_pressure_alert
+        )?;
+        write!(f, "{}low_oil_level_alert={}", sep, self.low_oil_level_alert)?;
+        write!(
+            f,
+            "{}low_coolant_level_alert={}",
+            sep, self.low_coolant_level_alert
+        )?;
+        write!(
             f,
-            " under_inflation_failure={}",
-            self.under_inflation_failure
+            "{}oil_temperature_alert={}",
+            sep, self.oil_temperature_alert
         )?;
-        writeln!(f, " cold_engine_alert={}", self.cold_engine_alert)?;
-        writeln!(
+        write!(
             f,
-            " low_brake_fluid_level_alert={}",
-            self.low_brake_fluid_level_alert
+            "{}coolant_temperature_alert={}",
+            sep, self.coolant_temperature_alert
         )?;
-        writeln!(f, " low_oil_pressure_alert={}", self.low_oil_pressure_alert)?;
-        writeln!(f, " low_oil_level_alert={}", self.low_oil_level_alert)?;
-        writeln!(
+        write!(
             f,
-            " low_coolant_level_alert={}",
-            self.low_coolant_level_alert
+            "{}max_engine_rpm_level2_indicator={}",
+            sep, self.max_engine_rpm_level2_indicator
         )?;
-        writeln!(f, " oil_temperature_alert={}", self.oil_temperature_alert)?;
-        writeln!(
+        write!(
             f,
-            " coolant_temperature_alert={}",
-            self.coolant_temperature_alert
+            "{}low_fuel_level_alert={}",
+            sep, self.low_fuel_level_alert
         )?;
-        writeln!(
+        write!(
             f,
-            " max_engine_rpm_level2_indicator={}",
-            self.max_engine_rpm_level2_indicator
+            "{}max_engine_rpm_level1_indicator={}",
+            sep, self.max_engine_rpm_level1_indicator
         )?;
-        writeln!(f, " low_fuel_level_alert={}", self.low_fuel_level_alert)?;
-        writeln!(
+        write!(
             f,
-            " max_engine_rpm_level1_indicator={}",
-            self.max_engine_rpm_level1_indicator
+            "{}automatic_wipers_enabled={}",
+            sep, self.automatic_wipers_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_wipers_enabled={}",
-            self.automatic_wipers_enabled
+            "{}particulate_filter_indicator={}",
+            sep, self.particulate_filter_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " particulate_filter_indicator={}",
-            self.particulate_filter_indicator
+            "{}automatic_stop_indicator={}",
+            sep, self.automatic_stop_indicator
         )?;
-        writeln!(
+        write!(f, "{}tyre_puncture_alert={}", sep, self.tyre_puncture_alert)?;
+        write!(
             f,
-            " automatic_stop_indicator={}",
-            self.automatic_stop_indicator
+            "{}under_inflation_alert_flag={}",
+            sep, self.under_inflation_alert_flag
         )?;
-        writeln!(f, " tyre_puncture_alert={}", self.tyre_puncture_alert)?;
-        writeln!(
+        write!(
             f,
-            " under_inflation_alert_flag={}",
-            self.under_inflation_alert_flag
+            "{}foot_on_clutch_pedal_indicator={}",
+            sep, self.foot_on_clutch_pedal_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " foot_on_clutch_pedal_indicator={}",
-            self.foot_on_clutch_pedal_indicator
+            "{}rear_right_seat_belt_indicator_blinking={}",
+            sep, self.rear_right_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_seat_belt_indicator_blinking={}",
-            self.rear_right_seat_belt_indicator_blinking
+            "{}rear_right_seat_belt_indicator={}",
+            sep, self.rear_right_seat_belt_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_seat_belt_indicator={}",
-            self.rear_right_seat_belt_indicator
+            "{}rear_middle_seat_belt_indicator_blinking={}",
+            sep, self.rear_middle_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_middle_seat_belt_indicator_blinking={}",
-            self.rear_middle_seat_belt_indicator_blinking
+            "{}rear_middle_seat_belt_indicator={}",
+            sep, self.rear_middle_seat_belt_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_middle_seat_belt_indicator={}",
-            self.rear_middle_seat_belt_indicator
+            "{}rear_left_seat_belt_indicator_blinking={}",
+            sep, self.rear_left_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_left_seat_belt_indicator_blinking={}",
-            self.rear_left_seat_belt_indicator_blinking
+            "{}rear_left_seat_belt_indicator={}",
+            sep, self.rear_left_seat_belt_indicator
         )?;
-        writeln!(
+        write!(f, "{}water_in_diesel={}", sep, self.water_in_diesel)?;
+        write!(f, "{}obd_fault={}", sep, self.obd_fault)?;
+        write!(
             f,
-            " rear_left_seat_belt_indicator={}",
-            self.rear_left_seat_belt_indicator
+            "{}worn_brake_pad_fault={}",
+            sep, self.worn_brake_pad_fault
         )?;
-        writeln!(f, " water_in_diesel={}", self.water_in_diesel)?;
-        writeln!(f, " obd_fault={}", self.obd_fault)?;
-        writeln!(f, " worn_brake_pad_fault={}", self.worn_brake_pad_fault)?;
-        writeln!(f, " gearbox_fault={}", self.gearbox_fault)?;
-        writeln!(f, " esp_asr_fault={}", self.esp_asr_fault)?;
-        writeln!(f, " abs_fault={}", self.abs_fault)?;
-        writeln!(f, " suspension_fault={}", self.suspension_fault)?;
-        writeln!(f, " ebd_fault={}", self.ebd_fault)?;
-        writeln!(f, " engine_fault={}", self.engine_fault)?;
-        writeln!(f, " turn_lights_fault={}", self.turn_lights_fault)?;
-        writeln!(
+        write!(f, "{}gearbox_fault={}", sep, self.gearbox_fault)?;
+        write!(f, "{}esp_asr_fault={}", sep, self.esp_asr_fault)?;
+        write!(f, "{}abs_fault={}", sep, self.abs_fault)?;
+        write!(f, "{}suspension_fault={}", sep, self.suspension_fault)?;
+        write!(f, "{}ebd_fault={}", sep, self.ebd_fault)?;
+        write!(f, "{}engine_fault={}", sep, self.engine_fault)?;
+        write!(f, "{}turn_lights_fault={}", sep, self.turn_lights_fault)?;
+        write!(
             f,
-            " automatic_levelling_indicator={}",
-            self.automatic_levelling_indicator
+            "{}automatic_levelling_indicator={}",
+            sep, self.automatic_levelling_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " gearbox_drive_mode_gear={}",
-            self.gearbox_drive_mode_gear
+            "{}gearbox_drive_mode_gear={}",
+            sep, self.gearbox_drive_mode_gear
         )?;
-        writeln!(
+        write!(
             f,
-            " electrical_generator_fault={}",
-            self.electrical_generator_fault
+            "{}electrical_generator_fault={}",
+            sep, self.electrical_generator_fault
         )?;
-        writeln!(f, " battery_charge_fault={}", self.battery_charge_fault)?;
-        writeln!(f, " anti_emission_fault={}", self.anti_emission_fault)?;
-        writeln!(f, " passive_safety_fault={}", self.passive_safety_fault)?;
-        writeln!(f, " adblue_indicator={}", self.adblue_indicator)?;
-        writeln!(f, " stop_start_indicator={}", self.stop_start_indicator)?;
-        writeln!(
+        write!(
             f,
-            " engine_fault_indicator_blinking={}",
-            self.engine_fault_indicator_blinking
+            "{}battery_charge_fault={}",
+            sep, self.battery_charge_fault
         )?;
-        writeln!(
+        write!(f, "{}anti_emission_fault={}", sep, self.anti_emission_fault)?;
+        write!(
             f,
-            " electrical_parking_brake_fault={}",
-            self.electrical_parking_brake_fault
+            "{}passive_safety_fault={}",
+            sep, self.passive_safety_fault
         )?;
-        writeln!(
+        write!(f, "{}adblue_indicator={}", sep, self.adblue_indicator)?;
+        write!(
             f,
-            " steering_assistance_fault={}",
-            self.steering_assistance_fault
+            "{}stop_start_indicator={}",
+            sep, self.stop_start_indicator
         )?;
-        writeln!(f, " zev_indicator={}", self.zev_indicator)?;
-        writeln!(f, " obd_code_readiness={}", self.obd_code_readiness)?;
-        writeln!(f, " fuse_fault={}", self.fuse_fault)
+        write!(
+            f,
+            "{}engine_fault_indicator_blinking={}",
+            sep, self.engine_fault_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}electrical_parking_brake_fault={}",
+            sep, self.electrical_parking_brake_fault
+        )?;
+        write!(
+            f,
+            "{}steering_assistance_fault={}",
+            sep, self.steering_assistance_fault
+        )?;
+        write!(f, "{}zev_indicator={}", sep, self.zev_indicator)?;
+        write!(f, "{}obd_code_readiness={}", sep, self.obd_code_readiness)?;
+        write!(f, "{}fuse_fault={}", sep, self.fuse_fault)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x168::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x168::Repr) -> Self {
+        Repr {
+            under_inflation_failure: repr_2010.under_inflation_failure,
+            cold_engine_alert: repr_2010.cold_engine_alert,
+            low_brake_fluid_level_alert: repr_2010.low_brake_fluid_level_alert,
+            low_oil_pressure_alert: repr_2010.low_oil_pressure_alert,
+            low_oil_level_alert: repr_2010.low_oil_level_alert,
+            low_coolant_level_alert: repr_2010.low_coolant_level_alert,
+            oil_temperature_alert: false, // No equivalent on AEE2010.
+            coolant_temperature_alert: repr_2010.coolant_temperature_alert,
+            max_engine_rpm_level2_indicator: false, // No equivalent on AEE2010.
+            low_fuel_level_alert: false,            // No equivalent on AEE2010.
+            max_engine_rpm_level1_indicator: false, // No equivalent on AEE2010.
+            automatic_wipers_enabled: repr_2010.automatic_wipers_enabled,
+            particulate_filter_indicator: repr_2010.particulate_filter_indicator,
+            automatic_stop_indicator: false, // No equivalent on AEE2010.
+            tyre_puncture_alert: repr_2010.tyre_puncture_alert,
+            under_inflation_alert_flag: repr_2010.under_inflation_alert_flag,
+            foot_on_clutch_pedal_indicator: IndicatorState::Off, // No equivalent on AEE2010.
+            rear_right_seat_belt_indicator_blinking: false,      // No equivalent on AEE2010.
+            rear_right_seat_belt_indicator: false,               // No equivalent on AEE2010.
+            rear_middle_seat_belt_indicator_blinking: false,     // No equivalent on AEE2010.
+            rear_middle_seat_belt_indicator: false,              // No equivalent on AEE2010.
+            rear_left_seat_belt_indicator_blinking: false,       // No equivalent on AEE2010.
+            rear_left_seat_belt_indicator: false,                // No equivalent on AEE2010.
+            water_in_diesel: repr_2010.water_in_diesel,
+            obd_fault: repr_2010.obd_fault,
+            worn_brake_pad_fault: repr_2010.worn_brake_pad_fault,
+            gearbox_fault: repr_2010.gearbox_fault,
+            esp_asr_fault: repr_2010.esp_asr_fault,
+            abs_fault: repr_2010.abs_fault,
+            suspension_fault: false, // No equivalent on AEE2010.
+            ebd_fault: repr_2010.ebd_fault,
+            engine_fault: false, // No equivalent on AEE2010.
+            turn_lights_fault: repr_2010.turn_lights_fault,
+            automatic_levelling_indicator: IndicatorState::Off, // No equivalent on AEE2010.
+            gearbox_drive_mode_gear: repr_2010.gearbox_drive_mode_gear,
+            electrical_generator_fault: repr_2010.electrical_generator_fault,
+            battery_charge_fault: repr_2010.battery_charge_fault,
+            anti_emission_fault: repr_2010.anti_emission_fault,
+            passive_safety_fault: repr_2010.passive_safety_fault,
+            adblue_indicator: AdBlueIndicatorState::Off, // No equivalent on AEE2010.
+            stop_start_indicator: IndicatorState::Off,   // No equivalent on AEE2010.
+            engine_fault_indicator_blinking: false,      // No equivalent on AEE2010.
+            electrical_parking_brake_fault: false,       // No equivalent on AEE2010.
+            steering_assistance_fault: repr_2010.steering_assistance_fault,
+            zev_indicator: IndicatorState::Off, // No equivalent on AEE2010.
+            obd_code_readiness: false,          // No equivalent on AEE2010.
+            fuse_fault: false,                  // No equivalent on AEE2010.
+        }
     }
 }
 
@@ -1068,4 +1288,14 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_gearbox_drive_mode_gear_mask() {
+        let mut bytes = [0u8; 8];
+        let mut frame = Frame::new_unchecked(&mut bytes);
+        frame.set_automatic_levelling_indicator(IndicatorState::On);
+        frame.set_gearbox_drive_mode_gear(GearboxDriveModeGear::Gear3);
+        assert_eq!(frame.automatic_levelling_indicator(), IndicatorState::On);
+        assert_eq!(frame.gearbox_drive_mode_gear(), GearboxDriveModeGear::Gear3);
+    }
 }
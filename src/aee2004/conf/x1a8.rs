@@ -1,13 +1,18 @@
 use core::{cmp::Ordering, fmt};
 
-use byteorder::{ByteOrder, NetworkEndian};
-
 use crate::{
     config::SpeedUnit,
+    field::{read_be, write_be},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    units::KilometersPerHour,
     vehicle::{SpeedRegulationMode, SpeedRegulationModeState},
     Error, Result,
 };
 
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
+
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -36,6 +41,9 @@ pub const FRAME_ID: u16 = 0x1a8;
 /// Length of a x1a8 CAN frame.
 pub const FRAME_LEN: usize = field::ODOMETER.end;
 
+/// Transmission of a x1a8 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -117,14 +125,14 @@ impl<T: AsRef<[u8]>> Frame<T> {
     #[inline]
     pub fn speed_setting(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::SPD_INST])
+        read_be(data, field::SPD_INST.start, 7, 16) as u16
     }
 
     /// Return the partial odometer field, in 0.1 kilometers units.
     #[inline]
     pub fn partial_odometer(&self) -> u32 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u24(&data[field::ODOMETER])
+        read_be(data, field::ODOMETER.start, 7, 24) as u32
     }
 }
 
@@ -169,21 +177,21 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_speed_setting(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::SPD_INST], value);
+        write_be(data, field::SPD_INST.start, 7, 16, value.into());
     }
 
     /// Set the partial odometer field, in 0.1 kilometers units.
     #[inline]
     pub fn set_partial_odometer(&mut self, value: u32) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u24(&mut data[field::ODOMETER], value);
+        write_be(data, field::ODOMETER.start, 7, 24, value.into());
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1a8 ({})", err)?;
                 Ok(())
@@ -198,9 +206,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1a8 CAN frame.
 #[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub speed_unit: SpeedUnit,
     pub try_enable: bool,
@@ -208,7 +222,7 @@ pub struct Repr {
     pub speed_regulation_mode: SpeedRegulationMode,
     pub speed_setting: u16,
     #[cfg(feature = "float")]
-    pub partial_odometer: f32,
+    pub partial_odometer: Physical,
     #[cfg(not(feature = "float"))]
     pub partial_odometer: u32,
 }
@@ -217,17 +231,22 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             speed_unit: frame.speed_unit(),
             try_enable: frame.try_enable(),
             speed_regulation_mode_state: frame.speed_regulation_mode_state(),
             speed_regulation_mode: frame.speed_regulation_mode(),
             speed_setting: frame.speed_setting(),
             #[cfg(feature = "float")]
-            partial_odometer: (frame.partial_odometer() as f32 / 10.0),
+            partial_odometer: physical::from_raw(frame.partial_odometer().into(), 0, 10),
             #[cfg(not(feature = "float"))]
             partial_odometer: (frame.partial_odometer()),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -243,34 +262,102 @@ impl Repr {
         frame.set_speed_regulation_mode(self.speed_regulation_mode);
         frame.set_speed_setting(self.speed_setting);
         #[cfg(feature = "float")]
-        frame.set_partial_odometer((self.partial_odometer * 10.0) as u32);
+        frame.set_partial_odometer(physical::to_u32(self.partial_odometer, 0, 10));
         #[cfg(not(feature = "float"))]
         frame.set_partial_odometer(self.partial_odometer);
     }
+
+    /// Return the speed setting as a typed speed, the speed setting being expressed in
+    /// [speed_unit](Repr::speed_unit). Returns `None` for unknown units.
+    pub fn speed_setting_kph(&self) -> Option<KilometersPerHour> {
+        KilometersPerHour::from_unit(self.speed_setting, self.speed_unit)
+    }
 }
 
+repr_builder! {
+    idle: [0x44, 0x00, 0x82, 0x00, 0x00, 0x00, 0x19, 0x84],
+    with_speed_unit => speed_unit: SpeedUnit,
+    with_try_enable => try_enable: bool,
+    with_speed_regulation_mode_state => speed_regulation_mode_state: SpeedRegulationModeState,
+    with_speed_regulation_mode => speed_regulation_mode: SpeedRegulationMode,
+    with_speed_setting => speed_setting: u16,
+    #[cfg(feature = "float")]
+    with_partial_odometer => partial_odometer: Physical,
+    #[cfg(not(feature = "float"))]
+    with_partial_odometer => partial_odometer: u32,
+}
+
+/// Signals of a x1a8 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("speed_unit", 0, 1, 1).values(&[(0, "Kph"), (1, "Mph")]),
+    Signal::new("try_enable", 0, 2, 1),
+    Signal::new("speed_regulation_mode_state", 0, 5, 3).values(&[
+        (0, "Standby"),
+        (1, "Up"),
+        (2, "LimiterUpAndRunning"),
+        (3, "UpOverspeed"),
+        (4, "UpOverspeedFromDriver"),
+        (6, "ForbiddenMode"),
+        (7, "Failure"),
+    ]),
+    Signal::new("speed_regulation_mode", 0, 7, 2).values(&[
+        (0, "Off"),
+        (1, "CruiseControl"),
+        (2, "SpeedLimiter"),
+        (3, "AdaptiveCruiseControl"),
+    ]),
+    Signal::new("speed_setting", 1, 7, 16).unit("km/h"),
+    Signal::new("partial_odometer", 5, 7, 24)
+        .scaled(0.1, 0.0)
+        .unit("km"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sep = field_separator(f);
         write!(f, "x1a8")?;
-        write!(f, " speed_unit={}", self.speed_unit)?;
-        write!(f, " try_enable={}", self.try_enable)?;
+        write!(f, "{}speed_unit={}", sep, self.speed_unit)?;
+        write!(f, "{}try_enable={}", sep, self.try_enable)?;
         write!(
             f,
-            " speed_regulation_mode_state={}",
-            self.speed_regulation_mode_state
+            "{}speed_regulation_mode_state={}",
+            sep, self.speed_regulation_mode_state
         )?;
-        write!(f, " speed_regulation_mode={}", self.speed_regulation_mode)?;
-        write!(f, " speed_setting={}", self.speed_setting)?;
-        write!(f, " partial_odometer={}", self.partial_odometer)
+        write!(
+            f,
+            "{}speed_regulation_mode={}",
+            sep, self.speed_regulation_mode
+        )?;
+        write!(f, "{}speed_setting={}", sep, self.speed_setting)?;
+        write!(f, "{}partial_odometer={}", sep, self.partial_odometer)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x228::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x228::Repr) -> Self {
+        Repr {
+            speed_unit: SpeedUnit::Kph, // Speed setting is always in km/h on AEE2010.
+            try_enable: repr_2010.try_enable,
+            speed_regulation_mode_state: repr_2010.speed_regulation_mode_state,
+            speed_regulation_mode: repr_2010.speed_regulation_mode,
+            speed_setting: repr_2010.speed_setting,
+            #[cfg(feature = "float")]
+            partial_odometer: physical::from_raw(0, 0, 10), // Partial odometer is in x1a8 on AEE2010.
+            #[cfg(not(feature = "float"))]
+            partial_odometer: 0, // Partial odometer is in x1a8 on AEE2010.
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
 
     use crate::{
         config::SpeedUnit,
+        units::KilometersPerHour,
         vehicle::{SpeedRegulationMode, SpeedRegulationModeState},
         Error,
     };
@@ -285,7 +372,7 @@ mod test {
             speed_regulation_mode_state: SpeedRegulationModeState::Standby,
             speed_regulation_mode: SpeedRegulationMode::CruiseControl,
             speed_setting: 130,
-            partial_odometer: 653.2,
+            partial_odometer: physical::decimal(6532, 10),
         }
     }
 
@@ -296,7 +383,7 @@ mod test {
             speed_regulation_mode_state: SpeedRegulationModeState::LimiterUpAndRunning,
             speed_regulation_mode: SpeedRegulationMode::SpeedLimiter,
             speed_setting: 50,
-            partial_odometer: 325.4,
+            partial_odometer: physical::decimal(3254, 10),
         }
     }
 
@@ -394,6 +481,15 @@ mod test {
         assert_eq!(repr, frame_2_repr());
     }
 
+    #[test]
+    fn test_repr_padded_parse() {
+        let mut bytes = [0xcc; 12];
+        bytes[..8].copy_from_slice(&REPR_FRAME_BYTES_1);
+        let frame = Frame::new_padded(&bytes).unwrap();
+        assert_eq!(Repr::parse(&frame), Err(Error::Overlong));
+        assert_eq!(Repr::parse_padded(&frame), Ok(frame_1_repr()));
+    }
+
     #[test]
     fn test_basic_repr_1_emit() {
         let mut buf = [0u8; 8];
@@ -411,4 +507,33 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_speed_setting_kph() {
+        assert_eq!(
+            frame_1_repr().speed_setting_kph(),
+            Some(KilometersPerHour(130))
+        );
+        assert_eq!(
+            frame_2_repr().speed_setting_kph(),
+            Some(KilometersPerHour(80))
+        );
+    }
+
+    #[test]
+    fn test_from_aee2010() {
+        let repr_2010 = crate::aee2010::infodiv::x228::Repr::from(&frame_2_repr());
+        assert_eq!(repr_2010.speed_setting, 80);
+        let repr = Repr::from(&repr_2010);
+        assert_eq!(repr.speed_unit, SpeedUnit::Kph);
+        assert_eq!(repr.speed_setting_kph(), frame_2_repr().speed_setting_kph());
+        assert_eq!(
+            repr.speed_regulation_mode,
+            frame_2_repr().speed_regulation_mode
+        );
+        assert_eq!(
+            repr.speed_regulation_mode_state,
+            frame_2_repr().speed_regulation_mode_state
+        );
+    }
 }
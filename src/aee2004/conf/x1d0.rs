@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
         ACAirDistributionPosition, ACAirIntakeMode, ACAirTemperature, ACFanMode2004, ACFanSpeed,
         ACModeRequest,
@@ -51,6 +53,8 @@ pub const FRAME_LEN: usize = field::AC_6 + 1;
 
 /// Periodicity of a x1d0 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x1d0 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -329,10 +333,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1d0 ({})", err)?;
                 Ok(())
@@ -347,9 +351,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1d0 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub ac_request: ACModeRequest,
     pub front_ac_failure: bool,
@@ -371,7 +381,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             ac_request: frame.ac_request(),
             front_ac_failure: frame.front_ac_failure(),
             front_ac_fan_mode: frame.front_ac_fan_mode(),
@@ -386,7 +401,7 @@ impl Repr {
             restore_mode: frame.restore_mode(),
             front_left_temp: frame.front_left_temp(),
             front_right_temp: frame.front_right_temp(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -413,31 +428,184 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x40, 0x40, 0x02, 0x40, 0x80, 0x03, 0x0a],
+    with_ac_request => ac_request: ACModeRequest,
+    with_front_ac_failure => front_ac_failure: bool,
+    with_front_ac_fan_mode => front_ac_fan_mode: ACFanMode2004,
+    with_rear_demist => rear_demist: bool,
+    with_ac_off => ac_off: bool,
+    with_fan_failure => fan_failure: bool,
+    with_cabin_sensor_failure => cabin_sensor_failure: bool,
+    with_front_fan_speed => front_fan_speed: ACFanSpeed,
+    with_front_right_distribution_position => front_right_distribution_position: ACAirDistributionPosition,
+    with_front_left_distribution_position => front_left_distribution_position: ACAirDistributionPosition,
+    with_air_intake_mode => air_intake_mode: ACAirIntakeMode,
+    with_restore_mode => restore_mode: bool,
+    with_front_left_temp => front_left_temp: ACAirTemperature,
+    with_front_right_temp => front_right_temp: ACAirTemperature,
+}
+
+/// Signals of a x1d0 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("ac_request", 0, 2, 3).values(&[(0, "AutoComfort"), (1, "AutoDemist"), (2, "Off")]),
+    Signal::new("front_ac_failure", 0, 3, 1),
+    Signal::new("front_ac_fan_mode", 0, 5, 2).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "Manual"),
+        (3, "AutoSoft"),
+    ]),
+    Signal::new("rear_demist", 0, 6, 1),
+    Signal::new("ac_off", 0, 7, 1),
+    Signal::new("fan_failure", 1, 6, 1),
+    Signal::new("cabin_sensor_failure", 1, 7, 1),
+    Signal::new("front_fan_speed", 2, 3, 4).values(&[
+        (0, "Speed1"),
+        (1, "Speed2"),
+        (2, "Speed3"),
+        (3, "Speed4"),
+        (4, "Speed5"),
+        (5, "Speed6"),
+        (6, "Speed7"),
+        (7, "Speed8"),
+        (15, "Speed0"),
+    ]),
+    Signal::new("front_right_distribution_position", 3, 3, 4).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "Foot"),
+        (3, "Ventilation"),
+        (4, "Demist"),
+        (5, "FootVentilation"),
+        (6, "FootDemist"),
+        (7, "VentilationDemist"),
+        (8, "FootVentilationDemist"),
+        (9, "AutoSoft"),
+    ]),
+    Signal::new("front_left_distribution_position", 3, 7, 4).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "Foot"),
+        (3, "Ventilation"),
+        (4, "Demist"),
+        (5, "FootVentilation"),
+        (6, "FootDemist"),
+        (7, "VentilationDemist"),
+        (8, "FootVentilationDemist"),
+        (9, "AutoSoft"),
+    ]),
+    Signal::new("air_intake_mode", 4, 6, 3).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "ForcedOpen"),
+        (3, "ForcedClose"),
+        (4, "AutoComfortWithoutAQS"),
+    ]),
+    Signal::new("restore_mode", 4, 7, 1),
+    Signal::new("front_left_temp", 5, 4, 5).values(&[
+        (0, "LO"),
+        (1, "Fourteen"),
+        (2, "Fifteen"),
+        (3, "Sixteen"),
+        (4, "Seventeen"),
+        (5, "Eighteen"),
+        (6, "EighteenDotFive"),
+        (7, "Nineteen"),
+        (8, "NineteenDotFive"),
+        (9, "Twenty"),
+        (10, "TwentyDotFive"),
+        (11, "TwentyOne"),
+        (12, "TwentyOneDotFive"),
+        (13, "TwentyTwo"),
+        (14, "TwentyTwoDotFive"),
+        (15, "TwentyThree"),
+        (16, "TwentyThreeDotFive"),
+        (17, "TwentyFour"),
+        (18, "TwentyFive"),
+        (19, "TwentySix"),
+        (20, "TwentySeven"),
+        (21, "TwentyEight"),
+        (22, "HI"),
+    ]),
+    Signal::new("front_right_temp", 6, 4, 5).values(&[
+        (0, "LO"),
+        (1, "Fourteen"),
+        (2, "Fifteen"),
+        (3, "Sixteen"),
+        (4, "Seventeen"),
+        (5, "Eighteen"),
+        (6, "EighteenDotFive"),
+        (7, "Nineteen"),
+        (8, "NineteenDotFive"),
+        (9, "Twenty"),
+        (10, "TwentyDotFive"),
+        (11, "TwentyOne"),
+        (12, "TwentyOneDotFive"),
+        (13, "TwentyTwo"),
+        (14, "TwentyTwoDotFive"),
+        (15, "TwentyThree"),
+        (16, "TwentyThreeDotFive"),
+        (17, "TwentyFour"),
+        (18, "TwentyFive"),
+        (19, "TwentySix"),
+        (20, "TwentySeven"),
+        (21, "TwentyEight"),
+        (22, "HI"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x1d0")?;
-        writeln!(f, " ac_request={}", self.ac_request)?;
-        writeln!(f, " front_ac_failure={}", self.front_ac_failure)?;
-        writeln!(f, " front_ac_fan_mode={}", self.front_ac_fan_mode)?;
-        writeln!(f, " rear_demist={}", self.rear_demist)?;
-        writeln!(f, " ac_off={}", self.ac_off)?;
-        writeln!(f, " fan_failure={}", self.fan_failure)?;
-        writeln!(f, " cabin_sensor_failure={}", self.cabin_sensor_failure)?;
-        writeln!(f, " front_fan_speed={}", self.front_fan_speed)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x1d0")?;
+        write!(f, "{}ac_request={}", sep, self.ac_request)?;
+        write!(f, "{}front_ac_failure={}", sep, self.front_ac_failure)?;
+        write!(f, "{}front_ac_fan_mode={}", sep, self.front_ac_fan_mode)?;
+        write!(f, "{}rear_demist={}", sep, self.rear_demist)?;
+        write!(f, "{}ac_off={}", sep, self.ac_off)?;
+        write!(f, "{}fan_failure={}", sep, self.fan_failure)?;
+        write!(
             f,
-            " front_right_distribution_position={}",
-            self.front_right_distribution_position
+            "{}cabin_sensor_failure={}",
+            sep, self.cabin_sensor_failure
         )?;
-        writeln!(
+        write!(f, "{}front_fan_speed={}", sep, self.front_fan_speed)?;
+        write!(
             f,
-            " front_left_distribution_position={}",
-            self.front_left_distribution_position
+            "{}front_right_distribution_position={}",
+            sep, self.front_right_distribution_position
         )?;
-        writeln!(f, " air_intake_mode={}", self.air_intake_mode)?;
-        writeln!(f, " restore_mode={}", self.restore_mode)?;
-        writeln!(f, " front_left_temp={}", self.front_left_temp)?;
-        writeln!(f, " front_right_temp={}", self.front_right_temp)
+        write!(
+            f,
+            "{}front_left_distribution_position={}",
+            sep, self.front_left_distribution_position
+        )?;
+        write!(f, "{}air_intake_mode={}", sep, self.air_intake_mode)?;
+        write!(f, "{}restore_mode={}", sep, self.restore_mode)?;
+        write!(f, "{}front_left_temp={}", sep, self.front_left_temp)?;
+        write!(f, "{}front_right_temp={}", sep, self.front_right_temp)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x350::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x350::Repr) -> Self {
+        Repr {
+            ac_request: repr_2010.ac_request,
+            front_ac_failure: false, // No equivalent on AEE2010.
+            front_ac_fan_mode: repr_2010.front_ac_fan_mode.into(),
+            rear_demist: false,          // No equivalent on AEE2010.
+            ac_off: false,               // No equivalent on AEE2010.
+            fan_failure: false,          // No equivalent on AEE2010.
+            cabin_sensor_failure: false, // No equivalent on AEE2010.
+            front_fan_speed: repr_2010.front_fan_speed,
+            front_right_distribution_position: repr_2010.front_right_distribution_position,
+            front_left_distribution_position: repr_2010.front_left_distribution_position,
+            air_intake_mode: repr_2010.air_intake_mode,
+            restore_mode: false, // No equivalent on AEE2010.
+            front_left_temp: repr_2010.front_left_temperature,
+            front_right_temp: repr_2010.front_right_temperature,
+        }
     }
 }
 
@@ -447,7 +615,7 @@ mod test {
     use crate::{
         vehicle::{
             ACAirDistributionPosition, ACAirIntakeMode, ACAirTemperature, ACFanMode2004,
-            ACFanSpeed, ACModeRequest,
+            ACFanMode2010, ACFanSpeed, ACModeRequest,
         },
         Error,
     };
@@ -638,4 +806,23 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2010() {
+        let repr_2010 = crate::aee2010::infodiv::x350::Repr::builder()
+            .with_front_ac_fan_mode(ACFanMode2010::AutoDemist)
+            .with_ac_request(ACModeRequest::AutoDemist)
+            .with_front_left_temperature(ACAirTemperature::Nineteen)
+            .with_front_right_temperature(ACAirTemperature::TwentyOneDotFive)
+            .with_front_fan_speed(ACFanSpeed::Speed2)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2010);
+        assert_eq!(repr.front_ac_fan_mode, ACFanMode2004::AutoDemist);
+        assert_eq!(repr.ac_request, ACModeRequest::AutoDemist);
+        assert_eq!(repr.front_left_temp, ACAirTemperature::Nineteen);
+        assert_eq!(repr.front_right_temp, ACAirTemperature::TwentyOneDotFive);
+        assert_eq!(repr.front_fan_speed, ACFanSpeed::Speed2);
+        assert_eq!(repr.rear_demist, false);
+    }
 }
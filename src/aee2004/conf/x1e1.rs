@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{PAXWheelState, UnderInflationSystemState, WheelState},
     Error, Result,
 };
@@ -45,6 +47,9 @@ pub const FRAME_ID: u16 = 0x1e1;
 /// Length of a x1e1 CAN frame.
 pub const FRAME_LEN: usize = field::EMPTY.end;
 
+/// Transmission of a x1e1 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -258,10 +263,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1e1 ({})", err)?;
                 Ok(())
@@ -276,9 +281,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1e1 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub front_left_wheel_pax_state: PAXWheelState,
     pub front_left_wheel_state: WheelState,
@@ -296,7 +307,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             front_left_wheel_pax_state: frame.front_left_wheel_pax_state(),
             front_left_wheel_state: frame.front_left_wheel_state(),
             front_right_wheel_pax_state: frame.front_right_wheel_pax_state(),
@@ -307,7 +323,7 @@ impl Repr {
             rear_right_wheel_state: frame.rear_right_wheel_state(),
             spare_wheel_state: frame.spare_wheel_state(),
             system_state: frame.system_state(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -330,40 +346,152 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x80, 0x22, 0x14, 0x08, 0x40, 0xe0, 0x00, 0x00],
+    parked: [0x80, 0x80, 0x80, 0x80, 0x40, 0xe0, 0x00, 0x00],
+    with_front_left_wheel_pax_state => front_left_wheel_pax_state: PAXWheelState,
+    with_front_left_wheel_state => front_left_wheel_state: WheelState,
+    with_front_right_wheel_pax_state => front_right_wheel_pax_state: PAXWheelState,
+    with_front_right_wheel_state => front_right_wheel_state: WheelState,
+    with_rear_left_wheel_pax_state => rear_left_wheel_pax_state: PAXWheelState,
+    with_rear_left_wheel_state => rear_left_wheel_state: WheelState,
+    with_rear_right_wheel_pax_state => rear_right_wheel_pax_state: PAXWheelState,
+    with_rear_right_wheel_state => rear_right_wheel_state: WheelState,
+    with_spare_wheel_state => spare_wheel_state: WheelState,
+    with_system_state => system_state: UnderInflationSystemState,
+}
+
+/// Signals of a x1e1 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("front_left_wheel_pax_state", 0, 2, 2).values(&[
+        (0, "Normal"),
+        (1, "Puncture"),
+        (2, "Unavailable"),
+    ]),
+    Signal::new("front_left_wheel_state", 0, 7, 5).values(&[
+        (1, "Puncture"),
+        (2, "LightlyDeflated"),
+        (4, "HighlyDeflated"),
+        (8, "NotMonitored"),
+        (16, "Normal"),
+    ]),
+    Signal::new("front_right_wheel_pax_state", 1, 2, 2).values(&[
+        (0, "Normal"),
+        (1, "Puncture"),
+        (2, "Unavailable"),
+    ]),
+    Signal::new("front_right_wheel_state", 1, 7, 5).values(&[
+        (1, "Puncture"),
+        (2, "LightlyDeflated"),
+        (4, "HighlyDeflated"),
+        (8, "NotMonitored"),
+        (16, "Normal"),
+    ]),
+    Signal::new("rear_left_wheel_pax_state", 2, 2, 2).values(&[
+        (0, "Normal"),
+        (1, "Puncture"),
+        (2, "Unavailable"),
+    ]),
+    Signal::new("rear_left_wheel_state", 2, 7, 5).values(&[
+        (1, "Puncture"),
+        (2, "LightlyDeflated"),
+        (4, "HighlyDeflated"),
+        (8, "NotMonitored"),
+        (16, "Normal"),
+    ]),
+    Signal::new("rear_right_wheel_pax_state", 3, 2, 2).values(&[
+        (0, "Normal"),
+        (1, "Puncture"),
+        (2, "Unavailable"),
+    ]),
+    Signal::new("rear_right_wheel_state", 3, 7, 5).values(&[
+        (1, "Puncture"),
+        (2, "LightlyDeflated"),
+        (4, "HighlyDeflated"),
+        (8, "NotMonitored"),
+        (16, "Normal"),
+    ]),
+    Signal::new("spare_wheel_state", 4, 7, 5).values(&[
+        (1, "Puncture"),
+        (2, "LightlyDeflated"),
+        (4, "HighlyDeflated"),
+        (8, "NotMonitored"),
+        (16, "Normal"),
+    ]),
+    Signal::new("system_state", 5, 7, 3).values(&[
+        (0, "PressureNotMonitored"),
+        (1, "SystemFailure"),
+        (2, "LoadUnsuitableWheelPressure"),
+        (3, "SpeedUnsuitableWheelPressure"),
+        (4, "MeasureInProgress"),
+        (7, "Ok"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x1e1")?;
-        writeln!(f, " front_left_wheel_state={}", self.front_left_wheel_state)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x1e1")?;
+        write!(
             f,
-            " front_right_wheel_state={}",
-            self.front_right_wheel_state
+            "{}front_left_wheel_state={}",
+            sep, self.front_left_wheel_state
         )?;
-        writeln!(f, " rear_left_wheel_state={}", self.rear_left_wheel_state)?;
-        writeln!(f, " rear_right_wheel_state={}", self.rear_right_wheel_state)?;
-        writeln!(f, " spare_wheel_state={}", self.spare_wheel_state)?;
-        writeln!(
+        write!(
             f,
-            " front_left_wheel_pax_state={}",
-            self.front_left_wheel_pax_state
+            "{}front_right_wheel_state={}",
+            sep, self.front_right_wheel_state
         )?;
-        writeln!(
+        write!(
             f,
-            " front_right_wheel_pax_state={}",
-            self.front_right_wheel_pax_state
+            "{}rear_left_wheel_state={}",
+            sep, self.rear_left_wheel_state
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_left_wheel_pax_state={}",
-            self.rear_left_wheel_pax_state
+            "{}rear_right_wheel_state={}",
+            sep, self.rear_right_wheel_state
         )?;
-        writeln!(
+        write!(f, "{}spare_wheel_state={}", sep, self.spare_wheel_state)?;
+        write!(
             f,
-            " rear_right_wheel_pax_state={}",
-            self.rear_right_wheel_pax_state
+            "{}front_left_wheel_pax_state={}",
+            sep, self.front_left_wheel_pax_state
         )?;
-        writeln!(f, " spare_wheel_state={}", self.spare_wheel_state)?;
-        writeln!(f, " system_state={}", self.system_state)
+        write!(
+            f,
+            "{}front_right_wheel_pax_state={}",
+            sep, self.front_right_wheel_pax_state
+        )?;
+        write!(
+            f,
+            "{}rear_left_wheel_pax_state={}",
+            sep, self.rear_left_wheel_pax_state
+        )?;
+        write!(
+            f,
+            "{}rear_right_wheel_pax_state={}",
+            sep, self.rear_right_wheel_pax_state
+        )?;
+        write!(f, "{}spare_wheel_state={}", sep, self.spare_wheel_state)?;
+        write!(f, "{}system_state={}", sep, self.system_state)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x1e1::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x1e1::Repr) -> Self {
+        Repr {
+            front_left_wheel_pax_state: PAXWheelState::Normal, // No equivalent on AEE2010.
+            front_left_wheel_state: repr_2010.front_left_wheel_state,
+            front_right_wheel_pax_state: PAXWheelState::Normal, // No equivalent on AEE2010.
+            front_right_wheel_state: repr_2010.front_right_wheel_state,
+            rear_left_wheel_pax_state: PAXWheelState::Normal, // No equivalent on AEE2010.
+            rear_left_wheel_state: repr_2010.rear_left_wheel_state,
+            rear_right_wheel_pax_state: PAXWheelState::Normal, // No equivalent on AEE2010.
+            rear_right_wheel_state: repr_2010.rear_right_wheel_state,
+            spare_wheel_state: WheelState::NotMonitored, // No equivalent on AEE2010.
+            system_state: repr_2010.system_state,
+        }
     }
 }
 
@@ -2,6 +2,8 @@ use core::{cmp::Ordering, fmt};
 
 use crate::{
     config::{MusicalAmbiance, SpeedDependentVolumeLaw},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result,
 };
 
@@ -49,6 +51,9 @@ pub const FRAME_ID: u16 = 0x1e5;
 /// Length of a x1e5 CAN frame.
 pub const FRAME_LEN: usize = field::FLAGS_AMBIANCE + 1;
 
+/// Transmission of a x1e5 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -404,10 +409,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1e5 ({})", err)?;
                 Ok(())
@@ -422,9 +427,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1e5 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub balance_level: u8,
     pub balance_under_adj: bool,
@@ -451,7 +462,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             balance_level: frame.balance_level(),
             balance_under_adj: frame.balance_under_adjustment(),
             fader_level: frame.fader_level(),
@@ -471,7 +487,7 @@ impl Repr {
             musical_ambiance: frame.musical_ambiance(),
             musical_ambiance_under_adj: frame.musical_ambiance_under_adjustment(),
             impossible_setting: frame.impossible_setting(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -503,50 +519,129 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x3f, 0x3f, 0x3f, 0x3f, 0x3f, 0x47, 0x00],
+    parked: [0x3f, 0x3f, 0x3f, 0x3f, 0x3f, 0x00, 0x00],
+    with_balance_level => balance_level: u8,
+    with_balance_under_adj => balance_under_adj: bool,
+    with_fader_level => fader_level: u8,
+    with_fader_under_adj => fader_under_adj: bool,
+    with_bass_level => bass_level: u8,
+    with_bass_under_adj => bass_under_adj: bool,
+    with_middle_level => middle_level: u8,
+    with_middle_under_adj => middle_under_adj: bool,
+    with_treble_level => treble_level: u8,
+    with_treble_under_adj => treble_under_adj: bool,
+    with_speed_dependent_volume => speed_dependent_volume: SpeedDependentVolumeLaw,
+    with_speed_dependent_volume_under_adj => speed_dependent_volume_under_adj: bool,
+    with_loudness_enabled => loudness_enabled: bool,
+    with_loudness_under_adj => loudness_under_adj: bool,
+    with_loudness_enabled_diag => loudness_enabled_diag: bool,
+    with_fader_enabled_diag => fader_enabled_diag: bool,
+    with_musical_ambiance => musical_ambiance: MusicalAmbiance,
+    with_musical_ambiance_under_adj => musical_ambiance_under_adj: bool,
+    with_impossible_setting => impossible_setting: bool,
+}
+
+/// Signals of a x1e5 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("balance_level", 0, 6, 7),
+    Signal::new("balance_under_adj", 0, 7, 1),
+    Signal::new("fader_level", 1, 6, 7),
+    Signal::new("fader_under_adj", 1, 7, 1),
+    Signal::new("bass_level", 2, 6, 7),
+    Signal::new("bass_under_adj", 2, 7, 1),
+    Signal::new("middle_level", 3, 6, 7),
+    Signal::new("middle_under_adj", 3, 7, 1),
+    Signal::new("treble_level", 4, 6, 7),
+    Signal::new("treble_under_adj", 4, 7, 1),
+    Signal::new("speed_dependent_volume", 5, 2, 3).values(&[
+        (0, "Off"),
+        (1, "Law0"),
+        (2, "Law1"),
+        (3, "Law2"),
+        (4, "Law3"),
+        (5, "Law4"),
+        (6, "Law5"),
+        (7, "On"),
+    ]),
+    Signal::new("speed_dependent_volume_under_adj", 5, 4, 1),
+    Signal::new("loudness_enabled", 5, 6, 1),
+    Signal::new("loudness_under_adj", 5, 7, 1),
+    Signal::new("loudness_enabled_diag", 6, 0, 1),
+    Signal::new("fader_enabled_diag", 6, 1, 1),
+    Signal::new("musical_ambiance", 6, 4, 3).values(&[
+        (0, "None"),
+        (1, "Classic"),
+        (2, "JazzBlues"),
+        (3, "PopRock"),
+        (4, "Vocal"),
+        (5, "Techno"),
+    ]),
+    Signal::new("musical_ambiance_under_adj", 6, 6, 1),
+    Signal::new("impossible_setting", 6, 5, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x1e5 balance_level={}", self.balance_level)?;
-        writeln!(f, " balance_under_adj={}", self.balance_under_adj)?;
-        writeln!(f, " fader_level={}", self.fader_level)?;
-        writeln!(f, " fader_under_adj={}", self.fader_under_adj)?;
-        writeln!(f, " bass_level={}", self.bass_level)?;
-        writeln!(f, " bass_under_adj={}", self.bass_under_adj)?;
-        writeln!(f, " middle_level={}", self.middle_level)?;
-        writeln!(f, " middle_under_adj={}", self.middle_under_adj)?;
-        writeln!(f, " treble_level={}", self.treble_level)?;
-        writeln!(f, " treble_under_adj={}", self.treble_under_adj)?;
-        writeln!(f, " speed_dependent_volume={}", self.speed_dependent_volume)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x1e5")?;
+        write!(f, "{}balance_level={}", sep, self.balance_level)?;
+        write!(f, "{}balance_under_adj={}", sep, self.balance_under_adj)?;
+        write!(f, "{}fader_level={}", sep, self.fader_level)?;
+        write!(f, "{}fader_under_adj={}", sep, self.fader_under_adj)?;
+        write!(f, "{}bass_level={}", sep, self.bass_level)?;
+        write!(f, "{}bass_under_adj={}", sep, self.bass_under_adj)?;
+        write!(f, "{}middle_level={}", sep, self.middle_level)?;
+        write!(f, "{}middle_under_adj={}", sep, self.middle_under_adj)?;
+        write!(f, "{}treble_level={}", sep, self.treble_level)?;
+        write!(f, "{}treble_under_adj={}", sep, self.treble_under_adj)?;
+        write!(
             f,
-            " speed_dependent_volume_under_adj={}",
-            self.speed_dependent_volume_under_adj
+            "{}speed_dependent_volume={}",
+            sep, self.speed_dependent_volume
         )?;
-        writeln!(f, " loudness_enabled={}", self.loudness_enabled)?;
-        writeln!(f, " loudness_under_adj={}", self.loudness_under_adj)?;
-        writeln!(f, " loudness_enabled_diag={}", self.loudness_enabled_diag)?;
-        writeln!(f, " fader_enabled_diag={}", self.fader_enabled_diag)?;
-        writeln!(f, " musical_ambiance={}", self.musical_ambiance)?;
-        writeln!(
+        write!(
             f,
-            " musical_ambiance_under_adj={}",
-            self.musical_ambiance_under_adj
+            "{}speed_dependent_volume_under_adj={}",
+            sep, self.speed_dependent_volume_under_adj
         )?;
-        writeln!(f, " impossible_setting={}", self.impossible_setting)
+        write!(f, "{}loudness_enabled={}", sep, self.loudness_enabled)?;
+        write!(f, "{}loudness_under_adj={}", sep, self.loudness_under_adj)?;
+        write!(
+            f,
+            "{}loudness_enabled_diag={}",
+            sep, self.loudness_enabled_diag
+        )?;
+        write!(f, "{}fader_enabled_diag={}", sep, self.fader_enabled_diag)?;
+        write!(f, "{}musical_ambiance={}", sep, self.musical_ambiance)?;
+        write!(
+            f,
+            "{}musical_ambiance_under_adj={}",
+            sep, self.musical_ambiance_under_adj
+        )?;
+        write!(f, "{}impossible_setting={}", sep, self.impossible_setting)
     }
 }
 
+/// Return the AEE2004 audio setting level of the AEE2010 level `level`, AEE2004 levels being
+/// offset by 49, saturating levels out of the 7-bit AEE2004 range.
+fn level_from_aee2010(level: u8) -> u8 {
+    level.saturating_add(49).min(0x7f)
+}
+
 impl From<&crate::aee2010::infodiv::x1e5::Repr> for Repr {
     fn from(repr_2010: &crate::aee2010::infodiv::x1e5::Repr) -> Self {
         Repr {
-            balance_level: repr_2010.balance_level + 49,
+            balance_level: level_from_aee2010(repr_2010.balance_level),
             balance_under_adj: repr_2010.balance_under_adj,
-            fader_level: repr_2010.fader_level + 49,
+            fader_level: level_from_aee2010(repr_2010.fader_level),
             fader_under_adj: repr_2010.fader_under_adj,
-            bass_level: repr_2010.bass_level + 49,
+            bass_level: level_from_aee2010(repr_2010.bass_level),
             bass_under_adj: repr_2010.bass_under_adj,
             middle_level: 0x3f,
             middle_under_adj: false,
-            treble_level: repr_2010.treble_level + 49,
+            treble_level: level_from_aee2010(repr_2010.treble_level),
             treble_under_adj: repr_2010.treble_under_adj,
             speed_dependent_volume: if repr_2010.speed_dependent_volume_enabled {
                 SpeedDependentVolumeLaw::On
@@ -777,4 +872,20 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2010() {
+        let repr_2010 = crate::aee2010::infodiv::x1e5::Repr {
+            bass_level: 0,
+            treble_level: 0xff,
+            ..crate::aee2010::infodiv::x1e5::Repr::builder()
+                .build()
+                .unwrap()
+        };
+        let repr = Repr::from(&repr_2010);
+        assert_eq!(repr.balance_level, 80);
+        assert_eq!(repr.bass_level, 49);
+        assert_eq!(repr.treble_level, 0x7f);
+        assert_eq!(repr.speed_dependent_volume, SpeedDependentVolumeLaw::On);
+    }
 }
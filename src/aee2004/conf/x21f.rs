@@ -0,0 +1,203 @@
+use core::time::Duration;
+
+use crate::{frame::TransmissionKind, signal::Signal};
+
+mod field {
+    /// 1-bit empty,
+    /// 1-bit source key flag,
+    /// 1-bit volume down key flag,
+    /// 1-bit volume up key flag,
+    /// 2-bit empty,
+    /// 1-bit previous key flag,
+    /// 1-bit next key flag.
+    pub const KEYS: usize = 0;
+    /// 8-bit scroll wheel position counter.
+    pub const SCROLL: usize = 1;
+    /// 8-bit unknown.
+    pub const _UNKNOWN: usize = 2;
+}
+
+/// Raw x21f CAN frame identifier.
+pub const FRAME_ID: u16 = 0x21f;
+/// Length of a x21f CAN frame.
+pub const FRAME_LEN: usize = field::_UNKNOWN + 1;
+
+/// Periodicity of a x21f CAN frame.
+pub const PERIODICITY: Duration = Duration::from_millis(100);
+/// Transmission of a x21f CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
+
+can_frame! {
+    /// A high-level representation of a x21f CAN frame.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        derive(defmt::Format)
+    )]
+    pub struct Repr {
+        #[flag(field::KEYS, 0x80, 7, next, set_next, "next key")]
+        pub next: bool,
+        #[flag(field::KEYS, 0x40, 6, previous, set_previous, "previous key")]
+        pub previous: bool,
+        #[flag(field::KEYS, 0x08, 3, volume_up, set_volume_up, "volume up key")]
+        pub volume_up: bool,
+        #[flag(field::KEYS, 0x04, 2, volume_down, set_volume_down, "volume down key")]
+        pub volume_down: bool,
+        #[flag(field::KEYS, 0x02, 1, source, set_source, "source key")]
+        pub source: bool,
+        #[field(field::SCROLL, 0xff, 0, scroll_position, set_scroll_position, "scroll wheel position counter")]
+        pub scroll_position: u8,
+    }
+}
+
+repr_builder! {
+    idle: [0x00, 0x00, 0x00],
+    with_next => next: bool,
+    with_previous => previous: bool,
+    with_volume_up => volume_up: bool,
+    with_volume_down => volume_down: bool,
+    with_source => source: bool,
+    with_scroll_position => scroll_position: u8,
+}
+
+/// Signals of a x21f CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("next", 0, 7, 1),
+    Signal::new("previous", 0, 6, 1),
+    Signal::new("volume_up", 0, 3, 1),
+    Signal::new("volume_down", 0, 2, 1),
+    Signal::new("source", 0, 1, 1),
+    Signal::new("scroll_position", 1, 7, 8),
+];
+
+#[cfg(test)]
+mod test {
+    use super::{Frame, Repr};
+    use crate::Error;
+
+    static REPR_FRAME_BYTES_1: [u8; 3] = [0x88, 0x05, 0x00];
+    static REPR_FRAME_BYTES_2: [u8; 3] = [0x46, 0xfe, 0x00];
+
+    fn frame_1_repr() -> Repr {
+        Repr {
+            next: true,
+            previous: false,
+            volume_up: true,
+            volume_down: false,
+            source: false,
+            scroll_position: 5,
+        }
+    }
+
+    fn frame_2_repr() -> Repr {
+        Repr {
+            next: false,
+            previous: true,
+            volume_up: false,
+            volume_down: true,
+            source: true,
+            scroll_position: 254,
+        }
+    }
+
+    #[test]
+    fn test_frame_1_deconstruction() {
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
+        assert_eq!(frame.check_len(), Ok(()));
+        assert_eq!(frame.next(), true);
+        assert_eq!(frame.previous(), false);
+        assert_eq!(frame.volume_up(), true);
+        assert_eq!(frame.volume_down(), false);
+        assert_eq!(frame.source(), false);
+        assert_eq!(frame.scroll_position(), 5);
+    }
+
+    #[test]
+    fn test_frame_2_deconstruction() {
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_2);
+        assert_eq!(frame.check_len(), Ok(()));
+        assert_eq!(frame.next(), false);
+        assert_eq!(frame.previous(), true);
+        assert_eq!(frame.volume_up(), false);
+        assert_eq!(frame.volume_down(), true);
+        assert_eq!(frame.source(), true);
+        assert_eq!(frame.scroll_position(), 254);
+    }
+
+    #[test]
+    fn test_frame_1_construction() {
+        let mut bytes = [0u8; 3];
+        let mut frame = Frame::new_unchecked(&mut bytes);
+
+        frame.set_next(true);
+        frame.set_previous(false);
+        frame.set_volume_up(true);
+        frame.set_volume_down(false);
+        frame.set_source(false);
+        frame.set_scroll_position(5);
+
+        assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_1);
+    }
+
+    #[test]
+    fn test_frame_2_construction() {
+        let mut bytes = [0u8; 3];
+        let mut frame = Frame::new_unchecked(&mut bytes);
+
+        frame.set_next(false);
+        frame.set_previous(true);
+        frame.set_volume_up(false);
+        frame.set_volume_down(true);
+        frame.set_source(true);
+        frame.set_scroll_position(254);
+
+        assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
+    }
+
+    #[test]
+    fn test_overlong() {
+        let bytes: [u8; 4] = [0x88, 0x05, 0x00, 0x00];
+        assert_eq!(
+            Frame::new_unchecked(&bytes).check_len().unwrap_err(),
+            Error::Overlong
+        );
+    }
+
+    #[test]
+    fn test_underlong() {
+        let bytes: [u8; 2] = [0x88, 0x05];
+        assert_eq!(Frame::new_checked(&bytes).unwrap_err(), Error::Truncated);
+    }
+
+    #[test]
+    fn test_repr_1_parse_valid() {
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
+        let repr = Repr::parse(&frame).unwrap();
+        assert_eq!(repr, frame_1_repr());
+    }
+
+    #[test]
+    fn test_repr_2_parse_valid() {
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_2);
+        let repr = Repr::parse(&frame).unwrap();
+        assert_eq!(repr, frame_2_repr());
+    }
+
+    #[test]
+    fn test_basic_repr_1_emit() {
+        let mut buf = [0u8; 3];
+        let mut frame = Frame::new_unchecked(&mut buf);
+        let repr = frame_1_repr();
+        repr.emit(&mut frame);
+        assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_1);
+    }
+
+    #[test]
+    fn test_basic_repr_2_emit() {
+        let mut buf = [0u8; 3];
+        let mut frame = Frame::new_unchecked(&mut buf);
+        let repr = frame_2_repr();
+        repr.emit(&mut frame);
+        assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
+    }
+}
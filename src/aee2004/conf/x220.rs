@@ -1,6 +1,11 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
-use crate::{vehicle::BodyType, Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    vehicle::BodyType,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -32,6 +37,8 @@ pub const FRAME_LEN: usize = field::FLAGS_1 + 1;
 
 /// Periodicity of a x220 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x220 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -116,10 +123,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x220 ({})", err)?;
                 Ok(())
@@ -134,9 +141,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x220 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub fuel_cap_opened: bool,
     pub rear_windscreen_opened: bool,
@@ -154,7 +167,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             fuel_cap_opened: frame.read_opening_state::<{ field::FLAGS_0 }, 0>(),
             rear_windscreen_opened: frame.read_opening_state::<{ field::FLAGS_0 }, 1>(),
             bonnet_opened: frame.read_opening_state::<{ field::FLAGS_0 }, 2>(),
@@ -165,7 +183,7 @@ impl Repr {
             front_left_door_opened: frame.read_opening_state::<{ field::FLAGS_0 }, 7>(),
             spare_wheel_arm_opened: frame.read_opening_state::<{ field::FLAGS_1 }, 6>(),
             vehicle_body_type: frame.vehicle_body_type(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -188,23 +206,72 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x55, 0x40],
+    with_fuel_cap_opened => fuel_cap_opened: bool,
+    with_rear_windscreen_opened => rear_windscreen_opened: bool,
+    with_bonnet_opened => bonnet_opened: bool,
+    with_boot_opened => boot_opened: bool,
+    with_rear_right_door_opened => rear_right_door_opened: bool,
+    with_rear_left_door_opened => rear_left_door_opened: bool,
+    with_front_right_door_opened => front_right_door_opened: bool,
+    with_front_left_door_opened => front_left_door_opened: bool,
+    with_spare_wheel_arm_opened => spare_wheel_arm_opened: bool,
+    with_vehicle_body_type => vehicle_body_type: BodyType,
+}
+
+/// Signals of a x220 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("fuel_cap_opened", 0, 0, 1),
+    Signal::new("rear_windscreen_opened", 0, 1, 1),
+    Signal::new("bonnet_opened", 0, 2, 1),
+    Signal::new("boot_opened", 0, 3, 1),
+    Signal::new("rear_right_door_opened", 0, 4, 1),
+    Signal::new("rear_left_door_opened", 0, 5, 1),
+    Signal::new("front_right_door_opened", 0, 6, 1),
+    Signal::new("front_left_door_opened", 0, 7, 1),
+    Signal::new("spare_wheel_arm_opened", 1, 6, 1),
+    Signal::new("vehicle_body_type", 1, 7, 1).values(&[(0, "FiveDoors"), (1, "ThreeDoors")]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x220")?;
-        writeln!(f, " fuel_cap_opened={}", self.fuel_cap_opened)?;
-        writeln!(f, " rear_windscreen_opened={}", self.rear_windscreen_opened)?;
-        writeln!(f, " bonnet_opened={}", self.bonnet_opened)?;
-        writeln!(f, " boot_opened={}", self.boot_opened)?;
-        writeln!(f, " rear_right_door_opened={}", self.rear_right_door_opened)?;
-        writeln!(f, " rear_left_door_opened={}", self.rear_left_door_opened)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x220")?;
+        write!(f, "{}fuel_cap_opened={}", sep, self.fuel_cap_opened)?;
+        write!(
+            f,
+            "{}rear_windscreen_opened={}",
+            sep, self.rear_windscreen_opened
+        )?;
+        write!(f, "{}bonnet_opened={}", sep, self.bonnet_opened)?;
+        write!(f, "{}boot_opened={}", sep, self.boot_opened)?;
+        write!(
+            f,
+            "{}rear_right_door_opened={}",
+            sep, self.rear_right_door_opened
+        )?;
+        write!(
+            f,
+            "{}rear_left_door_opened={}",
+            sep, self.rear_left_door_opened
+        )?;
+        write!(
+            f,
+            "{}front_right_door_opened={}",
+            sep, self.front_right_door_opened
+        )?;
+        write!(
+            f,
+            "{}front_left_door_opened={}",
+            sep, self.front_left_door_opened
+        )?;
+        write!(
             f,
-            " front_right_door_opened={}",
-            self.front_right_door_opened
+            "{}spare_wheel_arm_opened={}",
+            sep, self.spare_wheel_arm_opened
         )?;
-        writeln!(f, " front_left_door_opened={}", self.front_left_door_opened)?;
-        writeln!(f, " spare_wheel_arm_opened={}", self.spare_wheel_arm_opened)?;
-        writeln!(f, " vehicle_body_type={}", self.vehicle_body_type)
+        write!(f, "{}vehicle_body_type={}", sep, self.vehicle_body_type)
     }
 }
 
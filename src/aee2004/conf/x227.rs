@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{ACRecirculationState, FuelType, PushButtonLedState},
     Error, Result,
 };
@@ -47,6 +49,8 @@ pub const FRAME_LEN: usize = field::LED_4 + 1;
 
 /// Periodicity of a x227 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x227 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -385,10 +389,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x227 ({})", err)?;
                 Ok(())
@@ -403,9 +407,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x227 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub sport_suspension_led_state: PushButtonLedState,
     pub child_lock_led_state: PushButtonLedState,
@@ -431,7 +441,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             sport_suspension_led_state: frame.sport_suspension_led_state(),
             child_lock_led_state: frame.child_lock_led_state(),
             esp_led_state: frame.esp_led_state(),
@@ -450,7 +465,7 @@ impl Repr {
             ac_recirculation_state_request: frame.ac_recirculation_state_request(),
             over_speed_led_state: frame.over_speed_led_state(),
             stop_start_2: frame.stop_start_2(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -481,63 +496,190 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x24, 0x49, 0x02, 0x95, 0x00],
+    with_sport_suspension_led_state => sport_suspension_led_state: PushButtonLedState,
+    with_child_lock_led_state => child_lock_led_state: PushButtonLedState,
+    with_esp_led_state => esp_led_state: PushButtonLedState,
+    with_parking_sensors_led_state => parking_sensors_led_state: PushButtonLedState,
+    with_ac_on_led_state => ac_on_led_state: PushButtonLedState,
+    with_rear_windshield_demist_led_state => rear_windshield_demist_led_state: PushButtonLedState,
+    with_lane_centering_led_state => lane_centering_led_state: PushButtonLedState,
+    with_electrical_parking_brake_led_state => electrical_parking_brake_led_state: PushButtonLedState,
+    with_blind_spot_monitoring_led_state => blind_spot_monitoring_led_state: PushButtonLedState,
+    with_ac_recirculation_state => ac_recirculation_state: ACRecirculationState,
+    with_fuel_type => fuel_type: FuelType,
+    with_stop_start_1 => stop_start_1: PushButtonLedState,
+    with_adaptive_cruise_control_led_state => adaptive_cruise_control_led_state: PushButtonLedState,
+    with_preconditioning_reset => preconditioning_reset: bool,
+    with_preconditioning_request => preconditioning_request: bool,
+    with_ac_recirculation_state_request => ac_recirculation_state_request: bool,
+    with_over_speed_led_state => over_speed_led_state: PushButtonLedState,
+    with_stop_start_2 => stop_start_2: PushButtonLedState,
+}
+
+/// Signals of a x227 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("sport_suspension_led_state", 0, 1, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("child_lock_led_state", 0, 3, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("esp_led_state", 0, 5, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("parking_sensors_led_state", 0, 7, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("ac_on_led_state", 1, 1, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("rear_windshield_demist_led_state", 1, 3, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("lane_centering_led_state", 1, 5, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("electrical_parking_brake_led_state", 1, 7, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("blind_spot_monitoring_led_state", 2, 1, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("ac_recirculation_state", 2, 3, 2).values(&[
+        (0, "ExteriorAir"),
+        (1, "PartialAirRecirculation"),
+        (2, "FullAirRecirculation"),
+        (3, "Stopped"),
+    ]),
+    Signal::new("fuel_type", 2, 5, 2).values(&[(0, "Petrol"), (1, "Diesel")]),
+    Signal::new("stop_start_1", 2, 7, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("adaptive_cruise_control_led_state", 3, 1, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("preconditioning_reset", 3, 2, 1),
+    Signal::new("preconditioning_request", 3, 3, 1),
+    Signal::new("ac_recirculation_state_request", 3, 4, 1),
+    Signal::new("over_speed_led_state", 3, 7, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("stop_start_2", 4, 7, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x227")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x227")?;
+        write!(
+            f,
+            "{}sport_suspension_led_state={}",
+            sep, self.sport_suspension_led_state
+        )?;
+        write!(
             f,
-            " sport_suspension_led_state={}",
-            self.sport_suspension_led_state
+            "{}child_lock_led_state={}",
+            sep, self.child_lock_led_state
         )?;
-        writeln!(f, " child_lock_led_state={}", self.child_lock_led_state)?;
-        writeln!(f, " esp_led_state={}", self.esp_led_state)?;
-        writeln!(
+        write!(f, "{}esp_led_state={}", sep, self.esp_led_state)?;
+        write!(
             f,
-            " parking_sensors_led_state={}",
-            self.parking_sensors_led_state
+            "{}parking_sensors_led_state={}",
+            sep, self.parking_sensors_led_state
         )?;
-        writeln!(f, " ac_on_led_state={}", self.ac_on_led_state)?;
-        writeln!(
+        write!(f, "{}ac_on_led_state={}", sep, self.ac_on_led_state)?;
+        write!(
             f,
-            " rear_windshield_demist_led_state={}",
-            self.rear_windshield_demist_led_state
+            "{}rear_windshield_demist_led_state={}",
+            sep, self.rear_windshield_demist_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " lane_centering_led_state={}",
-            self.lane_centering_led_state
+            "{}lane_centering_led_state={}",
+            sep, self.lane_centering_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " electrical_parking_brake_led_state={}",
-            self.electrical_parking_brake_led_state
+            "{}electrical_parking_brake_led_state={}",
+            sep, self.electrical_parking_brake_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_monitoring_led_state={}",
-            self.blind_spot_monitoring_led_state
+            "{}blind_spot_monitoring_led_state={}",
+            sep, self.blind_spot_monitoring_led_state
         )?;
-        writeln!(f, " ac_recirculation_state={}", self.ac_recirculation_state)?;
-        writeln!(f, " fuel_type={}", self.fuel_type)?;
-        writeln!(f, " stop_start_1={}", self.stop_start_1)?;
-        writeln!(
+        write!(
             f,
-            " adaptive_cruise_control_led_state={}",
-            self.adaptive_cruise_control_led_state
+            "{}ac_recirculation_state={}",
+            sep, self.ac_recirculation_state
         )?;
-        writeln!(f, " preconditioning_reset={}", self.preconditioning_reset)?;
-        writeln!(
+        write!(f, "{}fuel_type={}", sep, self.fuel_type)?;
+        write!(f, "{}stop_start_1={}", sep, self.stop_start_1)?;
+        write!(
             f,
-            " preconditioning_request={}",
-            self.preconditioning_request
+            "{}adaptive_cruise_control_led_state={}",
+            sep, self.adaptive_cruise_control_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " ac_recirculation_state_request={}",
-            self.ac_recirculation_state_request
+            "{}preconditioning_reset={}",
+            sep, self.preconditioning_reset
         )?;
-        writeln!(f, " over_speed_led_state={}", self.over_speed_led_state)?;
-        writeln!(f, " stop_start_2={}", self.stop_start_2)
+        write!(
+            f,
+            "{}preconditioning_request={}",
+            sep, self.preconditioning_request
+        )?;
+        write!(
+            f,
+            "{}ac_recirculation_state_request={}",
+            sep, self.ac_recirculation_state_request
+        )?;
+        write!(
+            f,
+            "{}over_speed_led_state={}",
+            sep, self.over_speed_led_state
+        )?;
+        write!(f, "{}stop_start_2={}", sep, self.stop_start_2)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x227::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x227::Repr) -> Self {
+        Repr {
+            sport_suspension_led_state: repr_2010.sport_suspension_led_state,
+            child_lock_led_state: repr_2010.child_lock_led_state,
+            esp_led_state: repr_2010.esp_led_state,
+            parking_sensors_led_state: repr_2010.parking_sensors_led_state,
+            ac_on_led_state: repr_2010.ac_on_led_state,
+            rear_windshield_demist_led_state: repr_2010.rear_windshield_demist_led_state,
+            lane_centering_led_state: repr_2010.lane_centering_led_state,
+            electrical_parking_brake_led_state: repr_2010.electrical_parking_brake_led_state,
+            blind_spot_monitoring_led_state: repr_2010.blind_spot_monitoring_led_state,
+            ac_recirculation_state: repr_2010.ac_recirculation_state,
+            fuel_type: repr_2010.fuel_type,
+            stop_start_1: repr_2010.stop_start_1,
+            adaptive_cruise_control_led_state: repr_2010.adaptive_cruise_control_led_state,
+            preconditioning_reset: false,   // No equivalent on AEE2010.
+            preconditioning_request: false, // No equivalent on AEE2010.
+            ac_recirculation_state_request: false, // No equivalent on AEE2010.
+            over_speed_led_state: PushButtonLedState::Off, // No equivalent on AEE2010.
+            stop_start_2: repr_2010.stop_start_2,
+        }
     }
 }
 
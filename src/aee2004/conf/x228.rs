@@ -1,8 +1,12 @@
 use core::{cmp::Ordering, fmt};
 
-use time::Time;
+use time::{macros::time, Time};
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -23,6 +27,9 @@ pub const FRAME_ID: u16 = 0x228;
 /// Length of a x228 CAN frame.
 pub const FRAME_LEN: usize = field::MINUTE + 1;
 
+/// Transmission of a x228 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -104,10 +111,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x228 ({})", err)?;
                 Ok(())
@@ -122,10 +129,20 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x228 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub time: Time,
 }
 
@@ -149,9 +166,23 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x10, 0x2e],
+    parked: [0x00, 0x00] => Repr { time: time!(0:00) },
+    with_time => time: Time as Debug2Format,
+}
+
+/// Signals of a x228 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("time_hour", 0, 4, 5).unit("h"),
+    Signal::new("time_minute", 1, 5, 6).unit("min"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x228 time={}", self.time)
+        let sep = field_separator(f);
+        write!(f, "x228")?;
+        write!(f, "{}time={}", sep, self.time)
     }
 }
 
@@ -4,7 +4,14 @@ use time::Duration as TimeDuration;
 
 use byteorder::{ByteOrder, NetworkEndian};
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
+
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -32,6 +39,8 @@ pub const FRAME_LEN: usize = field::DURATION.end;
 
 /// Periodicity of a x261 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x261 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -138,10 +147,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x261 ({})", err)?;
                 Ok(())
@@ -156,16 +165,26 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x261 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub average_speed: u8,
     pub distance: u16,
     #[cfg(feature = "float")]
-    pub average_consumption: f32,
+    pub average_consumption: Physical,
     #[cfg(not(feature = "float"))]
     pub average_consumption: u16,
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub driving_duration: TimeDuration,
 }
 
@@ -173,15 +192,20 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             average_speed: frame.average_speed(),
             distance: frame.distance(),
             #[cfg(feature = "float")]
-            average_consumption: frame.average_consumption() as f32 / 10.0,
+            average_consumption: physical::from_raw(frame.average_consumption().into(), 0, 10),
             #[cfg(not(feature = "float"))]
             average_consumption: frame.average_consumption(),
             driving_duration: TimeDuration::minutes(frame.driving_duration().into()),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -194,30 +218,68 @@ impl Repr {
         frame.set_average_speed(self.average_speed);
         frame.set_distance(self.distance);
         #[cfg(feature = "float")]
-        frame.set_average_consumption((self.average_consumption * 10.0) as u16);
+        frame.set_average_consumption(physical::to_u16(self.average_consumption, 0, 10));
         #[cfg(not(feature = "float"))]
         frame.set_average_consumption(self.average_consumption);
         frame.set_driving_duration(self.driving_duration.whole_minutes() as u16);
     }
 }
 
+repr_builder! {
+    idle: [0x1d, 0x03, 0xe3, 0x00, 0x6b, 0x01, 0xe6],
+    with_average_speed => average_speed: u8,
+    with_distance => distance: u16,
+    #[cfg(feature = "float")]
+    with_average_consumption => average_consumption: Physical,
+    #[cfg(not(feature = "float"))]
+    with_average_consumption => average_consumption: u16,
+    with_driving_duration => driving_duration: TimeDuration as Debug2Format,
+}
+
+/// Signals of a x261 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("average_speed", 0, 7, 8).unit("km/h"),
+    Signal::new("distance", 1, 7, 16).unit("km"),
+    Signal::new("average_consumption", 3, 7, 16)
+        .scaled(0.1, 0.0)
+        .unit("l/100km"),
+    Signal::new("driving_duration", 5, 7, 16)
+        .scaled(60.0, 0.0)
+        .unit("s"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x261")?;
-        writeln!(f, " average_speed={}", self.average_speed)?;
-        writeln!(f, " distance={}", self.distance)?;
-        writeln!(f, " average_consumption={}", self.average_consumption)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x261")?;
+        write!(f, "{}average_speed={}", sep, self.average_speed)?;
+        write!(f, "{}distance={}", sep, self.distance)?;
+        write!(f, "{}average_consumption={}", sep, self.average_consumption)?;
+        write!(
             f,
-            " driving_duration={}",
+            "{}driving_duration={}",
+            sep,
             self.driving_duration.whole_minutes()
         )
     }
 }
 
+impl From<&crate::aee2010::infodiv::x261::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x261::Repr) -> Self {
+        Repr {
+            average_speed: repr_2010.average_speed,
+            distance: repr_2010.distance,
+            average_consumption: repr_2010.average_consumption,
+            driving_duration: TimeDuration::ZERO, // No equivalent on AEE2010.
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
     use time::Duration as TimeDuration;
 
     use crate::Error;
@@ -228,7 +290,7 @@ mod test {
         Repr {
             average_speed: 29,
             distance: 995,
-            average_consumption: 10.7,
+            average_consumption: physical::decimal(107, 10),
             driving_duration: TimeDuration::minutes(486),
         }
     }
@@ -1,9 +1,11 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
-        BootAndConvertibleRoofPosition, EnhancedTractionControlMode, FunctionState, SuspensionMode,
-        SuspensionMovement, SuspensionPosition,
+        BootAndConvertibleRoofPosition, EnhancedTractionControlMode, FunctionKind, FunctionState,
+        SuspensionMode, SuspensionMovement, SuspensionPosition,
     },
     Error, Result,
 };
@@ -49,6 +51,8 @@ pub const FRAME_LEN: usize = field::FN_4 + 1;
 
 /// Periodicity of a x2e1 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x2e1 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -96,6 +100,23 @@ impl<T: AsRef<[u8]>> Frame<T> {
         FRAME_LEN
     }
 
+    /// Return the state of the function `kind`.
+    pub fn function(&self, kind: FunctionKind) -> FunctionState {
+        match kind {
+            FunctionKind::AutomaticDoorLockingWhenDriving => {
+                self.auto_door_locking_when_driving_state()
+            }
+            FunctionKind::AutomaticHeadlamps => self.automatic_headlamps_state(),
+            FunctionKind::PassengerAirbag => self.passenger_airbag_state(),
+            FunctionKind::ParkingSensors => self.park_sensors_state(),
+            FunctionKind::AutomaticWipers => self.automatic_wipers_state(),
+            FunctionKind::Esp => self.esp_state(),
+            FunctionKind::DoorLocking => self.door_locking_state(),
+            FunctionKind::StopStart => self.stop_start_state(),
+            FunctionKind::RearDoorsChildLock => self.rear_doors_child_lock(),
+        }
+    }
+
     /// Return the automatic door locking when driving function state field.
     #[inline]
     pub fn auto_door_locking_when_driving_state(&self) -> FunctionState {
@@ -233,6 +254,23 @@ impl<T: AsRef<[u8]>> Frame<T> {
 }
 
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
+    /// Set the state of the function `kind`.
+    pub fn set_function(&mut self, kind: FunctionKind, state: FunctionState) {
+        match kind {
+            FunctionKind::AutomaticDoorLockingWhenDriving => {
+                self.set_auto_door_locking_when_driving_state(state)
+            }
+            FunctionKind::AutomaticHeadlamps => self.set_automatic_headlamps_state(state),
+            FunctionKind::PassengerAirbag => self.set_passenger_airbag_state(state),
+            FunctionKind::ParkingSensors => self.set_park_sensors_state(state),
+            FunctionKind::AutomaticWipers => self.set_automatic_wipers_state(state),
+            FunctionKind::Esp => self.set_esp_state(state),
+            FunctionKind::DoorLocking => self.set_door_locking_state(state),
+            FunctionKind::StopStart => self.set_stop_start_state(state),
+            FunctionKind::RearDoorsChildLock => self.set_rear_doors_child_lock(state),
+        }
+    }
+
     /// Set the automatic door locking when driving function state field.
     #[inline]
     pub fn set_auto_door_locking_when_driving_state(&mut self, value: FunctionState) {
@@ -390,10 +428,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x2e1 ({})", err)?;
                 Ok(())
@@ -408,9 +446,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x2e1 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub automatic_door_locking_when_driving_state: FunctionState,
     pub automatic_headlamps_state: FunctionState,
@@ -435,7 +479,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             automatic_door_locking_when_driving_state: frame.auto_door_locking_when_driving_state(),
             automatic_headlamps_state: frame.automatic_headlamps_state(),
             passenger_airbag_state: frame.passenger_airbag_state(),
@@ -453,7 +502,7 @@ impl Repr {
             current_settable_suspension_position: frame.current_settable_suspension_position(),
             enhanced_asr_state: frame.enhanced_asr_state(),
             settable_suspension_warning: frame.settable_suspension_warning(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -483,74 +532,283 @@ impl Repr {
         frame.set_enhanced_asr_state(self.enhanced_asr_state);
         frame.set_settable_suspension_warning(self.settable_suspension_warning);
     }
+
+    /// Return the state of the function `kind`.
+    pub fn function(&self, kind: FunctionKind) -> FunctionState {
+        *self.function_field(kind)
+    }
+
+    /// Set the state of the function `kind`.
+    pub fn set_function(&mut self, kind: FunctionKind, state: FunctionState) {
+        *self.function_field_mut(kind) = state;
+    }
+
+    /// Return an iterator over the functions and their states, in frame order.
+    pub fn functions(&self) -> impl Iterator<Item = (FunctionKind, FunctionState)> + '_ {
+        FunctionKind::ALL
+            .iter()
+            .map(move |&kind| (kind, self.function(kind)))
+    }
+
+    fn function_field(&self, kind: FunctionKind) -> &FunctionState {
+        match kind {
+            FunctionKind::AutomaticDoorLockingWhenDriving => {
+                &self.automatic_door_locking_when_driving_state
+            }
+            FunctionKind::AutomaticHeadlamps => &self.automatic_headlamps_state,
+            FunctionKind::PassengerAirbag => &self.passenger_airbag_state,
+            FunctionKind::ParkingSensors => &self.parking_sensors_state,
+            FunctionKind::AutomaticWipers => &self.automatic_wipers_state,
+            FunctionKind::Esp => &self.esp_state,
+            FunctionKind::DoorLocking => &self.door_locking_state,
+            FunctionKind::StopStart => &self.stop_start_state,
+            FunctionKind::RearDoorsChildLock => &self.rear_doors_child_lock,
+        }
+    }
+
+    fn function_field_mut(&mut self, kind: FunctionKind) -> &mut FunctionState {
+        match kind {
+            FunctionKind::AutomaticDoorLockingWhenDriving => {
+                &mut self.automatic_door_locking_when_driving_state
+            }
+            FunctionKind::AutomaticHeadlamps => &mut self.automatic_headlamps_state,
+            FunctionKind::PassengerAirbag => &mut self.passenger_airbag_state,
+            FunctionKind::ParkingSensors => &mut self.parking_sensors_state,
+            FunctionKind::AutomaticWipers => &mut self.automatic_wipers_state,
+            FunctionKind::Esp => &mut self.esp_state,
+            FunctionKind::DoorLocking => &mut self.door_locking_state,
+            FunctionKind::StopStart => &mut self.stop_start_state,
+            FunctionKind::RearDoorsChildLock => &mut self.rear_doors_child_lock,
+        }
+    }
+}
+
+repr_builder! {
+    idle: [0x77, 0xdc, 0x70, 0xfc, 0xf0],
+    with_automatic_door_locking_when_driving_state => automatic_door_locking_when_driving_state: FunctionState,
+    with_automatic_headlamps_state => automatic_headlamps_state: FunctionState,
+    with_passenger_airbag_state => passenger_airbag_state: FunctionState,
+    with_parking_sensors_state => parking_sensors_state: FunctionState,
+    with_settable_suspension_mode => settable_suspension_mode: SuspensionMode,
+    with_automatic_wipers_state => automatic_wipers_state: FunctionState,
+    with_esp_state => esp_state: FunctionState,
+    with_door_locking_state => door_locking_state: FunctionState,
+    with_boot_and_convertible_roof_position => boot_and_convertible_roof_position: BootAndConvertibleRoofPosition,
+    with_stop_start_state => stop_start_state: FunctionState,
+    with_rear_doors_child_lock => rear_doors_child_lock: FunctionState,
+    with_settable_suspension_movement_type => settable_suspension_movement_type: SuspensionMovement,
+    with_final_settable_suspension_position => final_settable_suspension_position: SuspensionPosition,
+    with_initial_settable_suspension_position => initial_settable_suspension_position: SuspensionPosition,
+    with_current_settable_suspension_position => current_settable_suspension_position: SuspensionPosition,
+    with_enhanced_asr_state => enhanced_asr_state: EnhancedTractionControlMode,
+    with_settable_suspension_warning => settable_suspension_warning: bool,
 }
 
+/// Signals of a x2e1 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("automatic_door_locking_when_driving_state", 0, 1, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("automatic_headlamps_state", 0, 3, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("passenger_airbag_state", 0, 5, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("parking_sensors_state", 0, 7, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("settable_suspension_mode", 1, 1, 2).values(&[
+        (0, "Absent"),
+        (1, "Sport"),
+        (2, "Normal"),
+    ]),
+    Signal::new("automatic_wipers_state", 1, 3, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("esp_state", 1, 5, 2).values(&[(0, "Absent"), (1, "Disabled"), (3, "Enabled")]),
+    Signal::new("door_locking_state", 1, 7, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("boot_and_convertible_roof_position", 2, 3, 3).values(&[
+        (0, "None"),
+        (1, "Coupe"),
+        (2, "OpenBootAndOpenRoof"),
+        (3, "OpenBootAndRoofInsideBoot"),
+        (4, "Convertible"),
+        (5, "OpenBootAndRoofClosed"),
+    ]),
+    Signal::new("stop_start_state", 2, 5, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("rear_doors_child_lock", 2, 7, 2).values(&[
+        (0, "Absent"),
+        (1, "Disabled"),
+        (3, "Enabled"),
+    ]),
+    Signal::new("settable_suspension_movement_type", 3, 1, 2).values(&[
+        (0, "Immobile"),
+        (1, "Ascent"),
+        (2, "Descent"),
+        (3, "Denied"),
+    ]),
+    Signal::new("final_settable_suspension_position", 3, 4, 3).values(&[
+        (0, "Normal"),
+        (1, "MidHigh"),
+        (2, "Low"),
+        (3, "High"),
+        (7, "None"),
+    ]),
+    Signal::new("initial_settable_suspension_position", 3, 7, 3).values(&[
+        (0, "Normal"),
+        (1, "MidHigh"),
+        (2, "Low"),
+        (3, "High"),
+        (7, "None"),
+    ]),
+    Signal::new("current_settable_suspension_position", 4, 7, 3).values(&[
+        (0, "Normal"),
+        (1, "MidHigh"),
+        (2, "Low"),
+        (3, "High"),
+        (7, "None"),
+    ]),
+    Signal::new("enhanced_asr_state", 4, 3, 3).values(&[
+        (0, "EspOff"),
+        (1, "Normal"),
+        (2, "Snow"),
+        (3, "Mud"),
+        (4, "Sand"),
+        (6, "ModeSelectorFault"),
+        (7, "SystemFault"),
+    ]),
+    Signal::new("settable_suspension_warning", 4, 4, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x2e1")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x2e1")?;
+        write!(
             f,
-            " auto_door_locking_when_driving_state={}",
-            self.automatic_door_locking_when_driving_state
+            "{}auto_door_locking_when_driving_state={}",
+            sep, self.automatic_door_locking_when_driving_state
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_headlamps_state={}",
-            self.automatic_headlamps_state
+            "{}automatic_headlamps_state={}",
+            sep, self.automatic_headlamps_state
         )?;
-        writeln!(f, " passenger_airbag_state={}", self.passenger_airbag_state)?;
-        writeln!(f, " park_sensors_state={}", self.parking_sensors_state)?;
-        writeln!(
+        write!(
             f,
-            " settable_suspension_mode={}",
-            self.settable_suspension_mode
+            "{}passenger_airbag_state={}",
+            sep, self.passenger_airbag_state
         )?;
-        writeln!(f, " automatic_wipers_state={}", self.automatic_wipers_state)?;
-        writeln!(f, " esp_state={}", self.esp_state)?;
-        writeln!(f, " door_locking={}", self.door_locking_state)?;
-        writeln!(
+        write!(
             f,
-            " boot_and_convertible_roof_position={}",
-            self.boot_and_convertible_roof_position
+            "{}park_sensors_state={}",
+            sep, self.parking_sensors_state
         )?;
-        writeln!(f, " stop_start_state={}", self.stop_start_state)?;
-        writeln!(f, " rear_doors_child_lock={}", self.rear_doors_child_lock)?;
-        writeln!(
+        write!(
             f,
-            " settable_suspension_movement_type={}",
-            self.settable_suspension_movement_type
+            "{}settable_suspension_mode={}",
+            sep, self.settable_suspension_mode
         )?;
-        writeln!(
+        write!(
             f,
-            " final_settable_suspension_position={}",
-            self.final_settable_suspension_position
+            "{}automatic_wipers_state={}",
+            sep, self.automatic_wipers_state
         )?;
-        writeln!(
+        write!(f, "{}esp_state={}", sep, self.esp_state)?;
+        write!(f, "{}door_locking={}", sep, self.door_locking_state)?;
+        write!(
             f,
-            " initial_settable_suspension_position={}",
-            self.initial_settable_suspension_position
+            "{}boot_and_convertible_roof_position={}",
+            sep, self.boot_and_convertible_roof_position
         )?;
-        writeln!(
+        write!(f, "{}stop_start_state={}", sep, self.stop_start_state)?;
+        write!(
             f,
-            " current_settable_suspension_position={}",
-            self.current_settable_suspension_position
+            "{}rear_doors_child_lock={}",
+            sep, self.rear_doors_child_lock
         )?;
-        writeln!(f, " enhanced_asr_state={}", self.enhanced_asr_state)?;
-        writeln!(
+        write!(
             f,
-            " settable_suspension_warning={}",
-            self.settable_suspension_warning
+            "{}settable_suspension_movement_type={}",
+            sep, self.settable_suspension_movement_type
+        )?;
+        write!(
+            f,
+            "{}final_settable_suspension_position={}",
+            sep, self.final_settable_suspension_position
+        )?;
+        write!(
+            f,
+            "{}initial_settable_suspension_position={}",
+            sep, self.initial_settable_suspension_position
+        )?;
+        write!(
+            f,
+            "{}current_settable_suspension_position={}",
+            sep, self.current_settable_suspension_position
+        )?;
+        write!(f, "{}enhanced_asr_state={}", sep, self.enhanced_asr_state)?;
+        write!(
+            f,
+            "{}settable_suspension_warning={}",
+            sep, self.settable_suspension_warning
         )
     }
 }
 
+impl From<&crate::aee2010::infodiv::x2e1::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x2e1::Repr) -> Self {
+        Repr {
+            automatic_door_locking_when_driving_state: repr_2010
+                .automatic_door_locking_when_driving_state,
+            automatic_headlamps_state: repr_2010.automatic_headlamps_state,
+            passenger_airbag_state: repr_2010.passenger_airbag_state,
+            parking_sensors_state: repr_2010.parking_sensors_state,
+            settable_suspension_mode: repr_2010.settable_suspension_mode,
+            automatic_wipers_state: repr_2010.automatic_wipers_state,
+            esp_state: repr_2010.esp_state,
+            door_locking_state: repr_2010.door_locking_state,
+            boot_and_convertible_roof_position: repr_2010.boot_and_convertible_roof_position,
+            stop_start_state: repr_2010.stop_start_state,
+            rear_doors_child_lock: repr_2010.rear_doors_child_lock,
+            settable_suspension_movement_type: repr_2010.settable_suspension_movement_type,
+            final_settable_suspension_position: repr_2010.final_settable_suspension_position,
+            initial_settable_suspension_position: repr_2010.initial_settable_suspension_position,
+            current_settable_suspension_position: repr_2010.current_settable_suspension_position,
+            enhanced_asr_state: repr_2010.enhanced_asr_state,
+            settable_suspension_warning: repr_2010.settable_suspension_warning,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use heapless::Vec;
+
     use super::{Frame, Repr};
     use crate::{
         vehicle::{
-            BootAndConvertibleRoofPosition, EnhancedTractionControlMode, FunctionState,
-            SuspensionMode, SuspensionMovement, SuspensionPosition,
+            BootAndConvertibleRoofPosition, EnhancedTractionControlMode, FunctionKind,
+            FunctionState, SuspensionMode, SuspensionMovement, SuspensionPosition,
         },
         Error,
     };
@@ -791,4 +1049,33 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_functions() {
+        let mut repr = frame_1_repr();
+        let enabled: Vec<FunctionKind, 9> = repr
+            .functions()
+            .filter(|&(_, state)| state == FunctionState::Enabled)
+            .map(|(kind, _)| kind)
+            .collect();
+        assert_eq!(
+            enabled,
+            [
+                FunctionKind::AutomaticDoorLockingWhenDriving,
+                FunctionKind::PassengerAirbag,
+                FunctionKind::AutomaticWipers,
+                FunctionKind::DoorLocking,
+                FunctionKind::StopStart,
+            ]
+        );
+
+        repr.set_function(FunctionKind::Esp, FunctionState::Enabled);
+        assert_eq!(repr.esp_state, FunctionState::Enabled);
+
+        let mut buf = REPR_FRAME_BYTES_1;
+        let mut frame = Frame::new_unchecked(&mut buf);
+        frame.set_function(FunctionKind::Esp, FunctionState::Enabled);
+        assert_eq!(frame.function(FunctionKind::Esp), FunctionState::Enabled);
+        assert_eq!(Repr::parse(&Frame::new_unchecked(&buf)).unwrap(), repr);
+    }
 }
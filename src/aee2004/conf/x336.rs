@@ -1,8 +1,12 @@
-use core::{cmp::Ordering, fmt};
+use core::{cmp::Ordering, fmt, time::Duration};
 
 use heapless::String;
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, DangerToken, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -25,6 +29,11 @@ pub const FRAME_ID: u16 = 0x336;
 /// Length of a x336 CAN frame.
 pub const FRAME_LEN: usize = field::WMI_3 + 1;
 
+/// Periodicity of a x336 CAN frame.
+pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x336 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -96,30 +105,30 @@ impl<T: AsRef<[u8]>> Frame<T> {
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     /// Set the WMI first char.
     #[inline]
-    pub fn set_wmi_first_char(&mut self, value: char) {
+    pub fn set_wmi_first_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::WMI_1] = value as u8;
     }
 
     /// Set the WMI second char.
     #[inline]
-    pub fn set_wmi_second_char(&mut self, value: char) {
+    pub fn set_wmi_second_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::WMI_2] = value as u8;
     }
 
     /// Set the WMI third char.
     #[inline]
-    pub fn set_wmi_third_char(&mut self, value: char) {
+    pub fn set_wmi_third_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::WMI_3] = value as u8;
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x336 ({})", err)?;
                 Ok(())
@@ -134,9 +143,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!(record);
+
 /// A high-level representation of a x336 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub wmi: String<3>,
 }
@@ -145,15 +160,27 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
+        // A non-ASCII char is encoded on several bytes, so it does not fit in the string.
+        if !frame.as_ref().is_ascii() {
+            return Err(Error::Invalid);
+        }
+
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked and whose chars are ASCII.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
         let mut wmi: String<3> = String::new();
-        wmi.push(frame.wmi_first_char())
-            .map_err(|_| Error::Invalid)?;
-        wmi.push(frame.wmi_second_char())
-            .map_err(|_| Error::Invalid)?;
-        wmi.push(frame.wmi_third_char())
-            .map_err(|_| Error::Invalid)?;
+        for c in [
+            frame.wmi_first_char(),
+            frame.wmi_second_char(),
+            frame.wmi_third_char(),
+        ] {
+            // An ASCII char is encoded on a single byte, so it always fits.
+            let _ = wmi.push(c);
+        }
 
-        Ok(Repr { wmi })
+        Repr { wmi }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -161,24 +188,49 @@ impl Repr {
         FRAME_LEN
     }
 
-    /// Emit a high-level representation into a x336 CAN frame.
-    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+    /// Emit a high-level representation into a x336 CAN frame, `token` acknowledging the
+    /// write of the VIN.
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>, token: DangerToken) {
         let mut wmi = self.wmi.clone();
-        frame.set_wmi_third_char(wmi.pop().unwrap_or(' '));
-        frame.set_wmi_second_char(wmi.pop().unwrap_or(' '));
-        frame.set_wmi_first_char(wmi.pop().unwrap_or(' '));
+        frame.set_wmi_third_char(wmi.pop().unwrap_or(' '), token);
+        frame.set_wmi_second_char(wmi.pop().unwrap_or(' '), token);
+        frame.set_wmi_first_char(wmi.pop().unwrap_or(' '), token);
     }
 }
 
+repr_builder! {
+    record,
+    idle: [0x56, 0x46, 0x37],
+    parked: [0x30, 0x30, 0x30],
+    with_wmi(token) => wmi: String<3>,
+}
+
+/// Signals of a x336 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("wmi_1", 0, 7, 8),
+    Signal::new("wmi_2", 1, 7, 8),
+    Signal::new("wmi_3", 2, 7, 8),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x336 wmi={}", self.wmi)
+        let sep = field_separator(f);
+        write!(f, "x336")?;
+        write!(f, "{}wmi={}", sep, self.wmi)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x336::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x336::Repr) -> Self {
+        Repr {
+            wmi: repr_2010.wmi.clone(),
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Frame, Repr};
+    use super::{DangerToken, Frame, Repr};
     use heapless::String;
 
     use crate::Error;
@@ -205,9 +257,9 @@ mod test {
         let mut bytes = [0u8; 3];
         let mut frame = Frame::new_unchecked(&mut bytes);
 
-        frame.set_wmi_first_char('V');
-        frame.set_wmi_second_char('F');
-        frame.set_wmi_third_char('7');
+        frame.set_wmi_first_char('V', DangerToken::unchecked());
+        frame.set_wmi_second_char('F', DangerToken::unchecked());
+        frame.set_wmi_third_char('7', DangerToken::unchecked());
 
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
@@ -239,7 +291,7 @@ mod test {
         let mut buf = [0u8; 3];
         let mut frame = Frame::new_unchecked(&mut buf);
         let repr = frame_repr();
-        repr.emit(&mut frame);
+        repr.emit(&mut frame, DangerToken::unchecked());
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
 }
@@ -1,7 +1,9 @@
-use core::{cmp::Ordering, fmt};
+use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
     config::{UnderInflationDetectionSystem, UserProfile},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result,
 };
 
@@ -61,6 +63,11 @@ pub const FRAME_ID: u16 = 0x361;
 /// Length of a x361 CAN frame.
 pub const FRAME_LEN: usize = field::OPT_5 + 1;
 
+/// Periodicity of a x361 CAN frame.
+pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x361 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -608,10 +615,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x361 ({})", err)?;
                 Ok(())
@@ -626,9 +633,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x361 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub profile_number: UserProfile,
     pub profile_change_allowed: bool,
@@ -667,7 +680,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             profile_number: frame.profile_number(),
             profile_change_allowed: frame.profile_change_allowed(),
             boot_permanent_locking_present: frame.boot_permanent_locking_presence(),
@@ -705,7 +723,7 @@ impl Repr {
             seat_belt_status_lamps_present: frame.seat_belt_status_lamps_presence(),
             under_inflation_detection: frame.under_inflation_detection(),
             blind_spot_audible_assistance_present: frame.blind_spot_audible_assistance_presence(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -762,142 +780,287 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x01, 0x00, 0x12, 0xe0, 0x30, 0x34],
+    with_profile_number => profile_number: UserProfile,
+    with_profile_change_allowed => profile_change_allowed: bool,
+    with_boot_permanent_locking_present => boot_permanent_locking_present: bool,
+    with_partial_window_opening_present => partial_window_opening_present: bool,
+    with_welcome_function_present => welcome_function_present: bool,
+    with_securoscope_present => securoscope_present: bool,
+    with_configurable_key_present => configurable_key_present: bool,
+    with_automatic_headlamps_present => automatic_headlamps_present: bool,
+    with_gear_efficiency_indicator_present => gear_efficiency_indicator_present: bool,
+    with_automatic_electric_parking_brake_application_present => automatic_electric_parking_brake_application_present: bool,
+    with_welcome_lighting_present => welcome_lighting_present: bool,
+    with_follow_me_home_present => follow_me_home_present: bool,
+    with_locking_mode_on_coe_present => locking_mode_on_coe_present: bool,
+    with_automatic_door_locking_when_leaving_present => automatic_door_locking_when_leaving_present: bool,
+    with_selective_unlocking_present => selective_unlocking_present: bool,
+    with_rear_wiper_in_reverse_gear_present => rear_wiper_in_reverse_gear_present: bool,
+    with_daytime_running_lamps_present => daytime_running_lamps_present: bool,
+    with_adaptive_lamps_present => adaptive_lamps_present: bool,
+    with_blind_spot_monitoring_inhibition_present => blind_spot_monitoring_inhibition_present: bool,
+    with_blind_spot_monitoring_present => blind_spot_monitoring_present: bool,
+    with_mood_lighting_present => mood_lighting_present: bool,
+    with_motorway_lighting_present => motorway_lighting_present: bool,
+    with_multi_function_display_present => multi_function_display_present: bool,
+    with_parking_sensors_inhibition_present => parking_sensors_inhibition_present: bool,
+    with_parking_sensors_audible_assistance_present => parking_sensors_audible_assistance_present: bool,
+    with_parking_sensors_visual_assistance_present => parking_sensors_visual_assistance_present: bool,
+    with_automatic_emergency_braking_present => automatic_emergency_braking_present: bool,
+    with_under_inflation_detection_reset_menu_present => under_inflation_detection_reset_menu_present: bool,
+    with_seat_belt_status_lamps_present => seat_belt_status_lamps_present: bool,
+    with_under_inflation_detection => under_inflation_detection: UnderInflationDetectionSystem,
+    with_blind_spot_audible_assistance_present => blind_spot_audible_assistance_present: bool,
+}
+
+/// Signals of a x361 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("profile_number", 0, 2, 3).values(&[
+        (0, "None"),
+        (1, "Profile1"),
+        (2, "Profile2"),
+        (4, "Profile3"),
+        (7, "Default"),
+    ]),
+    Signal::new("profile_change_allowed", 0, 3, 1),
+    Signal::new("boot_permanent_locking_present", 1, 0, 1),
+    Signal::new("partial_window_opening_present", 1, 1, 1),
+    Signal::new("welcome_function_present", 1, 2, 1),
+    Signal::new("securoscope_present", 1, 3, 1),
+    Signal::new("configurable_key_present", 1, 4, 1),
+    Signal::new("automatic_headlamps_present", 2, 0, 1),
+    Signal::new("gear_efficiency_indicator_present", 2, 1, 1),
+    Signal::new(
+        "automatic_electric_parking_brake_application_present",
+        2,
+        2,
+        1,
+    ),
+    Signal::new("welcome_lighting_present", 2, 3, 1),
+    Signal::new("follow_me_home_present", 2, 4, 1),
+    Signal::new("locking_mode_on_coe_present", 2, 5, 1),
+    Signal::new("automatic_door_locking_when_leaving_present", 2, 6, 1),
+    Signal::new("selective_unlocking_present", 2, 7, 1),
+    Signal::new("rear_wiper_in_reverse_gear_present", 3, 5, 1),
+    Signal::new("daytime_running_lamps_present", 3, 6, 1),
+    Signal::new("adaptive_lamps_present", 3, 7, 1),
+    Signal::new("blind_spot_monitoring_inhibition_present", 4, 0, 1),
+    Signal::new("blind_spot_monitoring_present", 4, 1, 1),
+    Signal::new("mood_lighting_present", 4, 2, 1),
+    Signal::new("motorway_lighting_present", 4, 3, 1),
+    Signal::new("multi_function_display_present", 4, 4, 1),
+    Signal::new("parking_sensors_inhibition_present", 4, 5, 1),
+    Signal::new("parking_sensors_audible_assistance_present", 4, 6, 1),
+    Signal::new("parking_sensors_visual_assistance_present", 4, 7, 1),
+    Signal::new("automatic_emergency_braking_present", 5, 1, 1),
+    Signal::new("under_inflation_detection_reset_menu_present", 5, 2, 1),
+    Signal::new("seat_belt_status_lamps_present", 5, 3, 1),
+    Signal::new("under_inflation_detection", 5, 6, 3).values(&[
+        (0, "None"),
+        (1, "DirectWithAbsolutePressure"),
+        (2, "DirectWithoutAbsolutePressure"),
+        (3, "Indirect"),
+        (4, "IndirectBorgWarner"),
+    ]),
+    Signal::new("blind_spot_audible_assistance_present", 5, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x361 profile_number={}", self.profile_number)?;
-        writeln!(f, " profile_change_allowed={}", self.profile_change_allowed)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x361")?;
+        write!(f, "{}profile_number={}", sep, self.profile_number)?;
+        write!(
+            f,
+            "{}profile_change_allowed={}",
+            sep, self.profile_change_allowed
+        )?;
+        write!(
             f,
-            " boot_permanent_locking_present={}",
-            self.boot_permanent_locking_present
+            "{}boot_permanent_locking_present={}",
+            sep, self.boot_permanent_locking_present
         )?;
-        writeln!(
+        write!(
             f,
-            " partial_window_opening_present={}",
-            self.partial_window_opening_present
+            "{}partial_window_opening_present={}",
+            sep, self.partial_window_opening_present
         )?;
-        writeln!(
+        write!(
             f,
-            " welcome_function_present={}",
-            self.welcome_function_present
+            "{}welcome_function_present={}",
+            sep, self.welcome_function_present
         )?;
-        writeln!(f, " securoscope_present={}", self.securoscope_present)?;
-        writeln!(
+        write!(f, "{}securoscope_present={}", sep, self.securoscope_present)?;
+        write!(
             f,
-            " configurable_key_present={}",
-            self.configurable_key_present
+            "{}configurable_key_present={}",
+            sep, self.configurable_key_present
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_headlamps_present={}",
-            self.automatic_headlamps_present
+            "{}automatic_headlamps_present={}",
+            sep, self.automatic_headlamps_present
         )?;
-        writeln!(
+        write!(
             f,
-            " gear_efficiency_indicator_present={}",
-            self.gear_efficiency_indicator_present
+            "{}gear_efficiency_indicator_present={}",
+            sep, self.gear_efficiency_indicator_present
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_electric_parking_brake_application_present={}",
-            self.automatic_electric_parking_brake_application_present
+            "{}automatic_electric_parking_brake_application_present={}",
+            sep, self.automatic_electric_parking_brake_application_present
         )?;
-        writeln!(
+        write!(
             f,
-            " welcome_lighting_present={}",
-            self.welcome_lighting_present
+            "{}welcome_lighting_present={}",
+            sep, self.welcome_lighting_present
         )?;
-        writeln!(f, " follow_me_home_present={}", self.follow_me_home_present)?;
-        writeln!(
+        write!(
             f,
-            " locking_mode_on_coe_present={}",
-            self.locking_mode_on_coe_present
+            "{}follow_me_home_present={}",
+            sep, self.follow_me_home_present
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_door_locking_when_leaving_present={}",
-            self.automatic_door_locking_when_leaving_present
+            "{}locking_mode_on_coe_present={}",
+            sep, self.locking_mode_on_coe_present
         )?;
-        writeln!(
+        write!(
             f,
-            " selective_unlocking_present={}",
-            self.selective_unlocking_present
+            "{}automatic_door_locking_when_leaving_present={}",
+            sep, self.automatic_door_locking_when_leaving_present
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_wiper_in_reverse_gear_present={}",
-            self.rear_wiper_in_reverse_gear_present
+            "{}selective_unlocking_present={}",
+            sep, self.selective_unlocking_present
         )?;
-        writeln!(
+        write!(
             f,
-            " daytime_running_lamps_present={}",
-            self.daytime_running_lamps_present
+            "{}rear_wiper_in_reverse_gear_present={}",
+            sep, self.rear_wiper_in_reverse_gear_present
         )?;
-        writeln!(f, " adaptive_lamps_present={}", self.adaptive_lamps_present)?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_monitoring_inhibition_present={}",
-            self.blind_spot_monitoring_inhibition_present
+            "{}daytime_running_lamps_present={}",
+            sep, self.daytime_running_lamps_present
         )?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_monitoring_present={}",
-            self.blind_spot_monitoring_present
+            "{}adaptive_lamps_present={}",
+            sep, self.adaptive_lamps_present
         )?;
-        writeln!(f, " mood_lighting_present={}", self.mood_lighting_present)?;
-        writeln!(
+        write!(
             f,
-            " motorway_lighting_present={}",
-            self.motorway_lighting_present
+            "{}blind_spot_monitoring_inhibition_present={}",
+            sep, self.blind_spot_monitoring_inhibition_present
         )?;
-        writeln!(
+        write!(
             f,
-            " multi_function_display_present={}",
-            self.multi_function_display_present
+            "{}blind_spot_monitoring_present={}",
+            sep, self.blind_spot_monitoring_present
         )?;
-        writeln!(
+        write!(
             f,
-            " parking_sensors_inhibition_present={}",
-            self.parking_sensors_inhibition_present
+            "{}mood_lighting_present={}",
+            sep, self.mood_lighting_present
         )?;
-        writeln!(
+        write!(
             f,
-            " parking_sensors_audible_assistance_present={}",
-            self.parking_sensors_audible_assistance_present
+            "{}motorway_lighting_present={}",
+            sep, self.motorway_lighting_present
         )?;
-        writeln!(
+        write!(
             f,
-            " parking_sensors_visual_assistance_present={}",
-            self.parking_sensors_visual_assistance_present
+            "{}multi_function_display_present={}",
+            sep, self.multi_function_display_present
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_emergency_braking_present={}",
-            self.automatic_emergency_braking_present
+            "{}parking_sensors_inhibition_present={}",
+            sep, self.parking_sensors_inhibition_present
         )?;
-        writeln!(
+        write!(
             f,
-            " under_inflation_detection_reset_menu_present={}",
-            self.under_inflation_detection_reset_menu_present
+            "{}parking_sensors_audible_assistance_present={}",
+            sep, self.parking_sensors_audible_assistance_present
         )?;
-        writeln!(
+        write!(
             f,
-            " seat_belt_status_lamps_present={}",
-            self.seat_belt_status_lamps_present
+            "{}parking_sensors_visual_assistance_present={}",
+            sep, self.parking_sensors_visual_assistance_present
         )?;
-        writeln!(
+        write!(
             f,
-            " under_inflation_detection={}",
-            self.under_inflation_detection
+            "{}automatic_emergency_braking_present={}",
+            sep, self.automatic_emergency_braking_present
         )?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_audible_assistance_present={}",
-            self.blind_spot_audible_assistance_present
+            "{}under_inflation_detection_reset_menu_present={}",
+            sep, self.under_inflation_detection_reset_menu_present
+        )?;
+        write!(
+            f,
+            "{}seat_belt_status_lamps_present={}",
+            sep, self.seat_belt_status_lamps_present
+        )?;
+        write!(
+            f,
+            "{}under_inflation_detection={}",
+            sep, self.under_inflation_detection
+        )?;
+        write!(
+            f,
+            "{}blind_spot_audible_assistance_present={}",
+            sep, self.blind_spot_audible_assistance_present
         )
     }
 }
 
+impl From<&crate::aee2010::infodiv::x361::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x361::Repr) -> Self {
+        Repr {
+            profile_number: UserProfile::None,     // No equivalent on AEE2010.
+            profile_change_allowed: false,         // No equivalent on AEE2010.
+            boot_permanent_locking_present: false, // No equivalent on AEE2010.
+            partial_window_opening_present: false, // No equivalent on AEE2010.
+            welcome_function_present: repr_2010.welcome_function_present,
+            securoscope_present: false, // No equivalent on AEE2010.
+            configurable_key_present: repr_2010.configurable_key_present,
+            automatic_headlamps_present: repr_2010.automatic_headlamps_present,
+            gear_efficiency_indicator_present: repr_2010.gear_efficiency_indicator_present,
+            automatic_electric_parking_brake_application_present: repr_2010
+                .automatic_electric_parking_brake_application_present,
+            welcome_lighting_present: repr_2010.welcome_lighting_present,
+            follow_me_home_present: repr_2010.follow_me_home_present,
+            locking_mode_on_coe_present: false, // No equivalent on AEE2010.
+            automatic_door_locking_when_leaving_present: false, // No equivalent on AEE2010.
+            selective_unlocking_present: repr_2010.selective_unlocking_present,
+            rear_wiper_in_reverse_gear_present: repr_2010.rear_wiper_in_reverse_gear_present,
+            daytime_running_lamps_present: repr_2010.daytime_running_lamps_present,
+            adaptive_lamps_present: repr_2010.adaptive_lamps_present,
+            blind_spot_monitoring_inhibition_present: false, // No equivalent on AEE2010.
+            blind_spot_monitoring_present: repr_2010.blind_spot_monitoring_present,
+            mood_lighting_present: repr_2010.mood_lighting_present,
+            motorway_lighting_present: repr_2010.motorway_lighting_present,
+            multi_function_display_present: false, // No equivalent on AEE2010.
+            parking_sensors_inhibition_present: repr_2010.parking_sensors_inhibition_present,
+            parking_sensors_audible_assistance_present: false, // No equivalent on AEE2010.
+            parking_sensors_visual_assistance_present: false,  // No equivalent on AEE2010.
+            automatic_emergency_braking_present: repr_2010.automatic_emergency_braking_present,
+            under_inflation_detection_reset_menu_present: repr_2010
+                .under_inflation_detection_reset_menu_present,
+            seat_belt_status_lamps_present: repr_2010.seat_belt_status_lamps_present,
+            under_inflation_detection: repr_2010.under_inflation_detection,
+            blind_spot_audible_assistance_present: false, // No equivalent on AEE2010.
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
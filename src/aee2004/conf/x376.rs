@@ -1,9 +1,15 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use byteorder::{ByteOrder, NetworkEndian};
-use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+use time::{macros::datetime, Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
 
-use crate::{config::DisplayMode, Error, Result, YEAR_OFFSET};
+use crate::{
+    config::DisplayMode,
+    datetime::CanDateTime,
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result, YEAR_OFFSET,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -35,6 +41,8 @@ pub const FRAME_LEN: usize = field::MINUTE_CLOCK_DISP_MODE + 1;
 
 /// Periodicity of a x376 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x376 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -127,6 +135,18 @@ impl<T: AsRef<[u8]>> Frame<T> {
         let data = self.buffer.as_ref();
         data[field::MINUTE_CLOCK_DISP_MODE] >> 2
     }
+
+    /// Return the date and time fields.
+    #[inline]
+    pub fn datetime(&self) -> CanDateTime {
+        CanDateTime {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+        }
+    }
 }
 
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
@@ -183,12 +203,22 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
         let raw = raw | (value << 2);
         data[field::MINUTE_CLOCK_DISP_MODE] = raw;
     }
+
+    /// Set the date and time fields.
+    #[inline]
+    pub fn set_datetime(&mut self, value: CanDateTime) {
+        self.set_year(value.year);
+        self.set_month(value.month);
+        self.set_day(value.day);
+        self.set_hour(value.hour);
+        self.set_minute(value.minute);
+    }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x376 ({})", err)?;
                 Ok(())
@@ -203,11 +233,21 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x376 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub clock_disp_mode: DisplayMode,
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub utc_datetime: OffsetDateTime,
 }
 
@@ -254,17 +294,48 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x2c, 0x63, 0xd4, 0x62],
+    parked: [0x00, 0x10, 0x40, 0x00] => Repr {
+        clock_disp_mode: DisplayMode::Steady,
+        utc_datetime: datetime!(2000-01-01 0:00 UTC),
+    },
+    with_clock_disp_mode => clock_disp_mode: DisplayMode,
+    with_utc_datetime => utc_datetime: OffsetDateTime as Debug2Format,
+}
+
+/// Signals of a x376 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("clock_disp_mode", 3, 1, 1).values(&[(0, "Steady"), (1, "Blinking")]),
+    Signal::new("utc_datetime_year", 0, 7, 7).scaled(1.0, 2000.0),
+    Signal::new("utc_datetime_month", 1, 7, 4),
+    Signal::new("utc_datetime_day", 1, 3, 6),
+    Signal::new("utc_datetime_hour", 2, 5, 5).unit("h"),
+    Signal::new("utc_datetime_minute", 3, 7, 6).unit("min"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, " clock_disp_mode={}", self.clock_disp_mode)?;
-        writeln!(f, " utc_datetime={}", self.utc_datetime)
+        let sep = field_separator(f);
+        write!(f, "x376")?;
+        write!(f, "{}clock_disp_mode={}", sep, self.clock_disp_mode)?;
+        write!(f, "{}utc_datetime={}", sep, self.utc_datetime)
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x276::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x276::Repr) -> Self {
+        Repr {
+            clock_disp_mode: repr_2010.clock_disp_mode,
+            utc_datetime: repr_2010.utc_datetime,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
-    use crate::{config::DisplayMode, Error};
+    use crate::{config::DisplayMode, datetime::CanDateTime, Error};
 
     use time::macros::datetime;
 
@@ -287,6 +358,10 @@ mod test {
         assert_eq!(frame.month(), 6);
         assert_eq!(frame.clock_display_mode(), DisplayMode::Blinking);
         assert_eq!(frame.minute(), 24);
+        assert_eq!(
+            frame.datetime(),
+            CanDateTime::new(22, 6, 15, 10, 24).unwrap()
+        );
     }
 
     #[test]
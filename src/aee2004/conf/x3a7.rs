@@ -4,6 +4,8 @@ use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
     config::{DisplayMode, MaintenanceDisplayMode, MaintenanceType},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result,
 };
 
@@ -47,6 +49,8 @@ pub const FRAME_LEN: usize = field::DISP_DURATION + 1;
 
 /// Periodicity of a x3a7 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x3a7 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -256,10 +260,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x3a7 ({})", err)?;
                 Ok(())
@@ -274,9 +278,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x3a7 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub wrench_standard_display_mode: MaintenanceDisplayMode,
     pub wrench_maintenance_display_mode: MaintenanceDisplayMode,
@@ -292,6 +302,11 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
         let mut remaining_days: i32 = frame.remaining_days().into();
         let mut remaining_distance: i32 = frame.remaining_distance().into();
 
@@ -303,7 +318,7 @@ impl Repr {
             remaining_days = -remaining_days;
         }
 
-        Ok(Repr {
+        Repr {
             wrench_standard_display_mode: frame.wrench_standard_display_mode(),
             wrench_maintenance_display_mode: frame.wrench_maintenance_display_mode(),
             maintenance_type: frame.maintenance_type(),
@@ -312,7 +327,7 @@ impl Repr {
             remaining_distance: remaining_distance * 20,
             remaining_days,
             display_duration: Duration::from_secs(frame.display_duration().into()),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -329,39 +344,94 @@ impl Repr {
         frame.set_distance_counter_display_mode(self.distance_counter_display_mode);
         frame.set_time_counter_negative(self.remaining_days < 0);
         frame.set_time_counter_display_mode(self.time_counter_display_mode);
-        frame.set_remaining_distance((self.remaining_distance / 20) as u16);
-        frame.set_remaining_days(self.remaining_days as u16);
+        frame.set_remaining_distance((self.remaining_distance.unsigned_abs() / 20) as u16);
+        frame.set_remaining_days(self.remaining_days.unsigned_abs() as u16);
         frame.set_display_duration(self.display_duration.as_secs() as u8);
     }
 }
 
+repr_builder! {
+    idle: [0x20, 0x20, 0x20, 0x00, 0x05, 0x00, 0x0a, 0x14],
+    with_wrench_standard_display_mode => wrench_standard_display_mode: MaintenanceDisplayMode,
+    with_wrench_maintenance_display_mode => wrench_maintenance_display_mode: MaintenanceDisplayMode,
+    with_maintenance_type => maintenance_type: MaintenanceType,
+    with_distance_counter_display_mode => distance_counter_display_mode: DisplayMode,
+    with_time_counter_display_mode => time_counter_display_mode: DisplayMode,
+    with_remaining_distance => remaining_distance: i32,
+    with_remaining_days => remaining_days: i32,
+    with_display_duration => display_duration: Duration,
+}
+
+/// Signals of a x3a7 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("wrench_standard_display_mode", 0, 3, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("wrench_maintenance_display_mode", 0, 5, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("maintenance_type", 0, 7, 1).values(&[(0, "Distance"), (1, "Time")]),
+    Signal::new("distance_counter_display_mode", 1, 5, 1).values(&[(0, "Steady"), (1, "Blinking")]),
+    Signal::new("time_counter_display_mode", 2, 5, 1).values(&[(0, "Steady"), (1, "Blinking")]),
+    Signal::new("remaining_distance", 3, 7, 16).unit("km"),
+    Signal::new("remaining_distance_negative", 1, 7, 1),
+    Signal::new("remaining_days", 5, 7, 16).unit("d"),
+    Signal::new("remaining_days_negative", 2, 7, 1),
+    Signal::new("display_duration", 7, 7, 8).unit("s"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x3a7")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x3a7")?;
+        write!(
             f,
-            " wrench_standard_display_mode={}",
-            self.wrench_standard_display_mode
+            "{}wrench_standard_display_mode={}",
+            sep, self.wrench_standard_display_mode
         )?;
-        writeln!(
+        write!(
             f,
-            " wrench_maintenance_display_mode={}",
-            self.wrench_maintenance_display_mode
+            "{}wrench_maintenance_display_mode={}",
+            sep, self.wrench_maintenance_display_mode
         )?;
-        writeln!(f, " maintenance_type={}", self.maintenance_type)?;
-        writeln!(
+        write!(f, "{}maintenance_type={}", sep, self.maintenance_type)?;
+        write!(
             f,
-            " distance_counter_display_mode={}",
-            self.distance_counter_display_mode
+            "{}distance_counter_display_mode={}",
+            sep, self.distance_counter_display_mode
         )?;
-        writeln!(
+        write!(
             f,
-            " time_counter_display_mode={}",
-            self.time_counter_display_mode
+            "{}time_counter_display_mode={}",
+            sep, self.time_counter_display_mode
         )?;
-        writeln!(f, " remaining_distance={}", self.remaining_distance)?;
-        writeln!(f, " remaining_days={}", self.remaining_days)?;
-        writeln!(f, " display_duration={}", self.display_duration.as_secs())
+        write!(f, "{}remaining_distance={}", sep, self.remaining_distance)?;
+        write!(f, "{}remaining_days={}", sep, self.remaining_days)?;
+        write!(
+            f,
+            "{}display_duration={}",
+            sep,
+            self.display_duration.as_secs()
+        )
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x3e7::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x3e7::Repr) -> Self {
+        Repr {
+            wrench_standard_display_mode: MaintenanceDisplayMode::Off, // No equivalent on AEE2010.
+            wrench_maintenance_display_mode: MaintenanceDisplayMode::Off, // No equivalent on AEE2010.
+            maintenance_type: MaintenanceType::Distance, // No equivalent on AEE2010.
+            distance_counter_display_mode: repr_2010.distance_counter_display_mode,
+            time_counter_display_mode: DisplayMode::Steady, // No equivalent on AEE2010.
+            remaining_distance: repr_2010.remaining_distance,
+            remaining_days: repr_2010.remaining_days,
+            display_duration: Duration::ZERO, // No equivalent on AEE2010.
+        }
     }
 }
 
@@ -461,4 +531,17 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
+
+    #[test]
+    fn test_from_aee2010() {
+        let repr_2010 = crate::aee2010::infodiv::x3e7::Repr::builder()
+            .with_remaining_distance(-1500)
+            .with_remaining_days(-12)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2010);
+        assert_eq!(repr.remaining_distance, -1500);
+        assert_eq!(repr.remaining_days, -12);
+        assert_eq!(repr.maintenance_type, MaintenanceType::Distance);
+    }
 }
@@ -3,6 +3,8 @@ use core::{cmp::Ordering, fmt, time::Duration};
 use time::Duration as TimeDuration;
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{EngineState, StopAndStartSystemState, TractionBatteryChargeState},
     Error, Result,
 };
@@ -41,6 +43,8 @@ pub const FRAME_LEN: usize = field::BATT_CHARGE + 1;
 
 /// Periodicity of a x3e1 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x3e1 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -239,10 +243,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x3e1 ({})", err)?;
                 Ok(())
@@ -257,10 +261,20 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x3e1 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub stop_start_stopped_duration: TimeDuration,
     pub electrical_engine_state: EngineState,
     pub petrol_engine_state: EngineState,
@@ -277,19 +291,24 @@ impl Repr {
         if frame.stop_start_seconds_stopped() >= 60 || frame.stop_start_minutes_stopped() >= 60 {
             Err(Error::Invalid)
         } else {
-            let stt_duration = TimeDuration::seconds(frame.stop_start_seconds_stopped().into())
-                + TimeDuration::minutes(frame.stop_start_minutes_stopped().into())
-                + TimeDuration::hours(frame.stop_start_hours_stopped().into());
-
-            Ok(Repr {
-                stop_start_stopped_duration: stt_duration,
-                electrical_engine_state: frame.electrical_engine_state(),
-                petrol_engine_state: frame.petrol_engine_state(),
-                zero_emission_request: frame.zero_emission_request(),
-                stop_start_presence: frame.stop_and_start_present(),
-                stop_start_state: frame.stop_and_start_state(),
-                traction_battery_charge_state: frame.traction_battery_charge_state(),
-            })
+            Ok(Repr::decode(frame))
+        }
+    }
+
+    /// Decode a frame whose length and duration fields are checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        let stt_duration = TimeDuration::seconds(frame.stop_start_seconds_stopped().into())
+            + TimeDuration::minutes(frame.stop_start_minutes_stopped().into())
+            + TimeDuration::hours(frame.stop_start_hours_stopped().into());
+
+        Repr {
+            stop_start_stopped_duration: stt_duration,
+            electrical_engine_state: frame.electrical_engine_state(),
+            petrol_engine_state: frame.petrol_engine_state(),
+            zero_emission_request: frame.zero_emission_request(),
+            stop_start_presence: frame.stop_and_start_present(),
+            stop_start_state: frame.stop_and_start_state(),
+            traction_battery_charge_state: frame.traction_battery_charge_state(),
         }
     }
 
@@ -326,32 +345,89 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x02, 0xe0, 0x00, 0x64, 0x40],
+    with_stop_start_stopped_duration => stop_start_stopped_duration: TimeDuration as Debug2Format,
+    with_electrical_engine_state => electrical_engine_state: EngineState,
+    with_petrol_engine_state => petrol_engine_state: EngineState,
+    with_zero_emission_request => zero_emission_request: bool,
+    with_stop_start_presence => stop_start_presence: bool,
+    with_stop_start_state => stop_start_state: StopAndStartSystemState,
+    with_traction_battery_charge_state => traction_battery_charge_state: TractionBatteryChargeState,
+}
+
+/// Signals of a x3e1 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("stop_start_stopped_duration", 0, 7, 14).unit("s"),
+    Signal::new("electrical_engine_state", 3, 1, 2).values(&[
+        (0, "Disabled"),
+        (1, "Driving"),
+        (2, "Braking"),
+    ]),
+    Signal::new("petrol_engine_state", 3, 3, 2).values(&[
+        (0, "Disabled"),
+        (1, "Driving"),
+        (2, "Braking"),
+    ]),
+    Signal::new("zero_emission_request", 3, 4, 1),
+    Signal::new("stop_start_presence", 3, 5, 1),
+    Signal::new("stop_start_state", 3, 7, 2).values(&[
+        (0, "Unavailable"),
+        (1, "Enabled"),
+        (2, "Disabled"),
+    ]),
+    Signal::new("traction_battery_charge_state", 4, 7, 2).values(&[
+        (0, "Disabled"),
+        (1, "Recharge"),
+        (2, "Discharge"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x3e1")?;
-
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x3e1")?;
+        write!(
             f,
-            " stop_start_stopped_duration={}",
+            "{}stop_start_stopped_duration={}",
+            sep,
             self.stop_start_stopped_duration.whole_seconds()
         )?;
-        writeln!(
+        write!(
             f,
-            " electrical_engine_state={}",
-            self.electrical_engine_state
+            "{}electrical_engine_state={}",
+            sep, self.electrical_engine_state
         )?;
-        writeln!(f, " petrol_engine_state={}", self.petrol_engine_state)?;
-        writeln!(f, " zero_emission_request={}", self.zero_emission_request)?;
-        writeln!(f, " stop_start_presence={}", self.stop_start_presence)?;
-        writeln!(f, " stop_start_state={}", self.stop_start_state)?;
-        writeln!(
+        write!(f, "{}petrol_engine_state={}", sep, self.petrol_engine_state)?;
+        write!(
             f,
-            " traction_battery_charge_state={}",
-            self.traction_battery_charge_state
+            "{}zero_emission_request={}",
+            sep, self.zero_emission_request
+        )?;
+        write!(f, "{}stop_start_presence={}", sep, self.stop_start_presence)?;
+        write!(f, "{}stop_start_state={}", sep, self.stop_start_state)?;
+        write!(
+            f,
+            "{}traction_battery_charge_state={}",
+            sep, self.traction_battery_charge_state
         )
     }
 }
 
+impl From<&crate::aee2010::infodiv::x3e1::Repr> for Repr {
+    fn from(repr_2010: &crate::aee2010::infodiv::x3e1::Repr) -> Self {
+        Repr {
+            stop_start_stopped_duration: repr_2010.stop_start_stopped_duration,
+            electrical_engine_state: repr_2010.electrical_engine_state,
+            petrol_engine_state: repr_2010.petrol_engine_state,
+            zero_emission_request: repr_2010.zero_emission_request,
+            stop_start_presence: repr_2010.stop_start_presence,
+            stop_start_state: repr_2010.stop_start_state,
+            traction_battery_charge_state: repr_2010.traction_battery_charge_state,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
@@ -3,7 +3,12 @@ use core::{cmp::Ordering, fmt};
 use byteorder::{ByteOrder, NetworkEndian};
 use time::Duration;
 
-use crate::{config::*, Error, Result};
+use crate::{
+    config::*,
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -38,6 +43,9 @@ pub const FRAME_ID: u16 = 0x3f6;
 /// Length of a x3f6 CAN frame.
 pub const FRAME_LEN: usize = field::LANGUAGE + 1;
 
+/// Transmission of a x3f6 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Unknown;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -292,10 +300,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x3f6 ({})", err)?;
                 Ok(())
@@ -310,10 +318,20 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x3f6 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub running_duration: Duration,
     pub distance_unit: DistanceUnit,
     pub volume_unit: VolumeUnit,
@@ -336,22 +354,27 @@ impl Repr {
         {
             Err(Error::Invalid)
         } else {
-            let running_duration = Duration::seconds(frame.running_seconds().into())
-                + Duration::days(frame.running_days().into())
-                + Duration::days(365 * i64::from(frame.running_years()));
-
-            Ok(Repr {
-                running_duration,
-                distance_unit: frame.distance_unit(),
-                volume_unit: frame.volume_unit(),
-                consumption_unit: frame.consumption_unit(),
-                pressure_unit: frame.pressure_unit(),
-                display_charset: frame.display_charset(),
-                temperature_unit: frame.temperature_unit(),
-                display_mode: frame.display_mode(),
-                clock_format: frame.clock_format(),
-                language: frame.language(),
-            })
+            Ok(Repr::decode(frame))
+        }
+    }
+
+    /// Decode a frame whose length and running duration fields are checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        let running_duration = Duration::seconds(frame.running_seconds().into())
+            + Duration::days(frame.running_days().into())
+            + Duration::days(365 * i64::from(frame.running_years()));
+
+        Repr {
+            running_duration,
+            distance_unit: frame.distance_unit(),
+            volume_unit: frame.volume_unit(),
+            consumption_unit: frame.consumption_unit(),
+            pressure_unit: frame.pressure_unit(),
+            display_charset: frame.display_charset(),
+            temperature_unit: frame.temperature_unit(),
+            display_mode: frame.display_mode(),
+            clock_format: frame.clock_format(),
+            language: frame.language(),
         }
     }
 
@@ -393,22 +416,73 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x00, 0xde, 0x80, 0x17, 0x00, 0x80, 0x04],
+    with_running_duration => running_duration: Duration as Debug2Format,
+    with_distance_unit => distance_unit: DistanceUnit,
+    with_volume_unit => volume_unit: VolumeUnit,
+    with_consumption_unit => consumption_unit: ConsumptionUnit,
+    with_pressure_unit => pressure_unit: PressureUnit,
+    with_display_charset => display_charset: DisplayCharset,
+    with_temperature_unit => temperature_unit: TemperatureUnit,
+    with_display_mode => display_mode: DisplayColorMode,
+    with_clock_format => clock_format: ClockFormat,
+    with_language => language: Language,
+}
+
+/// Signals of a x3f6 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("running_duration_seconds", 0, 7, 20).unit("s"),
+    Signal::new("running_duration_days", 3, 6, 7).unit("d"),
+    Signal::new("running_duration_years", 4, 7, 8).unit("y"),
+    Signal::new("distance_unit", 5, 0, 1).values(&[(0, "Kilometer"), (1, "Mile")]),
+    Signal::new("volume_unit", 5, 1, 1).values(&[(0, "Liter"), (1, "Gallon")]),
+    Signal::new("consumption_unit", 5, 2, 1)
+        .values(&[(0, "VolumePerDistance"), (1, "DistancePerVolume")]),
+    Signal::new("pressure_unit", 5, 3, 1).values(&[(0, "Bar"), (1, "PSI")]),
+    Signal::new("display_charset", 5, 4, 1).values(&[(0, "ASCII"), (1, "UTF8")]),
+    Signal::new("temperature_unit", 5, 5, 1).values(&[(0, "Celsius"), (1, "Fahrenheit")]),
+    Signal::new("display_mode", 5, 6, 1).values(&[(0, "Negative"), (1, "Positive")]),
+    Signal::new("clock_format", 5, 7, 1).values(&[(0, "H12"), (1, "H24")]),
+    Signal::new("language", 6, 3, 4).values(&[
+        (0, "French"),
+        (1, "English"),
+        (2, "German"),
+        (3, "Spanish"),
+        (4, "Italian"),
+        (5, "Portuguese"),
+        (6, "Dutch"),
+        (7, "Greek"),
+        (8, "BrazilianPortuguese"),
+        (9, "Polish"),
+        (10, "TraditionalChinese"),
+        (11, "SimplifiedChinese"),
+        (12, "Turkish"),
+        (13, "Japanese"),
+        (14, "Russian"),
+        (15, "Invalid"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x3f6")?;
+        write!(
             f,
-            "x3f6 running_duration={}",
+            "{}running_duration={}",
+            sep,
             self.running_duration.whole_seconds()
         )?;
-        writeln!(f, " distance_unit={}", self.distance_unit)?;
-        writeln!(f, " volume_unit={}", self.volume_unit)?;
-        writeln!(f, " consumption_unit={}", self.consumption_unit)?;
-        writeln!(f, " pressure_unit={}", self.pressure_unit)?;
-        writeln!(f, " display_charset={}", self.display_charset)?;
-        writeln!(f, " temperature_unit={}", self.temperature_unit)?;
-        writeln!(f, " display_mode={}", self.display_mode)?;
-        writeln!(f, " time_format={}", self.clock_format)?;
-        writeln!(f, " language={}", self.language)
+        write!(f, "{}distance_unit={}", sep, self.distance_unit)?;
+        write!(f, "{}volume_unit={}", sep, self.volume_unit)?;
+        write!(f, "{}consumption_unit={}", sep, self.consumption_unit)?;
+        write!(f, "{}pressure_unit={}", sep, self.pressure_unit)?;
+        write!(f, "{}display_charset={}", sep, self.display_charset)?;
+        write!(f, "{}temperature_unit={}", sep, self.temperature_unit)?;
+        write!(f, "{}display_mode={}", sep, self.display_mode)?;
+        write!(f, "{}time_format={}", sep, self.clock_format)?;
+        write!(f, "{}language={}", sep, self.language)
     }
 }
 
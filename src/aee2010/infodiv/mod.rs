@@ -105,3 +105,123 @@ pub use x3e1 as ID_INFOS_STT_ET_HY;
 
 pub mod x3e7;
 pub use x3e7 as ID_INFOS_MAINTENANCE_EV;
+
+frame_message! {
+    /// A high-level representation of any supported AEE2010 infodiv CAN frame.
+    pub enum Message {
+        X036(x036),
+        X0b6(x0b6),
+        X0e6(x0e6),
+        X0f6(x0f6),
+        X122(x122),
+        X128(x128),
+        X15b(x15b),
+        X167(x167),
+        X168(x168),
+        X1a5(x1a5),
+        X1a8(x1a8),
+        X1a9(x1a9),
+        X1d0(x1d0),
+        X1e1(x1e1),
+        X1e5(x1e5),
+        X221(x221),
+        X227(x227),
+        X228(x228),
+        X236(x236),
+        X260(x260),
+        X261(x261),
+        X276(x276),
+        X2a1(x2a1),
+        X2a8(x2a8),
+        X2ad(x2ad),
+        X2b6(x2b6),
+        X2e1(x2e1),
+        X329(x329),
+        X336(x336),
+        X350(x350),
+        X361(x361),
+        X39b(x39b),
+        X3b6(x3b6),
+        X3d0(x3d0),
+        X3e1(x3e1),
+        X3e7(x3e7),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{x0b6, x0f6, Message};
+
+    use crate::{
+        frame::DangerToken,
+        transport::slcan::{CanFrame, Id},
+        Error,
+    };
+
+    static X0B6_FRAME_BYTES: [u8; 8] = [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0];
+
+    #[test]
+    fn test_parse_valid() {
+        let message = Message::parse(x0b6::FRAME_ID, &X0B6_FRAME_BYTES).unwrap();
+        let repr = x0b6::Repr::parse(&x0b6::Frame::new_unchecked(&X0B6_FRAME_BYTES)).unwrap();
+        assert_eq!(message, Message::X0b6(repr));
+        assert_eq!(message, Message::from(repr));
+        assert_eq!(message.frame_id(), x0b6::FRAME_ID);
+        assert_eq!(message.buffer_len(), x0b6::FRAME_LEN);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Message::parse(0x7ff, &X0B6_FRAME_BYTES),
+            Err(Error::Unrecognized)
+        );
+        assert_eq!(
+            Message::parse(x0b6::FRAME_ID, &X0B6_FRAME_BYTES[..7]),
+            Err(Error::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_emit() {
+        let message = Message::parse(x0b6::FRAME_ID, &X0B6_FRAME_BYTES).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(message.emit(&mut buf), Ok(8));
+        assert_eq!(buf, X0B6_FRAME_BYTES);
+
+        let mut buf = [0u8; 4];
+        assert_eq!(message.emit(&mut buf), Err(Error::Exhausted));
+    }
+
+    #[test]
+    fn test_can_frame() {
+        let frame = CanFrame::new(x0b6::FRAME_ID, &X0B6_FRAME_BYTES).unwrap();
+        let message = Message::from_can_frame(&frame).unwrap();
+        assert_eq!(message.frame_id(), x0b6::FRAME_ID);
+        assert_eq!(message.to_can_frame(), Ok(frame));
+
+        let remote = CanFrame::new_remote(Id::Standard(x0b6::FRAME_ID), 8).unwrap();
+        assert_eq!(Message::from_can_frame(&remote), Err(Error::Unrecognized));
+        let extended =
+            CanFrame::new_data(Id::Extended(x0b6::FRAME_ID.into()), &X0B6_FRAME_BYTES).unwrap();
+        assert_eq!(Message::from_can_frame(&extended), Err(Error::Unrecognized));
+    }
+
+    #[test]
+    fn test_record_frames() {
+        let message = Message::parse(x0f6::FRAME_ID, &x0f6::IDLE_FRAME_BYTES).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(message.emit(&mut buf), Err(Error::Illegal));
+        assert_eq!(message.to_can_frame(), Err(Error::Illegal));
+
+        let token = DangerToken::unchecked();
+        assert_eq!(message.emit_acknowledged(&mut buf, token), Ok(8));
+        assert_eq!(buf, x0f6::IDLE_FRAME_BYTES);
+        let frame = CanFrame::new(x0f6::FRAME_ID, &x0f6::IDLE_FRAME_BYTES).unwrap();
+        assert_eq!(message.to_can_frame_acknowledged(token), Ok(frame));
+
+        let message = Message::parse(x0b6::FRAME_ID, &X0B6_FRAME_BYTES).unwrap();
+        assert_eq!(message.emit_acknowledged(&mut buf, token), Ok(8));
+        assert_eq!(buf, X0B6_FRAME_BYTES);
+    }
+}
@@ -1,8 +1,23 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
-use byteorder::{ByteOrder, NetworkEndian};
-
-use crate::{vehicle::SpeedValidity, Error, Result};
+use crate::{
+    field::{read_be, write_be},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    vehicle::SpeedValidity,
+    Error, Result,
+};
+
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
+
+#[cfg(feature = "uom")]
+use uom::si::{
+    f32::{Length, Velocity, Volume},
+    length::centimeter,
+    velocity::kilometer_per_hour,
+    volume::cubic_millimeter,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -43,6 +58,8 @@ pub const FRAME_LEN: usize = field::VALIDITY + 1;
 
 /// Periodicity of a x0b6 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(50);
+/// Transmission of a x0b6 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -94,21 +111,21 @@ impl<T: AsRef<[u8]>> Frame<T> {
     #[inline]
     pub fn engine_rpm(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::ENGINE_RPM])
+        read_be(data, field::ENGINE_RPM.start, 7, 16) as u16
     }
 
     /// Return the vehicle immediate speed measured on the driving wheels field, in 0.01 km/h.
     #[inline]
     pub fn vehicle_immediate_speed(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::VEHICLE_SPD])
+        read_be(data, field::VEHICLE_SPD.start, 7, 16) as u16
     }
 
     /// Return the odometer value since start of vehicle field, incremented at each distance top.
     #[inline]
     pub fn trip_odometer(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::ODOMETER])
+        read_be(data, field::ODOMETER.start, 7, 16) as u16
     }
 
     /// Return the fuel consumption since start of vehicle field.
@@ -123,7 +140,7 @@ impl<T: AsRef<[u8]>> Frame<T> {
     pub fn speed_validity(&self) -> SpeedValidity {
         let data = self.buffer.as_ref();
         let raw = (data[field::VALIDITY] & 0x78) >> 3;
-        SpeedValidity::from(raw)
+        SpeedValidity::lookup(raw)
     }
 
     /// Return the vehicle immediate speed value validity flag.
@@ -132,6 +149,34 @@ impl<T: AsRef<[u8]>> Frame<T> {
         let data = self.buffer.as_ref();
         data[field::VALIDITY] & 0x80 != 0
     }
+
+    /// Return the engine speed, in revolutions per minute.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn engine_speed(&self) -> Physical {
+        physical::from_raw(self.engine_rpm().into(), 0, 10)
+    }
+
+    /// Return the vehicle immediate speed measured on the driving wheels, in km/h.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn vehicle_speed(&self) -> Physical {
+        physical::from_raw(self.vehicle_immediate_speed().into(), 0, 100)
+    }
+
+    /// Return the distance traveled since start of vehicle, in meters.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn trip_distance(&self) -> Physical {
+        physical::from_raw(self.trip_odometer().into(), 0, 100)
+    }
+
+    /// Return the fuel consumed since start of vehicle, in milliliters.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn trip_fuel_volume(&self) -> Physical {
+        physical::from_raw(self.trip_fuel_consumption().into(), 0, 1000)
+    }
 }
 
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
@@ -139,21 +184,21 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_engine_rpm(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::ENGINE_RPM], value);
+        write_be(data, field::ENGINE_RPM.start, 7, 16, value.into());
     }
 
     /// Set the vehicle immediate speed measured on the driving wheels field, in 0.01 km/h.
     #[inline]
     pub fn set_vehicle_immediate_speed(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::VEHICLE_SPD], value);
+        write_be(data, field::VEHICLE_SPD.start, 7, 16, value.into());
     }
 
     /// Set the odometer value since start of vehicle field, incremented at each distance top.
     #[inline]
     pub fn set_trip_odometer(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::ODOMETER], value);
+        write_be(data, field::ODOMETER.start, 7, 16, value.into());
     }
 
     /// Set the fuel consumption since start of vehicle field.
@@ -180,12 +225,60 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
         let raw = if value { raw | 0x80 } else { raw & !0x80 };
         data[field::VALIDITY] = raw;
     }
+
+    /// Set the engine speed, in revolutions per minute.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the 0 to 6553.5 rpm range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_engine_speed(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, 0, 10)
+            .and_then(|raw| u16::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_engine_rpm(raw);
+        Ok(())
+    }
+
+    /// Set the vehicle immediate speed measured on the driving wheels, in km/h.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the 0 to 655.35 km/h range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_vehicle_speed(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, 0, 100)
+            .and_then(|raw| u16::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_vehicle_immediate_speed(raw);
+        Ok(())
+    }
+
+    /// Set the distance traveled since start of vehicle, in meters.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the 0 to 655.35 meters range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_trip_distance(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, 0, 100)
+            .and_then(|raw| u16::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_trip_odometer(raw);
+        Ok(())
+    }
+
+    /// Set the fuel consumed since start of vehicle, in milliliters.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the 0 to 0.255 milliliters range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_trip_fuel_volume(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, 0, 1000)
+            .and_then(|raw| u8::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_trip_fuel_consumption(raw);
+        Ok(())
+    }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x0b6 ({})", err)?;
                 Ok(())
@@ -200,16 +293,22 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x0b6 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     #[cfg(feature = "float")]
-    pub engine_rpm: f32,
+    pub engine_rpm: Physical,
     #[cfg(not(feature = "float"))]
     pub engine_rpm: u16,
     #[cfg(feature = "float")]
-    pub vehicle_immediate_speed: f32,
+    pub vehicle_immediate_speed: Physical,
     #[cfg(not(feature = "float"))]
     pub vehicle_immediate_speed: u16,
     pub trip_odometer: u16,
@@ -219,38 +318,54 @@ pub struct Repr {
 }
 
 impl Repr {
+    /// Parse a x0b6 CAN frame into a high-level representation.
+    ///
+    /// x0b6 is the highest rate frame of the network. The `frame` benchmarks of
+    /// `benches/throughput.rs` measure about 5ns per parse on an x86_64 Xeon, with or without
+    /// the `float` feature, and 20ns per emit with `float` against 2ns without it: the range
+    /// checks of the physical value scaling dominate the emit cost. Cores without an FPU
+    /// emulate the `f32` scaling in software, enable `fixed` to decode fixed-point numbers
+    /// instead or disable `float` to keep the raw integer units on such targets.
+    #[inline]
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             #[cfg(feature = "float")]
-            engine_rpm: frame.engine_rpm() as f32 / 10.0,
+            engine_rpm: frame.engine_speed(),
             #[cfg(not(feature = "float"))]
             engine_rpm: frame.engine_rpm(),
             #[cfg(feature = "float")]
-            vehicle_immediate_speed: frame.vehicle_immediate_speed() as f32 / 100.0,
+            vehicle_immediate_speed: frame.vehicle_speed(),
             #[cfg(not(feature = "float"))]
             vehicle_immediate_speed: frame.vehicle_immediate_speed(),
             trip_odometer: frame.trip_odometer(),
             trip_fuel_consumption: frame.trip_fuel_consumption(),
             speed_validity: frame.speed_validity(),
             immediate_speed_validity: frame.immediate_speed_validity(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
+    #[inline]
     pub fn buffer_len(&self) -> usize {
         FRAME_LEN
     }
 
     /// Emit a high-level representation into a x0b6 CAN frame.
+    #[inline]
     pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
         #[cfg(feature = "float")]
-        frame.set_engine_rpm((self.engine_rpm * 10.0) as u16);
+        frame.set_engine_rpm(physical::to_u16(self.engine_rpm, 0, 10));
         #[cfg(not(feature = "float"))]
         frame.set_engine_rpm(self.engine_rpm);
         #[cfg(feature = "float")]
-        frame.set_vehicle_immediate_speed((self.vehicle_immediate_speed * 100.0) as u16);
+        frame.set_vehicle_immediate_speed(physical::to_u16(self.vehicle_immediate_speed, 0, 100));
         #[cfg(not(feature = "float"))]
         frame.set_vehicle_immediate_speed(self.vehicle_immediate_speed);
         frame.set_trip_odometer(self.trip_odometer);
@@ -260,36 +375,110 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0],
+    parked: [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xd0],
+    #[cfg(feature = "float")]
+    with_engine_rpm => engine_rpm: Physical,
+    #[cfg(not(feature = "float"))]
+    with_engine_rpm => engine_rpm: u16,
+    #[cfg(feature = "float")]
+    with_vehicle_immediate_speed => vehicle_immediate_speed: Physical,
+    #[cfg(not(feature = "float"))]
+    with_vehicle_immediate_speed => vehicle_immediate_speed: u16,
+    with_trip_odometer => trip_odometer: u16,
+    with_trip_fuel_consumption => trip_fuel_consumption: u8,
+    with_speed_validity => speed_validity: SpeedValidity,
+    with_immediate_speed_validity => immediate_speed_validity: bool,
+}
+
+/// Signals of a x0b6 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("engine_rpm", 0, 7, 16)
+        .scaled(0.1, 0.0)
+        .unit("rpm"),
+    Signal::new("vehicle_immediate_speed", 2, 7, 16)
+        .scaled(0.01, 0.0)
+        .unit("km/h"),
+    Signal::new("trip_odometer", 4, 7, 16).unit("cm"),
+    Signal::new("trip_fuel_consumption", 6, 7, 8).unit("mm3"),
+    Signal::new("speed_validity", 7, 6, 4).values(&[(10, "Valid")]),
+    Signal::new("immediate_speed_validity", 7, 7, 1),
+];
+
+#[cfg(feature = "uom")]
+impl Repr {
+    /// Return the vehicle immediate speed as a typed quantity.
+    pub fn vehicle_immediate_speed_quantity(&self) -> Velocity {
+        #[cfg(feature = "float")]
+        let value = physical::to_f32(self.vehicle_immediate_speed);
+        #[cfg(not(feature = "float"))]
+        let value = self.vehicle_immediate_speed as f32 / 100.0;
+        Velocity::new::<kilometer_per_hour>(value)
+    }
+
+    /// Return the distance traveled since start of vehicle as a typed quantity.
+    pub fn trip_odometer_quantity(&self) -> Length {
+        Length::new::<centimeter>(self.trip_odometer as f32)
+    }
+
+    /// Return the fuel consumption since start of vehicle as a typed quantity.
+    pub fn trip_fuel_consumption_quantity(&self) -> Volume {
+        Volume::new::<cubic_millimeter>(self.trip_fuel_consumption as f32)
+    }
+}
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x0b6 engine_rpm={}", self.engine_rpm)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x0b6")?;
+        write!(f, "{}engine_rpm={}", sep, self.engine_rpm)?;
+        write!(
             f,
-            " vehicle_immediate_speed={}",
-            self.vehicle_immediate_speed
+            "{}vehicle_immediate_speed={}",
+            sep, self.vehicle_immediate_speed
         )?;
-        writeln!(f, " trip_odometer={}", self.trip_odometer)?;
-        writeln!(f, " trip_fuel_consumption={}", self.trip_fuel_consumption)?;
-        writeln!(f, " speed_validity={}", self.speed_validity)?;
-        writeln!(
+        write!(f, "{}trip_odometer={}", sep, self.trip_odometer)?;
+        write!(
             f,
-            " immediate_speed_validity={}",
-            self.immediate_speed_validity
+            "{}trip_fuel_consumption={}",
+            sep, self.trip_fuel_consumption
+        )?;
+        write!(f, "{}speed_validity={}", sep, self.speed_validity)?;
+        write!(
+            f,
+            "{}immediate_speed_validity={}",
+            sep, self.immediate_speed_validity
         )
     }
 }
 
+impl From<&crate::aee2004::conf::x0b6::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x0b6::Repr) -> Self {
+        Repr {
+            engine_rpm: repr_2004.engine_rpm,
+            vehicle_immediate_speed: repr_2004.vehicle_immediate_speed,
+            trip_odometer: repr_2004.trip_odometer,
+            trip_fuel_consumption: repr_2004.trip_fuel_consumption,
+            speed_validity: repr_2004.speed_validity,
+            immediate_speed_validity: repr_2004.immediate_speed_validity,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
     use crate::{vehicle::SpeedValidity, Error};
 
     static REPR_FRAME_BYTES_1: [u8; 8] = [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0];
 
     fn frame_1_repr() -> Repr {
         Repr {
-            engine_rpm: 631.1,
-            vehicle_immediate_speed: 0.0,
+            engine_rpm: physical::decimal(6311, 10),
+            vehicle_immediate_speed: physical::decimal(0, 10),
             trip_odometer: 0,
             trip_fuel_consumption: 66,
             speed_validity: SpeedValidity::Valid,
@@ -340,6 +529,26 @@ mod test {
         assert_eq!(Frame::new_checked(&bytes).unwrap_err(), Error::Truncated);
     }
 
+    #[test]
+    fn test_padded() {
+        let mut bytes = [0xcc; 12];
+        bytes[..8].copy_from_slice(&REPR_FRAME_BYTES_1);
+        assert_eq!(Frame::new_checked(&bytes).unwrap_err(), Error::Overlong);
+        let frame = Frame::new_padded(&bytes).unwrap();
+        assert_eq!(frame.payload(), &REPR_FRAME_BYTES_1);
+        assert_eq!(frame.padding(), &[0xcc; 4]);
+        assert_eq!(Repr::parse(&frame), Err(Error::Overlong));
+        assert_eq!(Repr::parse_padded(&frame), Ok(frame_1_repr()));
+        assert_eq!(
+            Repr::parse_padded(&Frame::new_unchecked(&bytes[..7])),
+            Err(Error::Truncated)
+        );
+        assert_eq!(
+            Frame::new_padded(&bytes[..7]).unwrap_err(),
+            Error::Truncated
+        );
+    }
+
     #[test]
     fn test_repr_1_parse_valid() {
         let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
@@ -355,4 +564,91 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_1);
     }
+
+    #[cfg(feature = "uom")]
+    #[test]
+    fn test_repr_1_quantities() {
+        use uom::si::{length::centimeter, velocity::kilometer_per_hour, volume::cubic_millimeter};
+
+        let repr = frame_1_repr();
+        assert_eq!(
+            repr.vehicle_immediate_speed_quantity()
+                .get::<kilometer_per_hour>(),
+            0.0
+        );
+        assert_eq!(repr.trip_odometer_quantity().get::<centimeter>(), 0.0);
+        assert_eq!(
+            repr.trip_fuel_consumption_quantity()
+                .get::<cubic_millimeter>(),
+            66.0
+        );
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_physical_accessors() {
+        // Idle engine, vehicle stopped.
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
+        assert_eq!(frame.engine_speed(), physical::decimal(6311, 10));
+        assert_eq!(frame.vehicle_speed(), physical::decimal(0, 1));
+        assert_eq!(frame.trip_distance(), physical::decimal(0, 1));
+        assert_eq!(frame.trip_fuel_volume(), physical::decimal(66, 1000));
+
+        // Cruising at 70 km/h.
+        let bytes: [u8; 8] = [0x2e, 0xe0, 0x1b, 0x58, 0x12, 0x34, 0x9a, 0xd0];
+        let frame = Frame::new_unchecked(&bytes);
+        assert_eq!(frame.engine_speed(), physical::decimal(1200, 1));
+        assert_eq!(frame.vehicle_speed(), physical::decimal(70, 1));
+        assert_eq!(frame.trip_distance(), physical::decimal(4660, 100));
+        assert_eq!(frame.trip_fuel_volume(), physical::decimal(154, 1000));
+
+        let mut buffer = [0x00; 8];
+        let mut frame = Frame::new_unchecked(&mut buffer);
+        assert_eq!(frame.set_engine_speed(physical::decimal(1200, 1)), Ok(()));
+        assert_eq!(frame.set_vehicle_speed(physical::decimal(70, 1)), Ok(()));
+        assert_eq!(
+            frame.set_trip_distance(physical::decimal(4660, 100)),
+            Ok(())
+        );
+        assert_eq!(
+            frame.set_trip_fuel_volume(physical::decimal(154, 1000)),
+            Ok(())
+        );
+        frame.set_speed_validity(SpeedValidity::Valid);
+        frame.set_immediate_speed_validity(true);
+        assert_eq!(frame.into_inner(), &bytes);
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_physical_accessors_out_of_range() {
+        let mut buffer = REPR_FRAME_BYTES_1;
+        let mut frame = Frame::new_unchecked(&mut buffer);
+        assert_eq!(
+            frame.set_engine_speed(physical::decimal(65536, 10)),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            frame.set_vehicle_speed(physical::decimal(-1, 100)),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            frame.set_trip_distance(physical::decimal(65536, 100)),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            frame.set_trip_fuel_volume(physical::decimal(256, 1000)),
+            Err(Error::Invalid)
+        );
+        #[cfg(not(feature = "fixed"))]
+        assert_eq!(frame.set_vehicle_speed(f32::NAN), Err(Error::Invalid));
+        assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_1);
+    }
+
+    #[test]
+    fn test_speed_validity_lookup() {
+        for raw in 0..=u8::MAX {
+            assert_eq!(SpeedValidity::lookup(raw), SpeedValidity::from_raw(raw));
+        }
+    }
 }
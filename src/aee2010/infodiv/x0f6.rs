@@ -1,12 +1,24 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
-use byteorder::{ByteOrder, NetworkEndian};
-
 use crate::{
+    field::{read_be, write_be},
+    frame::{field_separator, DangerToken, TransmissionKind},
+    signal::Signal,
+    units::Kilometers,
     vehicle::{BlinkersStatus, MainStatus, PowertrainStatus, SteeringWheelPosition, VsmConfigMode},
     Error, Result,
 };
 
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
+
+#[cfg(feature = "uom")]
+use uom::si::{
+    f32::{Length, ThermodynamicTemperature},
+    length::kilometer,
+    thermodynamic_temperature::degree_celsius,
+};
+
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -64,6 +76,8 @@ pub const FRAME_LEN: usize = field::FLAGS + 1;
 
 /// Periodicity of a x0f6 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x0f6 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -160,7 +174,7 @@ impl<T: AsRef<[u8]>> Frame<T> {
     #[inline]
     pub fn odometer(&self) -> u32 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u24(&data[field::ODOMETER])
+        read_be(data, field::ODOMETER.start, 7, 24) as u32
     }
 
     /// Return the external temperature value, in 0.5 celsius with a +40 offset.
@@ -177,6 +191,27 @@ impl<T: AsRef<[u8]>> Frame<T> {
         data[field::EXT_TEMP_FILTERED]
     }
 
+    /// Return the engine coolant temperature, in celsius.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn coolant_temperature(&self) -> Physical {
+        physical::from_raw(self.coolant_temp().into(), -40, 1)
+    }
+
+    /// Return the external temperature, in celsius.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn external_temperature(&self) -> Physical {
+        physical::from_raw(self.external_temp().into(), -80, 2)
+    }
+
+    /// Return the filtered external temperature, in celsius.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn external_temperature_filtered(&self) -> Physical {
+        physical::from_raw(self.external_temp_filtered().into(), -80, 2)
+    }
+
     /// Return the blinkers status field.
     #[inline]
     pub fn blinkers_status(&self) -> BlinkersStatus {
@@ -270,9 +305,9 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
 
     /// Set the odometer value, in 0.1 kilometers.
     #[inline]
-    pub fn set_odometer(&mut self, value: u32) {
+    pub fn set_odometer(&mut self, value: u32, _token: DangerToken) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u24(&mut data[field::ODOMETER], value);
+        write_be(data, field::ODOMETER.start, 7, 24, value.into());
     }
 
     /// Set the external temperature value, in 0.5 celsius with a +40 offset.
@@ -289,6 +324,42 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
         data[field::EXT_TEMP_FILTERED] = value;
     }
 
+    /// Set the engine coolant temperature, in celsius.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the -40 to 215 celsius range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_coolant_temperature(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, -40, 1)
+            .and_then(|raw| u8::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_coolant_temp(raw);
+        Ok(())
+    }
+
+    /// Set the external temperature, in celsius.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the -40 to 87.5 celsius range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_external_temperature(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, -80, 2)
+            .and_then(|raw| u8::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_external_temp(raw);
+        Ok(())
+    }
+
+    /// Set the filtered external temperature, in celsius.
+    /// Returns `Err(Error::Invalid)` if `value` is out of the -40 to 87.5 celsius range.
+    #[cfg(feature = "float")]
+    #[inline]
+    pub fn set_external_temperature_filtered(&mut self, value: Physical) -> Result<()> {
+        let raw = physical::to_raw(value, -80, 2)
+            .and_then(|raw| u8::try_from(raw).ok())
+            .ok_or(Error::Invalid)?;
+        self.set_external_temp_filtered(raw);
+        Ok(())
+    }
+
     /// Set the blinkers status field.
     #[inline]
     pub fn set_blinkers_status(&mut self, value: BlinkersStatus) {
@@ -335,10 +406,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x0f6 ({})", err)?;
                 Ok(())
@@ -353,9 +424,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!(record);
+
 /// A high-level representation of a x0f6 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub powertrain_status: PowertrainStatus,
     pub generator_working: bool,
@@ -363,19 +440,19 @@ pub struct Repr {
     pub factory_park_enabled: bool,
     pub vsm_config_mode: VsmConfigMode,
     #[cfg(feature = "float")]
-    pub coolant_temperature: f32,
+    pub coolant_temperature: Physical,
     #[cfg(not(feature = "float"))]
     pub coolant_temperature: u8,
     #[cfg(feature = "float")]
-    pub odometer: f32,
+    pub odometer: Physical,
     #[cfg(not(feature = "float"))]
     pub odometer: u32,
     #[cfg(feature = "float")]
-    pub external_temperature: f32,
+    pub external_temperature: Physical,
     #[cfg(not(feature = "float"))]
     pub external_temperature: u8,
     #[cfg(feature = "float")]
-    pub external_temperature_filtered: f32,
+    pub external_temperature_filtered: Physical,
     #[cfg(not(feature = "float"))]
     pub external_temperature_filtered: u8,
     pub blinkers_status: BlinkersStatus,
@@ -389,26 +466,31 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             powertrain_status: frame.powertrain_status(),
             generator_working: frame.generator_working(),
             vehicle_main_status: frame.vehicle_main_status(),
             factory_park_enabled: frame.factory_park(),
             vsm_config_mode: frame.vsm_config_mode(),
             #[cfg(feature = "float")]
-            coolant_temperature: frame.coolant_temp() as f32 - 40.0,
+            coolant_temperature: frame.coolant_temperature(),
             #[cfg(not(feature = "float"))]
             coolant_temperature: frame.coolant_temp(),
             #[cfg(feature = "float")]
-            odometer: (frame.odometer() as f32 / 10.0),
+            odometer: physical::from_raw(frame.odometer().into(), 0, 10),
             #[cfg(not(feature = "float"))]
             odometer: frame.odometer(),
             #[cfg(feature = "float")]
-            external_temperature: (frame.external_temp() as f32 / 2.0) - 40.0,
+            external_temperature: frame.external_temperature(),
             #[cfg(not(feature = "float"))]
             external_temperature: frame.external_temp(),
             #[cfg(feature = "float")]
-            external_temperature_filtered: (frame.external_temp_filtered() as f32 / 2.0) - 40.0,
+            external_temperature_filtered: frame.external_temperature_filtered(),
             #[cfg(not(feature = "float"))]
             external_temperature_filtered: frame.external_temp_filtered(),
             blinkers_status: frame.blinkers_status(),
@@ -416,7 +498,7 @@ impl Repr {
             steering_wheel_position: frame.steering_wheel_pos(),
             front_wiping_acknowledge: frame.front_wiping_ack(),
             reverse_gear_engaged: frame.reverse_gear_engaged(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -424,27 +506,32 @@ impl Repr {
         FRAME_LEN
     }
 
-    /// Emit a high-level representation into a x0f6 CAN frame.
-    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+    /// Emit a high-level representation into a x0f6 CAN frame, `token` acknowledging the
+    /// write of the odometer.
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>, token: DangerToken) {
         frame.set_powertrain_status(self.powertrain_status);
         frame.set_generator_working(self.generator_working);
         frame.set_vehicle_main_status(self.vehicle_main_status);
         frame.set_factory_park(self.factory_park_enabled);
         frame.set_vsm_config_mode(self.vsm_config_mode);
         #[cfg(feature = "float")]
-        frame.set_coolant_temp((self.coolant_temperature + 40.0) as u8);
+        frame.set_coolant_temp(physical::to_u8(self.coolant_temperature, -40, 1));
         #[cfg(not(feature = "float"))]
         frame.set_coolant_temp(self.coolant_temperature);
         #[cfg(feature = "float")]
-        frame.set_odometer((self.odometer * 10.0) as u32);
+        frame.set_odometer(physical::to_u32(self.odometer, 0, 10), token);
         #[cfg(not(feature = "float"))]
-        frame.set_odometer(self.odometer);
+        frame.set_odometer(self.odometer, token);
         #[cfg(feature = "float")]
-        frame.set_external_temp(((self.external_temperature + 40.0) * 2.0) as u8);
+        frame.set_external_temp(physical::to_u8(self.external_temperature, -80, 2));
         #[cfg(not(feature = "float"))]
         frame.set_external_temp(self.external_temperature);
         #[cfg(feature = "float")]
-        frame.set_external_temp_filtered(((self.external_temperature_filtered + 40.0) * 2.0) as u8);
+        frame.set_external_temp_filtered(physical::to_u8(
+            self.external_temperature_filtered,
+            -80,
+            2,
+        ));
         #[cfg(not(feature = "float"))]
         frame.set_external_temp_filtered(self.external_temperature_filtered);
         frame.set_blinkers_status(self.blinkers_status);
@@ -455,40 +542,199 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    record,
+    idle: [0x80, 0x3c, 0x12, 0x99, 0x36, 0x9b, 0x9b, 0x20],
+    parked: [0x80, 0x3c, 0x00, 0x00, 0x00, 0x78, 0x78, 0x20],
+    with_powertrain_status => powertrain_status: PowertrainStatus,
+    with_generator_working => generator_working: bool,
+    with_vehicle_main_status => vehicle_main_status: MainStatus,
+    with_factory_park_enabled => factory_park_enabled: bool,
+    with_vsm_config_mode => vsm_config_mode: VsmConfigMode,
+    #[cfg(feature = "float")]
+    with_coolant_temperature => coolant_temperature: Physical,
+    #[cfg(not(feature = "float"))]
+    with_coolant_temperature => coolant_temperature: u8,
+    #[cfg(feature = "float")]
+    with_odometer(token) => odometer: Physical,
+    #[cfg(not(feature = "float"))]
+    with_odometer(token) => odometer: u32,
+    #[cfg(feature = "float")]
+    with_external_temperature => external_temperature: Physical,
+    #[cfg(not(feature = "float"))]
+    with_external_temperature => external_temperature: u8,
+    #[cfg(feature = "float")]
+    with_external_temperature_filtered => external_temperature_filtered: Physical,
+    #[cfg(not(feature = "float"))]
+    with_external_temperature_filtered => external_temperature_filtered: u8,
+    with_blinkers_status => blinkers_status: BlinkersStatus,
+    with_cluster_lights_test => cluster_lights_test: bool,
+    with_steering_wheel_position => steering_wheel_position: SteeringWheelPosition,
+    with_front_wiping_acknowledge => front_wiping_acknowledge: bool,
+    with_reverse_gear_engaged => reverse_gear_engaged: bool,
+}
+
+/// Signals of a x0f6 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("powertrain_status", 0, 1, 2).values(&[
+        (0, "Stopped"),
+        (1, "Cranking"),
+        (2, "Running"),
+        (3, "Stopping"),
+    ]),
+    Signal::new("generator_working", 0, 2, 1),
+    Signal::new("vehicle_main_status", 0, 4, 2).values(&[(0, "Off"), (1, "On"), (2, "Cranking")]),
+    Signal::new("factory_park_enabled", 0, 5, 1),
+    Signal::new("vsm_config_mode", 0, 7, 2).values(&[
+        (0, "Factory"),
+        (1, "Showroom"),
+        (2, "Customer"),
+    ]),
+    Signal::new("coolant_temperature", 1, 7, 8)
+        .scaled(1.0, -40.0)
+        .unit("degC"),
+    Signal::new("odometer", 2, 7, 24)
+        .scaled(0.1, 0.0)
+        .unit("km"),
+    Signal::new("external_temperature", 5, 7, 8)
+        .scaled(0.5, -40.0)
+        .unit("degC"),
+    Signal::new("external_temperature_filtered", 6, 7, 8)
+        .scaled(0.5, -40.0)
+        .unit("degC"),
+    Signal::new("blinkers_status", 7, 1, 2).values(&[
+        (0, "Off"),
+        (1, "Right"),
+        (2, "Left"),
+        (3, "LeftAndRight"),
+    ]),
+    Signal::new("cluster_lights_test", 7, 3, 1),
+    Signal::new("steering_wheel_position", 7, 5, 2).values(&[(1, "Right"), (2, "Left")]),
+    Signal::new("front_wiping_acknowledge", 7, 6, 1),
+    Signal::new("reverse_gear_engaged", 7, 7, 1),
+];
+
+impl Repr {
+    /// Return the odometer value as a typed distance, rounded to the nearest kilometer.
+    pub fn odometer_km(&self) -> Kilometers {
+        #[cfg(feature = "float")]
+        let value = physical::to_u32(self.odometer, 0, 1);
+        #[cfg(not(feature = "float"))]
+        let value = (self.odometer + 5) / 10;
+        Kilometers(value)
+    }
+}
+
+#[cfg(feature = "uom")]
+impl Repr {
+    /// Return the engine coolant temperature as a typed quantity.
+    pub fn coolant_temperature_quantity(&self) -> ThermodynamicTemperature {
+        #[cfg(feature = "float")]
+        let value = physical::to_f32(self.coolant_temperature);
+        #[cfg(not(feature = "float"))]
+        let value = self.coolant_temperature as f32 - 40.0;
+        ThermodynamicTemperature::new::<degree_celsius>(value)
+    }
+
+    /// Return the odometer value as a typed quantity.
+    pub fn odometer_quantity(&self) -> Length {
+        #[cfg(feature = "float")]
+        let value = physical::to_f32(self.odometer);
+        #[cfg(not(feature = "float"))]
+        let value = self.odometer as f32 / 10.0;
+        Length::new::<kilometer>(value)
+    }
+
+    /// Return the external temperature as a typed quantity.
+    pub fn external_temperature_quantity(&self) -> ThermodynamicTemperature {
+        #[cfg(feature = "float")]
+        let value = physical::to_f32(self.external_temperature);
+        #[cfg(not(feature = "float"))]
+        let value = (self.external_temperature as f32 / 2.0) - 40.0;
+        ThermodynamicTemperature::new::<degree_celsius>(value)
+    }
+
+    /// Return the filtered external temperature as a typed quantity.
+    pub fn external_temperature_filtered_quantity(&self) -> ThermodynamicTemperature {
+        #[cfg(feature = "float")]
+        let value = physical::to_f32(self.external_temperature_filtered);
+        #[cfg(not(feature = "float"))]
+        let value = (self.external_temperature_filtered as f32 / 2.0) - 40.0;
+        ThermodynamicTemperature::new::<degree_celsius>(value)
+    }
+}
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x0f6 powertrain_status={}", self.powertrain_status)?;
-        writeln!(f, " generator_working={}", self.generator_working)?;
-        writeln!(f, " vehicle_main_status={}", self.vehicle_main_status)?;
-        writeln!(f, " factory_park_enabled={}", self.factory_park_enabled)?;
-        writeln!(f, " vsm_config_mode={}", self.vsm_config_mode)?;
-        writeln!(f, " coolant_temperature={}", self.coolant_temperature)?;
-        writeln!(f, " odometer={}", self.odometer)?;
-        writeln!(f, " external_temperature={}", self.external_temperature)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x0f6")?;
+        write!(f, "{}powertrain_status={}", sep, self.powertrain_status)?;
+        write!(f, "{}generator_working={}", sep, self.generator_working)?;
+        write!(f, "{}vehicle_main_status={}", sep, self.vehicle_main_status)?;
+        write!(
+            f,
+            "{}factory_park_enabled={}",
+            sep, self.factory_park_enabled
+        )?;
+        write!(f, "{}vsm_config_mode={}", sep, self.vsm_config_mode)?;
+        write!(f, "{}coolant_temperature={}", sep, self.coolant_temperature)?;
+        write!(f, "{}odometer={}", sep, self.odometer)?;
+        write!(
             f,
-            " external_temperature_filtered={}",
-            self.external_temperature_filtered
+            "{}external_temperature={}",
+            sep, self.external_temperature
         )?;
-        writeln!(f, " blinkers_status={}", self.blinkers_status)?;
-        writeln!(f, " cluster_lights_test={}", self.cluster_lights_test)?;
-        writeln!(
+        write!(
             f,
-            " steering_wheel_position={}",
-            self.steering_wheel_position
+            "{}external_temperature_filtered={}",
+            sep, self.external_temperature_filtered
         )?;
-        writeln!(
+        write!(f, "{}blinkers_status={}", sep, self.blinkers_status)?;
+        write!(f, "{}cluster_lights_test={}", sep, self.cluster_lights_test)?;
+        write!(
             f,
-            " front_wiping_acknowledge={}",
-            self.front_wiping_acknowledge
+            "{}steering_wheel_position={}",
+            sep, self.steering_wheel_position
         )?;
-        writeln!(f, " reverse_gear_engaged={}", self.reverse_gear_engaged)
+        write!(
+            f,
+            "{}front_wiping_acknowledge={}",
+            sep, self.front_wiping_acknowledge
+        )?;
+        write!(
+            f,
+            "{}reverse_gear_engaged={}",
+            sep, self.reverse_gear_engaged
+        )
+    }
+}
+
+impl From<&crate::aee2004::conf::x0f6::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x0f6::Repr) -> Self {
+        Repr {
+            powertrain_status: repr_2004.powertrain_status,
+            generator_working: repr_2004.generator_working,
+            vehicle_main_status: repr_2004.vehicle_main_status,
+            factory_park_enabled: repr_2004.factory_park_enabled,
+            vsm_config_mode: repr_2004.vsm_config_mode,
+            coolant_temperature: repr_2004.coolant_temperature,
+            odometer: repr_2004.odometer,
+            external_temperature: repr_2004.external_temperature,
+            external_temperature_filtered: repr_2004.external_temperature_filtered,
+            blinkers_status: repr_2004.blinkers_status,
+            cluster_lights_test: repr_2004.cluster_lights_test,
+            steering_wheel_position: repr_2004.steering_wheel_position,
+            front_wiping_acknowledge: repr_2004.front_wiping_acknowledge,
+            reverse_gear_engaged: repr_2004.reverse_gear_engaged,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Frame, Repr};
+    use super::{DangerToken, Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
     use crate::{
         vehicle::{
             BlinkersStatus, MainStatus, PowertrainStatus, SteeringWheelPosition, VsmConfigMode,
@@ -506,10 +752,10 @@ mod test {
             vehicle_main_status: MainStatus::Off,
             factory_park_enabled: false,
             vsm_config_mode: VsmConfigMode::Customer,
-            coolant_temperature: 20.0,
-            odometer: 121887.0,
-            external_temperature: 37.5,
-            external_temperature_filtered: 37.5,
+            coolant_temperature: physical::decimal(200, 10),
+            odometer: physical::decimal(1218870, 10),
+            external_temperature: physical::decimal(375, 10),
+            external_temperature_filtered: physical::decimal(375, 10),
             blinkers_status: BlinkersStatus::Off,
             cluster_lights_test: false,
             steering_wheel_position: SteeringWheelPosition::Left,
@@ -525,10 +771,10 @@ mod test {
             vehicle_main_status: MainStatus::On,
             factory_park_enabled: false,
             vsm_config_mode: VsmConfigMode::Customer,
-            coolant_temperature: 65.0,
-            odometer: 114413.4,
-            external_temperature: 30.0,
-            external_temperature_filtered: 30.0,
+            coolant_temperature: physical::decimal(650, 10),
+            odometer: physical::decimal(1144134, 10),
+            external_temperature: physical::decimal(300, 10),
+            external_temperature_filtered: physical::decimal(300, 10),
             blinkers_status: BlinkersStatus::Off,
             cluster_lights_test: false,
             steering_wheel_position: SteeringWheelPosition::Left,
@@ -588,7 +834,7 @@ mod test {
         frame.set_factory_park(false);
         frame.set_vsm_config_mode(VsmConfigMode::Customer);
         frame.set_coolant_temp(60);
-        frame.set_odometer(1218870);
+        frame.set_odometer(1218870, DangerToken::unchecked());
         frame.set_external_temp(155);
         frame.set_external_temp_filtered(155);
         frame.set_blinkers_status(BlinkersStatus::Off);
@@ -611,7 +857,7 @@ mod test {
         frame.set_factory_park(false);
         frame.set_vsm_config_mode(VsmConfigMode::Customer);
         frame.set_coolant_temp(105);
-        frame.set_odometer(1144134);
+        frame.set_odometer(1144134, DangerToken::unchecked());
         frame.set_external_temp(140);
         frame.set_external_temp_filtered(140);
         frame.set_blinkers_status(BlinkersStatus::Off);
@@ -638,6 +884,16 @@ mod test {
         assert_eq!(Frame::new_checked(&bytes).unwrap_err(), Error::Truncated);
     }
 
+    #[cfg(feature = "unchecked-writes")]
+    #[test]
+    fn test_acknowledged_odometer_write() {
+        let mut bytes = REPR_FRAME_BYTES_2;
+        let mut frame = Frame::new_unchecked(&mut bytes);
+        frame.set_odometer(1218870, DangerToken::acknowledge());
+        assert_eq!(frame.odometer(), 1218870);
+        assert_eq!(frame.into_inner()[2..5], REPR_FRAME_BYTES_1[2..5]);
+    }
+
     #[test]
     fn test_repr_1_parse_valid() {
         let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
@@ -657,7 +913,7 @@ mod test {
         let mut buf = [0u8; 8];
         let mut frame = Frame::new_unchecked(&mut buf);
         let repr = frame_1_repr();
-        repr.emit(&mut frame);
+        repr.emit(&mut frame, DangerToken::unchecked());
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_1);
     }
 
@@ -666,7 +922,72 @@ mod test {
         let mut buf = [0u8; 8];
         let mut frame = Frame::new_unchecked(&mut buf);
         let repr = frame_2_repr();
-        repr.emit(&mut frame);
+        repr.emit(&mut frame, DangerToken::unchecked());
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[cfg(feature = "uom")]
+    #[test]
+    fn test_repr_1_quantities() {
+        use uom::si::{length::kilometer, thermodynamic_temperature::degree_celsius};
+
+        let repr = frame_1_repr();
+        assert_eq!(
+            repr.coolant_temperature_quantity().get::<degree_celsius>(),
+            physical::to_f32(repr.coolant_temperature)
+        );
+        assert_eq!(
+            repr.odometer_quantity().get::<kilometer>(),
+            physical::to_f32(repr.odometer)
+        );
+        assert_eq!(
+            repr.external_temperature_quantity().get::<degree_celsius>(),
+            physical::to_f32(repr.external_temperature)
+        );
+        assert_eq!(
+            repr.external_temperature_filtered_quantity()
+                .get::<degree_celsius>(),
+            physical::to_f32(repr.external_temperature_filtered)
+        );
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn test_temperature_accessors() {
+        let mut bytes = [0x00; 8];
+        let mut frame = Frame::new_unchecked(&mut bytes);
+        assert_eq!(
+            frame.set_external_temperature(physical::decimal(215, 10)),
+            Ok(())
+        );
+        assert_eq!(frame.external_temp(), 123);
+        assert_eq!(frame.external_temperature(), physical::decimal(215, 10));
+        assert_eq!(
+            frame.set_external_temperature_filtered(physical::decimal(-400, 10)),
+            Ok(())
+        );
+        assert_eq!(frame.external_temp_filtered(), 0);
+        assert_eq!(
+            frame.set_coolant_temperature(physical::decimal(900, 10)),
+            Ok(())
+        );
+        assert_eq!(frame.coolant_temp(), 130);
+        assert_eq!(frame.coolant_temperature(), physical::decimal(900, 10));
+
+        assert_eq!(
+            frame.set_external_temperature(physical::decimal(880, 10)),
+            Err(Error::Invalid)
+        );
+        #[cfg(not(feature = "fixed"))]
+        assert_eq!(
+            frame.set_external_temperature(f32::NAN),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            frame.set_coolant_temperature(physical::decimal(-410, 10)),
+            Err(Error::Invalid)
+        );
+        assert_eq!(frame.external_temp(), 123);
+        assert_eq!(frame.coolant_temp(), 130);
+    }
 }
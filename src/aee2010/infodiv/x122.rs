@@ -1,6 +1,10 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -93,6 +97,8 @@ pub const FRAME_LEN: usize = field::WHL_2_TICKS + 1;
 
 /// Periodicity of a x122 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(200);
+/// Transmission of a x122 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -220,10 +226,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x122 ({})", err)?;
                 Ok(())
@@ -238,9 +244,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x122 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub front_panel_buttons_state: [bool; 44],
     pub front_panel_bp_button_state: bool,
@@ -255,6 +267,11 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
         let mut buttons = [false; 44];
 
         buttons[0] = frame.read_button_state::<{ field::PUSH_BTN_FLAGS_0 }, 7>();
@@ -302,7 +319,7 @@ impl Repr {
         buttons[42] = frame.read_button_state::<{ field::PUSH_BTN_FLAGS_5 }, 5>();
         buttons[43] = frame.read_button_state::<{ field::PUSH_BTN_FLAGS_5 }, 4>();
 
-        Ok(Repr {
+        Repr {
             front_panel_buttons_state: buttons,
             front_panel_bp_button_state: frame
                 .read_button_state::<{ field::PUSH_BTN_FLAGS_5 }, 2>(),
@@ -312,7 +329,7 @@ impl Repr {
             front_panel_second_wheel_sync_request: frame.fp_second_wheel_sync_request(),
             front_panel_first_wheel_ticks_counter: frame.fp_first_wheel_ticks_counter(),
             front_panel_second_wheel_ticks_counter: frame.fp_second_wheel_ticks_counter(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -466,39 +483,199 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00],
+    with_front_panel_buttons_state => front_panel_buttons_state: [bool; 44],
+    with_front_panel_bp_button_state => front_panel_bp_button_state: bool,
+    with_front_panel_esp_button_state => front_panel_esp_button_state: bool,
+    with_front_panel_first_wheel_sync_request => front_panel_first_wheel_sync_request: bool,
+    with_front_panel_second_wheel_sync_request => front_panel_second_wheel_sync_request: bool,
+    with_front_panel_first_wheel_ticks_counter => front_panel_first_wheel_ticks_counter: u8,
+    with_front_panel_second_wheel_ticks_counter => front_panel_second_wheel_ticks_counter: u8,
+}
+
+/// Signals of a x122 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("front_panel_buttons_state", 0, 7, 44),
+    Signal::new("front_panel_bp_button_state", 5, 2, 1),
+    Signal::new("front_panel_esp_button_state", 5, 3, 1),
+    Signal::new("front_panel_first_wheel_sync_request", 5, 1, 1),
+    Signal::new("front_panel_second_wheel_sync_request", 5, 0, 1),
+    Signal::new("front_panel_first_wheel_ticks_counter", 6, 7, 8),
+    Signal::new("front_panel_second_wheel_ticks_counter", 7, 7, 8),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x122",)?;
-        writeln!(f, " front_panel_buttons_state=")?;
+        let sep = field_separator(f);
+        write!(f, "x122")?;
         for (btn, val) in self.front_panel_buttons_state.into_iter().enumerate() {
-            writeln!(f, "  button_{}={}", btn, val)?;
+            write!(f, "{}front_panel_buttons_state[{}]={}", sep, btn, val)?;
         }
-        writeln!(
+        write!(
             f,
-            " front_panel_bp_button_state={}",
-            self.front_panel_bp_button_state
+            "{}front_panel_bp_button_state={}",
+            sep, self.front_panel_bp_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " front_panel_esp_button_state={}",
-            self.front_panel_esp_button_state
+            "{}front_panel_esp_button_state={}",
+            sep, self.front_panel_esp_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " front_panel_first_wheel_ticks_counter={}",
-            self.front_panel_first_wheel_ticks_counter
+            "{}front_panel_first_wheel_ticks_counter={}",
+            sep, self.front_panel_first_wheel_ticks_counter
         )?;
-        writeln!(
+        write!(
             f,
-            " front_panel_second_wheel_ticks_counter={}",
-            self.front_panel_second_wheel_ticks_counter
+            "{}front_panel_second_wheel_ticks_counter={}",
+            sep, self.front_panel_second_wheel_ticks_counter
         )
     }
 }
 
+/// Number of numbered front panel push buttons.
+const PUSH_BUTTONS: u8 = 44;
+
+/// A front panel key.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FmuxKey {
+    /// Numbered push button, from 1 to 44, i.e. `Repr::front_panel_buttons_state[n - 1]`.
+    Push(u8),
+    /// 'BP' button.
+    Bp,
+    /// ESP button.
+    Esp,
+}
+
+impl FmuxKey {
+    /// Return the key at index `index` of the keys bitmap.
+    fn from_index(index: u8) -> FmuxKey {
+        match index {
+            PUSH_BUTTONS => FmuxKey::Bp,
+            index if index > PUSH_BUTTONS => FmuxKey::Esp,
+            index => FmuxKey::Push(index + 1),
+        }
+    }
+}
+
+impl fmt::Display for FmuxKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FmuxKey::Push(number) => write!(f, "push {}", number),
+            FmuxKey::Bp => write!(f, "bp"),
+            FmuxKey::Esp => write!(f, "esp"),
+        }
+    }
+}
+
+/// A front panel key transition.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FmuxKeyEvent {
+    /// The key was pressed.
+    Pressed(FmuxKey),
+    /// The key was released.
+    Released(FmuxKey),
+}
+
+impl fmt::Display for FmuxKeyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FmuxKeyEvent::Pressed(key) => write!(f, "{} pressed", key),
+            FmuxKeyEvent::Released(key) => write!(f, "{} released", key),
+        }
+    }
+}
+
+/// Front panel key transitions decoder.
+///
+/// x122 frames carry the state of every key, [FmuxKeyDecoder::update] compares it with the
+/// state of the previous frame to report the key presses and releases. Keys are released
+/// before the first frame, so keys held when the decoder starts are reported as pressed.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct FmuxKeyDecoder {
+    keys: u64,
+}
+
+impl FmuxKeyDecoder {
+    /// Create a decoder with every key released.
+    pub fn new() -> FmuxKeyDecoder {
+        FmuxKeyDecoder::default()
+    }
+
+    /// Update the keys state from a received frame, returning an iterator over the key
+    /// transitions since the previous frame.
+    pub fn update(&mut self, repr: &Repr) -> FmuxKeyEvents {
+        let previous = self.keys;
+        self.keys = keys(repr);
+        FmuxKeyEvents {
+            previous,
+            current: self.keys,
+            index: 0,
+        }
+    }
+
+    /// Return whether `key` is currently pressed.
+    pub fn is_pressed(&self, key: FmuxKey) -> bool {
+        let index = match key {
+            FmuxKey::Push(number @ 1..=PUSH_BUTTONS) => number - 1,
+            FmuxKey::Push(_) => return false,
+            FmuxKey::Bp => PUSH_BUTTONS,
+            FmuxKey::Esp => PUSH_BUTTONS + 1,
+        };
+        self.keys & (1 << index) != 0
+    }
+
+    /// Release every key, e.g. when the frame is lost.
+    pub fn reset(&mut self) {
+        self.keys = 0;
+    }
+}
+
+/// Return the keys bitmap of `repr`, with a bit per numbered push button followed by the
+/// 'BP' and ESP buttons.
+fn keys(repr: &Repr) -> u64 {
+    let buttons = repr.front_panel_buttons_state.iter().chain([
+        &repr.front_panel_bp_button_state,
+        &repr.front_panel_esp_button_state,
+    ]);
+    buttons.enumerate().fold(0, |keys, (index, &pressed)| {
+        keys | (u64::from(pressed) << index)
+    })
+}
+
+/// Iterator over the key transitions between two frames, see [FmuxKeyDecoder::update].
+#[derive(Debug, Clone)]
+pub struct FmuxKeyEvents {
+    previous: u64,
+    current: u64,
+    index: u8,
+}
+
+impl Iterator for FmuxKeyEvents {
+    type Item = FmuxKeyEvent;
+
+    fn next(&mut self) -> Option<FmuxKeyEvent> {
+        while self.index < PUSH_BUTTONS + 2 {
+            let index = self.index;
+            let mask = 1u64 << index;
+            self.index += 1;
+            match (self.previous & mask != 0, self.current & mask != 0) {
+                (false, true) => return Some(FmuxKeyEvent::Pressed(FmuxKey::from_index(index))),
+                (true, false) => return Some(FmuxKeyEvent::Released(FmuxKey::from_index(index))),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{Frame, Repr};
+    use super::{FmuxKey, FmuxKeyDecoder, FmuxKeyEvent, Frame, Repr};
 
     use crate::Error;
 
@@ -635,4 +812,41 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_key_events() {
+        let mut decoder = FmuxKeyDecoder::new();
+        let mut repr = Repr::builder().build().unwrap();
+        repr.front_panel_buttons_state = [false; 44];
+        repr.front_panel_bp_button_state = false;
+        repr.front_panel_esp_button_state = false;
+        assert_eq!(decoder.update(&repr).next(), None);
+
+        repr.front_panel_buttons_state[0] = true;
+        repr.front_panel_esp_button_state = true;
+        let mut events = decoder.update(&repr);
+        assert_eq!(events.next(), Some(FmuxKeyEvent::Pressed(FmuxKey::Push(1))));
+        assert_eq!(events.next(), Some(FmuxKeyEvent::Pressed(FmuxKey::Esp)));
+        assert_eq!(events.next(), None);
+        assert!(decoder.is_pressed(FmuxKey::Push(1)));
+        assert!(!decoder.is_pressed(FmuxKey::Push(45)));
+
+        assert_eq!(decoder.update(&repr).next(), None);
+
+        repr.front_panel_buttons_state[0] = false;
+        repr.front_panel_buttons_state[43] = true;
+        let mut events = decoder.update(&repr);
+        assert_eq!(
+            events.next(),
+            Some(FmuxKeyEvent::Released(FmuxKey::Push(1)))
+        );
+        assert_eq!(
+            events.next(),
+            Some(FmuxKeyEvent::Pressed(FmuxKey::Push(44)))
+        );
+        assert_eq!(events.next(), None);
+
+        decoder.reset();
+        assert!(!decoder.is_pressed(FmuxKey::Esp));
+    }
 }
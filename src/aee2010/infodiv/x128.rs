@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
         AdBlueIndicatorState, AutoGearboxMode, GearEfficiencyArrowType, GearboxDriveModeGear,
         GearboxGear, GearboxType, IndicatorState,
@@ -133,6 +135,9 @@ pub const FRAME_ID: u16 = 0x128;
 /// Length of a x128 CAN frame.
 pub const FRAME_LEN: usize = field::FLAGS_8 + 1;
 
+/// Transmission of a x128 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnChange;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -318,10 +323,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x128 ({})", err)?;
                 Ok(())
@@ -336,9 +341,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x128 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub daytime_running_lamps_indicator: bool,
     pub left_blinker_indicator: bool,
@@ -392,7 +403,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             daytime_running_lamps_indicator: frame.read_bit::<{ field::FLAGS_1 }, 0>(),
             left_blinker_indicator: frame.read_bit::<{ field::FLAGS_1 }, 1>(),
             right_blinker_indicator: frame.read_bit::<{ field::FLAGS_1 }, 2>(),
@@ -440,7 +456,7 @@ impl Repr {
             rear_middle_seat_belt_indicator: frame.read_bit::<{ field::FLAGS_7 }, 6>(),
             rear_left_seat_belt_indicator_blinking: frame.read_bit::<{ field::FLAGS_7 }, 7>(),
             low_fuel_indicator_blinking: frame.read_bit::<{ field::FLAGS_8 }, 3>(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -501,154 +517,333 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x55, 0x35, 0x00, 0x55, 0x55, 0x53, 0x55, 0x08],
+    with_daytime_running_lamps_indicator => daytime_running_lamps_indicator: bool,
+    with_left_blinker_indicator => left_blinker_indicator: bool,
+    with_right_blinker_indicator => right_blinker_indicator: bool,
+    with_rear_anti_fog_light_indicator => rear_anti_fog_light_indicator: bool,
+    with_front_anti_fog_light_indicator => front_anti_fog_light_indicator: bool,
+    with_main_beam_indicator => main_beam_indicator: bool,
+    with_headlamps_indicator => headlamps_indicator: bool,
+    with_sidelights_indicator => sidelights_indicator: bool,
+    with_displayed_gear_blinking => displayed_gear_blinking: bool,
+    with_gearbox_drive_mode_gear => gearbox_drive_mode_gear: GearboxDriveModeGear,
+    with_gearbox_gear => gearbox_gear: GearboxGear,
+    with_gearbox_type => gearbox_type: GearboxType,
+    with_gear_efficiency_indicator_arrow_type => gear_efficiency_indicator_arrow_type: GearEfficiencyArrowType,
+    with_automatic_gearbox_mode => automatic_gearbox_mode: AutoGearboxMode,
+    with_gear_efficiency_indicator_blinking => gear_efficiency_indicator_blinking: bool,
+    with_automatic_parking_brake_inhibited => automatic_parking_brake_inhibited: bool,
+    with_parking_brake_applied => parking_brake_applied: bool,
+    with_foot_on_brake_pedal_indicator => foot_on_brake_pedal_indicator: IndicatorState,
+    with_passenger_airbag_inhibited => passenger_airbag_inhibited: bool,
+    with_child_lock_security => child_lock_security: bool,
+    with_stop_indicator => stop_indicator: bool,
+    with_service_indicator => service_indicator: bool,
+    with_suspension_indicator => suspension_indicator: bool,
+    with_esp_indicator => esp_indicator: bool,
+    with_esp_inhibited => esp_inhibited: bool,
+    with_automatic_main_beam_indicator => automatic_main_beam_indicator: bool,
+    with_available_space_measurement_indicator_blinking => available_space_measurement_indicator_blinking: bool,
+    with_available_space_measurement_indicator => available_space_measurement_indicator: bool,
+    with_opened_door => opened_door: bool,
+    with_diesel_pre_heating => diesel_pre_heating: bool,
+    with_rear_left_seat_belt_indicator => rear_left_seat_belt_indicator: bool,
+    with_adblue_indicator => adblue_indicator: AdBlueIndicatorState,
+    with_passenger_seat_belt_indicator_blinking => passenger_seat_belt_indicator_blinking: bool,
+    with_passenger_seat_belt_indicator => passenger_seat_belt_indicator: bool,
+    with_driver_seat_belt_indicator_blinking => driver_seat_belt_indicator_blinking: bool,
+    with_driver_seat_belt_indicator => driver_seat_belt_indicator: bool,
+    with_low_fuel => low_fuel: bool,
+    with_passenger_protection => passenger_protection: bool,
+    with_hazard_warning_lights => hazard_warning_lights: bool,
+    with_instrument_cluster_on => instrument_cluster_on: bool,
+    with_rear_right_seat_belt_indicator_blinking => rear_right_seat_belt_indicator_blinking: bool,
+    with_rear_right_seat_belt_indicator => rear_right_seat_belt_indicator: bool,
+    with_rear_middle_seat_belt_indicator_blinking => rear_middle_seat_belt_indicator_blinking: bool,
+    with_rear_middle_seat_belt_indicator => rear_middle_seat_belt_indicator: bool,
+    with_rear_left_seat_belt_indicator_blinking => rear_left_seat_belt_indicator_blinking: bool,
+    with_low_fuel_indicator_blinking => low_fuel_indicator_blinking: bool,
+}
+
+/// Signals of a x128 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("daytime_running_lamps_indicator", 0, 0, 1),
+    Signal::new("left_blinker_indicator", 0, 1, 1),
+    Signal::new("right_blinker_indicator", 0, 2, 1),
+    Signal::new("rear_anti_fog_light_indicator", 0, 3, 1),
+    Signal::new("front_anti_fog_light_indicator", 0, 4, 1),
+    Signal::new("main_beam_indicator", 0, 5, 1),
+    Signal::new("headlamps_indicator", 0, 6, 1),
+    Signal::new("sidelights_indicator", 0, 7, 1),
+    Signal::new("displayed_gear_blinking", 1, 0, 1),
+    Signal::new("gearbox_drive_mode_gear", 1, 3, 3).values(&[
+        (0, "Disengaged"),
+        (1, "Gear1"),
+        (2, "Gear2"),
+        (3, "Gear3"),
+        (4, "Gear4"),
+        (5, "Gear5"),
+        (6, "Gear6"),
+        (7, "Gear7"),
+    ]),
+    Signal::new("gearbox_gear", 1, 7, 4).values(&[
+        (0, "P"),
+        (1, "R"),
+        (2, "N"),
+        (3, "D"),
+        (4, "Gear6"),
+        (5, "Gear5"),
+        (6, "Gear4"),
+        (7, "Gear3"),
+        (8, "Gear2"),
+        (9, "Gear1"),
+        (11, "Nothing"),
+        (12, "Gear7"),
+        (13, "Gear8"),
+        (14, "Gear9"),
+        (15, "B"),
+    ]),
+    Signal::new("gearbox_type", 2, 1, 2).values(&[
+        (0, "Automatic"),
+        (1, "Manual"),
+        (2, "ManualRobotized"),
+        (3, "AutomaticDualClutch"),
+    ]),
+    Signal::new("gear_efficiency_indicator_arrow_type", 2, 3, 2).values(&[
+        (0, "Nothing"),
+        (1, "Up"),
+        (2, "Down"),
+        (3, "UpAndDown"),
+    ]),
+    Signal::new("automatic_gearbox_mode", 2, 6, 3).values(&[
+        (0, "Automatic"),
+        (2, "AutomaticSport"),
+        (4, "Sequential"),
+        (5, "SequentialSport"),
+        (6, "AutomaticSnow"),
+        (7, "ASM"),
+    ]),
+    Signal::new("gear_efficiency_indicator_blinking", 2, 7, 1),
+    Signal::new("automatic_parking_brake_inhibited", 3, 0, 1),
+    Signal::new("parking_brake_applied", 3, 1, 1),
+    Signal::new("foot_on_brake_pedal_indicator", 3, 3, 2).values(&[
+        (0, "Off"),
+        (1, "On"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("passenger_airbag_inhibited", 3, 4, 1),
+    Signal::new("child_lock_security", 3, 5, 1),
+    Signal::new("stop_indicator", 3, 6, 1),
+    Signal::new("service_indicator", 3, 7, 1),
+    Signal::new("suspension_indicator", 4, 0, 1),
+    Signal::new("esp_indicator", 4, 1, 1),
+    Signal::new("esp_inhibited", 4, 2, 1),
+    Signal::new("automatic_main_beam_indicator", 4, 3, 1),
+    Signal::new("available_space_measurement_indicator_blinking", 4, 4, 1),
+    Signal::new("available_space_measurement_indicator", 4, 5, 1),
+    Signal::new("opened_door", 4, 6, 1),
+    Signal::new("diesel_pre_heating", 4, 7, 1),
+    Signal::new("rear_left_seat_belt_indicator", 5, 0, 1),
+    Signal::new("adblue_indicator", 5, 2, 2).values(&[(0, "Off"), (1, "Blinking"), (2, "On")]),
+    Signal::new("passenger_seat_belt_indicator_blinking", 5, 3, 1),
+    Signal::new("passenger_seat_belt_indicator", 5, 4, 1),
+    Signal::new("driver_seat_belt_indicator_blinking", 5, 5, 1),
+    Signal::new("driver_seat_belt_indicator", 5, 6, 1),
+    Signal::new("low_fuel", 5, 7, 1),
+    Signal::new("passenger_protection", 6, 0, 1),
+    Signal::new("hazard_warning_lights", 6, 1, 1),
+    Signal::new("instrument_cluster_on", 6, 2, 1),
+    Signal::new("rear_right_seat_belt_indicator_blinking", 6, 3, 1),
+    Signal::new("rear_right_seat_belt_indicator", 6, 4, 1),
+    Signal::new("rear_middle_seat_belt_indicator_blinking", 6, 5, 1),
+    Signal::new("rear_middle_seat_belt_indicator", 6, 6, 1),
+    Signal::new("rear_left_seat_belt_indicator_blinking", 6, 7, 1),
+    Signal::new("low_fuel_indicator_blinking", 7, 3, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x128")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x128")?;
+        write!(
+            f,
+            "{}daytime_running_lamps_indicator={}",
+            sep, self.daytime_running_lamps_indicator
+        )?;
+        write!(
+            f,
+            "{}left_blinker_indicator={}",
+            sep, self.left_blinker_indicator
+        )?;
+        write!(
+            f,
+            "{}right_blinker_indicator={}",
+            sep, self.right_blinker_indicator
+        )?;
+        write!(
             f,
-            " daytime_running_lamps_indicator={}",
-            self.daytime_running_lamps_indicator
+            "{}rear_anti_fog_light_indicator={}",
+            sep, self.rear_anti_fog_light_indicator
         )?;
-        writeln!(f, " left_blinker_indicator={}", self.left_blinker_indicator)?;
-        writeln!(
+        write!(
             f,
-            " right_blinker_indicator={}",
-            self.right_blinker_indicator
+            "{}front_anti_fog_light_indicator={}",
+            sep, self.front_anti_fog_light_indicator
         )?;
-        writeln!(
+        write!(f, "{}main_beam_indicator={}", sep, self.main_beam_indicator)?;
+        write!(f, "{}headlamps_indicator={}", sep, self.headlamps_indicator)?;
+        write!(
             f,
-            " rear_anti_fog_light_indicator={}",
-            self.rear_anti_fog_light_indicator
+            "{}sidelights_indicator={}",
+            sep, self.sidelights_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " front_anti_fog_light_indicator={}",
-            self.front_anti_fog_light_indicator
+            "{}displayed_gear_blinking={}",
+            sep, self.displayed_gear_blinking
         )?;
-        writeln!(f, " main_beam_indicator={}", self.main_beam_indicator)?;
-        writeln!(f, " headlamps_indicator={}", self.headlamps_indicator)?;
-        writeln!(f, " sidelights_indicator={}", self.sidelights_indicator)?;
-        writeln!(
+        write!(
             f,
-            " displayed_gear_blinking={}",
-            self.displayed_gear_blinking
+            "{}gearbox_drive_mode_gear={}",
+            sep, self.gearbox_drive_mode_gear
         )?;
-        writeln!(
+        write!(f, "{}gearbox_gear={}", sep, self.gearbox_gear)?;
+        write!(f, "{}gearbox_type={}", sep, self.gearbox_type)?;
+        write!(
             f,
-            " gearbox_drive_mode_gear={}",
-            self.gearbox_drive_mode_gear
+            "{}gear_efficiency_indicator_arrow_type={}",
+            sep, self.gear_efficiency_indicator_arrow_type
         )?;
-        writeln!(f, " gearbox_gear={}", self.gearbox_gear)?;
-        writeln!(f, " gearbox_type={}", self.gearbox_type)?;
-        writeln!(
+        write!(
             f,
-            " gear_efficiency_indicator_arrow_type={}",
-            self.gear_efficiency_indicator_arrow_type
+            "{}automatic_gearbox_mode={}",
+            sep, self.automatic_gearbox_mode
         )?;
-        writeln!(f, " automatic_gearbox_mode={}", self.automatic_gearbox_mode)?;
-        writeln!(
+        write!(
             f,
-            " gear_efficiency_indicator_blinking={}",
-            self.gear_efficiency_indicator_blinking
+            "{}gear_efficiency_indicator_blinking={}",
+            sep, self.gear_efficiency_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_parking_brake_inhibited={}",
-            self.automatic_parking_brake_inhibited
+            "{}automatic_parking_brake_inhibited={}",
+            sep, self.automatic_parking_brake_inhibited
         )?;
-        writeln!(f, " parking_brake_applied={}", self.parking_brake_applied)?;
-        writeln!(
+        write!(
             f,
-            " foot_on_brake_pedal_indicator={}",
-            self.foot_on_brake_pedal_indicator
+            "{}parking_brake_applied={}",
+            sep, self.parking_brake_applied
         )?;
-        writeln!(
+        write!(
             f,
-            " passenger_airbag_inhibited={}",
-            self.passenger_airbag_inhibited
+            "{}foot_on_brake_pedal_indicator={}",
+            sep, self.foot_on_brake_pedal_indicator
         )?;
-        writeln!(f, " child_lock_security={}", self.child_lock_security)?;
-        writeln!(f, " stop_indicator={}", self.stop_indicator)?;
-        writeln!(f, " service_indicator={}", self.service_indicator)?;
-        writeln!(f, " suspension_indicator={}", self.suspension_indicator)?;
-        writeln!(f, " esp_indicator={}", self.esp_indicator)?;
-        writeln!(f, " esp_inhibited={}", self.esp_inhibited)?;
-        writeln!(
+        write!(
             f,
-            " automatic_main_beam_indicator={}",
-            self.automatic_main_beam_indicator
+            "{}passenger_airbag_inhibited={}",
+            sep, self.passenger_airbag_inhibited
         )?;
-        writeln!(
+        write!(f, "{}child_lock_security={}", sep, self.child_lock_security)?;
+        write!(f, "{}stop_indicator={}", sep, self.stop_indicator)?;
+        write!(f, "{}service_indicator={}", sep, self.service_indicator)?;
+        write!(
             f,
-            " available_space_measurement_indicator_blinking={}",
-            self.available_space_measurement_indicator_blinking
+            "{}suspension_indicator={}",
+            sep, self.suspension_indicator
         )?;
-        writeln!(
+        write!(f, "{}esp_indicator={}", sep, self.esp_indicator)?;
+        write!(f, "{}esp_inhibited={}", sep, self.esp_inhibited)?;
+        write!(
             f,
-            " available_space_measurement_indicator={}",
-            self.available_space_measurement_indicator
+            "{}automatic_main_beam_indicator={}",
+            sep, self.automatic_main_beam_indicator
         )?;
-        writeln!(f, " opened_door={}", self.opened_door)?;
-        writeln!(f, " diesel_pre_heating={}", self.diesel_pre_heating)?;
-        writeln!(
+        write!(
             f,
-            " rear_left_seat_belt_indicator={}",
-            self.rear_left_seat_belt_indicator
+            "{}available_space_measurement_indicator_blinking={}",
+            sep, self.available_space_measurement_indicator_blinking
         )?;
-        writeln!(f, " adblue_indicator={}", self.adblue_indicator)?;
-        writeln!(
+        write!(
             f,
-            " passenger_seat_belt_indicator_blinking={}",
-            self.passenger_seat_belt_indicator_blinking
+            "{}available_space_measurement_indicator={}",
+            sep, self.available_space_measurement_indicator
         )?;
-        writeln!(
+        write!(f, "{}opened_door={}", sep, self.opened_door)?;
+        write!(f, "{}diesel_pre_heating={}", sep, self.diesel_pre_heating)?;
+        write!(
             f,
-            " passenger_seat_belt_indicator={}",
-            self.passenger_seat_belt_indicator
+            "{}rear_left_seat_belt_indicator={}",
+            sep, self.rear_left_seat_belt_indicator
         )?;
-        writeln!(
+        write!(f, "{}adblue_indicator={}", sep, self.adblue_indicator)?;
+        write!(
             f,
-            " driver_seat_belt_indicator_blinking={}",
-            self.driver_seat_belt_indicator_blinking
+            "{}passenger_seat_belt_indicator_blinking={}",
+            sep, self.passenger_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " driver_seat_belt_indicator={}",
-            self.driver_seat_belt_indicator
+            "{}passenger_seat_belt_indicator={}",
+            sep, self.passenger_seat_belt_indicator
         )?;
-        writeln!(f, " low_fuel={}", self.low_fuel)?;
-        writeln!(f, " passenger_protection={}", self.passenger_protection)?;
-        writeln!(f, " hazard_warning_lights={}", self.hazard_warning_lights)?;
-        writeln!(f, " instrument_cluster_on={}", self.instrument_cluster_on)?;
-        writeln!(
+        write!(
             f,
-            " rear_right_seat_belt_indicator_blinking={}",
-            self.rear_right_seat_belt_indicator_blinking
+            "{}driver_seat_belt_indicator_blinking={}",
+            sep, self.driver_seat_belt_indicator_blinking
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_right_seat_belt_indicator={}",
-            self.rear_right_seat_belt_indicator
+            "{}driver_seat_belt_indicator={}",
+            sep, self.driver_seat_belt_indicator
         )?;
-        writeln!(
+        write!(f, "{}low_fuel={}", sep, self.low_fuel)?;
+        write!(
             f,
-            " rear_middle_seat_belt_indicator_blinking={}",
-            self.rear_middle_seat_belt_indicator_blinking
+            "{}passenger_protection={}",
+            sep, self.passenger_protection
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_middle_seat_belt_indicator={}",
-            self.rear_middle_seat_belt_indicator
+            "{}hazard_warning_lights={}",
+            sep, self.hazard_warning_lights
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_left_seat_belt_indicator_blinking={}",
-            self.rear_left_seat_belt_indicator_blinking
+            "{}instrument_cluster_on={}",
+            sep, self.instrument_cluster_on
         )?;
-        writeln!(
+        write!(
             f,
-            " low_fuel_indicator_blinking={}",
-            self.low_fuel_indicator_blinking
+            "{}rear_right_seat_belt_indicator_blinking={}",
+            sep, self.rear_right_seat_belt_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}rear_right_seat_belt_indicator={}",
+            sep, self.rear_right_seat_belt_indicator
+        )?;
+        write!(
+            f,
+            "{}rear_middle_seat_belt_indicator_blinking={}",
+            sep, self.rear_middle_seat_belt_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}rear_middle_seat_belt_indicator={}",
+            sep, self.rear_middle_seat_belt_indicator
+        )?;
+        write!(
+            f,
+            "{}rear_left_seat_belt_indicator_blinking={}",
+            sep, self.rear_left_seat_belt_indicator_blinking
+        )?;
+        write!(
+            f,
+            "{}low_fuel_indicator_blinking={}",
+            sep, self.low_fuel_indicator_blinking
         )
     }
 }
@@ -1099,4 +1294,27 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x128::Repr::builder()
+            .with_left_blinker_indicator(true)
+            .with_low_fuel(true)
+            .with_opened_door_more_10kph(true)
+            .with_gearbox_gear(GearboxGear::Gear5)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2004);
+        assert_eq!(repr.left_blinker_indicator, true);
+        assert_eq!(
+            repr.right_blinker_indicator,
+            repr_2004.right_blinker_indicator
+        );
+        assert_eq!(repr.low_fuel, true);
+        assert_eq!(repr.low_fuel_indicator_blinking, false);
+        assert_eq!(repr.opened_door, true);
+        assert_eq!(repr.gearbox_gear, GearboxGear::Gear5);
+        assert_eq!(repr.automatic_main_beam_indicator, false);
+        assert_eq!(repr.adblue_indicator, AdBlueIndicatorState::Off);
+    }
 }
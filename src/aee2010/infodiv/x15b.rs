@@ -1,11 +1,15 @@
 use core::{cmp::Ordering, fmt};
 
+use byteorder::{ByteOrder, NetworkEndian};
+
 use crate::{
     config::{
         CollisionAlertSensibilityLevel, ConfigurableKeyAction2010, ConsumptionUnit, DistanceUnit,
         Language, LightingDuration2010, MoodLightingLevel, SoundHarmony, TemperatureUnit,
         VolumeUnit,
     },
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result,
 };
 
@@ -128,6 +132,12 @@ pub const FRAME_ID: u16 = 0x15b;
 /// Length of a x15b CAN frame.
 pub const FRAME_LEN: usize = field::OPT_7 + 1;
 
+/// Transmission of a x15b CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnRequest;
+
+/// Reserved bits of a x15b CAN frame, in frame order: 2 bits of byte 6 and 7 bits of byte 7.
+pub const RESERVED_MASK: u64 = 0x0000_0000_0000_c0f7;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -174,6 +184,14 @@ impl<T: AsRef<[u8]>> Frame<T> {
         FRAME_LEN
     }
 
+    /// Return the raw reserved bits, i.e. the frame bits without known meaning, masked
+    /// with [RESERVED_MASK].
+    #[inline]
+    pub fn reserved_bits(&self) -> u64 {
+        let data = self.buffer.as_ref();
+        NetworkEndian::read_u64(&data[..FRAME_LEN]) & RESERVED_MASK
+    }
+
     /// Return the consumption unit field.
     #[inline]
     pub fn consumption_unit(&self) -> ConsumptionUnit {
@@ -860,10 +878,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x15b ({})", err)?;
                 Ok(())
@@ -878,9 +896,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x15b CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub consumption_unit: ConsumptionUnit,
     pub distance_unit: DistanceUnit,
@@ -930,7 +954,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             consumption_unit: frame.consumption_unit(),
             distance_unit: frame.distance_unit(),
             language: frame.language(),
@@ -975,7 +1004,17 @@ impl Repr {
                 .extended_traffic_sign_recognition_enable(),
             electric_child_security_temp_disabled: frame.electric_child_security_tempo_disable(),
             auto_mirrors_folding_inhibit: frame.auto_mirrors_folding_inhibit(),
-        })
+        }
+    }
+
+    /// Parse a x15b CAN frame like [Repr::parse], but return `Err(Error::Invalid)` if any
+    /// reserved bit is set, e.g. on firmware variants using undocumented bits.
+    pub fn parse_strict<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
+        frame.check_len()?;
+        if frame.reserved_bits() != 0 {
+            return Err(Error::Invalid);
+        }
+        Repr::parse(frame)
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -1036,170 +1075,439 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x01, 0x00, 0xab, 0xaa, 0xa3, 0xaa, 0x2a, 0x00],
+    parked: [0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00],
+    with_consumption_unit => consumption_unit: ConsumptionUnit,
+    with_distance_unit => distance_unit: DistanceUnit,
+    with_language => language: Language,
+    with_units_language_parameters_validity => units_language_parameters_validity: bool,
+    with_sound_harmony => sound_harmony: SoundHarmony,
+    with_parameters_validity => parameters_validity: bool,
+    with_mood_lighting_level => mood_lighting_level: MoodLightingLevel,
+    with_temperature_unit => temperature_unit: TemperatureUnit,
+    with_volume_unit => volume_unit: VolumeUnit,
+    with_mood_lighting_enabled => mood_lighting_enabled: bool,
+    with_daytime_running_lamps_enabled => daytime_running_lamps_enabled: bool,
+    with_adaptive_lamps_enabled => adaptive_lamps_enabled: bool,
+    with_welcome_function_enabled => welcome_function_enabled: bool,
+    with_boot_selective_unlocking_enabled => boot_selective_unlocking_enabled: bool,
+    with_selective_unlocking_enabled => selective_unlocking_enabled: bool,
+    with_key_selective_unlocking_enabled => key_selective_unlocking_enabled: bool,
+    with_automatic_elec_parking_brake_application_enabled => automatic_elec_parking_brake_application_enabled: bool,
+    with_automatic_headlamps_enabled => automatic_headlamps_enabled: bool,
+    with_welcome_lighting_duration => welcome_lighting_duration: LightingDuration2010,
+    with_welcome_lighting_enabled => welcome_lighting_enabled: bool,
+    with_motorway_lighting_enabled => motorway_lighting_enabled: bool,
+    with_follow_me_home_lighting_duration => follow_me_home_lighting_duration: LightingDuration2010,
+    with_follow_me_home_enabled => follow_me_home_enabled: bool,
+    with_configurable_key_mode => configurable_key_mode: ConfigurableKeyAction2010,
+    with_motorized_tailgate_enabled => motorized_tailgate_enabled: bool,
+    with_rear_wiper_in_reverse_gear_enabled => rear_wiper_in_reverse_gear_enabled: bool,
+    with_blind_spot_monitoring_enabled => blind_spot_monitoring_enabled: bool,
+    with_park_sensors_enabled => park_sensors_enabled: bool,
+    with_mirrors_tilting_in_reverse_gear_enabled => mirrors_tilting_in_reverse_gear_enabled: bool,
+    with_indirect_under_inflation_enabled => indirect_under_inflation_enabled: bool,
+    with_automatic_emergency_braking_enabled => automatic_emergency_braking_enabled: bool,
+    with_collision_alert_sensibility_level => collision_alert_sensibility_level: CollisionAlertSensibilityLevel,
+    with_collision_alert_enabled => collision_alert_enabled: bool,
+    with_hands_free_tailgate_enabled => hands_free_tailgate_enabled: bool,
+    with_speed_limit_recognition_enabled => speed_limit_recognition_enabled: bool,
+    with_radiator_grill_lamps_enabled => radiator_grill_lamps_enabled: bool,
+    with_automatic_main_beam_enabled => automatic_main_beam_enabled: bool,
+    with_driver_alert_assist_enabled => driver_alert_assist_enabled: bool,
+    with_hands_free_tailgate_auto_lock_enabled => hands_free_tailgate_auto_lock_enabled: bool,
+    with_extended_traffic_sign_recognition_enabled => extended_traffic_sign_recognition_enabled: bool,
+    with_electric_child_security_temp_disabled => electric_child_security_temp_disabled: bool,
+    with_auto_mirrors_folding_inhibit => auto_mirrors_folding_inhibit: bool,
+}
+
+/// Signals of a x15b CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("consumption_unit", 0, 0, 1)
+        .values(&[(0, "VolumePerDistance"), (1, "DistancePerVolume")]),
+    Signal::new("distance_unit", 0, 1, 1).values(&[(0, "Kilometer"), (1, "Mile")]),
+    Signal::new("language", 0, 6, 5).values(&[
+        (0, "French"),
+        (1, "English"),
+        (2, "German"),
+        (3, "Spanish"),
+        (4, "Italian"),
+        (5, "Portuguese"),
+        (6, "Dutch"),
+        (7, "Greek"),
+        (8, "BrazilianPortuguese"),
+        (9, "Polish"),
+        (10, "TraditionalChinese"),
+        (11, "SimplifiedChinese"),
+        (12, "Turkish"),
+        (13, "Japanese"),
+        (14, "Russian"),
+        (15, "Invalid"),
+        (18, "Arabic"),
+        (23, "Farsi"),
+        (29, "Swedish"),
+    ]),
+    Signal::new("units_language_parameters_validity", 0, 7, 1),
+    Signal::new("sound_harmony", 1, 1, 2).values(&[
+        (0, "Harmony1"),
+        (1, "Harmony2"),
+        (2, "Harmony3"),
+        (3, "Harmony4"),
+    ]),
+    Signal::new("parameters_validity", 1, 2, 1),
+    Signal::new("mood_lighting_level", 1, 5, 3).values(&[
+        (0, "Level1"),
+        (1, "Level2"),
+        (2, "Level3"),
+        (3, "Level4"),
+        (5, "Level5"),
+        (6, "Level6"),
+    ]),
+    Signal::new("temperature_unit", 1, 6, 1).values(&[(0, "Celsius"), (1, "Fahrenheit")]),
+    Signal::new("volume_unit", 1, 7, 1).values(&[(0, "Liter"), (1, "Gallon")]),
+    Signal::new("mood_lighting_enabled", 2, 0, 1),
+    Signal::new("daytime_running_lamps_enabled", 2, 1, 1),
+    Signal::new("adaptive_lamps_enabled", 2, 2, 1),
+    Signal::new("welcome_function_enabled", 2, 3, 1),
+    Signal::new("boot_selective_unlocking_enabled", 2, 4, 1),
+    Signal::new("selective_unlocking_enabled", 2, 5, 1),
+    Signal::new("key_selective_unlocking_enabled", 2, 6, 1),
+    Signal::new("automatic_elec_parking_brake_application_enabled", 2, 7, 1),
+    Signal::new("automatic_headlamps_enabled", 3, 0, 1),
+    Signal::new("welcome_lighting_duration", 3, 2, 2).values(&[
+        (0, "FifteenSeconds"),
+        (1, "ThirtySeconds"),
+        (2, "SixtySeconds"),
+    ]),
+    Signal::new("welcome_lighting_enabled", 3, 3, 1),
+    Signal::new("motorway_lighting_enabled", 3, 4, 1),
+    Signal::new("follow_me_home_lighting_duration", 3, 6, 2).values(&[
+        (0, "FifteenSeconds"),
+        (1, "ThirtySeconds"),
+        (2, "SixtySeconds"),
+    ]),
+    Signal::new("follow_me_home_enabled", 3, 7, 1),
+    Signal::new("configurable_key_mode", 4, 3, 4).values(&[
+        (0, "CeilingLight"),
+        (1, "BlackPanel"),
+        (2, "FaultLog"),
+        (3, "ClusterCustomization"),
+        (5, "ClusterColor"),
+        (6, "ManualFaultCheck"),
+    ]),
+    Signal::new("motorized_tailgate_enabled", 4, 4, 1),
+    Signal::new("rear_wiper_in_reverse_gear_enabled", 4, 5, 1),
+    Signal::new("blind_spot_monitoring_enabled", 4, 6, 1),
+    Signal::new("park_sensors_enabled", 4, 7, 1),
+    Signal::new("mirrors_tilting_in_reverse_gear_enabled", 5, 2, 1),
+    Signal::new("indirect_under_inflation_enabled", 5, 3, 1),
+    Signal::new("automatic_emergency_braking_enabled", 5, 4, 1),
+    Signal::new("collision_alert_sensibility_level", 5, 6, 2).values(&[
+        (1, "Close"),
+        (2, "Normal"),
+        (3, "Distant"),
+    ]),
+    Signal::new("collision_alert_enabled", 5, 7, 1),
+    Signal::new("hands_free_tailgate_enabled", 6, 2, 1),
+    Signal::new("speed_limit_recognition_enabled", 6, 3, 1),
+    Signal::new("radiator_grill_lamps_enabled", 6, 4, 1),
+    Signal::new("automatic_main_beam_enabled", 6, 5, 1),
+    Signal::new("driver_alert_assist_enabled", 6, 0, 1),
+    Signal::new("hands_free_tailgate_auto_lock_enabled", 6, 1, 1),
+    Signal::new("extended_traffic_sign_recognition_enabled", 5, 0, 1),
+    Signal::new("electric_child_security_temp_disabled", 5, 1, 1),
+    Signal::new("auto_mirrors_folding_inhibit", 7, 3, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x15b")?;
-        writeln!(f, " consumption_unit={}", self.consumption_unit)?;
-        writeln!(f, " distance_unit={}", self.distance_unit)?;
-        writeln!(f, " language={}", self.language)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x15b")?;
+        write!(f, "{}consumption_unit={}", sep, self.consumption_unit)?;
+        write!(f, "{}distance_unit={}", sep, self.distance_unit)?;
+        write!(f, "{}language={}", sep, self.language)?;
+        write!(
+            f,
+            "{}units_language_parameters_validity={}",
+            sep, self.units_language_parameters_validity
+        )?;
+        write!(f, "{}sound_harmony={}", sep, self.sound_harmony)?;
+        write!(f, "{}parameters_validity={}", sep, self.parameters_validity)?;
+        write!(f, "{}mood_lighting_level={}", sep, self.mood_lighting_level)?;
+        write!(f, "{}temperature_unit={}", sep, self.temperature_unit)?;
+        write!(f, "{}volume_unit={}", sep, self.volume_unit)?;
+        write!(
+            f,
+            "{}mood_lighting_enabled={}",
+            sep, self.mood_lighting_enabled
+        )?;
+        write!(
+            f,
+            "{}daytime_running_lamps_enabled={}",
+            sep, self.daytime_running_lamps_enabled
+        )?;
+        write!(
+            f,
+            "{}adaptive_lamps_enabled={}",
+            sep, self.adaptive_lamps_enabled
+        )?;
+        write!(
             f,
-            " units_language_parameters_validity={}",
-            self.units_language_parameters_validity
+            "{}welcome_function_enabled={}",
+            sep, self.welcome_function_enabled
         )?;
-        writeln!(f, " sound_harmony={}", self.sound_harmony)?;
-        writeln!(f, " parameters_validity={}", self.parameters_validity)?;
-        writeln!(f, " mood_lighting_level={}", self.mood_lighting_level)?;
-        writeln!(f, " temperature_unit={}", self.temperature_unit)?;
-        writeln!(f, " volume_unit={}", self.volume_unit)?;
-        writeln!(f, " mood_lighting_enabled={}", self.mood_lighting_enabled)?;
-        writeln!(
+        write!(
             f,
-            " daytime_running_lamps_enabled={}",
-            self.daytime_running_lamps_enabled
+            "{}boot_selective_unlocking_enabled={}",
+            sep, self.boot_selective_unlocking_enabled
         )?;
-        writeln!(f, " adaptive_lamps_enabled={}", self.adaptive_lamps_enabled)?;
-        writeln!(
+        write!(
             f,
-            " welcome_function_enabled={}",
-            self.welcome_function_enabled
+            "{}selective_unlocking_enabled={}",
+            sep, self.selective_unlocking_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " boot_selective_unlocking_enabled={}",
-            self.boot_selective_unlocking_enabled
+            "{}key_selective_unlocking_enabled={}",
+            sep, self.key_selective_unlocking_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " selective_unlocking_enabled={}",
-            self.selective_unlocking_enabled
+            "{}automatic_elec_parking_brake_application_enabled={}",
+            sep, self.automatic_elec_parking_brake_application_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " key_selective_unlocking_enabled={}",
-            self.key_selective_unlocking_enabled
+            "{}automatic_headlamps_enabled={}",
+            sep, self.automatic_headlamps_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_elec_parking_brake_application_enabled={}",
-            self.automatic_elec_parking_brake_application_enabled
+            "{}welcome_lighting_duration={}",
+            sep, self.welcome_lighting_duration
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_headlamps_enabled={}",
-            self.automatic_headlamps_enabled
+            "{}welcome_lighting_enabled={}",
+            sep, self.welcome_lighting_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " welcome_lighting_duration={}",
-            self.welcome_lighting_duration
+            "{}motorway_lighting_enabled={}",
+            sep, self.motorway_lighting_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " welcome_lighting_enabled={}",
-            self.welcome_lighting_enabled
+            "{}follow_me_home_lighting_duration={}",
+            sep, self.follow_me_home_lighting_duration
         )?;
-        writeln!(
+        write!(
             f,
-            " motorway_lighting_enabled={}",
-            self.motorway_lighting_enabled
+            "{}follow_me_home_enabled={}",
+            sep, self.follow_me_home_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " follow_me_home_lighting_duration={}",
-            self.follow_me_home_lighting_duration
+            "{}configurable_key_mode={}",
+            sep, self.configurable_key_mode
         )?;
-        writeln!(f, " follow_me_home_enabled={}", self.follow_me_home_enabled)?;
-        writeln!(f, " configurable_key_mode={}", self.configurable_key_mode)?;
-        writeln!(
+        write!(
             f,
-            " motorized_tailgate_enabled={}",
-            self.motorized_tailgate_enabled
+            "{}motorized_tailgate_enabled={}",
+            sep, self.motorized_tailgate_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_wiper_in_reverse_gear_enabled={}",
-            self.rear_wiper_in_reverse_gear_enabled
+            "{}rear_wiper_in_reverse_gear_enabled={}",
+            sep, self.rear_wiper_in_reverse_gear_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_monitoring_enabled={}",
-            self.blind_spot_monitoring_enabled
+            "{}blind_spot_monitoring_enabled={}",
+            sep, self.blind_spot_monitoring_enabled
         )?;
-        writeln!(f, " park_sensors_enabled={}", self.park_sensors_enabled)?;
-        writeln!(
+        write!(
             f,
-            " mirrors_tilting_in_reverse_gear_enabled={}",
-            self.mirrors_tilting_in_reverse_gear_enabled
+            "{}park_sensors_enabled={}",
+            sep, self.park_sensors_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " indirect_under_inflation_enabled={}",
-            self.indirect_under_inflation_enabled
+            "{}mirrors_tilting_in_reverse_gear_enabled={}",
+            sep, self.mirrors_tilting_in_reverse_gear_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_emergency_braking_enabled={}",
-            self.automatic_emergency_braking_enabled
+            "{}indirect_under_inflation_enabled={}",
+            sep, self.indirect_under_inflation_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " collision_alert_sensibility_level={}",
-            self.collision_alert_sensibility_level
+            "{}automatic_emergency_braking_enabled={}",
+            sep, self.automatic_emergency_braking_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " collision_alert_enabled={}",
-            self.collision_alert_enabled
+            "{}collision_alert_sensibility_level={}",
+            sep, self.collision_alert_sensibility_level
         )?;
-        writeln!(
+        write!(
             f,
-            " hands_free_tailgate_enabled={}",
-            self.hands_free_tailgate_enabled
+            "{}collision_alert_enabled={}",
+            sep, self.collision_alert_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " speed_limit_recognition_enabled={}",
-            self.speed_limit_recognition_enabled
+            "{}hands_free_tailgate_enabled={}",
+            sep, self.hands_free_tailgate_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " radiator_grill_lamps_enabled={}",
-            self.radiator_grill_lamps_enabled
+            "{}speed_limit_recognition_enabled={}",
+            sep, self.speed_limit_recognition_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_main_beam_enabled={}",
-            self.automatic_main_beam_enabled
+            "{}radiator_grill_lamps_enabled={}",
+            sep, self.radiator_grill_lamps_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " driver_alert_assist_enabled={}",
-            self.driver_alert_assist_enabled
+            "{}automatic_main_beam_enabled={}",
+            sep, self.automatic_main_beam_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " hands_free_tailgate_auto_lock_enabled={}",
-            self.hands_free_tailgate_auto_lock_enabled
+            "{}driver_alert_assist_enabled={}",
+            sep, self.driver_alert_assist_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " extended_traffic_sign_recognition_enabled={}",
-            self.extended_traffic_sign_recognition_enabled
+            "{}hands_free_tailgate_auto_lock_enabled={}",
+            sep, self.hands_free_tailgate_auto_lock_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " electric_child_security_temp_disabled={}",
-            self.electric_child_security_temp_disabled
+            "{}extended_traffic_sign_recognition_enabled={}",
+            sep, self.extended_traffic_sign_recognition_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " auto_mirrors_folding_inhibit={}",
-            self.auto_mirrors_folding_inhibit
+            "{}electric_child_security_temp_disabled={}",
+            sep, self.electric_child_security_temp_disabled
+        )?;
+        write!(
+            f,
+            "{}auto_mirrors_folding_inhibit={}",
+            sep, self.auto_mirrors_folding_inhibit
         )
     }
 }
 
+impl From<&crate::aee2004::conf::x15b::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x15b::Repr) -> Self {
+        Repr {
+            consumption_unit: ConsumptionUnit::VolumePerDistance, // No equivalent on AEE2004.
+            distance_unit: DistanceUnit::Kilometer,               // No equivalent on AEE2004.
+            language: Language::English,                          // No equivalent on AEE2004.
+            units_language_parameters_validity: true,             // No equivalent on AEE2004.
+            sound_harmony: SoundHarmony::Harmony1,                // No equivalent on AEE2004.
+            parameters_validity: repr_2004.parameters_validity,
+            mood_lighting_level: MoodLightingLevel::Level3, // No equivalent on AEE2004.
+            temperature_unit: TemperatureUnit::Celsius,     // No equivalent on AEE2004.
+            volume_unit: VolumeUnit::Liter,                 // No equivalent on AEE2004.
+            mood_lighting_enabled: repr_2004.mood_lighting_enabled,
+            daytime_running_lamps_enabled: repr_2004.daytime_running_lamps_enabled,
+            adaptive_lamps_enabled: repr_2004.adaptive_lamps_enabled,
+            welcome_function_enabled: repr_2004.welcome_function_enabled,
+            boot_selective_unlocking_enabled: repr_2004.boot_permanent_locking_enabled, // No such thing on AEE2010.
+            selective_unlocking_enabled: repr_2004.selective_unlocking_enabled,
+            key_selective_unlocking_enabled: repr_2004.auto_door_locking_when_leaving_enabled,
+            automatic_elec_parking_brake_application_enabled: repr_2004
+                .auto_elec_parking_brake_application_enabled,
+            automatic_headlamps_enabled: repr_2004.automatic_headlamps_enabled,
+            welcome_lighting_duration: LightingDuration2010::FifteenSeconds, // No equivalent on AEE2004.
+            welcome_lighting_enabled: false,
+            motorway_lighting_enabled: repr_2004.motorway_lighting_enabled,
+            follow_me_home_lighting_duration: repr_2004.follow_me_home_lighting_duration.into(),
+            follow_me_home_enabled: repr_2004.follow_me_home_enabled,
+            configurable_key_mode: repr_2004.configurable_key_mode.into(),
+            motorized_tailgate_enabled: false, // No equivalent on AEE2004.
+            rear_wiper_in_reverse_gear_enabled: repr_2004.rear_wiper_in_reverse_gear_enabled,
+            blind_spot_monitoring_enabled: false, // No equivalent on AEE2004.
+            park_sensors_enabled: repr_2004.park_sensors_status > 0,
+            mirrors_tilting_in_reverse_gear_enabled: repr_2004
+                .mirrors_tilting_in_reverse_gear_enabled,
+            indirect_under_inflation_enabled: false, // No equivalent on AEE2004.
+            automatic_emergency_braking_enabled: true, // FARC is equivalent on AEE2004, but not possible to disable.
+            collision_alert_sensibility_level: CollisionAlertSensibilityLevel::Normal, // No equivalent on AEE2004.
+            collision_alert_enabled: false, // No equivalent on AEE2004.
+            hands_free_tailgate_enabled: false, // No equivalent on AEE2004.
+            speed_limit_recognition_enabled: false, // No equivalent on AEE2004.
+            radiator_grill_lamps_enabled: false, // No equivalent on AEE2004.
+            automatic_main_beam_enabled: false, // No equivalent on AEE2004.
+            driver_alert_assist_enabled: false, // No equivalent on AEE2004.
+            hands_free_tailgate_auto_lock_enabled: false, // No equivalent on AEE2004.
+            extended_traffic_sign_recognition_enabled: false, // No equivalent on AEE2004.
+            electric_child_security_temp_disabled: false, // No equivalent on AEE2004.
+            auto_mirrors_folding_inhibit: false, // No equivalent on AEE2004.
+        }
+    }
+}
+
+impl From<&crate::aee2010::infodiv::x260::Repr> for Repr {
+    fn from(repr_x260: &crate::aee2010::infodiv::x260::Repr) -> Self {
+        Repr {
+            consumption_unit: repr_x260.consumption_unit,
+            distance_unit: repr_x260.distance_unit,
+            language: repr_x260.language,
+            units_language_parameters_validity: repr_x260.units_language_parameters_validity,
+            sound_harmony: repr_x260.sound_harmony,
+            parameters_validity: repr_x260.parameters_validity,
+            mood_lighting_level: repr_x260.mood_lighting_level,
+            temperature_unit: repr_x260.temperature_unit,
+            volume_unit: repr_x260.volume_unit,
+            mood_lighting_enabled: repr_x260.mood_lighting_enabled,
+            daytime_running_lamps_enabled: repr_x260.daytime_running_lamps_enabled,
+            adaptive_lamps_enabled: repr_x260.adaptive_lamps_enabled,
+            welcome_function_enabled: repr_x260.welcome_function_enabled,
+            boot_selective_unlocking_enabled: repr_x260.boot_selective_unlocking_enabled,
+            selective_unlocking_enabled: repr_x260.selective_unlocking_enabled,
+            key_selective_unlocking_enabled: repr_x260.key_selective_unlocking_enabled,
+            automatic_elec_parking_brake_application_enabled: repr_x260
+                .automatic_elec_parking_brake_application_enabled,
+            automatic_headlamps_enabled: repr_x260.automatic_headlamps_enabled,
+            welcome_lighting_duration: repr_x260.welcome_lighting_duration,
+            welcome_lighting_enabled: repr_x260.welcome_lighting_enabled,
+            motorway_lighting_enabled: repr_x260.motorway_lighting_enabled,
+            follow_me_home_lighting_duration: repr_x260.follow_me_home_lighting_duration,
+            follow_me_home_enabled: repr_x260.follow_me_home_enabled,
+            configurable_key_mode: repr_x260.configurable_key_mode,
+            motorized_tailgate_enabled: repr_x260.motorized_tailgate_enabled,
+            rear_wiper_in_reverse_gear_enabled: repr_x260.rear_wiper_in_reverse_gear_enabled,
+            blind_spot_monitoring_enabled: repr_x260.blind_spot_monitoring_enabled,
+            park_sensors_enabled: repr_x260.park_sensors_enabled,
+            mirrors_tilting_in_reverse_gear_enabled: repr_x260
+                .mirrors_tilting_in_reverse_gear_enabled,
+            indirect_under_inflation_enabled: false, // The x260 flag is a reset status.
+            automatic_emergency_braking_enabled: repr_x260.automatic_emergency_braking_enabled,
+            collision_alert_sensibility_level: repr_x260.collision_alert_sensibility_level,
+            collision_alert_enabled: repr_x260.collision_alert_enabled,
+            hands_free_tailgate_enabled: repr_x260.hands_free_tailgate_enabled,
+            speed_limit_recognition_enabled: repr_x260.speed_limit_recognition_enabled,
+            radiator_grill_lamps_enabled: repr_x260.radiator_grill_lamps_enabled,
+            automatic_main_beam_enabled: repr_x260.automatic_main_beam_enabled,
+            driver_alert_assist_enabled: repr_x260.driver_alert_assist_enabled,
+            hands_free_tailgate_auto_lock_enabled: repr_x260.hands_free_tailgate_auto_lock_enabled,
+            extended_traffic_sign_recognition_enabled: repr_x260
+                .extended_traffic_sign_recognition_enabled,
+            electric_child_security_temp_disabled: false, // No temporary disabling requested.
+            auto_mirrors_folding_inhibit: repr_x260.auto_mirrors_folding_inhibit,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
@@ -1577,4 +1885,30 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_repr_parse_strict() {
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
+        assert_eq!(frame.reserved_bits(), 0);
+        assert_eq!(Repr::parse_strict(&frame), Repr::parse(&frame));
+
+        let bytes: [u8; 8] = [0x01, 0x00, 0xab, 0xaa, 0xa3, 0xaa, 0x2a, 0x80];
+        let frame = Frame::new_unchecked(&bytes);
+        assert_eq!(frame.reserved_bits(), 0x80);
+        assert_eq!(Repr::parse_strict(&frame).unwrap_err(), Error::Invalid);
+        assert!(Repr::parse(&frame).is_ok());
+    }
+
+    #[test]
+    fn test_x260_round_trip() {
+        let status = crate::aee2010::infodiv::x260::Repr {
+            mood_lighting_enabled: true,
+            electric_child_security_enabled: true,
+            ..crate::aee2010::infodiv::x260::Repr::default()
+        };
+        let request = Repr::from(&status);
+        assert!(request.mood_lighting_enabled);
+        assert!(!request.electric_child_security_temp_disabled);
+        assert_eq!(crate::aee2010::infodiv::x260::Repr::from(&request), status);
+    }
 }
@@ -3,7 +3,10 @@ use core::{cmp::Ordering, fmt};
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
+    field::{read_be, write_be},
+    frame::{field_separator, TransmissionKind},
     mfd::{Menu, Popup, TripComputerPage, UserAction2010},
+    signal::Signal,
     Error, Result,
 };
 
@@ -69,6 +72,9 @@ pub const FRAME_ID: u16 = 0x167;
 /// Length of a x167 CAN frame.
 pub const FRAME_LEN: usize = field::MENU_ACTION + 1;
 
+/// Transmission of a x167 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnRequest;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -198,14 +204,14 @@ impl<T: AsRef<[u8]>> Frame<T> {
     #[inline]
     pub fn total_trip_distance(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::TOTAL_TRIP_DISTANCE])
+        read_be(data, field::TOTAL_TRIP_DISTANCE.start, 7, 16) as u16
     }
 
     /// Return the interactive message field.
     #[inline]
     pub fn interactive_message(&self) -> u16 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u16(&data[field::INTERACTIVE_MSG_STOP_CHK]) & 0x7fff
+        read_be(data, field::INTERACTIVE_MSG_STOP_CHK.start, 6, 15) as u16
     }
 
     /// Return the MFD stop check request field.
@@ -352,16 +358,20 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_total_trip_distance(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u16(&mut data[field::TOTAL_TRIP_DISTANCE], value);
+        write_be(data, field::TOTAL_TRIP_DISTANCE.start, 7, 16, value.into());
     }
 
     /// Set the interactive message field.
     #[inline]
     pub fn set_interactive_message(&mut self, value: u16) {
         let data = self.buffer.as_mut();
-        let raw = NetworkEndian::read_u16(&data[field::INTERACTIVE_MSG_STOP_CHK]);
-        let raw = raw | (value & 0x7fff);
-        NetworkEndian::write_u16(&mut data[field::INTERACTIVE_MSG_STOP_CHK], raw);
+        write_be(
+            data,
+            field::INTERACTIVE_MSG_STOP_CHK.start,
+            6,
+            15,
+            value.into(),
+        );
     }
 
     /// Set the MFD stop check request field.
@@ -402,16 +412,16 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_user_action_on_mfd(&mut self, value: UserAction2010) {
         let data = self.buffer.as_mut();
-        let raw = data[field::MENU_ACTION];
-        let raw = raw | (u8::from(value) << 6);
+        let raw = data[field::MENU_ACTION] & !0xc0;
+        let raw = raw | ((u8::from(value) & 0x03) << 6);
         data[field::MENU_ACTION] = raw;
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x167 ({})", err)?;
                 Ok(())
@@ -426,9 +436,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x167 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub mfd_trip_computer_page: TripComputerPage,
     pub maintenance_reset_request: bool,
@@ -454,7 +470,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             mfd_trip_computer_page: frame.mfd_trip_computer_page(),
             maintenance_reset_request: frame.maintenance_reset_request(),
             emergency_call_in_progress: frame.emergency_call_in_progress(),
@@ -468,14 +489,18 @@ impl Repr {
             black_panel_enabled: frame.black_panel_enabled(),
             indirect_under_inflation_reset_request: frame.indirect_under_inflation_reset_request(),
             pre_conditioning_request: frame.pre_conditioning_request(),
-            total_trip_distance: frame.total_trip_distance() * 2,
+            total_trip_distance: if frame.total_trip_distance() > 0x7fff {
+                0xffff
+            } else {
+                frame.total_trip_distance() * 2
+            },
             interactive_message: frame.interactive_message(),
             stop_check_request: frame.stop_check_request(),
             popup_id_acknowledge: frame.popup_id_ack(),
             selected_menu: frame.selected_menu(),
             wifi_parameters_acknowledge: frame.wifi_parameters_ack(),
             user_action_on_mfd: frame.user_action_on_mfd(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -495,14 +520,23 @@ impl Repr {
         frame.set_trip_computer_primary_trip_reset_request(
             self.trip_computer_primary_trip_reset_request,
         );
-        frame.set_pre_conditioning_time(self.pre_conditioning_time * 5);
+        // The field only holds its 0x08 bit, parsed as 8 / 5 = 1.
+        frame.set_pre_conditioning_time(if self.pre_conditioning_time != 0 {
+            0x08
+        } else {
+            0x00
+        });
         frame.set_telematics_enabled(self.telematics_enabled);
         frame.set_black_panel_enabled(self.black_panel_enabled);
         frame.set_indirect_under_inflation_reset_request(
             self.indirect_under_inflation_reset_request,
         );
         frame.set_pre_conditioning_request(self.pre_conditioning_request);
-        frame.set_total_trip_distance(self.total_trip_distance / 2);
+        frame.set_total_trip_distance(if self.total_trip_distance == 0xffff {
+            0xffff
+        } else {
+            self.total_trip_distance / 2
+        });
         frame.set_interactive_message(self.interactive_message);
         frame.set_stop_check_request(self.stop_check_request);
         frame.set_popup_id_ack(self.popup_id_acknowledge);
@@ -512,58 +546,175 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x08, 0x00, 0xff, 0xff, 0x7f, 0xff, 0x00, 0x00],
+    parked: [0x08, 0x00, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00],
+    with_mfd_trip_computer_page => mfd_trip_computer_page: TripComputerPage,
+    with_maintenance_reset_request => maintenance_reset_request: bool,
+    with_emergency_call_in_progress => emergency_call_in_progress: bool,
+    with_fault_recall_request => fault_recall_request: bool,
+    with_trip_computer_secondary_trip_reset_request => trip_computer_secondary_trip_reset_request: bool,
+    with_trip_computer_primary_trip_reset_request => trip_computer_primary_trip_reset_request: bool,
+    with_pre_conditioning_time => pre_conditioning_time: u8,
+    with_telematics_enabled => telematics_enabled: bool,
+    with_black_panel_enabled => black_panel_enabled: bool,
+    with_indirect_under_inflation_reset_request => indirect_under_inflation_reset_request: bool,
+    with_pre_conditioning_request => pre_conditioning_request: bool,
+    with_total_trip_distance => total_trip_distance: u16,
+    with_interactive_message => interactive_message: u16,
+    with_stop_check_request => stop_check_request: bool,
+    with_popup_id_acknowledge => popup_id_acknowledge: Popup,
+    with_selected_menu => selected_menu: Menu,
+    with_wifi_parameters_acknowledge => wifi_parameters_acknowledge: bool,
+    with_user_action_on_mfd => user_action_on_mfd: UserAction2010,
+}
+
+/// Signals of a x167 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("mfd_trip_computer_page", 0, 2, 3).values(&[
+        (0, "Nothing"),
+        (1, "GeneralParameters"),
+        (2, "Trip1"),
+        (4, "Trip2"),
+        (5, "Page4"),
+        (7, "NotManagedByMFD"),
+    ]),
+    Signal::new("maintenance_reset_request", 0, 3, 1).values(&[(0, "true"), (1, "false")]),
+    Signal::new("emergency_call_in_progress", 0, 4, 1),
+    Signal::new("fault_recall_request", 0, 5, 1),
+    Signal::new("trip_computer_secondary_trip_reset_request", 0, 6, 1),
+    Signal::new("trip_computer_primary_trip_reset_request", 0, 7, 1),
+    Signal::new("pre_conditioning_time", 1, 3, 1),
+    Signal::new("telematics_enabled", 1, 4, 1),
+    Signal::new("black_panel_enabled", 1, 5, 1),
+    Signal::new("indirect_under_inflation_reset_request", 1, 6, 1),
+    Signal::new("pre_conditioning_request", 1, 7, 1),
+    Signal::new("total_trip_distance", 2, 7, 16)
+        .scaled(2.0, 0.0)
+        .unit("km"),
+    Signal::new("interactive_message", 4, 6, 15),
+    Signal::new("stop_check_request", 4, 7, 1),
+    Signal::new("popup_id_acknowledge", 6, 7, 8).values(&[
+        (0, "NoDisplay"),
+        (1, "IncomingAdvisorCall"),
+        (2, "InitiatedAdvisorCall"),
+        (3, "InitiatedEmergencyCall"),
+        (4, "ConnectedAdvisorCall"),
+        (5, "ConnectedEmergencyCall"),
+        (6, "SendingVehicleLocation"),
+        (7, "OnstarMainMenu"),
+        (8, "RoamingActive"),
+        (9, "RoamingEnded"),
+    ]),
+    Signal::new("selected_menu", 7, 4, 3).values(&[
+        (0, "WifiSettings"),
+        (1, "DataRoaming"),
+        (2, "PrivacySettings"),
+        (3, "Cancel"),
+        (7, "NoDisplay"),
+    ]),
+    Signal::new("wifi_parameters_acknowledge", 7, 5, 1),
+    Signal::new("user_action_on_mfd", 7, 7, 2).values(&[
+        (0, "NoAction"),
+        (1, "Dismiss"),
+        (2, "Yes"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x167")?;
+        write!(
             f,
-            "x167 mfd_trip_computer_page={}",
-            self.mfd_trip_computer_page
+            "{}mfd_trip_computer_page={}",
+            sep, self.mfd_trip_computer_page
         )?;
-        writeln!(
+        write!(
             f,
-            " maintenance_reset_request={}",
-            self.maintenance_reset_request
+            "{}maintenance_reset_request={}",
+            sep, self.maintenance_reset_request
         )?;
-        writeln!(
+        write!(
             f,
-            " emergency_call_in_progress={}",
-            self.emergency_call_in_progress
+            "{}emergency_call_in_progress={}",
+            sep, self.emergency_call_in_progress
         )?;
-        writeln!(f, " fault_recall_request={}", self.fault_recall_request)?;
-        writeln!(
+        write!(
             f,
-            " trip_computer_secondary_trip_reset_request={}",
-            self.trip_computer_secondary_trip_reset_request
+            "{}fault_recall_request={}",
+            sep, self.fault_recall_request
         )?;
-        writeln!(
+        write!(
             f,
-            " trip_computer_primary_trip_reset_request={}",
-            self.trip_computer_primary_trip_reset_request
+            "{}trip_computer_secondary_trip_reset_request={}",
+            sep, self.trip_computer_secondary_trip_reset_request
         )?;
-        writeln!(f, " preconditioning_time={}", self.pre_conditioning_time)?;
-        writeln!(f, " telematics_enabled={}", self.telematics_enabled)?;
-        writeln!(f, " black_panel_enabled={}", self.black_panel_enabled)?;
-        writeln!(
+        write!(
             f,
-            " indirect_under_inflation_reset_request={}",
-            self.indirect_under_inflation_reset_request
+            "{}trip_computer_primary_trip_reset_request={}",
+            sep, self.trip_computer_primary_trip_reset_request
         )?;
-        writeln!(
+        write!(
             f,
-            " pre_conditioning_request={}",
-            self.pre_conditioning_request
+            "{}preconditioning_time={}",
+            sep, self.pre_conditioning_time
         )?;
-        writeln!(f, " total_trip_distance={}", self.total_trip_distance)?;
-        writeln!(f, " interactive_message={}", self.interactive_message)?;
-        writeln!(f, " stopcheck_request={}", self.stop_check_request)?;
-        writeln!(f, " popup_id_acknowledge={}", self.popup_id_acknowledge)?;
-        writeln!(f, " selected_menu={}", self.selected_menu)?;
-        writeln!(
+        write!(f, "{}telematics_enabled={}", sep, self.telematics_enabled)?;
+        write!(f, "{}black_panel_enabled={}", sep, self.black_panel_enabled)?;
+        write!(
             f,
-            " wifi_parameters_acknowledge={}",
-            self.wifi_parameters_acknowledge
+            "{}indirect_under_inflation_reset_request={}",
+            sep, self.indirect_under_inflation_reset_request
         )?;
-        writeln!(f, " user_action_on_mfd={}", self.user_action_on_mfd)
+        write!(
+            f,
+            "{}pre_conditioning_request={}",
+            sep, self.pre_conditioning_request
+        )?;
+        write!(f, "{}total_trip_distance={}", sep, self.total_trip_distance)?;
+        write!(f, "{}interactive_message={}", sep, self.interactive_message)?;
+        write!(f, "{}stopcheck_request={}", sep, self.stop_check_request)?;
+        write!(
+            f,
+            "{}popup_id_acknowledge={}",
+            sep, self.popup_id_acknowledge
+        )?;
+        write!(f, "{}selected_menu={}", sep, self.selected_menu)?;
+        write!(
+            f,
+            "{}wifi_parameters_acknowledge={}",
+            sep, self.wifi_parameters_acknowledge
+        )?;
+        write!(f, "{}user_action_on_mfd={}", sep, self.user_action_on_mfd)
+    }
+}
+
+impl From<&crate::aee2004::conf::x167::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x167::Repr) -> Self {
+        Repr {
+            mfd_trip_computer_page: repr_2004.mfd_trip_computer_page,
+            maintenance_reset_request: repr_2004.maintenance_reset_request,
+            emergency_call_in_progress: repr_2004.emergency_call_in_progress,
+            fault_recall_request: repr_2004.fault_recall_request,
+            trip_computer_secondary_trip_reset_request: repr_2004
+                .trip_computer_secondary_trip_reset_request,
+            trip_computer_primary_trip_reset_request: repr_2004
+                .trip_computer_primary_trip_reset_request,
+            pre_conditioning_time: repr_2004.pre_conditioning_time,
+            telematics_enabled: repr_2004.telematics_enabled,
+            black_panel_enabled: repr_2004.black_panel_enabled,
+            indirect_under_inflation_reset_request: repr_2004
+                .indirect_under_inflation_reset_request,
+            pre_conditioning_request: repr_2004.pre_conditioning_request,
+            total_trip_distance: repr_2004.total_trip_distance,
+            interactive_message: repr_2004.interactive_message,
+            stop_check_request: false, // No equivalent on AEE2004.
+            popup_id_acknowledge: Popup::NoDisplay, // No equivalent on AEE2004.
+            selected_menu: Menu::WifiSettings, // No equivalent on AEE2004.
+            wifi_parameters_acknowledge: false, // No equivalent on AEE2004.
+            user_action_on_mfd: repr_2004.user_action_on_mfd.into(),
+        }
     }
 }
 
@@ -571,7 +722,7 @@ impl fmt::Display for Repr {
 mod test {
     use super::{Frame, Repr};
     use crate::{
-        mfd::{Menu, Popup, TripComputerPage, UserAction2010},
+        mfd::{Menu, Popup, TripComputerPage, UserAction2004, UserAction2010},
         Error,
     };
 
@@ -726,6 +877,49 @@ mod test {
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
 
+    #[test]
+    fn test_interactive_message_overwrite() {
+        let mut bytes = [0u8; 8];
+        let mut frame = Frame::new_unchecked(&mut bytes);
+        frame.set_stop_check_request(true);
+        frame.set_interactive_message(0x7fff);
+        frame.set_interactive_message(0x0123);
+        assert_eq!(frame.interactive_message(), 0x0123);
+        assert!(frame.stop_check_request());
+    }
+
+    #[test]
+    fn test_total_trip_distance() {
+        let mut buf = [0u8; 8];
+        let repr = Repr {
+            total_trip_distance: 40000,
+            ..frame_1_repr()
+        };
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        let frame = Frame::new_unchecked(&buf);
+        assert_eq!(frame.total_trip_distance(), 20000);
+        assert_eq!(Repr::parse(&frame), Ok(repr));
+
+        // Raw values above 0x7fff are all decoded as unavailable.
+        Frame::new_unchecked(&mut buf).set_total_trip_distance(0x8000);
+        let repr = Repr::parse(&Frame::new_unchecked(&buf)).unwrap();
+        assert_eq!(repr.total_trip_distance, 0xffff);
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        assert_eq!(Frame::new_unchecked(&buf).total_trip_distance(), 0xffff);
+    }
+
+    #[test]
+    fn test_pre_conditioning_time() {
+        let mut buf = [0u8; 8];
+        Frame::new_unchecked(&mut buf).set_pre_conditioning_time(0x08);
+        let repr = Repr::parse(&Frame::new_unchecked(&buf)).unwrap();
+        assert_eq!(repr.pre_conditioning_time, 1);
+
+        let mut buf = [0u8; 8];
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        assert_eq!(Frame::new_unchecked(&buf).pre_conditioning_time(), 0x08);
+    }
+
     #[test]
     fn test_overlong() {
         let bytes: [u8; 9] = [0x08, 0x00, 0x00, 0x00, 0x7f, 0xff, 0x00, 0x00, 0xff];
@@ -772,4 +966,15 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x167::Repr::builder()
+            .with_user_action_on_mfd(UserAction2004::ValueReturn)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2004);
+        assert_eq!(repr.user_action_on_mfd, UserAction2010::Yes);
+        assert_eq!(repr.popup_id_acknowledge, Popup::NoDisplay);
+    }
 }
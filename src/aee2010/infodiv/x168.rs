@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
         GearboxDriveModeGear, IndicatorState, LaneCenteringIndicatorState,
         SteeringAssistanceFaultType, SteeringAssistanceIndicatorState,
@@ -131,6 +133,9 @@ pub const FRAME_ID: u16 = 0x168;
 /// Length of a x168 CAN frame.
 pub const FRAME_LEN: usize = field::FLAGS_8 + 1;
 
+/// Transmission of a x168 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnChange;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -282,10 +287,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x168 ({})", err)?;
                 Ok(())
@@ -300,9 +305,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x168 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub under_inflation_failure: bool,
     pub cold_engine_alert: bool,
@@ -343,7 +354,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             under_inflation_failure: frame.read_bit::<{ field::FLAGS_1 }, 0>(),
             cold_engine_alert: frame.read_bit::<{ field::FLAGS_1 }, 1>(),
             low_brake_fluid_level_alert: frame.read_bit::<{ field::FLAGS_1 }, 2>(),
@@ -377,7 +393,7 @@ impl Repr {
             gearbox_drive_mode_gear: frame.gearbox_drive_mode_gear(),
             lane_centering_indicator: frame.lane_centering_indicator(),
             automatic_emergency_braking_indicator: frame.automatic_emergency_braking_indicator(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -423,113 +439,233 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x55, 0x50, 0x01, 0x14, 0x44, 0x94, 0x11, 0x84],
+    with_under_inflation_failure => under_inflation_failure: bool,
+    with_cold_engine_alert => cold_engine_alert: bool,
+    with_low_brake_fluid_level_alert => low_brake_fluid_level_alert: bool,
+    with_low_oil_pressure_alert => low_oil_pressure_alert: bool,
+    with_low_oil_level_alert => low_oil_level_alert: bool,
+    with_low_coolant_level_alert => low_coolant_level_alert: bool,
+    with_gearbox_has_more_than_six_speed => gearbox_has_more_than_six_speed: bool,
+    with_coolant_temperature_alert => coolant_temperature_alert: bool,
+    with_automatic_wipers_enabled => automatic_wipers_enabled: bool,
+    with_particulate_filter_indicator => particulate_filter_indicator: bool,
+    with_anti_emission_fault => anti_emission_fault: bool,
+    with_tyre_puncture_alert => tyre_puncture_alert: bool,
+    with_under_inflation_alert_flag => under_inflation_alert_flag: bool,
+    with_electrical_generator_fault => electrical_generator_fault: bool,
+    with_battery_charge_fault => battery_charge_fault: bool,
+    with_ebd_fault => ebd_fault: bool,
+    with_obd_fault => obd_fault: bool,
+    with_worn_brake_pad_fault => worn_brake_pad_fault: bool,
+    with_gearbox_fault => gearbox_fault: bool,
+    with_esp_asr_fault => esp_asr_fault: bool,
+    with_abs_fault => abs_fault: bool,
+    with_steering_assistance_fault => steering_assistance_fault: bool,
+    with_passive_safety_fault => passive_safety_fault: bool,
+    with_turn_lights_fault => turn_lights_fault: bool,
+    with_water_in_diesel => water_in_diesel: bool,
+    with_steering_assistance_fault_type_validity => steering_assistance_fault_type_validity: bool,
+    with_steering_assistance_fault_type => steering_assistance_fault_type: SteeringAssistanceFaultType,
+    with_steering_assistance_indicator_validity => steering_assistance_indicator_validity: bool,
+    with_steering_assistance_indicator => steering_assistance_indicator: SteeringAssistanceIndicatorState,
+    with_braking_assistance_fault => braking_assistance_fault: bool,
+    with_gearbox_drive_mode_gear => gearbox_drive_mode_gear: GearboxDriveModeGear,
+    with_lane_centering_indicator => lane_centering_indicator: LaneCenteringIndicatorState,
+    with_automatic_emergency_braking_indicator => automatic_emergency_braking_indicator: IndicatorState,
+}
+
+/// Signals of a x168 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("under_inflation_failure", 0, 0, 1),
+    Signal::new("cold_engine_alert", 0, 1, 1),
+    Signal::new("low_brake_fluid_level_alert", 0, 2, 1),
+    Signal::new("low_oil_pressure_alert", 0, 3, 1),
+    Signal::new("low_oil_level_alert", 0, 4, 1),
+    Signal::new("low_coolant_level_alert", 0, 5, 1),
+    Signal::new("gearbox_has_more_than_six_speed", 0, 6, 1),
+    Signal::new("coolant_temperature_alert", 0, 7, 1),
+    Signal::new("automatic_wipers_enabled", 1, 3, 1),
+    Signal::new("particulate_filter_indicator", 1, 4, 1),
+    Signal::new("anti_emission_fault", 1, 5, 1),
+    Signal::new("tyre_puncture_alert", 1, 6, 1),
+    Signal::new("under_inflation_alert_flag", 1, 7, 1),
+    Signal::new("electrical_generator_fault", 2, 0, 1),
+    Signal::new("battery_charge_fault", 2, 1, 1),
+    Signal::new("ebd_fault", 2, 3, 1),
+    Signal::new("obd_fault", 3, 1, 1),
+    Signal::new("worn_brake_pad_fault", 3, 2, 1),
+    Signal::new("gearbox_fault", 3, 3, 1),
+    Signal::new("esp_asr_fault", 3, 4, 1),
+    Signal::new("abs_fault", 3, 5, 1),
+    Signal::new("steering_assistance_fault", 4, 2, 1),
+    Signal::new("passive_safety_fault", 4, 5, 1),
+    Signal::new("turn_lights_fault", 4, 6, 1),
+    Signal::new("water_in_diesel", 4, 7, 1),
+    Signal::new("steering_assistance_fault_type_validity", 5, 2, 1),
+    Signal::new("steering_assistance_fault_type", 5, 4, 2).values(&[
+        (0, "None"),
+        (1, "G4"),
+        (2, "G3"),
+        (3, "G3AndG4"),
+    ]),
+    Signal::new("steering_assistance_indicator_validity", 5, 5, 1),
+    Signal::new("steering_assistance_indicator", 5, 7, 2).values(&[
+        (0, "Off"),
+        (1, "Red"),
+        (2, "Orange"),
+    ]),
+    Signal::new("braking_assistance_fault", 6, 0, 1),
+    Signal::new("gearbox_drive_mode_gear", 6, 4, 4).values(&[
+        (0, "Disengaged"),
+        (1, "Gear1"),
+        (2, "Gear2"),
+        (3, "Gear3"),
+        (4, "Gear4"),
+        (5, "Gear5"),
+        (6, "Gear6"),
+        (7, "Gear7"),
+        (8, "Gear8"),
+        (9, "Gear9"),
+    ]),
+    Signal::new("lane_centering_indicator", 7, 3, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "BlinkingFault"),
+        (3, "BlinkingAlert"),
+    ]),
+    Signal::new("automatic_emergency_braking_indicator", 7, 7, 2).values(&[
+        (0, "Off"),
+        (1, "On"),
+        (2, "Blinking"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x168")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x168")?;
+        write!(
+            f,
+            "{}under_inflation_failure={}",
+            sep, self.under_inflation_failure
+        )?;
+        write!(f, "{}cold_engine_alert={}", sep, self.cold_engine_alert)?;
+        write!(
+            f,
+            "{}low_brake_fluid_level_alert={}",
+            sep, self.low_brake_fluid_level_alert
+        )?;
+        write!(
+            f,
+            "{}low_oil_pressure_alert={}",
+            sep, self.low_oil_pressure_alert
+        )?;
+        write!(f, "{}low_oil_level_alert={}", sep, self.low_oil_level_alert)?;
+        write!(
+            f,
+            "{}low_coolant_level_alert={}",
+            sep, self.low_coolant_level_alert
+        )?;
+        write!(
             f,
-            " under_inflation_failure={}",
-            self.under_inflation_failure
+            "{}gearbox_has_more_than_six_speed={}",
+            sep, self.gearbox_has_more_than_six_speed
         )?;
-        writeln!(f, " cold_engine_alert={}", self.cold_engine_alert)?;
-        writeln!(
+        write!(
             f,
-            " low_brake_fluid_level_alert={}",
-            self.low_brake_fluid_level_alert
+            "{}coolant_temperature_alert={}",
+            sep, self.coolant_temperature_alert
         )?;
-        writeln!(f, " low_oil_pressure_alert={}", self.low_oil_pressure_alert)?;
-        writeln!(f, " low_oil_level_alert={}", self.low_oil_level_alert)?;
-        writeln!(
+        write!(
             f,
-            " low_coolant_level_alert={}",
-            self.low_coolant_level_alert
+            "{}automatic_wipers_enabled={}",
+            sep, self.automatic_wipers_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " gearbox_has_more_than_six_speed={}",
-            self.gearbox_has_more_than_six_speed
+            "{}particulate_filter_indicator={}",
+            sep, self.particulate_filter_indicator
         )?;
-        writeln!(
+        write!(f, "{}anti_emission_fault={}", sep, self.anti_emission_fault)?;
+        write!(f, "{}tyre_puncture_alert={}", sep, self.tyre_puncture_alert)?;
+        write!(
             f,
-            " coolant_temperature_alert={}",
-            self.coolant_temperature_alert
+            "{}under_inflation_alert_flag={}",
+            sep, self.under_inflation_alert_flag
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_wipers_enabled={}",
-            self.automatic_wipers_enabled
+            "{}electrical_generator_fault={}",
+            sep, self.electrical_generator_fault
         )?;
-        writeln!(
+        write!(
             f,
-            " particulate_filter_indicator={}",
-            self.particulate_filter_indicator
+            "{}battery_charge_fault={}",
+            sep, self.battery_charge_fault
         )?;
-        writeln!(f, " anti_emission_fault={}", self.anti_emission_fault)?;
-        writeln!(f, " tyre_puncture_alert={}", self.tyre_puncture_alert)?;
-        writeln!(
+        write!(f, "{}ebd_fault={}", sep, self.ebd_fault)?;
+        write!(f, "{}obd_fault={}", sep, self.obd_fault)?;
+        write!(
             f,
-            " under_inflation_alert_flag={}",
-            self.under_inflation_alert_flag
+            "{}worn_brake_pad_fault={}",
+            sep, self.worn_brake_pad_fault
         )?;
-        writeln!(
+        write!(f, "{}gearbox_fault={}", sep, self.gearbox_fault)?;
+        write!(f, "{}esp_asr_fault={}", sep, self.esp_asr_fault)?;
+        write!(f, "{}abs_fault={}", sep, self.abs_fault)?;
+        write!(
             f,
-            " electrical_generator_fault={}",
-            self.electrical_generator_fault
+            "{}steering_assistance_fault={}",
+            sep, self.steering_assistance_fault
         )?;
-        writeln!(f, " battery_charge_fault={}", self.battery_charge_fault)?;
-        writeln!(f, " ebd_fault={}", self.ebd_fault)?;
-        writeln!(f, " obd_fault={}", self.obd_fault)?;
-        writeln!(f, " worn_brake_pad_fault={}", self.worn_brake_pad_fault)?;
-        writeln!(f, " gearbox_fault={}", self.gearbox_fault)?;
-        writeln!(f, " esp_asr_fault={}", self.esp_asr_fault)?;
-        writeln!(f, " abs_fault={}", self.abs_fault)?;
-        writeln!(
+        write!(
             f,
-            " steering_assistance_fault={}",
-            self.steering_assistance_fault
+            "{}passive_safety_fault={}",
+            sep, self.passive_safety_fault
         )?;
-        writeln!(f, " passive_safety_fault={}", self.passive_safety_fault)?;
-        writeln!(f, " turn_lights_fault={}", self.turn_lights_fault)?;
-        writeln!(f, " water_in_diesel={}", self.water_in_diesel)?;
-        writeln!(
+        write!(f, "{}turn_lights_fault={}", sep, self.turn_lights_fault)?;
+        write!(f, "{}water_in_diesel={}", sep, self.water_in_diesel)?;
+        write!(
             f,
-            " steering_assistance_fault_type_validity={}",
-            self.steering_assistance_fault_type_validity
+            "{}steering_assistance_fault_type_validity={}",
+            sep, self.steering_assistance_fault_type_validity
         )?;
-        writeln!(
+        write!(
             f,
-            " steering_assistance_fault_type={}",
-            self.steering_assistance_fault_type
+            "{}steering_assistance_fault_type={}",
+            sep, self.steering_assistance_fault_type
         )?;
-        writeln!(
+        write!(
             f,
-            " steering_assistance_indicator_validity={}",
-            self.steering_assistance_indicator_validity
+            "{}steering_assistance_indicator_validity={}",
+            sep, self.steering_assistance_indicator_validity
         )?;
-        writeln!(
+        write!(
             f,
-            " steering_assistance_indicator={}",
-            self.steering_assistance_indicator
+            "{}steering_assistance_indicator={}",
+            sep, self.steering_assistance_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " braking_assistance_fault={}",
-            self.braking_assistance_fault
+            "{}braking_assistance_fault={}",
+            sep, self.braking_assistance_fault
         )?;
-        writeln!(
+        write!(
             f,
-            " gearbox_drive_mode_gear={}",
-            self.gearbox_drive_mode_gear
+            "{}gearbox_drive_mode_gear={}",
+            sep, self.gearbox_drive_mode_gear
         )?;
-        writeln!(
+        write!(
             f,
-            " lane_centering_indicator={}",
-            self.lane_centering_indicator
+            "{}lane_centering_indicator={}",
+            sep, self.lane_centering_indicator
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_emergency_braking_indicator={}",
-            self.automatic_emergency_braking_indicator
+            "{}automatic_emergency_braking_indicator={}",
+            sep, self.automatic_emergency_braking_indicator
         )
     }
 }
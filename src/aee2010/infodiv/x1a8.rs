@@ -1,8 +1,14 @@
 use core::{cmp::Ordering, fmt};
 
-use byteorder::{ByteOrder, NetworkEndian};
+use crate::{
+    field::{read_be, write_be},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
-use crate::{Error, Result};
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -37,6 +43,9 @@ pub const FRAME_ID: u16 = 0x1a8;
 /// Length of a x1a8 CAN frame.
 pub const FRAME_LEN: usize = field::ODOMETER.end;
 
+/// Transmission of a x1a8 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnChange;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -101,7 +110,7 @@ impl<T: AsRef<[u8]>> Frame<T> {
     #[inline]
     pub fn partial_odometer(&self) -> u32 {
         let data = self.buffer.as_ref();
-        NetworkEndian::read_u24(&data[field::ODOMETER])
+        read_be(data, field::ODOMETER.start, 7, 24) as u32
     }
 }
 
@@ -128,14 +137,14 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     #[inline]
     pub fn set_partial_odometer(&mut self, value: u32) {
         let data = self.buffer.as_mut();
-        NetworkEndian::write_u24(&mut data[field::ODOMETER], value);
+        write_be(data, field::ODOMETER.start, 7, 24, value.into());
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1a8 ({})", err)?;
                 Ok(())
@@ -150,14 +159,20 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1a8 CAN frame.
 #[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub limit_reached: bool,
     pub pre_programming_state: bool,
     #[cfg(feature = "float")]
-    pub partial_odometer: f32,
+    pub partial_odometer: Physical,
     #[cfg(not(feature = "float"))]
     pub partial_odometer: u32,
 }
@@ -166,14 +181,19 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             limit_reached: frame.limit_reached(),
             pre_programming_state: frame.pre_programming_state(),
             #[cfg(feature = "float")]
-            partial_odometer: (frame.partial_odometer() as f32 / 10.0),
+            partial_odometer: physical::from_raw(frame.partial_odometer().into(), 0, 10),
             #[cfg(not(feature = "float"))]
             partial_odometer: frame.partial_odometer(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -186,24 +206,60 @@ impl Repr {
         frame.set_limit_reached(self.limit_reached);
         frame.set_pre_programming_state(self.pre_programming_state);
         #[cfg(feature = "float")]
-        frame.set_partial_odometer((self.partial_odometer * 10.0) as u32);
+        frame.set_partial_odometer(physical::to_u32(self.partial_odometer, 0, 10));
         #[cfg(not(feature = "float"))]
         frame.set_partial_odometer(self.partial_odometer);
     }
 }
 
+repr_builder! {
+    idle: [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0x84],
+    with_limit_reached => limit_reached: bool,
+    with_pre_programming_state => pre_programming_state: bool,
+    #[cfg(feature = "float")]
+    with_partial_odometer => partial_odometer: Physical,
+    #[cfg(not(feature = "float"))]
+    with_partial_odometer => partial_odometer: u32,
+}
+
+/// Signals of a x1a8 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("limit_reached", 0, 0, 1),
+    Signal::new("pre_programming_state", 0, 1, 1),
+    Signal::new("partial_odometer", 5, 7, 24)
+        .scaled(0.1, 0.0)
+        .unit("km"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sep = field_separator(f);
         write!(f, "x1a8")?;
-        write!(f, " limit_reached={}", self.limit_reached)?;
-        write!(f, " pre_programming_state={}", self.pre_programming_state)?;
-        write!(f, " partial_odometer={}", self.partial_odometer)
+        write!(f, "{}limit_reached={}", sep, self.limit_reached)?;
+        write!(
+            f,
+            "{}pre_programming_state={}",
+            sep, self.pre_programming_state
+        )?;
+        write!(f, "{}partial_odometer={}", sep, self.partial_odometer)
+    }
+}
+
+impl From<&crate::aee2004::conf::x1a8::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x1a8::Repr) -> Self {
+        Repr {
+            limit_reached: false,         // No equivalent on AEE2004.
+            pre_programming_state: false, // No equivalent on AEE2004.
+            partial_odometer: repr_2004.partial_odometer,
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
 
     use crate::Error;
 
@@ -214,7 +270,7 @@ mod test {
         Repr {
             limit_reached: true,
             pre_programming_state: false,
-            partial_odometer: 653.2,
+            partial_odometer: physical::decimal(6532, 10),
         }
     }
 
@@ -222,7 +278,7 @@ mod test {
         Repr {
             limit_reached: false,
             pre_programming_state: true,
-            partial_odometer: 325.4,
+            partial_odometer: physical::decimal(3254, 10),
         }
     }
 
@@ -313,4 +369,21 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x1a8::Repr::parse(
+            &crate::aee2004::conf::x1a8::Frame::new_unchecked(&[
+                0x44, 0x00, 0x82, 0x00, 0x00, 0x00, 0x19, 0x84,
+            ]),
+        )
+        .unwrap();
+        assert_eq!(
+            Repr::from(&repr_2004),
+            Repr {
+                limit_reached: false,
+                ..frame_1_repr()
+            }
+        );
+    }
 }
@@ -3,6 +3,8 @@ use core::{cmp::Ordering, fmt, time::Duration};
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{AutomaticParkingMode, CruiseControlCustomSettingPosition},
     Error, Result,
 };
@@ -103,6 +105,8 @@ pub const FRAME_LEN: usize = field::REQ_4 + 1;
 
 /// Periodicity of a x1a9 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(200);
+/// Transmission of a x1a9 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -653,10 +657,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1a9 ({})", err)?;
                 Ok(())
@@ -671,9 +675,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1a9 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub trip_computer_secondary_trip_reset_request: bool,
     pub trip_computer_primary_trip_reset_request: bool,
@@ -712,7 +722,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             trip_computer_secondary_trip_reset_request: frame
                 .trip_computer_secondary_trip_reset_request(),
             trip_computer_primary_trip_reset_request: frame
@@ -757,7 +772,7 @@ impl Repr {
                 .rear_visual_parking_assistance_button_state(),
             visual_parking_assistance_activation_request: frame
                 .visual_parking_assistance_activation_request(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -829,138 +844,227 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x85, 0x00, 0x00, 0x55, 0x00, 0x14, 0x01, 0x50],
+    with_trip_computer_secondary_trip_reset_request => trip_computer_secondary_trip_reset_request: bool,
+    with_trip_computer_primary_trip_reset_request => trip_computer_primary_trip_reset_request: bool,
+    with_adaptive_cruise_control_button_state => adaptive_cruise_control_button_state: bool,
+    with_automatic_parking_mode => automatic_parking_mode: AutomaticParkingMode,
+    with_telematics_enabled => telematics_enabled: bool,
+    with_black_panel_enabled => black_panel_enabled: bool,
+    with_interactive_message => interactive_message: u16,
+    with_stop_check_request => stop_check_request: bool,
+    with_cruise_control_custom_speed_memorization_request => cruise_control_custom_speed_memorization_request: bool,
+    with_available_space_measurement_button_state => available_space_measurement_button_state: bool,
+    with_parking_sensors_button_state => parking_sensors_button_state: bool,
+    with_auto_main_beam_button_state => auto_main_beam_button_state: bool,
+    with_lane_centering_button_state => lane_centering_button_state: bool,
+    with_blind_spot_monitoring_button_state => blind_spot_monitoring_button_state: bool,
+    with_adaptive_cruise_control_plus_button_state => adaptive_cruise_control_plus_button_state: bool,
+    with_adaptive_cruise_control_minus_button_state => adaptive_cruise_control_minus_button_state: bool,
+    with_cruise_control_speed_instruction => cruise_control_speed_instruction: u8,
+    with_indirect_under_inflation_button_state => indirect_under_inflation_button_state: bool,
+    with_automatic_parking_state_change_request => automatic_parking_state_change_request: bool,
+    with_collision_alert_failure_display_request => collision_alert_failure_display_request: bool,
+    with_cruise_control_speed_setting_instruction_position => cruise_control_speed_setting_instruction_position: CruiseControlCustomSettingPosition,
+    with_fault_check_request => fault_check_request: bool,
+    with_telematic_screen_lighting_level => telematic_screen_lighting_level: u8,
+    with_telematic_unit_life_state => telematic_unit_life_state: u8,
+    with_stop_start_button_state => stop_start_button_state: bool,
+    with_visual_parking_assistance_button_state => visual_parking_assistance_button_state: u8,
+    with_cruise_control_speed_instruction_value_request => cruise_control_speed_instruction_value_request: bool,
+    with_visual_parking_assistance_panoramic_view_button_state => visual_parking_assistance_panoramic_view_button_state: bool,
+    with_front_visual_parking_assistance_button_state => front_visual_parking_assistance_button_state: bool,
+    with_rear_visual_parking_assistance_button_state => rear_visual_parking_assistance_button_state: bool,
+    with_visual_parking_assistance_activation_request => visual_parking_assistance_activation_request: bool,
+}
+
+/// Signals of a x1a9 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("trip_computer_secondary_trip_reset_request", 0, 0, 1),
+    Signal::new("trip_computer_primary_trip_reset_request", 0, 1, 1),
+    Signal::new("adaptive_cruise_control_button_state", 0, 2, 1),
+    Signal::new("automatic_parking_mode", 0, 4, 2).values(&[(0, "SCP6"), (1, "SCP9")]),
+    Signal::new("telematics_enabled", 0, 5, 1),
+    Signal::new("black_panel_enabled", 0, 7, 1),
+    Signal::new("interactive_message", 1, 6, 15),
+    Signal::new("stop_check_request", 1, 7, 1),
+    Signal::new("cruise_control_custom_speed_memorization_request", 3, 0, 1),
+    Signal::new("available_space_measurement_button_state", 3, 1, 1),
+    Signal::new("parking_sensors_button_state", 3, 2, 1),
+    Signal::new("auto_main_beam_button_state", 3, 3, 1),
+    Signal::new("lane_centering_button_state", 3, 4, 1),
+    Signal::new("blind_spot_monitoring_button_state", 3, 5, 1),
+    Signal::new("adaptive_cruise_control_plus_button_state", 3, 6, 1),
+    Signal::new("adaptive_cruise_control_minus_button_state", 3, 7, 1),
+    Signal::new("cruise_control_speed_instruction", 4, 7, 8).unit("km/h"),
+    Signal::new("indirect_under_inflation_button_state", 5, 0, 1),
+    Signal::new("automatic_parking_state_change_request", 5, 2, 1),
+    Signal::new("collision_alert_failure_display_request", 5, 3, 1),
+    Signal::new("cruise_control_speed_setting_instruction_position", 5, 6, 3).values(&[
+        (0, "None"),
+        (1, "Position1"),
+        (2, "Position2"),
+        (3, "Position3"),
+        (4, "Position4"),
+        (5, "Position5"),
+        (6, "Position6"),
+    ]),
+    Signal::new("fault_check_request", 6, 0, 1),
+    Signal::new("telematic_screen_lighting_level", 6, 4, 4),
+    Signal::new("telematic_unit_life_state", 6, 6, 2),
+    Signal::new("stop_start_button_state", 6, 7, 1),
+    Signal::new("visual_parking_assistance_button_state", 7, 2, 3),
+    Signal::new("cruise_control_speed_instruction_value_request", 7, 3, 1),
+    Signal::new(
+        "visual_parking_assistance_panoramic_view_button_state",
+        7,
+        4,
+        1,
+    ),
+    Signal::new("front_visual_parking_assistance_button_state", 7, 5, 1),
+    Signal::new("rear_visual_parking_assistance_button_state", 7, 6, 1),
+    Signal::new("visual_parking_assistance_activation_request", 7, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x1a9")?;
+        write!(
+            f,
+            "{}trip_computer_secondary_trip_reset_request={}",
+            sep, self.trip_computer_secondary_trip_reset_request
+        )?;
+        write!(
             f,
-            "x1a9 trip_computer_secondary_trip_reset_request={}",
-            self.trip_computer_secondary_trip_reset_request
+            "{}trip_computer_primary_trip_reset_request={}",
+            sep, self.trip_computer_primary_trip_reset_request
         )?;
-        writeln!(
+        write!(
             f,
-            " trip_computer_primary_trip_reset_request={}",
-            self.trip_computer_primary_trip_reset_request
+            "{}adaptive_cruise_control_button_state={}",
+            sep, self.adaptive_cruise_control_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " adaptive_cruise_control_button_state={}",
-            self.adaptive_cruise_control_button_state
+            "{}automatic_parking_mode={}",
+            sep, self.automatic_parking_mode
         )?;
-        writeln!(f, " automatic_parking_mode={}", self.automatic_parking_mode)?;
-        writeln!(f, " telematics_enabled={}", self.telematics_enabled)?;
-        writeln!(f, " black_panel_enabled={}", self.black_panel_enabled)?;
-        writeln!(f, " interactive_message={}", self.interactive_message)?;
-        writeln!(f, " stop_check_request={}", self.stop_check_request)?;
-        writeln!(
+        write!(f, "{}telematics_enabled={}", sep, self.telematics_enabled)?;
+        write!(f, "{}black_panel_enabled={}", sep, self.black_panel_enabled)?;
+        write!(f, "{}interactive_message={}", sep, self.interactive_message)?;
+        write!(f, "{}stop_check_request={}", sep, self.stop_check_request)?;
+        write!(
             f,
-            " cruise_control_custom_speed_memorization_request={}",
-            self.cruise_control_custom_speed_memorization_request
+            "{}cruise_control_custom_speed_memorization_request={}",
+            sep, self.cruise_control_custom_speed_memorization_request
         )?;
-        writeln!(
+        write!(
             f,
-            " available_space_measurement_button_state={}",
-            self.available_space_measurement_button_state
+            "{}available_space_measurement_button_state={}",
+            sep, self.available_space_measurement_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " parking_sensors_button_state={}",
-            self.parking_sensors_button_state
+            "{}parking_sensors_button_state={}",
+            sep, self.parking_sensors_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " auto_main_beam_button_state={}",
-            self.auto_main_beam_button_state
+            "{}auto_main_beam_button_state={}",
+            sep, self.auto_main_beam_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " lane_centering_button_state={}",
-            self.lane_centering_button_state
+            "{}lane_centering_button_state={}",
+            sep, self.lane_centering_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_monitoring_button_state={}",
-            self.blind_spot_monitoring_button_state
+            "{}blind_spot_monitoring_button_state={}",
+            sep, self.blind_spot_monitoring_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " adaptive_cruise_control_plus_button_state={}",
-            self.adaptive_cruise_control_plus_button_state
+            "{}adaptive_cruise_control_plus_button_state={}",
+            sep, self.adaptive_cruise_control_plus_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " adaptive_cruise_control_minus_button_state={}",
-            self.adaptive_cruise_control_minus_button_state
+            "{}adaptive_cruise_control_minus_button_state={}",
+            sep, self.adaptive_cruise_control_minus_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " cruise_control_speed_instruction={}",
-            self.cruise_control_speed_instruction
+            "{}cruise_control_speed_instruction={}",
+            sep, self.cruise_control_speed_instruction
         )?;
-        writeln!(
+        write!(
             f,
-            " indirect_under_inflation_button_state={}",
-            self.indirect_under_inflation_button_state
+            "{}indirect_under_inflation_button_state={}",
+            sep, self.indirect_under_inflation_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " automatic_parking_state_change_request={}",
-            self.automatic_parking_state_change_request
+            "{}automatic_parking_state_change_request={}",
+            sep, self.automatic_parking_state_change_request
         )?;
-        writeln!(
+        write!(
             f,
-            " collision_alert_failure_display_request={}",
-            self.collision_alert_failure_display_request
+            "{}collision_alert_failure_display_request={}",
+            sep, self.collision_alert_failure_display_request
         )?;
-        writeln!(
+        write!(
             f,
-            " cruise_control_speed_setting_instruction_position={}",
-            self.cruise_control_speed_setting_instruction_position
+            "{}cruise_control_speed_setting_instruction_position={}",
+            sep, self.cruise_control_speed_setting_instruction_position
         )?;
-        writeln!(f, " fault_check_request={}", self.fault_check_request)?;
-        writeln!(
+        write!(f, "{}fault_check_request={}", sep, self.fault_check_request)?;
+        write!(
             f,
-            " telematic_screen_lighting_level={}",
-            self.telematic_screen_lighting_level
+            "{}telematic_screen_lighting_level={}",
+            sep, self.telematic_screen_lighting_level
         )?;
-        writeln!(
+        write!(
             f,
-            " telematic_unit_life_state={}",
-            self.telematic_unit_life_state
+            "{}telematic_unit_life_state={}",
+            sep, self.telematic_unit_life_state
         )?;
-        writeln!(
+        write!(
             f,
-            " stop_start_button_state={}",
-            self.stop_start_button_state
+            "{}stop_start_button_state={}",
+            sep, self.stop_start_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " visual_parking_assistance_button_state={}",
-            self.visual_parking_assistance_button_state
+            "{}visual_parking_assistance_button_state={}",
+            sep, self.visual_parking_assistance_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " cruise_control_speed_instruction_value_request={}",
-            self.cruise_control_speed_instruction_value_request
+            "{}cruise_control_speed_instruction_value_request={}",
+            sep, self.cruise_control_speed_instruction_value_request
         )?;
-        writeln!(
+        write!(
             f,
-            " visual_parking_assistance_panoramic_view_button_state={}",
-            self.visual_parking_assistance_panoramic_view_button_state
+            "{}visual_parking_assistance_panoramic_view_button_state={}",
+            sep, self.visual_parking_assistance_panoramic_view_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " front_visual_parking_assistance_button_state={}",
-            self.front_visual_parking_assistance_button_state
+            "{}front_visual_parking_assistance_button_state={}",
+            sep, self.front_visual_parking_assistance_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " rear_visual_parking_assistance_button_state={}",
-            self.rear_visual_parking_assistance_button_state
+            "{}rear_visual_parking_assistance_button_state={}",
+            sep, self.rear_visual_parking_assistance_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " visual_parking_assistance_activation_request={}",
-            self.visual_parking_assistance_activation_request
+            "{}visual_parking_assistance_activation_request={}",
+            sep, self.visual_parking_assistance_activation_request
         )
     }
 }
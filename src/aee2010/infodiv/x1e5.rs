@@ -1,7 +1,9 @@
 use core::{cmp::Ordering, fmt};
 
 use crate::{
-    config::{ConfigOption, MusicalAmbiance, SoundRepartition},
+    config::{ConfigOption, MusicalAmbiance, SoundRepartition, SpeedDependentVolumeLaw},
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result,
 };
 
@@ -54,6 +56,9 @@ pub const FRAME_ID: u16 = 0x1e5;
 /// Length of a x1e5 CAN frame.
 pub const FRAME_LEN: usize = field::SPATIAL_SPECTRAL_REPARTITION + 1;
 
+/// Transmission of a x1e5 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnChange;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -545,10 +550,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x1e5 ({})", err)?;
                 Ok(())
@@ -563,9 +568,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x1e5 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub balance_opt: ConfigOption,
     pub balance_level: u8,
@@ -600,7 +611,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             balance_opt: frame.balance_option(),
             balance_level: frame.balance_level(),
             balance_under_adj: frame.balance_under_adjustment(),
@@ -628,7 +644,7 @@ impl Repr {
             spatial_sound_under_adj: frame.spatial_sound_under_adjustment(),
             spectral_sound_under_adj: frame.spectral_sound_under_adjustment(),
             impossible_setting: frame.impossible_setting(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -668,63 +684,233 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x7e, 0x7e, 0x7e, 0x7e, 0x56, 0x82, 0x70],
+    parked: [0x7c, 0x7c, 0x7c, 0x7c, 0x00, 0x00, 0x00],
+    with_balance_opt => balance_opt: ConfigOption,
+    with_balance_level => balance_level: u8,
+    with_balance_under_adj => balance_under_adj: bool,
+    with_fader_opt => fader_opt: ConfigOption,
+    with_fader_level => fader_level: u8,
+    with_fader_under_adj => fader_under_adj: bool,
+    with_bass_opt => bass_opt: ConfigOption,
+    with_bass_level => bass_level: u8,
+    with_bass_under_adj => bass_under_adj: bool,
+    with_treble_opt => treble_opt: ConfigOption,
+    with_treble_level => treble_level: u8,
+    with_treble_under_adj => treble_under_adj: bool,
+    with_speed_dependent_volume_opt => speed_dependent_volume_opt: ConfigOption,
+    with_speed_dependent_volume_enabled => speed_dependent_volume_enabled: bool,
+    with_speed_dependent_volume_under_adj => speed_dependent_volume_under_adj: bool,
+    with_loudness_opt => loudness_opt: ConfigOption,
+    with_loudness_enabled => loudness_enabled: bool,
+    with_loudness_under_adj => loudness_under_adj: bool,
+    with_musical_ambiance_opt => musical_ambiance_opt: ConfigOption,
+    with_musical_ambiance => musical_ambiance: MusicalAmbiance,
+    with_musical_ambiance_under_adj => musical_ambiance_under_adj: bool,
+    with_sound_repartition_opt => sound_repartition_opt: ConfigOption,
+    with_sound_repartition => sound_repartition: SoundRepartition,
+    with_sound_repartition_under_adj => sound_repartition_under_adj: bool,
+    with_spatial_sound_under_adj => spatial_sound_under_adj: bool,
+    with_spectral_sound_under_adj => spectral_sound_under_adj: bool,
+    with_impossible_setting => impossible_setting: bool,
+}
+
+/// Signals of a x1e5 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("balance_opt", 0, 1, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("balance_level", 0, 6, 5),
+    Signal::new("balance_under_adj", 0, 7, 1),
+    Signal::new("fader_opt", 1, 1, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("fader_level", 1, 6, 5),
+    Signal::new("fader_under_adj", 1, 7, 1),
+    Signal::new("bass_opt", 2, 1, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("bass_level", 2, 6, 5),
+    Signal::new("bass_under_adj", 2, 7, 1),
+    Signal::new("treble_opt", 3, 1, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("treble_level", 3, 6, 5),
+    Signal::new("treble_under_adj", 3, 7, 1),
+    Signal::new("speed_dependent_volume_opt", 4, 1, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("speed_dependent_volume_enabled", 4, 2, 1),
+    Signal::new("speed_dependent_volume_under_adj", 4, 3, 1),
+    Signal::new("loudness_opt", 4, 6, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("loudness_enabled", 4, 4, 1),
+    Signal::new("loudness_under_adj", 4, 7, 1),
+    Signal::new("musical_ambiance_opt", 5, 7, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("musical_ambiance", 5, 5, 3).values(&[
+        (0, "None"),
+        (1, "Classic"),
+        (2, "JazzBlues"),
+        (3, "PopRock"),
+        (4, "Vocal"),
+        (5, "Techno"),
+    ]),
+    Signal::new("musical_ambiance_under_adj", 5, 2, 1),
+    Signal::new("sound_repartition_opt", 5, 1, 2).values(&[
+        (0, "Unavailable"),
+        (1, "UnselectableOption"),
+        (2, "SelectableOption"),
+    ]),
+    Signal::new("sound_repartition", 6, 6, 3).values(&[
+        (0, "Off"),
+        (1, "Driver"),
+        (2, "Surround"),
+        (7, "AllPassengers"),
+    ]),
+    Signal::new("sound_repartition_under_adj", 6, 7, 1),
+    Signal::new("spatial_sound_under_adj", 6, 1, 1),
+    Signal::new("spectral_sound_under_adj", 6, 2, 1),
+    Signal::new("impossible_setting", 6, 3, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x1e5 balance opt={}", self.balance_opt)?;
-        writeln!(f, " balance_level={}", self.balance_level)?;
-        writeln!(f, " balance_under_adj={}", self.balance_under_adj)?;
-        writeln!(f, " fader_opt={}", self.fader_opt)?;
-        writeln!(f, " fader_level={}", self.fader_level)?;
-        writeln!(f, " fader_under_adj={}", self.fader_under_adj)?;
-        writeln!(f, " bass_opt={}", self.bass_opt)?;
-        writeln!(f, " bass_level={}", self.bass_level)?;
-        writeln!(f, " bass_under_adj={}", self.bass_under_adj)?;
-        writeln!(f, " treble_opt={}", self.treble_opt)?;
-        writeln!(f, " treble_level={}", self.treble_level)?;
-        writeln!(f, " treble_under_adj={}", self.treble_under_adj)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x1e5")?;
+        write!(f, "{}balance_opt={}", sep, self.balance_opt)?;
+        write!(f, "{}balance_level={}", sep, self.balance_level)?;
+        write!(f, "{}balance_under_adj={}", sep, self.balance_under_adj)?;
+        write!(f, "{}fader_opt={}", sep, self.fader_opt)?;
+        write!(f, "{}fader_level={}", sep, self.fader_level)?;
+        write!(f, "{}fader_under_adj={}", sep, self.fader_under_adj)?;
+        write!(f, "{}bass_opt={}", sep, self.bass_opt)?;
+        write!(f, "{}bass_level={}", sep, self.bass_level)?;
+        write!(f, "{}bass_under_adj={}", sep, self.bass_under_adj)?;
+        write!(f, "{}treble_opt={}", sep, self.treble_opt)?;
+        write!(f, "{}treble_level={}", sep, self.treble_level)?;
+        write!(f, "{}treble_under_adj={}", sep, self.treble_under_adj)?;
+        write!(
             f,
-            " speed_dependent_volume_opt={}",
-            self.speed_dependent_volume_opt
+            "{}speed_dependent_volume_opt={}",
+            sep, self.speed_dependent_volume_opt
         )?;
-        writeln!(
+        write!(
             f,
-            " speed_dependent_volume_enabled={}",
-            self.speed_dependent_volume_enabled
+            "{}speed_dependent_volume_enabled={}",
+            sep, self.speed_dependent_volume_enabled
         )?;
-        writeln!(
+        write!(
             f,
-            " speed_dependent_volume_under_adj={}",
-            self.speed_dependent_volume_under_adj
+            "{}speed_dependent_volume_under_adj={}",
+            sep, self.speed_dependent_volume_under_adj
         )?;
-        writeln!(f, " loudness_opt={}", self.loudness_opt)?;
-        writeln!(f, " loudness_enabled={}", self.loudness_enabled)?;
-        writeln!(f, " loudness_under_adj={}", self.loudness_under_adj)?;
-        writeln!(f, " musical_ambiance_opt={}", self.musical_ambiance_opt)?;
-        writeln!(f, " musical_ambiance={}", self.musical_ambiance)?;
-        writeln!(
+        write!(f, "{}loudness_opt={}", sep, self.loudness_opt)?;
+        write!(f, "{}loudness_enabled={}", sep, self.loudness_enabled)?;
+        write!(f, "{}loudness_under_adj={}", sep, self.loudness_under_adj)?;
+        write!(
             f,
-            " musical_ambiance_under_adj={}",
-            self.musical_ambiance_under_adj
+            "{}musical_ambiance_opt={}",
+            sep, self.musical_ambiance_opt
         )?;
-        writeln!(f, " sound_repartition_opt={}", self.sound_repartition_opt)?;
-        writeln!(f, " sound_repartition ={}", self.sound_repartition)?;
-        writeln!(
+        write!(f, "{}musical_ambiance={}", sep, self.musical_ambiance)?;
+        write!(
             f,
-            " sound_repartition_under_adj={}",
-            self.sound_repartition_under_adj
+            "{}musical_ambiance_under_adj={}",
+            sep, self.musical_ambiance_under_adj
         )?;
-        writeln!(
+        write!(
             f,
-            " spatial_sound_under_adj={}",
-            self.spatial_sound_under_adj
+            "{}sound_repartition_opt={}",
+            sep, self.sound_repartition_opt
         )?;
-        writeln!(
+        write!(f, "{}sound_repartition={}", sep, self.sound_repartition)?;
+        write!(
             f,
-            " spectral_sound_under_adj={}",
-            self.spectral_sound_under_adj
+            "{}sound_repartition_under_adj={}",
+            sep, self.sound_repartition_under_adj
         )?;
-        writeln!(f, " impossible_setting={}", self.impossible_setting)
+        write!(
+            f,
+            "{}spatial_sound_under_adj={}",
+            sep, self.spatial_sound_under_adj
+        )?;
+        write!(
+            f,
+            "{}spectral_sound_under_adj={}",
+            sep, self.spectral_sound_under_adj
+        )?;
+        write!(f, "{}impossible_setting={}", sep, self.impossible_setting)
+    }
+}
+
+/// Return the AEE2010 audio setting level of the AEE2004 level `level`, AEE2004 levels being
+/// offset by 49, saturating levels out of the 5-bit AEE2010 range.
+fn level_from_aee2004(level: u8) -> u8 {
+    level.saturating_sub(49).min(0x1f)
+}
+
+impl From<&crate::aee2004::conf::x1e5::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x1e5::Repr) -> Self {
+        let fader_opt = if repr_2004.fader_enabled_diag {
+            ConfigOption::SelectableOption
+        } else {
+            ConfigOption::UnselectableOption
+        };
+        let loudness_opt = if repr_2004.loudness_enabled_diag {
+            ConfigOption::SelectableOption
+        } else {
+            ConfigOption::UnselectableOption
+        };
+        Repr {
+            balance_opt: ConfigOption::SelectableOption,
+            balance_level: level_from_aee2004(repr_2004.balance_level),
+            balance_under_adj: repr_2004.balance_under_adj,
+            fader_opt,
+            fader_level: level_from_aee2004(repr_2004.fader_level),
+            fader_under_adj: repr_2004.fader_under_adj,
+            bass_opt: ConfigOption::SelectableOption,
+            bass_level: level_from_aee2004(repr_2004.bass_level),
+            bass_under_adj: repr_2004.bass_under_adj,
+            treble_opt: ConfigOption::SelectableOption,
+            treble_level: level_from_aee2004(repr_2004.treble_level),
+            treble_under_adj: repr_2004.treble_under_adj,
+            speed_dependent_volume_opt: ConfigOption::SelectableOption,
+            // The speed-dependent volume law is not transmitted on AEE2010.
+            speed_dependent_volume_enabled: repr_2004.speed_dependent_volume
+                != SpeedDependentVolumeLaw::Off,
+            speed_dependent_volume_under_adj: repr_2004.speed_dependent_volume_under_adj,
+            loudness_opt,
+            loudness_enabled: repr_2004.loudness_enabled,
+            loudness_under_adj: repr_2004.loudness_under_adj,
+            musical_ambiance_opt: ConfigOption::SelectableOption,
+            musical_ambiance: repr_2004.musical_ambiance,
+            musical_ambiance_under_adj: repr_2004.musical_ambiance_under_adj,
+            // No equivalent on AEE2004.
+            sound_repartition_opt: ConfigOption::Unavailable,
+            sound_repartition: SoundRepartition::Off,
+            sound_repartition_under_adj: false,
+            spatial_sound_under_adj: false,
+            spectral_sound_under_adj: false,
+            impossible_setting: repr_2004.impossible_setting,
+        }
     }
 }
 
@@ -1005,4 +1191,23 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x1e5::Repr {
+            bass_level: 0x48,
+            treble_level: 0x10,
+            middle_level: 0x50,
+            ..crate::aee2004::conf::x1e5::Repr::builder().build().unwrap()
+        };
+        let repr = Repr::from(&repr_2004);
+        assert_eq!(repr.balance_level, 14);
+        assert_eq!(repr.bass_level, 23);
+        assert_eq!(repr.treble_level, 0);
+        assert!(repr.speed_dependent_volume_enabled);
+        assert_eq!(
+            crate::aee2004::conf::x1e5::Repr::from(&repr).bass_level,
+            repr_2004.bass_level
+        );
+    }
 }
@@ -2,7 +2,15 @@ use core::{cmp::Ordering, fmt, time::Duration};
 
 use byteorder::{ByteOrder, NetworkEndian};
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    units::Kilometers,
+    Error, Result,
+};
+
+#[cfg(feature = "float")]
+use crate::physical::{self, Physical};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -46,6 +54,8 @@ pub const FRAME_LEN: usize = field::REM_TRIP_DIST.end;
 
 /// Periodicity of a x221 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x221 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -202,10 +212,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x221 ({})", err)?;
                 Ok(())
@@ -220,16 +230,22 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x221 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub nav_vocal_command_push_button_state: bool,
     pub trip_computer_push_button_state: bool,
     pub fuel_autonomy_data_valid: bool,
     pub fuel_consumption_data_valid: bool,
     #[cfg(feature = "float")]
-    pub instant_fuel_consumption: f32,
+    pub instant_fuel_consumption: Physical,
     #[cfg(not(feature = "float"))]
     pub instant_fuel_consumption: u16,
     pub remaining_fuel_range: u16,
@@ -240,18 +256,27 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             nav_vocal_command_push_button_state: frame.nav_vocal_command_push_button_state(),
             trip_computer_push_button_state: frame.trip_computer_push_button_state(),
             fuel_autonomy_data_valid: frame.fuel_autonomy_data_valid(),
             fuel_consumption_data_valid: frame.fuel_consumption_data_valid(),
             #[cfg(feature = "float")]
-            instant_fuel_consumption: frame.instant_fuel_consumption() as f32 / 10.0,
+            instant_fuel_consumption: physical::from_raw(
+                frame.instant_fuel_consumption().into(),
+                0,
+                10,
+            ),
             #[cfg(not(feature = "float"))]
             instant_fuel_consumption: frame.instant_fuel_consumption(),
             remaining_fuel_range: frame.remaining_fuel_range(),
             remaining_trip_distance: frame.remaining_trip_distance(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -266,7 +291,7 @@ impl Repr {
         frame.set_fuel_autonomy_data_valid(self.fuel_autonomy_data_valid);
         frame.set_fuel_consumption_data_valid(self.fuel_consumption_data_valid);
         #[cfg(feature = "float")]
-        frame.set_instant_fuel_consumption((self.instant_fuel_consumption * 10.0) as u16);
+        frame.set_instant_fuel_consumption(physical::to_u16(self.instant_fuel_consumption, 0, 10));
         #[cfg(not(feature = "float"))]
         frame.set_instant_fuel_consumption(self.instant_fuel_consumption);
         frame.set_remaining_fuel_range(self.remaining_fuel_range);
@@ -274,46 +299,106 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x81, 0x00, 0x00, 0x00, 0xb9, 0x00, 0x00],
+    with_nav_vocal_command_push_button_state => nav_vocal_command_push_button_state: bool,
+    with_trip_computer_push_button_state => trip_computer_push_button_state: bool,
+    with_fuel_autonomy_data_valid => fuel_autonomy_data_valid: bool,
+    with_fuel_consumption_data_valid => fuel_consumption_data_valid: bool,
+    #[cfg(feature = "float")]
+    with_instant_fuel_consumption => instant_fuel_consumption: Physical,
+    #[cfg(not(feature = "float"))]
+    with_instant_fuel_consumption => instant_fuel_consumption: u16,
+    with_remaining_fuel_range => remaining_fuel_range: u16,
+    with_remaining_trip_distance => remaining_trip_distance: u16,
+}
+
+/// Signals of a x221 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("nav_vocal_command_push_button_state", 0, 0, 1),
+    Signal::new("trip_computer_push_button_state", 0, 3, 1),
+    Signal::new("fuel_autonomy_data_valid", 0, 6, 1).values(&[(0, "true"), (1, "false")]),
+    Signal::new("fuel_consumption_data_valid", 0, 7, 1).values(&[(0, "true"), (1, "false")]),
+    Signal::new("instant_fuel_consumption", 1, 7, 16)
+        .scaled(0.1, 0.0)
+        .unit("l/100km"),
+    Signal::new("remaining_fuel_range", 3, 7, 16).unit("km"),
+    Signal::new("remaining_trip_distance", 5, 7, 16).unit("km"),
+];
+
+impl Repr {
+    /// Return the remaining fuel range as a typed distance.
+    pub fn remaining_fuel_range_km(&self) -> Kilometers {
+        Kilometers(self.remaining_fuel_range.into())
+    }
+
+    /// Return the remaining trip distance as a typed distance.
+    pub fn remaining_trip_distance_km(&self) -> Kilometers {
+        Kilometers(self.remaining_trip_distance.into())
+    }
+}
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x221")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x221")?;
+        write!(
+            f,
+            "{}nav_vocal_command_push_button_state={}",
+            sep, self.nav_vocal_command_push_button_state
+        )?;
+        write!(
             f,
-            " nav_vocal_command_push_button_state={}",
-            self.nav_vocal_command_push_button_state
+            "{}trip_computer_push_button_state={}",
+            sep, self.trip_computer_push_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " trip_computer_push_button_state={}",
-            self.trip_computer_push_button_state
+            "{}fuel_autonomy_data_valid={}",
+            sep, self.fuel_autonomy_data_valid
         )?;
-        writeln!(
+        write!(
             f,
-            " fuel_autonomy_data_valid={}",
-            self.fuel_autonomy_data_valid
+            "{}fuel_consumption_data_valid={}",
+            sep, self.fuel_consumption_data_valid
         )?;
-        writeln!(
+        write!(
             f,
-            " fuel_consumption_data_valid={}",
-            self.fuel_consumption_data_valid
+            "{}instant_fuel_consumption={}",
+            sep, self.instant_fuel_consumption
         )?;
-        writeln!(
+        write!(
             f,
-            " instant_fuel_consumption={}",
-            self.instant_fuel_consumption
+            "{}remaining_fuel_range={}",
+            sep, self.remaining_fuel_range
         )?;
-        writeln!(f, " remaining_fuel_range={}", self.remaining_fuel_range)?;
-        writeln!(
+        write!(
             f,
-            " remaining_trip_distance={}",
-            self.remaining_trip_distance
+            "{}remaining_trip_distance={}",
+            sep, self.remaining_trip_distance
         )
     }
 }
 
+impl From<&crate::aee2004::conf::x221::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x221::Repr) -> Self {
+        Repr {
+            nav_vocal_command_push_button_state: repr_2004.nav_vocal_command_push_button_state,
+            trip_computer_push_button_state: repr_2004.trip_computer_push_button_state,
+            fuel_autonomy_data_valid: repr_2004.fuel_autonomy_data_valid,
+            fuel_consumption_data_valid: repr_2004.fuel_consumption_data_valid,
+            instant_fuel_consumption: repr_2004.instant_fuel_consumption,
+            remaining_fuel_range: repr_2004.remaining_fuel_range,
+            remaining_trip_distance: repr_2004.remaining_trip_distance,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
+    #[cfg(feature = "float")]
+    use crate::physical;
 
     use crate::Error;
 
@@ -326,7 +411,7 @@ mod test {
             trip_computer_push_button_state: false,
             fuel_autonomy_data_valid: true,
             fuel_consumption_data_valid: false,
-            instant_fuel_consumption: 0.0,
+            instant_fuel_consumption: physical::decimal(0, 10),
             remaining_fuel_range: 185,
             remaining_trip_distance: 0,
         }
@@ -338,7 +423,7 @@ mod test {
             trip_computer_push_button_state: true,
             fuel_autonomy_data_valid: false,
             fuel_consumption_data_valid: true,
-            instant_fuel_consumption: 12.7,
+            instant_fuel_consumption: physical::decimal(127, 10),
             remaining_fuel_range: 1025,
             remaining_trip_distance: 250,
         }
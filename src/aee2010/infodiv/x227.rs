@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{ACRecirculationState, FuelType, PushButtonLedState},
     Error, Result,
 };
@@ -80,6 +82,8 @@ pub const FRAME_LEN: usize = field::LED_4 + 1;
 
 /// Periodicity of a x227 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x227 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -386,10 +390,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x227 ({})", err)?;
                 Ok(())
@@ -404,9 +408,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x227 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub sport_suspension_led_state: PushButtonLedState,
     pub child_lock_led_state: PushButtonLedState,
@@ -430,7 +440,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             sport_suspension_led_state: frame.sport_suspension_led_state(),
             child_lock_led_state: frame.child_lock_led_state(),
             esp_led_state: frame.esp_led_state(),
@@ -447,7 +462,7 @@ impl Repr {
             automatic_main_beam_enabled: frame.automatic_main_beam_enable(),
             adaptive_cruise_control_led_state: frame.adaptive_cruise_control_led_state(),
             lane_keep_assist_led_state: frame.lane_keep_assist_led_state(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -474,62 +489,374 @@ impl Repr {
         frame.set_adaptive_cruise_control_led_state(self.adaptive_cruise_control_led_state);
         frame.set_lane_keep_assist_led_state(self.lane_keep_assist_led_state);
     }
+
+    /// Return the state of `led`.
+    pub fn led_state(&self, led: PushButtonLed) -> PushButtonLedState {
+        match led {
+            PushButtonLed::SportSuspension => self.sport_suspension_led_state,
+            PushButtonLed::ChildLock => self.child_lock_led_state,
+            PushButtonLed::Esp => self.esp_led_state,
+            PushButtonLed::ParkingSensors => self.parking_sensors_led_state,
+            PushButtonLed::AcOn => self.ac_on_led_state,
+            PushButtonLed::RearWindshieldDemist => self.rear_windshield_demist_led_state,
+            PushButtonLed::LaneCentering => self.lane_centering_led_state,
+            PushButtonLed::ElectricalParkingBrake => self.electrical_parking_brake_led_state,
+            PushButtonLed::BlindSpotMonitoring => self.blind_spot_monitoring_led_state,
+            PushButtonLed::StopStart1 => self.stop_start_1,
+            PushButtonLed::StopStart2 => self.stop_start_2,
+            PushButtonLed::AdaptiveCruiseControl => self.adaptive_cruise_control_led_state,
+            PushButtonLed::LaneKeepAssist => self.lane_keep_assist_led_state,
+        }
+    }
+
+    /// Return a mutable reference to the state of `led`.
+    pub fn led_state_mut(&mut self, led: PushButtonLed) -> &mut PushButtonLedState {
+        match led {
+            PushButtonLed::SportSuspension => &mut self.sport_suspension_led_state,
+            PushButtonLed::ChildLock => &mut self.child_lock_led_state,
+            PushButtonLed::Esp => &mut self.esp_led_state,
+            PushButtonLed::ParkingSensors => &mut self.parking_sensors_led_state,
+            PushButtonLed::AcOn => &mut self.ac_on_led_state,
+            PushButtonLed::RearWindshieldDemist => &mut self.rear_windshield_demist_led_state,
+            PushButtonLed::LaneCentering => &mut self.lane_centering_led_state,
+            PushButtonLed::ElectricalParkingBrake => &mut self.electrical_parking_brake_led_state,
+            PushButtonLed::BlindSpotMonitoring => &mut self.blind_spot_monitoring_led_state,
+            PushButtonLed::StopStart1 => &mut self.stop_start_1,
+            PushButtonLed::StopStart2 => &mut self.stop_start_2,
+            PushButtonLed::AdaptiveCruiseControl => &mut self.adaptive_cruise_control_led_state,
+            PushButtonLed::LaneKeepAssist => &mut self.lane_keep_assist_led_state,
+        }
+    }
 }
 
+/// A push-button LED driven by a x227 CAN frame.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PushButtonLed {
+    /// Sport suspension push-button LED.
+    SportSuspension,
+    /// Child lock push-button LED.
+    ChildLock,
+    /// ESP push-button LED.
+    Esp,
+    /// Parking sensors push-button LED.
+    ParkingSensors,
+    /// A/C on push-button LED.
+    AcOn,
+    /// Rear windshield demist push-button LED.
+    RearWindshieldDemist,
+    /// Lane centering push-button LED.
+    LaneCentering,
+    /// Electrical parking brake push-button LED.
+    ElectricalParkingBrake,
+    /// Blind spot monitoring push-button LED.
+    BlindSpotMonitoring,
+    /// First Stop & Start push-button LED.
+    StopStart1,
+    /// Second Stop & Start push-button LED.
+    StopStart2,
+    /// Adaptive cruise control push-button LED.
+    AdaptiveCruiseControl,
+    /// Lane keep assist push-button LED.
+    LaneKeepAssist,
+}
+
+/// Number of push-button LEDs driven by a x227 CAN frame.
+pub const LED_COUNT: usize = 13;
+
+impl PushButtonLed {
+    /// Every push-button LED, in frame order.
+    pub const ALL: [PushButtonLed; LED_COUNT] = [
+        PushButtonLed::SportSuspension,
+        PushButtonLed::ChildLock,
+        PushButtonLed::Esp,
+        PushButtonLed::ParkingSensors,
+        PushButtonLed::AcOn,
+        PushButtonLed::RearWindshieldDemist,
+        PushButtonLed::LaneCentering,
+        PushButtonLed::ElectricalParkingBrake,
+        PushButtonLed::BlindSpotMonitoring,
+        PushButtonLed::StopStart1,
+        PushButtonLed::StopStart2,
+        PushButtonLed::AdaptiveCruiseControl,
+        PushButtonLed::LaneKeepAssist,
+    ];
+
+    /// Return the state of the LED in `frame`.
+    pub fn state<T: AsRef<[u8]>>(&self, frame: &Frame<T>) -> PushButtonLedState {
+        match *self {
+            PushButtonLed::SportSuspension => frame.sport_suspension_led_state(),
+            PushButtonLed::ChildLock => frame.child_lock_led_state(),
+            PushButtonLed::Esp => frame.esp_led_state(),
+            PushButtonLed::ParkingSensors => frame.parking_sensors_led_state(),
+            PushButtonLed::AcOn => frame.ac_on_led_state(),
+            PushButtonLed::RearWindshieldDemist => frame.rear_windshield_demist_led_state(),
+            PushButtonLed::LaneCentering => frame.lane_centering_led_state(),
+            PushButtonLed::ElectricalParkingBrake => frame.electrical_parking_brake_led_state(),
+            PushButtonLed::BlindSpotMonitoring => frame.blind_spot_monitoring_led_state(),
+            PushButtonLed::StopStart1 => frame.stop_start_1(),
+            PushButtonLed::StopStart2 => frame.stop_start_2(),
+            PushButtonLed::AdaptiveCruiseControl => frame.adaptive_cruise_control_led_state(),
+            PushButtonLed::LaneKeepAssist => frame.lane_keep_assist_led_state(),
+        }
+    }
+
+    /// Set the state of the LED in `frame`, leaving the other fields unchanged.
+    pub fn set_state<T: AsRef<[u8]> + AsMut<[u8]>>(
+        &self,
+        frame: &mut Frame<T>,
+        state: PushButtonLedState,
+    ) {
+        match *self {
+            PushButtonLed::SportSuspension => frame.set_sport_suspension_led_state(state),
+            PushButtonLed::ChildLock => frame.set_child_lock_led_state(state),
+            PushButtonLed::Esp => frame.set_esp_led_state(state),
+            PushButtonLed::ParkingSensors => frame.set_parking_sensors_led_state(state),
+            PushButtonLed::AcOn => frame.set_ac_on_led_state(state),
+            PushButtonLed::RearWindshieldDemist => {
+                frame.set_rear_windshield_demist_led_state(state)
+            }
+            PushButtonLed::LaneCentering => frame.set_lane_centering_led_state(state),
+            PushButtonLed::ElectricalParkingBrake => {
+                frame.set_electrical_parking_brake_led_state(state)
+            }
+            PushButtonLed::BlindSpotMonitoring => frame.set_blind_spot_monitoring_led_state(state),
+            PushButtonLed::StopStart1 => frame.set_stop_start_1(state),
+            PushButtonLed::StopStart2 => frame.set_stop_start_2(state),
+            PushButtonLed::AdaptiveCruiseControl => {
+                frame.set_adaptive_cruise_control_led_state(state)
+            }
+            PushButtonLed::LaneKeepAssist => frame.set_lane_keep_assist_led_state(state),
+        }
+    }
+
+    /// Return the index of the LED in [PushButtonLed::ALL].
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl fmt::Display for PushButtonLed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PushButtonLed::SportSuspension => write!(f, "sport suspension"),
+            PushButtonLed::ChildLock => write!(f, "child lock"),
+            PushButtonLed::Esp => write!(f, "ESP"),
+            PushButtonLed::ParkingSensors => write!(f, "parking sensors"),
+            PushButtonLed::AcOn => write!(f, "A/C on"),
+            PushButtonLed::RearWindshieldDemist => write!(f, "rear windshield demist"),
+            PushButtonLed::LaneCentering => write!(f, "lane centering"),
+            PushButtonLed::ElectricalParkingBrake => write!(f, "electrical parking brake"),
+            PushButtonLed::BlindSpotMonitoring => write!(f, "blind spot monitoring"),
+            PushButtonLed::StopStart1 => write!(f, "stop & start 1"),
+            PushButtonLed::StopStart2 => write!(f, "stop & start 2"),
+            PushButtonLed::AdaptiveCruiseControl => write!(f, "adaptive cruise control"),
+            PushButtonLed::LaneKeepAssist => write!(f, "lane keep assist"),
+        }
+    }
+}
+
+/// A set of push-button LED state changes.
+///
+/// Unlike [Repr::emit], which overwrites every field of the frame, [LedCommand::emit] only
+/// writes the LEDs whose state is set in the command, keeping the current state of the
+/// other LEDs and fields of the frame.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LedCommand {
+    states: [Option<PushButtonLedState>; LED_COUNT],
+}
+
+impl LedCommand {
+    /// Create a command changing no LED.
+    pub fn new() -> LedCommand {
+        LedCommand::default()
+    }
+
+    /// Set the state of `led` to `state`.
+    pub fn with(mut self, led: PushButtonLed, state: PushButtonLedState) -> LedCommand {
+        self.states[led.index()] = Some(state);
+        self
+    }
+
+    /// Return the state `led` is set to, if any.
+    pub fn get(&self, led: PushButtonLed) -> Option<PushButtonLedState> {
+        self.states[led.index()]
+    }
+
+    /// Return an iterator over the LEDs set by the command and their state.
+    pub fn iter(&self) -> impl Iterator<Item = (PushButtonLed, PushButtonLedState)> + '_ {
+        PushButtonLed::ALL
+            .iter()
+            .zip(self.states.iter())
+            .filter_map(|(&led, state)| state.map(|state| (led, state)))
+    }
+
+    /// Return whether the command changes no LED.
+    pub fn is_empty(&self) -> bool {
+        self.states.iter().all(Option::is_none)
+    }
+
+    /// Apply the command to `repr`.
+    pub fn apply(&self, repr: &mut Repr) {
+        for (led, state) in self.iter() {
+            *repr.led_state_mut(led) = state;
+        }
+    }
+
+    /// Merge the command into `frame`, leaving the LEDs it does not set and the other
+    /// fields of the frame unchanged.
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+        for (led, state) in self.iter() {
+            led.set_state(frame, state);
+        }
+    }
+}
+
+repr_builder! {
+    idle: [0x24, 0x49, 0x02, 0x54, 0x00],
+    with_sport_suspension_led_state => sport_suspension_led_state: PushButtonLedState,
+    with_child_lock_led_state => child_lock_led_state: PushButtonLedState,
+    with_esp_led_state => esp_led_state: PushButtonLedState,
+    with_parking_sensors_led_state => parking_sensors_led_state: PushButtonLedState,
+    with_ac_on_led_state => ac_on_led_state: PushButtonLedState,
+    with_rear_windshield_demist_led_state => rear_windshield_demist_led_state: PushButtonLedState,
+    with_lane_centering_led_state => lane_centering_led_state: PushButtonLedState,
+    with_electrical_parking_brake_led_state => electrical_parking_brake_led_state: PushButtonLedState,
+    with_blind_spot_monitoring_led_state => blind_spot_monitoring_led_state: PushButtonLedState,
+    with_ac_recirculation_state => ac_recirculation_state: ACRecirculationState,
+    with_fuel_type => fuel_type: FuelType,
+    with_stop_start_1 => stop_start_1: PushButtonLedState,
+    with_stop_start_2 => stop_start_2: PushButtonLedState,
+    with_automatic_main_beam_enabled => automatic_main_beam_enabled: bool,
+    with_adaptive_cruise_control_led_state => adaptive_cruise_control_led_state: PushButtonLedState,
+    with_lane_keep_assist_led_state => lane_keep_assist_led_state: PushButtonLedState,
+}
+
+/// Signals of a x227 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("sport_suspension_led_state", 0, 1, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("child_lock_led_state", 0, 3, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("esp_led_state", 0, 5, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("parking_sensors_led_state", 0, 7, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("ac_on_led_state", 1, 1, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("rear_windshield_demist_led_state", 1, 3, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("lane_centering_led_state", 1, 5, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("electrical_parking_brake_led_state", 1, 7, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("blind_spot_monitoring_led_state", 2, 1, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("ac_recirculation_state", 2, 3, 2).values(&[
+        (0, "ExteriorAir"),
+        (1, "PartialAirRecirculation"),
+        (2, "FullAirRecirculation"),
+        (3, "Stopped"),
+    ]),
+    Signal::new("fuel_type", 2, 5, 2).values(&[(0, "Petrol"), (1, "Diesel")]),
+    Signal::new("stop_start_1", 2, 7, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("stop_start_2", 3, 3, 2).values(&[(0, "Off"), (1, "Steady"), (2, "Blinking")]),
+    Signal::new("automatic_main_beam_enabled", 3, 4, 1),
+    Signal::new("adaptive_cruise_control_led_state", 3, 6, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+    Signal::new("lane_keep_assist_led_state", 4, 5, 2).values(&[
+        (0, "Off"),
+        (1, "Steady"),
+        (2, "Blinking"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x227")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x227")?;
+        write!(
             f,
-            " sport_suspension_led_state={}",
-            self.sport_suspension_led_state
+            "{}sport_suspension_led_state={}",
+            sep, self.sport_suspension_led_state
         )?;
-        writeln!(f, " child_lock_led_state={}", self.child_lock_led_state)?;
-        writeln!(f, " esp_led_state={}", self.esp_led_state)?;
-        writeln!(
+        write!(
             f,
-            " parking_sensors_led_state={}",
-            self.parking_sensors_led_state
+            "{}child_lock_led_state={}",
+            sep, self.child_lock_led_state
         )?;
-        writeln!(f, " ac_on_led_state={}", self.ac_on_led_state)?;
-        writeln!(
+        write!(f, "{}esp_led_state={}", sep, self.esp_led_state)?;
+        write!(
             f,
-            " rear_windshield_demist_led_state={}",
-            self.rear_windshield_demist_led_state
+            "{}parking_sensors_led_state={}",
+            sep, self.parking_sensors_led_state
         )?;
-        writeln!(
+        write!(f, "{}ac_on_led_state={}", sep, self.ac_on_led_state)?;
+        write!(
             f,
-            " lane_centering_led_state={}",
-            self.lane_centering_led_state
+            "{}rear_windshield_demist_led_state={}",
+            sep, self.rear_windshield_demist_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " electrical_parking_brake_led_state={}",
-            self.electrical_parking_brake_led_state
+            "{}lane_centering_led_state={}",
+            sep, self.lane_centering_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " blind_spot_monitoring_led_state={}",
-            self.blind_spot_monitoring_led_state
+            "{}electrical_parking_brake_led_state={}",
+            sep, self.electrical_parking_brake_led_state
         )?;
-        writeln!(f, " ac_recirculation_state={}", self.ac_recirculation_state)?;
-        writeln!(f, " fuel_type={}", self.fuel_type)?;
-        writeln!(f, " stop_start_1={}", self.stop_start_1)?;
-        writeln!(f, " stop_start_2={}", self.stop_start_2)?;
-        writeln!(
+        write!(
             f,
-            " automatic_main_beam_enabled={}",
-            self.automatic_main_beam_enabled
+            "{}blind_spot_monitoring_led_state={}",
+            sep, self.blind_spot_monitoring_led_state
         )?;
-        writeln!(
+        write!(
             f,
-            " adaptive_cruise_control_led_state={}",
-            self.adaptive_cruise_control_led_state
+            "{}ac_recirculation_state={}",
+            sep, self.ac_recirculation_state
         )?;
-        writeln!(
+        write!(f, "{}fuel_type={}", sep, self.fuel_type)?;
+        write!(f, "{}stop_start_1={}", sep, self.stop_start_1)?;
+        write!(f, "{}stop_start_2={}", sep, self.stop_start_2)?;
+        write!(
             f,
-            " lane_keep_assist_led_state={}",
-            self.lane_keep_assist_led_state
+            "{}automatic_main_beam_enabled={}",
+            sep, self.automatic_main_beam_enabled
+        )?;
+        write!(
+            f,
+            "{}adaptive_cruise_control_led_state={}",
+            sep, self.adaptive_cruise_control_led_state
+        )?;
+        write!(
+            f,
+            "{}lane_keep_assist_led_state={}",
+            sep, self.lane_keep_assist_led_state
         )
     }
 }
@@ -559,7 +886,7 @@ impl From<&crate::aee2004::conf::x227::Repr> for Repr {
 
 #[cfg(test)]
 mod test {
-    use super::{Frame, Repr};
+    use super::{Frame, LedCommand, PushButtonLed, Repr};
 
     use crate::{
         vehicle::{ACRecirculationState, FuelType, PushButtonLedState},
@@ -793,4 +1120,35 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_led_command() {
+        let command = LedCommand::new()
+            .with(PushButtonLed::Esp, PushButtonLedState::Off)
+            .with(PushButtonLed::StopStart2, PushButtonLedState::Blinking);
+        assert!(!command.is_empty());
+        assert_eq!(
+            command.get(PushButtonLed::Esp),
+            Some(PushButtonLedState::Off)
+        );
+        assert_eq!(command.get(PushButtonLed::ChildLock), None);
+        assert_eq!(command.iter().count(), 2);
+
+        let mut buf = REPR_FRAME_BYTES_1;
+        let mut frame = Frame::new_unchecked(&mut buf);
+        command.emit(&mut frame);
+        let mut repr = frame_1_repr();
+        command.apply(&mut repr);
+        assert_eq!(Repr::parse(&Frame::new_unchecked(&buf)), Ok(repr));
+        assert_eq!(repr.led_state(PushButtonLed::Esp), PushButtonLedState::Off);
+        assert_eq!(
+            repr.led_state(PushButtonLed::StopStart2),
+            PushButtonLedState::Blinking
+        );
+        assert_eq!(
+            repr.led_state(PushButtonLed::ChildLock),
+            frame_1_repr().child_lock_led_state
+        );
+        assert_eq!(repr.fuel_type, frame_1_repr().fuel_type);
+    }
 }
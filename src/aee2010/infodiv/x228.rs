@@ -3,6 +3,9 @@ use core::{cmp::Ordering, fmt, time::Duration};
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    units::KilometersPerHour,
     vehicle::{
         AdaptiveCruiseControlState, SpeedRegulationMode, SpeedRegulationModeState,
         SpeedRegulationSettingPage,
@@ -70,6 +73,8 @@ pub const FRAME_LEN: usize = field::XVV_4 + 1;
 
 /// Periodicity of a x228 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x228 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -310,10 +315,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x228 ({})", err)?;
                 Ok(())
@@ -328,9 +333,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x228 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub speed_setting: u16,
     pub speed_setting_adjustment_in_progress: bool,
@@ -350,7 +361,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             speed_setting: frame.speed_setting(),
             speed_setting_adjustment_in_progress: frame.speed_setting_adjustment_in_progress(),
             try_enable: frame.try_enable(),
@@ -364,7 +380,7 @@ impl Repr {
                 .set_speed_from_traffic_sign_recognition_allowed(),
             mem_key_state: frame.mem_key_state(),
             acc_displayed_state: frame.acc_displayed_state(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -389,50 +405,128 @@ impl Repr {
         frame.set_mem_key_state(self.mem_key_state);
         frame.set_acc_displayed_state(self.acc_displayed_state);
     }
+
+    /// Return the speed setting as a typed speed.
+    pub fn speed_setting_kph(&self) -> KilometersPerHour {
+        KilometersPerHour(self.speed_setting)
+    }
+
+    /// Return the adaptive cruise-control adjusted speed setting as a typed speed.
+    pub fn acc_adjusted_speed_kph(&self) -> KilometersPerHour {
+        KilometersPerHour(self.acc_adjusted_speed)
+    }
+}
+
+repr_builder! {
+    idle: [0x00, 0x82, 0x49, 0x80, 0x80, 0x00, 0x00, 0x92],
+    with_speed_setting => speed_setting: u16,
+    with_speed_setting_adjustment_in_progress => speed_setting_adjustment_in_progress: bool,
+    with_try_enable => try_enable: bool,
+    with_speed_regulation_mode_state => speed_regulation_mode_state: SpeedRegulationModeState,
+    with_speed_regulation_mode => speed_regulation_mode: SpeedRegulationMode,
+    with_speed_regulation_available => speed_regulation_available: bool,
+    with_acc_time => acc_time: u8,
+    with_speed_regulation_page_req => speed_regulation_page_req: SpeedRegulationSettingPage,
+    with_acc_adjusted_speed => acc_adjusted_speed: u16,
+    with_set_speed_from_traffic_sign_recognition_allowed => set_speed_from_traffic_sign_recognition_allowed: bool,
+    with_mem_key_state => mem_key_state: bool,
+    with_acc_displayed_state => acc_displayed_state: AdaptiveCruiseControlState,
 }
 
+/// Signals of a x228 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("speed_setting", 0, 7, 16).unit("km/h"),
+    Signal::new("speed_setting_adjustment_in_progress", 2, 0, 1),
+    Signal::new("try_enable", 2, 2, 1),
+    Signal::new("speed_regulation_mode_state", 2, 5, 3).values(&[
+        (0, "Standby"),
+        (1, "Up"),
+        (2, "LimiterUpAndRunning"),
+        (3, "UpOverspeed"),
+        (4, "UpOverspeedFromDriver"),
+        (6, "ForbiddenMode"),
+        (7, "Failure"),
+    ]),
+    Signal::new("speed_regulation_mode", 2, 7, 2).values(&[
+        (0, "Off"),
+        (1, "CruiseControl"),
+        (2, "SpeedLimiter"),
+        (3, "AdaptiveCruiseControl"),
+    ]),
+    Signal::new("speed_regulation_available", 3, 7, 1),
+    Signal::new("acc_time", 4, 5, 6),
+    Signal::new("speed_regulation_page_req", 4, 7, 2).values(&[
+        (0, "Close"),
+        (1, "SpeedLimiter"),
+        (2, "CruiseControl"),
+    ]),
+    Signal::new("acc_adjusted_speed", 5, 7, 16).unit("km/h"),
+    Signal::new("set_speed_from_traffic_sign_recognition_allowed", 7, 0, 1),
+    Signal::new("mem_key_state", 7, 1, 1),
+    Signal::new("acc_displayed_state", 7, 7, 4).values(&[
+        (0, "NoAdjust"),
+        (1, "AdjustInProgress"),
+        (2, "HighLimit"),
+        (3, "LowLimit"),
+        (4, "DisableSpeedAdjustment"),
+        (5, "DisableAutomaticAdjustment"),
+        (6, "Overspeed"),
+        (7, "RadarLowVisibility"),
+        (8, "RadarLearning"),
+        (9, "Disabled"),
+        (10, "AdjustmentLimit"),
+    ]),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x228")?;
-        writeln!(f, " speed_setting={}", self.speed_setting)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x228")?;
+        write!(f, "{}speed_setting={}", sep, self.speed_setting)?;
+        write!(
+            f,
+            "{}speed_setting_adjustment_in_progress={}",
+            sep, self.speed_setting_adjustment_in_progress
+        )?;
+        write!(f, "{}try_enable={}", sep, self.try_enable)?;
+        write!(
             f,
-            " speed_setting_adjustment_in_progress={}",
-            self.speed_setting_adjustment_in_progress
+            "{}speed_regulation_mode_state={}",
+            sep, self.speed_regulation_mode_state
         )?;
-        writeln!(f, " try_enable={}", self.try_enable)?;
-        writeln!(
+        write!(
             f,
-            " speed_regulation_mode_state={}",
-            self.speed_regulation_mode_state
+            "{}speed_regulation_mode={}",
+            sep, self.speed_regulation_mode
         )?;
-        writeln!(f, " speed_regulation_mode={}", self.speed_regulation_mode)?;
-        writeln!(
+        write!(
             f,
-            " speed_regulation_available={}",
-            self.speed_regulation_available
+            "{}speed_regulation_available={}",
+            sep, self.speed_regulation_available
         )?;
-        writeln!(f, " acc_time={}", self.acc_time)?;
-        writeln!(
+        write!(f, "{}acc_time={}", sep, self.acc_time)?;
+        write!(
             f,
-            " speed_regulation_page_req={}",
-            self.speed_regulation_page_req
+            "{}speed_regulation_page_req={}",
+            sep, self.speed_regulation_page_req
         )?;
-        writeln!(f, " acc_adjusted_speed={}", self.acc_adjusted_speed)?;
-        writeln!(
+        write!(f, "{}acc_adjusted_speed={}", sep, self.acc_adjusted_speed)?;
+        write!(
             f,
-            " set_speed_from_traffic_sign_recognition_allowed={}",
-            self.set_speed_from_traffic_sign_recognition_allowed
+            "{}set_speed_from_traffic_sign_recognition_allowed={}",
+            sep, self.set_speed_from_traffic_sign_recognition_allowed
         )?;
-        writeln!(f, " mem_key_state={}", self.mem_key_state)?;
-        writeln!(f, " acc_displayed_state={}", self.acc_displayed_state)
+        write!(f, "{}mem_key_state={}", sep, self.mem_key_state)?;
+        write!(f, "{}acc_displayed_state={}", sep, self.acc_displayed_state)
     }
 }
 
 impl From<&crate::aee2004::conf::x1a8::Repr> for Repr {
     fn from(repr_2004: &crate::aee2004::conf::x1a8::Repr) -> Self {
         Repr {
-            speed_setting: repr_2004.speed_setting,
+            speed_setting: repr_2004
+                .speed_setting_kph()
+                .map_or(repr_2004.speed_setting, |speed| speed.0),
             speed_setting_adjustment_in_progress: false, // No known conversion.
             try_enable: repr_2004.try_enable,
             speed_regulation_mode_state: repr_2004.speed_regulation_mode_state,
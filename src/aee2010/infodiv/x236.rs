@@ -3,6 +3,8 @@ use core::{cmp::Ordering, fmt, time::Duration};
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{ElectricalNetworkState, FaultLogContext, VehicleConfigMode},
     Error, Result,
 };
@@ -59,6 +61,8 @@ pub const FRAME_LEN: usize = field::UNKNOWN_FLAGS + 1;
 
 /// Periodicity of a x236 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x236 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -236,10 +240,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x236 ({})", err)?;
                 Ok(())
@@ -254,9 +258,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x236 CAN frame.
 #[derive(Debug, PartialEq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub vehicle_config_mode: VehicleConfigMode,
     pub electrical_network_status: ElectricalNetworkState,
@@ -272,7 +282,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             vehicle_config_mode: frame.vehicle_config_mode(),
             electrical_network_status: frame.electrical_network_status(),
             vsm_temporal_counter: frame.vsm_temporal_counter(),
@@ -281,7 +296,7 @@ impl Repr {
             boot_open: frame.boot_open(),
             gct_reset_counter: frame.gct_reset_counter(),
             power_on_req_denied: frame.power_on_req_denied(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -302,20 +317,78 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x54, 0x00, 0x01, 0xe2, 0x40, 0x80, 0xfe, 0x00],
+    parked: [0x04, 0x00, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00],
+    with_vehicle_config_mode => vehicle_config_mode: VehicleConfigMode,
+    with_electrical_network_status => electrical_network_status: ElectricalNetworkState,
+    with_vsm_temporal_counter => vsm_temporal_counter: u32,
+    with_fault_log_context => fault_log_context: FaultLogContext,
+    with_driver_door_open_evt => driver_door_open_evt: bool,
+    with_boot_open => boot_open: bool,
+    with_gct_reset_counter => gct_reset_counter: u8,
+    with_power_on_req_denied => power_on_req_denied: bool,
+}
+
+/// Signals of a x236 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("vehicle_config_mode", 0, 3, 4).values(&[
+        (0, "Assembly"),
+        (1, "Factory"),
+        (2, "Control"),
+        (3, "Storage"),
+        (4, "Customer"),
+        (5, "Showroom"),
+        (6, "Workshop"),
+    ]),
+    Signal::new("electrical_network_status", 0, 7, 4).values(&[
+        (0, "BatteryNormal"),
+        (1, "BatteryFailSoftMode"),
+        (2, "BatteryCrankingAvailable"),
+        (3, "Starting"),
+        (4, "Restart"),
+        (5, "GeneratorNormal"),
+        (6, "GeneratorFailSoftMode"),
+        (7, "GeneratorSecured"),
+        (8, "GeneratorUrgent"),
+    ]),
+    Signal::new("vsm_temporal_counter", 1, 7, 32),
+    Signal::new("fault_log_context", 5, 4, 5).values(&[
+        (12, "MainOffEco"),
+        (13, "MainOff"),
+        (14, "MainOnEco"),
+        (15, "MainOn"),
+    ]),
+    Signal::new("driver_door_open_evt", 5, 6, 1),
+    Signal::new("boot_open", 5, 7, 1),
+    Signal::new("gct_reset_counter", 6, 7, 8),
+    Signal::new("power_on_req_denied", 7, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x236 vehicle_config_mode={}", self.vehicle_config_mode)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x236")?;
+        write!(f, "{}vehicle_config_mode={}", sep, self.vehicle_config_mode)?;
+        write!(
+            f,
+            "{}electrical_network_status={}",
+            sep, self.electrical_network_status
+        )?;
+        write!(
+            f,
+            "{}vsm_temporal_counter={}",
+            sep, self.vsm_temporal_counter
+        )?;
+        write!(f, "{}fault_log_context={}", sep, self.fault_log_context)?;
+        write!(
             f,
-            " electrical_network_status={}",
-            self.electrical_network_status
+            "{}driver_door_open_evt={}",
+            sep, self.driver_door_open_evt
         )?;
-        writeln!(f, " vsm_temporal_counter={}", self.vsm_temporal_counter)?;
-        writeln!(f, " fault_log_context={}", self.fault_log_context)?;
-        writeln!(f, " driver_door_open_evt={}", self.driver_door_open_evt)?;
-        writeln!(f, " boot_open={}", self.boot_open)?;
-        writeln!(f, " gct_reset_counter={}", self.gct_reset_counter)?;
-        writeln!(f, " power_on_req_denied={}", self.power_on_req_denied)
+        write!(f, "{}boot_open={}", sep, self.boot_open)?;
+        write!(f, "{}gct_reset_counter={}", sep, self.gct_reset_counter)?;
+        write!(f, "{}power_on_req_denied={}", sep, self.power_on_req_denied)
     }
 }
 
@@ -1,4 +1,6 @@
-use core::{cmp::Ordering, fmt, time::Duration};
+use core::time::Duration;
+
+use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::{
     config::{
@@ -6,16 +8,11 @@ use crate::{
         Language, LightingDuration2010, MoodLightingLevel, SoundHarmony, TemperatureUnit,
         VolumeUnit,
     },
+    frame::TransmissionKind,
+    signal::Signal,
     Error, Result,
 };
 
-/// A read/write wrapper around an CAN frame buffer.
-#[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct Frame<T: AsRef<[u8]>> {
-    buffer: T,
-}
-
 /*
 260 VSM_INF_PROFILS_AAS_STATUS_HS7_260                  // OK
 260 VSM_INF_PROFILS_ACCUEIL_COND_HS7_260                // OK
@@ -128,1116 +125,276 @@ pub const FRAME_ID: u16 = 0x260;
 /// Length of a x260 CAN frame.
 pub const FRAME_LEN: usize = field::OPT_7 + 1;
 
+/// Reserved bits of a x260 CAN frame, in frame order: 2 bits of byte 5 and 7 bits of byte 7.
+pub const RESERVED_MASK: u64 = 0x0000_0000_0003_00f7;
+
 /// Periodicity of a x260 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
-
-impl<T: AsRef<[u8]>> Frame<T> {
-    /// Create a raw octet buffer with a CAN frame structure.
-    #[inline]
-    pub fn new_unchecked(buffer: T) -> Frame<T> {
-        Frame { buffer }
-    }
-
-    /// Shorthand for a combination of [new_unchecked] and [check_len].
-    ///
-    /// [new_unchecked]: #method.new_unchecked
-    /// [check_len]: #method.check_len
-    #[inline]
-    pub fn new_checked(buffer: T) -> Result<Frame<T>> {
-        let packet = Self::new_unchecked(buffer);
-        packet.check_len()?;
-        Ok(packet)
-    }
-
-    /// Ensure that no accessor method will panic if called.
-    /// Returns `Err(Error::Truncated)` if the buffer is too short.
-    ///
-    /// The result of this check is invalidated by calling [set_payload_len].
-    ///
-    /// [set_payload_len]: #method.set_payload_len
-    #[inline]
-    pub fn check_len(&self) -> Result<()> {
-        let len = self.buffer.as_ref().len();
-        match len.cmp(&FRAME_LEN) {
-            Ordering::Less => Err(Error::Truncated),
-            Ordering::Greater => Err(Error::Overlong),
-            Ordering::Equal => Ok(()),
-        }
-    }
-
-    /// Consume the frame, returning the underlying buffer.
-    #[inline]
-    pub fn into_inner(self) -> T {
-        self.buffer
-    }
-
-    /// Return the frame length.
-    #[inline]
-    pub fn frame_len(&self) -> usize {
-        FRAME_LEN
-    }
-
-    /// Return the consumption unit field.
-    #[inline]
-    pub fn consumption_unit(&self) -> ConsumptionUnit {
-        let data = self.buffer.as_ref();
-        let raw = data[field::OPT_0] & 0x01;
-        ConsumptionUnit::from(raw)
-    }
-
-    /// Return the distance unit field.
-    #[inline]
-    pub fn distance_unit(&self) -> DistanceUnit {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_0] & 0x02) >> 1;
-        DistanceUnit::from(raw)
-    }
-
-    /// Return the language field.
-    #[inline]
-    pub fn language(&self) -> Language {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_0] & 0x7c) >> 2;
-        Language::from(raw)
-    }
-
-    /// Return the units and language parameters validity flag.
-    #[inline]
-    pub fn units_language_parameters_validity(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_0] & 0x80 != 0
-    }
-
-    /// Return the sound harmony field.
-    #[inline]
-    pub fn sound_harmony(&self) -> SoundHarmony {
-        let data = self.buffer.as_ref();
-        let raw = data[field::OPT_1] & 0x03;
-        SoundHarmony::from(raw)
-    }
-
-    /// Return the parameters validity flag.
-    #[inline]
-    pub fn parameters_validity(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_1] & 0x04 != 0
-    }
-
-    /// Return the mood lighting level field.
-    #[inline]
-    pub fn mood_lighting_level(&self) -> MoodLightingLevel {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_1] & 0x38) >> 3;
-        MoodLightingLevel::from(raw)
-    }
-
-    /// Return the temperature unit field.
-    #[inline]
-    pub fn temperature_unit(&self) -> TemperatureUnit {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_1] & 0x40) >> 6;
-        TemperatureUnit::from(raw)
-    }
-
-    /// Return the volume unit field.
-    #[inline]
-    pub fn volume_unit(&self) -> VolumeUnit {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_1] & 0x80) >> 7;
-        VolumeUnit::from(raw)
-    }
-
-    /// Return the mood lighting enable flag.
-    #[inline]
-    pub fn mood_lighting_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x01 != 0
-    }
-
-    /// Return the daytime running lamps enable flag.
-    #[inline]
-    pub fn daytime_running_lamps_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x02 != 0
-    }
-
-    /// Return the adaptive lamps enable flag.
-    #[inline]
-    pub fn adaptive_lamps_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x04 != 0
-    }
-
-    /// Return the welcome function enable flag.
-    #[inline]
-    pub fn welcome_function_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x08 != 0
-    }
-
-    /// Return the boot selective unlocking enable flag.
-    #[inline]
-    pub fn boot_selective_unlocking_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x10 != 0
-    }
-
-    /// Return the selective unlocking enable flag.
-    #[inline]
-    pub fn selective_unlocking_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x20 != 0
-    }
-
-    /// Return the key selective unlocking enable flag.
-    #[inline]
-    pub fn key_selective_unlocking_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x40 != 0
-    }
-
-    /// Return the automatic electrical parking brake application enable flag.
-    #[inline]
-    pub fn auto_elec_parking_brake_application_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_2] & 0x80 != 0
-    }
-
-    /// Return the automatic headlamps enable flag.
-    #[inline]
-    pub fn automatic_headlamps_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_3] & 0x01 != 0
-    }
-
-    /// Return the welcome lighting duration field.
-    #[inline]
-    pub fn welcome_lighting_duration(&self) -> LightingDuration2010 {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_3] & 0x06) >> 1;
-        LightingDuration2010::from(raw)
-    }
-
-    /// Return the welcome lighting enable flag.
-    #[inline]
-    pub fn welcome_lighting_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_3] & 0x08 != 0
-    }
-
-    /// Return the motorway lighting enable flag.
-    #[inline]
-    pub fn motorway_lighting_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_3] & 0x10 != 0
-    }
-
-    /// Return the follow-me-home lighting duration field.
-    #[inline]
-    pub fn follow_me_home_lighting_duration(&self) -> LightingDuration2010 {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_3] & 0x60) >> 5;
-        LightingDuration2010::from(raw)
-    }
-
-    /// Return the follow-me-home enable flag.
-    #[inline]
-    pub fn follow_me_home_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_3] & 0x80 != 0
-    }
-
-    /// Return the configurable button/key mode field.
-    #[inline]
-    pub fn configurable_key_mode(&self) -> ConfigurableKeyAction2010 {
-        let data = self.buffer.as_ref();
-        let raw = data[field::OPT_4] & 0x0f;
-        ConfigurableKeyAction2010::from(raw)
-    }
-
-    /// Return the motorized tailgate enable flag.
-    #[inline]
-    pub fn motorized_tailgate_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_4] & 0x10 != 0
-    }
-
-    /// Return the rear wiper in reverse gear enable flag.
-    #[inline]
-    pub fn rear_wiper_in_reverse_gear_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_4] & 0x20 != 0
-    }
-
-    /// Return the blind spot monitoring enable flag.
-    #[inline]
-    pub fn blind_spot_monitoring_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_4] & 0x40 != 0
-    }
-
-    /// Return the parking sensors enable flag.
-    #[inline]
-    pub fn park_sensors_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_4] & 0x80 != 0
-    }
-
-    /// Return the mirrors tilting in reverse gear enable flag.
-    #[inline]
-    pub fn mirrors_tilting_in_reverse_gear_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_5] & 0x04 != 0
-    }
-
-    /// Return the indirect under-inflation detection reset status flag.
-    #[inline]
-    pub fn indirect_under_inflation_reset_status(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_5] & 0x08 != 0
-    }
-
-    /// Return the automatic emergency braking enable flag.
-    #[inline]
-    pub fn automatic_emergency_braking_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_5] & 0x10 != 0
-    }
-
-    /// Return the collision alert sensibility level field.
-    #[inline]
-    pub fn collision_alert_sensibility_level(&self) -> CollisionAlertSensibilityLevel {
-        let data = self.buffer.as_ref();
-        let raw = (data[field::OPT_5] & 0x60) >> 5;
-        CollisionAlertSensibilityLevel::from(raw)
-    }
-
-    /// Return the collision alert enable flag.
-    #[inline]
-    pub fn collision_alert_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_5] & 0x80 != 0
-    }
-
-    /// Return the hands-free tailgate enable flag.
-    #[inline]
-    pub fn hands_free_tailgate_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x01 != 0
-    }
-
-    /// Return the speed limit recognition enable flag.
-    #[inline]
-    pub fn speed_limit_recognition_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x02 != 0
-    }
-
-    /// Return the radiator grill lamps enable flag (maybe anti-fog lights?).
-    #[inline]
-    pub fn radiator_grill_lamps_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x04 != 0
-    }
-
-    /// Return the automatic main beam enable flag.
-    #[inline]
-    pub fn automatic_main_beam_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x08 != 0
-    }
-
-    /// Return the driver alert assist enable flag.
-    #[inline]
-    pub fn driver_alert_assist_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x10 != 0
-    }
-
-    /// Return the hands-free tailgate automatic locking enable flag.
-    #[inline]
-    pub fn hands_free_tailgate_auto_lock_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x20 != 0
-    }
-
-    /// Return the extended traffic sign recognition enable flag.
-    #[inline]
-    pub fn extended_traffic_sign_recognition_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x40 != 0
-    }
-
-    /// Return the electric child lock security enable flag.
-    #[inline]
-    pub fn electric_child_security_enable(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_6] & 0x80 != 0
-    }
-
-    /// Return automatic mirrors folding inhibit enable flag.
-    #[inline]
-    pub fn auto_mirrors_folding_inhibit(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::OPT_7] & 0x08 != 0
+/// Transmission of a x260 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
+
+can_frame! {
+    /// A high-level representation of a x260 CAN frame.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        derive(defmt::Format)
+    )]
+    pub struct Repr {
+        #[field(field::OPT_0, 0x01, 0, consumption_unit, set_consumption_unit, "consumption unit", "VSM_INF_PROFILS_UNITE_CONSO_HS7_260")]
+        pub consumption_unit: ConsumptionUnit,
+        #[field(field::OPT_0, 0x02, 1, distance_unit, set_distance_unit, "distance unit", "VSM_INF_PROFILS_UNITE_DISTANCE_HS7_260")]
+        pub distance_unit: DistanceUnit,
+        #[field(field::OPT_0, 0x7c, 2, language, set_language, "language", "VSM_INF_PROFILS_LANGUE_VHL_HS7_260")]
+        pub language: Language,
+        #[flag(field::OPT_0, 0x80, 7, units_language_parameters_validity, set_units_language_parameters_validity, "units and language parameters validity", "VSM_INF_PROFILS_DISPO_UNITES_LANGUE_HS7_260")]
+        pub units_language_parameters_validity: bool,
+        #[field(field::OPT_1, 0x03, 0, sound_harmony, set_sound_harmony, "sound harmony", "VSM_INF_PROFILS_HARMONIE_SON_HS7_260")]
+        pub sound_harmony: SoundHarmony,
+        #[flag(field::OPT_1, 0x04, 2, parameters_validity, set_parameters_validity, "parameters validity", "VSM_INF_PROFILS_DISPO_PARAM_HS7_260")]
+        pub parameters_validity: bool,
+        #[field(field::OPT_1, 0x38, 3, mood_lighting_level, set_mood_lighting_level, "mood lighting level", "VSM_INF_PROFILS_NIV_AMBIANCE_HS7_260")]
+        pub mood_lighting_level: MoodLightingLevel,
+        #[field(field::OPT_1, 0x40, 6, temperature_unit, set_temperature_unit, "temperature unit", "VSM_INF_PROFILS_UNITE_TEMPERATURE_HS7_260")]
+        pub temperature_unit: TemperatureUnit,
+        #[field(field::OPT_1, 0x80, 7, volume_unit, set_volume_unit, "volume unit", "VSM_INF_PROFILS_UNITE_VOLUME_HS7_260")]
+        pub volume_unit: VolumeUnit,
+        #[flag(field::OPT_2, 0x01, 0, mood_lighting_enable, set_mood_lighting_enable, "mood lighting enable", "VSM_INF_PROFILS_ECLAI_AMBI_HS7_260")]
+        pub mood_lighting_enabled: bool,
+        #[flag(field::OPT_2, 0x02, 1, daytime_running_lamps_enable, set_daytime_running_lamps_enable, "daytime running lamps enable", "VSM_INF_PROFILS_FCT_FEUX_DIURN_O_HS7_260")]
+        pub daytime_running_lamps_enabled: bool,
+        #[flag(field::OPT_2, 0x04, 2, adaptive_lamps_enable, set_adaptive_lamps_enable, "adaptive lamps enable", "VSM_INF_PROFILS_ECL_ADAPT_HS7_260")]
+        pub adaptive_lamps_enabled: bool,
+        #[flag(field::OPT_2, 0x08, 3, welcome_function_enable, set_welcome_function_enable, "welcome function enable", "VSM_INF_PROFILS_ACCUEIL_COND_HS7_260")]
+        pub welcome_function_enabled: bool,
+        #[flag(field::OPT_2, 0x10, 4, boot_selective_unlocking_enable, set_boot_selective_unlocking_enable, "boot selective unlocking enable", "VSM_INF_PROFILS_SELEC_ARRIERE_HS7_260")]
+        pub boot_selective_unlocking_enabled: bool,
+        #[flag(field::OPT_2, 0x20, 5, selective_unlocking_enable, set_selective_unlocking_enable, "selective unlocking enable", "VSM_INF_PROFILS_SELEC_CABINE_HS7_260")]
+        pub selective_unlocking_enabled: bool,
+        #[flag(field::OPT_2, 0x40, 6, key_selective_unlocking_enable, set_key_selective_unlocking_enable, "key selective unlocking enable", "VSM_INF_PROFILS_SELEC_OUV_PLIP_HS7_260")]
+        pub key_selective_unlocking_enabled: bool,
+        #[flag(field::OPT_2, 0x80, 7, auto_elec_parking_brake_application_enable, set_auto_elec_parking_brake_application_enable, "automatic electrical parking brake application enable", "VSM_INF_PROFILS_SER_FSE_AUTO_HS7_260")]
+        pub automatic_elec_parking_brake_application_enabled: bool,
+        #[flag(field::OPT_3, 0x01, 0, automatic_headlamps_enable, set_automatic_headlamps_enable, "automatic headlamps enable", "VSM_INF_PROFILS_ECLAIRAGE_AUTO_HS7_260")]
+        pub automatic_headlamps_enabled: bool,
+        #[field(field::OPT_3, 0x06, 1, welcome_lighting_duration, set_welcome_lighting_duration, "welcome lighting duration", "VSM_INF_PROFILS_TEMPO_ECL_DECONDA_HS7_260")]
+        pub welcome_lighting_duration: LightingDuration2010,
+        #[flag(field::OPT_3, 0x08, 3, welcome_lighting_enable, set_welcome_lighting_enable, "welcome lighting enable", "VSM_INF_PROFILS_ECL_DECONDA_HS7_260")]
+        pub welcome_lighting_enabled: bool,
+        #[flag(field::OPT_3, 0x10, 4, motorway_lighting_enable, set_motorway_lighting_enable, "motorway lighting enable", "VSM_INF_PROFILS_ECL_AUTOROUTE_HS7_260")]
+        pub motorway_lighting_enabled: bool,
+        #[field(field::OPT_3, 0x60, 5, follow_me_home_lighting_duration, set_follow_me_home_lighting_duration, "follow-me-home lighting duration", "VSM_INF_PROFILS_TEMPO_EXT_PHARE_HS7_260")]
+        pub follow_me_home_lighting_duration: LightingDuration2010,
+        #[flag(field::OPT_3, 0x80, 7, follow_me_home_enable, set_follow_me_home_enable, "follow-me-home enable", "VSM_INF_PROFILS_ECLAIRAGE_ACCOM_HS7_260")]
+        pub follow_me_home_enabled: bool,
+        #[field(field::OPT_4, 0x0f, 0, configurable_key_mode, set_configurable_key_mode, "configurable button/key mode", "VSM_INF_PROFILS_FCT_TCFG_HS7_260")]
+        pub configurable_key_mode: ConfigurableKeyAction2010,
+        #[flag(field::OPT_4, 0x10, 4, motorized_tailgate_enable, set_motorized_tailgate_enable, "motorized tailgate enable", "VSM_INF_PROFILS_FCT_MOT_VOL_AR_HS7_260")]
+        pub motorized_tailgate_enabled: bool,
+        #[flag(field::OPT_4, 0x20, 5, rear_wiper_in_reverse_gear_enable, set_rear_wiper_in_reverse_gear_enable, "rear wiper in reverse gear enable", "VSM_INF_PROFILS_ESSUI_VIT_MAR_HS7_260")]
+        pub rear_wiper_in_reverse_gear_enabled: bool,
+        #[flag(field::OPT_4, 0x40, 6, blind_spot_monitoring_enable, set_blind_spot_monitoring_enable, "blind spot monitoring enable", "VSM_INF_PROFILS_SAM_STATUS_HS7_260")]
+        pub blind_spot_monitoring_enabled: bool,
+        #[flag(field::OPT_4, 0x80, 7, park_sensors_enable, set_park_sensors_enable, "parking sensors enable", "VSM_INF_PROFILS_AAS_STATUS_HS7_260")]
+        pub park_sensors_enabled: bool,
+        #[flag(field::OPT_5, 0x04, 2, mirrors_tilting_in_reverse_gear_enable, set_mirrors_tilting_in_reverse_gear_enable, "mirrors tilting in reverse gear enable")]
+        pub mirrors_tilting_in_reverse_gear_enabled: bool,
+        #[flag(field::OPT_5, 0x08, 3, indirect_under_inflation_reset_status, set_indirect_under_inflation_reset_status, "indirect under-inflation detection reset status", "VSM_INF_PROFILS_REINIT_DSG_STATUS_HS7_260")]
+        pub indirect_under_inflation_reset_status: bool,
+        #[flag(field::OPT_5, 0x10, 4, automatic_emergency_braking_enable, set_automatic_emergency_braking_enable, "automatic emergency braking enable", "VSM_INF_PROFILS_SELEC_FARC_FA_HS7_260")]
+        pub automatic_emergency_braking_enabled: bool,
+        #[field(field::OPT_5, 0x60, 5, collision_alert_sensibility_level, set_collision_alert_sensibility_level, "collision alert sensibility level", "VSM_INF_PROFILS_ARC_SENS_NIV_HS7_260")]
+        pub collision_alert_sensibility_level: CollisionAlertSensibilityLevel,
+        #[flag(field::OPT_5, 0x80, 7, collision_alert_enable, set_collision_alert_enable, "collision alert enable", "VSM_INF_PROFILS_ARC_SENS_HS7_260")]
+        pub collision_alert_enabled: bool,
+        #[flag(field::OPT_6, 0x01, 0, hands_free_tailgate_enable, set_hands_free_tailgate_enable, "hands-free tailgate enable", "VSM_INF_PROFILS_FCT_MENU_VAM_BAA_HS7_260")]
+        pub hands_free_tailgate_enabled: bool,
+        #[flag(field::OPT_6, 0x02, 1, speed_limit_recognition_enable, set_speed_limit_recognition_enable, "speed limit recognition enable", "VSM_INF_PROFILS_FCT_MENU_ILV_ILV_HS7_260")]
+        pub speed_limit_recognition_enabled: bool,
+        #[flag(field::OPT_6, 0x04, 2, radiator_grill_lamps_enable, set_radiator_grill_lamps_enable, "radiator grill lamps enable", "VSM_INF_PROFILS_FCT_ECL_CALAND_HS7_260")]
+        pub radiator_grill_lamps_enabled: bool,
+        #[flag(field::OPT_6, 0x08, 3, automatic_main_beam_enable, set_automatic_main_beam_enable, "automatic main beam enable", "VSM_INF_PROFILS_FCT_MENU_ECLX_ECL_CAFR_HS7_260")]
+        pub automatic_main_beam_enabled: bool,
+        #[flag(field::OPT_6, 0x10, 4, driver_alert_assist_enable, set_driver_alert_assist_enable, "driver alert assist enable", "VSM_INF_PROFILS_FCT_MENU_DAA_ACTIV_HS7_260")]
+        pub driver_alert_assist_enabled: bool,
+        #[flag(field::OPT_6, 0x20, 5, hands_free_tailgate_auto_lock_enable, set_hands_free_tailgate_auto_lock_enable, "hands-free tailgate automatic locking enable", "VSM_INF_PROFILS_FCT_MENU_BAA_LOCK_HS7_260")]
+        pub hands_free_tailgate_auto_lock_enabled: bool,
+        #[flag(field::OPT_6, 0x40, 6, extended_traffic_sign_recognition_enable, set_extended_traffic_sign_recognition_enable, "extended traffic sign recognition enable", "VSM_INF_PROFILS_FCT_MENU_ILV_ETSR_HS7_260")]
+        pub extended_traffic_sign_recognition_enabled: bool,
+        #[flag(field::OPT_6, 0x80, 7, electric_child_security_enable, set_electric_child_security_enable, "electric child lock security enable", "VSM_INF_PROFILS_FCT_MENU_ECS_MODE_HS7_260")]
+        pub electric_child_security_enabled: bool,
+        #[flag(field::OPT_7, 0x08, 3, auto_mirrors_folding_inhibit, set_auto_mirrors_folding_inhibit, "automatic mirrors folding inhibit enable")]
+        pub auto_mirrors_folding_inhibit: bool,
     }
 }
 
-impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
-    /// Set the consumption unit field.
-    #[inline]
-    pub fn set_consumption_unit(&mut self, value: ConsumptionUnit) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_0] & !0x01;
-        let raw = raw | u8::from(value);
-        data[field::OPT_0] = raw;
-    }
-
-    /// Set the distance unit field.
-    #[inline]
-    pub fn set_distance_unit(&mut self, value: DistanceUnit) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_0] & !0x02;
-        let raw = raw | (u8::from(value) << 1);
-        data[field::OPT_0] = raw;
-    }
-
-    /// Set the language field.
-    #[inline]
-    pub fn set_language(&mut self, value: Language) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_0] & !0x7c;
-        let raw = raw | ((u8::from(value) << 2) & 0x7c);
-        data[field::OPT_0] = raw;
-    }
-
-    /// Set the units and language parameters validity flag.
-    #[inline]
-    pub fn set_units_language_parameters_validity(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_0];
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::OPT_0] = raw;
-    }
-
-    /// Set the sound harmony field.
-    #[inline]
-    pub fn set_sound_harmony(&mut self, value: SoundHarmony) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x03;
-        let raw = raw | (u8::from(value) & 0x03);
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the parameters validity flag.
-    #[inline]
-    pub fn set_parameters_validity(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1];
-        let raw = if value { raw | 0x04 } else { raw & !0x04 };
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the mood lighting level field.
-    #[inline]
-    pub fn set_mood_lighting_level(&mut self, value: MoodLightingLevel) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x38;
-        let raw = raw | ((u8::from(value) << 3) & 0x38);
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the temperature unit field.
-    #[inline]
-    pub fn set_temperature_unit(&mut self, value: TemperatureUnit) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x40;
-        let raw = raw | ((u8::from(value) << 6) & 0x40);
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the volume unit field.
-    #[inline]
-    pub fn set_volume_unit(&mut self, value: VolumeUnit) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x80;
-        let raw = raw | (u8::from(value) << 7);
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the mood lighting enable flag.
-    #[inline]
-    pub fn set_mood_lighting_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x01;
-        let raw = if value { raw | 0x01 } else { raw & !0x01 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the daytime running lamps enable flag.
-    #[inline]
-    pub fn set_daytime_running_lamps_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x02;
-        let raw = if value { raw | 0x02 } else { raw & !0x02 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the adaptive lamps enable flag.
-    #[inline]
-    pub fn set_adaptive_lamps_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x04;
-        let raw = if value { raw | 0x04 } else { raw & !0x04 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the welcome function enable flag.
-    #[inline]
-    pub fn set_welcome_function_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x08;
-        let raw = if value { raw | 0x08 } else { raw & !0x08 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the bool selective unlocking enable flag.
-    #[inline]
-    pub fn set_boot_selective_unlocking_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x10;
-        let raw = if value { raw | 0x10 } else { raw & !0x10 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the selective unlocking enable flag.
-    #[inline]
-    pub fn set_selective_unlocking_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x20;
-        let raw = if value { raw | 0x20 } else { raw & !0x20 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the key selective unlocking enable flag.
-    #[inline]
-    pub fn set_key_selective_unlocking_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x40;
-        let raw = if value { raw | 0x40 } else { raw & !0x40 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the automatic electrical parking brake application enable flag.
-    #[inline]
-    pub fn set_auto_elec_parking_brake_application_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_2] & !0x80;
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::OPT_2] = raw;
-    }
-
-    /// Set the automatic headlamps enable flag.
-    #[inline]
-    pub fn set_automatic_headlamps_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_3] & !0x01;
-        let raw = if value { raw | 0x01 } else { raw & !0x01 };
-        data[field::OPT_3] = raw;
-    }
-
-    /// Set the welcome lighting duration field.
-    #[inline]
-    pub fn set_welcome_lighting_duration(&mut self, value: LightingDuration2010) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_3] & !0x06;
-        let raw = raw | ((u8::from(value) << 1) & 0x06);
-        data[field::OPT_3] = raw;
-    }
-
-    /// Set the welcome lighting enable flag.
-    #[inline]
-    pub fn set_welcome_lighting_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_3] & !0x08;
-        let raw = if value { raw | 0x08 } else { raw & !0x08 };
-        data[field::OPT_3] = raw;
-    }
-
-    /// Set the motorway lighting enable flag.
-    #[inline]
-    pub fn set_motorway_lighting_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_3] & !0x10;
-        let raw = if value { raw | 0x10 } else { raw & !0x10 };
-        data[field::OPT_3] = raw;
-    }
-
-    /// Set the follow-me-home lighting duration field.
-    #[inline]
-    pub fn set_follow_me_home_lighting_duration(&mut self, value: LightingDuration2010) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_3] & !0x60;
-        let raw = raw | ((u8::from(value) << 5) & 0x60);
-        data[field::OPT_3] = raw;
-    }
-
-    /// Set the follow-me-home enable flag.
-    #[inline]
-    pub fn set_follow_me_home_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_3] & !0x80;
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::OPT_3] = raw;
-    }
-
-    /// Set the locking mode on 'COE' enable flag.
-    #[inline]
-    pub fn set_locking_mode_on_coe_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x08;
-        let raw = if value { raw | 0x08 } else { raw & !0x08 };
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the automatic door locking when leaving enable flag.
-    #[inline]
-    pub fn set_auto_door_locking_when_leaving_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x10;
-        let raw = if value { raw | 0x10 } else { raw & !0x10 };
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the boot permanent locking enable flag.
-    #[inline]
-    pub fn set_boot_permanent_locking_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x20;
-        let raw = if value { raw | 0x20 } else { raw & !0x20 };
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the automatic door locking when driving enable flag.
-    #[inline]
-    pub fn set_auto_door_locking_when_driving_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_1] & !0x40;
-        let raw = if value { raw | 0x40 } else { raw & !0x40 };
-        data[field::OPT_1] = raw;
-    }
-
-    /// Set the configurable button/key enable flag.
-    #[inline]
-    pub fn set_configurable_key_mode(&mut self, value: ConfigurableKeyAction2010) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_4] & !0x0f;
-        let raw = raw | (u8::from(value) & 0x0f);
-        data[field::OPT_4] = raw;
-    }
-
-    /// Set the motorized tailgate enable flag.
-    #[inline]
-    pub fn set_motorized_tailgate_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_4] & !0x10;
-        let raw = if value { raw | 0x10 } else { raw & !0x10 };
-        data[field::OPT_4] = raw;
-    }
-
-    /// Set the rear wiper in reverse gear enable flag.
-    #[inline]
-    pub fn set_rear_wiper_in_reverse_gear_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_4] & !0x20;
-        let raw = if value { raw | 0x20 } else { raw & !0x20 };
-        data[field::OPT_4] = raw;
-    }
-
-    /// Set the blind spot monitoring enable flag.
-    #[inline]
-    pub fn set_blind_spot_monitoring_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_4] & !0x40;
-        let raw = if value { raw | 0x40 } else { raw & !0x40 };
-        data[field::OPT_4] = raw;
-    }
-
-    /// Set the parking sensors enable flag.
-    #[inline]
-    pub fn set_park_sensors_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_4] & !0x80;
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::OPT_4] = raw;
-    }
-
-    /// Set the mirrors tilting in reverse gear enable flag.
-    #[inline]
-    pub fn set_mirrors_tilting_in_reverse_gear_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_5] & !0x04;
-        let raw = if value { raw | 0x04 } else { raw & !0x04 };
-        data[field::OPT_5] = raw;
-    }
-
-    /// Set the indirect under-inflation detection reset status flag.
-    #[inline]
-    pub fn set_indirect_under_inflation_reset_status(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_5] & !0x08;
-        let raw = if value { raw | 0x08 } else { raw & !0x08 };
-        data[field::OPT_5] = raw;
-    }
-
-    /// Set the automatic emergency braking enable flag.
-    #[inline]
-    pub fn set_automatic_emergency_braking_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_5] & !0x10;
-        let raw = if value { raw | 0x10 } else { raw & !0x10 };
-        data[field::OPT_5] = raw;
-    }
-
-    /// Set the collision alert sensibility level field.
-    #[inline]
-    pub fn set_collision_alert_sensibility_level(&mut self, value: CollisionAlertSensibilityLevel) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_5] & !0x60;
-        let raw = raw | ((u8::from(value) << 5) & 0x60);
-        data[field::OPT_5] = raw;
-    }
-
-    /// Set the collision alert enable flag.
-    #[inline]
-    pub fn set_collision_alert_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_5] & !0x80;
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::OPT_5] = raw;
-    }
-
-    /// Set the hands-free tailgate enable flag.
-    #[inline]
-    pub fn set_hands_free_tailgate_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x01;
-        let raw = if value { raw | 0x01 } else { raw & !0x01 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the speed limit recognition enable flag.
-    #[inline]
-    pub fn set_speed_limit_recognition_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x02;
-        let raw = if value { raw | 0x02 } else { raw & !0x02 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the radiator grill lamps enable flag (maybe anti-fog lights).
-    #[inline]
-    pub fn set_radiator_grill_lamps_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x04;
-        let raw = if value { raw | 0x04 } else { raw & !0x04 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the automatic main beam enable flag.
-    #[inline]
-    pub fn set_automatic_main_beam_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x08;
-        let raw = if value { raw | 0x08 } else { raw & !0x08 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the driver alert assist enable flag.
-    #[inline]
-    pub fn set_driver_alert_assist_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x10;
-        let raw = if value { raw | 0x10 } else { raw & !0x10 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the hands-free tailgate automatic locking enable flag.
-    #[inline]
-    pub fn set_hands_free_tailgate_auto_lock_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x20;
-        let raw = if value { raw | 0x20 } else { raw & !0x20 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the extended traffic sign recognition enable flag.
-    #[inline]
-    pub fn set_extended_traffic_sign_recognition_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x40;
-        let raw = if value { raw | 0x40 } else { raw & !0x40 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the electric child lock security enable flag.
-    #[inline]
-    pub fn set_electric_child_security_enable(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_6] & !0x80;
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::OPT_6] = raw;
-    }
-
-    /// Set the automatic mirrors folding inhibit enable flag.
+impl<T: AsRef<[u8]>> Frame<T> {
+    /// Return the raw reserved bits, i.e. the frame bits without known meaning, masked
+    /// with [RESERVED_MASK].
     #[inline]
-    pub fn set_auto_mirrors_folding_inhibit(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::OPT_7] & !0x08;
-        let raw = if value { raw | 0x08 } else { raw & !0x08 };
-        data[field::OPT_7] = raw;
-    }
-}
-
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
-            Err(err) => {
-                write!(f, "x260 ({})", err)?;
-                Ok(())
-            }
-        }
-    }
-}
-
-impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
-    fn as_ref(&self) -> &[u8] {
-        self.buffer.as_ref()
+    pub fn reserved_bits(&self) -> u64 {
+        let data = self.buffer.as_ref();
+        NetworkEndian::read_u64(&data[..FRAME_LEN]) & RESERVED_MASK
     }
 }
 
-/// A high-level representation of a x260 CAN frame.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct Repr {
-    pub consumption_unit: ConsumptionUnit,
-    pub distance_unit: DistanceUnit,
-    pub language: Language,
-    pub units_language_parameters_validity: bool,
-    pub sound_harmony: SoundHarmony,
-    pub parameters_validity: bool,
-    pub mood_lighting_level: MoodLightingLevel,
-    pub temperature_unit: TemperatureUnit,
-    pub volume_unit: VolumeUnit,
-    pub mood_lighting_enabled: bool,
-    pub daytime_running_lamps_enabled: bool,
-    pub adaptive_lamps_enabled: bool,
-    pub welcome_function_enabled: bool,
-    pub boot_selective_unlocking_enabled: bool,
-    pub selective_unlocking_enabled: bool,
-    pub key_selective_unlocking_enabled: bool,
-    pub automatic_elec_parking_brake_application_enabled: bool,
-    pub automatic_headlamps_enabled: bool,
-    pub welcome_lighting_duration: LightingDuration2010,
-    pub welcome_lighting_enabled: bool,
-    pub motorway_lighting_enabled: bool,
-    pub follow_me_home_lighting_duration: LightingDuration2010,
-    pub follow_me_home_enabled: bool,
-    pub configurable_key_mode: ConfigurableKeyAction2010,
-    pub motorized_tailgate_enabled: bool,
-    pub rear_wiper_in_reverse_gear_enabled: bool,
-    pub blind_spot_monitoring_enabled: bool,
-    pub park_sensors_enabled: bool,
-    pub mirrors_tilting_in_reverse_gear_enabled: bool,
-    pub indirect_under_inflation_reset_status: bool,
-    pub automatic_emergency_braking_enabled: bool,
-    pub collision_alert_sensibility_level: CollisionAlertSensibilityLevel,
-    pub collision_alert_enabled: bool,
-    pub hands_free_tailgate_enabled: bool,
-    pub speed_limit_recognition_enabled: bool,
-    pub radiator_grill_lamps_enabled: bool,
-    pub automatic_main_beam_enabled: bool,
-    pub driver_alert_assist_enabled: bool,
-    pub hands_free_tailgate_auto_lock_enabled: bool,
-    pub extended_traffic_sign_recognition_enabled: bool,
-    pub electric_child_security_enabled: bool,
-    pub auto_mirrors_folding_inhibit: bool,
-}
-
 impl Repr {
-    pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
+    /// Parse a x260 CAN frame like [Repr::parse], but return `Err(Error::Invalid)` if any
+    /// reserved bit is set, e.g. on firmware variants using undocumented bits.
+    pub fn parse_strict<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
-
-        Ok(Repr {
-            consumption_unit: frame.consumption_unit(),
-            distance_unit: frame.distance_unit(),
-            language: frame.language(),
-            units_language_parameters_validity: frame.units_language_parameters_validity(),
-            sound_harmony: frame.sound_harmony(),
-            parameters_validity: frame.parameters_validity(),
-            mood_lighting_level: frame.mood_lighting_level(),
-            temperature_unit: frame.temperature_unit(),
-            volume_unit: frame.volume_unit(),
-            mood_lighting_enabled: frame.mood_lighting_enable(),
-            daytime_running_lamps_enabled: frame.daytime_running_lamps_enable(),
-            adaptive_lamps_enabled: frame.adaptive_lamps_enable(),
-            welcome_function_enabled: frame.welcome_function_enable(),
-            boot_selective_unlocking_enabled: frame.boot_selective_unlocking_enable(),
-            selective_unlocking_enabled: frame.selective_unlocking_enable(),
-            key_selective_unlocking_enabled: frame.key_selective_unlocking_enable(),
-            automatic_elec_parking_brake_application_enabled: frame
-                .auto_elec_parking_brake_application_enable(),
-            automatic_headlamps_enabled: frame.automatic_headlamps_enable(),
-            welcome_lighting_duration: frame.welcome_lighting_duration(),
-            welcome_lighting_enabled: frame.welcome_lighting_enable(),
-            motorway_lighting_enabled: frame.motorway_lighting_enable(),
-            follow_me_home_lighting_duration: frame.follow_me_home_lighting_duration(),
-            follow_me_home_enabled: frame.follow_me_home_enable(),
-            configurable_key_mode: frame.configurable_key_mode(),
-            motorized_tailgate_enabled: frame.motorized_tailgate_enable(),
-            rear_wiper_in_reverse_gear_enabled: frame.rear_wiper_in_reverse_gear_enable(),
-            blind_spot_monitoring_enabled: frame.blind_spot_monitoring_enable(),
-            park_sensors_enabled: frame.park_sensors_enable(),
-            mirrors_tilting_in_reverse_gear_enabled: frame.mirrors_tilting_in_reverse_gear_enable(),
-            indirect_under_inflation_reset_status: frame.indirect_under_inflation_reset_status(),
-            automatic_emergency_braking_enabled: frame.automatic_emergency_braking_enable(),
-            collision_alert_sensibility_level: frame.collision_alert_sensibility_level(),
-            collision_alert_enabled: frame.collision_alert_enable(),
-            hands_free_tailgate_enabled: frame.hands_free_tailgate_enable(),
-            speed_limit_recognition_enabled: frame.speed_limit_recognition_enable(),
-            radiator_grill_lamps_enabled: frame.radiator_grill_lamps_enable(),
-            automatic_main_beam_enabled: frame.automatic_main_beam_enable(),
-            driver_alert_assist_enabled: frame.driver_alert_assist_enable(),
-            hands_free_tailgate_auto_lock_enabled: frame.hands_free_tailgate_auto_lock_enable(),
-            extended_traffic_sign_recognition_enabled: frame
-                .extended_traffic_sign_recognition_enable(),
-            electric_child_security_enabled: frame.electric_child_security_enable(),
-            auto_mirrors_folding_inhibit: frame.auto_mirrors_folding_inhibit(),
-        })
-    }
-
-    /// Return the length of a frame that will be emitted from this high-level representation.
-    pub fn buffer_len(&self) -> usize {
-        FRAME_LEN
-    }
-
-    /// Emit a high-level representation into a x260 CAN frame.
-    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
-        frame.set_consumption_unit(self.consumption_unit);
-        frame.set_distance_unit(self.distance_unit);
-        frame.set_language(self.language);
-        frame.set_units_language_parameters_validity(self.units_language_parameters_validity);
-        frame.set_sound_harmony(self.sound_harmony);
-        frame.set_parameters_validity(self.parameters_validity);
-        frame.set_mood_lighting_level(self.mood_lighting_level);
-        frame.set_temperature_unit(self.temperature_unit);
-        frame.set_volume_unit(self.volume_unit);
-        frame.set_mood_lighting_enable(self.mood_lighting_enabled);
-        frame.set_daytime_running_lamps_enable(self.daytime_running_lamps_enabled);
-        frame.set_adaptive_lamps_enable(self.adaptive_lamps_enabled);
-        frame.set_welcome_function_enable(self.welcome_function_enabled);
-        frame.set_boot_selective_unlocking_enable(self.boot_selective_unlocking_enabled);
-        frame.set_selective_unlocking_enable(self.selective_unlocking_enabled);
-        frame.set_key_selective_unlocking_enable(self.key_selective_unlocking_enabled);
-        frame.set_auto_elec_parking_brake_application_enable(
-            self.automatic_elec_parking_brake_application_enabled,
-        );
-        frame.set_automatic_headlamps_enable(self.automatic_headlamps_enabled);
-        frame.set_welcome_lighting_duration(self.welcome_lighting_duration);
-        frame.set_welcome_lighting_enable(self.welcome_lighting_enabled);
-        frame.set_motorway_lighting_enable(self.motorway_lighting_enabled);
-        frame.set_follow_me_home_lighting_duration(self.follow_me_home_lighting_duration);
-        frame.set_follow_me_home_enable(self.follow_me_home_enabled);
-        frame.set_configurable_key_mode(self.configurable_key_mode);
-        frame.set_motorized_tailgate_enable(self.motorized_tailgate_enabled);
-        frame.set_rear_wiper_in_reverse_gear_enable(self.rear_wiper_in_reverse_gear_enabled);
-        frame.set_blind_spot_monitoring_enable(self.blind_spot_monitoring_enabled);
-        frame.set_park_sensors_enable(self.park_sensors_enabled);
-        frame.set_mirrors_tilting_in_reverse_gear_enable(
-            self.mirrors_tilting_in_reverse_gear_enabled,
-        );
-        frame.set_indirect_under_inflation_reset_status(self.indirect_under_inflation_reset_status);
-        frame.set_automatic_emergency_braking_enable(self.automatic_emergency_braking_enabled);
-        frame.set_collision_alert_sensibility_level(self.collision_alert_sensibility_level);
-        frame.set_collision_alert_enable(self.collision_alert_enabled);
-        frame.set_hands_free_tailgate_enable(self.hands_free_tailgate_enabled);
-        frame.set_speed_limit_recognition_enable(self.speed_limit_recognition_enabled);
-        frame.set_radiator_grill_lamps_enable(self.radiator_grill_lamps_enabled);
-        frame.set_automatic_main_beam_enable(self.automatic_main_beam_enabled);
-        frame.set_driver_alert_assist_enable(self.driver_alert_assist_enabled);
-        frame.set_hands_free_tailgate_auto_lock_enable(self.hands_free_tailgate_auto_lock_enabled);
-        frame.set_extended_traffic_sign_recognition_enable(
-            self.extended_traffic_sign_recognition_enabled,
-        );
-        frame.set_electric_child_security_enable(self.electric_child_security_enabled);
-        frame.set_auto_mirrors_folding_inhibit(self.auto_mirrors_folding_inhibit);
+        if frame.reserved_bits() != 0 {
+            return Err(Error::Invalid);
+        }
+        Repr::parse(frame)
     }
 }
 
-impl fmt::Display for Repr {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x260 consumption_unit={}", self.consumption_unit)?;
-        writeln!(f, " distance_unit={}", self.distance_unit)?;
-        writeln!(f, " language={}", self.language)?;
-        writeln!(
-            f,
-            " units_language_parameters_validity={}",
-            self.units_language_parameters_validity
-        )?;
-        writeln!(f, " sound_harmony={}", self.sound_harmony)?;
-        writeln!(f, " parameters_validity={}", self.parameters_validity)?;
-        writeln!(f, " mood_lighting_level={}", self.mood_lighting_level)?;
-        writeln!(f, " temperature_unit={}", self.temperature_unit)?;
-        writeln!(f, " volume_unit={}", self.volume_unit)?;
-        writeln!(f, " mood_lighting_enabled={}", self.mood_lighting_enabled)?;
-        writeln!(
-            f,
-            " daytime_running_lamps_enabled={}",
-            self.daytime_running_lamps_enabled
-        )?;
-        writeln!(f, " adaptive_lamps_enabled={}", self.adaptive_lamps_enabled)?;
-        writeln!(
-            f,
-            " welcome_function_enabled={}",
-            self.welcome_function_enabled
-        )?;
-        writeln!(
-            f,
-            " boot_selective_unlocking_enabled={}",
-            self.boot_selective_unlocking_enabled
-        )?;
-        writeln!(
-            f,
-            " selective_unlocking_enabled={}",
-            self.selective_unlocking_enabled
-        )?;
-        writeln!(
-            f,
-            " key_selective_unlocking_enabled={}",
-            self.key_selective_unlocking_enabled
-        )?;
-        writeln!(
-            f,
-            " automatic_elec_parking_brake_application_enabled={}",
-            self.automatic_elec_parking_brake_application_enabled
-        )?;
-        writeln!(
-            f,
-            " automatic_headlamps_enabled={}",
-            self.automatic_headlamps_enabled
-        )?;
-        writeln!(
-            f,
-            " welcome_lighting_duration={}",
-            self.welcome_lighting_duration
-        )?;
-        writeln!(
-            f,
-            " welcome_lighting_enabled={}",
-            self.welcome_lighting_enabled
-        )?;
-        writeln!(
-            f,
-            " motorway_lighting_enabled={}",
-            self.motorway_lighting_enabled
-        )?;
-        writeln!(
-            f,
-            " follow_me_home_lighting_duration={}",
-            self.follow_me_home_lighting_duration
-        )?;
-        writeln!(f, " follow_me_home_enabled={}", self.follow_me_home_enabled)?;
-        writeln!(f, " configurable_key_mode={}", self.configurable_key_mode)?;
-        writeln!(
-            f,
-            " motorized_tailgate_enabled={}",
-            self.motorized_tailgate_enabled
-        )?;
-        writeln!(
-            f,
-            " rear_wiper_in_reverse_gear_enabled={}",
-            self.rear_wiper_in_reverse_gear_enabled
-        )?;
-        writeln!(
-            f,
-            " blind_spot_monitoring_enabled={}",
-            self.blind_spot_monitoring_enabled
-        )?;
-        writeln!(f, " park_sensors_enabled={}", self.park_sensors_enabled)?;
-        writeln!(
-            f,
-            " mirrors_tilting_in_reverse_gear_enabled={}",
-            self.mirrors_tilting_in_reverse_gear_enabled
-        )?;
-        writeln!(
-            f,
-            " indirect_under_inflation_reset_status={}",
-            self.indirect_under_inflation_reset_status
-        )?;
-        writeln!(
-            f,
-            " automatic_emergency_braking_enabled={}",
-            self.automatic_emergency_braking_enabled
-        )?;
-        writeln!(
-            f,
-            " collision_alert_sensibility_level={}",
-            self.collision_alert_sensibility_level
-        )?;
-        writeln!(
-            f,
-            " collision_alert_enabled={}",
-            self.collision_alert_enabled
-        )?;
-        writeln!(
-            f,
-            " hands_free_tailgate_enabled={}",
-            self.hands_free_tailgate_enabled
-        )?;
-        writeln!(
-            f,
-            " speed_limit_recognition_enabled={}",
-            self.speed_limit_recognition_enabled
-        )?;
-        writeln!(
-            f,
-            " radiator_grill_lamps_enabled={}",
-            self.radiator_grill_lamps_enabled
-        )?;
-        writeln!(
-            f,
-            " automatic_main_beam_enabled={}",
-            self.automatic_main_beam_enabled
-        )?;
-        writeln!(
-            f,
-            " driver_alert_assist_enabled={}",
-            self.driver_alert_assist_enabled
-        )?;
-        writeln!(
-            f,
-            " hands_free_tailgate_auto_lock_enabled={}",
-            self.hands_free_tailgate_auto_lock_enabled
-        )?;
-        writeln!(
-            f,
-            " extended_traffic_sign_recognition_enabled={}",
-            self.extended_traffic_sign_recognition_enabled
-        )?;
-        writeln!(
-            f,
-            " electric_child_security_enabled={}",
-            self.electric_child_security_enabled
-        )?;
-        writeln!(
-            f,
-            " auto_mirrors_folding_inhibit={}",
-            self.auto_mirrors_folding_inhibit
-        )
-    }
+repr_builder! {
+    idle: [0x01, 0x00, 0xab, 0xaa, 0xa3, 0xa8, 0xaa, 0x00],
+    parked: [0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00],
+    with_consumption_unit => consumption_unit: ConsumptionUnit,
+    with_distance_unit => distance_unit: DistanceUnit,
+    with_language => language: Language,
+    with_units_language_parameters_validity => units_language_parameters_validity: bool,
+    with_sound_harmony => sound_harmony: SoundHarmony,
+    with_parameters_validity => parameters_validity: bool,
+    with_mood_lighting_level => mood_lighting_level: MoodLightingLevel,
+    with_temperature_unit => temperature_unit: TemperatureUnit,
+    with_volume_unit => volume_unit: VolumeUnit,
+    with_mood_lighting_enabled => mood_lighting_enabled: bool,
+    with_daytime_running_lamps_enabled => daytime_running_lamps_enabled: bool,
+    with_adaptive_lamps_enabled => adaptive_lamps_enabled: bool,
+    with_welcome_function_enabled => welcome_function_enabled: bool,
+    with_boot_selective_unlocking_enabled => boot_selective_unlocking_enabled: bool,
+    with_selective_unlocking_enabled => selective_unlocking_enabled: bool,
+    with_key_selective_unlocking_enabled => key_selective_unlocking_enabled: bool,
+    with_automatic_elec_parking_brake_application_enabled => automatic_elec_parking_brake_application_enabled: bool,
+    with_automatic_headlamps_enabled => automatic_headlamps_enabled: bool,
+    with_welcome_lighting_duration => welcome_lighting_duration: LightingDuration2010,
+    with_welcome_lighting_enabled => welcome_lighting_enabled: bool,
+    with_motorway_lighting_enabled => motorway_lighting_enabled: bool,
+    with_follow_me_home_lighting_duration => follow_me_home_lighting_duration: LightingDuration2010,
+    with_follow_me_home_enabled => follow_me_home_enabled: bool,
+    with_configurable_key_mode => configurable_key_mode: ConfigurableKeyAction2010,
+    with_motorized_tailgate_enabled => motorized_tailgate_enabled: bool,
+    with_rear_wiper_in_reverse_gear_enabled => rear_wiper_in_reverse_gear_enabled: bool,
+    with_blind_spot_monitoring_enabled => blind_spot_monitoring_enabled: bool,
+    with_park_sensors_enabled => park_sensors_enabled: bool,
+    with_mirrors_tilting_in_reverse_gear_enabled => mirrors_tilting_in_reverse_gear_enabled: bool,
+    with_indirect_under_inflation_reset_status => indirect_under_inflation_reset_status: bool,
+    with_automatic_emergency_braking_enabled => automatic_emergency_braking_enabled: bool,
+    with_collision_alert_sensibility_level => collision_alert_sensibility_level: CollisionAlertSensibilityLevel,
+    with_collision_alert_enabled => collision_alert_enabled: bool,
+    with_hands_free_tailgate_enabled => hands_free_tailgate_enabled: bool,
+    with_speed_limit_recognition_enabled => speed_limit_recognition_enabled: bool,
+    with_radiator_grill_lamps_enabled => radiator_grill_lamps_enabled: bool,
+    with_automatic_main_beam_enabled => automatic_main_beam_enabled: bool,
+    with_driver_alert_assist_enabled => driver_alert_assist_enabled: bool,
+    with_hands_free_tailgate_auto_lock_enabled => hands_free_tailgate_auto_lock_enabled: bool,
+    with_extended_traffic_sign_recognition_enabled => extended_traffic_sign_recognition_enabled: bool,
+    with_electric_child_security_enabled => electric_child_security_enabled: bool,
+    with_auto_mirrors_folding_inhibit => auto_mirrors_folding_inhibit: bool,
 }
 
+/// Signals of a x260 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("consumption_unit", 0, 0, 1)
+        .values(&[(0, "VolumePerDistance"), (1, "DistancePerVolume")]),
+    Signal::new("distance_unit", 0, 1, 1).values(&[(0, "Kilometer"), (1, "Mile")]),
+    Signal::new("language", 0, 6, 5).values(&[
+        (0, "French"),
+        (1, "English"),
+        (2, "German"),
+        (3, "Spanish"),
+        (4, "Italian"),
+        (5, "Portuguese"),
+        (6, "Dutch"),
+        (7, "Greek"),
+        (8, "BrazilianPortuguese"),
+        (9, "Polish"),
+        (10, "TraditionalChinese"),
+        (11, "SimplifiedChinese"),
+        (12, "Turkish"),
+        (13, "Japanese"),
+        (14, "Russian"),
+        (15, "Invalid"),
+        (18, "Arabic"),
+        (23, "Farsi"),
+        (29, "Swedish"),
+    ]),
+    Signal::new("units_language_parameters_validity", 0, 7, 1),
+    Signal::new("sound_harmony", 1, 1, 2).values(&[
+        (0, "Harmony1"),
+        (1, "Harmony2"),
+        (2, "Harmony3"),
+        (3, "Harmony4"),
+    ]),
+    Signal::new("parameters_validity", 1, 2, 1),
+    Signal::new("mood_lighting_level", 1, 5, 3).values(&[
+        (0, "Level1"),
+        (1, "Level2"),
+        (2, "Level3"),
+        (3, "Level4"),
+        (5, "Level5"),
+        (6, "Level6"),
+    ]),
+    Signal::new("temperature_unit", 1, 6, 1).values(&[(0, "Celsius"), (1, "Fahrenheit")]),
+    Signal::new("volume_unit", 1, 7, 1).values(&[(0, "Liter"), (1, "Gallon")]),
+    Signal::new("mood_lighting_enabled", 2, 0, 1),
+    Signal::new("daytime_running_lamps_enabled", 2, 1, 1),
+    Signal::new("adaptive_lamps_enabled", 2, 2, 1),
+    Signal::new("welcome_function_enabled", 2, 3, 1),
+    Signal::new("boot_selective_unlocking_enabled", 2, 4, 1),
+    Signal::new("selective_unlocking_enabled", 2, 5, 1),
+    Signal::new("key_selective_unlocking_enabled", 2, 6, 1),
+    Signal::new("automatic_elec_parking_brake_application_enabled", 2, 7, 1),
+    Signal::new("automatic_headlamps_enabled", 3, 0, 1),
+    Signal::new("welcome_lighting_duration", 3, 2, 2).values(&[
+        (0, "FifteenSeconds"),
+        (1, "ThirtySeconds"),
+        (2, "SixtySeconds"),
+    ]),
+    Signal::new("welcome_lighting_enabled", 3, 3, 1),
+    Signal::new("motorway_lighting_enabled", 3, 4, 1),
+    Signal::new("follow_me_home_lighting_duration", 3, 6, 2).values(&[
+        (0, "FifteenSeconds"),
+        (1, "ThirtySeconds"),
+        (2, "SixtySeconds"),
+    ]),
+    Signal::new("follow_me_home_enabled", 3, 7, 1),
+    Signal::new("configurable_key_mode", 4, 3, 4).values(&[
+        (0, "CeilingLight"),
+        (1, "BlackPanel"),
+        (2, "FaultLog"),
+        (3, "ClusterCustomization"),
+        (5, "ClusterColor"),
+        (6, "ManualFaultCheck"),
+    ]),
+    Signal::new("motorized_tailgate_enabled", 4, 4, 1),
+    Signal::new("rear_wiper_in_reverse_gear_enabled", 4, 5, 1),
+    Signal::new("blind_spot_monitoring_enabled", 4, 6, 1),
+    Signal::new("park_sensors_enabled", 4, 7, 1),
+    Signal::new("mirrors_tilting_in_reverse_gear_enabled", 5, 2, 1),
+    Signal::new("indirect_under_inflation_reset_status", 5, 3, 1),
+    Signal::new("automatic_emergency_braking_enabled", 5, 4, 1),
+    Signal::new("collision_alert_sensibility_level", 5, 6, 2).values(&[
+        (1, "Close"),
+        (2, "Normal"),
+        (3, "Distant"),
+    ]),
+    Signal::new("collision_alert_enabled", 5, 7, 1),
+    Signal::new("hands_free_tailgate_enabled", 6, 0, 1),
+    Signal::new("speed_limit_recognition_enabled", 6, 1, 1),
+    Signal::new("radiator_grill_lamps_enabled", 6, 2, 1),
+    Signal::new("automatic_main_beam_enabled", 6, 3, 1),
+    Signal::new("driver_alert_assist_enabled", 6, 4, 1),
+    Signal::new("hands_free_tailgate_auto_lock_enabled", 6, 5, 1),
+    Signal::new("extended_traffic_sign_recognition_enabled", 6, 6, 1),
+    Signal::new("electric_child_security_enabled", 6, 7, 1),
+    Signal::new("auto_mirrors_folding_inhibit", 7, 3, 1),
+];
+
 impl From<&crate::aee2004::conf::x260::Repr> for Repr {
     fn from(repr_2004: &crate::aee2004::conf::x260::Repr) -> Self {
         Repr {
@@ -1289,6 +446,58 @@ impl From<&crate::aee2004::conf::x260::Repr> for Repr {
     }
 }
 
+impl From<&crate::aee2010::infodiv::x15b::Repr> for Repr {
+    fn from(repr_x15b: &crate::aee2010::infodiv::x15b::Repr) -> Self {
+        Repr {
+            consumption_unit: repr_x15b.consumption_unit,
+            distance_unit: repr_x15b.distance_unit,
+            language: repr_x15b.language,
+            units_language_parameters_validity: repr_x15b.units_language_parameters_validity,
+            sound_harmony: repr_x15b.sound_harmony,
+            parameters_validity: repr_x15b.parameters_validity,
+            mood_lighting_level: repr_x15b.mood_lighting_level,
+            temperature_unit: repr_x15b.temperature_unit,
+            volume_unit: repr_x15b.volume_unit,
+            mood_lighting_enabled: repr_x15b.mood_lighting_enabled,
+            daytime_running_lamps_enabled: repr_x15b.daytime_running_lamps_enabled,
+            adaptive_lamps_enabled: repr_x15b.adaptive_lamps_enabled,
+            welcome_function_enabled: repr_x15b.welcome_function_enabled,
+            boot_selective_unlocking_enabled: repr_x15b.boot_selective_unlocking_enabled,
+            selective_unlocking_enabled: repr_x15b.selective_unlocking_enabled,
+            key_selective_unlocking_enabled: repr_x15b.key_selective_unlocking_enabled,
+            automatic_elec_parking_brake_application_enabled: repr_x15b
+                .automatic_elec_parking_brake_application_enabled,
+            automatic_headlamps_enabled: repr_x15b.automatic_headlamps_enabled,
+            welcome_lighting_duration: repr_x15b.welcome_lighting_duration,
+            welcome_lighting_enabled: repr_x15b.welcome_lighting_enabled,
+            motorway_lighting_enabled: repr_x15b.motorway_lighting_enabled,
+            follow_me_home_lighting_duration: repr_x15b.follow_me_home_lighting_duration,
+            follow_me_home_enabled: repr_x15b.follow_me_home_enabled,
+            configurable_key_mode: repr_x15b.configurable_key_mode,
+            motorized_tailgate_enabled: repr_x15b.motorized_tailgate_enabled,
+            rear_wiper_in_reverse_gear_enabled: repr_x15b.rear_wiper_in_reverse_gear_enabled,
+            blind_spot_monitoring_enabled: repr_x15b.blind_spot_monitoring_enabled,
+            park_sensors_enabled: repr_x15b.park_sensors_enabled,
+            mirrors_tilting_in_reverse_gear_enabled: repr_x15b
+                .mirrors_tilting_in_reverse_gear_enabled,
+            indirect_under_inflation_reset_status: false, // Not a setting.
+            automatic_emergency_braking_enabled: repr_x15b.automatic_emergency_braking_enabled,
+            collision_alert_sensibility_level: repr_x15b.collision_alert_sensibility_level,
+            collision_alert_enabled: repr_x15b.collision_alert_enabled,
+            hands_free_tailgate_enabled: repr_x15b.hands_free_tailgate_enabled,
+            speed_limit_recognition_enabled: repr_x15b.speed_limit_recognition_enabled,
+            radiator_grill_lamps_enabled: repr_x15b.radiator_grill_lamps_enabled,
+            automatic_main_beam_enabled: repr_x15b.automatic_main_beam_enabled,
+            driver_alert_assist_enabled: repr_x15b.driver_alert_assist_enabled,
+            hands_free_tailgate_auto_lock_enabled: repr_x15b.hands_free_tailgate_auto_lock_enabled,
+            extended_traffic_sign_recognition_enabled: repr_x15b
+                .extended_traffic_sign_recognition_enabled,
+            electric_child_security_enabled: !repr_x15b.electric_child_security_temp_disabled,
+            auto_mirrors_folding_inhibit: repr_x15b.auto_mirrors_folding_inhibit,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
@@ -1666,4 +875,31 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_repr_parse_strict() {
+        let frame = Frame::new_unchecked(&REPR_FRAME_BYTES_1);
+        assert_eq!(frame.reserved_bits(), 0);
+        assert_eq!(Repr::parse_strict(&frame), Repr::parse(&frame));
+
+        let bytes: [u8; 8] = [0x01, 0x00, 0xab, 0xaa, 0xa3, 0xa8, 0xaa, 0x80];
+        let frame = Frame::new_unchecked(&bytes);
+        assert_eq!(frame.reserved_bits(), 0x80);
+        assert_eq!(Repr::parse_strict(&frame).unwrap_err(), Error::Invalid);
+        assert!(Repr::parse(&frame).is_ok());
+    }
+
+    #[test]
+    fn test_repr_builder() {
+        assert_eq!(Repr::builder().build(), Ok(frame_1_repr()));
+
+        let repr = Repr::builder()
+            .with_language(Language::French)
+            .with_blind_spot_monitoring_enabled(true)
+            .build()
+            .unwrap();
+        assert_eq!(repr.language, Language::French);
+        assert!(repr.blind_spot_monitoring_enabled);
+        assert_eq!(repr.distance_unit, frame_1_repr().distance_unit);
+    }
 }
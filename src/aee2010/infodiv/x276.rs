@@ -1,10 +1,13 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use byteorder::{ByteOrder, NetworkEndian};
-use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+use time::{macros::datetime, Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
 
 use crate::{
     config::{ClockFormat, DisplayMode},
+    datetime::CanDateTime,
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     Error, Result, YEAR_OFFSET,
 };
 
@@ -65,6 +68,8 @@ pub const FRAME_LEN: usize = field::FLAGS_ADBLUE_AUTONOMY.end;
 
 /// Periodicity of a x276 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x276 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -163,6 +168,18 @@ impl<T: AsRef<[u8]>> Frame<T> {
         data[field::MINUTE] & 0x3f
     }
 
+    /// Return the date and time fields.
+    #[inline]
+    pub fn datetime(&self) -> CanDateTime {
+        CanDateTime {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+        }
+    }
+
     /// Return the Adblue autonomy field.
     #[inline]
     pub fn adblue_autonomy(&self) -> u16 {
@@ -243,6 +260,16 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
         data[field::MINUTE] = raw;
     }
 
+    /// Set the date and time fields.
+    #[inline]
+    pub fn set_datetime(&mut self, value: CanDateTime) {
+        self.set_year(value.year);
+        self.set_month(value.month);
+        self.set_day(value.day);
+        self.set_hour(value.hour);
+        self.set_minute(value.minute);
+    }
+
     /// Set the Adblue autonomy field.
     #[inline]
     pub fn set_adblue_autonomy(&mut self, value: u16) {
@@ -262,10 +289,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x276 ({})", err)?;
                 Ok(())
@@ -280,12 +307,22 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x276 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub clock_format: ClockFormat,
     pub clock_disp_mode: DisplayMode,
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub utc_datetime: OffsetDateTime,
     pub adblue_autonomy: u16,
     pub adblue_autonomy_display_request: bool,
@@ -340,25 +377,69 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x96, 0x11, 0x0a, 0x0f, 0x1d, 0x3f, 0xfe],
+    parked: [0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00] => Repr {
+        clock_format: ClockFormat::H12,
+        clock_disp_mode: DisplayMode::Steady,
+        utc_datetime: datetime!(2000-01-01 0:00 UTC),
+        adblue_autonomy: 0,
+        adblue_autonomy_display_request: false,
+    },
+    with_clock_format => clock_format: ClockFormat,
+    with_clock_disp_mode => clock_disp_mode: DisplayMode,
+    with_utc_datetime => utc_datetime: OffsetDateTime as Debug2Format,
+    with_adblue_autonomy => adblue_autonomy: u16,
+    with_adblue_autonomy_display_request => adblue_autonomy_display_request: bool,
+}
+
+/// Signals of a x276 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("clock_format", 0, 7, 1).values(&[(0, "H12"), (1, "H24")]),
+    Signal::new("clock_disp_mode", 1, 4, 1).values(&[(0, "Steady"), (1, "Blinking")]),
+    Signal::new("utc_datetime_year", 0, 6, 7).scaled(1.0, 2000.0),
+    Signal::new("utc_datetime_month", 1, 3, 4),
+    Signal::new("utc_datetime_day", 2, 5, 6),
+    Signal::new("utc_datetime_hour", 3, 4, 5).unit("h"),
+    Signal::new("utc_datetime_minute", 4, 5, 6).unit("min"),
+    Signal::new("adblue_autonomy", 5, 5, 14).unit("km"),
+    Signal::new("adblue_autonomy_display_request", 5, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x276 clock_format={}", self.clock_format)?;
-        writeln!(f, " clock_disp_mode={}", self.clock_disp_mode)?;
-        writeln!(f, " utc_datetime={}", self.utc_datetime)?;
-        writeln!(f, " adblue_autonomy={}", self.adblue_autonomy)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x276")?;
+        write!(f, "{}clock_format={}", sep, self.clock_format)?;
+        write!(f, "{}clock_disp_mode={}", sep, self.clock_disp_mode)?;
+        write!(f, "{}utc_datetime={}", sep, self.utc_datetime)?;
+        write!(f, "{}adblue_autonomy={}", sep, self.adblue_autonomy)?;
+        write!(
             f,
-            " adblue_autonomy_display_request={}",
-            self.adblue_autonomy_display_request
+            "{}adblue_autonomy_display_request={}",
+            sep, self.adblue_autonomy_display_request
         )
     }
 }
 
+impl From<&crate::aee2004::conf::x376::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x376::Repr) -> Self {
+        Repr {
+            clock_format: ClockFormat::H12, // No equivalent on AEE2004.
+            clock_disp_mode: repr_2004.clock_disp_mode,
+            utc_datetime: repr_2004.utc_datetime,
+            adblue_autonomy: 0,                     // No equivalent on AEE2004.
+            adblue_autonomy_display_request: false, // No equivalent on AEE2004.
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
     use crate::{
         config::{ClockFormat, DisplayMode},
+        datetime::CanDateTime,
         Error,
     };
 
@@ -387,6 +468,10 @@ mod test {
         assert_eq!(frame.day(), 0x0a);
         assert_eq!(frame.hour(), 0x0f);
         assert_eq!(frame.minute(), 0x1d);
+        assert_eq!(
+            frame.datetime(),
+            CanDateTime::new(0x16, 0x01, 0x0a, 0x0f, 0x1d).unwrap()
+        );
         assert_eq!(frame.adblue_autonomy(), 0x3ffe);
         assert_eq!(frame.adblue_autonomy_display_request(), false);
     }
@@ -439,4 +524,18 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x376::Repr::builder()
+            .with_clock_disp_mode(DisplayMode::Blinking)
+            .with_utc_datetime(datetime!(2022-06-15 10:24 UTC))
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2004);
+        assert_eq!(repr.clock_format, ClockFormat::H12);
+        assert_eq!(repr.clock_disp_mode, DisplayMode::Blinking);
+        assert_eq!(repr.utc_datetime, datetime!(2022-06-15 10:24 UTC));
+        assert_eq!(repr.adblue_autonomy, 0);
+    }
 }
@@ -1,13 +1,6 @@
-use core::{cmp::Ordering, fmt, time::Duration};
+use core::time::Duration;
 
-use crate::{Error, Result};
-
-/// A read/write wrapper around an CAN frame buffer.
-#[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct Frame<T: AsRef<[u8]>> {
-    buffer: T,
-}
+use crate::{frame::TransmissionKind, signal::Signal};
 
 /*
 2A8 ACC_XVV_IHM_ETAT_2_ACC_INCIT_GO_AUTO_HS7_2A8
@@ -31,123 +24,29 @@ pub const FRAME_LEN: usize = field::XVV_0 + 1;
 
 /// Periodicity of a x2a8 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
-
-impl<T: AsRef<[u8]>> Frame<T> {
-    /// Create a raw octet buffer with a CAN frame structure.
-    #[inline]
-    pub fn new_unchecked(buffer: T) -> Frame<T> {
-        Frame { buffer }
-    }
-
-    /// Shorthand for a combination of [new_unchecked] and [check_len].
-    ///
-    /// [new_unchecked]: #method.new_unchecked
-    /// [check_len]: #method.check_len
-    #[inline]
-    pub fn new_checked(buffer: T) -> Result<Frame<T>> {
-        let packet = Self::new_unchecked(buffer);
-        packet.check_len()?;
-        Ok(packet)
-    }
-
-    /// Ensure that no accessor method will panic if called.
-    /// Returns `Err(Error::Truncated)` if the buffer is too short.
-    ///
-    /// The result of this check is invalidated by calling [set_payload_len].
-    ///
-    /// [set_payload_len]: #method.set_payload_len
-    #[inline]
-    pub fn check_len(&self) -> Result<()> {
-        let len = self.buffer.as_ref().len();
-        match len.cmp(&FRAME_LEN) {
-            Ordering::Less => Err(Error::Truncated),
-            Ordering::Greater => Err(Error::Overlong),
-            Ordering::Equal => Ok(()),
-        }
-    }
-
-    /// Consume the frame, returning the underlying buffer.
-    #[inline]
-    pub fn into_inner(self) -> T {
-        self.buffer
-    }
-
-    /// Return the frame length.
-    #[inline]
-    pub fn frame_len(&self) -> usize {
-        FRAME_LEN
-    }
-
-    /// Return the 'bascule' position flag.
-    #[inline]
-    pub fn bascule_position(&self) -> bool {
-        let data = self.buffer.as_ref();
-        data[field::XVV_0] & 0x80 != 0
-    }
-}
-
-impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
-    /// Set the 'bascule' position flag.
-    #[inline]
-    pub fn set_bascule_position(&mut self, value: bool) {
-        let data = self.buffer.as_mut();
-        let raw = data[field::XVV_0];
-        let raw = if value { raw | 0x80 } else { raw & !0x80 };
-        data[field::XVV_0] = raw;
+/// Transmission of a x2a8 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
+
+can_frame! {
+    /// A high-level representation of a x2a8 CAN frame.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        derive(defmt::Format)
+    )]
+    pub struct Repr {
+        #[flag(field::XVV_0, 0x80, 7, bascule_position, set_bascule_position, "'bascule' position", "ACC_XVV_IHM_ETAT_2_POSITION_BASCULE_HS7_2A8")]
+        pub bascule_position: bool,
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
-            Err(err) => {
-                write!(f, "x2a8 ({})", err)?;
-                Ok(())
-            }
-        }
-    }
-}
-
-impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
-    fn as_ref(&self) -> &[u8] {
-        self.buffer.as_ref()
-    }
+repr_builder! {
+    idle: [0x00],
+    with_bascule_position => bascule_position: bool,
 }
 
-/// A high-level representation of a x2a8 CAN frame.
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct Repr {
-    pub bascule_position: bool,
-}
-
-impl Repr {
-    pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
-        frame.check_len()?;
-
-        Ok(Repr {
-            bascule_position: frame.bascule_position(),
-        })
-    }
-
-    /// Return the length of a frame that will be emitted from this high-level representation.
-    pub fn buffer_len(&self) -> usize {
-        FRAME_LEN
-    }
-
-    /// Emit a high-level representation into a x2a8 CAN frame.
-    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
-        frame.set_bascule_position(self.bascule_position);
-    }
-}
-
-impl fmt::Display for Repr {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x2a8")?;
-        writeln!(f, " bascule_position={}", self.bascule_position)
-    }
-}
+/// Signals of a x2a8 CAN frame.
+pub const SIGNALS: &[Signal] = &[Signal::new("bascule_position", 0, 7, 1)];
 
 #[cfg(test)]
 mod test {
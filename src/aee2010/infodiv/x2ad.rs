@@ -1,6 +1,10 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -67,6 +71,8 @@ pub const FRAME_LEN: usize = field::AC_7 + 1;
 
 /// Periodicity of a x2ad CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x2ad CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -133,10 +139,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x2ad ({})", err)?;
                 Ok(())
@@ -151,9 +157,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x2ad CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub central_temperature: u8,
 }
@@ -162,9 +174,14 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             central_temperature: frame.central_temperature(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -178,10 +195,19 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    with_central_temperature => central_temperature: u8,
+}
+
+/// Signals of a x2ad CAN frame.
+pub const SIGNALS: &[Signal] = &[Signal::new("central_temperature", 0, 4, 3)];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x2ad")?;
-        writeln!(f, " central_temperature={}", self.central_temperature)
+        let sep = field_separator(f);
+        write!(f, "x2ad")?;
+        write!(f, "{}central_temperature={}", sep, self.central_temperature)
     }
 }
 
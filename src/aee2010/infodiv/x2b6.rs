@@ -2,7 +2,11 @@ use core::{cmp::Ordering, fmt, time::Duration};
 
 use heapless::String;
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, DangerToken, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -48,6 +52,8 @@ pub const FRAME_LEN: usize = field::VIS_8 + 1;
 
 /// Periodicity of a x2b6 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x2b6 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -155,65 +161,65 @@ impl<T: AsRef<[u8]>> Frame<T> {
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     /// Set the VIS first char.
     #[inline]
-    pub fn set_vis_first_char(&mut self, value: char) {
+    pub fn set_vis_first_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_1] = value as u8;
     }
 
     /// Set the VIS second char.
     #[inline]
-    pub fn set_vis_second_char(&mut self, value: char) {
+    pub fn set_vis_second_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_2] = value as u8;
     }
 
     /// Set the VIS third char.
     #[inline]
-    pub fn set_vis_third_char(&mut self, value: char) {
+    pub fn set_vis_third_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_3] = value as u8;
     }
 
     /// Set the VIS fourth char.
     #[inline]
-    pub fn set_vis_fourth_char(&mut self, value: char) {
+    pub fn set_vis_fourth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_4] = value as u8;
     }
 
     /// Set the VIS fifth char.
     #[inline]
-    pub fn set_vis_fifth_char(&mut self, value: char) {
+    pub fn set_vis_fifth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_5] = value as u8;
     }
 
     /// Set the VIS sixth char.
     #[inline]
-    pub fn set_vis_sixth_char(&mut self, value: char) {
+    pub fn set_vis_sixth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_6] = value as u8;
     }
 
     /// Set the VIS seventh char.
     #[inline]
-    pub fn set_vis_seventh_char(&mut self, value: char) {
+    pub fn set_vis_seventh_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_7] = value as u8;
     }
 
     /// Set the VIS eighth char.
     #[inline]
-    pub fn set_vis_eighth_char(&mut self, value: char) {
+    pub fn set_vis_eighth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VIS_8] = value as u8;
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x2b6 ({})", err)?;
                 Ok(())
@@ -228,9 +234,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!(record);
+
 /// A high-level representation of a x2b6 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub vis: String<8>,
 }
@@ -239,25 +251,32 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
+        // A non-ASCII char is encoded on several bytes, so it does not fit in the string.
+        if !frame.as_ref().is_ascii() {
+            return Err(Error::Invalid);
+        }
+
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked and whose chars are ASCII.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
         let mut vis: String<8> = String::new();
-        vis.push(frame.vis_first_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_second_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_third_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_fourth_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_fifth_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_sixth_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_seventh_char())
-            .map_err(|_| Error::Invalid)?;
-        vis.push(frame.vis_eighth_char())
-            .map_err(|_| Error::Invalid)?;
-
-        Ok(Repr { vis })
+        for c in [
+            frame.vis_first_char(),
+            frame.vis_second_char(),
+            frame.vis_third_char(),
+            frame.vis_fourth_char(),
+            frame.vis_fifth_char(),
+            frame.vis_sixth_char(),
+            frame.vis_seventh_char(),
+            frame.vis_eighth_char(),
+        ] {
+            // An ASCII char is encoded on a single byte, so it always fits.
+            let _ = vis.push(c);
+        }
+
+        Repr { vis }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -265,29 +284,59 @@ impl Repr {
         FRAME_LEN
     }
 
-    /// Emit a high-level representation into a x2b6 CAN frame.
-    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+    /// Emit a high-level representation into a x2b6 CAN frame, `token` acknowledging the
+    /// write of the VIN.
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>, token: DangerToken) {
         let mut vis = self.vis.clone();
-        frame.set_vis_eighth_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_seventh_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_sixth_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_fifth_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_fourth_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_third_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_second_char(vis.pop().unwrap_or(' '));
-        frame.set_vis_first_char(vis.pop().unwrap_or(' '));
+        frame.set_vis_eighth_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_seventh_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_sixth_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_fifth_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_fourth_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_third_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_second_char(vis.pop().unwrap_or(' '), token);
+        frame.set_vis_first_char(vis.pop().unwrap_or(' '), token);
     }
 }
 
+repr_builder! {
+    record,
+    idle: [0x37, 0x34, 0x37, 0x38, 0x30, 0x32, 0x34, 0x38],
+    parked: [0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30],
+    with_vis(token) => vis: String<8>,
+}
+
+/// Signals of a x2b6 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("vis_1", 0, 7, 8),
+    Signal::new("vis_2", 1, 7, 8),
+    Signal::new("vis_3", 2, 7, 8),
+    Signal::new("vis_4", 3, 7, 8),
+    Signal::new("vis_5", 4, 7, 8),
+    Signal::new("vis_6", 5, 7, 8),
+    Signal::new("vis_7", 6, 7, 8),
+    Signal::new("vis_8", 7, 7, 8),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x2b6 vis={}", self.vis)
+        let sep = field_separator(f);
+        write!(f, "x2b6")?;
+        write!(f, "{}vis={}", sep, self.vis)
+    }
+}
+
+impl From<&crate::aee2004::conf::x2b6::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x2b6::Repr) -> Self {
+        Repr {
+            vis: repr_2004.vis.clone(),
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Frame, Repr};
+    use super::{DangerToken, Frame, Repr};
     use heapless::String;
 
     use crate::Error;
@@ -319,14 +368,14 @@ mod test {
         let mut bytes = [0u8; 8];
         let mut frame = Frame::new_unchecked(&mut bytes);
 
-        frame.set_vis_first_char('7');
-        frame.set_vis_second_char('4');
-        frame.set_vis_third_char('7');
-        frame.set_vis_fourth_char('8');
-        frame.set_vis_fifth_char('0');
-        frame.set_vis_sixth_char('2');
-        frame.set_vis_seventh_char('4');
-        frame.set_vis_eighth_char('8');
+        frame.set_vis_first_char('7', DangerToken::unchecked());
+        frame.set_vis_second_char('4', DangerToken::unchecked());
+        frame.set_vis_third_char('7', DangerToken::unchecked());
+        frame.set_vis_fourth_char('8', DangerToken::unchecked());
+        frame.set_vis_fifth_char('0', DangerToken::unchecked());
+        frame.set_vis_sixth_char('2', DangerToken::unchecked());
+        frame.set_vis_seventh_char('4', DangerToken::unchecked());
+        frame.set_vis_eighth_char('8', DangerToken::unchecked());
 
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
@@ -358,7 +407,7 @@ mod test {
         let mut buf = [0u8; 8];
         let mut frame = Frame::new_unchecked(&mut buf);
         let repr = frame_repr();
-        repr.emit(&mut frame);
+        repr.emit(&mut frame, DangerToken::unchecked());
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
 }
@@ -1,4 +1,8 @@
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 use core::{cmp::Ordering, fmt, time::Duration};
 
 /// A read/write wrapper around an CAN frame buffer.
@@ -79,6 +83,8 @@ pub const FRAME_LEN: usize = field::REQ_6 + 1;
 
 /// Periodicity of a x329 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(200);
+/// Transmission of a x329 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -321,10 +327,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x329 ({})", err)?;
                 Ok(())
@@ -339,9 +345,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x329 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub fragrance_diffuser_mono_fragrance_cartridge_type: u8,
     pub lane_keep_assist_button_state: bool,
@@ -361,7 +373,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             fragrance_diffuser_mono_fragrance_cartridge_type: frame.fragrance_diffuser_mono_type(),
             lane_keep_assist_button_state: frame.lane_keep_assist_button_state(),
             front_seat_massage_adjustment: frame.front_massage_adjustment(),
@@ -374,7 +391,7 @@ impl Repr {
             asr_inhibit: frame.asr_inhibit(),
             consumption_history_zoomed_value: frame.consumption_history_zoomed_value(),
             dynamic_mode_selected_mode: frame.dynamic_mode_selected_mode(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -401,55 +418,89 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x10, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00],
+    with_fragrance_diffuser_mono_fragrance_cartridge_type => fragrance_diffuser_mono_fragrance_cartridge_type: u8,
+    with_lane_keep_assist_button_state => lane_keep_assist_button_state: bool,
+    with_front_seat_massage_adjustment => front_seat_massage_adjustment: u8,
+    with_fragrance_diffuser_perfume_selection => fragrance_diffuser_perfume_selection: u8,
+    with_fragrance_diffuser_intensity => fragrance_diffuser_intensity: u8,
+    with_fragrance_diffuser_request => fragrance_diffuser_request: u8,
+    with_massage_activation_request => massage_activation_request: bool,
+    with_massage_intensity => massage_intensity: u8,
+    with_massage_type => massage_type: u8,
+    with_asr_inhibit => asr_inhibit: bool,
+    with_consumption_history_zoomed_value => consumption_history_zoomed_value: u8,
+    with_dynamic_mode_selected_mode => dynamic_mode_selected_mode: u8,
+}
+
+/// Signals of a x329 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("fragrance_diffuser_mono_fragrance_cartridge_type", 0, 1, 2),
+    Signal::new("lane_keep_assist_button_state", 0, 4, 1),
+    Signal::new("front_seat_massage_adjustment", 1, 1, 2),
+    Signal::new("fragrance_diffuser_perfume_selection", 1, 3, 2),
+    Signal::new("fragrance_diffuser_intensity", 1, 5, 2),
+    Signal::new("fragrance_diffuser_request", 1, 7, 2),
+    Signal::new("massage_activation_request", 2, 1, 1),
+    Signal::new("massage_intensity", 2, 3, 2),
+    Signal::new("massage_type", 2, 7, 4),
+    Signal::new("asr_inhibit", 3, 0, 1),
+    Signal::new("consumption_history_zoomed_value", 3, 2, 2),
+    Signal::new("dynamic_mode_selected_mode", 3, 4, 2),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x329")?;
+        write!(
             f,
-            "x329 fragrance_diffuser_mono_fragrance_cartridge_type={}",
-            self.fragrance_diffuser_mono_fragrance_cartridge_type
+            "{}fragrance_diffuser_mono_fragrance_cartridge_type={}",
+            sep, self.fragrance_diffuser_mono_fragrance_cartridge_type
         )?;
-        writeln!(
+        write!(
             f,
-            " lane_keep_assist_button_state={}",
-            self.lane_keep_assist_button_state
+            "{}lane_keep_assist_button_state={}",
+            sep, self.lane_keep_assist_button_state
         )?;
-        writeln!(
+        write!(
             f,
-            " front_seat_massage_adjustment={}",
-            self.front_seat_massage_adjustment
+            "{}front_seat_massage_adjustment={}",
+            sep, self.front_seat_massage_adjustment
         )?;
-        writeln!(
+        write!(
             f,
-            " fragrance_diffuser_perfume_selection={}",
-            self.fragrance_diffuser_perfume_selection
+            "{}fragrance_diffuser_perfume_selection={}",
+            sep, self.fragrance_diffuser_perfume_selection
         )?;
-        writeln!(
+        write!(
             f,
-            " fragrance_diffuser_intensity={}",
-            self.fragrance_diffuser_intensity
+            "{}fragrance_diffuser_intensity={}",
+            sep, self.fragrance_diffuser_intensity
         )?;
-        writeln!(
+        write!(
             f,
-            " fragrance_diffuser_request={}",
-            self.fragrance_diffuser_request
+            "{}fragrance_diffuser_request={}",
+            sep, self.fragrance_diffuser_request
         )?;
-        writeln!(
+        write!(
             f,
-            " massage_activation_request={}",
-            self.massage_activation_request
+            "{}massage_activation_request={}",
+            sep, self.massage_activation_request
         )?;
-        writeln!(f, " massage_intensity={}", self.massage_intensity)?;
-        writeln!(f, " massage_type={}", self.massage_type)?;
-        writeln!(f, " asr_inhibit={}", self.asr_inhibit)?;
-        writeln!(
+        write!(f, "{}massage_intensity={}", sep, self.massage_intensity)?;
+        write!(f, "{}massage_type={}", sep, self.massage_type)?;
+        write!(f, "{}asr_inhibit={}", sep, self.asr_inhibit)?;
+        write!(
             f,
-            " consumption_history_zoomed_value={}",
-            self.consumption_history_zoomed_value
+            "{}consumption_history_zoomed_value={}",
+            sep, self.consumption_history_zoomed_value
         )?;
-        writeln!(
+        write!(
             f,
-            " dynamic_mode_selected_mode={}",
-            self.dynamic_mode_selected_mode
+            "{}dynamic_mode_selected_mode={}",
+            sep, self.dynamic_mode_selected_mode
         )
     }
 }
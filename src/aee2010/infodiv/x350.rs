@@ -1,6 +1,8 @@
 use core::{cmp::Ordering, fmt, time::Duration};
 
 use crate::{
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
     vehicle::{
         ACAirDistributionPosition, ACAirIntakeMode, ACAirTemperature, ACFanMode2010, ACFanSpeed,
         ACModeRequest,
@@ -81,6 +83,8 @@ pub const FRAME_LEN: usize = field::AC_7 + 1;
 
 /// Periodicity of a x350 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x350 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -390,10 +394,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x350 ({})", err)?;
                 Ok(())
@@ -408,9 +412,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x350 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub front_ac_fan_mode: ACFanMode2010,
     pub ac_request: ACModeRequest,
@@ -434,7 +444,12 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
-        Ok(Repr {
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+        Repr {
             front_ac_fan_mode: frame.front_ac_fan_mode(),
             ac_request: frame.ac_request(),
             front_left_temperature: frame.front_left_temp(),
@@ -451,7 +466,7 @@ impl Repr {
             front_left_seat_heating: frame.front_left_seat_heating(),
             front_right_seat_heating: frame.front_right_seat_heating(),
             energy_saver_mode_enabled: frame.energy_saver_mode_enable(),
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -480,56 +495,192 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x09, 0x00, 0x00, 0x94, 0x14, 0x25, 0x32, 0xc8],
+    with_front_ac_fan_mode => front_ac_fan_mode: ACFanMode2010,
+    with_ac_request => ac_request: ACModeRequest,
+    with_front_left_temperature => front_left_temperature: ACAirTemperature,
+    with_mono_temperature => mono_temperature: bool,
+    with_ac_max => ac_max: bool,
+    with_front_right_temperature => front_right_temperature: ACAirTemperature,
+    with_front_left_seat_ventilation => front_left_seat_ventilation: u8,
+    with_front_fan_speed => front_fan_speed: ACFanSpeed,
+    with_air_intake_mode => air_intake_mode: ACAirIntakeMode,
+    with_air_quality_enabled => air_quality_enabled: bool,
+    with_front_right_distribution_position => front_right_distribution_position: ACAirDistributionPosition,
+    with_front_left_distribution_position => front_left_distribution_position: ACAirDistributionPosition,
+    with_front_right_seat_ventilation => front_right_seat_ventilation: u8,
+    with_front_left_seat_heating => front_left_seat_heating: u8,
+    with_front_right_seat_heating => front_right_seat_heating: u8,
+    with_energy_saver_mode_enabled => energy_saver_mode_enabled: bool,
+}
+
+/// Signals of a x350 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("front_ac_fan_mode", 0, 1, 2).values(&[
+        (0, "AutoSoft"),
+        (1, "AutoComfort"),
+        (2, "AutoDemist"),
+        (3, "Manual"),
+    ]),
+    Signal::new("ac_request", 0, 3, 2).values(&[(0, "AutoComfort"), (1, "AutoDemist"), (2, "Off")]),
+    Signal::new("front_left_temperature", 3, 4, 5).values(&[
+        (0, "LO"),
+        (1, "Fourteen"),
+        (2, "Fifteen"),
+        (3, "Sixteen"),
+        (4, "Seventeen"),
+        (5, "Eighteen"),
+        (6, "EighteenDotFive"),
+        (7, "Nineteen"),
+        (8, "NineteenDotFive"),
+        (9, "Twenty"),
+        (10, "TwentyDotFive"),
+        (11, "TwentyOne"),
+        (12, "TwentyOneDotFive"),
+        (13, "TwentyTwo"),
+        (14, "TwentyTwoDotFive"),
+        (15, "TwentyThree"),
+        (16, "TwentyThreeDotFive"),
+        (17, "TwentyFour"),
+        (18, "TwentyFive"),
+        (19, "TwentySix"),
+        (20, "TwentySeven"),
+        (21, "TwentyEight"),
+        (22, "HI"),
+    ]),
+    Signal::new("mono_temperature", 3, 6, 1),
+    Signal::new("ac_max", 3, 7, 1),
+    Signal::new("front_right_temperature", 4, 4, 5).values(&[
+        (0, "LO"),
+        (1, "Fourteen"),
+        (2, "Fifteen"),
+        (3, "Sixteen"),
+        (4, "Seventeen"),
+        (5, "Eighteen"),
+        (6, "EighteenDotFive"),
+        (7, "Nineteen"),
+        (8, "NineteenDotFive"),
+        (9, "Twenty"),
+        (10, "TwentyDotFive"),
+        (11, "TwentyOne"),
+        (12, "TwentyOneDotFive"),
+        (13, "TwentyTwo"),
+        (14, "TwentyTwoDotFive"),
+        (15, "TwentyThree"),
+        (16, "TwentyThreeDotFive"),
+        (17, "TwentyFour"),
+        (18, "TwentyFive"),
+        (19, "TwentySix"),
+        (20, "TwentySeven"),
+        (21, "TwentyEight"),
+        (22, "HI"),
+    ]),
+    Signal::new("front_left_seat_ventilation", 4, 6, 2),
+    Signal::new("front_fan_speed", 5, 3, 4).values(&[
+        (0, "Speed1"),
+        (1, "Speed2"),
+        (2, "Speed3"),
+        (3, "Speed4"),
+        (4, "Speed5"),
+        (5, "Speed6"),
+        (6, "Speed7"),
+        (7, "Speed8"),
+        (15, "Speed0"),
+    ]),
+    Signal::new("air_intake_mode", 5, 6, 3).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "ForcedOpen"),
+        (3, "ForcedClose"),
+        (4, "AutoComfortWithoutAQS"),
+    ]),
+    Signal::new("air_quality_enabled", 5, 7, 1),
+    Signal::new("front_right_distribution_position", 6, 3, 4).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "Foot"),
+        (3, "Ventilation"),
+        (4, "Demist"),
+        (5, "FootVentilation"),
+        (6, "FootDemist"),
+        (7, "VentilationDemist"),
+        (8, "FootVentilationDemist"),
+        (9, "AutoSoft"),
+    ]),
+    Signal::new("front_left_distribution_position", 6, 7, 4).values(&[
+        (0, "AutoComfort"),
+        (1, "AutoDemist"),
+        (2, "Foot"),
+        (3, "Ventilation"),
+        (4, "Demist"),
+        (5, "FootVentilation"),
+        (6, "FootDemist"),
+        (7, "VentilationDemist"),
+        (8, "FootVentilationDemist"),
+        (9, "AutoSoft"),
+    ]),
+    Signal::new("front_right_seat_ventilation", 7, 2, 2),
+    Signal::new("front_left_seat_heating", 7, 4, 2),
+    Signal::new("front_right_seat_heating", 7, 6, 2),
+    Signal::new("energy_saver_mode_enabled", 7, 7, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x350")?;
-        writeln!(f, " front_ac_fan_mode={}", self.front_ac_fan_mode)?;
-        writeln!(f, " ac_request={}", self.ac_request)?;
-        writeln!(f, " front_left_temperature={}", self.front_left_temperature)?;
-        writeln!(f, " mono_temperature={}", self.mono_temperature)?;
-        writeln!(f, " ac_max={}", self.ac_max)?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x350")?;
+        write!(f, "{}front_ac_fan_mode={}", sep, self.front_ac_fan_mode)?;
+        write!(f, "{}ac_request={}", sep, self.ac_request)?;
+        write!(
             f,
-            " front_right_temperature={}",
-            self.front_right_temperature
+            "{}front_left_temperature={}",
+            sep, self.front_left_temperature
         )?;
-        writeln!(
+        write!(f, "{}mono_temperature={}", sep, self.mono_temperature)?;
+        write!(f, "{}ac_max={}", sep, self.ac_max)?;
+        write!(
             f,
-            " front_left_seat_ventilation={}",
-            self.front_left_seat_ventilation
+            "{}front_right_temperature={}",
+            sep, self.front_right_temperature
         )?;
-        writeln!(f, " front_fan_speed={}", self.front_fan_speed)?;
-        writeln!(f, " air_intake_mode={}", self.air_intake_mode)?;
-        writeln!(f, " air_quality_enabled={}", self.air_quality_enabled)?;
-        writeln!(
+        write!(
             f,
-            " front_right_distribution_position={}",
-            self.front_right_distribution_position
+            "{}front_left_seat_ventilation={}",
+            sep, self.front_left_seat_ventilation
         )?;
-        writeln!(
+        write!(f, "{}front_fan_speed={}", sep, self.front_fan_speed)?;
+        write!(f, "{}air_intake_mode={}", sep, self.air_intake_mode)?;
+        write!(f, "{}air_quality_enabled={}", sep, self.air_quality_enabled)?;
+        write!(
             f,
-            " front_left_distribution_position={}",
-            self.front_left_distribution_position
+            "{}front_right_distribution_position={}",
+            sep, self.front_right_distribution_position
         )?;
-        writeln!(
+        write!(
             f,
-            " front_right_seat_ventilation={}",
-            self.front_right_seat_ventilation
+            "{}front_left_distribution_position={}",
+            sep, self.front_left_distribution_position
         )?;
-        writeln!(
+        write!(
             f,
-            " front_left_seat_heating={}",
-            self.front_left_seat_heating
+            "{}front_right_seat_ventilation={}",
+            sep, self.front_right_seat_ventilation
         )?;
-        writeln!(
+        write!(
             f,
-            " front_right_seat_heating={}",
-            self.front_right_seat_heating
+            "{}front_left_seat_heating={}",
+            sep, self.front_left_seat_heating
         )?;
-        writeln!(
+        write!(
             f,
-            " energy_saver_mode_enabled={}",
-            self.energy_saver_mode_enabled
+            "{}front_right_seat_heating={}",
+            sep, self.front_right_seat_heating
+        )?;
+        write!(
+            f,
+            "{}energy_saver_mode_enabled={}",
+            sep, self.energy_saver_mode_enabled
         )
     }
 }
@@ -562,8 +713,8 @@ mod test {
     use super::{Frame, Repr};
     use crate::{
         vehicle::{
-            ACAirDistributionPosition, ACAirIntakeMode, ACAirTemperature, ACFanMode2010,
-            ACFanSpeed, ACModeRequest,
+            ACAirDistributionPosition, ACAirIntakeMode, ACAirTemperature, ACFanMode2004,
+            ACFanMode2010, ACFanSpeed, ACModeRequest,
         },
         Error,
     };
@@ -765,4 +916,39 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES_2);
     }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x1d0::Repr::builder()
+            .with_front_ac_fan_mode(ACFanMode2004::AutoSoft)
+            .with_ac_request(ACModeRequest::AutoDemist)
+            .with_front_left_temp(ACAirTemperature::Nineteen)
+            .with_front_right_temp(ACAirTemperature::TwentyOneDotFive)
+            .with_front_fan_speed(ACFanSpeed::Speed2)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2004);
+        assert_eq!(repr.front_ac_fan_mode, ACFanMode2010::AutoSoft);
+        assert_eq!(repr.ac_request, ACModeRequest::AutoDemist);
+        assert_eq!(repr.front_left_temperature, ACAirTemperature::Nineteen);
+        assert_eq!(
+            repr.front_right_temperature,
+            ACAirTemperature::TwentyOneDotFive
+        );
+        assert_eq!(repr.mono_temperature, false);
+        assert_eq!(repr.ac_max, false);
+        assert_eq!(repr.front_fan_speed, ACFanSpeed::Speed2);
+        assert_eq!(repr.air_intake_mode, repr_2004.air_intake_mode);
+        assert_eq!(repr.front_left_seat_heating, 0);
+
+        // Both architectures share the raw temperature and fan speed encodings.
+        let mut buf = [0u8; 8];
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        let mut buf_2004 = [0u8; 7];
+        repr_2004.emit(&mut crate::aee2004::conf::x1d0::Frame::new_unchecked(
+            &mut buf_2004,
+        ));
+        assert_eq!(buf[3] & 0x1f, buf_2004[5] & 0x1f);
+        assert_eq!(buf[5] & 0x0f, buf_2004[2] & 0x0f);
+    }
 }
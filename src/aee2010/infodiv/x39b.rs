@@ -1,7 +1,13 @@
 use core::{cmp::Ordering, fmt};
-use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+use time::{macros::datetime, Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
 
-use crate::{config::ClockFormat, Error, Result, YEAR_OFFSET};
+use crate::{
+    config::ClockFormat,
+    datetime::CanDateTime,
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result, YEAR_OFFSET,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -37,6 +43,9 @@ pub const FRAME_ID: u16 = 0x39b;
 /// Length of a x39b CAN frame.
 pub const FRAME_LEN: usize = field::MINUTE + 1;
 
+/// Transmission of a x39b CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::OnRequest;
+
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
     #[inline]
@@ -125,6 +134,18 @@ impl<T: AsRef<[u8]>> Frame<T> {
         let data = self.buffer.as_ref();
         data[field::MINUTE] & 0x3f
     }
+
+    /// Return the date and time fields.
+    #[inline]
+    pub fn datetime(&self) -> CanDateTime {
+        CanDateTime {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            hour: self.hour(),
+            minute: self.minute(),
+        }
+    }
 }
 
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
@@ -181,12 +202,22 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
         let raw = raw | value & 0x3f;
         data[field::MINUTE] = raw;
     }
+
+    /// Set the date and time fields.
+    #[inline]
+    pub fn set_datetime(&mut self, value: CanDateTime) {
+        self.set_year(value.year);
+        self.set_month(value.month);
+        self.set_day(value.day);
+        self.set_hour(value.hour);
+        self.set_minute(value.minute);
+    }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x39b ({})", err)?;
                 Ok(())
@@ -201,11 +232,21 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x39b CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub clock_format: ClockFormat,
+    #[cfg_attr(
+        all(feature = "defmt", not(feature = "defmt-compact")),
+        defmt(Debug2Format)
+    )]
     pub utc_datetime: OffsetDateTime,
 }
 
@@ -247,17 +288,39 @@ impl Repr {
     }
 }
 
+repr_builder! {
+    idle: [0x96, 0x01, 0x0a, 0x0f, 0x1d],
+    parked: [0x00, 0x01, 0x01, 0x00, 0x00] => Repr {
+        clock_format: ClockFormat::H12,
+        utc_datetime: datetime!(2000-01-01 0:00 UTC),
+    },
+    with_clock_format => clock_format: ClockFormat,
+    with_utc_datetime => utc_datetime: OffsetDateTime as Debug2Format,
+}
+
+/// Signals of a x39b CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("clock_format", 0, 7, 1).values(&[(0, "H12"), (1, "H24")]),
+    Signal::new("utc_datetime_year", 0, 6, 7).scaled(1.0, 2000.0),
+    Signal::new("utc_datetime_month", 1, 3, 4),
+    Signal::new("utc_datetime_day", 2, 5, 6),
+    Signal::new("utc_datetime_hour", 3, 4, 5).unit("h"),
+    Signal::new("utc_datetime_minute", 4, 5, 6).unit("min"),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x39b utc_datetime={}", self.utc_datetime)?;
-        writeln!(f, " clock_format={}", self.clock_format)
+        let sep = field_separator(f);
+        write!(f, "x39b")?;
+        write!(f, "{}utc_datetime={}", sep, self.utc_datetime)?;
+        write!(f, "{}clock_format={}", sep, self.clock_format)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::{Frame, Repr};
-    use crate::{config::ClockFormat, Error};
+    use crate::{config::ClockFormat, datetime::CanDateTime, Error};
 
     use time::macros::datetime;
 
@@ -280,6 +343,10 @@ mod test {
         assert_eq!(frame.day(), 0x0a);
         assert_eq!(frame.hour(), 0x0f);
         assert_eq!(frame.minute(), 0x1d);
+        assert_eq!(
+            frame.datetime(),
+            CanDateTime::new(0x16, 0x01, 0x0a, 0x0f, 0x1d).unwrap()
+        );
     }
 
     #[test]
@@ -2,7 +2,11 @@ use core::{cmp::Ordering, fmt, time::Duration};
 
 use heapless::String;
 
-use crate::{Error, Result};
+use crate::{
+    frame::{field_separator, DangerToken, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -42,6 +46,8 @@ pub const FRAME_LEN: usize = field::VDS_6 + 1;
 
 /// Periodicity of a x3b6 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(1000);
+/// Transmission of a x3b6 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -135,51 +141,51 @@ impl<T: AsRef<[u8]>> Frame<T> {
 impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     /// Set the VDS first char.
     #[inline]
-    pub fn set_vds_first_char(&mut self, value: char) {
+    pub fn set_vds_first_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VDS_1] = value as u8;
     }
 
     /// Set the VDS second char.
     #[inline]
-    pub fn set_vds_second_char(&mut self, value: char) {
+    pub fn set_vds_second_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VDS_2] = value as u8;
     }
 
     /// Set the VDS third char.
     #[inline]
-    pub fn set_vds_third_char(&mut self, value: char) {
+    pub fn set_vds_third_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VDS_3] = value as u8;
     }
 
     /// Set the VDS fourth char.
     #[inline]
-    pub fn set_vds_fourth_char(&mut self, value: char) {
+    pub fn set_vds_fourth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VDS_4] = value as u8;
     }
 
     /// Set the VDS fifth char.
     #[inline]
-    pub fn set_vds_fifth_char(&mut self, value: char) {
+    pub fn set_vds_fifth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VDS_5] = value as u8;
     }
 
     /// Set the VDS sixth char.
     #[inline]
-    pub fn set_vds_sixth_char(&mut self, value: char) {
+    pub fn set_vds_sixth_char(&mut self, value: char, _token: DangerToken) {
         let data = self.buffer.as_mut();
         data[field::VDS_6] = value as u8;
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x3b6 ({})", err)?;
                 Ok(())
@@ -194,9 +200,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!(record);
+
 /// A high-level representation of a x3b6 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub vds: String<6>,
 }
@@ -205,21 +217,30 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
+        // A non-ASCII char is encoded on several bytes, so it does not fit in the string.
+        if !frame.as_ref().is_ascii() {
+            return Err(Error::Invalid);
+        }
+
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked and whose chars are ASCII.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
         let mut vds: String<6> = String::new();
-        vds.push(frame.vds_first_char())
-            .map_err(|_| Error::Invalid)?;
-        vds.push(frame.vds_second_char())
-            .map_err(|_| Error::Invalid)?;
-        vds.push(frame.vds_third_char())
-            .map_err(|_| Error::Invalid)?;
-        vds.push(frame.vds_fourth_char())
-            .map_err(|_| Error::Invalid)?;
-        vds.push(frame.vds_fifth_char())
-            .map_err(|_| Error::Invalid)?;
-        vds.push(frame.vds_sixth_char())
-            .map_err(|_| Error::Invalid)?;
-
-        Ok(Repr { vds })
+        for c in [
+            frame.vds_first_char(),
+            frame.vds_second_char(),
+            frame.vds_third_char(),
+            frame.vds_fourth_char(),
+            frame.vds_fifth_char(),
+            frame.vds_sixth_char(),
+        ] {
+            // An ASCII char is encoded on a single byte, so it always fits.
+            let _ = vds.push(c);
+        }
+
+        Repr { vds }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -227,27 +248,55 @@ impl Repr {
         FRAME_LEN
     }
 
-    /// Emit a high-level representation into a x3b6 CAN frame.
-    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+    /// Emit a high-level representation into a x3b6 CAN frame, `token` acknowledging the
+    /// write of the VIN.
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>, token: DangerToken) {
         let mut vds = self.vds.clone();
-        frame.set_vds_sixth_char(vds.pop().unwrap_or(' '));
-        frame.set_vds_fifth_char(vds.pop().unwrap_or(' '));
-        frame.set_vds_fourth_char(vds.pop().unwrap_or(' '));
-        frame.set_vds_third_char(vds.pop().unwrap_or(' '));
-        frame.set_vds_second_char(vds.pop().unwrap_or(' '));
-        frame.set_vds_first_char(vds.pop().unwrap_or(' '));
+        frame.set_vds_sixth_char(vds.pop().unwrap_or(' '), token);
+        frame.set_vds_fifth_char(vds.pop().unwrap_or(' '), token);
+        frame.set_vds_fourth_char(vds.pop().unwrap_or(' '), token);
+        frame.set_vds_third_char(vds.pop().unwrap_or(' '), token);
+        frame.set_vds_second_char(vds.pop().unwrap_or(' '), token);
+        frame.set_vds_first_char(vds.pop().unwrap_or(' '), token);
     }
 }
 
+repr_builder! {
+    record,
+    idle: [0x53, 0x41, 0x39, 0x48, 0x52, 0x38],
+    parked: [0x30, 0x30, 0x30, 0x30, 0x30, 0x30],
+    with_vds(token) => vds: String<6>,
+}
+
+/// Signals of a x3b6 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("vds_1", 0, 7, 8),
+    Signal::new("vds_2", 1, 7, 8),
+    Signal::new("vds_3", 2, 7, 8),
+    Signal::new("vds_4", 3, 7, 8),
+    Signal::new("vds_5", 4, 7, 8),
+    Signal::new("vds_6", 5, 7, 8),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x3b6 vds={}", self.vds)
+        let sep = field_separator(f);
+        write!(f, "x3b6")?;
+        write!(f, "{}vds={}", sep, self.vds)
+    }
+}
+
+impl From<&crate::aee2004::conf::x3b6::Repr> for Repr {
+    fn from(repr_2004: &crate::aee2004::conf::x3b6::Repr) -> Self {
+        Repr {
+            vds: repr_2004.vds.clone(),
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Frame, Repr};
+    use super::{DangerToken, Frame, Repr};
     use heapless::String;
 
     use crate::Error;
@@ -277,12 +326,12 @@ mod test {
         let mut bytes = [0u8; 6];
         let mut frame = Frame::new_unchecked(&mut bytes);
 
-        frame.set_vds_first_char('S');
-        frame.set_vds_second_char('A');
-        frame.set_vds_third_char('9');
-        frame.set_vds_fourth_char('H');
-        frame.set_vds_fifth_char('R');
-        frame.set_vds_sixth_char('8');
+        frame.set_vds_first_char('S', DangerToken::unchecked());
+        frame.set_vds_second_char('A', DangerToken::unchecked());
+        frame.set_vds_third_char('9', DangerToken::unchecked());
+        frame.set_vds_fourth_char('H', DangerToken::unchecked());
+        frame.set_vds_fifth_char('R', DangerToken::unchecked());
+        frame.set_vds_sixth_char('8', DangerToken::unchecked());
 
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
@@ -314,7 +363,7 @@ mod test {
         let mut buf = [0u8; 6];
         let mut frame = Frame::new_unchecked(&mut buf);
         let repr = frame_repr();
-        repr.emit(&mut frame);
+        repr.emit(&mut frame, DangerToken::unchecked());
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
 }
@@ -2,7 +2,12 @@ use core::{cmp::Ordering, fmt, time::Duration};
 
 use byteorder::{ByteOrder, NetworkEndian};
 
-use crate::{config::DisplayMode, Error, Result};
+use crate::{
+    config::DisplayMode,
+    frame::{field_separator, TransmissionKind},
+    signal::Signal,
+    Error, Result,
+};
 
 /// A read/write wrapper around an CAN frame buffer.
 #[derive(Debug, PartialEq, Clone)]
@@ -45,6 +50,8 @@ pub const FRAME_LEN: usize = field::MAINTENANCE_DISTANCE.end;
 
 /// Periodicity of a x3e7 CAN frame.
 pub const PERIODICITY: Duration = Duration::from_millis(500);
+/// Transmission of a x3e7 CAN frame.
+pub const TRANSMISSION: TransmissionKind = TransmissionKind::Periodic(PERIODICITY);
 
 impl<T: AsRef<[u8]>> Frame<T> {
     /// Create a raw octet buffer with a CAN frame structure.
@@ -175,10 +182,10 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&'a T> {
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match Repr::parse(self) {
-            Ok(repr) => write!(f, "{}", repr),
+            Ok(repr) => fmt::Display::fmt(&repr, f),
             Err(err) => {
                 write!(f, "x3e7 ({})", err)?;
                 Ok(())
@@ -193,9 +200,15 @@ impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
     }
 }
 
+frame_padding!();
+frame_fixed!();
+
 /// A high-level representation of a x3e7 CAN frame.
 #[derive(Debug, PartialEq, Eq, Clone)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    all(feature = "defmt", not(feature = "defmt-compact")),
+    derive(defmt::Format)
+)]
 pub struct Repr {
     pub distance_counter_display_mode: DisplayMode,
     pub maintenance_wrench_display_mode: DisplayMode,
@@ -207,6 +220,11 @@ impl Repr {
     pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Result<Repr> {
         frame.check_len()?;
 
+        Ok(Repr::decode(frame))
+    }
+
+    /// Decode a frame whose length is checked.
+    fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
         let remaining_days = frame.remaining_days().into();
         let mut remaining_distance: i32 = frame.remaining_distance().into();
 
@@ -214,12 +232,12 @@ impl Repr {
             remaining_distance = -remaining_distance;
         }
 
-        Ok(Repr {
+        Repr {
             distance_counter_display_mode: frame.distance_counter_display_mode(),
             maintenance_wrench_display_mode: frame.maintenance_wrench_display_mode(),
             remaining_days,
             remaining_distance: remaining_distance * 20,
-        })
+        }
     }
 
     /// Return the length of a frame that will be emitted from this high-level representation.
@@ -227,31 +245,57 @@ impl Repr {
         FRAME_LEN
     }
 
+    /// Return whether the maintenance is overdue, i.e. the remaining distance or days are
+    /// negative. x3e7 does not carry the sign of the remaining days counter, which is only
+    /// negative when converted from the AEE2004 x3a7.
+    pub fn overdue(&self) -> bool {
+        self.remaining_distance < 0 || self.remaining_days < 0
+    }
+
     /// Emit a high-level representation into a x3e7 CAN frame.
     pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
         frame.set_distance_counter_negative(self.remaining_distance < 0);
         frame.set_distance_counter_display_mode(self.distance_counter_display_mode);
         frame.set_maintenance_wrench_display_mode(self.maintenance_wrench_display_mode);
-        frame.set_remaining_days(self.remaining_days as u16);
-        frame.set_remaining_distance((self.remaining_distance / 20) as u16);
+        frame.set_remaining_days(self.remaining_days.clamp(0, 0x7ff) as u16);
+        frame.set_remaining_distance((self.remaining_distance.unsigned_abs() / 20) as u16);
     }
 }
 
+repr_builder! {
+    idle: [0x00, 0x00, 0x50, 0x00, 0x01],
+    with_distance_counter_display_mode => distance_counter_display_mode: DisplayMode,
+    with_maintenance_wrench_display_mode => maintenance_wrench_display_mode: DisplayMode,
+    with_remaining_days => remaining_days: i32,
+    with_remaining_distance => remaining_distance: i32,
+}
+
+/// Signals of a x3e7 CAN frame.
+pub const SIGNALS: &[Signal] = &[
+    Signal::new("distance_counter_display_mode", 0, 4, 1).values(&[(0, "Steady"), (1, "Blinking")]),
+    Signal::new("maintenance_wrench_display_mode", 0, 5, 1)
+        .values(&[(0, "Steady"), (1, "Blinking")]),
+    Signal::new("remaining_days", 1, 5, 11).unit("d"),
+    Signal::new("remaining_distance", 3, 7, 16).unit("km"),
+    Signal::new("remaining_distance_negative", 0, 3, 1),
+];
+
 impl fmt::Display for Repr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "x3e7")?;
-        writeln!(
+        let sep = field_separator(f);
+        write!(f, "x3e7")?;
+        write!(
             f,
-            " distance_counter_display_mode={}",
-            self.distance_counter_display_mode
+            "{}distance_counter_display_mode={}",
+            sep, self.distance_counter_display_mode
         )?;
-        writeln!(
+        write!(
             f,
-            " maintenance_wrench_display_mode={}",
-            self.maintenance_wrench_display_mode
+            "{}maintenance_wrench_display_mode={}",
+            sep, self.maintenance_wrench_display_mode
         )?;
-        writeln!(f, " remaining_days={}", self.remaining_days)?;
-        writeln!(f, " remaining_distance={}", self.remaining_distance)
+        write!(f, "{}remaining_days={}", sep, self.remaining_days)?;
+        write!(f, "{}remaining_distance={}", sep, self.remaining_distance)
     }
 }
 
@@ -278,7 +322,10 @@ impl From<&crate::aee2004::conf::x3a7::Repr> for Repr {
 mod test {
     use super::{Frame, Repr};
 
-    use crate::{config::DisplayMode, Error};
+    use crate::{
+        config::{DisplayMode, MaintenanceDisplayMode},
+        Error,
+    };
 
     static REPR_FRAME_BYTES: [u8; 5] = [0x00, 0x00, 0x50, 0x00, 0x01];
 
@@ -346,4 +393,56 @@ mod test {
         repr.emit(&mut frame);
         assert_eq!(frame.into_inner(), &REPR_FRAME_BYTES);
     }
+
+    #[test]
+    fn test_overdue() {
+        let repr = Repr {
+            remaining_days: 0,
+            remaining_distance: -1500,
+            ..frame_repr()
+        };
+        assert!(repr.overdue());
+        assert!(!frame_repr().overdue());
+
+        let mut buf = [0u8; 5];
+        let mut frame = Frame::new_unchecked(&mut buf);
+        repr.emit(&mut frame);
+        assert_eq!(frame.distance_counter_negative(), true);
+        assert_eq!(frame.remaining_distance(), 75);
+        assert_eq!(Repr::parse(&Frame::new_unchecked(&buf)).unwrap(), repr);
+    }
+
+    #[test]
+    fn test_overdue_days() {
+        let repr = Repr {
+            remaining_days: -12,
+            remaining_distance: 1500,
+            ..frame_repr()
+        };
+        assert!(repr.overdue());
+        assert!(!Repr {
+            remaining_days: 0,
+            ..repr
+        }
+        .overdue());
+    }
+
+    #[test]
+    fn test_from_aee2004() {
+        let repr_2004 = crate::aee2004::conf::x3a7::Repr::builder()
+            .with_wrench_maintenance_display_mode(MaintenanceDisplayMode::Blinking)
+            .with_remaining_distance(-1500)
+            .with_remaining_days(-12)
+            .build()
+            .unwrap();
+        let repr = Repr::from(&repr_2004);
+        assert_eq!(repr.maintenance_wrench_display_mode, DisplayMode::Blinking);
+        assert_eq!(repr.remaining_distance, -1500);
+        assert!(repr.overdue());
+
+        let mut buf = [0u8; 5];
+        let mut frame = Frame::new_unchecked(&mut buf);
+        repr.emit(&mut frame);
+        assert_eq!(frame.remaining_days(), 0);
+    }
 }
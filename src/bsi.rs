@@ -0,0 +1,271 @@
+//! Built-in systems interface (BSI) simulator.
+//!
+//! The [BsiSimulator] owns the high-level representations of the periodic frames a BSI
+//! broadcasts on the AEE2010 infodiv bus and emits them at their declared `PERIODICITY`,
+//! which makes bench testing of clusters, head units and telematics units possible without
+//! a vehicle. Event-driven frames like x128 or x39b are not scheduled by the simulator.
+//!
+//! The `now` arguments are times of the caller's monotonic time base, as described in
+//! [next_deadline](crate::frame::next_deadline).
+//!
+//! The odometer and VIN frames listed in [RECORD_FRAMES] are only emitted once their writes
+//! are acknowledged with a [DangerToken] through [BsiSimulator::acknowledge_record_writes].
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::bsi::BsiSimulator;
+//! let mut bsi = BsiSimulator::new(Duration::ZERO);
+//! let mut buffer = [0u8; 8];
+//! // No frame is emitted until its representation is set.
+//! assert_eq!(bsi.poll(Duration::from_millis(100), &mut buffer), Ok(None));
+//! ```
+
+use core::time::Duration;
+
+use crate::{
+    aee2010::infodiv::{x036, x0b6, x0f6, x221, x260, x261, x2a1, x2b6, x336, x361, x3b6},
+    frame::{next_deadline, DangerToken, PsaFrame},
+    gateway::RECORD_FRAMES,
+    Error, Result,
+};
+
+macro_rules! bsi_simulator {
+    ($($(#[$doc:meta])* $frame:ident),+ $(,)?) => {
+        /// Number of frames scheduled by the simulator.
+        pub const FRAME_COUNT: usize = [$($frame::FRAME_ID),+].len();
+
+        /// Identifier and periodicity of the frames scheduled by the simulator, in
+        /// emission priority order.
+        pub const FRAMES: [(u16, Duration); FRAME_COUNT] = [$(($frame::FRAME_ID, $frame::PERIODICITY)),+];
+
+        /// A BSI simulator emitting periodic AEE2010 infodiv frames.
+        ///
+        /// Frames whose representation is `None` are not emitted, and neither are the
+        /// [RECORD_FRAMES] until their writes are acknowledged.
+        #[derive(Debug, Clone)]
+        pub struct BsiSimulator {
+            $($(#[$doc])* pub $frame: Option<$frame::Repr>,)+
+            deadlines: [Duration; FRAME_COUNT],
+            record_writes: Option<DangerToken>,
+        }
+
+        impl BsiSimulator {
+            /// Create a simulator with no frame representation, every frame being due at `now`.
+            pub fn new(now: Duration) -> BsiSimulator {
+                BsiSimulator {
+                    $($frame: None,)+
+                    deadlines: [now; FRAME_COUNT],
+                    record_writes: None,
+                }
+            }
+
+            /// Return whether the frame at `index` of [FRAMES] is emitted: its representation
+            /// is set, and the writes of the [RECORD_FRAMES] are acknowledged.
+            fn is_set(&self, index: usize) -> bool {
+                let set = [$(self.$frame.is_some()),+].get(index).copied().unwrap_or(false);
+                set && (self.record_writes.is_some() || !RECORD_FRAMES.contains(&FRAMES[index].0))
+            }
+
+            /// Emit the frame at `index` of [FRAMES] into `buffer`, if its representation is set.
+            fn emit(&self, index: usize, buffer: &mut [u8]) -> Result<Option<usize>> {
+                let mut i = 0;
+                $(
+                    if index == i {
+                        return match &self.$frame {
+                            Some(repr) if self.is_set(index) => {
+                                let len = repr.buffer_len();
+                                let buffer = buffer.get_mut(..len).ok_or(Error::Exhausted)?;
+                                match self.record_writes {
+                                    Some(token) => repr.emit_acknowledged(buffer, token)?,
+                                    None => PsaFrame::emit(repr, buffer)?,
+                                }
+                                Ok(Some(len))
+                            }
+                            _ => Ok(None),
+                        };
+                    }
+                    i += 1;
+                )+
+                let _ = i;
+                Ok(None)
+            }
+        }
+    };
+}
+
+bsi_simulator! {
+    /// Commands from BSI frame.
+    x036,
+    /// Fast BSI data frame.
+    x0b6,
+    /// Slow BSI data frame.
+    x0f6,
+    /// General on-board computer data frame.
+    x221,
+    /// Personalization profiles frame.
+    x260,
+    /// Trip 2 on-board computer data frame.
+    x261,
+    /// Trip 1 on-board computer data frame.
+    x2a1,
+    /// VIN VIS part frame.
+    x2b6,
+    /// VIN WMI part frame.
+    x336,
+    /// Vehicle configuration frame.
+    x361,
+    /// VIN VDS part frame.
+    x3b6,
+}
+
+impl BsiSimulator {
+    /// Emit the [RECORD_FRAMES] like the other frames.
+    pub fn acknowledge_record_writes(&mut self, token: DangerToken) {
+        self.record_writes = Some(token);
+    }
+
+    /// Return whether the simulator emits the [RECORD_FRAMES].
+    pub fn record_writes(&self) -> bool {
+        self.record_writes.is_some()
+    }
+
+    /// Return the earliest time at which a frame is due, if any frame representation is set.
+    pub fn poll_at(&self) -> Option<Duration> {
+        (0..FRAME_COUNT)
+            .filter(|&index| self.is_set(index))
+            .map(|index| self.deadlines[index])
+            .min()
+    }
+
+    /// Emit the next frame due at `now` into `buffer`.
+    ///
+    /// Returns the identifier and length of the emitted frame, or `None` if no frame is due.
+    /// Call it repeatedly until it returns `None` to emit every due frame. A frame late by more
+    /// than its periodicity is rescheduled from `now` instead of being emitted in bursts.
+    ///
+    /// Returns `Err(Error::Exhausted)` if `buffer` is too small for the due frame.
+    pub fn poll(&mut self, now: Duration, buffer: &mut [u8]) -> Result<Option<(u16, usize)>> {
+        for (index, &(id, periodicity)) in FRAMES.iter().enumerate() {
+            let deadline = self.deadlines[index];
+            if deadline > now {
+                continue;
+            }
+
+            if let Some(len) = self.emit(index, buffer)? {
+                self.deadlines[index] = next_deadline(deadline, periodicity, now);
+                return Ok(Some((id, len)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Make every frame due at `now`, e.g. after a bus wake-up.
+    pub fn reset(&mut self, now: Duration) {
+        self.deadlines = [now; FRAME_COUNT];
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::BsiSimulator;
+
+    use crate::{
+        aee2010::infodiv::{x036, x0b6, x0f6},
+        frame::DangerToken,
+    };
+
+    fn simulator() -> BsiSimulator {
+        let x036_bytes: [u8; 8] = [0x51, 0xff, 0x88, 0xc8, 0xa1, 0xb0, 0x0a, 0xa2];
+        let x0b6_bytes: [u8; 8] = [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0];
+        let x0f6_bytes: [u8; 8] = [0x80, 0x3c, 0x12, 0x99, 0x36, 0x9b, 0x9b, 0x20];
+
+        let mut bsi = BsiSimulator::new(Duration::ZERO);
+        bsi.x036 = x036::Repr::parse(&x036::Frame::new_unchecked(&x036_bytes)).ok();
+        bsi.x0b6 = x0b6::Repr::parse(&x0b6::Frame::new_unchecked(&x0b6_bytes)).ok();
+        bsi.x0f6 = x0f6::Repr::parse(&x0f6::Frame::new_unchecked(&x0f6_bytes)).ok();
+        bsi.acknowledge_record_writes(DangerToken::unchecked());
+        bsi
+    }
+
+    fn poll_all(bsi: &mut BsiSimulator, now: Duration) -> heapless::Vec<u16, 16> {
+        let mut buffer = [0u8; 8];
+        let mut ids = heapless::Vec::new();
+        while let Some((id, len)) = bsi.poll(now, &mut buffer).unwrap() {
+            assert_eq!(len, 8);
+            ids.push(id).unwrap();
+        }
+        ids
+    }
+
+    #[test]
+    fn test_schedule() {
+        let mut bsi = simulator();
+        assert!(bsi.x036.is_some() && bsi.x0b6.is_some() && bsi.x0f6.is_some());
+        assert_eq!(bsi.poll_at(), Some(Duration::ZERO));
+
+        assert_eq!(poll_all(&mut bsi, Duration::ZERO), [0x036, 0x0b6, 0x0f6]);
+        assert_eq!(bsi.poll_at(), Some(Duration::from_millis(50)));
+        assert_eq!(poll_all(&mut bsi, Duration::from_millis(49)), []);
+        assert_eq!(poll_all(&mut bsi, Duration::from_millis(50)), [0x0b6]);
+        assert_eq!(
+            poll_all(&mut bsi, Duration::from_millis(100)),
+            [0x036, 0x0b6]
+        );
+        assert_eq!(
+            poll_all(&mut bsi, Duration::from_millis(500)),
+            [0x036, 0x0b6, 0x0f6]
+        );
+        assert_eq!(bsi.poll_at(), Some(Duration::from_millis(550)));
+    }
+
+    #[test]
+    fn test_emit() {
+        let mut bsi = simulator();
+        let mut buffer = [0u8; 8];
+        let repr = bsi.x036.unwrap();
+
+        assert_eq!(
+            bsi.poll(Duration::ZERO, &mut buffer),
+            Ok(Some((x036::FRAME_ID, 8)))
+        );
+        assert_eq!(
+            x036::Repr::parse(&x036::Frame::new_unchecked(&buffer)),
+            Ok(repr)
+        );
+    }
+
+    #[test]
+    fn test_exhausted() {
+        let mut bsi = simulator();
+        let mut buffer = [0u8; 4];
+        assert_eq!(
+            bsi.poll(Duration::ZERO, &mut buffer),
+            Err(crate::Error::Exhausted)
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut bsi = BsiSimulator::new(Duration::ZERO);
+        assert_eq!(bsi.poll_at(), None);
+        assert_eq!(poll_all(&mut bsi, Duration::from_secs(1)), []);
+    }
+
+    #[test]
+    fn test_record_writes() {
+        let mut bsi = simulator();
+        bsi.record_writes = None;
+        assert!(!bsi.record_writes());
+        assert_eq!(poll_all(&mut bsi, Duration::ZERO), [0x036, 0x0b6]);
+        assert_eq!(bsi.poll_at(), Some(Duration::from_millis(50)));
+
+        bsi.acknowledge_record_writes(DangerToken::unchecked());
+        assert!(bsi.record_writes());
+        assert_eq!(
+            poll_all(&mut bsi, Duration::from_millis(50)),
+            [0x0b6, 0x0f6]
+        );
+    }
+}
@@ -0,0 +1,510 @@
+//! Climate control.
+//!
+//! The climate control unit broadcasts the front A/C settings in the AEE2004 x1d0 frame, or
+//! in the AEE2010 x350 frame along with the rear settings in x3d0. These frames encode the
+//! settings with enumerations like [ACAirTemperature] or [ACFanSpeed], whose raw values
+//! follow no particular order. [ClimateControl] keeps the settings of a climate control
+//! panel, adjusts them like the panel buttons do, and emits the frames of the selected
+//! architecture at their periodicity, a modified setting being emitted right away.
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2004::conf::x1d0, climate::*, vehicle::*, Architecture};
+//! let mut climate = ClimateControl::new(Architecture::Aee2004, Duration::ZERO);
+//! let mut buffer = [0u8; 8];
+//! assert_eq!(climate.poll(Duration::ZERO, &mut buffer), Ok(Some((0x1d0, 7))));
+//! assert_eq!(climate.poll(Duration::from_millis(100), &mut buffer), Ok(None));
+//!
+//! climate.set_temperature(ACAirTemperature::TwentyTwoDotFive);
+//! climate.fan_speed_up();
+//! assert_eq!(climate.poll(Duration::from_millis(100), &mut buffer), Ok(Some((0x1d0, 7))));
+//!
+//! let repr = x1d0::Repr::parse(&x1d0::Frame::new_unchecked(&buffer[..7])).unwrap();
+//! assert_eq!(repr.front_left_temp, ACAirTemperature::TwentyTwoDotFive);
+//! assert_eq!(repr.front_fan_speed, ACFanSpeed::Speed4);
+//! assert_eq!(repr.front_ac_fan_mode, ACFanMode2004::Manual);
+//! ```
+
+use core::time::Duration;
+
+use crate::{
+    aee2004::conf::x1d0,
+    aee2010::infodiv::{x350, x3d0},
+    frame::next_deadline,
+    vehicle::{
+        ACAirDistributionPosition, ACAirIntakeMode, ACAirTemperature, ACFanMode2004, ACFanSpeed,
+        ACModeRequest,
+    },
+    Architecture, Error, Result,
+};
+
+/// Identifier and periodicity of the AEE2004 climate control frames.
+pub const AEE2004_FRAMES: [(u16, Duration); 1] = [(x1d0::FRAME_ID, x1d0::PERIODICITY)];
+/// Identifier and periodicity of the AEE2010 climate control frames.
+pub const AEE2010_FRAMES: [(u16, Duration); 2] = [
+    (x350::FRAME_ID, x350::PERIODICITY),
+    (x3d0::FRAME_ID, x3d0::PERIODICITY),
+];
+
+/// Highest fan speed, see [ClimateControl::fan_speed_up].
+const MAX_FAN_SPEED: u8 = 8;
+
+/// Manual air distribution positions, in [ClimateControl::cycle_distribution] order.
+pub const DISTRIBUTION_CYCLE: [ACAirDistributionPosition; 7] = [
+    ACAirDistributionPosition::Ventilation,
+    ACAirDistributionPosition::FootVentilation,
+    ACAirDistributionPosition::Foot,
+    ACAirDistributionPosition::FootDemist,
+    ACAirDistributionPosition::Demist,
+    ACAirDistributionPosition::VentilationDemist,
+    ACAirDistributionPosition::FootVentilationDemist,
+];
+
+/// Return the identifier and periodicity of the climate control frames of `architecture`.
+fn frames(architecture: Architecture) -> &'static [(u16, Duration)] {
+    match architecture {
+        Architecture::Aee2004 => &AEE2004_FRAMES,
+        Architecture::Aee2010 => &AEE2010_FRAMES,
+    }
+}
+
+/// A climate control panel.
+#[derive(Debug, Clone)]
+pub struct ClimateControl {
+    architecture: Architecture,
+    mode: ACModeRequest,
+    manual_fan: bool,
+    fan_speed: ACFanSpeed,
+    distribution: ACAirDistributionPosition,
+    air_intake_mode: ACAirIntakeMode,
+    left_temperature: ACAirTemperature,
+    right_temperature: ACAirTemperature,
+    rear: x3d0::Repr,
+    deadlines: [Duration; 2],
+}
+
+impl ClimateControl {
+    /// Create a climate control in automatic comfort mode at 21°C, whose frames are all due
+    /// at `now`.
+    pub fn new(architecture: Architecture, now: Duration) -> ClimateControl {
+        ClimateControl {
+            architecture,
+            mode: ACModeRequest::AutoComfort,
+            manual_fan: false,
+            fan_speed: ACFanSpeed::Speed3,
+            distribution: ACAirDistributionPosition::AutoComfort,
+            air_intake_mode: ACAirIntakeMode::AutoComfort,
+            left_temperature: ACAirTemperature::TwentyOne,
+            right_temperature: ACAirTemperature::TwentyOne,
+            rear: x3d0::Repr {
+                rear_left_temp: 20,
+                rear_right_temp: 20,
+                rear_ac_state: 2,
+            },
+            deadlines: [now; 2],
+        }
+    }
+
+    /// Return the architecture the frames are emitted for.
+    pub fn architecture(&self) -> Architecture {
+        self.architecture
+    }
+
+    /// Return the A/C mode.
+    pub fn mode(&self) -> ACModeRequest {
+        self.mode
+    }
+
+    /// Set the A/C mode.
+    pub fn set_mode(&mut self, mode: ACModeRequest) {
+        self.mode = mode;
+        self.modified();
+    }
+
+    /// Return whether the fan speed and air distribution are automatic.
+    pub fn is_automatic(&self) -> bool {
+        !self.manual_fan && self.distribution == ACAirDistributionPosition::AutoComfort
+    }
+
+    /// Switch to automatic comfort mode, like the 'AUTO' button.
+    pub fn set_automatic(&mut self) {
+        self.mode = ACModeRequest::AutoComfort;
+        self.manual_fan = false;
+        self.distribution = ACAirDistributionPosition::AutoComfort;
+        self.air_intake_mode = ACAirIntakeMode::AutoComfort;
+        self.modified();
+    }
+
+    /// Return the front left and front right temperature settings.
+    pub fn temperature(&self) -> (ACAirTemperature, ACAirTemperature) {
+        (self.left_temperature, self.right_temperature)
+    }
+
+    /// Set both front temperatures to `temperature`.
+    pub fn set_temperature(&mut self, temperature: ACAirTemperature) {
+        self.set_dual_temperature(temperature, temperature);
+    }
+
+    /// Set the front left and front right temperatures.
+    pub fn set_dual_temperature(&mut self, left: ACAirTemperature, right: ACAirTemperature) {
+        self.left_temperature = left;
+        self.right_temperature = right;
+        self.modified();
+    }
+
+    /// Set both front temperatures to the setting nearest to `celsius` degrees Celsius,
+    /// see [ACAirTemperature::from_celsius].
+    #[cfg(feature = "float")]
+    pub fn set_temperature_celsius(&mut self, celsius: crate::physical::Physical) {
+        self.set_temperature(ACAirTemperature::from_celsius(celsius));
+    }
+
+    /// Return the fan speed.
+    pub fn fan_speed(&self) -> ACFanSpeed {
+        self.fan_speed
+    }
+
+    /// Set the fan speed, which switches the fan to manual mode.
+    pub fn set_fan_speed(&mut self, speed: ACFanSpeed) {
+        self.fan_speed = speed;
+        self.manual_fan = true;
+        self.modified();
+    }
+
+    /// Raise the fan speed by one step, up to [ACFanSpeed::Speed8], which switches the fan
+    /// to manual mode.
+    pub fn fan_speed_up(&mut self) {
+        let level = fan_level(self.fan_speed);
+        self.set_fan_speed(fan_speed(level.saturating_add(1).min(MAX_FAN_SPEED)));
+    }
+
+    /// Lower the fan speed by one step, down to [ACFanSpeed::Speed0], which switches the fan
+    /// to manual mode.
+    pub fn fan_speed_down(&mut self) {
+        let level = fan_level(self.fan_speed);
+        self.set_fan_speed(fan_speed(level.saturating_sub(1)));
+    }
+
+    /// Return the front air distribution position.
+    pub fn distribution(&self) -> ACAirDistributionPosition {
+        self.distribution
+    }
+
+    /// Set the front air distribution position.
+    pub fn set_distribution(&mut self, position: ACAirDistributionPosition) {
+        self.distribution = position;
+        self.modified();
+    }
+
+    /// Switch to the next manual air distribution position of [DISTRIBUTION_CYCLE], like the
+    /// distribution button. An automatic position switches to the first manual one.
+    pub fn cycle_distribution(&mut self) {
+        let next = DISTRIBUTION_CYCLE
+            .iter()
+            .position(|&position| position == self.distribution)
+            .map_or(0, |index| (index + 1) % DISTRIBUTION_CYCLE.len());
+        self.set_distribution(DISTRIBUTION_CYCLE[next]);
+    }
+
+    /// Return the air intake mode.
+    pub fn air_intake_mode(&self) -> ACAirIntakeMode {
+        self.air_intake_mode
+    }
+
+    /// Set the air intake mode.
+    pub fn set_air_intake_mode(&mut self, mode: ACAirIntakeMode) {
+        self.air_intake_mode = mode;
+        self.modified();
+    }
+
+    /// Return the rear settings, emitted on AEE2010 only.
+    pub fn rear(&self) -> &x3d0::Repr {
+        &self.rear
+    }
+
+    /// Set the rear settings, emitted on AEE2010 only. The encoding of the rear
+    /// temperatures is not known, they are emitted as is.
+    pub fn set_rear(&mut self, rear: x3d0::Repr) {
+        self.rear = rear;
+        self.modified();
+    }
+
+    /// Return the front settings as a x1d0 frame representation.
+    pub fn to_aee2004(&self) -> Result<x1d0::Repr> {
+        let fan_mode = match (self.manual_fan, self.mode) {
+            (true, _) => ACFanMode2004::Manual,
+            (false, ACModeRequest::AutoDemist) => ACFanMode2004::AutoDemist,
+            (false, _) => ACFanMode2004::AutoComfort,
+        };
+        x1d0::Repr::builder()
+            .with_ac_request(self.mode)
+            .with_ac_off(self.mode == ACModeRequest::Off)
+            .with_front_ac_fan_mode(fan_mode)
+            .with_front_fan_speed(self.fan_speed)
+            .with_front_left_distribution_position(self.distribution)
+            .with_front_right_distribution_position(self.distribution)
+            .with_air_intake_mode(self.air_intake_mode)
+            .with_front_left_temp(self.left_temperature)
+            .with_front_right_temp(self.right_temperature)
+            .build()
+    }
+
+    /// Return the front settings as a x350 frame representation.
+    pub fn to_aee2010(&self) -> Result<x350::Repr> {
+        self.to_aee2004().map(|repr| x350::Repr::from(&repr))
+    }
+
+    /// Emit the frame at `index` of the architecture frames into `buffer`, and return its
+    /// length.
+    fn emit(&self, index: usize, buffer: &mut [u8]) -> Result<usize> {
+        match (self.architecture, index) {
+            (Architecture::Aee2004, 0) => {
+                let repr = self.to_aee2004()?;
+                let buffer = buffer
+                    .get_mut(..repr.buffer_len())
+                    .ok_or(Error::Exhausted)?;
+                repr.emit(&mut x1d0::Frame::new_unchecked(&mut *buffer));
+                Ok(buffer.len())
+            }
+            (Architecture::Aee2010, 0) => {
+                let repr = self.to_aee2010()?;
+                let buffer = buffer
+                    .get_mut(..repr.buffer_len())
+                    .ok_or(Error::Exhausted)?;
+                repr.emit(&mut x350::Frame::new_unchecked(&mut *buffer));
+                Ok(buffer.len())
+            }
+            (Architecture::Aee2010, 1) => {
+                let buffer = buffer
+                    .get_mut(..self.rear.buffer_len())
+                    .ok_or(Error::Exhausted)?;
+                self.rear
+                    .emit(&mut x3d0::Frame::new_unchecked(&mut *buffer));
+                Ok(buffer.len())
+            }
+            _ => Err(Error::Illegal),
+        }
+    }
+
+    /// Return the time at which the next frame is due.
+    pub fn poll_at(&self) -> Duration {
+        let count = frames(self.architecture).len();
+        self.deadlines[..count]
+            .iter()
+            .copied()
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// Emit into `buffer` the first frame due at `now`, if any.
+    ///
+    /// Returns the identifier and length of the emitted frame, or `Err(Error::Exhausted)`
+    /// if `buffer` is too small for the due frame.
+    pub fn poll(&mut self, now: Duration, buffer: &mut [u8]) -> Result<Option<(u16, usize)>> {
+        for (index, &(id, periodicity)) in frames(self.architecture).iter().enumerate() {
+            let deadline = self.deadlines[index];
+            if deadline > now {
+                continue;
+            }
+
+            let len = self.emit(index, buffer)?;
+            self.deadlines[index] = next_deadline(deadline, periodicity, now);
+            return Ok(Some((id, len)));
+        }
+        Ok(None)
+    }
+
+    /// Make every frame due immediately after a modification.
+    fn modified(&mut self) {
+        self.deadlines = [Duration::ZERO; 2];
+    }
+}
+
+/// Return the fan speed level of `speed`, from 0 to [MAX_FAN_SPEED]. Unknown speeds are
+/// level 0.
+fn fan_level(speed: ACFanSpeed) -> u8 {
+    match u8::from(speed) {
+        raw @ 0..=7 => raw + 1,
+        _ => 0,
+    }
+}
+
+/// Return the fan speed of `level`, from 0 to [MAX_FAN_SPEED].
+fn fan_speed(level: u8) -> ACFanSpeed {
+    match level {
+        0 => ACFanSpeed::Speed0,
+        level => ACFanSpeed::from(level - 1),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{ClimateControl, DISTRIBUTION_CYCLE};
+
+    use crate::{
+        aee2010::infodiv::{x350, x3d0},
+        vehicle::{
+            ACAirDistributionPosition, ACAirTemperature, ACFanMode2004, ACFanMode2010, ACFanSpeed,
+            ACModeRequest,
+        },
+        Architecture, Error,
+    };
+
+    #[cfg(feature = "float")]
+    use crate::physical;
+
+    #[test]
+    fn test_temperature() {
+        let mut climate = ClimateControl::new(Architecture::Aee2004, Duration::ZERO);
+        climate.set_dual_temperature(ACAirTemperature::LO, ACAirTemperature::TwentyFive);
+        let repr = climate.to_aee2004().unwrap();
+        assert_eq!(repr.front_left_temp, ACAirTemperature::LO);
+        assert_eq!(repr.front_right_temp, ACAirTemperature::TwentyFive);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn test_celsius() {
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(137, 10)),
+            ACAirTemperature::LO
+        );
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(140, 10)),
+            ACAirTemperature::Fourteen
+        );
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(145, 10)),
+            ACAirTemperature::Fifteen
+        );
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(213, 10)),
+            ACAirTemperature::TwentyOneDotFive
+        );
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(245, 10)),
+            ACAirTemperature::TwentyFive
+        );
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(280, 10)),
+            ACAirTemperature::TwentyEight
+        );
+        assert_eq!(
+            ACAirTemperature::from_celsius(physical::decimal(283, 10)),
+            ACAirTemperature::HI
+        );
+        #[cfg(not(feature = "fixed"))]
+        assert_eq!(
+            ACAirTemperature::from_celsius(f32::NAN),
+            ACAirTemperature::LO
+        );
+
+        assert_eq!(
+            ACAirTemperature::TwentyDotFive.celsius(),
+            Some(physical::decimal(205, 10))
+        );
+        assert_eq!(ACAirTemperature::HI.celsius(), None);
+
+        let mut climate = ClimateControl::new(Architecture::Aee2004, Duration::ZERO);
+        climate.set_temperature_celsius(physical::decimal(194, 10));
+        assert_eq!(
+            climate.temperature(),
+            (
+                ACAirTemperature::NineteenDotFive,
+                ACAirTemperature::NineteenDotFive
+            )
+        );
+    }
+
+    #[test]
+    fn test_fan_speed() {
+        let mut climate = ClimateControl::new(Architecture::Aee2004, Duration::ZERO);
+        assert!(climate.is_automatic());
+        climate.fan_speed_down();
+        assert!(!climate.is_automatic());
+        assert_eq!(climate.fan_speed(), ACFanSpeed::Speed2);
+        assert_eq!(
+            climate.to_aee2004().unwrap().front_ac_fan_mode,
+            ACFanMode2004::Manual
+        );
+
+        for _ in 0..3 {
+            climate.fan_speed_down();
+        }
+        assert_eq!(climate.fan_speed(), ACFanSpeed::Speed0);
+        for _ in 0..10 {
+            climate.fan_speed_up();
+        }
+        assert_eq!(climate.fan_speed(), ACFanSpeed::Speed8);
+
+        climate.set_automatic();
+        assert!(climate.is_automatic());
+        assert_eq!(
+            climate.to_aee2004().unwrap().front_ac_fan_mode,
+            ACFanMode2004::AutoComfort
+        );
+    }
+
+    #[test]
+    fn test_distribution() {
+        let mut climate = ClimateControl::new(Architecture::Aee2004, Duration::ZERO);
+        climate.cycle_distribution();
+        assert_eq!(climate.distribution(), DISTRIBUTION_CYCLE[0]);
+        for _ in 0..DISTRIBUTION_CYCLE.len() - 1 {
+            climate.cycle_distribution();
+        }
+        assert_eq!(
+            climate.distribution(),
+            ACAirDistributionPosition::FootVentilationDemist
+        );
+        climate.cycle_distribution();
+        assert_eq!(
+            climate.distribution(),
+            ACAirDistributionPosition::Ventilation
+        );
+
+        let repr = climate.to_aee2004().unwrap();
+        assert_eq!(
+            repr.front_left_distribution_position,
+            ACAirDistributionPosition::Ventilation
+        );
+        assert_eq!(
+            repr.front_right_distribution_position,
+            ACAirDistributionPosition::Ventilation
+        );
+    }
+
+    #[test]
+    fn test_poll_aee2010() {
+        let mut climate = ClimateControl::new(Architecture::Aee2010, Duration::ZERO);
+        let mut buffer = [0u8; 8];
+        assert_eq!(
+            climate.poll(Duration::ZERO, &mut buffer),
+            Ok(Some((x350::FRAME_ID, x350::FRAME_LEN)))
+        );
+        assert_eq!(
+            climate.poll(Duration::ZERO, &mut buffer),
+            Ok(Some((x3d0::FRAME_ID, x3d0::FRAME_LEN)))
+        );
+        assert_eq!(climate.poll(Duration::ZERO, &mut buffer), Ok(None));
+        assert_eq!(climate.poll_at(), Duration::from_millis(500));
+
+        climate.set_mode(ACModeRequest::AutoDemist);
+        assert_eq!(climate.poll_at(), Duration::ZERO);
+        assert_eq!(
+            climate.poll(Duration::from_millis(200), &mut buffer),
+            Ok(Some((x350::FRAME_ID, x350::FRAME_LEN)))
+        );
+        let repr = x350::Repr::parse(&x350::Frame::new_unchecked(&buffer)).unwrap();
+        assert_eq!(repr.ac_request, ACModeRequest::AutoDemist);
+        assert_eq!(repr.front_ac_fan_mode, ACFanMode2010::AutoDemist);
+        assert_eq!(repr.front_left_temperature, ACAirTemperature::TwentyOne);
+
+        assert_eq!(
+            climate.poll(Duration::from_millis(200), &mut buffer[..4]),
+            Err(Error::Exhausted)
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Vehicle clock synchronization.
+//!
+//! The BSI owns the vehicle clock and broadcasts the current date and time every second, in
+//! the AEE2004 x376 frame or the AEE2010 x276 frame; AEE2004 vehicles also send the time of
+//! day in x228. Navigation and telematics units which know the actual time, e.g. from GPS,
+//! set the vehicle clock with a x39b date and time update request on AEE2010, or with a x228
+//! time of day on AEE2004, where the date cannot be set.
+//!
+//! [ClockSync] tracks the vehicle clock from these frames, and emits an update request when
+//! it drifts from the reference time by more than a tolerance:
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2010::infodiv::{x276, x39b}, clock::ClockSync, datetime::CanDateTime, Architecture};
+//! let mut sync = ClockSync::new(Architecture::Aee2010);
+//! let mut buffer = [0u8; 8];
+//! // The vehicle clock is set to 2022-01-10 15:29.
+//! sync.on_frame(x276::FRAME_ID, &x276::IDLE_FRAME_BYTES, Duration::ZERO).unwrap();
+//!
+//! let gps = CanDateTime::new(22, 1, 10, 15, 29).unwrap();
+//! assert_eq!(sync.sync(&gps, Duration::ZERO, &mut buffer), Ok(None));
+//!
+//! let gps = CanDateTime::new(22, 1, 10, 15, 42).unwrap();
+//! assert_eq!(sync.sync(&gps, Duration::ZERO, &mut buffer), Ok(Some((0x39b, 5))));
+//! assert_eq!(x39b::Frame::new_unchecked(&buffer[..5]).datetime(), gps);
+//! ```
+
+use core::time::Duration;
+
+use crate::{
+    aee2004::conf::{x228, x376},
+    aee2010::infodiv::{x276, x39b},
+    config::ClockFormat,
+    datetime::CanDateTime,
+    rx::STALE_PERIODS,
+    Architecture, Error, Result,
+};
+
+/// Number of minutes in a day.
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Default drift tolerated between the vehicle clock and the reference time.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(60);
+/// Time given to the BSI to apply an update request before another one is sent.
+pub const REQUEST_HOLDOFF: Duration = Duration::from_secs(5);
+/// Time after which the last received vehicle clock is not trusted anymore.
+pub const VEHICLE_CLOCK_VALIDITY: Duration =
+    Duration::from_secs(x276::PERIODICITY.as_secs() * STALE_PERIODS as u64);
+
+/// Vehicle clock synchronization with a reference time.
+#[derive(Debug, Clone)]
+pub struct ClockSync {
+    architecture: Architecture,
+    clock_format: ClockFormat,
+    tolerance: Duration,
+    /// Vehicle clock, in minutes since the CAN time origin on AEE2010, or since midnight on
+    /// AEE2004, and its reception time.
+    vehicle: Option<(u32, Duration)>,
+    last_request: Option<Duration>,
+}
+
+impl ClockSync {
+    /// Create a synchronization of the vehicle clock of `architecture` with a
+    /// [DEFAULT_TOLERANCE] and a 24-hour clock format.
+    pub fn new(architecture: Architecture) -> ClockSync {
+        ClockSync {
+            architecture,
+            clock_format: ClockFormat::H24,
+            tolerance: DEFAULT_TOLERANCE,
+            vehicle: None,
+            last_request: None,
+        }
+    }
+
+    /// Set the drift tolerated between the vehicle clock and the reference time, with a
+    /// minute resolution.
+    pub fn with_tolerance(mut self, tolerance: Duration) -> ClockSync {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the clock format of the AEE2010 update requests.
+    pub fn with_clock_format(mut self, clock_format: ClockFormat) -> ClockSync {
+        self.clock_format = clock_format;
+        self
+    }
+
+    /// Return the architecture of the synchronized vehicle.
+    pub fn architecture(&self) -> Architecture {
+        self.architecture
+    }
+
+    /// Process the payload of the CAN frame with identifier `id` received at `now`.
+    ///
+    /// Returns whether the frame carries the vehicle clock, or `Err(Error::Truncated)` if
+    /// the payload is too short and `Err(Error::Invalid)` if the vehicle clock is not set.
+    pub fn on_frame(&mut self, id: u16, payload: &[u8], now: Duration) -> Result<bool> {
+        let minutes = match (self.architecture, id) {
+            (Architecture::Aee2004, x376::FRAME_ID) => {
+                let datetime = x376::Frame::new_checked(payload)?.datetime();
+                datetime.check()?;
+                datetime.minute_of_day().into()
+            }
+            (Architecture::Aee2004, x228::FRAME_ID) => {
+                let frame = x228::Frame::new_checked(payload)?;
+                let (hour, minute) = (frame.hour(), frame.minute());
+                if hour > 23 || minute > 59 {
+                    return Err(Error::Invalid);
+                }
+                u32::from(hour) * 60 + u32::from(minute)
+            }
+            (Architecture::Aee2010, x276::FRAME_ID) => {
+                let datetime = x276::Frame::new_checked(payload)?.datetime();
+                datetime.check()?;
+                datetime.minutes()
+            }
+            _ => return Ok(false),
+        };
+        self.vehicle = Some((minutes, now));
+        Ok(true)
+    }
+
+    /// Return the drift of the vehicle clock from `reference` in minutes, positive when the
+    /// vehicle clock is ahead, or `None` if the vehicle clock has not been received within
+    /// [VEHICLE_CLOCK_VALIDITY] at `now`. On AEE2004, only the time of day is compared.
+    pub fn drift(&self, reference: &CanDateTime, now: Duration) -> Option<i64> {
+        let (vehicle, received) = self.vehicle?;
+        if now.saturating_sub(received) > VEHICLE_CLOCK_VALIDITY {
+            return None;
+        }
+        let drift = match self.architecture {
+            Architecture::Aee2004 => {
+                let day = i64::from(MINUTES_PER_DAY);
+                let drift = i64::from(vehicle) - i64::from(reference.minute_of_day());
+                // The shortest way around the clock.
+                (drift + day + day / 2) % day - day / 2
+            }
+            Architecture::Aee2010 => i64::from(vehicle) - i64::from(reference.minutes()),
+        };
+        Some(drift)
+    }
+
+    /// Emit into `buffer` the update request setting the vehicle clock to `reference`.
+    ///
+    /// Returns the identifier and length of the emitted frame, `Err(Error::Invalid)` if
+    /// `reference` is not a valid date and time, or `Err(Error::Exhausted)` if `buffer` is
+    /// too small.
+    pub fn emit_request(&self, reference: &CanDateTime, buffer: &mut [u8]) -> Result<(u16, usize)> {
+        reference.check()?;
+        match self.architecture {
+            Architecture::Aee2004 => {
+                let buffer = buffer.get_mut(..x228::FRAME_LEN).ok_or(Error::Exhausted)?;
+                let mut frame = x228::Frame::new_unchecked(&mut *buffer);
+                frame.set_hour(reference.hour);
+                frame.set_minute(reference.minute);
+                Ok((x228::FRAME_ID, buffer.len()))
+            }
+            Architecture::Aee2010 => {
+                let buffer = buffer.get_mut(..x39b::FRAME_LEN).ok_or(Error::Exhausted)?;
+                let mut frame = x39b::Frame::new_unchecked(&mut *buffer);
+                frame.set_clock_format(self.clock_format);
+                frame.set_datetime(*reference);
+                Ok((x39b::FRAME_ID, buffer.len()))
+            }
+        }
+    }
+
+    /// Compare the vehicle clock with `reference` at `now`, and emit an update request into
+    /// `buffer` if it drifts by more than the tolerance.
+    ///
+    /// No request is emitted while the vehicle clock is unknown or outdated, see
+    /// [ClockSync::drift], nor within [REQUEST_HOLDOFF] of the previous request.
+    ///
+    /// Returns the identifier and length of the emitted frame, `Err(Error::Invalid)` if
+    /// `reference` is not a valid date and time, or `Err(Error::Exhausted)` if `buffer` is
+    /// too small.
+    pub fn sync(
+        &mut self,
+        reference: &CanDateTime,
+        now: Duration,
+        buffer: &mut [u8],
+    ) -> Result<Option<(u16, usize)>> {
+        reference.check()?;
+        if matches!(self.last_request, Some(last) if now.saturating_sub(last) < REQUEST_HOLDOFF) {
+            return Ok(None);
+        }
+        let tolerance = i64::try_from(self.tolerance.as_secs() / 60).unwrap_or(i64::MAX);
+        match self.drift(reference, now) {
+            Some(drift) if drift.abs() > tolerance => {
+                let emitted = self.emit_request(reference, buffer)?;
+                self.last_request = Some(now);
+                Ok(Some(emitted))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{ClockSync, REQUEST_HOLDOFF, VEHICLE_CLOCK_VALIDITY};
+
+    use crate::{
+        aee2004::conf::{x228, x376},
+        aee2010::infodiv::{x276, x39b},
+        config::ClockFormat,
+        datetime::CanDateTime,
+        Architecture, Error,
+    };
+
+    fn vehicle_2010(datetime: CanDateTime) -> [u8; x276::FRAME_LEN] {
+        let mut payload = x276::IDLE_FRAME_BYTES;
+        x276::Frame::new_unchecked(&mut payload).set_datetime(datetime);
+        payload
+    }
+
+    #[test]
+    fn test_sync_aee2010() {
+        let mut sync = ClockSync::new(Architecture::Aee2010).with_clock_format(ClockFormat::H12);
+        let mut buffer = [0u8; 8];
+        let vehicle = CanDateTime::new(23, 12, 31, 23, 59).unwrap();
+        let gps = CanDateTime::new(24, 1, 1, 0, 1).unwrap();
+
+        // Unknown vehicle clock.
+        assert_eq!(sync.drift(&gps, Duration::ZERO), None);
+        assert_eq!(sync.sync(&gps, Duration::ZERO, &mut buffer), Ok(None));
+
+        assert_eq!(
+            sync.on_frame(x276::FRAME_ID, &vehicle_2010(vehicle), Duration::ZERO),
+            Ok(true)
+        );
+        assert_eq!(sync.drift(&gps, Duration::ZERO), Some(-2));
+        assert_eq!(
+            sync.sync(&gps, Duration::ZERO, &mut buffer),
+            Ok(Some((x39b::FRAME_ID, x39b::FRAME_LEN)))
+        );
+        let frame = x39b::Frame::new_unchecked(&buffer[..x39b::FRAME_LEN]);
+        assert_eq!(frame.datetime(), gps);
+        assert_eq!(frame.clock_format(), ClockFormat::H12);
+
+        // Held off, then outdated vehicle clock.
+        assert_eq!(
+            sync.sync(&gps, Duration::from_secs(1), &mut buffer),
+            Ok(None)
+        );
+        assert_eq!(sync.sync(&gps, REQUEST_HOLDOFF, &mut buffer), Ok(None));
+        assert!(REQUEST_HOLDOFF > VEHICLE_CLOCK_VALIDITY);
+
+        sync.on_frame(x276::FRAME_ID, &vehicle_2010(vehicle), REQUEST_HOLDOFF)
+            .unwrap();
+        assert_eq!(
+            sync.sync(&gps, REQUEST_HOLDOFF, &mut buffer[..4]),
+            Err(Error::Exhausted)
+        );
+        let invalid = CanDateTime { month: 0x0e, ..gps };
+        assert_eq!(
+            sync.sync(&invalid, REQUEST_HOLDOFF, &mut buffer),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_sync_aee2004() {
+        let mut sync =
+            ClockSync::new(Architecture::Aee2004).with_tolerance(Duration::from_secs(300));
+        let mut buffer = [0u8; 8];
+        let mut payload = x376::IDLE_FRAME_BYTES;
+        x376::Frame::new_unchecked(&mut payload)
+            .set_datetime(CanDateTime::new(22, 3, 1, 23, 58).unwrap());
+        assert_eq!(
+            sync.on_frame(x376::FRAME_ID, &payload, Duration::ZERO),
+            Ok(true)
+        );
+
+        // The date is ignored, and the drift wraps around midnight.
+        let gps = CanDateTime::new(23, 7, 14, 0, 2).unwrap();
+        assert_eq!(sync.drift(&gps, Duration::ZERO), Some(-4));
+        assert_eq!(sync.sync(&gps, Duration::ZERO, &mut buffer), Ok(None));
+
+        let gps = CanDateTime { minute: 4, ..gps };
+        assert_eq!(
+            sync.sync(&gps, Duration::ZERO, &mut buffer),
+            Ok(Some((x228::FRAME_ID, x228::FRAME_LEN)))
+        );
+        let frame = x228::Frame::new_unchecked(&buffer[..x228::FRAME_LEN]);
+        assert_eq!((frame.hour(), frame.minute()), (0, 4));
+
+        assert_eq!(
+            sync.on_frame(x228::FRAME_ID, &buffer[..x228::FRAME_LEN], Duration::ZERO),
+            Ok(true)
+        );
+        assert_eq!(sync.drift(&gps, Duration::ZERO), Some(0));
+    }
+
+    #[test]
+    fn test_on_frame() {
+        let mut sync = ClockSync::new(Architecture::Aee2004);
+        assert_eq!(
+            sync.on_frame(x276::FRAME_ID, &x276::IDLE_FRAME_BYTES, Duration::ZERO),
+            Ok(false)
+        );
+        assert_eq!(
+            sync.on_frame(x228::FRAME_ID, &[0x18, 0x00], Duration::ZERO),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            sync.on_frame(x376::FRAME_ID, &[0x00], Duration::ZERO),
+            Err(Error::Truncated)
+        );
+    }
+}
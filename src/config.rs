@@ -519,6 +519,136 @@ impl fmt::Display for UserProfile {
     }
 }
 
+/// Number of driver profiles a vehicle can store.
+pub const PROFILE_COUNT: usize = 3;
+
+/// Driver profile number, used as key for per-profile settings.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ProfileNumber {
+    /// Profile number one.
+    Profile1,
+    /// Profile number two.
+    Profile2,
+    /// Profile number three.
+    Profile3,
+}
+
+impl ProfileNumber {
+    /// All the profile numbers, in order.
+    pub const ALL: [ProfileNumber; PROFILE_COUNT] = [
+        ProfileNumber::Profile1,
+        ProfileNumber::Profile2,
+        ProfileNumber::Profile3,
+    ];
+
+    /// Return the zero-based index of the profile.
+    pub fn index(&self) -> usize {
+        match *self {
+            ProfileNumber::Profile1 => 0,
+            ProfileNumber::Profile2 => 1,
+            ProfileNumber::Profile3 => 2,
+        }
+    }
+
+    /// Return the profile at the zero-based `index`, if any.
+    pub fn from_index(index: usize) -> Option<ProfileNumber> {
+        ProfileNumber::ALL.get(index).copied()
+    }
+}
+
+impl TryFrom<UserProfile> for ProfileNumber {
+    type Error = crate::Error;
+
+    /// Convert a broadcast user profile into a profile number.
+    /// Returns `Err(Error::Invalid)` for the none, default and unknown profiles,
+    /// which do not hold driver settings.
+    fn try_from(profile: UserProfile) -> crate::Result<ProfileNumber> {
+        match profile {
+            UserProfile::Profile1 => Ok(ProfileNumber::Profile1),
+            UserProfile::Profile2 => Ok(ProfileNumber::Profile2),
+            UserProfile::Profile3 => Ok(ProfileNumber::Profile3),
+            _ => Err(crate::Error::Invalid),
+        }
+    }
+}
+
+impl From<ProfileNumber> for UserProfile {
+    fn from(profile: ProfileNumber) -> UserProfile {
+        match profile {
+            ProfileNumber::Profile1 => UserProfile::Profile1,
+            ProfileNumber::Profile2 => UserProfile::Profile2,
+            ProfileNumber::Profile3 => UserProfile::Profile3,
+        }
+    }
+}
+
+impl fmt::Display for ProfileNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "profile {}", self.index() + 1)
+    }
+}
+
+/// Settings storage keyed by driver profile, e.g. for profile-aware backup and restore
+/// of personalization frames such as `x260`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ProfileSettings<T> {
+    profiles: [Option<T>; PROFILE_COUNT],
+}
+
+impl<T> ProfileSettings<T> {
+    /// Create an empty storage.
+    pub const fn new() -> ProfileSettings<T> {
+        ProfileSettings {
+            profiles: [None, None, None],
+        }
+    }
+
+    /// Return the settings stored for `profile`, if any.
+    pub fn get(&self, profile: ProfileNumber) -> Option<&T> {
+        self.profiles[profile.index()].as_ref()
+    }
+
+    /// Return a mutable reference to the settings stored for `profile`, if any.
+    pub fn get_mut(&mut self, profile: ProfileNumber) -> Option<&mut T> {
+        self.profiles[profile.index()].as_mut()
+    }
+
+    /// Store `settings` for `profile`, returning the previously stored settings.
+    pub fn insert(&mut self, profile: ProfileNumber, settings: T) -> Option<T> {
+        self.profiles[profile.index()].replace(settings)
+    }
+
+    /// Remove and return the settings stored for `profile`.
+    pub fn remove(&mut self, profile: ProfileNumber) -> Option<T> {
+        self.profiles[profile.index()].take()
+    }
+
+    /// Store `settings` for the broadcast user profile `profile`.
+    /// Returns `Err(Error::Invalid)` without storing anything if `profile` is not a
+    /// driver profile.
+    pub fn update(&mut self, profile: UserProfile, settings: T) -> crate::Result<ProfileNumber> {
+        let profile = ProfileNumber::try_from(profile)?;
+        self.insert(profile, settings);
+        Ok(profile)
+    }
+
+    /// Return an iterator over the stored settings and their profile.
+    pub fn iter(&self) -> impl Iterator<Item = (ProfileNumber, &T)> {
+        ProfileNumber::ALL
+            .iter()
+            .zip(self.profiles.iter())
+            .filter_map(|(profile, settings)| settings.as_ref().map(|s| (*profile, s)))
+    }
+}
+
+impl<T> Default for ProfileSettings<T> {
+    fn default() -> ProfileSettings<T> {
+        ProfileSettings::new()
+    }
+}
+
 enum_with_unknown! {
     /// Sound harmony setting. AEE 2010 only.
     pub enum SoundHarmony(u8) {
@@ -725,9 +855,9 @@ impl From<ConfigurableKeyAction2004> for ConfigurableKeyAction2010 {
     }
 }
 
-impl Into<ConfigurableKeyAction2004> for ConfigurableKeyAction2010 {
-    fn into(self) -> ConfigurableKeyAction2004 {
-        match self {
+impl From<ConfigurableKeyAction2010> for ConfigurableKeyAction2004 {
+    fn from(action_2010: ConfigurableKeyAction2010) -> Self {
+        match action_2010 {
             ConfigurableKeyAction2010::CeilingLight => ConfigurableKeyAction2004::CeilingLight,
             ConfigurableKeyAction2010::BlackPanel => ConfigurableKeyAction2004::BlackPanel,
             ConfigurableKeyAction2010::FaultLog => ConfigurableKeyAction2004::FaultLog,
@@ -763,3 +893,53 @@ impl fmt::Display for CollisionAlertSensibilityLevel {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{ProfileNumber, ProfileSettings, UserProfile};
+
+    use crate::Error;
+
+    #[test]
+    fn test_profile_number_conversions() {
+        for (index, profile) in ProfileNumber::ALL.iter().enumerate() {
+            assert_eq!(ProfileNumber::from_index(index), Some(*profile));
+            assert_eq!(
+                ProfileNumber::try_from(UserProfile::from(*profile)),
+                Ok(*profile)
+            );
+        }
+        assert_eq!(ProfileNumber::from_index(3), None);
+        assert_eq!(
+            ProfileNumber::try_from(UserProfile::Default),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            ProfileNumber::try_from(UserProfile::Unknown(3)),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_profile_settings() {
+        let mut settings = ProfileSettings::new();
+        assert_eq!(
+            settings.update(UserProfile::Profile2, 2u8),
+            Ok(ProfileNumber::Profile2)
+        );
+        assert_eq!(settings.update(UserProfile::None, 0u8), Err(Error::Invalid));
+        assert_eq!(settings.insert(ProfileNumber::Profile1, 1), None);
+        assert_eq!(settings.insert(ProfileNumber::Profile2, 3), Some(2));
+        assert_eq!(settings.get(ProfileNumber::Profile3), None);
+
+        {
+            let mut iter = settings.iter();
+            assert_eq!(iter.next(), Some((ProfileNumber::Profile1, &1)));
+            assert_eq!(iter.next(), Some((ProfileNumber::Profile2, &3)));
+            assert_eq!(iter.next(), None);
+        }
+
+        assert_eq!(settings.remove(ProfileNumber::Profile1), Some(1));
+        assert_eq!(settings.get(ProfileNumber::Profile1), None);
+    }
+}
@@ -0,0 +1,279 @@
+//! Consistency of the profile settings with the vehicle options.
+//!
+//! The BSI declares in x361 which optional functions the vehicle is fitted with, and
+//! broadcasts in x260 the settings of the active driver profile. A profile can still hold a
+//! setting enabled for an absent option, e.g. after a telecoding removed the option, or with a
+//! BSI swapped from another vehicle. Head units hide the menu items of absent options:
+//! [inconsistencies] pairs both frames of a bus and reports the settings enabled for an option
+//! x361 declares absent, collected by `check` with the `alloc` feature or by [check_heapless].
+//!
+//! ```
+//! # use canpsa::{aee2004::conf::{x260, x361}, consistency::*};
+//! let config = x361::Repr {
+//!     mood_lighting_present: false,
+//!     ..x361::Repr::default()
+//! };
+//! let profile = x260::Repr {
+//!     mood_lighting_enabled: true,
+//!     ..x260::Repr::default()
+//! };
+//!
+//! let inconsistencies = check_heapless::<_, 8>(&config, &profile).unwrap();
+//! assert!(inconsistencies.contains(&Inconsistency {
+//!     option: "mood_lighting_present",
+//!     setting: "mood_lighting_enabled",
+//! }));
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{aee2004::conf, aee2010::infodiv, Error, Result};
+
+/// A profile setting enabled for an option declared absent.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Inconsistency {
+    /// Name of the option presence field of x361.
+    pub option: &'static str,
+    /// Name of the enabled setting field of x260.
+    pub setting: &'static str,
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} enabled without {}", self.setting, self.option)
+    }
+}
+
+/// An option declared by a x361 representation `C`, and the setting of a x260
+/// representation `P` which depends on it.
+pub struct Pairing<C, P> {
+    /// Name of the option presence field of x361.
+    pub option: &'static str,
+    /// Name of the setting field of x260.
+    pub setting: &'static str,
+    present: fn(&C) -> bool,
+    enabled: fn(&P) -> bool,
+}
+
+impl<C, P> Pairing<C, P> {
+    /// Return the inconsistency of the pair, if the setting is enabled in `profile` while
+    /// the option is absent from `menu_cfg`.
+    pub fn check(&self, menu_cfg: &C, profile: &P) -> Option<Inconsistency> {
+        if (self.enabled)(profile) && !(self.present)(menu_cfg) {
+            Some(Inconsistency {
+                option: self.option,
+                setting: self.setting,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// A x361 vehicle options representation, paired with the x260 profile settings
+/// representation of the same bus.
+pub trait MenuConfig: Sized + 'static {
+    /// The x260 representation of the bus.
+    type Profile: 'static;
+
+    /// The options conditioning a profile setting, in x361 declaration order.
+    const PAIRINGS: &'static [Pairing<Self, Self::Profile>];
+}
+
+macro_rules! pairings {
+    ($config:ty, $profile:ty, { $($option:ident => $setting:ident),+ $(,)? }) => {
+        impl MenuConfig for $config {
+            type Profile = $profile;
+
+            const PAIRINGS: &'static [Pairing<$config, $profile>] = &[
+                $(
+                  Pairing {
+                      option: stringify!($option),
+                      setting: stringify!($setting),
+                      present: |config| config.$option,
+                      enabled: |profile| profile.$setting,
+                  },
+                )+
+            ];
+        }
+    };
+}
+
+pairings!(conf::x361::Repr, conf::x260::Repr, {
+    boot_permanent_locking_present => boot_permanent_locking_enabled,
+    partial_window_opening_present => partial_window_opening_enabled,
+    welcome_function_present => welcome_function_enabled,
+    automatic_headlamps_present => automatic_headlamps_enabled,
+    automatic_electric_parking_brake_application_present => auto_elec_parking_brake_application_enabled,
+    follow_me_home_present => follow_me_home_enabled,
+    locking_mode_on_coe_present => locking_mode_on_coe_enabled,
+    automatic_door_locking_when_leaving_present => auto_door_locking_when_leaving_enabled,
+    selective_unlocking_present => selective_unlocking_enabled,
+    rear_wiper_in_reverse_gear_present => rear_wiper_in_reverse_gear_enabled,
+    daytime_running_lamps_present => daytime_running_lamps_enabled,
+    adaptive_lamps_present => adaptive_lamps_enabled,
+    mood_lighting_present => mood_lighting_enabled,
+    motorway_lighting_present => motorway_lighting_enabled,
+});
+
+pairings!(infodiv::x361::Repr, infodiv::x260::Repr, {
+    daytime_running_lamps_present => daytime_running_lamps_enabled,
+    automatic_headlamps_present => automatic_headlamps_enabled,
+    mood_lighting_present => mood_lighting_enabled,
+    blind_spot_monitoring_present => blind_spot_monitoring_enabled,
+    adaptive_lamps_present => adaptive_lamps_enabled,
+    welcome_lighting_present => welcome_lighting_enabled,
+    motorway_lighting_present => motorway_lighting_enabled,
+    selective_unlocking_present => selective_unlocking_enabled,
+    key_selective_unlocking_present => key_selective_unlocking_enabled,
+    boot_selective_unlocking_present => boot_selective_unlocking_enabled,
+    motorized_tailgate_present => motorized_tailgate_enabled,
+    welcome_function_present => welcome_function_enabled,
+    follow_me_home_present => follow_me_home_enabled,
+    rear_wiper_in_reverse_gear_present => rear_wiper_in_reverse_gear_enabled,
+    extended_traffic_sign_recognition_present => extended_traffic_sign_recognition_enabled,
+    mirror_tilt_in_reverse_present => mirrors_tilting_in_reverse_gear_enabled,
+    automatic_electric_parking_brake_application_present => automatic_elec_parking_brake_application_enabled,
+    automatic_emergency_braking_present => automatic_emergency_braking_enabled,
+    hands_free_tailgate_auto_lock_menu_present => hands_free_tailgate_auto_lock_enabled,
+    hands_free_tailgate_present => hands_free_tailgate_enabled,
+    speed_limit_recognition_present => speed_limit_recognition_enabled,
+    radiator_grill_lamps_present => radiator_grill_lamps_enabled,
+    automatic_main_beam_present => automatic_main_beam_enabled,
+    electric_child_security_present => electric_child_security_enabled,
+    driver_alert_assist_present => driver_alert_assist_enabled,
+});
+
+/// Return an iterator over the settings of `profile` enabled for an option absent from
+/// `menu_cfg`, in x361 declaration order.
+pub fn inconsistencies<'a, C: MenuConfig>(
+    menu_cfg: &'a C,
+    profile: &'a C::Profile,
+) -> impl Iterator<Item = Inconsistency> + 'a {
+    C::PAIRINGS
+        .iter()
+        .filter_map(move |pairing| pairing.check(menu_cfg, profile))
+}
+
+/// Return the settings of `profile` enabled for an option absent from `menu_cfg`.
+#[cfg(feature = "alloc")]
+pub fn check<C: MenuConfig>(menu_cfg: &C, profile: &C::Profile) -> Vec<Inconsistency> {
+    inconsistencies(menu_cfg, profile).collect()
+}
+
+/// Return the settings of `profile` enabled for an option absent from `menu_cfg`, like
+/// `check` but without allocation.
+/// Returns `Err(Error::Exhausted)` if there are more than `N` inconsistencies.
+pub fn check_heapless<C: MenuConfig, const N: usize>(
+    menu_cfg: &C,
+    profile: &C::Profile,
+) -> Result<heapless::Vec<Inconsistency, N>> {
+    let mut found = heapless::Vec::new();
+    for inconsistency in inconsistencies(menu_cfg, profile) {
+        found.push(inconsistency).map_err(|_| Error::Exhausted)?;
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Write;
+
+    use heapless::String;
+
+    use super::{check_heapless, inconsistencies, Inconsistency, MenuConfig};
+
+    use crate::{
+        aee2004::conf,
+        aee2010::infodiv::{x260, x361},
+        Error,
+    };
+
+    fn config() -> x361::Repr {
+        x361::Repr {
+            mood_lighting_present: false,
+            driver_alert_assist_present: false,
+            ..x361::Repr::default()
+        }
+    }
+
+    fn profile() -> x260::Repr {
+        x260::Repr {
+            mood_lighting_enabled: true,
+            driver_alert_assist_enabled: true,
+            ..x260::Repr::default()
+        }
+    }
+
+    #[test]
+    fn test_pairings() {
+        for pairing in x361::Repr::PAIRINGS {
+            assert!(pairing.option.ends_with("_present"));
+            assert!(pairing.setting.ends_with("_enabled"));
+        }
+        for pairing in conf::x361::Repr::PAIRINGS {
+            assert!(pairing.option.ends_with("_present"));
+            assert!(pairing.setting.ends_with("_enabled"));
+        }
+    }
+
+    #[test]
+    fn test_inconsistencies() {
+        let consistent = x361::Repr {
+            mood_lighting_present: true,
+            driver_alert_assist_present: true,
+            ..config()
+        };
+        let before = inconsistencies(&consistent, &profile()).count();
+        assert_eq!(inconsistencies(&config(), &profile()).count(), before + 2);
+
+        let found = check_heapless::<_, 32>(&config(), &profile()).unwrap();
+        let mood = Inconsistency {
+            option: "mood_lighting_present",
+            setting: "mood_lighting_enabled",
+        };
+        assert!(found.contains(&mood));
+        assert!(found
+            .iter()
+            .any(|i| i.setting == "driver_alert_assist_enabled"));
+
+        let disabled = x260::Repr {
+            mood_lighting_enabled: false,
+            driver_alert_assist_enabled: false,
+            ..profile()
+        };
+        assert!(inconsistencies(&config(), &disabled)
+            .all(|i| i.option != "mood_lighting_present"
+                && i.option != "driver_alert_assist_present"));
+
+        let mut text: String<64> = String::new();
+        write!(text, "{}", mood).unwrap();
+        assert_eq!(
+            text,
+            "mood_lighting_enabled enabled without mood_lighting_present"
+        );
+    }
+
+    #[test]
+    fn test_exhausted() {
+        let config = conf::x361::Repr {
+            welcome_function_present: false,
+            selective_unlocking_present: false,
+            ..conf::x361::Repr::default()
+        };
+        let profile = conf::x260::Repr {
+            welcome_function_enabled: true,
+            selective_unlocking_enabled: true,
+            ..conf::x260::Repr::default()
+        };
+        assert!(inconsistencies(&config, &profile).count() >= 2);
+        assert_eq!(
+            check_heapless::<_, 1>(&config, &profile),
+            Err(Error::Exhausted)
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Steering wheel controls.
+//!
+//! The steering wheel switch module broadcasts the state of the radio stalk keys and of its
+//! scroll wheel every 100 ms in the x21f frame of the AEE2004 CONF bus. A key stays set in every
+//! frame as long as it is pressed, and the scroll wheel is reported as a wrapping position
+//! counter.
+//!
+//! [SteeringWheelControls] turns these repeated frames into key events: a key is reported
+//! [pressed](KeyEvent::Pressed) once on its first frame, [held](KeyEvent::Held) once it stays
+//! pressed for [HOLD_DELAY], and [released](KeyEvent::Released) once it is cleared or the
+//! frames stop for [RELEASE_TIMEOUT]. Scroll wheel moves are accumulated between polls.
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2004::conf::x21f, controls::*};
+//! let mut controls = SteeringWheelControls::new();
+//! let mut payload = x21f::IDLE_FRAME_BYTES;
+//! controls.on_frame(x21f::FRAME_ID, &payload, Duration::ZERO).unwrap();
+//!
+//! x21f::Frame::new_unchecked(&mut payload).set_volume_up(true);
+//! controls.on_frame(x21f::FRAME_ID, &payload, Duration::from_millis(100)).unwrap();
+//! assert_eq!(controls.poll(Duration::from_millis(100)), Some(KeyEvent::Pressed(Key::VolumeUp)));
+//! assert_eq!(controls.poll(Duration::from_millis(100)), None);
+//!
+//! controls.on_frame(x21f::FRAME_ID, &payload, Duration::from_millis(600)).unwrap();
+//! assert_eq!(controls.poll(Duration::from_millis(600)), Some(KeyEvent::Held(Key::VolumeUp)));
+//!
+//! x21f::Frame::new_unchecked(&mut payload).set_volume_up(false);
+//! controls.on_frame(x21f::FRAME_ID, &payload, Duration::from_millis(700)).unwrap();
+//! assert_eq!(controls.poll(Duration::from_millis(700)), Some(KeyEvent::Released(Key::VolumeUp)));
+//! ```
+//!
+//! The caller polls the events until `None` after each received frame.
+
+use core::{fmt, time::Duration};
+
+use crate::{aee2004::conf::x21f, rx::STALE_PERIODS, Result};
+
+/// Time after which a pressed key is reported as held.
+pub const HOLD_DELAY: Duration = Duration::from_millis(500);
+/// Time after which the pressed keys are released when no x21f frame is received anymore.
+pub const RELEASE_TIMEOUT: Duration =
+    Duration::from_millis(x21f::PERIODICITY.as_millis() as u64 * STALE_PERIODS as u64);
+
+/// Steering wheel key.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Key {
+    Next,
+    Previous,
+    VolumeUp,
+    VolumeDown,
+    Source,
+}
+
+impl Key {
+    /// All the steering wheel keys, in the order their events are reported.
+    pub const ALL: [Key; 5] = [
+        Key::Next,
+        Key::Previous,
+        Key::VolumeUp,
+        Key::VolumeDown,
+        Key::Source,
+    ];
+
+    fn mask(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Key::Next => write!(f, "next"),
+            Key::Previous => write!(f, "previous"),
+            Key::VolumeUp => write!(f, "volume up"),
+            Key::VolumeDown => write!(f, "volume down"),
+            Key::Source => write!(f, "source"),
+        }
+    }
+}
+
+/// Steering wheel controls event.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum KeyEvent {
+    /// The key has been pressed.
+    Pressed(Key),
+    /// The key is still pressed after [HOLD_DELAY].
+    Held(Key),
+    /// The key has been released.
+    Released(Key),
+    /// The scroll wheel moved by the given number of steps, positive when its position
+    /// counter increases.
+    Scrolled(i8),
+}
+
+impl fmt::Display for KeyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyEvent::Pressed(key) => write!(f, "{} pressed", key),
+            KeyEvent::Held(key) => write!(f, "{} held", key),
+            KeyEvent::Released(key) => write!(f, "{} released", key),
+            KeyEvent::Scrolled(steps) => write!(f, "scrolled by {}", steps),
+        }
+    }
+}
+
+/// Steering wheel controls decoder.
+#[derive(Debug, Clone, Default)]
+pub struct SteeringWheelControls {
+    /// Keys set in the last received frame.
+    pressed: u16,
+    /// Keys reported as pressed.
+    reported: u16,
+    /// Keys reported as held.
+    held: u16,
+    /// Reception time of the first frame of each pressed key.
+    since: [Duration; Key::ALL.len()],
+    /// Last scroll wheel position and steps accumulated since the last poll.
+    scroll: Option<u8>,
+    steps: i16,
+    last_frame: Option<Duration>,
+}
+
+impl SteeringWheelControls {
+    /// Create a decoder of the steering wheel controls.
+    pub fn new() -> SteeringWheelControls {
+        SteeringWheelControls {
+            pressed: 0,
+            reported: 0,
+            held: 0,
+            since: [Duration::ZERO; Key::ALL.len()],
+            scroll: None,
+            steps: 0,
+            last_frame: None,
+        }
+    }
+
+    /// Return whether `key` is currently reported as pressed.
+    pub fn is_pressed(&self, key: Key) -> bool {
+        self.reported & key.mask() != 0
+    }
+
+    /// Process the payload of the CAN frame with identifier `id` received at `now`.
+    ///
+    /// Returns whether the frame carries the steering wheel controls, or
+    /// `Err(Error::Truncated)` if the payload is too short.
+    pub fn on_frame(&mut self, id: u16, payload: &[u8], now: Duration) -> Result<bool> {
+        if id != x21f::FRAME_ID {
+            return Ok(false);
+        }
+        let repr = x21f::Repr::parse(&x21f::Frame::new_checked(payload)?)?;
+
+        let keys = [
+            repr.next,
+            repr.previous,
+            repr.volume_up,
+            repr.volume_down,
+            repr.source,
+        ];
+        let pressed = Key::ALL
+            .iter()
+            .zip(keys)
+            .filter(|(_, set)| *set)
+            .fold(0, |mask, (key, _)| mask | key.mask());
+        for key in Key::ALL {
+            if pressed & !self.pressed & key.mask() != 0 {
+                self.since[key as usize] = now;
+            }
+        }
+        self.pressed = pressed;
+
+        if let Some(last) = self.scroll {
+            let delta = repr.scroll_position.wrapping_sub(last) as i8;
+            self.steps = self.steps.saturating_add(delta.into());
+        }
+        self.scroll = Some(repr.scroll_position);
+        self.last_frame = Some(now);
+        Ok(true)
+    }
+
+    /// Return the next steering wheel controls event at `now`, or `None` if there is none.
+    ///
+    /// Presses are reported first, then releases, holds and finally scroll wheel moves.
+    pub fn poll(&mut self, now: Duration) -> Option<KeyEvent> {
+        if matches!(self.last_frame, Some(last) if now.saturating_sub(last) > RELEASE_TIMEOUT) {
+            self.pressed = 0;
+            self.scroll = None;
+            self.last_frame = None;
+        }
+
+        for key in Key::ALL {
+            if self.pressed & !self.reported & key.mask() != 0 {
+                self.reported |= key.mask();
+                return Some(KeyEvent::Pressed(key));
+            }
+        }
+        for key in Key::ALL {
+            if self.reported & !self.pressed & key.mask() != 0 {
+                self.reported &= !key.mask();
+                self.held &= !key.mask();
+                return Some(KeyEvent::Released(key));
+            }
+        }
+        for key in Key::ALL {
+            if self.reported & !self.held & key.mask() != 0
+                && now.saturating_sub(self.since[key as usize]) >= HOLD_DELAY
+            {
+                self.held |= key.mask();
+                return Some(KeyEvent::Held(key));
+            }
+        }
+        if self.steps != 0 {
+            let steps = self.steps.clamp(i8::MIN.into(), i8::MAX.into());
+            self.steps -= steps;
+            return Some(KeyEvent::Scrolled(steps as i8));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn payload(repr: &x21f::Repr) -> [u8; x21f::FRAME_LEN] {
+        let mut buffer = [0u8; x21f::FRAME_LEN];
+        repr.emit(&mut x21f::Frame::new_unchecked(&mut buffer));
+        buffer
+    }
+
+    fn idle() -> x21f::Repr {
+        x21f::ReprBuilder::new().build().unwrap()
+    }
+
+    fn at(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_press_hold_release() {
+        let mut controls = SteeringWheelControls::new();
+        let idle = idle();
+        let next = x21f::Repr { next: true, ..idle };
+
+        // Repeated frames are debounced into a single press.
+        for millis in [0, 100, 200] {
+            assert_eq!(
+                controls.on_frame(x21f::FRAME_ID, &payload(&next), at(millis)),
+                Ok(true)
+            );
+        }
+        assert_eq!(controls.poll(at(200)), Some(KeyEvent::Pressed(Key::Next)));
+        assert_eq!(controls.poll(at(200)), None);
+        assert!(controls.is_pressed(Key::Next));
+
+        controls
+            .on_frame(x21f::FRAME_ID, &payload(&next), at(500))
+            .unwrap();
+        assert_eq!(controls.poll(at(500)), Some(KeyEvent::Held(Key::Next)));
+        assert_eq!(controls.poll(at(600)), None);
+
+        controls
+            .on_frame(x21f::FRAME_ID, &payload(&idle), at(600))
+            .unwrap();
+        assert_eq!(controls.poll(at(600)), Some(KeyEvent::Released(Key::Next)));
+        assert_eq!(controls.poll(at(600)), None);
+        assert!(!controls.is_pressed(Key::Next));
+    }
+
+    #[test]
+    fn test_release_timeout() {
+        let mut controls = SteeringWheelControls::new();
+        let mut buffer = [0u8; x21f::FRAME_LEN];
+        let mut frame = x21f::Frame::new_unchecked(&mut buffer);
+        frame.set_source(true);
+
+        controls
+            .on_frame(x21f::FRAME_ID, &buffer, Duration::ZERO)
+            .unwrap();
+        assert_eq!(
+            controls.poll(Duration::ZERO),
+            Some(KeyEvent::Pressed(Key::Source))
+        );
+        assert_eq!(controls.poll(RELEASE_TIMEOUT), None);
+        assert_eq!(
+            controls.poll(RELEASE_TIMEOUT + at(1)),
+            Some(KeyEvent::Released(Key::Source))
+        );
+    }
+
+    #[test]
+    fn test_scroll() {
+        let mut controls = SteeringWheelControls::new();
+        let idle = idle();
+
+        for (millis, position) in [(0, 254), (100, 255), (200, 2)] {
+            let repr = x21f::Repr {
+                scroll_position: position,
+                ..idle
+            };
+            controls
+                .on_frame(x21f::FRAME_ID, &payload(&repr), at(millis))
+                .unwrap();
+        }
+        assert_eq!(controls.poll(at(200)), Some(KeyEvent::Scrolled(4)));
+        assert_eq!(controls.poll(at(200)), None);
+
+        let repr = x21f::Repr {
+            scroll_position: 0,
+            ..idle
+        };
+        controls
+            .on_frame(x21f::FRAME_ID, &payload(&repr), at(300))
+            .unwrap();
+        assert_eq!(controls.poll(at(300)), Some(KeyEvent::Scrolled(-2)));
+    }
+
+    #[test]
+    fn test_other_frames() {
+        let mut controls = SteeringWheelControls::new();
+        let payload = payload(&idle());
+        assert_eq!(controls.on_frame(0x220, &payload, at(0)), Ok(false));
+        assert_eq!(
+            controls.on_frame(x21f::FRAME_ID, &payload[..2], at(0)),
+            Err(crate::Error::Truncated)
+        );
+    }
+}
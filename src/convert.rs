@@ -0,0 +1,222 @@
+//! Conversions between the AEE2004 and AEE2010 frame representations.
+//!
+//! Most frames of the AEE2004 conf bus have an equivalent on the AEE2010 infodiv bus, sometimes
+//! under another identifier. [ConvertFrom2004] and [ConvertFrom2010] tie each representation to
+//! its equivalent on the other bus, for tools converting every received frame generically, like
+//! [gateway](crate::gateway). The conversions themselves are the `From<&Repr>` implementations of
+//! the frame modules, which can also be used directly.
+//!
+//! The equivalents under another identifier are:
+//!
+//! | AEE2004 conf                | AEE2010 infodiv                |
+//! |-----------------------------|--------------------------------|
+//! | x1a8 `GESTION_VITESSE`      | x228 `ID_ACC_XVV_IHM_ETAT`     |
+//! | x1d0 `ETAT_CLIM_AV_BSI`     | x350 `ID_ETAT_CLIM_AV`         |
+//! | x376 `DATE_CONFIG_2`        | x276 `ID_DONNEES_BSI_LENTES_3` |
+//! | x3a7 `INFOS_MAINTENANCE`    | x3e7 `ID_INFOS_MAINTENANCE_EV` |
+//!
+//! The slow BSI data of the AEE2010 x276 are thus found in the AEE2004 x376, which carries the
+//! clock but not the AdBlue autonomy. The AEE2004 x1a8 also converts to the AEE2010 x1a8.
+//!
+//! Representations are not always one-to-one, so conversions follow this defaulting policy:
+//! - Fields with an equivalent on the other bus are copied, enums being mapped variant by variant.
+//!   Unknown enum values are kept as is.
+//! - Fields of the source without any equivalent are dropped.
+//! - Fields of the target without any equivalent take the value the target frame carries on a
+//!   parked vehicle, i.e. the value of its [Default] representation, unless a better guess can
+//!   be made from the source fields. Those fields are commented in each conversion.
+//!
+//! ```
+//! use canpsa::{aee2004::conf::x0b6, aee2010::infodiv, convert::ConvertFrom2004};
+//!
+//! let repr_2004 = x0b6::Repr::default();
+//! let repr_2010 = infodiv::x0b6::Repr::from_aee2004(&repr_2004);
+//! assert_eq!(repr_2010.engine_rpm, repr_2004.engine_rpm);
+//! ```
+
+use crate::{aee2004::conf, aee2010::infodiv};
+
+/// A frame representation which can be built from its AEE2004 equivalent.
+pub trait ConvertFrom2004: Sized {
+    /// Equivalent representation on the AEE2004 conf bus.
+    type Aee2004;
+
+    /// Converts an AEE2004 representation.
+    fn from_aee2004(repr: &Self::Aee2004) -> Self;
+}
+
+/// A frame representation which can be built from its AEE2010 equivalent.
+pub trait ConvertFrom2010: Sized {
+    /// Equivalent representation on the AEE2010 infodiv bus.
+    type Aee2010;
+
+    /// Converts an AEE2010 representation.
+    fn from_aee2010(repr: &Self::Aee2010) -> Self;
+}
+
+macro_rules! convert_from_2004 {
+    ($( $aee2004:ident => $aee2010:ident ),+ $(,)?) => {
+        $(
+            impl ConvertFrom2004 for infodiv::$aee2010::Repr {
+                type Aee2004 = conf::$aee2004::Repr;
+
+                fn from_aee2004(repr: &conf::$aee2004::Repr) -> Self {
+                    Self::from(repr)
+                }
+            }
+        )+
+
+        #[cfg(test)]
+        fn check_from_2004() {
+            $( check::<infodiv::$aee2010::Repr, conf::$aee2004::Repr>(
+                infodiv::$aee2010::Repr::from_aee2004,
+                infodiv::$aee2010::FRAME_LEN,
+                |repr, buf| {
+                    let token = crate::frame::DangerToken::unchecked();
+                    assert_eq!(crate::frame::PsaFrame::emit_acknowledged(repr, buf, token), Ok(()));
+                },
+            ); )+
+        }
+    };
+}
+
+macro_rules! convert_from_2010 {
+    ($( $aee2010:ident => $aee2004:ident ),+ $(,)?) => {
+        $(
+            impl ConvertFrom2010 for conf::$aee2004::Repr {
+                type Aee2010 = infodiv::$aee2010::Repr;
+
+                fn from_aee2010(repr: &infodiv::$aee2010::Repr) -> Self {
+                    Self::from(repr)
+                }
+            }
+        )+
+
+        #[cfg(test)]
+        fn check_from_2010() {
+            $( check::<conf::$aee2004::Repr, infodiv::$aee2010::Repr>(
+                conf::$aee2004::Repr::from_aee2010,
+                conf::$aee2004::FRAME_LEN,
+                |repr, buf| {
+                    let token = crate::frame::DangerToken::unchecked();
+                    assert_eq!(crate::frame::PsaFrame::emit_acknowledged(repr, buf, token), Ok(()));
+                },
+            ); )+
+        }
+    };
+}
+
+convert_from_2004! {
+    x036 => x036,
+    x0b6 => x0b6,
+    x0e6 => x0e6,
+    x0f6 => x0f6,
+    x128 => x128,
+    x15b => x15b,
+    x167 => x167,
+    x168 => x168,
+    x1a5 => x1a5,
+    x1a8 => x1a8,
+    x1a8 => x228,
+    x1d0 => x350,
+    x1e1 => x1e1,
+    x1e5 => x1e5,
+    x221 => x221,
+    x227 => x227,
+    x260 => x260,
+    x261 => x261,
+    x2a1 => x2a1,
+    x2b6 => x2b6,
+    x2e1 => x2e1,
+    x336 => x336,
+    x361 => x361,
+    x376 => x276,
+    x3a7 => x3e7,
+    x3b6 => x3b6,
+    x3e1 => x3e1,
+}
+
+convert_from_2010! {
+    x036 => x036,
+    x0b6 => x0b6,
+    x0e6 => x0e6,
+    x0f6 => x0f6,
+    x128 => x128,
+    x15b => x15b,
+    x167 => x167,
+    x168 => x168,
+    x1a5 => x1a5,
+    x1e1 => x1e1,
+    x1e5 => x1e5,
+    x221 => x221,
+    x227 => x227,
+    x228 => x1a8,
+    x260 => x260,
+    x261 => x261,
+    x2a1 => x2a1,
+    x2b6 => x2b6,
+    x2e1 => x2e1,
+    x336 => x336,
+    x350 => x1d0,
+    x361 => x361,
+    x276 => x376,
+    x3e7 => x3a7,
+    x3b6 => x3b6,
+    x3e1 => x3e1,
+}
+
+/// Converts the parked representation of a frame and checks the result only holds known
+/// values and can be emitted.
+#[cfg(test)]
+fn check<T, S>(convert: fn(&S) -> T, len: usize, emit: fn(&T, &mut [u8]))
+where
+    T: crate::validate::ValidateFields + core::fmt::Debug,
+    S: Default,
+{
+    let repr = convert(&S::default());
+    assert_eq!(
+        repr.validate().next(),
+        None,
+        "unknown field value in {:?}",
+        repr
+    );
+
+    let mut buffer = [0u8; 8];
+    emit(&repr, &mut buffer[..len]);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_from_2004, check_from_2010, conf, infodiv};
+    use super::{ConvertFrom2004, ConvertFrom2010};
+
+    #[test]
+    fn test_parked_conversions() {
+        check_from_2004();
+        check_from_2010();
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let repr = conf::x0b6::Repr::parse(&conf::x0b6::Frame::new_unchecked(
+            &conf::x0b6::IDLE_FRAME_BYTES,
+        ))
+        .unwrap();
+        let repr_2010 = infodiv::x0b6::Repr::from_aee2004(&repr);
+        assert_eq!(conf::x0b6::Repr::from_aee2010(&repr_2010), repr);
+
+        let repr = infodiv::x221::Repr::parse(&infodiv::x221::Frame::new_unchecked(
+            &infodiv::x221::IDLE_FRAME_BYTES,
+        ))
+        .unwrap();
+        let repr_2004 = conf::x221::Repr::from_aee2010(&repr);
+        assert_eq!(infodiv::x221::Repr::from_aee2004(&repr_2004), repr);
+
+        let repr = conf::x376::Repr::parse(&conf::x376::Frame::new_unchecked(
+            &conf::x376::IDLE_FRAME_BYTES,
+        ))
+        .unwrap();
+        let repr_2010 = infodiv::x276::Repr::from_aee2004(&repr);
+        assert_eq!(conf::x376::Repr::from_aee2010(&repr_2010), repr);
+    }
+}
@@ -0,0 +1,226 @@
+//! Frame parsing checks against capture corpora.
+//!
+//! Frame modules are written from partial documentation, and a field decoded from the wrong
+//! bits often only shows up with the frames of an actual vehicle. [check] parses every frame
+//! of a `candump -L` capture, see [replay](crate::replay), with the `Message::parse`
+//! dispatcher of the captured bus, and collects a [Report] of parsing statistics per frame
+//! identifier:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv, corpus};
+//! let capture = "\
+//! (0.000000) can0 0B6#18A70000000042D0
+//! (0.010000) can0 0B6#18A700000000
+//! (0.020000) can0 7DF#0201
+//! ";
+//! let report = corpus::check(capture.as_bytes(), infodiv::Message::parse).unwrap();
+//! let stats = report.get(0x0b6).unwrap();
+//! assert_eq!((stats.frames, stats.parsed, stats.truncated), (2, 1, 1));
+//! assert_eq!(report.unrecognized, 1);
+//! report.assert_valid();
+//! ```
+//!
+//! Captures are committed as test data, the `corpus` integration test checking every capture
+//! of `tests/fixtures/corpus`. Downstream crates run their own captures the same way from
+//! their tests, with the `corpus` feature enabled.
+
+use core::fmt;
+
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead},
+    string::String,
+};
+
+use crate::{replay, validate::Validate, Error, Result};
+
+/// Parsing statistics of the frames of an identifier.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct FrameStats {
+    /// Number of captured frames.
+    pub frames: usize,
+    /// Number of frames parsed successfully.
+    pub parsed: usize,
+    /// Number of parsed frames holding undocumented values, see [Validate].
+    pub undocumented: usize,
+    /// Number of frames too short for the frame layout.
+    pub truncated: usize,
+    /// Number of frames rejected with any other error, e.g. [Error::Invalid].
+    pub invalid: usize,
+    /// Line number of the first rejected frame, starting at 1.
+    pub first_invalid: Option<usize>,
+}
+
+impl FrameStats {
+    /// Return the ratio of frames parsed successfully, from 0 to 1.
+    pub fn coverage(&self) -> f32 {
+        if self.frames == 0 {
+            return 0.0;
+        }
+        self.parsed as f32 / self.frames as f32
+    }
+}
+
+impl fmt::Display for FrameStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} frames, {} parsed, {} undocumented, {} truncated, {} invalid",
+            self.frames, self.parsed, self.undocumented, self.truncated, self.invalid
+        )?;
+        if let Some(line) = self.first_invalid {
+            write!(f, " (first at line {})", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parsing statistics of a capture, see [check].
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Report {
+    /// Statistics of the identifiers known to the dispatcher, by identifier.
+    pub frames: BTreeMap<u16, FrameStats>,
+    /// Number of frames whose identifier is unknown to the dispatcher.
+    pub unrecognized: usize,
+    /// Number of remote and extended identifier frames, which are not parsed.
+    pub skipped: usize,
+}
+
+impl Report {
+    /// Return the statistics of the frames of identifier `id`, if any was captured.
+    pub fn get(&self, id: u16) -> Option<&FrameStats> {
+        self.frames.get(&id)
+    }
+
+    /// Return the total number of frames rejected by the dispatcher, truncated frames
+    /// excluded.
+    pub fn invalid(&self) -> usize {
+        self.frames.values().map(|stats| stats.invalid).sum()
+    }
+
+    /// Check that no frame of the capture has been rejected, truncated frames excluded.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the report if a frame has been rejected.
+    pub fn assert_valid(&self) {
+        assert!(self.invalid() == 0, "rejected frames in capture:\n{}", self);
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, stats) in self.frames.iter() {
+            writeln!(f, "x{:03x}: {}", id, stats)?;
+        }
+        write!(
+            f,
+            "{} unrecognized, {} skipped",
+            self.unrecognized, self.skipped
+        )
+    }
+}
+
+/// Parse every data frame of the `candump -L` capture read from `capture` with the `decode`
+/// dispatcher, and return the parsing statistics. Blank lines and lines starting with `#`
+/// are ignored.
+///
+/// Returns an [io::ErrorKind::InvalidData] error on the first malformed line.
+pub fn check<R: BufRead, M: Validate>(
+    capture: R,
+    decode: fn(u16, &[u8]) -> Result<M>,
+) -> io::Result<Report> {
+    let mut report = Report::default();
+    for (index, line) in capture.lines().enumerate() {
+        let line: String = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let record = replay::parse(line).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                std::format!("line {}: {}", index + 1, err),
+            )
+        })?;
+        let (Some(id), false) = (record.frame.id.standard(), record.frame.remote) else {
+            report.skipped += 1;
+            continue;
+        };
+
+        let result = decode(id, record.frame.payload());
+        if let Err(Error::Unrecognized) = result {
+            report.unrecognized += 1;
+            continue;
+        }
+
+        let stats = report.frames.entry(id).or_default();
+        stats.frames += 1;
+        match result {
+            Ok(message) => {
+                stats.parsed += 1;
+                if !message.is_known() {
+                    stats.undocumented += 1;
+                }
+            }
+            Err(Error::Truncated) => stats.truncated += 1,
+            Err(_) => {
+                stats.invalid += 1;
+                stats.first_invalid.get_or_insert(index + 1);
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{io, string::ToString};
+
+    use super::{check, FrameStats};
+
+    use crate::aee2004::conf;
+
+    #[test]
+    fn test_check() {
+        let capture = "\
+(0.000000) can0 0E6#552C1582267C80
+
+(0.010000) can0 0E6#552C1582267C
+# 24:00 and 25:00.
+(0.020000) can0 228#180
+(0.030000) can0 228#1900
+(0.040000) can0 1D0#4040024080030A
+(0.050000) can0 1D0#40400C4080030A
+(0.060000) can0 0E6#R
+(0.070000) can0 7DF#0201
+";
+        let report = check(capture.as_bytes(), conf::Message::parse);
+        assert_eq!(
+            report.map_err(|err| err.kind()).err(),
+            Some(io::ErrorKind::InvalidData)
+        );
+
+        let capture = capture.replace("#180\n", "#1800\n");
+        let report = check(capture.as_bytes(), conf::Message::parse).unwrap();
+        assert_eq!(report.frames.len(), 3);
+        assert_eq!(
+            report.get(0x0e6),
+            Some(&FrameStats {
+                frames: 2,
+                parsed: 1,
+                truncated: 1,
+                ..FrameStats::default()
+            })
+        );
+        assert_eq!(report.get(0x228).unwrap().first_invalid, Some(5));
+        assert_eq!(report.get(0x1d0).unwrap().undocumented, 1);
+        assert_eq!(report.get(0x1d0).unwrap().coverage(), 1.0);
+        assert_eq!((report.unrecognized, report.skipped), (1, 1));
+        assert_eq!(report.invalid(), 2);
+        assert!(report
+            .to_string()
+            .starts_with("x0e6: 2 frames, 1 parsed, 0 undocumented, 1 truncated, 0 invalid\n"));
+    }
+}
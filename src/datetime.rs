@@ -0,0 +1,205 @@
+//! CAN date and time.
+//!
+//! Clock frames like the AEE2004 x376 or the AEE2010 x39b and x276 carry a date and time
+//! with a minute resolution, the year being counted from [YEAR_OFFSET]. [CanDateTime] holds
+//! these raw fields and validates them without any calendar library, and converts from and to
+//! [time::PrimitiveDateTime].
+
+use core::fmt;
+
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+use crate::{Error, Result, YEAR_OFFSET};
+
+/// Maximum CAN year value, years are 7-bit fields.
+pub const YEAR_MAX: u8 = 0x7f;
+
+/// Date and time as carried by CAN frames.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CanDateTime {
+    /// Year, counted from [YEAR_OFFSET].
+    pub year: u8,
+    /// Month, from 1 to 12.
+    pub month: u8,
+    /// Day of the month, from 1.
+    pub day: u8,
+    /// Hour, from 0 to 23.
+    pub hour: u8,
+    /// Minute, from 0 to 59.
+    pub minute: u8,
+}
+
+impl CanDateTime {
+    /// Create a CAN date and time from its raw fields.
+    /// Returns `Err(Error::Invalid)` if the fields do not form a valid date and time.
+    pub fn new(year: u8, month: u8, day: u8, hour: u8, minute: u8) -> Result<CanDateTime> {
+        let datetime = CanDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        };
+        datetime.check()?;
+        Ok(datetime)
+    }
+
+    /// Ensure that the fields form a valid date and time.
+    /// Returns `Err(Error::Invalid)` on e.g. a month value of 0x0e (unavailable) or a
+    /// February 30th.
+    pub fn check(&self) -> Result<()> {
+        if self.year > YEAR_MAX
+            || !(1..=12).contains(&self.month)
+            || self.day == 0
+            || self.day > days_in_month(self.full_year(), self.month)
+            || self.hour > 23
+            || self.minute > 59
+        {
+            return Err(Error::Invalid);
+        }
+        Ok(())
+    }
+
+    /// Return the year, e.g. 2022.
+    pub fn full_year(&self) -> i32 {
+        YEAR_OFFSET + i32::from(self.year)
+    }
+
+    /// Return the number of minutes elapsed since the CAN time origin, January 1st 2000 0:00.
+    /// The result is meaningless for invalid dates and times, see [CanDateTime::check].
+    pub fn minutes(&self) -> u32 {
+        let days_before_year: u32 = (YEAR_OFFSET..self.full_year())
+            .map(|year| 337 + u32::from(days_in_month(year, 2)))
+            .sum();
+        let days_before_month: u32 = (1..self.month.min(13))
+            .map(|month| u32::from(days_in_month(self.full_year(), month)))
+            .sum();
+        let days = days_before_year + days_before_month + u32::from(self.day.saturating_sub(1));
+        (days * 24 + u32::from(self.hour)) * 60 + u32::from(self.minute)
+    }
+
+    /// Return the number of minutes elapsed since midnight.
+    pub fn minute_of_day(&self) -> u16 {
+        u16::from(self.hour) * 60 + u16::from(self.minute)
+    }
+}
+
+/// Return the number of days of `month` in `year`, 0 for invalid months.
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+impl fmt::Display for CanDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:02}-{:02} {:02}:{:02}",
+            self.full_year(),
+            self.month,
+            self.day,
+            self.hour,
+            self.minute
+        )
+    }
+}
+
+impl TryFrom<CanDateTime> for PrimitiveDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: CanDateTime) -> Result<PrimitiveDateTime> {
+        datetime.check()?;
+        let month = Month::try_from(datetime.month).map_err(|_| Error::Invalid)?;
+        let date = Date::from_calendar_date(datetime.full_year(), month, datetime.day)
+            .map_err(|_| Error::Invalid)?;
+        let time = Time::from_hms(datetime.hour, datetime.minute, 0).map_err(|_| Error::Invalid)?;
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+}
+
+impl TryFrom<PrimitiveDateTime> for CanDateTime {
+    type Error = Error;
+
+    /// Convert a date and time to its CAN representation, truncated to the minute.
+    /// Returns `Err(Error::Invalid)` if the year is out of the CAN years range.
+    fn try_from(datetime: PrimitiveDateTime) -> Result<CanDateTime> {
+        let year = u8::try_from(datetime.year() - YEAR_OFFSET).map_err(|_| Error::Invalid)?;
+        CanDateTime::new(
+            year,
+            datetime.month().into(),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CanDateTime;
+
+    use crate::Error;
+
+    #[test]
+    fn test_check() {
+        assert!(CanDateTime::new(22, 2, 28, 23, 59).is_ok());
+        assert!(CanDateTime::new(24, 2, 29, 0, 0).is_ok());
+        assert_eq!(CanDateTime::new(23, 2, 29, 0, 0), Err(Error::Invalid));
+        assert_eq!(CanDateTime::new(22, 0x0e, 1, 0, 0), Err(Error::Invalid));
+        assert_eq!(CanDateTime::new(22, 4, 31, 0, 0), Err(Error::Invalid));
+        assert_eq!(CanDateTime::new(22, 4, 0, 0, 0), Err(Error::Invalid));
+        assert_eq!(CanDateTime::new(22, 4, 1, 24, 0), Err(Error::Invalid));
+        assert_eq!(CanDateTime::new(0x80, 4, 1, 0, 0), Err(Error::Invalid));
+        assert_eq!(CanDateTime::default().check(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_minutes() {
+        assert_eq!(CanDateTime::new(0, 1, 1, 0, 0).unwrap().minutes(), 0);
+        assert_eq!(CanDateTime::new(0, 1, 2, 1, 1).unwrap().minutes(), 1501);
+        // 2000 is a leap year.
+        assert_eq!(
+            CanDateTime::new(1, 1, 1, 0, 0).unwrap().minutes(),
+            366 * 24 * 60
+        );
+        let datetime = CanDateTime::new(22, 9, 6, 23, 22).unwrap();
+        assert_eq!(datetime.minutes(), 11_930_362);
+        assert_eq!(datetime.minute_of_day(), 1402);
+    }
+
+    #[test]
+    fn test_time_conversion() {
+        use time::{macros::datetime, PrimitiveDateTime};
+
+        let can_datetime = CanDateTime::new(22, 9, 6, 23, 22).unwrap();
+        let datetime = PrimitiveDateTime::try_from(can_datetime).unwrap();
+        assert_eq!(datetime, datetime!(2022-09-06 23:22));
+        assert_eq!(CanDateTime::try_from(datetime), Ok(can_datetime));
+        assert_eq!(
+            CanDateTime::try_from(datetime!(2022-09-06 23:22:59)),
+            Ok(can_datetime)
+        );
+        assert_eq!(
+            CanDateTime::try_from(datetime!(1999-12-31 23:59)),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            CanDateTime::try_from(datetime!(2128-01-01 0:00)),
+            Err(Error::Invalid)
+        );
+        assert_eq!(
+            PrimitiveDateTime::try_from(CanDateTime {
+                day: 31,
+                month: 11,
+                ..can_datetime
+            }),
+            Err(Error::Invalid)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Vector DBC export.
+//!
+//! [to_dbc] describes frames, e.g. the frames of a bus listed by the [registry](crate::registry),
+//! in the Vector DBC format understood by most CAN analysis tools:
+//!
+//! ```
+//! # use canpsa::{dbc, registry};
+//! let dbc = dbc::to_dbc(registry::aee2010::FRAMES);
+//! assert!(dbc.contains("BO_ 182 x0b6: 8 Vector__XXX"));
+//! ```
+//!
+//! Frames are named after their module, signals after the `Repr` field they are decoded into,
+//! and the frame periodicities are exported as the `GenMsgCycleTime` attribute.
+
+use core::fmt::{self, Write};
+
+use std::string::String;
+
+use crate::{
+    registry::FrameInfo,
+    signal::{ByteOrder, Signal},
+};
+
+/// Name of the DBC node sending or receiving the frames, the frames are not assigned to ECUs.
+const NODE: &str = "Vector__XXX";
+
+fn write_signal<W: Write>(w: &mut W, signal: &Signal) -> fmt::Result {
+    let byte_order = match signal.byte_order {
+        ByteOrder::BigEndian => 0,
+        ByteOrder::LittleEndian => 1,
+    };
+    let sign = if signal.signed { '-' } else { '+' };
+    let (min, max) = signal.range();
+    writeln!(
+        w,
+        " SG_ {} : {}|{}@{}{} ({},{}) [{}|{}] \"{}\" {}",
+        signal.name,
+        signal.start_bit(),
+        signal.len,
+        byte_order,
+        sign,
+        signal.factor,
+        signal.offset,
+        min,
+        max,
+        signal.unit,
+        NODE
+    )
+}
+
+/// Write the DBC description of `frames` into `w`.
+pub fn write_dbc<W: Write>(w: &mut W, frames: &[FrameInfo]) -> fmt::Result {
+    writeln!(w, "VERSION \"\"\n\n")?;
+    writeln!(w, "NS_ :\n")?;
+    writeln!(w, "BS_:\n")?;
+    writeln!(w, "BU_:\n\n")?;
+
+    for frame in frames {
+        writeln!(
+            w,
+            "BO_ {} x{:03x}: {} {}",
+            frame.id, frame.id, frame.len, NODE
+        )?;
+        for signal in frame.signals {
+            write_signal(w, signal)?;
+        }
+        writeln!(w)?;
+    }
+
+    writeln!(w)?;
+    writeln!(w, "BA_DEF_ BO_ \"GenMsgCycleTime\" INT 0 65535;")?;
+    writeln!(w, "BA_DEF_DEF_ \"GenMsgCycleTime\" 0;")?;
+    for frame in frames {
+        if let Some(periodicity) = frame.periodicity {
+            writeln!(
+                w,
+                "BA_ \"GenMsgCycleTime\" BO_ {} {};",
+                frame.id,
+                periodicity.as_millis()
+            )?;
+        }
+    }
+
+    for frame in frames {
+        for signal in frame.signals.iter().filter(|s| !s.values.is_empty()) {
+            write!(w, "VAL_ {} {}", frame.id, signal.name)?;
+            for (raw, name) in signal.values {
+                write!(w, " {} \"{}\"", raw, name)?;
+            }
+            writeln!(w, " ;")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the DBC description of `frames`.
+pub fn to_dbc(frames: &[FrameInfo]) -> String {
+    let mut dbc = String::new();
+    // Writing into a `String` never fails.
+    let _ = write_dbc(&mut dbc, frames);
+    dbc
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::to_dbc;
+
+    use crate::{frame::TransmissionKind, registry, registry::FrameInfo, signal::Signal};
+
+    static SIGNALS: &[Signal] = &[
+        Signal::new("speed", 0, 7, 16)
+            .scaled(0.01, 0.0)
+            .unit("km/h"),
+        Signal::new("validity", 2, 0, 1).values(&[(0, "Valid"), (1, "Invalid")]),
+        Signal::new("journal", 3, 0, 8).little_endian().signed(),
+    ];
+
+    #[test]
+    fn test_to_dbc() {
+        let frames = [FrameInfo {
+            id: 0x0b6,
+            len: 4,
+            periodicity: Some(Duration::from_millis(50)),
+            transmission: TransmissionKind::Periodic(Duration::from_millis(50)),
+            signals: SIGNALS,
+        }];
+        assert_eq!(
+            to_dbc(&frames),
+            "\
+VERSION \"\"
+
+
+NS_ :
+
+BS_:
+
+BU_:
+
+
+BO_ 182 x0b6: 4 Vector__XXX
+ SG_ speed : 7|16@0+ (0.01,0) [0|655.35] \"km/h\" Vector__XXX
+ SG_ validity : 16|1@0+ (1,0) [0|1] \"\" Vector__XXX
+ SG_ journal : 24|8@1- (1,0) [-128|127] \"\" Vector__XXX
+
+
+BA_DEF_ BO_ \"GenMsgCycleTime\" INT 0 65535;
+BA_DEF_DEF_ \"GenMsgCycleTime\" 0;
+BA_ \"GenMsgCycleTime\" BO_ 182 50;
+VAL_ 182 validity 0 \"Valid\" 1 \"Invalid\" ;
+"
+        );
+    }
+
+    #[test]
+    fn test_registry_to_dbc() {
+        let dbc = to_dbc(registry::aee2004::FRAMES);
+        assert!(dbc.contains("BO_ 246 x0f6: 8 Vector__XXX\n"));
+        assert!(dbc.contains(
+            " SG_ coolant_temperature : 15|8@0+ (1,-40) [-40|215] \"degC\" Vector__XXX\n"
+        ));
+        assert!(dbc.contains("BA_ \"GenMsgCycleTime\" BO_ 935 500;\n"));
+        assert!(dbc.contains("BA_ \"GenMsgCycleTime\" BO_ 246 500;\n"));
+        assert!(!dbc.contains("BA_ \"GenMsgCycleTime\" BO_ 424 "));
+    }
+}
@@ -0,0 +1,264 @@
+//! Filtered streaming decoder.
+//!
+//! Gateways and loggers usually care about a handful of frames out of the thousands received
+//! every second. The [Decoder] only parses the frames whose identifier is in its [IdFilter], a
+//! bitset over the 11-bit CAN identifiers, so the other frames are skipped with a single bit
+//! test instead of going through the `Message` parsing of the bus. It does not allocate.
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::{x0b6, x0e6, Message}, decode::{Decoder, IdFilter}};
+//! let filter = IdFilter::from_ids(&[x0b6::FRAME_ID]).unwrap();
+//! let mut decoder: Decoder<Message> = Decoder::new(filter);
+//! assert!(matches!(
+//!     decoder.feed(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES),
+//!     Some(Message::X0b6(_))
+//! ));
+//! assert_eq!(decoder.feed(x0e6::FRAME_ID, &x0e6::IDLE_FRAME_BYTES), None);
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use crate::{frame::BusMessage, registry::FrameInfo, Error, Result};
+
+/// Number of 11-bit CAN frame identifiers.
+pub const ID_COUNT: usize = 0x800;
+
+const WORDS: usize = ID_COUNT / 32;
+
+/// A set of 11-bit CAN frame identifiers.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IdFilter {
+    bits: [u32; WORDS],
+}
+
+impl IdFilter {
+    /// Create an empty filter, rejecting every identifier.
+    pub const fn new() -> IdFilter {
+        IdFilter { bits: [0; WORDS] }
+    }
+
+    /// Create a filter accepting every identifier.
+    pub const fn all() -> IdFilter {
+        IdFilter {
+            bits: [u32::MAX; WORDS],
+        }
+    }
+
+    /// Create a filter accepting the identifiers `ids`.
+    /// Returns `Err(Error::Illegal)` if one of them is not an 11-bit identifier.
+    pub fn from_ids(ids: &[u16]) -> Result<IdFilter> {
+        let mut filter = IdFilter::new();
+        for &id in ids {
+            filter.insert(id)?;
+        }
+        Ok(filter)
+    }
+
+    /// Create a filter accepting the identifiers of the frames `frames`, e.g. a bus
+    /// `FRAMES` table of the [registry](crate::registry).
+    pub fn from_frames(frames: &[FrameInfo]) -> IdFilter {
+        let mut filter = IdFilter::new();
+        for frame in frames {
+            // Frame identifiers of the registry are 11-bit identifiers.
+            let _ = filter.insert(frame.id);
+        }
+        filter
+    }
+
+    /// Accept the identifier `id`.
+    /// Returns `Err(Error::Illegal)` if `id` is not an 11-bit identifier.
+    pub fn insert(&mut self, id: u16) -> Result<()> {
+        let (word, bit) = Self::position(id).ok_or(Error::Illegal)?;
+        self.bits[word] |= bit;
+        Ok(())
+    }
+
+    /// Reject the identifier `id`.
+    pub fn remove(&mut self, id: u16) {
+        if let Some((word, bit)) = Self::position(id) {
+            self.bits[word] &= !bit;
+        }
+    }
+
+    /// Return whether the identifier `id` is accepted.
+    #[inline]
+    pub fn contains(&self, id: u16) -> bool {
+        match Self::position(id) {
+            Some((word, bit)) => self.bits[word] & bit != 0,
+            None => false,
+        }
+    }
+
+    /// Return the number of accepted identifiers.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Return whether the filter rejects every identifier.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// Return the accepted identifiers, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        (0..ID_COUNT as u16).filter(move |&id| self.contains(id))
+    }
+
+    #[inline]
+    fn position(id: u16) -> Option<(usize, u32)> {
+        let id = usize::from(id);
+        if id < ID_COUNT {
+            Some((id / 32, 1 << (id % 32)))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for IdFilter {
+    fn default() -> IdFilter {
+        IdFilter::new()
+    }
+}
+
+impl fmt::Debug for IdFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut set = f.debug_set();
+        for id in self.iter() {
+            set.entry(&format_args!("x{:03x}", id));
+        }
+        set.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for IdFilter {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "IdFilter({=usize} ids)", self.len())
+    }
+}
+
+/// A decoder parsing the frames of a bus accepted by its [IdFilter].
+///
+/// `M` is the `Message` enum of the bus.
+#[derive(Debug, Clone)]
+pub struct Decoder<M> {
+    filter: IdFilter,
+    errors: u32,
+    _message: PhantomData<M>,
+}
+
+impl<M: BusMessage> Decoder<M> {
+    /// Create a decoder parsing the frames accepted by `filter`.
+    pub fn new(filter: IdFilter) -> Decoder<M> {
+        Decoder {
+            filter,
+            errors: 0,
+            _message: PhantomData,
+        }
+    }
+
+    /// Return the filter of the decoder.
+    pub fn filter(&self) -> &IdFilter {
+        &self.filter
+    }
+
+    /// Return a mutable reference to the filter of the decoder.
+    pub fn filter_mut(&mut self) -> &mut IdFilter {
+        &mut self.filter
+    }
+
+    /// Parse the payload `data` of the CAN frame with identifier `id`.
+    ///
+    /// Returns `None` if `id` is not accepted by the filter, or if the frame cannot be
+    /// parsed, in which case the [errors](Decoder::errors) counter is incremented.
+    #[inline]
+    pub fn feed(&mut self, id: u16, data: &[u8]) -> Option<M> {
+        if !self.filter.contains(id) {
+            return None;
+        }
+
+        match M::parse(id, data) {
+            Ok(message) => Some(message),
+            Err(_) => {
+                self.errors = self.errors.saturating_add(1);
+                None
+            }
+        }
+    }
+
+    /// Return the number of accepted frames which could not be parsed, e.g. truncated frames
+    /// or frames not supported by this crate.
+    pub fn errors(&self) -> u32 {
+        self.errors
+    }
+
+    /// Reset the [errors](Decoder::errors) counter.
+    pub fn reset_errors(&mut self) {
+        self.errors = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Decoder, IdFilter, ID_COUNT};
+
+    use crate::{
+        aee2004::conf::{self, x0b6, x0e6, x260},
+        registry, Error,
+    };
+
+    #[test]
+    fn test_filter() {
+        let mut filter = IdFilter::new();
+        assert!(filter.is_empty());
+        assert_eq!(filter.insert(0x000), Ok(()));
+        assert_eq!(filter.insert(0x7ff), Ok(()));
+        assert_eq!(filter.insert(0x800), Err(Error::Illegal));
+        assert!(filter.contains(0x000));
+        assert!(filter.contains(0x7ff));
+        assert!(!filter.contains(0x001));
+        assert!(!filter.contains(0x800));
+        assert_eq!(filter.len(), 2);
+
+        filter.remove(0x000);
+        filter.remove(0xffff);
+        assert_eq!(filter.iter().collect::<heapless::Vec<u16, 4>>(), [0x7ff]);
+
+        assert_eq!(IdFilter::all().len(), ID_COUNT);
+        assert_eq!(IdFilter::from_ids(&[0x0b6, 0x900]), Err(Error::Illegal));
+
+        let filter = IdFilter::from_frames(registry::aee2004::FRAMES);
+        assert_eq!(filter.len(), registry::aee2004::FRAMES.len());
+        assert!(filter.contains(x260::FRAME_ID));
+    }
+
+    #[test]
+    fn test_decoder() {
+        let filter = IdFilter::from_ids(&[x0b6::FRAME_ID, 0x123]).unwrap();
+        let mut decoder: Decoder<conf::Message> = Decoder::new(filter);
+
+        assert!(matches!(
+            decoder.feed(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES),
+            Some(conf::Message::X0b6(_))
+        ));
+        assert_eq!(decoder.feed(x0e6::FRAME_ID, &x0e6::IDLE_FRAME_BYTES), None);
+        assert_eq!(decoder.errors(), 0);
+
+        // Accepted but truncated, then accepted but unsupported.
+        assert_eq!(decoder.feed(x0b6::FRAME_ID, &[0x00; 3]), None);
+        assert_eq!(decoder.feed(0x123, &[0x00; 8]), None);
+        assert_eq!(decoder.errors(), 2);
+        decoder.reset_errors();
+        assert_eq!(decoder.errors(), 0);
+
+        decoder.filter_mut().insert(x0e6::FRAME_ID).unwrap();
+        assert!(matches!(
+            decoder.feed(x0e6::FRAME_ID, &x0e6::IDLE_FRAME_BYTES),
+            Some(conf::Message::X0e6(_))
+        ));
+    }
+}
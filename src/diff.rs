@@ -0,0 +1,108 @@
+//! Change detection between frame representations.
+//!
+//! Every frame `Repr` implements [Diff], which lists the fields changed between two
+//! representations of the same frame, e.g. to only redraw the parts of a display which
+//! depend on the changed fields:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::x0b6, diff::Diff};
+//! let old = x0b6::Repr::builder().build().unwrap();
+//! let new = x0b6::Repr::builder().with_trip_odometer(1234).build().unwrap();
+//! let changed: Vec<&str> = old.diff(&new).map(|change| change.name).collect();
+//! assert_eq!(changed, ["trip_odometer"]);
+//! ```
+
+use core::fmt;
+
+/// A changed field descriptor.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Change {
+    /// Index of the field in [Diff::FIELDS].
+    pub index: usize,
+    /// Name of the field.
+    pub name: &'static str,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Field by field comparison of two representations of the same frame.
+pub trait Diff: Sized {
+    /// Names of the representation fields, in declaration order.
+    const FIELDS: &'static [&'static str];
+
+    /// Return whether the field `name` differs between `self` and `other`.
+    /// Returns `false` for unknown fields.
+    fn field_changed(&self, other: &Self, name: &str) -> bool;
+
+    /// Return an iterator over the fields which differ between `self` and `other`.
+    fn diff<'a>(&'a self, other: &'a Self) -> Changes<'a, Self> {
+        Changes {
+            old: self,
+            new: other,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the fields changed between two representations, see [Diff::diff].
+#[derive(Debug, Clone)]
+pub struct Changes<'a, R> {
+    old: &'a R,
+    new: &'a R,
+    index: usize,
+}
+
+impl<'a, R: Diff> Iterator for Changes<'a, R> {
+    type Item = Change;
+
+    fn next(&mut self) -> Option<Change> {
+        while let Some(&name) = R::FIELDS.get(self.index) {
+            let index = self.index;
+            self.index += 1;
+            if self.old.field_changed(self.new, name) {
+                return Some(Change { index, name });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Change, Diff};
+
+    use crate::aee2010::infodiv::x0e6;
+
+    #[test]
+    fn test_diff() {
+        let old = x0e6::Repr::builder().build().unwrap();
+        assert_eq!(old.diff(&old).next(), None);
+
+        let new = x0e6::Repr::builder()
+            .with_worn_brake_pad(!old.worn_brake_pad)
+            .with_checksum_computation_counter(0)
+            .build()
+            .unwrap();
+        let mut changes = old.diff(&new);
+        assert_eq!(
+            changes.next(),
+            Some(Change {
+                index: 2,
+                name: "worn_brake_pad"
+            })
+        );
+        assert_eq!(
+            changes.next().map(|c| c.name),
+            Some("checksum_computation_counter")
+        );
+        assert_eq!(changes.next(), None);
+
+        assert_eq!(x0e6::Repr::FIELDS.len(), 18);
+        assert!(!old.field_changed(&new, "unknown"));
+    }
+}
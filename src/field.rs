@@ -0,0 +1,99 @@
+//! Field access helpers shared by the frame modules.
+//!
+//! Multi-byte signals are located like in the frame `SIGNALS` tables: big endian (Motorola)
+//! signals by their most significant bit, as a byte index and a bit position in that byte, 0
+//! being the least significant bit. The helpers below read and write such signals with the
+//! same coordinates as their [Signal](crate::signal::Signal), instead of shifting and masking
+//! in each frame.
+
+pub type Field = ::core::ops::Range<usize>;
+pub type _Rest = ::core::ops::RangeFrom<usize>;
+
+/// Return the mask of a signal of `len` bits, from 1 to 64.
+#[inline]
+const fn mask(len: u8) -> u128 {
+    (u64::MAX >> (64 - len)) as u128
+}
+
+/// Return the index of the last byte of a big endian signal of `len` bits whose most
+/// significant bit is bit `bit` of byte `byte`, and the position of its least significant bit
+/// from the least significant bit of that byte.
+#[inline]
+const fn be_span(byte: usize, bit: u8, len: u8) -> (usize, usize) {
+    let end = byte + (len as usize + 6 - bit as usize) / 8;
+    let lsb = (end - byte) * 8 + bit as usize + 1 - len as usize;
+    (end, lsb)
+}
+
+/// Return the raw value of the big endian (Motorola) signal of `len` bits whose most
+/// significant bit is bit `bit` of `data[byte]`.
+///
+/// # Panics
+/// This function panics if the signal does not fit in `data` or is longer than 64 bits.
+#[inline]
+pub fn read_be(data: &[u8], byte: usize, bit: u8, len: u8) -> u64 {
+    let (end, lsb) = be_span(byte, bit, len);
+    let raw = data[byte..=end]
+        .iter()
+        .fold(0u128, |raw, byte| (raw << 8) | u128::from(*byte));
+    ((raw >> lsb) & mask(len)) as u64
+}
+
+/// Write `value` into the big endian (Motorola) signal of `len` bits whose most significant
+/// bit is bit `bit` of `data[byte]`. Bits of `value` beyond `len` are ignored, and the bits
+/// of `data` outside of the signal are kept.
+///
+/// # Panics
+/// This function panics if the signal does not fit in `data` or is longer than 64 bits.
+#[inline]
+pub fn write_be(data: &mut [u8], byte: usize, bit: u8, len: u8, value: u64) {
+    let (end, lsb) = be_span(byte, bit, len);
+    let bytes = &mut data[byte..=end];
+    let raw = bytes
+        .iter()
+        .fold(0u128, |raw, byte| (raw << 8) | u128::from(*byte));
+    let mut raw = (raw & !(mask(len) << lsb)) | ((u128::from(value) & mask(len)) << lsb);
+    for byte in bytes.iter_mut().rev() {
+        *byte = raw as u8;
+        raw >>= 8;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{read_be, write_be};
+
+    use crate::signal::Signal;
+
+    static BYTES: [u8; 8] = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0];
+
+    #[test]
+    fn test_big_endian() {
+        assert_eq!(read_be(&BYTES, 0, 7, 16), 0x1234);
+        assert_eq!(read_be(&BYTES, 1, 7, 24), 0x0034_5678);
+        assert_eq!(read_be(&BYTES, 2, 6, 15), 0x5678);
+        assert_eq!(read_be(&BYTES, 0, 3, 8), 0x23);
+        assert_eq!(read_be(&BYTES, 7, 7, 4), 0xf);
+
+        let mut bytes = BYTES;
+        write_be(&mut bytes, 2, 6, 15, 0xffff);
+        assert_eq!(bytes[2..4], [0x7f, 0xff]);
+        write_be(&mut bytes, 2, 6, 15, 0);
+        assert_eq!(bytes[2..4], [0x00, 0x00]);
+        write_be(&mut bytes, 0, 3, 8, 0xab);
+        assert_eq!(bytes[..2], [0x1a, 0xb4]);
+        assert_eq!(bytes[4..], BYTES[4..]);
+    }
+
+    #[test]
+    fn test_signal_coordinates() {
+        for (byte, bit, len) in [(0, 7, 16), (1, 3, 12), (2, 6, 15), (5, 0, 1), (3, 7, 32)] {
+            let signal = Signal::new("be", byte, bit, len);
+            let raw = read_be(&BYTES, byte.into(), bit, len);
+            assert_eq!(Some(raw), signal.raw(&BYTES));
+        }
+
+        let signal = Signal::new("be", 0, 7, 64);
+        assert_eq!(Some(read_be(&BYTES, 0, 7, 64)), signal.raw(&BYTES));
+    }
+}
@@ -0,0 +1,259 @@
+//! Simulated frame sequences.
+//!
+//! Testing a dashboard or a gateway against a bench or a capture is not always possible.
+//! This module generates realistic AEE2010 INFODIV traffic for a few common scenarios, each
+//! frame being emitted at its periodicity:
+//!
+//! - [engine_start]: ignition on, cranking and idling (x0f6, x0b6).
+//! - [constant_speed]: a drive cycle at constant speed (x0f6, x0b6).
+//! - [ac_adjustments]: fan speed and temperature adjustments (x350).
+//!
+//! A sequence is a list of `(timestamp, identifier, payload)` tuples, ordered by timestamp.
+//! Payloads shorter than 8 bytes are padded with zeros, see [registry::aee2010::frame_len]
+//! to get the actual frame length:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::x0b6, fixtures};
+//! let sequence = fixtures::engine_start();
+//! let (_, _, payload) = sequence.iter().rev().find(|(_, id, _)| *id == x0b6::FRAME_ID).unwrap();
+//! let frame = x0b6::Frame::new_checked(&payload[..x0b6::FRAME_LEN]).unwrap();
+//! assert_eq!(frame.engine_rpm(), 8000);
+//! ```
+//!
+//! [registry::aee2010::frame_len]: crate::registry::aee2010::frame_len
+
+use core::time::Duration;
+
+use std::vec::Vec;
+
+use crate::{
+    aee2010::infodiv::{x0b6, x0f6, x350},
+    units::KilometersPerHour,
+    vehicle::{ACAirTemperature, ACFanSpeed, MainStatus, PowertrainStatus},
+};
+
+/// A timestamped sequence of `(timestamp, identifier, payload)` frames.
+pub type Sequence = Vec<(Duration, u16, [u8; 8])>;
+
+/// Engine speed while cranking, in rpm.
+const CRANKING_RPM: u16 = 250;
+/// Engine speed while idling, in rpm.
+const IDLE_RPM: u16 = 800;
+/// Engine speed per km/h while driving, in rpm.
+const RPM_PER_KPH: u16 = 25;
+
+/// Generate a 3 seconds engine start: the vehicle is switched on and the engine stopped
+/// during the first second, then the engine cranks for half a second and idles.
+pub fn engine_start() -> Sequence {
+    let cranking = Duration::from_secs(1);
+    let running = Duration::from_millis(1500);
+    let state = |time: Duration| {
+        if time < cranking {
+            (PowertrainStatus::Stopped, 0)
+        } else if time < running {
+            (PowertrainStatus::Cranking, CRANKING_RPM)
+        } else {
+            (PowertrainStatus::Running, IDLE_RPM)
+        }
+    };
+
+    let duration = Duration::from_secs(3);
+    let mut sequence = Sequence::new();
+    periodic(
+        &mut sequence,
+        x0f6::FRAME_ID,
+        x0f6::PERIODICITY,
+        duration,
+        |time| {
+            let (powertrain, _) = state(time);
+            let status = if powertrain == PowertrainStatus::Cranking {
+                MainStatus::Cranking
+            } else {
+                MainStatus::On
+            };
+            x0f6_payload(powertrain, status)
+        },
+    );
+    periodic(
+        &mut sequence,
+        x0b6::FRAME_ID,
+        x0b6::PERIODICITY,
+        duration,
+        |time| x0b6_payload(state(time).1, 0, 0),
+    );
+    sort(sequence)
+}
+
+/// Generate a drive cycle at constant `speed` lasting `duration`. The trip odometer starts
+/// at zero and increases with the traveled distance.
+pub fn constant_speed(speed: KilometersPerHour, duration: Duration) -> Sequence {
+    let rpm = IDLE_RPM.saturating_add(speed.0.saturating_mul(RPM_PER_KPH));
+
+    let mut sequence = Sequence::new();
+    periodic(
+        &mut sequence,
+        x0f6::FRAME_ID,
+        x0f6::PERIODICITY,
+        duration,
+        |_| x0f6_payload(PowertrainStatus::Running, MainStatus::On),
+    );
+    periodic(
+        &mut sequence,
+        x0b6::FRAME_ID,
+        x0b6::PERIODICITY,
+        duration,
+        |time| {
+            // km/h to cm/ms is a 10 / 36 ratio, the trip odometer wraps around.
+            let distance = u128::from(speed.0) * time.as_millis() * 10 / 36;
+            x0b6_payload(rpm, speed.0, distance as u16)
+        },
+    );
+    sort(sequence)
+}
+
+/// Generate a 5 seconds sequence of A/C adjustments: the front fan speed is raised from 3
+/// to 5 after one second, then both front temperatures from 20°C to 22°C after three
+/// seconds.
+pub fn ac_adjustments() -> Sequence {
+    let mut sequence = Sequence::new();
+    periodic(
+        &mut sequence,
+        x350::FRAME_ID,
+        x350::PERIODICITY,
+        Duration::from_secs(5),
+        |time| {
+            let fan_speed = if time < Duration::from_secs(1) {
+                ACFanSpeed::Speed3
+            } else {
+                ACFanSpeed::Speed5
+            };
+            let temperature = if time < Duration::from_secs(3) {
+                ACAirTemperature::Twenty
+            } else {
+                ACAirTemperature::TwentyTwo
+            };
+
+            let mut payload = [0u8; 8];
+            payload[..x350::FRAME_LEN].copy_from_slice(&x350::IDLE_FRAME_BYTES);
+            let mut frame = x350::Frame::new_unchecked(&mut payload[..x350::FRAME_LEN]);
+            frame.set_front_fan_speed(fan_speed);
+            frame.set_front_left_temp(temperature);
+            frame.set_front_right_temp(temperature);
+            payload
+        },
+    );
+    sequence
+}
+
+/// Push the frames `id` emitted every `period` from zero to `duration` into `sequence`,
+/// their payload being returned by `payload` for the emission time.
+fn periodic<F>(
+    sequence: &mut Sequence,
+    id: u16,
+    period: Duration,
+    duration: Duration,
+    mut payload: F,
+) where
+    F: FnMut(Duration) -> [u8; 8],
+{
+    let mut time = Duration::ZERO;
+    while time < duration {
+        sequence.push((time, id, payload(time)));
+        time += period;
+    }
+}
+
+/// Order `sequence` by timestamp, frames emitted at the same time keeping their order.
+fn sort(mut sequence: Sequence) -> Sequence {
+    sequence.sort_by_key(|(time, _, _)| *time);
+    sequence
+}
+
+/// Return a x0f6 payload with the given powertrain and vehicle states.
+fn x0f6_payload(powertrain: PowertrainStatus, status: MainStatus) -> [u8; 8] {
+    let mut payload = x0f6::IDLE_FRAME_BYTES;
+    let mut frame = x0f6::Frame::new_unchecked(&mut payload);
+    frame.set_powertrain_status(powertrain);
+    frame.set_vehicle_main_status(status);
+    payload
+}
+
+/// Return a x0b6 payload with the given engine speed in rpm, vehicle speed in km/h and trip
+/// odometer in centimeters.
+fn x0b6_payload(rpm: u16, speed: u16, trip_odometer: u16) -> [u8; 8] {
+    let mut payload = x0b6::IDLE_FRAME_BYTES;
+    let mut frame = x0b6::Frame::new_unchecked(&mut payload);
+    frame.set_engine_rpm(rpm.saturating_mul(10));
+    frame.set_vehicle_immediate_speed(speed.saturating_mul(100));
+    frame.set_trip_odometer(trip_odometer);
+    frame.set_trip_fuel_consumption(0);
+    payload
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use std::vec::Vec;
+
+    use super::{ac_adjustments, constant_speed, engine_start};
+
+    use crate::{
+        aee2010::infodiv::{x0b6, x0f6, x350},
+        units::KilometersPerHour,
+        vehicle::{ACAirTemperature, ACFanSpeed, PowertrainStatus},
+    };
+
+    #[test]
+    fn test_engine_start() {
+        let sequence = engine_start();
+        assert!(sequence.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert_eq!(
+            sequence.iter().filter(|f| f.1 == x0b6::FRAME_ID).count(),
+            60
+        );
+        assert_eq!(sequence.iter().filter(|f| f.1 == x0f6::FRAME_ID).count(), 6);
+
+        let states: Vec<_> = sequence
+            .iter()
+            .filter(|f| f.1 == x0f6::FRAME_ID)
+            .map(|f| x0f6::Frame::new_unchecked(&f.2).powertrain_status())
+            .collect();
+        assert_eq!(
+            states,
+            [
+                PowertrainStatus::Stopped,
+                PowertrainStatus::Stopped,
+                PowertrainStatus::Cranking,
+                PowertrainStatus::Running,
+                PowertrainStatus::Running,
+                PowertrainStatus::Running,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_constant_speed() {
+        let sequence = constant_speed(KilometersPerHour(90), Duration::from_secs(2));
+        let (time, _, payload) = sequence
+            .iter()
+            .rev()
+            .find(|f| f.1 == x0b6::FRAME_ID)
+            .unwrap();
+        assert_eq!(*time, Duration::from_millis(1950));
+        let frame = x0b6::Frame::new_checked(&payload[..]).unwrap();
+        assert_eq!(frame.vehicle_immediate_speed(), 9000);
+        assert_eq!(frame.engine_rpm(), 30500);
+        assert_eq!(frame.trip_odometer(), 48750);
+    }
+
+    #[test]
+    fn test_ac_adjustments() {
+        let sequence = ac_adjustments();
+        assert_eq!(sequence.len(), 10);
+        let frame = x350::Frame::new_unchecked(&sequence[9].2);
+        assert_eq!(frame.front_fan_speed(), ACFanSpeed::Speed5);
+        assert_eq!(frame.front_left_temp(), ACAirTemperature::TwentyTwo);
+        assert_eq!(frame.front_right_temp(), ACAirTemperature::TwentyTwo);
+    }
+}
@@ -0,0 +1,702 @@
+//! Generic frame access.
+//!
+//! Every frame `Repr` listed in the [registry](crate::registry) implements [PsaFrame], which
+//! exposes the frame identifier, length and [transmission](TransmissionKind) as well as
+//! parsing from and emitting into raw payloads. Gateways, schedulers or loggers can then be
+//! written once for any frame:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::x0b6, frame::PsaFrame};
+//! fn forward<F: PsaFrame>(payload: &[u8], out: &mut [u8]) -> canpsa::Result<u16> {
+//!     F::parse(payload)?.emit(out)?;
+//!     Ok(F::ID)
+//! }
+//!
+//! let mut out = [0u8; x0b6::FRAME_LEN];
+//! assert_eq!(forward::<x0b6::Repr>(&x0b6::IDLE_FRAME_BYTES, &mut out), Ok(0x0b6));
+//! ```
+//!
+//! Likewise, the `Message` enums of the buses implement [BusMessage].
+//!
+//! [PsaFrame::parse_with] and the `Repr::parse_with` methods take [ParseOptions], to either
+//! decode the frames of noisy buses permissively, or to validate them against the frame
+//! `SIGNALS` tables for conformance testing.
+//!
+//! Classic frames padded into longer CAN FD frames are parsed by disabling
+//! [ParseOptions::strict_len], or with the `Repr::parse_padded` methods of the frame modules.
+//! `Repr::parse` rejects them with [Error::Overlong](crate::Error::Overlong), even when
+//! wrapped by the `Frame::new_padded` constructors.
+//!
+//! Payloads of a known length, e.g. copied out of a CAN controller mailbox, can be wrapped into
+//! the `FixedFrame` of the frame modules instead, whose length is checked at compile time:
+//!
+//! ```
+//! # use canpsa::aee2010::infodiv::x0b6;
+//! let frame = x0b6::FixedFrame::from_array(x0b6::IDLE_FRAME_BYTES);
+//! let repr = x0b6::Repr::parse_fixed(&frame).unwrap();
+//! assert_eq!(x0b6::FixedFrame::from(&repr), frame);
+//! ```
+//!
+//! The frame representations display on a single `key=value` line, suitable for logs, or one
+//! field per line with the alternate flag:
+//!
+//! ```
+//! # use canpsa::aee2004::conf::x0b6;
+//! let repr = x0b6::Repr::default();
+//! assert!(!format!("{}", repr).contains('\n'));
+//! assert!(format!("{:#}", repr).starts_with("x0b6\n "));
+//! ```
+//!
+//! Without an allocator, `Repr::write_to` renders either [Style] into any
+//! [fmt::Write] sink, such as a [TruncatingWriter] over a fixed buffer.
+//!
+//! The `Frame` setters of fields whose emission can tamper with the vehicle records, the
+//! odometer of x0f6 and the VIN characters of x336, x2b6 and x3b6, take a [DangerToken], only
+//! available with the `unchecked-writes` feature.
+
+use core::{fmt, time::Duration};
+
+use crate::{signal::Signal, Result};
+
+#[cfg(feature = "detailed-errors")]
+use crate::{DetailedError, Error};
+
+/// Transmission of a CAN frame by its emitter.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TransmissionKind {
+    /// The frame is sent at a fixed period.
+    Periodic(Duration),
+    /// The frame is sent when its content changes, e.g. a status or a warning.
+    OnChange,
+    /// The frame is sent on request, e.g. a user action or a command to another ECU.
+    OnRequest,
+    /// The frame transmission is not known, i.e. the frame may be sent periodically or on
+    /// event.
+    Unknown,
+}
+
+impl TransmissionKind {
+    /// Return the frame periodicity, `None` for event-driven frames and frames of unknown
+    /// transmission.
+    pub const fn periodicity(&self) -> Option<Duration> {
+        match *self {
+            TransmissionKind::Periodic(periodicity) => Some(periodicity),
+            TransmissionKind::OnChange
+            | TransmissionKind::OnRequest
+            | TransmissionKind::Unknown => None,
+        }
+    }
+
+    /// Return whether the frame is sent at a fixed period.
+    pub const fn is_periodic(&self) -> bool {
+        matches!(*self, TransmissionKind::Periodic(_))
+    }
+}
+
+impl fmt::Display for TransmissionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransmissionKind::Periodic(periodicity) => {
+                write!(f, "periodic ({} ms)", periodicity.as_millis())
+            }
+            TransmissionKind::OnChange => write!(f, "on change"),
+            TransmissionKind::OnRequest => write!(f, "on request"),
+            TransmissionKind::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Return the next deadline of a periodic frame sent every `periodicity`, after its emission
+/// at `now` for the `deadline` it was due at.
+///
+/// The schedulers of the crate, like the [BSI simulator](crate::bsi) or the
+/// [gateway](crate::gateway), do not own a clock: the caller passes them the current time of
+/// a monotonic time base, e.g. the uptime of the device, and every deadline is expressed in
+/// that time base. The same applies to the timeouts of the [watchdog](crate::watchdog) and of
+/// the [mailbox](crate::rx). A frame late by more than its periodicity is rescheduled from
+/// `now` instead of being emitted in bursts.
+///
+/// ```
+/// # use core::time::Duration;
+/// # use canpsa::frame::next_deadline;
+/// let period = Duration::from_millis(100);
+/// let next = next_deadline(Duration::from_millis(100), period, Duration::from_millis(120));
+/// assert_eq!(next, Duration::from_millis(200));
+/// let late = next_deadline(Duration::from_millis(100), period, Duration::from_millis(350));
+/// assert_eq!(late, Duration::from_millis(450));
+/// ```
+pub fn next_deadline(deadline: Duration, periodicity: Duration, now: Duration) -> Duration {
+    let next = deadline + periodicity;
+    if next > now {
+        next
+    } else {
+        now + periodicity
+    }
+}
+
+/// Return the separator written before each field by the `Display` implementations of the
+/// frame representations: a space for the compact `{}` form, or a new indented line for the
+/// multi-line `{:#}` form. Neither form ends with a newline.
+pub(crate) fn field_separator(f: &fmt::Formatter) -> &'static str {
+    if f.alternate() {
+        "\n "
+    } else {
+        " "
+    }
+}
+
+/// Layout of a frame representation rendered by `Repr::write_to`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Style {
+    /// All the fields on a single line, like the `{}` form of `Display`.
+    #[default]
+    Compact,
+    /// One field per indented line, like the `{:#}` form of `Display`.
+    Multiline,
+}
+
+/// A [fmt::Write] sink over a byte buffer, rendering frame representations without
+/// allocation, e.g. to a UART transmit buffer.
+///
+/// Text which does not fit is cut on a character boundary: the writer keeps what fits,
+/// reports the truncation with [TruncatingWriter::is_truncated] and fails the write with
+/// `fmt::Error` so that formatting stops early.
+///
+/// ```
+/// # use canpsa::{aee2004::conf::x1a5, frame::{Style, TruncatingWriter}};
+/// let repr = x1a5::Repr::parse(&x1a5::Frame::new_unchecked(&x1a5::IDLE_FRAME_BYTES)).unwrap();
+/// let mut buffer = [0u8; 16];
+/// let mut writer = TruncatingWriter::new(&mut buffer);
+/// assert!(repr.write_to(&mut writer, Style::Compact).is_err());
+/// assert!(writer.is_truncated());
+/// assert_eq!(writer.as_str(), "x1a5 volume=10 o");
+/// ```
+#[derive(Debug)]
+pub struct TruncatingWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+    truncated: bool,
+}
+
+impl<'a> TruncatingWriter<'a> {
+    /// Create an empty writer over `buffer`.
+    pub fn new(buffer: &'a mut [u8]) -> TruncatingWriter<'a> {
+        TruncatingWriter {
+            buffer,
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Return the text written so far.
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are copied into the buffer.
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or_default()
+    }
+
+    /// Return the written length in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return whether nothing has been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return whether some text did not fit in the buffer.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Discard the written text, to render into the buffer again.
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+}
+
+impl fmt::Write for TruncatingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Err(fmt::Error);
+        }
+        let available = self.buffer.len() - self.len;
+        let mut fit = s.len().min(available);
+        while !s.is_char_boundary(fit) {
+            fit -= 1;
+        }
+        self.buffer[self.len..self.len + fit].copy_from_slice(&s.as_bytes()[..fit]);
+        self.len += fit;
+        if fit < s.len() {
+            self.truncated = true;
+            return Err(fmt::Error);
+        }
+        Ok(())
+    }
+}
+
+/// Acknowledgement of the dangerous field setters, e.g. odometer or VIN setters.
+///
+/// Clusters and ECUs may record an odometer value or a VIN they receive, and a wrong value
+/// can't be reverted without a workshop tool. The raw setters of such fields, the builder
+/// setters of the representations and the `Repr::emit` methods of the x0f6, x336, x2b6 and
+/// x3b6 frames take a token to prevent writing them by accident, e.g. from generic code
+/// setting every field of a frame or emitting every message of a bus. Bench tools enabling the
+/// `unchecked-writes` feature can still write them.
+///
+/// The generic emission paths, [PsaFrame::emit] and `Message::emit`, reject these frames with
+/// `Err(Error::Illegal)`, and emit them with [PsaFrame::emit_acknowledged] and
+/// `Message::emit_acknowledged` only.
+///
+/// ```compile_fail
+/// # use canpsa::frame::DangerToken;
+/// let token = DangerToken(());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DangerToken(());
+
+impl DangerToken {
+    /// Return a token acknowledging the danger of writing the odometer or VIN fields.
+    #[cfg(feature = "unchecked-writes")]
+    pub const fn acknowledge() -> DangerToken {
+        DangerToken(())
+    }
+
+    /// Return a token for the tests.
+    #[cfg(test)]
+    pub(crate) const fn unchecked() -> DangerToken {
+        DangerToken(())
+    }
+}
+
+/// Frame parsing strictness.
+///
+/// The default options match `Repr::parse`: the payload length must equal the frame length,
+/// reserved bits and enumerated values are not checked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParseOptions {
+    /// Reject payloads longer than the frame length. Otherwise, the extra bytes are ignored.
+    /// Shorter payloads are always rejected.
+    pub strict_len: bool,
+    /// Reject payloads with bits set outside of the frame signals.
+    pub validate_reserved: bool,
+    /// Reject payloads with enumerated signals holding a value without a name.
+    pub validate_enums: bool,
+}
+
+impl ParseOptions {
+    /// Permissive decoding, e.g. for noisy buses.
+    pub const PERMISSIVE: ParseOptions = ParseOptions {
+        strict_len: false,
+        validate_reserved: false,
+        validate_enums: false,
+    };
+
+    /// Strict validation, e.g. for conformance testing.
+    pub const STRICT: ParseOptions = ParseOptions {
+        strict_len: true,
+        validate_reserved: true,
+        validate_enums: true,
+    };
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            strict_len: true,
+            validate_reserved: false,
+            validate_enums: false,
+        }
+    }
+}
+
+/// A payload rejected by the validations of [ParseOptions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Violation {
+    /// Bits `mask` of byte `byte` are set outside of the frame signals.
+    Reserved { byte: usize, mask: u8 },
+    /// The enumerated signal `name` holds the value `raw`, which has no name.
+    Value { name: &'static str, raw: u64 },
+}
+
+/// Ensure that `payload` passes the reserved bits and enumerated values checks of `options`
+/// against the frame `signals`.
+/// Returns the first failed check otherwise.
+pub(crate) fn check_signals(
+    signals: &'static [Signal],
+    payload: &[u8],
+    options: &ParseOptions,
+) -> core::result::Result<(), Violation> {
+    if options.validate_reserved {
+        for (index, &byte) in payload.iter().enumerate() {
+            let mask = signals
+                .iter()
+                .fold(0, |mask, signal| mask | signal.mask(index));
+            if byte & !mask != 0 {
+                return Err(Violation::Reserved {
+                    byte: index,
+                    mask: byte & !mask,
+                });
+            }
+        }
+    }
+
+    if options.validate_enums {
+        for signal in signals.iter().filter(|signal| !signal.values.is_empty()) {
+            // Signals are checked to fit in their frame.
+            let raw = signal.raw(payload).unwrap_or_default();
+            if !signal
+                .values
+                .iter()
+                .any(|&(value, _)| u64::from(value) == raw)
+            {
+                return Err(Violation::Value {
+                    name: signal.name,
+                    raw,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A high-level representation of a CAN frame.
+pub trait PsaFrame: Sized {
+    /// CAN frame identifier.
+    const ID: u16;
+    /// CAN frame length.
+    const LEN: usize;
+    /// CAN frame signals.
+    const SIGNALS: &'static [Signal];
+
+    /// Return the frame periodicity, `None` for event-driven frames and frames of unknown
+    /// transmission.
+    fn periodicity() -> Option<Duration>;
+
+    /// Return the frame transmission. Defaults to [TransmissionKind::Unknown] for frames
+    /// without periodicity.
+    fn transmission() -> TransmissionKind {
+        Self::periodicity().map_or(TransmissionKind::Unknown, TransmissionKind::Periodic)
+    }
+
+    /// Parse a frame payload.
+    /// Returns `Err(Error::Truncated)` or `Err(Error::Overlong)` if the payload length
+    /// differs from [PsaFrame::LEN], or the error of the frame `Repr::parse`.
+    fn parse(payload: &[u8]) -> Result<Self>;
+
+    /// Parse a frame payload with the given strictness.
+    /// Returns `Err(Error::Truncated)` if the payload is shorter than [PsaFrame::LEN],
+    /// `Err(Error::Overlong)` if it is longer with [ParseOptions::strict_len],
+    /// `Err(Error::Invalid)` if it fails the enabled validations, or the error of the frame
+    /// `Repr::parse`.
+    fn parse_with(payload: &[u8], options: &ParseOptions) -> Result<Self>;
+
+    /// Parse a frame payload with the given strictness, like [PsaFrame::parse_with], but
+    /// return the context of the error on failure.
+    #[cfg(feature = "detailed-errors")]
+    fn parse_detailed(
+        payload: &[u8],
+        options: &ParseOptions,
+    ) -> core::result::Result<Self, DetailedError> {
+        let (id, expected, actual) = (Self::ID, Self::LEN, payload.len());
+        Self::parse_with(payload, options).map_err(|error| match error {
+            Error::Truncated => DetailedError::Truncated {
+                id,
+                expected,
+                actual,
+            },
+            Error::Overlong => DetailedError::Overlong {
+                id,
+                expected,
+                actual,
+            },
+            Error::Invalid => {
+                let payload = payload.get(..expected).unwrap_or(payload);
+                match check_signals(Self::SIGNALS, payload, options) {
+                    Err(Violation::Reserved { byte, mask }) => {
+                        DetailedError::ReservedBits { id, byte, mask }
+                    }
+                    Err(Violation::Value { name, raw }) => DetailedError::InvalidField {
+                        id,
+                        field: name,
+                        raw,
+                    },
+                    Ok(()) => DetailedError::Other { id, error },
+                }
+            }
+            error => DetailedError::Other { id, error },
+        })
+    }
+
+    /// Emit the representation into a frame payload.
+    /// Returns `Err(Error::Truncated)` or `Err(Error::Overlong)` if the payload length
+    /// differs from [PsaFrame::LEN], and `Err(Error::Illegal)` for the frames holding the
+    /// odometer or the VIN, see [DangerToken].
+    fn emit(&self, payload: &mut [u8]) -> Result<()>;
+
+    /// Emit the representation into a frame payload like [PsaFrame::emit], including the
+    /// frames holding the odometer or the VIN, whose writes are acknowledged by `token`.
+    fn emit_acknowledged(&self, payload: &mut [u8], token: DangerToken) -> Result<()> {
+        let _ = token;
+        self.emit(payload)
+    }
+}
+
+/// A high-level representation of any supported frame of a bus, i.e. the `Message` enum of
+/// the bus module.
+pub trait BusMessage: Sized {
+    /// Parse the payload of the CAN frame with identifier `id`.
+    /// Returns `Err(Error::Unrecognized)` if `id` is not a supported frame identifier.
+    fn parse(id: u16, payload: &[u8]) -> Result<Self>;
+
+    /// Return the CAN frame identifier of the message.
+    fn frame_id(&self) -> u16;
+
+    /// Return the periodicity of the frame with identifier `id`.
+    /// Returns `None` for unknown and event-driven frames.
+    fn periodicity(id: u16) -> Option<Duration>;
+
+    /// Return the transmission of the frame with identifier `id`.
+    /// Returns `None` for unknown frames.
+    fn transmission(id: u16) -> Option<TransmissionKind>;
+}
+
+#[cfg(test)]
+mod test {
+    use core::{fmt::Write, time::Duration};
+
+    use heapless::String;
+
+    use super::{next_deadline, ParseOptions, PsaFrame, Style, TransmissionKind, TruncatingWriter};
+
+    use crate::{
+        aee2004::conf::{x0b6, x1a8, x21f, x260},
+        aee2010::infodiv::x3d0,
+        Error,
+    };
+
+    #[test]
+    fn test_display() {
+        let repr = x21f::Repr::default();
+        let mut compact: String<256> = String::new();
+        let mut multi_line: String<256> = String::new();
+        write!(compact, "{}", repr).unwrap();
+        write!(multi_line, "{:#}", repr).unwrap();
+        assert!(compact.starts_with("x21f next=false previous=false "));
+        assert!(!compact.contains('\n'));
+        assert!(multi_line.starts_with("x21f\n next=false\n previous=false\n "));
+        assert!(!multi_line.ends_with('\n'));
+        assert!(multi_line.split("\n ").eq(compact.split(' ')));
+
+        let frame = x21f::Frame::new_unchecked(&x21f::IDLE_FRAME_BYTES);
+        let mut frame_text: String<256> = String::new();
+        write!(frame_text, "{:#}", frame).unwrap();
+        assert_eq!(frame_text, multi_line);
+    }
+
+    #[test]
+    fn test_write_to() {
+        let repr = x21f::Repr::default();
+        let mut compact: String<256> = String::new();
+        let mut multi_line: String<256> = String::new();
+        write!(compact, "{}", repr).unwrap();
+        write!(multi_line, "{:#}", repr).unwrap();
+
+        let mut buffer = [0u8; 256];
+        let mut writer = TruncatingWriter::new(&mut buffer);
+        assert!(writer.is_empty());
+        repr.write_to(&mut writer, Style::Compact).unwrap();
+        assert!(!writer.is_truncated());
+        assert_eq!(writer.as_str(), compact);
+
+        writer.clear();
+        repr.write_to(&mut writer, Style::Multiline).unwrap();
+        assert_eq!(writer.as_str(), multi_line);
+        assert_eq!(writer.len(), multi_line.len());
+
+        let mut buffer = [0u8; 10];
+        let mut writer = TruncatingWriter::new(&mut buffer);
+        assert!(repr.write_to(&mut writer, Style::Compact).is_err());
+        assert!(writer.is_truncated());
+        assert_eq!(writer.as_str(), &compact[..10]);
+        assert!(writer.write_str("").is_err());
+    }
+
+    #[test]
+    fn test_truncating_writer_char_boundary() {
+        let mut buffer = [0u8; 3];
+        let mut writer = TruncatingWriter::new(&mut buffer);
+        assert!(writer.write_str("12°C").is_err());
+        assert_eq!(writer.as_str(), "12");
+        assert!(writer.is_truncated());
+    }
+
+    fn round_trip<F: PsaFrame + PartialEq + core::fmt::Debug>(payload: &[u8]) {
+        let repr = F::parse(payload).unwrap();
+        let mut buf = [0u8; 8];
+        repr.emit(&mut buf[..F::LEN]).unwrap();
+        assert_eq!(F::parse(&buf[..F::LEN]).unwrap(), repr);
+    }
+
+    #[test]
+    fn test_next_deadline() {
+        let period = Duration::from_millis(100);
+        let ms = Duration::from_millis;
+        assert_eq!(next_deadline(ms(100), period, ms(100)), ms(200));
+        assert_eq!(next_deadline(ms(100), period, ms(199)), ms(200));
+        // A frame late by a whole period is not emitted twice in a row.
+        assert_eq!(next_deadline(ms(100), period, ms(200)), ms(300));
+        assert_eq!(next_deadline(ms(100), period, ms(250)), ms(350));
+    }
+
+    #[test]
+    fn test_psa_frame() {
+        assert_eq!(x0b6::Repr::ID, 0x0b6);
+        assert_eq!(x0b6::Repr::LEN, 8);
+        assert_eq!(x0b6::Repr::periodicity(), Some(Duration::from_millis(50)));
+        assert_eq!(x1a8::Repr::periodicity(), None);
+        assert_eq!(
+            x0b6::Repr::transmission(),
+            TransmissionKind::Periodic(Duration::from_millis(50))
+        );
+        assert_eq!(x1a8::Repr::transmission(), TransmissionKind::Unknown);
+
+        round_trip::<x0b6::Repr>(&x0b6::IDLE_FRAME_BYTES);
+        round_trip::<x260::Repr>(&x260::IDLE_FRAME_BYTES);
+
+        // Inherent `parse` and `emit` methods take precedence over the trait ones.
+        let repr = <x0b6::Repr as PsaFrame>::parse(&x0b6::IDLE_FRAME_BYTES).unwrap();
+        assert_eq!(
+            <x0b6::Repr as PsaFrame>::parse(&[0u8; 7]),
+            Err(Error::Truncated)
+        );
+        assert_eq!(PsaFrame::emit(&repr, &mut [0u8; 9]), Err(Error::Overlong));
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let mut payload = [0u8; 8];
+        payload[..x3d0::FRAME_LEN].copy_from_slice(&x3d0::IDLE_FRAME_BYTES);
+        let repr = x3d0::Repr::builder().build().unwrap();
+
+        let options = ParseOptions::default();
+        assert_eq!(
+            x3d0::Repr::parse_with(&x3d0::Frame::new_unchecked(&payload), &options),
+            Err(Error::Overlong)
+        );
+        let options = ParseOptions::PERMISSIVE;
+        assert_eq!(
+            x3d0::Repr::parse_with(&x3d0::Frame::new_unchecked(&payload), &options),
+            Ok(repr)
+        );
+        assert_eq!(
+            <x3d0::Repr as PsaFrame>::parse_with(&payload[..5], &options),
+            Err(Error::Truncated)
+        );
+
+        // Bits of the unknown byte 0 are reserved.
+        payload[0] = 0x01;
+        let options = ParseOptions::STRICT;
+        assert_eq!(
+            <x3d0::Repr as PsaFrame>::parse_with(&payload[..x3d0::FRAME_LEN], &options),
+            Err(Error::Invalid)
+        );
+        let options = ParseOptions {
+            validate_reserved: false,
+            ..ParseOptions::STRICT
+        };
+        assert_eq!(
+            <x3d0::Repr as PsaFrame>::parse_with(&payload[..x3d0::FRAME_LEN], &options),
+            Ok(repr)
+        );
+
+        // Profile number 3 has no name.
+        let mut payload = x260::IDLE_FRAME_BYTES;
+        let options = ParseOptions::STRICT;
+        assert!(<x260::Repr as PsaFrame>::parse_with(&payload, &options).is_ok());
+        payload[0] = (payload[0] & !0x07) | 0x03;
+        assert_eq!(
+            <x260::Repr as PsaFrame>::parse_with(&payload, &options),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn test_trace() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::aee2010::infodiv::Message;
+
+        static RECORDS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counter;
+
+        impl log::Log for Counter {
+            fn enabled(&self, _: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                if record.target().starts_with("canpsa") {
+                    RECORDS.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&Counter).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let message = Message::parse(x3d0::FRAME_ID, &x3d0::IDLE_FRAME_BYTES).unwrap();
+        assert!(Message::parse(x3d0::FRAME_ID, &[0u8; 2]).is_err());
+        message.emit(&mut [0u8; 8]).unwrap();
+        assert!(RECORDS.load(Ordering::Relaxed) >= 3);
+    }
+
+    #[cfg(feature = "detailed-errors")]
+    #[test]
+    fn test_parse_detailed() {
+        use crate::DetailedError;
+
+        let options = ParseOptions::STRICT;
+        assert_eq!(
+            x3d0::Repr::parse_detailed(&[0u8; 5], &options),
+            Err(DetailedError::Truncated {
+                id: 0x3d0,
+                expected: 6,
+                actual: 5
+            })
+        );
+
+        let mut payload = x3d0::IDLE_FRAME_BYTES;
+        payload[4] = 0x81;
+        assert_eq!(
+            x3d0::Repr::parse_detailed(&payload, &options),
+            Err(DetailedError::ReservedBits {
+                id: 0x3d0,
+                byte: 4,
+                mask: 0x81
+            })
+        );
+
+        let mut payload = x260::IDLE_FRAME_BYTES;
+        payload[0] = (payload[0] & !0x07) | 0x03;
+        let error = x260::Repr::parse_detailed(&payload, &options).unwrap_err();
+        assert_eq!(
+            error,
+            DetailedError::InvalidField {
+                id: 0x260,
+                field: "profile_number",
+                raw: 3
+            }
+        );
+        assert_eq!(Error::from(error), Error::Invalid);
+    }
+}
@@ -0,0 +1,480 @@
+//! AEE2004 <-> AEE2010 gateway.
+//!
+//! The [Gateway] translates parsed frames of one electrical architecture into their
+//! equivalent on the other one, which is the main building block for retrofitting an AEE2010
+//! head unit or cluster into an AEE2004 vehicle, or the other way around.
+//!
+//! Frames are translated with the `From` conversions of the frame modules, which handle the
+//! identifier remapping (e.g. AEE2004 x1a8 to AEE2010 x228) and fill the fields without an
+//! equivalent as described in [convert](crate::convert). Frames without any equivalent are
+//! handled according to the gateway [UnmappedPolicy], and so are the fields lost by a
+//! conversion with [Gateway::set_field_policy].
+//!
+//! Some AEE2004 frames are not known to be periodic while their AEE2010 equivalent is. The
+//! gateway keeps the last translation of these frames and [Gateway::poll_aee2010] repeats
+//! them at the AEE2010 periodicity.
+//!
+//! The odometer and VIN frames listed in [RECORD_FRAMES] share their layout on both
+//! architectures. Unless the writes of these fields are acknowledged with a [DangerToken]
+//! through [Gateway::acknowledge_record_writes], the gateway does not translate them and
+//! passes them through unchanged as [Translation::Forward]. Once acknowledged, their
+//! translations are emitted with `Message::emit_acknowledged`, as `Message::emit` rejects
+//! them.
+
+use core::{fmt, time::Duration};
+
+use heapless::Vec;
+
+use crate::{
+    aee2004::conf,
+    aee2010::infodiv::{self, x0f6, x2b6, x336, x3b6},
+    frame::{next_deadline, DangerToken},
+    registry,
+};
+
+/// Maximum number of periodic AEE2010 frames repeated by the gateway.
+pub const MAX_PERIODIC_FRAMES: usize = 8;
+
+/// Identifiers of the frames holding the odometer (x0f6) and the VIN (x336, x2b6, x3b6),
+/// which the gateway passes through unchanged unless their writes are acknowledged.
+pub const RECORD_FRAMES: [u16; 4] = [
+    x0f6::FRAME_ID,
+    x336::FRAME_ID,
+    x2b6::FRAME_ID,
+    x3b6::FRAME_ID,
+];
+
+/// Policy applied to frames without an equivalent on the other architecture.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UnmappedPolicy {
+    /// Discard the frame.
+    #[default]
+    Drop,
+    /// Forward the frame unchanged to the other architecture.
+    Forward,
+}
+
+impl fmt::Display for UnmappedPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UnmappedPolicy::Drop => write!(f, "drop"),
+            UnmappedPolicy::Forward => write!(f, "forward"),
+        }
+    }
+}
+
+/// Result of the translation of a frame by the gateway.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Translation<M> {
+    /// The frame has an equivalent on the other architecture.
+    Converted(M),
+    /// The frame has an equivalent on the other architecture, but the conversion lost fields
+    /// without an equivalent, so the frame should also be forwarded unchanged.
+    ConvertedAndForward(M),
+    /// The frame has no equivalent and should be forwarded unchanged.
+    Forward,
+    /// The frame has no equivalent and should be discarded.
+    Drop,
+}
+
+impl<M> Translation<M> {
+    /// Return the converted message, if any.
+    pub fn converted(self) -> Option<M> {
+        match self {
+            Translation::Converted(message) | Translation::ConvertedAndForward(message) => {
+                Some(message)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A periodic AEE2010 frame repeated by the gateway.
+#[derive(Debug, Clone)]
+struct Periodic {
+    message: infodiv::Message,
+    periodicity: Duration,
+    deadline: Duration,
+}
+
+/// A bidirectional AEE2004 <-> AEE2010 gateway.
+#[derive(Debug, Clone, Default)]
+pub struct Gateway {
+    policy: UnmappedPolicy,
+    field_policy: UnmappedPolicy,
+    record_writes: bool,
+    periodic: Vec<Periodic, MAX_PERIODIC_FRAMES>,
+}
+
+impl Gateway {
+    /// Create a gateway applying `policy` to frames without an equivalent.
+    pub fn new(policy: UnmappedPolicy) -> Gateway {
+        Gateway {
+            policy,
+            field_policy: UnmappedPolicy::Drop,
+            record_writes: false,
+            periodic: Vec::new(),
+        }
+    }
+
+    /// Translate and repeat the [RECORD_FRAMES] like the other frames, instead of passing
+    /// them through unchanged.
+    pub fn acknowledge_record_writes(&mut self, _token: DangerToken) {
+        self.record_writes = true;
+    }
+
+    /// Return whether the gateway translates the [RECORD_FRAMES].
+    pub fn record_writes(&self) -> bool {
+        self.record_writes
+    }
+
+    /// Return the policy applied to frames without an equivalent.
+    pub fn policy(&self) -> UnmappedPolicy {
+        self.policy
+    }
+
+    /// Set the policy applied to frames without an equivalent.
+    pub fn set_policy(&mut self, policy: UnmappedPolicy) {
+        self.policy = policy;
+    }
+
+    /// Return the policy applied to fields without an equivalent.
+    pub fn field_policy(&self) -> UnmappedPolicy {
+        self.field_policy
+    }
+
+    /// Set the policy applied to fields without an equivalent. With
+    /// [UnmappedPolicy::Forward], frames whose conversion back does not give them back, e.g.
+    /// because a field without an equivalent is lost, are translated as
+    /// [Translation::ConvertedAndForward]. With [UnmappedPolicy::Drop], the default, these
+    /// fields are dropped as described in [convert](crate::convert).
+    pub fn set_field_policy(&mut self, policy: UnmappedPolicy) {
+        self.field_policy = policy;
+    }
+
+    fn is_passed_through(&self, id: u16) -> bool {
+        !self.record_writes && RECORD_FRAMES.contains(&id)
+    }
+
+    fn unmapped<M>(&self) -> Translation<M> {
+        match self.policy {
+            UnmappedPolicy::Drop => Translation::Drop,
+            UnmappedPolicy::Forward => Translation::Forward,
+        }
+    }
+
+    fn converted<S: PartialEq, M>(
+        &self,
+        source: &S,
+        converted: M,
+        convert_back: fn(&M) -> Option<S>,
+    ) -> Translation<M> {
+        let lossy = || convert_back(&converted).as_ref() != Some(source);
+        if self.field_policy == UnmappedPolicy::Forward && lossy() {
+            Translation::ConvertedAndForward(converted)
+        } else {
+            Translation::Converted(converted)
+        }
+    }
+
+    /// Translate an AEE2004 conf frame received at `now` into its AEE2010 infodiv equivalent.
+    pub fn to_aee2010(
+        &mut self,
+        message: &conf::Message,
+        now: Duration,
+    ) -> Translation<infodiv::Message> {
+        if self.is_passed_through(message.frame_id()) {
+            return Translation::Forward;
+        }
+
+        let Some(converted) = convert_to_aee2010(message) else {
+            return self.unmapped();
+        };
+
+        if let Some(periodicity) = repeated_periodicity(message.frame_id(), converted.frame_id()) {
+            self.track(&converted, periodicity, now);
+        }
+
+        self.converted(message, converted, convert_to_aee2004)
+    }
+
+    /// Translate an AEE2010 infodiv frame into its AEE2004 conf equivalent.
+    pub fn to_aee2004(&mut self, message: &infodiv::Message) -> Translation<conf::Message> {
+        if self.is_passed_through(message.frame_id()) {
+            return Translation::Forward;
+        }
+
+        let Some(converted) = convert_to_aee2004(message) else {
+            return self.unmapped();
+        };
+
+        self.converted(message, converted, convert_to_aee2010)
+    }
+
+    /// Return the next repeated AEE2010 frame due at `now`, if any.
+    /// Call it repeatedly until it returns `None` to get every due frame.
+    pub fn poll_aee2010(&mut self, now: Duration) -> Option<infodiv::Message> {
+        let periodic = self.periodic.iter_mut().find(|p| p.deadline <= now)?;
+        periodic.deadline = next_deadline(periodic.deadline, periodic.periodicity, now);
+        Some(periodic.message.clone())
+    }
+
+    /// Forget the repeated AEE2010 frames, e.g. when the AEE2004 network goes to sleep.
+    pub fn reset(&mut self) {
+        self.periodic.clear();
+    }
+
+    fn track(&mut self, message: &infodiv::Message, periodicity: Duration, now: Duration) {
+        let deadline = now + periodicity;
+        let id = message.frame_id();
+        match self
+            .periodic
+            .iter_mut()
+            .find(|p| p.message.frame_id() == id)
+        {
+            Some(periodic) => {
+                periodic.message = message.clone();
+                periodic.deadline = deadline;
+            }
+            None => {
+                // The repeated frames are a fixed set smaller than the capacity.
+                let _ = self.periodic.push(Periodic {
+                    message: message.clone(),
+                    periodicity,
+                    deadline,
+                });
+            }
+        }
+    }
+}
+
+/// Convert an AEE2004 conf frame into its AEE2010 infodiv equivalent, if any.
+fn convert_to_aee2010(message: &conf::Message) -> Option<infodiv::Message> {
+    use conf::Message as M2004;
+    use infodiv::Message as M2010;
+
+    let converted = match message {
+        M2004::X036(repr) => M2010::X036(repr.into()),
+        M2004::X0b6(repr) => M2010::X0b6(repr.into()),
+        M2004::X0e6(repr) => M2010::X0e6(repr.into()),
+        M2004::X0f6(repr) => M2010::X0f6(repr.into()),
+        M2004::X128(repr) => M2010::X128(repr.into()),
+        M2004::X15b(repr) => M2010::X15b(repr.into()),
+        M2004::X167(repr) => M2010::X167(repr.into()),
+        M2004::X168(repr) => M2010::X168(repr.into()),
+        M2004::X1a5(repr) => M2010::X1a5(repr.into()),
+        M2004::X1a8(repr) => M2010::X228(repr.into()),
+        M2004::X1d0(repr) => M2010::X350(repr.into()),
+        M2004::X1e1(repr) => M2010::X1e1(repr.into()),
+        M2004::X1e5(repr) => M2010::X1e5(repr.into()),
+        M2004::X221(repr) => M2010::X221(repr.into()),
+        M2004::X227(repr) => M2010::X227(repr.into()),
+        M2004::X260(repr) => M2010::X260(repr.into()),
+        M2004::X261(repr) => M2010::X261(repr.into()),
+        M2004::X2a1(repr) => M2010::X2a1(repr.into()),
+        M2004::X2b6(repr) => M2010::X2b6(repr.into()),
+        M2004::X2e1(repr) => M2010::X2e1(repr.into()),
+        M2004::X336(repr) => M2010::X336(repr.into()),
+        M2004::X361(repr) => M2010::X361(repr.into()),
+        M2004::X376(repr) => M2010::X276(repr.into()),
+        M2004::X3a7(repr) => M2010::X3e7(repr.into()),
+        M2004::X3b6(repr) => M2010::X3b6(repr.into()),
+        M2004::X3e1(repr) => M2010::X3e1(repr.into()),
+        _ => return None,
+    };
+    Some(converted)
+}
+
+/// Convert an AEE2010 infodiv frame into its AEE2004 conf equivalent, if any.
+fn convert_to_aee2004(message: &infodiv::Message) -> Option<conf::Message> {
+    use conf::Message as M2004;
+    use infodiv::Message as M2010;
+
+    let converted = match message {
+        M2010::X036(repr) => M2004::X036(repr.into()),
+        M2010::X0b6(repr) => M2004::X0b6(repr.into()),
+        M2010::X0e6(repr) => M2004::X0e6(repr.into()),
+        M2010::X0f6(repr) => M2004::X0f6(repr.into()),
+        M2010::X128(repr) => M2004::X128(repr.into()),
+        M2010::X15b(repr) => M2004::X15b(repr.into()),
+        M2010::X167(repr) => M2004::X167(repr.into()),
+        M2010::X168(repr) => M2004::X168(repr.into()),
+        M2010::X1a5(repr) => M2004::X1a5(repr.into()),
+        M2010::X1e1(repr) => M2004::X1e1(repr.into()),
+        M2010::X1e5(repr) => M2004::X1e5(repr.into()),
+        M2010::X221(repr) => M2004::X221(repr.into()),
+        M2010::X227(repr) => M2004::X227(repr.into()),
+        M2010::X228(repr) => M2004::X1a8(repr.into()),
+        M2010::X260(repr) => M2004::X260(repr.into()),
+        M2010::X261(repr) => M2004::X261(repr.into()),
+        M2010::X276(repr) => M2004::X376(repr.into()),
+        M2010::X2a1(repr) => M2004::X2a1(repr.into()),
+        M2010::X2b6(repr) => M2004::X2b6(repr.into()),
+        M2010::X2e1(repr) => M2004::X2e1(repr.into()),
+        M2010::X336(repr) => M2004::X336(repr.into()),
+        M2010::X350(repr) => M2004::X1d0(repr.into()),
+        M2010::X361(repr) => M2004::X361(repr.into()),
+        M2010::X3b6(repr) => M2004::X3b6(repr.into()),
+        M2010::X3e1(repr) => M2004::X3e1(repr.into()),
+        M2010::X3e7(repr) => M2004::X3a7(repr.into()),
+        _ => return None,
+    };
+    Some(converted)
+}
+
+/// Return the AEE2010 periodicity of the frame `id`, translated from the AEE2004 frame
+/// `source`, if the gateway repeats it, i.e. if the AEE2004 frame is not periodic.
+fn repeated_periodicity(source: u16, id: u16) -> Option<Duration> {
+    match registry::aee2004::periodicity(source) {
+        Some(_) => None,
+        None => registry::aee2010::periodicity(id),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{Gateway, Translation, UnmappedPolicy};
+
+    use crate::{aee2004::conf, aee2010::infodiv, frame::DangerToken, vehicle::WheelState};
+
+    static X0E6_FRAME_BYTES: [u8; 7] = [0x55, 0x2c, 0x15, 0x82, 0x26, 0x7c, 0x80];
+    static X1A8_FRAME_BYTES: [u8; 8] = [0x44, 0x00, 0x82, 0x00, 0x00, 0x00, 0x19, 0x84];
+    static X0B6_FRAME_BYTES: [u8; 8] = [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0];
+
+    #[test]
+    fn test_to_aee2010() {
+        let mut gateway = Gateway::default();
+        let message = conf::Message::parse(conf::x0e6::FRAME_ID, &X0E6_FRAME_BYTES).unwrap();
+        let translation = gateway.to_aee2010(&message, Duration::ZERO);
+        let converted = translation.converted().unwrap();
+        assert_eq!(converted.frame_id(), infodiv::x0e6::FRAME_ID);
+        assert_eq!(gateway.poll_aee2010(Duration::from_secs(10)), None);
+    }
+
+    #[test]
+    fn test_to_aee2004() {
+        let mut gateway = Gateway::new(UnmappedPolicy::Drop);
+        let message = infodiv::Message::parse(infodiv::x0b6::FRAME_ID, &X0B6_FRAME_BYTES).unwrap();
+        let converted = gateway.to_aee2004(&message).converted().unwrap();
+        assert_eq!(converted.frame_id(), conf::x0b6::FRAME_ID);
+        assert_eq!(
+            gateway.to_aee2010(&converted, Duration::ZERO),
+            Translation::Converted(message)
+        );
+    }
+
+    #[test]
+    fn test_unmapped_policy() {
+        let message = conf::Message::parse(conf::x0e6::FRAME_ID, &X0E6_FRAME_BYTES).unwrap();
+        let message_2010 =
+            infodiv::Message::parse(infodiv::x236::FRAME_ID, &infodiv::x236::IDLE_FRAME_BYTES)
+                .unwrap();
+
+        let mut gateway = Gateway::new(UnmappedPolicy::Drop);
+        assert_eq!(gateway.to_aee2004(&message_2010), Translation::Drop);
+
+        gateway.set_policy(UnmappedPolicy::Forward);
+        assert_eq!(gateway.policy(), UnmappedPolicy::Forward);
+        assert_eq!(gateway.to_aee2004(&message_2010), Translation::Forward);
+        assert!(matches!(
+            gateway.to_aee2010(&message, Duration::ZERO),
+            Translation::Converted(_)
+        ));
+    }
+
+    #[test]
+    fn test_field_policy() {
+        let repr = conf::x1e1::Repr::from(&infodiv::x1e1::Repr::default());
+        let lossy = conf::x1e1::Repr {
+            spare_wheel_state: WheelState::Normal,
+            ..repr.clone()
+        };
+
+        let mut gateway = Gateway::default();
+        assert_eq!(gateway.field_policy(), UnmappedPolicy::Drop);
+        let message = conf::Message::X1e1(lossy.clone());
+        assert!(matches!(
+            gateway.to_aee2010(&message, Duration::ZERO),
+            Translation::Converted(_)
+        ));
+
+        // The AEE2010 x1e1 has no spare wheel state.
+        gateway.set_field_policy(UnmappedPolicy::Forward);
+        let translation = gateway.to_aee2010(&message, Duration::ZERO);
+        assert!(matches!(
+            translation,
+            Translation::ConvertedAndForward(infodiv::Message::X1e1(_))
+        ));
+        assert_eq!(
+            translation.converted(),
+            Some(infodiv::Message::X1e1((&lossy).into()))
+        );
+
+        let message = conf::Message::X1e1(repr);
+        assert!(matches!(
+            gateway.to_aee2010(&message, Duration::ZERO),
+            Translation::Converted(_)
+        ));
+    }
+
+    #[test]
+    fn test_record_frames() {
+        let message =
+            conf::Message::parse(conf::x0f6::FRAME_ID, &conf::x0f6::IDLE_FRAME_BYTES).unwrap();
+        let message_2010 =
+            infodiv::Message::parse(infodiv::x336::FRAME_ID, &infodiv::x336::IDLE_FRAME_BYTES)
+                .unwrap();
+
+        let mut gateway = Gateway::new(UnmappedPolicy::Drop);
+        assert!(!gateway.record_writes());
+        assert_eq!(
+            gateway.to_aee2010(&message, Duration::ZERO),
+            Translation::Forward
+        );
+        assert_eq!(gateway.to_aee2004(&message_2010), Translation::Forward);
+        assert_eq!(gateway.poll_aee2010(Duration::from_secs(10)), None);
+
+        gateway.acknowledge_record_writes(DangerToken::unchecked());
+        assert!(gateway.record_writes());
+        let converted = gateway
+            .to_aee2010(&message, Duration::ZERO)
+            .converted()
+            .unwrap();
+        assert_eq!(converted.frame_id(), infodiv::x0f6::FRAME_ID);
+        // x0f6 is periodic on both buses and is not repeated.
+        assert_eq!(gateway.poll_aee2010(Duration::from_millis(500)), None);
+        assert!(matches!(
+            gateway.to_aee2004(&message_2010),
+            Translation::Converted(conf::Message::X336(_))
+        ));
+    }
+
+    #[test]
+    fn test_periodic_repeat() {
+        let mut gateway = Gateway::default();
+        let message = conf::Message::parse(conf::x1a8::FRAME_ID, &X1A8_FRAME_BYTES).unwrap();
+        let converted = gateway
+            .to_aee2010(&message, Duration::from_millis(100))
+            .converted()
+            .unwrap();
+        assert_eq!(converted.frame_id(), infodiv::x228::FRAME_ID);
+
+        assert_eq!(gateway.poll_aee2010(Duration::from_millis(1099)), None);
+        assert_eq!(
+            gateway.poll_aee2010(Duration::from_millis(1100)),
+            Some(converted.clone())
+        );
+        assert_eq!(gateway.poll_aee2010(Duration::from_millis(1100)), None);
+        assert_eq!(
+            gateway.poll_aee2010(Duration::from_millis(2100)),
+            Some(converted)
+        );
+
+        gateway.reset();
+        assert_eq!(gateway.poll_aee2010(Duration::from_secs(10)), None);
+    }
+}
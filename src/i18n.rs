@@ -0,0 +1,170 @@
+//! Localized display.
+//!
+//! The `Display` implementations of the value enums render English strings. Dashboards showing
+//! decoded frames to end users rather want them in the language configured in the vehicle, see
+//! [Language]. The [Localized] wrapper renders a [Localize] value in a given language, falling
+//! back to the English `Display` output for the languages and values without a translation:
+//!
+//! ```
+//! # use canpsa::{config::Language, i18n::Localized, vehicle::WheelState};
+//! let state = WheelState::Puncture;
+//! assert_eq!(Localized(&state, Language::French).to_string(), "crevaison");
+//! assert_eq!(Localized(&state, Language::English).to_string(), "puncture");
+//! assert_eq!(Localized(&state, Language::German).to_string(), "puncture");
+//! ```
+//!
+//! French and English are supported.
+
+use core::fmt;
+
+use crate::{
+    config::{DistanceUnit, Language, TemperatureUnit},
+    vehicle::{
+        ACAirDistributionPosition, ACAirIntakeMode, ACFanMode2004, ACFanMode2010, ACModeRequest,
+        FuelType, FunctionState, WheelState,
+    },
+};
+
+/// A value which can be displayed in several languages.
+pub trait Localize: fmt::Display {
+    /// Format the value in `language`.
+    fn fmt_localized(&self, f: &mut fmt::Formatter, language: Language) -> fmt::Result;
+}
+
+/// A formatter wrapper displaying a value in a given language.
+#[derive(Debug, Clone, Copy)]
+pub struct Localized<'a, T: Localize>(pub &'a T, pub Language);
+
+impl<'a, T: Localize> fmt::Display for Localized<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_localized(f, self.1)
+    }
+}
+
+macro_rules! localize {
+    ($($name:ident { $($variant:ident => $french:literal),+ $(,)? })+) => {
+        $(
+          impl Localize for $name {
+              fn fmt_localized(&self, f: &mut fmt::Formatter, language: Language) -> fmt::Result {
+                  match (language, *self) {
+                      $( (Language::French, $name::$variant) => f.write_str($french), )+
+                      _ => fmt::Display::fmt(self, f),
+                  }
+              }
+          }
+        )+
+    };
+}
+
+localize! {
+    Language {
+        French => "français",
+        English => "anglais",
+        German => "allemand",
+        Spanish => "espagnol",
+        Italian => "italien",
+        Portuguese => "portugais",
+        Dutch => "néerlandais",
+        Greek => "grec",
+        BrazilianPortuguese => "portugais brésilien",
+        Polish => "polonais",
+        TraditionalChinese => "chinois traditionnel",
+        SimplifiedChinese => "chinois simplifié",
+        Turkish => "turc",
+        Japanese => "japonais",
+        Russian => "russe",
+        Invalid => "valeur invalide",
+        Arabic => "arabe",
+        Farsi => "persan",
+        Swedish => "suédois",
+    }
+    DistanceUnit {
+        Kilometer => "kilomètre",
+        Mile => "mile",
+    }
+    TemperatureUnit {
+        Celsius => "celsius",
+        Fahrenheit => "fahrenheit",
+    }
+    FunctionState {
+        Absent => "absent",
+        Disabled => "désactivé",
+        Enabled => "activé",
+    }
+    FuelType {
+        Petrol => "essence",
+        Diesel => "diesel",
+    }
+    WheelState {
+        Puncture => "crevaison",
+        LightlyDeflated => "légèrement dégonflé",
+        HighlyDeflated => "fortement dégonflé",
+        NotMonitored => "non surveillé",
+        Normal => "normal",
+    }
+    ACFanMode2004 {
+        AutoComfort => "auto confort",
+        AutoDemist => "auto désembuage",
+        Manual => "manuel",
+        AutoSoft => "auto doux",
+    }
+    ACFanMode2010 {
+        AutoSoft => "auto doux",
+        AutoComfort => "auto confort",
+        AutoDemist => "auto désembuage",
+        Manual => "manuel",
+    }
+    ACModeRequest {
+        AutoComfort => "auto confort",
+        AutoDemist => "auto désembuage",
+        Off => "arrêt",
+    }
+    ACAirDistributionPosition {
+        AutoComfort => "auto confort",
+        AutoDemist => "auto + désembuage",
+        Foot => "pieds",
+        Ventilation => "aérateurs",
+        Demist => "désembuage",
+        FootVentilation => "pieds + aérateurs",
+        FootDemist => "pieds + désembuage",
+        VentilationDemist => "aérateurs + désembuage",
+        FootVentilationDemist => "pieds + aérateurs + désembuage",
+        AutoSoft => "auto doux",
+    }
+    ACAirIntakeMode {
+        AutoComfort => "auto confort",
+        AutoDemist => "auto + désembuage",
+        ForcedOpen => "air extérieur forcé",
+        ForcedClose => "recyclage forcé",
+        AutoComfortWithoutAQS => "auto confort sans AQS",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Write;
+
+    use heapless::String;
+
+    use super::{Localize, Localized};
+
+    use crate::{config::Language, vehicle::ACModeRequest};
+
+    fn display<T: Localize>(value: &T, language: Language) -> String<32> {
+        let mut text = String::new();
+        write!(text, "{}", Localized(value, language)).unwrap();
+        text
+    }
+
+    #[test]
+    fn test_localized() {
+        assert_eq!(display(&ACModeRequest::Off, Language::French), "arrêt");
+        assert_eq!(display(&ACModeRequest::Off, Language::English), "off");
+        assert_eq!(display(&ACModeRequest::Off, Language::Unknown(0x1f)), "off");
+        assert_eq!(
+            display(&ACModeRequest::Unknown(0x05), Language::French),
+            "0x05"
+        );
+        assert_eq!(display(&Language::German, Language::French), "allemand");
+    }
+}
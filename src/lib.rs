@@ -2,6 +2,11 @@
 #![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 mod macros;
 
@@ -9,14 +14,46 @@ use core::fmt;
 
 pub mod aee2004;
 pub mod aee2010;
+pub mod bsi;
+pub mod climate;
+pub mod clock;
 pub mod config;
+pub mod consistency;
+pub mod controls;
+pub mod convert;
+#[cfg(feature = "corpus")]
+pub mod corpus;
+pub mod datetime;
+#[cfg(feature = "dbc")]
+pub mod dbc;
+pub mod decode;
+pub mod diff;
+mod field;
+#[cfg(feature = "std")]
+pub mod fixtures;
+pub mod frame;
+pub mod gateway;
+#[cfg(feature = "i18n")]
+pub mod i18n;
 pub mod mfd;
+#[cfg(feature = "alloc")]
+pub mod owned;
+pub mod physical;
+pub mod profiles;
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod replay;
+pub mod rx;
+pub mod secured;
+pub mod signal;
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+pub mod testing;
+pub mod transport;
+pub mod units;
+pub mod validate;
 pub mod vehicle;
-
-mod field {
-    pub type Field = ::core::ops::Range<usize>;
-    pub type _Rest = ::core::ops::RangeFrom<usize>;
-}
+pub mod watchdog;
 
 /// Year value offset. Stellantis CAN time origin is January 1st 2000 0:00.
 /// To get human year, add this constant to the CAN bus value.
@@ -24,6 +61,25 @@ pub const YEAR_OFFSET: i32 = 2000;
 /// Offset to apply to convert Unix epoch from/to PSA epoch.
 pub const UNIX_EPOCH_OFFSET: i64 = 946684800;
 
+/// Electrical architecture of a vehicle.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Architecture {
+    /// AEE2004 architecture, with the conf bus.
+    Aee2004,
+    /// AEE2010 architecture, with the infodiv bus.
+    Aee2010,
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Architecture::Aee2004 => write!(f, "AEE2004"),
+            Architecture::Aee2010 => write!(f, "AEE2010"),
+        }
+    }
+}
+
 /// The error type for the networking stack.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -43,6 +99,8 @@ pub enum Error {
     Invalid,
     /// An incoming frame was recognized but contradicted internal state.
     Dropped,
+    /// An incoming frame identifier is not handled by this crate.
+    Unrecognized,
 }
 
 /// The result type for the networking stack.
@@ -57,6 +115,94 @@ impl fmt::Display for Error {
             Error::Overlong => write!(f, "overlong frame"),
             Error::Invalid => write!(f, "invalid frame"),
             Error::Dropped => write!(f, "dropped by socket"),
+            Error::Unrecognized => write!(f, "unrecognized frame"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// An [Error] with the context of the frame which caused it, see
+/// [PsaFrame::parse_detailed](frame::PsaFrame::parse_detailed).
+#[cfg(feature = "detailed-errors")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DetailedError {
+    /// The payload of frame `id` is shorter than the frame length.
+    Truncated {
+        id: u16,
+        expected: usize,
+        actual: usize,
+    },
+    /// The payload of frame `id` is longer than the frame length.
+    Overlong {
+        id: u16,
+        expected: usize,
+        actual: usize,
+    },
+    /// The field `field` of frame `id` holds the invalid raw value `raw`.
+    InvalidField {
+        id: u16,
+        field: &'static str,
+        raw: u64,
+    },
+    /// The reserved bits `mask` of byte `byte` of frame `id` are set.
+    ReservedBits { id: u16, byte: usize, mask: u8 },
+    /// Any other error of frame `id`, e.g. a checksum mismatch.
+    Other { id: u16, error: Error },
+}
+
+#[cfg(feature = "detailed-errors")]
+impl DetailedError {
+    /// Return the identifier of the frame which caused the error.
+    pub fn id(&self) -> u16 {
+        match *self {
+            DetailedError::Truncated { id, .. }
+            | DetailedError::Overlong { id, .. }
+            | DetailedError::InvalidField { id, .. }
+            | DetailedError::ReservedBits { id, .. }
+            | DetailedError::Other { id, .. } => id,
         }
     }
 }
+
+#[cfg(feature = "detailed-errors")]
+impl From<DetailedError> for Error {
+    fn from(error: DetailedError) -> Error {
+        match error {
+            DetailedError::Truncated { .. } => Error::Truncated,
+            DetailedError::Overlong { .. } => Error::Overlong,
+            DetailedError::InvalidField { .. } | DetailedError::ReservedBits { .. } => {
+                Error::Invalid
+            }
+            DetailedError::Other { error, .. } => error,
+        }
+    }
+}
+
+#[cfg(feature = "detailed-errors")]
+impl fmt::Display for DetailedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "x{:03x} {}", self.id(), Error::from(*self))?;
+        match *self {
+            DetailedError::Truncated {
+                expected, actual, ..
+            }
+            | DetailedError::Overlong {
+                expected, actual, ..
+            } => write!(f, " ({} bytes, expected {})", actual, expected),
+            DetailedError::InvalidField { field, raw, .. } => {
+                write!(f, " ({}=0x{:x})", field, raw)
+            }
+            DetailedError::ReservedBits { byte, mask, .. } => {
+                write!(f, " (reserved bits 0x{:02x} of byte {})", mask, byte)
+            }
+            DetailedError::Other { .. } => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(feature = "std", feature = "detailed-errors"))]
+impl std::error::Error for DetailedError {}
@@ -10,6 +10,7 @@ macro_rules! enum_with_unknown {
    ) => {
        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+       #[non_exhaustive]
        $( #[$enum_attr] )*
        pub enum $name {
            $(
@@ -19,20 +20,784 @@ macro_rules! enum_with_unknown {
            Unknown($ty)
        }
 
-       impl ::core::convert::From<$ty> for $name {
-           fn from(value: $ty) -> Self {
+       impl $name {
+           /// Return the variant of the raw value `value`, `Unknown` if it is not documented.
+           #[inline]
+           pub const fn from_raw(value: $ty) -> $name {
                match value {
                    $( $value => $name::$variant ),*,
                    other => $name::Unknown(other)
                }
            }
+
+           /// Return the raw value of the variant.
+           #[inline]
+           pub const fn as_raw(self) -> $ty {
+               match self {
+                   $( $name::$variant => $value ),*,
+                   $name::Unknown(other) => other
+               }
+           }
+
+           /// Return an iterator over the known variants, in declaration order.
+           pub fn iter() -> impl Iterator<Item = $name> {
+               [$( $name::$variant ),*].into_iter()
+           }
+       }
+
+       impl ::core::convert::From<$ty> for $name {
+           #[inline]
+           fn from(value: $ty) -> Self {
+               $name::from_raw(value)
+           }
        }
 
        impl ::core::convert::From<$name> for $ty {
+           #[inline]
            fn from(value: $name) -> Self {
-               match value {
-                   $( $name::$variant => $value ),*,
-                   $name::Unknown(other) => other
+               value.as_raw()
+           }
+       }
+
+       impl $crate::validate::Validate for $name {
+           #[inline]
+           fn is_known(&self) -> bool {
+               !matches!(self, $name::Unknown(_))
+           }
+       }
+   }
+}
+
+/// Add a 256-entry lookup table conversion to `u8` enums defined with `enum_with_unknown!`.
+///
+/// `lookup` replaces the `match` of `from_raw` with a single indexed load. The `enum`
+/// benchmarks of `benches/throughput.rs` measure 0.6ns against 1.7ns per conversion for
+/// `SpeedValidity` and 0.7ns against 1.9ns for `NetworkState` on an x86_64 Xeon, while the
+/// difference is within the noise of the 5ns x0b6 parse benchmark. Each table takes 512 bytes
+/// of flash, so it is only used by the decoders of the high-rate frames.
+macro_rules! enum_lookup_table {
+    ($( $name:ident ),+ $(,)?) => {
+        $(
+            impl $name {
+                /// Conversion of every raw value, indexed by the raw value.
+                const LOOKUP_TABLE: [$name; 256] = {
+                    let mut table = [$name::Unknown(0); 256];
+                    let mut raw = 0;
+                    while raw < table.len() {
+                        table[raw] = $name::from_raw(raw as u8);
+                        raw += 1;
+                    }
+                    table
+                };
+
+                /// Return the variant of the raw value `value` with a lookup table, like
+                /// [from_raw](Self::from_raw).
+                #[inline]
+                pub fn lookup(value: u8) -> $name {
+                    Self::LOOKUP_TABLE[usize::from(value)]
+                }
+            }
+        )+
+    };
+}
+
+/// Log a received frame parsing result or an emitted frame with the `trace` feature.
+/// Parsing errors are logged at the debug level, frames at the trace level.
+macro_rules! trace_frame {
+    (rx, $id:expr, $result:expr) => {
+        #[cfg(feature = "trace")]
+        {
+            match $result {
+                Ok(repr) => ::log::trace!("rx x{:03x} {:?}", $id, repr),
+                Err(err) => ::log::debug!("rx x{:03x} {}", $id, err),
+            }
+        }
+    };
+    (tx, $id:expr, $repr:expr) => {
+        #[cfg(feature = "trace")]
+        {
+            ::log::trace!("tx x{:03x} {:?}", $id, $repr);
+        }
+    };
+}
+
+macro_rules! frame_message {
+   (
+       $( #[$enum_attr:meta] )*
+       pub enum $name:ident {
+           $(
+             $( #[$variant_attr:meta] )*
+             $variant:ident($frame:ident)
+           ),+ $(,)?
+       }
+   ) => {
+       #[derive(Debug, PartialEq, Clone)]
+       #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+       $( #[$enum_attr] )*
+       pub enum $name {
+           $(
+             $( #[$variant_attr] )*
+             $variant($frame::Repr)
+           ),+
+       }
+
+       impl $name {
+           /// Parse the payload of the CAN frame with identifier `id`.
+           /// Returns `Err(Error::Unrecognized)` if `id` is not a supported frame identifier.
+           pub fn parse(id: u16, payload: &[u8]) -> $crate::Result<$name> {
+               match id {
+                   $(
+                     $frame::FRAME_ID => {
+                         let message = $frame::Frame::new_checked(payload)
+                             .and_then(|frame| $frame::Repr::parse(&frame))
+                             .map($name::$variant);
+                         trace_frame!(rx, id, &message);
+                         message
+                     }
+                   )+
+                   _ => Err($crate::Error::Unrecognized),
+               }
+           }
+
+           /// Return the CAN frame identifier of the message.
+           pub fn frame_id(&self) -> u16 {
+               match self {
+                   $( $name::$variant(_) => $frame::FRAME_ID ),+
+               }
+           }
+
+           /// Return the length of a frame that will be emitted from this message.
+           pub fn buffer_len(&self) -> usize {
+               match self {
+                   $( $name::$variant(repr) => repr.buffer_len() ),+
+               }
+           }
+
+           /// Emit the message into `buffer`, returning the emitted frame length.
+           /// Returns `Err(Error::Exhausted)` if `buffer` is too small, and
+           /// `Err(Error::Illegal)` for the frames holding the odometer or the VIN, see
+           /// [emit_acknowledged](Self::emit_acknowledged).
+           pub fn emit(&self, buffer: &mut [u8]) -> $crate::Result<usize> {
+               let len = self.buffer_len();
+               let buffer = buffer.get_mut(..len).ok_or($crate::Error::Exhausted)?;
+               match self {
+                   $( $name::$variant(repr) => $crate::frame::PsaFrame::emit(repr, buffer)? ),+
+               }
+               Ok(len)
+           }
+
+           /// Emit the message into `buffer` like [emit](Self::emit), including the frames
+           /// holding the odometer or the VIN, whose writes are acknowledged by `token`.
+           pub fn emit_acknowledged(
+               &self,
+               buffer: &mut [u8],
+               token: $crate::frame::DangerToken,
+           ) -> $crate::Result<usize> {
+               let len = self.buffer_len();
+               let buffer = buffer.get_mut(..len).ok_or($crate::Error::Exhausted)?;
+               match self {
+                   $( $name::$variant(repr) => $crate::frame::PsaFrame::emit_acknowledged(repr, buffer, token)? ),+
+               }
+               Ok(len)
+           }
+
+           /// Parse a raw CAN frame received from an adapter.
+           /// Returns `Err(Error::Unrecognized)` for remote frames, extended identifier frames
+           /// and frames with an unsupported identifier.
+           pub fn from_can_frame(
+               frame: &$crate::transport::slcan::CanFrame,
+           ) -> $crate::Result<$name> {
+               match (frame.id.standard(), frame.remote) {
+                   (Some(id), false) => $name::parse(id, frame.payload()),
+                   _ => Err($crate::Error::Unrecognized),
+               }
+           }
+
+           /// Emit the message into a raw CAN frame to send to an adapter.
+           /// Returns `Err(Error::Illegal)` for the frames holding the odometer or the VIN, see
+           /// [to_can_frame_acknowledged](Self::to_can_frame_acknowledged).
+           pub fn to_can_frame(&self) -> $crate::Result<$crate::transport::slcan::CanFrame> {
+               let mut data = [0u8; $crate::transport::slcan::MAX_DATA_LEN];
+               let len = self.emit(&mut data)?;
+               $crate::transport::slcan::CanFrame::new(self.frame_id(), &data[..len])
+           }
+
+           /// Emit the message into a raw CAN frame like [to_can_frame](Self::to_can_frame),
+           /// including the frames holding the odometer or the VIN, whose writes are
+           /// acknowledged by `token`.
+           pub fn to_can_frame_acknowledged(
+               &self,
+               token: $crate::frame::DangerToken,
+           ) -> $crate::Result<$crate::transport::slcan::CanFrame> {
+               let mut data = [0u8; $crate::transport::slcan::MAX_DATA_LEN];
+               let len = self.emit_acknowledged(&mut data, token)?;
+               $crate::transport::slcan::CanFrame::new(self.frame_id(), &data[..len])
+           }
+
+           /// Emit the message into an [OwnedFrame]($crate::owned::OwnedFrame).
+           /// Returns `Err(Error::Illegal)` for the frames holding the odometer or the VIN.
+           #[cfg(feature = "alloc")]
+           pub fn to_owned_frame(&self) -> $crate::Result<$crate::owned::OwnedFrame> {
+               let mut data = ::alloc::vec![0; self.buffer_len()];
+               self.emit(&mut data)?;
+               Ok($crate::owned::OwnedFrame {
+                   id: self.frame_id(),
+                   data,
+               })
+           }
+       }
+
+       impl ::core::fmt::Display for $name {
+           fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+               match self {
+                   $( $name::$variant(repr) => ::core::fmt::Display::fmt(repr, f) ),+
+               }
+           }
+       }
+
+       impl $crate::validate::Validate for $name {
+           fn is_known(&self) -> bool {
+               match self {
+                   $( $name::$variant(repr) => $crate::validate::Validate::is_known(repr) ),+
+               }
+           }
+       }
+
+       $(
+         impl ::core::convert::From<$frame::Repr> for $name {
+             #[inline]
+             fn from(repr: $frame::Repr) -> Self {
+                 $name::$variant(repr)
+             }
+         }
+       )+
+   }
+}
+
+/// Implement the CAN FD accessors of the `Frame` wrapper of a frame module.
+///
+/// Newer platforms carry classic frames padded into longer CAN FD frames. These accessors
+/// accept buffers longer than `FRAME_LEN`, the accessor methods only reading the first
+/// `FRAME_LEN` bytes, and split the buffer into the frame payload and the padding bytes.
+macro_rules! frame_padding {
+    () => {
+        impl<T: AsRef<[u8]>> Frame<T> {
+            /// Shorthand for a combination of [new_unchecked] and [check_len_min], for frames
+            /// padded into a longer CAN FD frame.
+            ///
+            /// [new_unchecked]: #method.new_unchecked
+            /// [check_len_min]: #method.check_len_min
+            #[inline]
+            pub fn new_padded(buffer: T) -> $crate::Result<Frame<T>> {
+                let packet = Self::new_unchecked(buffer);
+                packet.check_len_min()?;
+                Ok(packet)
+            }
+
+            /// Ensure that no accessor method will panic if called, allowing padding bytes
+            /// after the frame payload.
+            /// Returns `Err(Error::Truncated)` if the buffer is too short.
+            #[inline]
+            pub fn check_len_min(&self) -> $crate::Result<()> {
+                if self.buffer.as_ref().len() < FRAME_LEN {
+                    Err($crate::Error::Truncated)
+                } else {
+                    Ok(())
+                }
+            }
+
+            /// Return the frame payload, without the padding bytes.
+            ///
+            /// # Panics
+            /// This function panics if the buffer is shorter than the frame length.
+            #[inline]
+            pub fn payload(&self) -> &[u8] {
+                &self.buffer.as_ref()[..FRAME_LEN]
+            }
+
+            /// Return the padding bytes following the frame payload.
+            ///
+            /// # Panics
+            /// This function panics if the buffer is shorter than the frame length.
+            #[inline]
+            pub fn padding(&self) -> &[u8] {
+                &self.buffer.as_ref()[FRAME_LEN..]
+            }
+        }
+
+        impl Repr {
+            /// Parse a frame padded into a longer CAN FD frame, ignoring the padding bytes.
+            /// Returns `Err(Error::Truncated)` if the buffer is too short.
+            pub fn parse_padded<T: AsRef<[u8]> + ?Sized>(
+                frame: &Frame<&T>,
+            ) -> $crate::Result<Repr> {
+                frame.check_len_min()?;
+                Repr::parse(&Frame::new_unchecked(frame.payload()))
+            }
+        }
+    };
+}
+
+/// Implement the fixed length `FixedFrame` of a frame module.
+///
+/// A `FixedFrame` owns an array of exactly `FRAME_LEN` bytes, so its length is checked at
+/// compile time: the accessor methods cannot panic, and the length check of `Repr::parse` is
+/// optimized out when parsing it with `Repr::parse_fixed`. The slice based `Frame` stays
+/// available for buffers whose length is only known at runtime.
+///
+/// The frames holding the odometer or the VIN use `frame_fixed!(record)`: their `Repr::emit`
+/// takes a [DangerToken](crate::frame::DangerToken), so they do not convert from a `Repr`.
+macro_rules! frame_fixed {
+    () => {
+        frame_fixed!(record);
+
+        impl From<&Repr> for Frame<[u8; FRAME_LEN]> {
+            fn from(repr: &Repr) -> Self {
+                let mut frame = Frame::from_array([0u8; FRAME_LEN]);
+                repr.emit(&mut frame);
+                frame
+            }
+        }
+    };
+    (record) => {
+        /// A frame wrapper around an array of exactly [FRAME_LEN] bytes.
+        pub type FixedFrame = Frame<[u8; FRAME_LEN]>;
+
+        impl Frame<[u8; FRAME_LEN]> {
+            /// Create a fixed length frame. No length check is needed.
+            #[inline]
+            pub const fn from_array(buffer: [u8; FRAME_LEN]) -> FixedFrame {
+                Frame { buffer }
+            }
+        }
+
+        impl From<[u8; FRAME_LEN]> for Frame<[u8; FRAME_LEN]> {
+            fn from(buffer: [u8; FRAME_LEN]) -> Self {
+                Frame::from_array(buffer)
+            }
+        }
+
+        impl Repr {
+            /// Parse a fixed length frame.
+            /// Unlike [Repr::parse], it never returns `Err(Error::Truncated)` nor
+            /// `Err(Error::Overlong)`.
+            #[inline]
+            pub fn parse_fixed(frame: &FixedFrame) -> $crate::Result<Repr> {
+                Repr::parse(&Frame::new_unchecked(&frame.buffer))
+            }
+        }
+    };
+}
+
+/// Define the `Frame` wrapper and the `Repr` of a CAN frame whose fields all fit in one byte.
+///
+/// Every `Repr` field is annotated with its location in the frame: the byte index, the mask
+/// and the shift of the field in the byte, the names of the `Frame` accessor and setter, a
+/// description and optionally the OEM signal name. `flag` fields are `bool`s, `field` fields
+/// are any type convertible from and to `u8`, like `u8` or the `enum_with_unknown!` enums.
+///
+/// The generated accessor and setter documentation gives the description, the byte and mask
+/// of the field and the OEM signal name, so the frame layout can be read from the rustdoc. The
+/// OEM name follows the description, e.g. `"VSM_INF_PROFILS_UNITE_CONSO_HS7_260"` for the x260
+/// consumption unit.
+///
+/// ```ignore
+/// can_frame! {
+///     /// A high-level representation of a x1a5 CAN frame.
+///     #[derive(Debug, PartialEq, Eq, Clone)]
+///     pub struct Repr {
+///         #[field(field::VOLUME, 0x1f, 0, volume_level, set_volume_level, "audio volume level")]
+///         pub volume: u8,
+///     }
+/// }
+/// ```
+///
+/// The module still declares the `field` module, `FRAME_ID` and `FRAME_LEN`.
+macro_rules! can_frame {
+   (
+       $( #[$repr_attr:meta] )*
+       pub struct Repr {
+           $(
+             #[$kind:ident($byte:path, $mask:literal, $shift:literal, $getter:ident, $setter:ident, $doc:literal $(, $oem:literal)?)]
+             pub $field:ident: $ty:ty,
+           )+
+       }
+   ) => {
+       /// A read/write wrapper around an CAN frame buffer.
+       #[derive(Debug, PartialEq, Clone)]
+       #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+       pub struct Frame<T: AsRef<[u8]>> {
+           buffer: T,
+       }
+
+       impl<T: AsRef<[u8]>> Frame<T> {
+           /// Create a raw octet buffer with a CAN frame structure.
+           #[inline]
+           pub fn new_unchecked(buffer: T) -> Frame<T> {
+               Frame { buffer }
+           }
+
+           /// Shorthand for a combination of [new_unchecked] and [check_len].
+           ///
+           /// [new_unchecked]: #method.new_unchecked
+           /// [check_len]: #method.check_len
+           #[inline]
+           pub fn new_checked(buffer: T) -> $crate::Result<Frame<T>> {
+               let packet = Self::new_unchecked(buffer);
+               packet.check_len()?;
+               Ok(packet)
+           }
+
+           /// Ensure that no accessor method will panic if called.
+           /// Returns `Err(Error::Truncated)` if the buffer is too short.
+           #[inline]
+           pub fn check_len(&self) -> $crate::Result<()> {
+               let len = self.buffer.as_ref().len();
+               match len.cmp(&FRAME_LEN) {
+                   ::core::cmp::Ordering::Less => Err($crate::Error::Truncated),
+                   ::core::cmp::Ordering::Greater => Err($crate::Error::Overlong),
+                   ::core::cmp::Ordering::Equal => Ok(()),
+               }
+           }
+
+           /// Consume the frame, returning the underlying buffer.
+           #[inline]
+           pub fn into_inner(self) -> T {
+               self.buffer
+           }
+
+           /// Return the frame length.
+           #[inline]
+           pub fn frame_len(&self) -> usize {
+               FRAME_LEN
+           }
+
+           $(
+             #[doc = concat!("Return the ", $doc, " ", can_frame!(@noun $kind), ".")]
+             #[doc = ""]
+             #[doc = concat!("Mask `", stringify!($mask), "` of byte `", stringify!($byte), "`.")]
+             $( #[doc = concat!("OEM signal `", $oem, "`.")] )?
+             #[inline]
+             pub fn $getter(&self) -> $ty {
+                 let data = self.buffer.as_ref();
+                 let raw = (data[$byte] & $mask) >> $shift;
+                 can_frame!(@from_raw $kind, $ty, raw)
+             }
+           )+
+       }
+
+       impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
+           $(
+             #[doc = concat!("Set the ", $doc, " ", can_frame!(@noun $kind), ".")]
+             #[doc = ""]
+             #[doc = concat!("Mask `", stringify!($mask), "` of byte `", stringify!($byte), "`.")]
+             $( #[doc = concat!("OEM signal `", $oem, "`.")] )?
+             #[inline]
+             pub fn $setter(&mut self, value: $ty) {
+                 let data = self.buffer.as_mut();
+                 let raw = data[$byte] & !$mask;
+                 data[$byte] = raw | ((u8::from(value) << $shift) & $mask);
+             }
+           )+
+       }
+
+       impl<T: AsRef<[u8]> + ?Sized> ::core::fmt::Display for Frame<&T> {
+           fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+               match Repr::parse(self) {
+                   Ok(repr) => ::core::fmt::Display::fmt(&repr, f),
+                   Err(err) => write!(f, "x{:03x} ({})", FRAME_ID, err),
+               }
+           }
+       }
+
+       impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
+           fn as_ref(&self) -> &[u8] {
+               self.buffer.as_ref()
+           }
+       }
+
+       frame_padding!();
+       frame_fixed!();
+
+       $( #[$repr_attr] )*
+       pub struct Repr {
+           $( pub $field: $ty, )+
+       }
+
+       impl Repr {
+           pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> $crate::Result<Repr> {
+               frame.check_len()?;
+
+               Ok(Repr::decode(frame))
+           }
+
+           /// Decode a frame whose length is checked.
+           fn decode<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&T>) -> Repr {
+               Repr {
+                   $( $field: frame.$getter(), )+
+               }
+           }
+
+           /// Return the length of a frame that will be emitted from this high-level representation.
+           pub fn buffer_len(&self) -> usize {
+               FRAME_LEN
+           }
+
+           /// Emit a high-level representation into a CAN frame.
+           pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+               $( frame.$setter(self.$field); )+
+           }
+       }
+
+       impl ::core::fmt::Display for Repr {
+           fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+               let sep = $crate::frame::field_separator(f);
+               write!(f, "x{:03x}", FRAME_ID)?;
+               $( write!(f, concat!("{}", stringify!($field), "={}"), sep, self.$field)?; )+
+               Ok(())
+           }
+       }
+   };
+   (@noun flag) => {
+       "flag"
+   };
+   (@noun field) => {
+       "field"
+   };
+   (@from_raw flag, $ty:ty, $raw:expr) => {
+       $raw != 0
+   };
+   (@from_raw field, $ty:ty, $raw:expr) => {
+       <$ty>::from($raw)
+   };
+}
+
+macro_rules! repr_builder {
+   (@defmt $value:expr) => {
+       $value
+   };
+   (@defmt $value:expr, $defmt:ident) => {
+       ::defmt::$defmt(&$value)
+   };
+   (@setter [$( #[$attr:meta] )*] $setter:ident $field:ident $ty:ty) => {
+       $( #[$attr] )*
+       #[doc = concat!("Set the `", stringify!($field), "` field.")]
+       pub fn $setter(mut self, value: $ty) -> ReprBuilder {
+           self.$field = Some(value);
+           self
+       }
+   };
+   (@setter [$( #[$attr:meta] )*] $setter:ident $field:ident $ty:ty, $token:ident) => {
+       $( #[$attr] )*
+       #[doc = concat!("Set the `", stringify!($field), "` field, `token` acknowledging its write.")]
+       pub fn $setter(mut self, value: $ty, $token: $crate::frame::DangerToken) -> ReprBuilder {
+           let _ = $token;
+           self.$field = Some(value);
+           self
+       }
+   };
+   (@emit [] $repr:expr, $frame:expr) => {
+       $repr.emit($frame)
+   };
+   (@emit [record] $repr:expr, $frame:expr) => {
+       $repr.emit($frame, $crate::frame::DangerToken::unchecked())
+   };
+   // `PARKED_FRAME_BYTES` holds exactly `FRAME_LEN` bytes, so decoding it cannot fail.
+   (@default []) => {
+       Repr::decode(&Frame::new_unchecked(&PARKED_FRAME_BYTES))
+   };
+   (@default [$default:expr]) => {
+       $default
+   };
+   (@defmt_payload [] $repr:expr, $f:ident) => {
+       let mut payload = [0u8; FRAME_LEN];
+       $repr.emit(&mut Frame::new_unchecked(&mut payload[..]));
+       ::defmt::write!($f, "x{=u16:x} {=[u8]:x}", FRAME_ID, payload);
+   };
+   (@defmt_payload [record] $repr:expr, $f:ident) => {
+       ::defmt::write!($f, "x{=u16:x}", FRAME_ID);
+   };
+   // The frames holding the odometer or the VIN start with `record,`: their `Repr::emit`
+   // takes a `DangerToken`, and so do the builder setters of these fields, which are
+   // followed by `(token)`.
+   (record, $($rest:tt)+) => {
+       repr_builder!(@mode [record] $($rest)+);
+   };
+   (idle: $($rest:tt)+) => {
+       repr_builder!(@mode [] idle: $($rest)+);
+   };
+   (
+       @mode [$($mode:ident)?]
+       idle: [$($byte:expr),+ $(,)?],
+       $(
+         $( #[$field_attr:meta] )*
+         $setter:ident $(($token:ident))? => $field:ident: $ty:ty $(as $defmt:ident)?
+       ),+ $(,)?
+   ) => {
+       // Without an explicit parked content, the fields of a parked vehicle are all zero.
+       repr_builder! {
+           @mode [$($mode)?]
+           idle: [$($byte),+],
+           parked: [0x00; FRAME_LEN],
+           $(
+             $( #[$field_attr] )*
+             $setter $(($token))? => $field: $ty $(as $defmt)?
+           ),+
+       }
+   };
+   // Fields whose type does not implement `defmt::Format`, e.g. the `time` crate types, are
+   // followed by `as Debug2Format` to be formatted through their `Debug` implementation.
+   //
+   // The `Repr` default is decoded from the parked content. The frames whose fields cannot
+   // be decoded from any content, e.g. a date, give it after the parked content instead:
+   // `parked: [...] => Repr { ... },`.
+   (
+       @mode [$($mode:ident)?]
+       idle: [$($byte:expr),+ $(,)?],
+       parked: $parked:expr $(=> $default:expr)?,
+       $(
+         $( #[$field_attr:meta] )*
+         $setter:ident $(($token:ident))? => $field:ident: $ty:ty $(as $defmt:ident)?
+       ),+ $(,)?
+   ) => {
+       /// Reference frame content providing the values of the fields left unset by
+       /// [ReprBuilder].
+       pub const IDLE_FRAME_BYTES: [u8; FRAME_LEN] = [$($byte),+];
+
+       /// Frame content of a parked vehicle with the ignition off, providing the [Repr]
+       /// default: all the fields hold documented values, validity flags are set and
+       /// temperatures are not at their raw zero offset.
+       pub const PARKED_FRAME_BYTES: [u8; FRAME_LEN] = $parked;
+
+       // Fail the build on signals out of the frame or overlapping each other.
+       const _: () = $crate::signal::check_layout(SIGNALS, FRAME_LEN);
+
+       /// A [Repr] builder. Fields which are not set keep their value from
+       /// [IDLE_FRAME_BYTES].
+       #[derive(Debug, Clone, Default)]
+       pub struct ReprBuilder {
+           $(
+             $( #[$field_attr] )*
+             $field: Option<$ty>,
+           )+
+       }
+
+       impl ReprBuilder {
+           /// Create a builder with every field unset.
+           pub fn new() -> ReprBuilder {
+               ReprBuilder::default()
+           }
+
+           $(
+             repr_builder!(@setter [$( #[$field_attr] )*] $setter $field $ty $(, $token)?);
+           )+
+
+           /// Build the [Repr], unset fields being parsed from [IDLE_FRAME_BYTES].
+           pub fn build(self) -> $crate::Result<Repr> {
+               let mut repr = Repr::parse(&Frame::new_unchecked(&IDLE_FRAME_BYTES))?;
+               $(
+                 $( #[$field_attr] )*
+                 if let Some(value) = self.$field {
+                     repr.$field = value;
+                 }
+               )+
+               Ok(repr)
+           }
+       }
+
+       impl Repr {
+           /// Return a builder for this representation.
+           pub fn builder() -> ReprBuilder {
+               ReprBuilder::new()
+           }
+
+           /// Render the representation into `w` in the given style, without allocation.
+           /// Returns `Err(fmt::Error)` if `w` fails, e.g. a full
+           /// [TruncatingWriter](crate::frame::TruncatingWriter).
+           pub fn write_to<W: ::core::fmt::Write>(
+               &self,
+               w: &mut W,
+               style: $crate::frame::Style,
+           ) -> ::core::fmt::Result {
+               match style {
+                   $crate::frame::Style::Compact => write!(w, "{}", self),
+                   $crate::frame::Style::Multiline => write!(w, "{:#}", self),
+               }
+           }
+       }
+
+       impl Default for Repr {
+           /// Return the representation of [PARKED_FRAME_BYTES].
+           fn default() -> Repr {
+               repr_builder!(@default [$($default)?])
+           }
+       }
+
+       #[cfg(test)]
+       #[test]
+       fn test_parked_frame_bytes() {
+           let frame = Frame::new_unchecked(&PARKED_FRAME_BYTES);
+           let repr = Repr::parse(&frame).unwrap();
+           assert_eq!(repr, Repr::default());
+           assert_eq!(
+               $crate::validate::ValidateFields::validate(&repr).next(),
+               None,
+               "unknown field value in PARKED_FRAME_BYTES"
+           );
+
+           let mut buffer = [0u8; FRAME_LEN];
+           repr_builder!(@emit [$($mode)?] repr, &mut Frame::new_unchecked(&mut buffer[..]));
+           assert_eq!(buffer, PARKED_FRAME_BYTES);
+       }
+
+       /// Compact formatting with the `defmt-compact` feature, keeping the string table small:
+       /// the frame identifier, the emitted payload unless the frame holds the odometer or the
+       /// VIN, and the fields differing from the [Repr] default.
+       #[cfg(feature = "defmt-compact")]
+       impl ::defmt::Format for Repr {
+           fn format(&self, f: ::defmt::Formatter) {
+               repr_builder!(@defmt_payload [$($mode)?] self, f);
+               let default = Repr::default();
+               $(
+                 $( #[$field_attr] )*
+                 if self.$field != default.$field {
+                     ::defmt::write!(
+                         f,
+                         " {=str}={}",
+                         stringify!($field),
+                         repr_builder!(@defmt self.$field $(, $defmt)?)
+                     );
+                 }
+               )+
+           }
+       }
+
+       impl $crate::diff::Diff for Repr {
+           const FIELDS: &'static [&'static str] = &[
+               $( $( #[$field_attr] )* stringify!($field), )+
+           ];
+
+           fn field_changed(&self, other: &Repr, name: &str) -> bool {
+               // Destructure to fail the build if a field is missing from the list.
+               let Repr { $( $( #[$field_attr] )* $field, )+ } = self;
+               match name {
+                   $( $( #[$field_attr] )* stringify!($field) => *$field != other.$field, )+
+                   _ => false,
+               }
+           }
+       }
+
+       impl $crate::validate::Validate for Repr {
+           fn is_known(&self) -> bool {
+               $crate::validate::ValidateFields::validate(self).next().is_none()
+           }
+       }
+
+       impl $crate::validate::ValidateFields for Repr {
+           fn field_known(&self, name: &str) -> bool {
+               match name {
+                   $( $( #[$field_attr] )* stringify!($field) => $crate::validate::Validate::is_known(&self.$field), )+
+                   _ => true,
                }
            }
        }
@@ -1,5 +1,7 @@
 use core::fmt;
 
+use crate::{aee2010::infodiv::x167, Result};
+
 enum_with_unknown! {
    /// Trip computer displayed page on multi-function display.
    pub enum TripComputerPage(u8) {
@@ -87,6 +89,30 @@ impl fmt::Display for UserAction2010 {
     }
 }
 
+impl From<UserAction2004> for UserAction2010 {
+    fn from(action_2004: UserAction2004) -> Self {
+        match action_2004 {
+            UserAction2004::NoAction => UserAction2010::NoAction,
+            UserAction2004::Yes | UserAction2004::ValueReturn => UserAction2010::Yes,
+            UserAction2004::No | UserAction2004::Esc | UserAction2004::Timeout => {
+                UserAction2010::Dismiss
+            }
+            UserAction2004::Unknown(action) => UserAction2010::Unknown(action),
+        }
+    }
+}
+
+impl From<UserAction2010> for UserAction2004 {
+    fn from(action_2010: UserAction2010) -> Self {
+        match action_2010 {
+            UserAction2010::NoAction => UserAction2004::NoAction,
+            UserAction2010::Dismiss => UserAction2004::Esc,
+            UserAction2010::Yes => UserAction2004::Yes,
+            UserAction2010::Unknown(action) => UserAction2004::Unknown(action),
+        }
+    }
+}
+
 enum_with_unknown! {
    /// Popup to display on MFD.
    pub enum Popup(u8) {
@@ -159,3 +185,565 @@ impl fmt::Display for Menu {
         }
     }
 }
+
+/// Trip computer trip counter.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Trip {
+    /// Primary trip, aka trip 1.
+    Primary,
+    /// Secondary trip, aka trip 2.
+    Secondary,
+}
+
+impl fmt::Display for Trip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Trip::Primary => write!(f, "primary"),
+            Trip::Secondary => write!(f, "secondary"),
+        }
+    }
+}
+
+/// Device which requested a trip computer reset.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TripResetSource {
+    /// Long press on the cluster stalk trip computer push button.
+    ClusterStalk,
+    /// Multi-function display menu.
+    Mfd,
+    /// Navigation and connectivity head unit (NAC).
+    Nac,
+}
+
+impl fmt::Display for TripResetSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TripResetSource::ClusterStalk => write!(f, "cluster stalk"),
+            TripResetSource::Mfd => write!(f, "MFD"),
+            TripResetSource::Nac => write!(f, "NAC"),
+        }
+    }
+}
+
+/// Reset statistics of a trip counter.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TripResetStats {
+    /// Source of the last reset, if any.
+    pub last_source: Option<TripResetSource>,
+    /// Number of resets requested with the cluster stalk.
+    pub cluster_stalk_count: u32,
+    /// Number of resets requested from the MFD menu.
+    pub mfd_count: u32,
+    /// Number of resets requested from the NAC.
+    pub nac_count: u32,
+}
+
+impl TripResetStats {
+    /// Return the total number of resets, saturating at `u32::MAX`.
+    pub fn count(&self) -> u32 {
+        self.cluster_stalk_count
+            .saturating_add(self.mfd_count)
+            .saturating_add(self.nac_count)
+    }
+
+    fn record(&mut self, source: TripResetSource) {
+        self.last_source = Some(source);
+        let count = match source {
+            TripResetSource::ClusterStalk => &mut self.cluster_stalk_count,
+            TripResetSource::Mfd => &mut self.mfd_count,
+            TripResetSource::Nac => &mut self.nac_count,
+        };
+        *count = count.saturating_add(1);
+    }
+}
+
+/// Number of consecutive x221 frames with the trip computer push button pressed
+/// for the press to be a reset long press. x221 is sent every second.
+pub const STALK_LONG_PRESS_FRAMES: u8 = 2;
+
+/// Trip computer model tracking trip reset requests from the x167, x1a9 and x221 frames.
+///
+/// Reset requests are held by their emitter during several frames, only a rising
+/// edge counts as one reset.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TripComputer {
+    /// Page displayed on the multi-function display, if known.
+    pub page: Option<TripComputerPage>,
+    /// Primary trip reset statistics.
+    pub primary: TripResetStats,
+    /// Secondary trip reset statistics.
+    pub secondary: TripResetStats,
+    mfd_requests: (bool, bool),
+    nac_requests: (bool, bool),
+    stalk_pressed_frames: u8,
+}
+
+impl TripComputer {
+    /// Create a trip computer model with no recorded reset.
+    pub fn new() -> TripComputer {
+        TripComputer::default()
+    }
+
+    /// Return the reset statistics of `trip`.
+    pub fn stats(&self, trip: Trip) -> &TripResetStats {
+        match trip {
+            Trip::Primary => &self.primary,
+            Trip::Secondary => &self.secondary,
+        }
+    }
+
+    /// Update the model from an AEE2004 x167 MFD request frame.
+    pub fn update_from_mfd_2004(&mut self, repr: &crate::aee2004::conf::x167::Repr) {
+        self.page = Some(repr.mfd_trip_computer_page);
+        self.mfd_requests = self.track(
+            TripResetSource::Mfd,
+            self.mfd_requests,
+            (
+                repr.trip_computer_primary_trip_reset_request,
+                repr.trip_computer_secondary_trip_reset_request,
+            ),
+        );
+    }
+
+    /// Update the model from an AEE2010 x167 MFD request frame.
+    pub fn update_from_mfd_2010(&mut self, repr: &crate::aee2010::infodiv::x167::Repr) {
+        self.page = Some(repr.mfd_trip_computer_page);
+        self.mfd_requests = self.track(
+            TripResetSource::Mfd,
+            self.mfd_requests,
+            (
+                repr.trip_computer_primary_trip_reset_request,
+                repr.trip_computer_secondary_trip_reset_request,
+            ),
+        );
+    }
+
+    /// Update the model from an AEE2010 x1a9 NAC request frame.
+    pub fn update_from_nac(&mut self, repr: &crate::aee2010::infodiv::x1a9::Repr) {
+        self.nac_requests = self.track(
+            TripResetSource::Nac,
+            self.nac_requests,
+            (
+                repr.trip_computer_primary_trip_reset_request,
+                repr.trip_computer_secondary_trip_reset_request,
+            ),
+        );
+    }
+
+    /// Update the model from the trip computer push button state of a x221 frame,
+    /// on either architecture. A long press resets the trip displayed on the MFD.
+    pub fn update_from_stalk(&mut self, push_button_pressed: bool) {
+        if !push_button_pressed {
+            self.stalk_pressed_frames = 0;
+            return;
+        }
+
+        self.stalk_pressed_frames = self.stalk_pressed_frames.saturating_add(1);
+        if self.stalk_pressed_frames != STALK_LONG_PRESS_FRAMES {
+            return;
+        }
+
+        match self.page {
+            Some(TripComputerPage::Trip1) => self.primary.record(TripResetSource::ClusterStalk),
+            Some(TripComputerPage::Trip2) => self.secondary.record(TripResetSource::ClusterStalk),
+            _ => {}
+        }
+    }
+
+    fn track(
+        &mut self,
+        source: TripResetSource,
+        previous: (bool, bool),
+        current: (bool, bool),
+    ) -> (bool, bool) {
+        if current.0 && !previous.0 {
+            self.primary.record(source);
+        }
+        if current.1 && !previous.1 {
+            self.secondary.record(source);
+        }
+        current
+    }
+}
+
+/// Length of a serialized [AccumulatorState].
+pub const ACCUMULATOR_STATE_LEN: usize = 16;
+
+/// Persisted totals of a [TripAccumulator].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccumulatorState {
+    /// Total distance, in cm.
+    pub distance: u64,
+    /// Total fuel consumption, in mm3.
+    pub fuel_consumption: u64,
+}
+
+impl AccumulatorState {
+    /// Serialize the state into little-endian bytes, e.g. for an EEPROM or flash page.
+    pub fn to_bytes(&self) -> [u8; ACCUMULATOR_STATE_LEN] {
+        let mut bytes = [0u8; ACCUMULATOR_STATE_LEN];
+        bytes[..8].copy_from_slice(&self.distance.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.fuel_consumption.to_le_bytes());
+        bytes
+    }
+
+    /// Deserialize a state serialized with [AccumulatorState::to_bytes].
+    pub fn from_bytes(bytes: &[u8; ACCUMULATOR_STATE_LEN]) -> AccumulatorState {
+        let mut distance = [0u8; 8];
+        let mut fuel_consumption = [0u8; 8];
+        distance.copy_from_slice(&bytes[..8]);
+        fuel_consumption.copy_from_slice(&bytes[8..]);
+        AccumulatorState {
+            distance: u64::from_le_bytes(distance),
+            fuel_consumption: u64::from_le_bytes(fuel_consumption),
+        }
+    }
+}
+
+/// Distance and fuel consumption totals accumulated from the x0b6 counters.
+///
+/// x0b6 carries a 16-bit distance counter in cm and an 8-bit fuel consumption counter in mm3,
+/// both counting since the vehicle start and wrapping around within minutes. The accumulator
+/// adds the increments of the counters between consecutive frames, wrap-around included, to
+/// monotonic totals which survive power cycles through [TripAccumulator::state] and
+/// [TripAccumulator::from_state]. x221 only carries instantaneous values and needs no
+/// accumulation.
+///
+/// The counters restart from zero when the BSI wakes up: call [TripAccumulator::restart] when
+/// the network wakes up so the restart is not taken for a wrap-around.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TripAccumulator {
+    state: AccumulatorState,
+    last: Option<(u16, u8)>,
+}
+
+impl TripAccumulator {
+    /// Create an accumulator with zero totals.
+    pub fn new() -> TripAccumulator {
+        TripAccumulator::default()
+    }
+
+    /// Create an accumulator continuing from persisted totals.
+    pub fn from_state(state: AccumulatorState) -> TripAccumulator {
+        TripAccumulator { state, last: None }
+    }
+
+    /// Return the totals to persist.
+    pub fn state(&self) -> AccumulatorState {
+        self.state
+    }
+
+    /// Return the total distance, in cm.
+    pub fn distance(&self) -> u64 {
+        self.state.distance
+    }
+
+    /// Return the total fuel consumption, in mm3.
+    pub fn fuel_consumption(&self) -> u64 {
+        self.state.fuel_consumption
+    }
+
+    /// Accumulate the raw x0b6 counters `trip_odometer` and `trip_fuel_consumption`.
+    /// The first counters after creation or [TripAccumulator::restart] are only recorded as
+    /// the reference of the next increments.
+    pub fn update(&mut self, trip_odometer: u16, trip_fuel_consumption: u8) {
+        if let Some((odometer, fuel_consumption)) = self.last {
+            let distance = trip_odometer.wrapping_sub(odometer);
+            let fuel = trip_fuel_consumption.wrapping_sub(fuel_consumption);
+            self.state.distance = self.state.distance.saturating_add(distance.into());
+            self.state.fuel_consumption = self.state.fuel_consumption.saturating_add(fuel.into());
+        }
+        self.last = Some((trip_odometer, trip_fuel_consumption));
+    }
+
+    /// Accumulate the counters of an AEE2004 x0b6 frame.
+    pub fn update_from_aee2004(&mut self, repr: &crate::aee2004::conf::x0b6::Repr) {
+        self.update(repr.trip_odometer, repr.trip_fuel_consumption);
+    }
+
+    /// Accumulate the counters of an AEE2010 x0b6 frame.
+    pub fn update_from_aee2010(&mut self, repr: &crate::aee2010::infodiv::x0b6::Repr) {
+        self.update(repr.trip_odometer, repr.trip_fuel_consumption);
+    }
+
+    /// Forget the last counters, keeping the totals, e.g. when the network wakes up and the
+    /// BSI counters restart.
+    pub fn restart(&mut self) {
+        self.last = None;
+    }
+}
+
+/// Request sent by the AEE2010 multi-function display in x167 frames.
+///
+/// x167 packs the page displayed by the trip computer together with one-shot requests to
+/// the BSI. [EmfRequest] gives a name to the common combinations of the fields: it builds
+/// a [x167::Repr] for a request with [EmfRequest::to_repr] and recovers the request carried
+/// by an incoming frame with [EmfRequest::decode].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EmfRequest {
+    /// Display a trip computer page, no other request.
+    ShowTripPage(TripComputerPage),
+    /// Reset a trip counter.
+    ResetTrip(Trip),
+    /// Reset the maintenance countdown.
+    ResetMaintenance,
+    /// Reset the indirect under-inflation detection.
+    ResetUnderInflation,
+    /// Recall the check messages of the alert journal.
+    RecallFaults,
+    /// Stop the displayed check message.
+    StopCheck,
+    /// Acknowledge a popup.
+    AcknowledgePopup(Popup),
+    /// User action on a menu, e.g. entering the wifi settings menu.
+    MenuAction(Menu, UserAction2010),
+}
+
+impl EmfRequest {
+    /// Return the request carried by `repr`.
+    ///
+    /// One-shot requests take precedence over the displayed page, in the order of the
+    /// [EmfRequest] variants. If several one-shot requests are set, only the first one
+    /// is returned.
+    pub fn decode(repr: &x167::Repr) -> EmfRequest {
+        if repr.trip_computer_primary_trip_reset_request {
+            EmfRequest::ResetTrip(Trip::Primary)
+        } else if repr.trip_computer_secondary_trip_reset_request {
+            EmfRequest::ResetTrip(Trip::Secondary)
+        } else if repr.maintenance_reset_request {
+            EmfRequest::ResetMaintenance
+        } else if repr.indirect_under_inflation_reset_request {
+            EmfRequest::ResetUnderInflation
+        } else if repr.fault_recall_request {
+            EmfRequest::RecallFaults
+        } else if repr.stop_check_request {
+            EmfRequest::StopCheck
+        } else if repr.popup_id_acknowledge != Popup::NoDisplay {
+            EmfRequest::AcknowledgePopup(repr.popup_id_acknowledge)
+        } else if repr.user_action_on_mfd != UserAction2010::NoAction {
+            EmfRequest::MenuAction(repr.selected_menu, repr.user_action_on_mfd)
+        } else {
+            EmfRequest::ShowTripPage(repr.mfd_trip_computer_page)
+        }
+    }
+
+    /// Return the x167 representation carrying the request. Fields unrelated to the
+    /// request keep their value from [x167::IDLE_FRAME_BYTES].
+    pub fn to_repr(&self) -> Result<x167::Repr> {
+        let builder = x167::Repr::builder();
+        let builder = match *self {
+            EmfRequest::ShowTripPage(page) => builder.with_mfd_trip_computer_page(page),
+            EmfRequest::ResetTrip(Trip::Primary) => {
+                builder.with_trip_computer_primary_trip_reset_request(true)
+            }
+            EmfRequest::ResetTrip(Trip::Secondary) => {
+                builder.with_trip_computer_secondary_trip_reset_request(true)
+            }
+            EmfRequest::ResetMaintenance => builder.with_maintenance_reset_request(true),
+            EmfRequest::ResetUnderInflation => {
+                builder.with_indirect_under_inflation_reset_request(true)
+            }
+            EmfRequest::RecallFaults => builder.with_fault_recall_request(true),
+            EmfRequest::StopCheck => builder.with_stop_check_request(true),
+            EmfRequest::AcknowledgePopup(popup) => builder.with_popup_id_acknowledge(popup),
+            EmfRequest::MenuAction(menu, action) => builder
+                .with_selected_menu(menu)
+                .with_user_action_on_mfd(action),
+        };
+        builder.build()
+    }
+
+    /// Emit the x167 frame carrying the request into `frame`.
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut x167::Frame<T>) -> Result<()> {
+        self.to_repr()?.emit(frame);
+        Ok(())
+    }
+}
+
+impl fmt::Display for EmfRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EmfRequest::ShowTripPage(page) => write!(f, "show trip page {}", page),
+            EmfRequest::ResetTrip(trip) => write!(f, "reset {} trip", trip),
+            EmfRequest::ResetMaintenance => write!(f, "reset maintenance"),
+            EmfRequest::ResetUnderInflation => write!(f, "reset under-inflation detection"),
+            EmfRequest::RecallFaults => write!(f, "recall faults"),
+            EmfRequest::StopCheck => write!(f, "stop check"),
+            EmfRequest::AcknowledgePopup(popup) => write!(f, "acknowledge popup {}", popup),
+            EmfRequest::MenuAction(menu, action) => write!(f, "{} on menu {}", action, menu),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        AccumulatorState, EmfRequest, Menu, Popup, Trip, TripAccumulator, TripComputer,
+        TripComputerPage, TripResetSource, TripResetStats, UserAction2010, STALK_LONG_PRESS_FRAMES,
+    };
+
+    use crate::{
+        aee2004::conf::x167,
+        aee2010::infodiv::{x0b6, x167 as x167_2010, x1a9},
+    };
+
+    fn mfd_repr(primary: bool, secondary: bool) -> x167::Repr {
+        let bytes = [0u8; x167::FRAME_LEN];
+        let mut repr = x167::Repr::parse(&x167::Frame::new_unchecked(&bytes)).unwrap();
+        repr.mfd_trip_computer_page = TripComputerPage::Trip2;
+        repr.trip_computer_primary_trip_reset_request = primary;
+        repr.trip_computer_secondary_trip_reset_request = secondary;
+        repr
+    }
+
+    fn nac_repr(primary: bool) -> x1a9::Repr {
+        let bytes = [0u8; x1a9::FRAME_LEN];
+        let mut repr = x1a9::Repr::parse(&x1a9::Frame::new_unchecked(&bytes)).unwrap();
+        repr.trip_computer_primary_trip_reset_request = primary;
+        repr
+    }
+
+    #[test]
+    fn test_trip_reset_edges() {
+        let mut trip_computer = TripComputer::new();
+
+        trip_computer.update_from_mfd_2004(&mfd_repr(true, false));
+        trip_computer.update_from_mfd_2004(&mfd_repr(true, false));
+        trip_computer.update_from_mfd_2004(&mfd_repr(false, false));
+        trip_computer.update_from_mfd_2004(&mfd_repr(true, true));
+        trip_computer.update_from_nac(&nac_repr(true));
+        trip_computer.update_from_nac(&nac_repr(true));
+
+        let primary = trip_computer.stats(Trip::Primary);
+        assert_eq!(primary.mfd_count, 2);
+        assert_eq!(primary.nac_count, 1);
+        assert_eq!(primary.count(), 3);
+        assert_eq!(primary.last_source, Some(TripResetSource::Nac));
+
+        let secondary = trip_computer.stats(Trip::Secondary);
+        assert_eq!(secondary.count(), 1);
+        assert_eq!(secondary.last_source, Some(TripResetSource::Mfd));
+
+        let saturated = TripResetStats {
+            cluster_stalk_count: u32::MAX,
+            nac_count: 1,
+            ..TripResetStats::default()
+        };
+        assert_eq!(saturated.count(), u32::MAX);
+    }
+
+    #[test]
+    fn test_trip_reset_stalk_long_press() {
+        let mut trip_computer = TripComputer::new();
+
+        // Page unknown, no reset.
+        for _ in 0..STALK_LONG_PRESS_FRAMES {
+            trip_computer.update_from_stalk(true);
+        }
+        assert_eq!(trip_computer.secondary.count(), 0);
+
+        trip_computer.update_from_mfd_2004(&mfd_repr(false, false));
+        trip_computer.update_from_stalk(false);
+        // Short press.
+        trip_computer.update_from_stalk(true);
+        trip_computer.update_from_stalk(false);
+        assert_eq!(trip_computer.secondary.count(), 0);
+        // Long press, held for longer than the threshold.
+        for _ in 0..STALK_LONG_PRESS_FRAMES + 3 {
+            trip_computer.update_from_stalk(true);
+        }
+        assert_eq!(trip_computer.secondary.cluster_stalk_count, 1);
+        assert_eq!(
+            trip_computer.secondary.last_source,
+            Some(TripResetSource::ClusterStalk)
+        );
+        assert_eq!(trip_computer.primary.count(), 0);
+    }
+
+    #[test]
+    fn test_trip_accumulator() {
+        let mut accumulator = TripAccumulator::new();
+        accumulator.update(65000, 250);
+        assert_eq!(accumulator.distance(), 0);
+        accumulator.update(65500, 254);
+        accumulator.update(200, 3);
+        assert_eq!(accumulator.distance(), 500 + 236);
+        assert_eq!(accumulator.fuel_consumption(), 4 + 5);
+
+        let mut repr =
+            x0b6::Repr::parse(&x0b6::Frame::new_unchecked(&x0b6::IDLE_FRAME_BYTES)).unwrap();
+        repr.trip_odometer = 264;
+        repr.trip_fuel_consumption = 3;
+        accumulator.update_from_aee2010(&repr);
+        assert_eq!(accumulator.distance(), 800);
+        assert_eq!(accumulator.fuel_consumption(), 9);
+
+        // The counters restart from zero when the BSI wakes up.
+        accumulator.restart();
+        accumulator.update(0, 0);
+        accumulator.update(100, 1);
+        assert_eq!(accumulator.distance(), 900);
+        assert_eq!(accumulator.fuel_consumption(), 10);
+    }
+
+    #[test]
+    fn test_accumulator_state() {
+        let mut accumulator = TripAccumulator::new();
+        accumulator.update(0, 0);
+        accumulator.update(1000, 20);
+
+        let bytes = accumulator.state().to_bytes();
+        let state = AccumulatorState::from_bytes(&bytes);
+        assert_eq!(state, accumulator.state());
+
+        let mut restored = TripAccumulator::from_state(state);
+        restored.update(1000, 20);
+        assert_eq!(restored.distance(), 1000);
+        restored.update(1500, 25);
+        assert_eq!(restored.distance(), 1500);
+        assert_eq!(restored.fuel_consumption(), 25);
+    }
+
+    #[test]
+    fn test_emf_request() {
+        let requests = [
+            EmfRequest::ShowTripPage(TripComputerPage::Trip2),
+            EmfRequest::ResetTrip(Trip::Primary),
+            EmfRequest::ResetTrip(Trip::Secondary),
+            EmfRequest::ResetMaintenance,
+            EmfRequest::ResetUnderInflation,
+            EmfRequest::RecallFaults,
+            EmfRequest::StopCheck,
+            EmfRequest::AcknowledgePopup(Popup::RoamingEnded),
+            EmfRequest::MenuAction(Menu::WifiSettings, UserAction2010::Yes),
+        ];
+        for request in requests {
+            let mut bytes = [0u8; x167_2010::FRAME_LEN];
+            let mut frame = x167_2010::Frame::new_unchecked(&mut bytes);
+            request.emit(&mut frame).unwrap();
+            let repr = x167_2010::Repr::parse(&x167_2010::Frame::new_unchecked(&bytes)).unwrap();
+            assert_eq!(EmfRequest::decode(&repr), request);
+        }
+
+        let idle = x167_2010::Frame::new_unchecked(&x167_2010::IDLE_FRAME_BYTES);
+        let repr = x167_2010::Repr::parse(&idle).unwrap();
+        assert_eq!(
+            EmfRequest::decode(&repr),
+            EmfRequest::ShowTripPage(TripComputerPage::Nothing)
+        );
+        assert_eq!(
+            EmfRequest::ResetTrip(Trip::Primary)
+                .to_repr()
+                .unwrap()
+                .mfd_trip_computer_page,
+            TripComputerPage::Nothing
+        );
+    }
+}
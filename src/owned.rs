@@ -0,0 +1,103 @@
+//! Owned CAN frames.
+//!
+//! The `Frame` wrappers of the frame modules borrow their buffer, which is the right fit for
+//! parsing in place but gets in the way of storing received traffic. With the `alloc` feature,
+//! an [OwnedFrame] keeps a frame identifier and a copy of its payload, and the `Message` enums
+//! of the buses emit into one with `Message::to_owned_frame`:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::{x0b6, Message}, owned::OwnedFrame};
+//! let mut log: Vec<OwnedFrame> = Vec::new();
+//! log.push(OwnedFrame::new(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES));
+//!
+//! let message: Message = log[0].parse().unwrap();
+//! assert_eq!(message.to_owned_frame(), Ok(log[0].clone()));
+//! ```
+
+use core::fmt;
+
+use alloc::vec::Vec;
+
+use crate::{frame::BusMessage, Result};
+
+/// A CAN frame owning its payload.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct OwnedFrame {
+    /// CAN frame identifier.
+    pub id: u16,
+    /// CAN frame payload.
+    pub data: Vec<u8>,
+}
+
+impl OwnedFrame {
+    /// Create an owned frame with identifier `id`, copying `data`.
+    pub fn new(id: u16, data: &[u8]) -> OwnedFrame {
+        OwnedFrame {
+            id,
+            data: data.to_vec(),
+        }
+    }
+
+    /// Return the frame length.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return whether the frame has an empty payload.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Parse the frame into a message of the bus `M`.
+    /// Returns `Err(Error::Unrecognized)` if the frame identifier is not supported on the bus.
+    pub fn parse<M: BusMessage>(&self) -> Result<M> {
+        M::parse(self.id, &self.data)
+    }
+}
+
+impl fmt::Display for OwnedFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "x{:03x} {:02x?}", self.id, self.data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{collections::BTreeMap, vec};
+
+    use super::OwnedFrame;
+
+    use crate::{
+        aee2004::conf::{self, x0e6, x21f},
+        Error,
+    };
+
+    #[test]
+    fn test_round_trip() {
+        let frames = vec![
+            OwnedFrame::new(x0e6::FRAME_ID, &x0e6::IDLE_FRAME_BYTES),
+            OwnedFrame::new(x21f::FRAME_ID, &x21f::IDLE_FRAME_BYTES),
+        ];
+
+        let messages: BTreeMap<u16, conf::Message> = frames
+            .iter()
+            .map(|frame| (frame.id, frame.parse().unwrap()))
+            .collect();
+        assert_eq!(messages.len(), 2);
+
+        for frame in &frames {
+            assert_eq!(messages[&frame.id].to_owned_frame().as_ref(), Ok(frame));
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let frame = OwnedFrame::new(x21f::FRAME_ID, &[0x00]);
+        assert_eq!(frame.len(), 1);
+        assert_eq!(frame.parse::<conf::Message>(), Err(Error::Truncated));
+
+        let frame = OwnedFrame::new(0x7ff, &[]);
+        assert!(frame.is_empty());
+        assert_eq!(frame.parse::<conf::Message>(), Err(Error::Unrecognized));
+    }
+}
@@ -0,0 +1,208 @@
+//! Physical values.
+//!
+//! With the `float` feature, the scaled fields of the frame representations, e.g. speeds or
+//! temperatures, are decoded into [Physical] values in engineering units. [Physical] is `f32`
+//! by default, and [Fixed] with the `fixed` feature, which avoids the software floating point
+//! routines on targets without an FPU like Cortex-M0.
+
+use core::fmt;
+
+/// Signed fixed-point number with 16 fractional bits.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Fixed(i64);
+
+impl Fixed {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u32 = 16;
+
+    /// Create a fixed-point number from its raw representation.
+    pub const fn from_bits(bits: i64) -> Fixed {
+        Fixed(bits)
+    }
+
+    /// Return the raw representation of the number.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Create a fixed-point number from an integer.
+    pub const fn from_int(value: i32) -> Fixed {
+        Fixed((value as i64) << Self::FRAC_BITS)
+    }
+
+    /// Create a fixed-point number from the ratio `num / den`, rounded to the nearest
+    /// representable value. Returns zero if `den` is zero.
+    pub const fn from_ratio(num: i64, den: i64) -> Fixed {
+        if den == 0 {
+            return Fixed(0);
+        }
+        Fixed(div_round(num << Self::FRAC_BITS, den))
+    }
+
+    /// Return the integer part of the number, rounded toward zero.
+    pub const fn to_int(self) -> i64 {
+        if self.0 < 0 {
+            -(-self.0 >> Self::FRAC_BITS)
+        } else {
+            self.0 >> Self::FRAC_BITS
+        }
+    }
+
+    /// Return the number as a `f32`.
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / (1u32 << Self::FRAC_BITS) as f32
+    }
+}
+
+/// Return `num / den` rounded to the nearest integer, halves away from zero.
+const fn div_round(num: i64, den: i64) -> i64 {
+    if (num < 0) == (den < 0) {
+        (num + den / 2) / den
+    } else {
+        (num - den / 2) / den
+    }
+}
+
+impl From<f32> for Fixed {
+    /// Convert a `f32` to the nearest fixed-point number, saturating out of range values.
+    /// NaN is converted to zero.
+    fn from(value: f32) -> Fixed {
+        let bits = value * (1u32 << Fixed::FRAC_BITS) as f32;
+        Fixed(if bits < 0.0 { bits - 0.5 } else { bits + 0.5 } as i64)
+    }
+}
+
+impl From<Fixed> for f32 {
+    fn from(value: Fixed) -> f32 {
+        value.to_f32()
+    }
+}
+
+impl fmt::Display for Fixed {
+    /// Format the number in decimal notation, with up to 4 fractional digits.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = 10_000;
+        let value = div_round(self.0.saturating_mul(scale), 1i64 << Self::FRAC_BITS);
+        let sign = if value < 0 { "-" } else { "" };
+        let (int, mut frac) = (value.abs() / scale, value.abs() % scale);
+        if frac == 0 {
+            return write!(f, "{}{}", sign, int);
+        }
+        let mut digits = 4;
+        while frac % 10 == 0 {
+            frac /= 10;
+            digits -= 1;
+        }
+        write!(f, "{}{}.{:0digits$}", sign, int, frac, digits = digits)
+    }
+}
+
+/// Physical value type.
+#[cfg(all(feature = "float", not(feature = "fixed")))]
+pub type Physical = f32;
+
+/// Physical value type.
+#[cfg(feature = "fixed")]
+pub type Physical = Fixed;
+
+/// Return the physical value `(raw + offset) / div` of a raw field value.
+#[cfg(all(feature = "float", not(feature = "fixed")))]
+pub(crate) fn from_raw(raw: i64, offset: i64, div: i64) -> Physical {
+    (raw + offset) as f32 / div as f32
+}
+
+/// Return the physical value `(raw + offset) / div` of a raw field value.
+#[cfg(feature = "fixed")]
+pub(crate) fn from_raw(raw: i64, offset: i64, div: i64) -> Physical {
+    Fixed::from_ratio(raw + offset, div)
+}
+
+/// Return the raw field value of the physical value `value`, i.e. `value * div - offset`
+/// rounded to the nearest integer. Returns `None` for NaN.
+#[cfg(all(feature = "float", not(feature = "fixed")))]
+pub(crate) fn to_raw(value: Physical, offset: i64, div: i64) -> Option<i64> {
+    let raw = value * div as f32 - offset as f32;
+    if raw.is_nan() {
+        return None;
+    }
+    Some(if raw < 0.0 { raw - 0.5 } else { raw + 0.5 } as i64)
+}
+
+/// Return the raw field value of the physical value `value`, i.e. `value * div - offset`
+/// rounded to the nearest integer.
+#[cfg(feature = "fixed")]
+pub(crate) fn to_raw(value: Physical, offset: i64, div: i64) -> Option<i64> {
+    let raw = div_round(
+        value.to_bits().saturating_mul(div),
+        1i64 << Fixed::FRAC_BITS,
+    );
+    Some(raw - offset)
+}
+
+/// Return the physical value `value` as a `f32`.
+#[cfg(all(feature = "uom", feature = "float", not(feature = "fixed")))]
+pub(crate) fn to_f32(value: Physical) -> f32 {
+    value
+}
+
+/// Return the physical value `value` as a `f32`.
+#[cfg(all(feature = "uom", feature = "fixed"))]
+pub(crate) fn to_f32(value: Physical) -> f32 {
+    value.to_f32()
+}
+
+/// Return the physical value of the decimal number `units / scale`.
+#[cfg(all(test, feature = "float"))]
+pub(crate) fn decimal(units: i64, scale: i64) -> Physical {
+    from_raw(units, 0, scale)
+}
+
+macro_rules! to_raw_saturating {
+    ($($name:ident => $ty:ty),+) => {
+        $(
+          #[doc = concat!("Return the `", stringify!($ty), "` raw field value of the physical value `value`, saturating out of range values.")]
+          #[cfg(feature = "float")]
+          pub(crate) fn $name(value: Physical, offset: i64, div: i64) -> $ty {
+              let raw = to_raw(value, offset, div).unwrap_or(0);
+              raw.clamp(0, <$ty>::MAX.into()) as $ty
+          }
+        )+
+    };
+}
+
+to_raw_saturating!(to_u8 => u8, to_u16 => u16, to_u32 => u32);
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Write;
+
+    use heapless::String;
+
+    use super::Fixed;
+
+    fn display(value: Fixed) -> String<16> {
+        let mut text = String::new();
+        write!(text, "{}", value).unwrap();
+        text
+    }
+
+    #[test]
+    fn test_fixed() {
+        assert_eq!(Fixed::from_int(-40).to_bits(), -40 << 16);
+        assert_eq!(Fixed::from_ratio(135, 10), Fixed::from(13.5));
+        assert_eq!(Fixed::from_ratio(-81, 2).to_int(), -40);
+        assert_eq!(Fixed::from_ratio(1, 0), Fixed::default());
+        assert_eq!(Fixed::from_ratio(-81, 2).to_f32(), -40.5);
+        assert!(Fixed::from_int(-40) < Fixed::from_ratio(-79, 2));
+    }
+
+    #[test]
+    fn test_fixed_display() {
+        assert_eq!(display(Fixed::from_int(90)), "90");
+        assert_eq!(display(Fixed::from_ratio(-81, 2)), "-40.5");
+        assert_eq!(display(Fixed::from_ratio(65535, 100)), "655.35");
+        assert_eq!(display(Fixed::from_ratio(1, 10)), "0.1");
+        assert_eq!(display(Fixed::from_ratio(-1, 100)), "-0.01");
+    }
+}
@@ -0,0 +1,513 @@
+//! Driver profile edition.
+//!
+//! On AEE2004 vehicles, the BSI owns the personalization settings of the driver profiles and
+//! broadcasts those of the active profile in its x260 status frame. To change them, the
+//! multi-function display (x15b) or the cluster (x1db) sends the whole set of desired settings,
+//! which the BSI acknowledges by broadcasting them back in x260. [ProfileEdit] implements this
+//! handshake: it repeats the modification request every [REQUEST_PERIOD] until x260 reports
+//! the desired settings, the BSI switches to another profile, or the edit times out.
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2004::conf::x260, config::ProfileNumber, profiles::*};
+//! let current = x260::Repr::parse(&x260::Frame::new_unchecked(&x260::IDLE_FRAME_BYTES)).unwrap();
+//! let desired = x260::Repr {
+//!     mood_lighting_enabled: true,
+//!     ..current
+//! };
+//!
+//! let mut edit = ProfileEdit::new(ProfileNumber::Profile1, &desired, Requester::Display, Duration::ZERO);
+//! let mut buffer = [0u8; 8];
+//! assert_eq!(edit.poll(Duration::ZERO, &mut buffer), Ok(Some((0x15b, 8))));
+//!
+//! assert_eq!(edit.on_status(&current), EditState::Pending);
+//! assert_eq!(edit.on_status(&desired), EditState::Completed);
+//! ```
+//!
+//! The BSI only accepts personalization changes in customer mode: in factory, showroom or
+//! storage mode, the vehicle functions are restricted and the settings are not kept for the
+//! customer. [ConfigWrite] wraps a [ProfileEdit] and refuses to emit its requests until the
+//! configuration mode broadcast by the BSI, `VsmConfigMode` in x0f6 or `VehicleConfigMode` in
+//! the AEE2010 x236, reports customer mode.
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    aee2004::conf::{x0f6, x15b, x1db, x260, x361},
+    aee2010::infodiv::x236,
+    config::{ProfileNumber, UserProfile},
+    frame::next_deadline,
+    vehicle::{VehicleConfigMode, VsmConfigMode},
+    Error, Result,
+};
+
+/// Period at which a modification request is repeated until it is acknowledged.
+pub const REQUEST_PERIOD: Duration = Duration::from_millis(200);
+/// Default time given to the BSI to acknowledge a modification request.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Emitter of a profile modification request.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Requester {
+    /// Multi-function display, sending x15b frames.
+    Display,
+    /// Cluster, sending x1db frames.
+    Cluster,
+}
+
+impl Requester {
+    /// Return the identifier of the modification request frame of the requester.
+    pub fn frame_id(&self) -> u16 {
+        match *self {
+            Requester::Display => x15b::FRAME_ID,
+            Requester::Cluster => x1db::FRAME_ID,
+        }
+    }
+}
+
+impl fmt::Display for Requester {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Requester::Display => write!(f, "display"),
+            Requester::Cluster => write!(f, "cluster"),
+        }
+    }
+}
+
+/// State of a profile modification.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EditState {
+    /// The modification has not been acknowledged yet.
+    Pending,
+    /// The BSI broadcasts the desired settings.
+    Completed,
+    /// The BSI reported another active profile before acknowledging the modification.
+    Aborted,
+    /// The modification has not been acknowledged in time.
+    TimedOut,
+}
+
+impl EditState {
+    /// Return whether the modification is over, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        *self != EditState::Pending
+    }
+}
+
+impl fmt::Display for EditState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditState::Pending => write!(f, "pending"),
+            EditState::Completed => write!(f, "completed"),
+            EditState::Aborted => write!(f, "aborted"),
+            EditState::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// A modification of the settings of a driver profile.
+#[derive(Debug, Clone)]
+pub struct ProfileEdit {
+    profile: ProfileNumber,
+    settings: x260::Repr,
+    requester: Requester,
+    started: Duration,
+    timeout: Duration,
+    next_request: Duration,
+    state: EditState,
+}
+
+impl ProfileEdit {
+    /// Start at `now` the modification of the settings of `profile` to `settings`, as they
+    /// should be broadcast by the BSI in x260. The profile number and parameters validity
+    /// flag of `settings` are ignored.
+    pub fn new(
+        profile: ProfileNumber,
+        settings: &x260::Repr,
+        requester: Requester,
+        now: Duration,
+    ) -> ProfileEdit {
+        ProfileEdit {
+            profile,
+            settings: x260::Repr {
+                profile_number: profile.into(),
+                parameters_validity: true,
+                ..*settings
+            },
+            requester,
+            started: now,
+            timeout: DEFAULT_TIMEOUT,
+            next_request: now,
+            state: EditState::Pending,
+        }
+    }
+
+    /// Set the time given to the BSI to acknowledge the modification, [DEFAULT_TIMEOUT] by
+    /// default.
+    pub fn with_timeout(mut self, timeout: Duration) -> ProfileEdit {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Return the edited profile.
+    pub fn profile(&self) -> ProfileNumber {
+        self.profile
+    }
+
+    /// Return the desired settings.
+    pub fn settings(&self) -> &x260::Repr {
+        &self.settings
+    }
+
+    /// Return the emitter of the modification request.
+    pub fn requester(&self) -> Requester {
+        self.requester
+    }
+
+    /// Return the state of the modification.
+    pub fn state(&self) -> EditState {
+        self.state
+    }
+
+    /// Emit the modification request into `buffer`.
+    ///
+    /// Returns the identifier and length of the emitted frame, or `Err(Error::Exhausted)`
+    /// if `buffer` is too small.
+    pub fn emit_request(&self, buffer: &mut [u8]) -> Result<(u16, usize)> {
+        let id = self.requester.frame_id();
+        let len = match self.requester {
+            Requester::Display => {
+                let repr = x15b::Repr::from(&self.settings);
+                let buffer = buffer
+                    .get_mut(..repr.buffer_len())
+                    .ok_or(Error::Exhausted)?;
+                repr.emit(&mut x15b::Frame::new_unchecked(&mut *buffer));
+                buffer.len()
+            }
+            Requester::Cluster => {
+                let repr = x1db::Repr::from(&self.settings);
+                let buffer = buffer
+                    .get_mut(..repr.buffer_len())
+                    .ok_or(Error::Exhausted)?;
+                repr.emit(&mut x1db::Frame::new_unchecked(&mut *buffer));
+                buffer.len()
+            }
+        };
+        Ok((id, len))
+    }
+
+    /// Update the modification at `now`, and emit the modification request into `buffer`
+    /// if it is due.
+    ///
+    /// Returns the identifier and length of the emitted frame, or `None` if no request is
+    /// due or the modification is over. Returns `Err(Error::Exhausted)` if `buffer` is too
+    /// small for the request.
+    pub fn poll(&mut self, now: Duration, buffer: &mut [u8]) -> Result<Option<(u16, usize)>> {
+        if self.state == EditState::Pending && now.saturating_sub(self.started) > self.timeout {
+            self.state = EditState::TimedOut;
+        }
+        if self.state.is_finished() || self.next_request > now {
+            return Ok(None);
+        }
+
+        let emitted = self.emit_request(buffer)?;
+        self.next_request = next_deadline(self.next_request, REQUEST_PERIOD, now);
+        Ok(Some(emitted))
+    }
+
+    /// Process a x260 status frame broadcast by the BSI, and return the updated state.
+    pub fn on_status(&mut self, status: &x260::Repr) -> EditState {
+        if self.state == EditState::Pending {
+            // The parameters validity flag is not a setting.
+            let status = x260::Repr {
+                parameters_validity: true,
+                ..*status
+            };
+            if status.profile_number != UserProfile::from(self.profile) {
+                self.state = EditState::Aborted;
+            } else if status == self.settings {
+                self.state = EditState::Completed;
+            }
+        }
+        self.state
+    }
+
+    /// Process a x361 configuration frame broadcast by the BSI, and return the updated state.
+    pub fn on_config(&mut self, config: &x361::Repr) -> EditState {
+        if self.state == EditState::Pending
+            && config.profile_number != UserProfile::from(self.profile)
+        {
+            self.state = EditState::Aborted;
+        }
+        self.state
+    }
+}
+
+/// A [ProfileEdit] only emitted while the BSI reports customer configuration mode.
+#[derive(Debug, Clone)]
+pub struct ConfigWrite {
+    edit: ProfileEdit,
+    vsm_config_mode: Option<VsmConfigMode>,
+    vehicle_config_mode: Option<VehicleConfigMode>,
+}
+
+impl ConfigWrite {
+    /// Guard `edit` until a configuration mode is received from the BSI.
+    pub fn new(edit: ProfileEdit) -> ConfigWrite {
+        ConfigWrite {
+            edit,
+            vsm_config_mode: None,
+            vehicle_config_mode: None,
+        }
+    }
+
+    /// Return the guarded modification.
+    pub fn edit(&self) -> &ProfileEdit {
+        &self.edit
+    }
+
+    /// Return the last VSM configuration mode received, if any.
+    pub fn vsm_config_mode(&self) -> Option<VsmConfigMode> {
+        self.vsm_config_mode
+    }
+
+    /// Return the last vehicle configuration mode received, if any.
+    pub fn vehicle_config_mode(&self) -> Option<VehicleConfigMode> {
+        self.vehicle_config_mode
+    }
+
+    /// Return whether the modification request may be emitted, i.e. at least one
+    /// configuration mode has been received and all those received report customer mode.
+    pub fn is_permitted(&self) -> bool {
+        let vsm = self
+            .vsm_config_mode
+            .map(|mode| mode == VsmConfigMode::Customer);
+        let vehicle = self
+            .vehicle_config_mode
+            .map(|mode| mode == VehicleConfigMode::Customer);
+        match (vsm, vehicle) {
+            (None, None) => false,
+            (vsm, vehicle) => vsm.unwrap_or(true) && vehicle.unwrap_or(true),
+        }
+    }
+
+    /// Emit the modification request into `buffer`.
+    ///
+    /// Returns the identifier and length of the emitted frame, `Err(Error::Illegal)` if the
+    /// configuration mode does not permit the modification, or `Err(Error::Exhausted)` if
+    /// `buffer` is too small.
+    pub fn emit_request(&self, buffer: &mut [u8]) -> Result<(u16, usize)> {
+        if !self.is_permitted() {
+            return Err(Error::Illegal);
+        }
+        self.edit.emit_request(buffer)
+    }
+
+    /// Update the modification at `now`, and emit the modification request into `buffer`
+    /// if it is due, see [ProfileEdit::poll].
+    ///
+    /// Returns `Err(Error::Illegal)` instead of emitting a due request if the configuration
+    /// mode does not permit the modification. The modification still times out meanwhile.
+    pub fn poll(&mut self, now: Duration, buffer: &mut [u8]) -> Result<Option<(u16, usize)>> {
+        if self.is_permitted() {
+            return self.edit.poll(now, buffer);
+        }
+        // Let the modification time out, without emitting anything.
+        match self.edit.poll(now, &mut []) {
+            Ok(None) => Ok(None),
+            _ => Err(Error::Illegal),
+        }
+    }
+
+    /// Process a x0f6 VSM state frame broadcast by the BSI.
+    pub fn on_vsm_state(&mut self, state: &x0f6::Repr) {
+        self.vsm_config_mode = Some(state.vsm_config_mode);
+    }
+
+    /// Process a AEE2010 x236 vehicle state frame broadcast by the BSI.
+    pub fn on_vehicle_state(&mut self, state: &x236::Repr) {
+        self.vehicle_config_mode = Some(state.vehicle_config_mode);
+    }
+
+    /// Process a x260 status frame broadcast by the BSI, see [ProfileEdit::on_status].
+    pub fn on_status(&mut self, status: &x260::Repr) -> EditState {
+        self.edit.on_status(status)
+    }
+
+    /// Process a x361 configuration frame broadcast by the BSI, see [ProfileEdit::on_config].
+    pub fn on_config(&mut self, config: &x361::Repr) -> EditState {
+        self.edit.on_config(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{ConfigWrite, EditState, ProfileEdit, Requester, REQUEST_PERIOD};
+
+    use crate::{
+        aee2004::conf::{x0f6, x15b, x1db, x260, x361},
+        aee2010::infodiv::x236,
+        config::{ProfileNumber, UserProfile},
+        vehicle::{VehicleConfigMode, VsmConfigMode},
+        Error,
+    };
+
+    fn current() -> x260::Repr {
+        x260::Repr::parse(&x260::Frame::new_unchecked(&x260::IDLE_FRAME_BYTES)).unwrap()
+    }
+
+    fn desired() -> x260::Repr {
+        x260::Repr {
+            mood_lighting_enabled: true,
+            ..current()
+        }
+    }
+
+    #[test]
+    fn test_request() {
+        let mut edit = ProfileEdit::new(
+            ProfileNumber::Profile1,
+            &desired(),
+            Requester::Cluster,
+            Duration::ZERO,
+        );
+        let mut buffer = [0u8; 8];
+        assert_eq!(
+            edit.poll(Duration::ZERO, &mut buffer),
+            Ok(Some((x1db::FRAME_ID, 8)))
+        );
+        let request = x1db::Repr::parse(&x1db::Frame::new_unchecked(&buffer)).unwrap();
+        assert_eq!(request.profile_number, UserProfile::Profile1);
+        assert!(request.parameters_validity);
+        assert!(request.mood_lighting_enabled);
+
+        assert_eq!(edit.poll(Duration::from_millis(199), &mut buffer), Ok(None));
+        assert_eq!(
+            edit.poll(REQUEST_PERIOD, &mut buffer),
+            Ok(Some((x1db::FRAME_ID, 8)))
+        );
+        assert_eq!(
+            edit.poll(Duration::from_secs(1), &mut buffer[..4]),
+            Err(Error::Exhausted)
+        );
+    }
+
+    #[test]
+    fn test_completed() {
+        let mut edit = ProfileEdit::new(
+            ProfileNumber::Profile1,
+            &desired(),
+            Requester::Display,
+            Duration::ZERO,
+        );
+        let mut buffer = [0u8; 8];
+        assert_eq!(
+            edit.emit_request(&mut buffer),
+            Ok((x15b::FRAME_ID, x15b::FRAME_LEN))
+        );
+
+        assert_eq!(edit.on_status(&current()), EditState::Pending);
+        let status = x260::Repr {
+            parameters_validity: false,
+            ..desired()
+        };
+        assert_eq!(edit.on_status(&status), EditState::Completed);
+        assert_eq!(edit.on_status(&current()), EditState::Completed);
+        assert_eq!(edit.poll(Duration::from_secs(10), &mut buffer), Ok(None));
+    }
+
+    #[test]
+    fn test_aborted() {
+        let mut edit = ProfileEdit::new(
+            ProfileNumber::Profile2,
+            &desired(),
+            Requester::Display,
+            Duration::ZERO,
+        );
+        let config =
+            x361::Repr::parse(&x361::Frame::new_unchecked(&x361::IDLE_FRAME_BYTES)).unwrap();
+        assert_eq!(config.profile_number, UserProfile::Profile1);
+        assert_eq!(edit.on_config(&config), EditState::Aborted);
+        assert_eq!(edit.on_status(&desired()), EditState::Aborted);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut edit = ProfileEdit::new(
+            ProfileNumber::Profile1,
+            &desired(),
+            Requester::Display,
+            Duration::ZERO,
+        )
+        .with_timeout(Duration::from_millis(500));
+        let mut buffer = [0u8; 8];
+        assert!(edit.poll(Duration::from_millis(500), &mut buffer).is_ok());
+        assert_eq!(edit.state(), EditState::Pending);
+        assert_eq!(edit.poll(Duration::from_millis(501), &mut buffer), Ok(None));
+        assert_eq!(edit.state(), EditState::TimedOut);
+        assert!(edit.state().is_finished());
+        assert_eq!(edit.on_status(&desired()), EditState::TimedOut);
+    }
+
+    #[test]
+    fn test_config_write() {
+        let edit = ProfileEdit::new(
+            ProfileNumber::Profile1,
+            &desired(),
+            Requester::Display,
+            Duration::ZERO,
+        );
+        let mut write = ConfigWrite::new(edit);
+        let mut buffer = [0u8; 8];
+        assert!(!write.is_permitted());
+        assert_eq!(write.poll(Duration::ZERO, &mut buffer), Err(Error::Illegal));
+
+        let state =
+            x0f6::Repr::parse(&x0f6::Frame::new_unchecked(&x0f6::IDLE_FRAME_BYTES)).unwrap();
+        write.on_vsm_state(&x0f6::Repr {
+            vsm_config_mode: VsmConfigMode::Showroom,
+            ..state
+        });
+        assert_eq!(write.vsm_config_mode(), Some(VsmConfigMode::Showroom));
+        assert_eq!(write.emit_request(&mut buffer), Err(Error::Illegal));
+
+        write.on_vsm_state(&x0f6::Repr {
+            vsm_config_mode: VsmConfigMode::Customer,
+            ..state
+        });
+        assert!(write.is_permitted());
+        assert_eq!(
+            write.poll(Duration::ZERO, &mut buffer),
+            Ok(Some((x15b::FRAME_ID, 8)))
+        );
+
+        let state =
+            x236::Repr::parse(&x236::Frame::new_unchecked(&x236::IDLE_FRAME_BYTES)).unwrap();
+        write.on_vehicle_state(&x236::Repr {
+            vehicle_config_mode: VehicleConfigMode::Factory,
+            ..state
+        });
+        assert!(!write.is_permitted());
+        assert_eq!(write.poll(REQUEST_PERIOD, &mut buffer), Err(Error::Illegal));
+        assert_eq!(write.on_status(&desired()), EditState::Completed);
+        assert_eq!(write.poll(Duration::from_secs(1), &mut buffer), Ok(None));
+    }
+
+    #[test]
+    fn test_config_write_timeout() {
+        let edit = ProfileEdit::new(
+            ProfileNumber::Profile1,
+            &desired(),
+            Requester::Cluster,
+            Duration::ZERO,
+        );
+        let mut write = ConfigWrite::new(edit);
+        let mut buffer = [0u8; 8];
+        assert_eq!(write.poll(Duration::from_secs(3), &mut buffer), Ok(None));
+        assert_eq!(write.edit().state(), EditState::TimedOut);
+    }
+}
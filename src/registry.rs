@@ -0,0 +1,457 @@
+//! Frame lookup tables.
+//!
+//! [aee2004] and [aee2010] list every frame supported by this crate on the AEE2004 conf and
+//! AEE2010 infodiv buses, with their length and transmission, for tools which only know the
+//! identifier of a received frame, like loggers, bus replayers or timeout monitors such as
+//! the [watchdog](crate::watchdog).
+//!
+//! The frame representations listed there also implement [PsaFrame](crate::frame::PsaFrame),
+//! and the `Message` enums of the buses [BusMessage](crate::frame::BusMessage).
+//!
+//! Signals can also be read by name, without compile-time knowledge of the frame
+//! representations, e.g. by bridges exporting arbitrary signals:
+//!
+//! ```
+//! # use canpsa::registry::aee2010;
+//! let payload = [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0];
+//! assert_eq!(aee2010::get(0x0b6, &payload, "engine_rpm"), Some(0x18a7));
+//! assert_eq!(aee2010::get(0x0b6, &payload, "unknown"), None);
+//! ```
+//!
+//! Each bus also provides an `audit` of its frames, checking that the bits decoded by every
+//! frame implementation are described by its `SIGNALS` and emitted back unchanged, which
+//! catches setters writing outside of their field.
+//!
+//! When both buses are bridged, [classify] tells which decoder family knows a frame identifier:
+//!
+//! ```
+//! # use canpsa::registry::{classify, BusKind};
+//! assert_eq!(classify(0x0b6), Some(BusKind::Both));
+//! assert_eq!(classify(0x3a7), Some(BusKind::Aee2004Conf));
+//! assert_eq!(classify(0x7ff), None);
+//! ```
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    diff::Diff,
+    frame::{PsaFrame, TransmissionKind},
+    signal::Signal,
+    testing::{bit_walk, BitCoverage},
+};
+
+/// Static description of a CAN frame.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FrameInfo {
+    /// CAN frame identifier.
+    pub id: u16,
+    /// CAN frame length.
+    pub len: usize,
+    /// CAN frame periodicity, `None` for event-driven frames and frames of unknown transmission.
+    pub periodicity: Option<Duration>,
+    /// CAN frame transmission.
+    pub transmission: TransmissionKind,
+    /// CAN frame signals.
+    pub signals: &'static [Signal],
+}
+
+impl FrameInfo {
+    /// Return the description of the signal named `name`.
+    pub fn signal(&self, name: &str) -> Option<&'static Signal> {
+        self.signals.iter().find(|signal| signal.name == name)
+    }
+}
+
+/// Return the identifier of frame `F` and the first inconsistent bit of `payload`, a payload
+/// of the frame, see [bit_walk].
+fn audit_frame<F: PsaFrame + Diff>(payload: &[u8]) -> Option<(u16, BitCoverage)> {
+    match bit_walk::<F>(payload) {
+        Ok(mut walk) => walk
+            .find(|bit| bit.coverage.is_error())
+            .map(|bit| (F::ID, bit)),
+        // Reference payloads are checked to parse by the generated frame tests.
+        Err(_) => None,
+    }
+}
+
+macro_rules! psa_frame {
+    ($bus:ident::$frame:ident) => {
+        psa_frame!(@impl $bus::$frame {
+            fn emit(&self, payload: &mut [u8]) -> crate::Result<()> {
+                let mut frame = $bus::$frame::Frame::new_checked(payload)?;
+                self.emit(&mut frame);
+                trace_frame!(tx, Self::ID, self);
+                Ok(())
+            }
+        });
+    };
+    // The frames holding the odometer or the VIN are only emitted with a `DangerToken`.
+    ($bus:ident::$frame:ident, record) => {
+        psa_frame!(@impl $bus::$frame {
+            fn emit(&self, _payload: &mut [u8]) -> crate::Result<()> {
+                Err(crate::Error::Illegal)
+            }
+
+            fn emit_acknowledged(
+                &self,
+                payload: &mut [u8],
+                token: crate::frame::DangerToken,
+            ) -> crate::Result<()> {
+                let mut frame = $bus::$frame::Frame::new_checked(payload)?;
+                self.emit(&mut frame, token);
+                trace_frame!(tx, Self::ID, self);
+                Ok(())
+            }
+        });
+    };
+    (@impl $bus:ident::$frame:ident { $($emit:tt)* }) => {
+        impl PsaFrame for $bus::$frame::Repr {
+            const ID: u16 = $bus::$frame::FRAME_ID;
+            const LEN: usize = $bus::$frame::FRAME_LEN;
+            const SIGNALS: &'static [crate::signal::Signal] = $bus::$frame::SIGNALS;
+
+            fn periodicity() -> Option<Duration> {
+                $bus::$frame::TRANSMISSION.periodicity()
+            }
+
+            fn transmission() -> TransmissionKind {
+                $bus::$frame::TRANSMISSION
+            }
+
+            fn parse(payload: &[u8]) -> crate::Result<Self> {
+                let repr = $bus::$frame::Frame::new_checked(payload)
+                    .and_then(|frame| $bus::$frame::Repr::parse(&frame));
+                trace_frame!(rx, Self::ID, &repr);
+                repr
+            }
+
+            fn parse_with(payload: &[u8], options: &ParseOptions) -> crate::Result<Self> {
+                let payload = match payload.get(..Self::LEN) {
+                    Some(payload) if !options.strict_len => payload,
+                    _ => payload,
+                };
+                let repr = <Self as PsaFrame>::parse(payload)?;
+                crate::frame::check_signals(Self::SIGNALS, payload, options)
+                    .map_err(|_| crate::Error::Invalid)?;
+                Ok(repr)
+            }
+
+            $($emit)*
+        }
+
+        impl $bus::$frame::Repr {
+            /// Parse a frame with the given strictness, see [PsaFrame::parse_with].
+            pub fn parse_with<T: AsRef<[u8]> + ?Sized>(
+                frame: &$bus::$frame::Frame<&T>,
+                options: &ParseOptions,
+            ) -> crate::Result<Self> {
+                <Self as PsaFrame>::parse_with(frame.as_ref(), options)
+            }
+        }
+    };
+}
+
+macro_rules! registry {
+    (
+        $bus:ident,
+        periodic {
+            $($periodic:ident $(($periodic_record:ident))?),* $(,)?,
+        },
+        event_driven {
+            $($event:ident $(($event_record:ident))?),* $(,)?,
+        } $(,)?
+    ) => {
+        use core::time::Duration;
+
+        use super::FrameInfo;
+        use crate::{
+            frame::{BusMessage, ParseOptions, PsaFrame, TransmissionKind},
+            signal::Signal,
+            testing::BitCoverage,
+        };
+
+        use super::audit_frame;
+
+        $(const _: () = assert!(
+            $bus::$periodic::TRANSMISSION.is_periodic(),
+            concat!(stringify!($periodic), " is not periodic"),
+        );)*
+        $(const _: () = assert!(
+            !$bus::$event::TRANSMISSION.is_periodic(),
+            concat!(stringify!($event), " is periodic"),
+        );)*
+
+        /// Every frame of the bus, periodic frames first.
+        pub const FRAMES: &[FrameInfo] = &[
+            $(FrameInfo {
+                id: $bus::$periodic::FRAME_ID,
+                len: $bus::$periodic::FRAME_LEN,
+                signals: $bus::$periodic::SIGNALS,
+                periodicity: Some($bus::$periodic::PERIODICITY),
+                transmission: $bus::$periodic::TRANSMISSION,
+            },)*
+            $(FrameInfo {
+                id: $bus::$event::FRAME_ID,
+                len: $bus::$event::FRAME_LEN,
+                signals: $bus::$event::SIGNALS,
+                periodicity: None,
+                transmission: $bus::$event::TRANSMISSION,
+            },)*
+        ];
+
+        /// Return the description of the frame with identifier `id`.
+        pub fn lookup(id: u16) -> Option<&'static FrameInfo> {
+            FRAMES.iter().find(|info| info.id == id)
+        }
+
+        /// Return the length of the frame with identifier `id`.
+        pub fn frame_len(id: u16) -> Option<usize> {
+            lookup(id).map(|info| info.len)
+        }
+
+        /// Return the periodicity of the frame with identifier `id`.
+        /// Returns `None` for unknown and event-driven frames.
+        pub fn periodicity(id: u16) -> Option<Duration> {
+            lookup(id).and_then(|info| info.periodicity)
+        }
+
+        /// Return the transmission of the frame with identifier `id`.
+        /// Returns `None` for unknown frames.
+        pub fn transmission(id: u16) -> Option<TransmissionKind> {
+            lookup(id).map(|info| info.transmission)
+        }
+
+        /// Return the description of the signal named `name` of the frame with identifier `id`.
+        pub fn signal(id: u16, name: &str) -> Option<&'static Signal> {
+            lookup(id).and_then(|info| info.signal(name))
+        }
+
+        /// Return the raw value of the signal named `name` in `payload`, the payload of the
+        /// frame with identifier `id`. Returns `None` for unknown frames and signals, and if
+        /// `payload` is too short to hold the signal.
+        pub fn get(id: u16, payload: &[u8], name: &str) -> Option<u64> {
+            signal(id, name).and_then(|signal| signal.raw(payload))
+        }
+
+        /// Return the identifier and the first inconsistent bit of the first frame of the bus
+        /// whose layout does not match its implementation, see [Coverage::is_error]. The bits
+        /// of the parked and idle payloads of every frame are walked with [bit_walk].
+        /// Returns `None` if every frame is consistent.
+        ///
+        /// [Coverage::is_error]: crate::testing::Coverage::is_error
+        /// [bit_walk]: crate::testing::bit_walk
+        pub fn audit() -> Option<(u16, BitCoverage)> {
+            None
+                $(
+                  .or_else(|| audit_frame::<$bus::$periodic::Repr>(&$bus::$periodic::PARKED_FRAME_BYTES))
+                  .or_else(|| audit_frame::<$bus::$periodic::Repr>(&$bus::$periodic::IDLE_FRAME_BYTES))
+                )*
+                $(
+                  .or_else(|| audit_frame::<$bus::$event::Repr>(&$bus::$event::PARKED_FRAME_BYTES))
+                  .or_else(|| audit_frame::<$bus::$event::Repr>(&$bus::$event::IDLE_FRAME_BYTES))
+                )*
+        }
+
+        impl BusMessage for $bus::Message {
+            fn parse(id: u16, payload: &[u8]) -> crate::Result<Self> {
+                $bus::Message::parse(id, payload)
+            }
+
+            fn frame_id(&self) -> u16 {
+                $bus::Message::frame_id(self)
+            }
+
+            fn periodicity(id: u16) -> Option<Duration> {
+                periodicity(id)
+            }
+
+            fn transmission(id: u16) -> Option<TransmissionKind> {
+                transmission(id)
+            }
+        }
+
+        $(psa_frame!($bus::$periodic $(, $periodic_record)?);)*
+        $(psa_frame!($bus::$event $(, $event_record)?);)*
+    };
+}
+
+/// AEE2004 conf bus frames.
+pub mod aee2004 {
+    use crate::aee2004::conf;
+
+    registry! {
+        conf,
+        periodic {
+            x036, x0b6, x0e6, x0f6(record), x136, x1d0, x21f, x220, x221, x227, x260, x261, x2a1,
+            x2b6(record), x2e1, x336(record), x361, x376, x3a7, x3b6(record), x3e1,
+        },
+        event_driven {
+            x128, x15b, x167, x168, x1a5, x1a8, x1db, x1e1, x1e5, x228, x3f6,
+        },
+    }
+}
+
+/// AEE2010 infodiv bus frames.
+pub mod aee2010 {
+    use crate::aee2010::infodiv;
+
+    registry! {
+        infodiv,
+        periodic {
+            x036, x0b6, x0e6, x0f6(record), x122, x1a9, x1d0, x221, x227, x228, x236, x260, x261,
+            x276, x2a1, x2a8, x2ad, x2b6(record), x2e1, x329, x336(record), x350, x361, x3b6(record),
+            x3d0, x3e1, x3e7,
+        },
+        event_driven {
+            x128, x15b, x167, x168, x1a5, x1a8, x1e1, x1e5, x39b,
+        },
+    }
+}
+
+/// Buses on which a frame identifier is supported.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BusKind {
+    /// Frame only supported on the AEE2004 conf bus.
+    Aee2004Conf,
+    /// Frame only supported on the AEE2010 infodiv bus.
+    Aee2010Infodiv,
+    /// Frame supported on both buses, not necessarily with the same layout.
+    Both,
+}
+
+impl fmt::Display for BusKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BusKind::Aee2004Conf => write!(f, "AEE2004 conf"),
+            BusKind::Aee2010Infodiv => write!(f, "AEE2010 infodiv"),
+            BusKind::Both => write!(f, "AEE2004 conf and AEE2010 infodiv"),
+        }
+    }
+}
+
+/// Return whether `id` is the identifier of a frame supported on the AEE2004 conf bus.
+pub fn is_aee2004_conf_id(id: u16) -> bool {
+    aee2004::lookup(id).is_some()
+}
+
+/// Return whether `id` is the identifier of a frame supported on the AEE2010 infodiv bus.
+pub fn is_aee2010_infodiv_id(id: u16) -> bool {
+    aee2010::lookup(id).is_some()
+}
+
+/// Return the buses on which the frame with identifier `id` is supported.
+/// Returns `None` for frames unknown on both buses.
+pub fn classify(id: u16) -> Option<BusKind> {
+    match (is_aee2004_conf_id(id), is_aee2010_infodiv_id(id)) {
+        (true, true) => Some(BusKind::Both),
+        (true, false) => Some(BusKind::Aee2004Conf),
+        (false, true) => Some(BusKind::Aee2010Infodiv),
+        (false, false) => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{aee2004, aee2010, classify, is_aee2004_conf_id, is_aee2010_infodiv_id, BusKind};
+
+    use crate::frame::TransmissionKind;
+
+    #[test]
+    fn test_frame_len() {
+        assert_eq!(aee2004::frame_len(0x0e6), Some(7));
+        assert_eq!(aee2010::frame_len(0x0b6), Some(8));
+        assert_eq!(aee2010::frame_len(0x7ff), None);
+    }
+
+    #[test]
+    fn test_periodicity() {
+        assert_eq!(aee2010::periodicity(0x0b6), Some(Duration::from_millis(50)));
+        assert_eq!(aee2004::periodicity(0x1a8), None);
+        assert_eq!(
+            aee2010::periodicity(0x228),
+            Some(Duration::from_millis(1000))
+        );
+        assert!(aee2004::lookup(0x1a8).is_some());
+    }
+
+    #[test]
+    fn test_transmission() {
+        assert_eq!(
+            aee2010::transmission(0x0b6),
+            Some(TransmissionKind::Periodic(Duration::from_millis(50)))
+        );
+        assert_eq!(
+            aee2004::transmission(0x0f6),
+            Some(TransmissionKind::Periodic(Duration::from_millis(500)))
+        );
+        assert_eq!(
+            aee2004::transmission(0x128),
+            Some(TransmissionKind::Unknown)
+        );
+        assert_eq!(
+            aee2010::transmission(0x128),
+            Some(TransmissionKind::OnChange)
+        );
+        assert_eq!(
+            aee2010::transmission(0x39b),
+            Some(TransmissionKind::OnRequest)
+        );
+        assert_eq!(aee2010::transmission(0x7ff), None);
+
+        for info in aee2004::FRAMES.iter().chain(aee2010::FRAMES) {
+            assert_eq!(info.transmission.periodicity(), info.periodicity);
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let payload = [0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0];
+        assert_eq!(aee2010::get(0x0b6, &payload, "engine_rpm"), Some(0x18a7));
+        assert_eq!(aee2010::get(0x0b6, &payload[..1], "engine_rpm"), None);
+        assert_eq!(aee2010::get(0x0b6, &payload, "unknown"), None);
+        assert_eq!(aee2010::get(0x7ff, &payload, "engine_rpm"), None);
+
+        let signal = aee2010::signal(0x0b6, "vehicle_immediate_speed").unwrap();
+        assert_eq!((signal.byte, signal.bit, signal.len), (2, 7, 16));
+        assert_eq!(
+            aee2004::signal(0x0b6, "engine_rpm"),
+            aee2004::lookup(0x0b6).unwrap().signal("engine_rpm")
+        );
+    }
+
+    #[test]
+    fn test_audit() {
+        assert_eq!(aee2004::audit(), None);
+        assert_eq!(aee2010::audit(), None);
+    }
+
+    #[test]
+    fn test_classify() {
+        assert!(is_aee2004_conf_id(0x3a7));
+        assert!(!is_aee2010_infodiv_id(0x3a7));
+        assert!(is_aee2010_infodiv_id(0x3e7));
+        assert!(!is_aee2004_conf_id(0x3e7));
+
+        assert_eq!(classify(0x0b6), Some(BusKind::Both));
+        assert_eq!(classify(0x3a7), Some(BusKind::Aee2004Conf));
+        assert_eq!(classify(0x3e7), Some(BusKind::Aee2010Infodiv));
+        assert_eq!(classify(0x7ff), None);
+
+        for info in aee2004::FRAMES {
+            assert!(matches!(
+                classify(info.id),
+                Some(BusKind::Aee2004Conf | BusKind::Both)
+            ));
+        }
+        for info in aee2010::FRAMES {
+            assert!(matches!(
+                classify(info.id),
+                Some(BusKind::Aee2010Infodiv | BusKind::Both)
+            ));
+        }
+    }
+}
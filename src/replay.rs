@@ -0,0 +1,338 @@
+//! Replay of socketcan `candump -L` capture files.
+//!
+//! `candump -L` logs one frame per line:
+//!
+//! ```text
+//! (1436509052.249713) can0 0B6#18A70000000042D0
+//! (1436509052.250020) can0 7DF#R
+//! ```
+//!
+//! [parse] decodes a single line into a [Record] and [Replay] decodes whole captures into
+//! timestamped bus messages, using the `Message::parse` dispatcher of the captured bus:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv, replay::Replay};
+//! let log = "(0.000000) can0 0B6#18A70000000042D0\n(0.010000) can0 7DF#0201\n";
+//! let mut replay = Replay::new(log.as_bytes(), infodiv::Message::parse);
+//! let decoded = replay.next().unwrap().unwrap();
+//! assert_eq!(decoded.message.frame_id(), 0x0b6);
+//! // Frames unknown to the dispatcher are skipped.
+//! assert!(replay.next().is_none());
+//! ```
+
+use core::{fmt, time::Duration};
+
+use std::{
+    io::{self, BufRead},
+    string::String,
+};
+
+use crate::{
+    transport::slcan::{CanFrame, Id, MAX_DATA_LEN, MAX_EXTENDED_ID, MAX_STANDARD_ID},
+    Error, Result,
+};
+
+/// A `candump -L` log record.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Record<'a> {
+    /// Capture timestamp.
+    pub timestamp: Duration,
+    /// CAN interface name.
+    pub interface: &'a str,
+    /// Captured frame.
+    pub frame: CanFrame,
+}
+
+impl<'a> fmt::Display for Record<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "({}.{:06}) {} ",
+            self.timestamp.as_secs(),
+            self.timestamp.subsec_micros(),
+            self.interface
+        )?;
+        match self.frame.id {
+            Id::Standard(id) => write!(f, "{:03X}#", id)?,
+            Id::Extended(id) => write!(f, "{:08X}#", id)?,
+        }
+        if self.frame.remote {
+            return write!(f, "R");
+        }
+        for byte in self.frame.payload() {
+            write!(f, "{:02X}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_hex(digits: &str) -> Result<u32> {
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::Invalid);
+    }
+    u32::from_str_radix(digits, 16).map_err(|_| Error::Invalid)
+}
+
+fn parse_timestamp(text: &str) -> Result<Duration> {
+    let text = text
+        .strip_prefix('(')
+        .and_then(|text| text.strip_suffix(')'))
+        .ok_or(Error::Invalid)?;
+    let (secs, fraction) = text.split_once('.').ok_or(Error::Invalid)?;
+    if !(1..=9).contains(&fraction.len()) || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(Error::Invalid);
+    }
+
+    let secs: u64 = secs.parse().map_err(|_| Error::Invalid)?;
+    let nanos: u32 = fraction.parse().map_err(|_| Error::Invalid)?;
+    let nanos = nanos * 10u32.pow(9 - fraction.len() as u32);
+    Ok(Duration::new(secs, nanos))
+}
+
+/// Parse a `candump -L` log line.
+/// Returns `Err(Error::Invalid)` for malformed lines and CAN FD frames, and
+/// `Err(Error::Overlong)` for data frames longer than 8 bytes.
+pub fn parse(line: &str) -> Result<Record<'_>> {
+    let mut fields = line.split_ascii_whitespace();
+    let (Some(timestamp), Some(interface), Some(frame), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err(Error::Invalid);
+    };
+
+    let timestamp = parse_timestamp(timestamp)?;
+    let (id, data) = frame.split_once('#').ok_or(Error::Invalid)?;
+
+    let id = match id.len() {
+        3 => match parse_hex(id)? {
+            id if id <= u32::from(MAX_STANDARD_ID) => Id::Standard(id as u16),
+            _ => return Err(Error::Invalid),
+        },
+        8 => match parse_hex(id)? {
+            id if id <= MAX_EXTENDED_ID => Id::Extended(id),
+            _ => return Err(Error::Invalid),
+        },
+        _ => return Err(Error::Invalid),
+    };
+
+    if let Some(len) = data.strip_prefix('R') {
+        let len = match len {
+            "" => 0,
+            len => match parse_hex(len)? {
+                len if len as usize <= MAX_DATA_LEN => len as u8,
+                _ => return Err(Error::Invalid),
+            },
+        };
+        let frame = CanFrame::new_remote(id, len)?;
+        return Ok(Record {
+            timestamp,
+            interface,
+            frame,
+        });
+    }
+
+    if data.len() % 2 != 0 || data.starts_with('#') {
+        return Err(Error::Invalid);
+    }
+    if data.len() / 2 > MAX_DATA_LEN {
+        return Err(Error::Overlong);
+    }
+    let mut payload = [0u8; MAX_DATA_LEN];
+    let payload = &mut payload[..data.len() / 2];
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte = parse_hex(&data[2 * i..2 * i + 2])? as u8;
+    }
+    let frame = CanFrame::new_data(id, payload)?;
+
+    Ok(Record {
+        timestamp,
+        interface,
+        frame,
+    })
+}
+
+/// A bus message decoded from a capture.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Decoded<M> {
+    /// Capture timestamp.
+    pub timestamp: Duration,
+    /// Decoded message.
+    pub message: M,
+}
+
+/// Streaming `candump -L` capture decoder over a [BufRead] stream.
+///
+/// Each data frame is decoded with the `decode` dispatcher. Remote frames, extended
+/// identifier frames and frames unknown to the dispatcher are skipped. Malformed lines and
+/// frames the dispatcher fails to parse are reported as [io::ErrorKind::InvalidData] errors,
+/// the iteration can continue past them.
+#[derive(Debug)]
+pub struct Replay<R: BufRead, M> {
+    inner: R,
+    decode: fn(u16, &[u8]) -> Result<M>,
+    interface: Option<String>,
+    line: String,
+}
+
+impl<R: BufRead, M> Replay<R, M> {
+    /// Create a decoder reading from `inner`, decoding frames with `decode`.
+    pub fn new(inner: R, decode: fn(u16, &[u8]) -> Result<M>) -> Replay<R, M> {
+        Replay {
+            inner,
+            decode,
+            interface: None,
+            line: String::new(),
+        }
+    }
+
+    /// Only decode the frames captured on `interface`, if any.
+    /// Captures of several buses log each bus on its own interface.
+    pub fn set_interface(&mut self, interface: Option<&str>) {
+        self.interface = interface.map(String::from);
+    }
+
+    /// Consume the decoder, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read and decode the next message of the capture.
+    /// Returns `Ok(None)` at the end of the stream.
+    pub fn read_message(&mut self) -> io::Result<Option<Decoded<M>>> {
+        loop {
+            self.line.clear();
+            if self.inner.read_line(&mut self.line)? == 0 {
+                return Ok(None);
+            }
+
+            let line = self.line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let invalid = |err: Error| io::Error::new(io::ErrorKind::InvalidData, err);
+            let record = parse(line).map_err(invalid)?;
+            if self
+                .interface
+                .as_deref()
+                .is_some_and(|interface| interface != record.interface)
+            {
+                continue;
+            }
+
+            let (Some(id), false) = (record.frame.id.standard(), record.frame.remote) else {
+                continue;
+            };
+
+            match (self.decode)(id, record.frame.payload()) {
+                Ok(message) => {
+                    return Ok(Some(Decoded {
+                        timestamp: record.timestamp,
+                        message,
+                    }))
+                }
+                Err(Error::Unrecognized) => continue,
+                Err(err) => return Err(invalid(err)),
+            }
+        }
+    }
+}
+
+impl<R: BufRead, M> Iterator for Replay<R, M> {
+    type Item = io::Result<Decoded<M>>;
+
+    fn next(&mut self) -> Option<io::Result<Decoded<M>>> {
+        self.read_message().transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use std::{format, io, vec::Vec};
+
+    use super::{parse, Replay};
+
+    use crate::{aee2004::conf, aee2010::infodiv, transport::slcan::Id, Error};
+
+    static LOG: &str = "\
+(1436509052.249713) can0 0B6#18A70000000042D0
+(1436509052.250020) can0 7DF#R
+(1436509052.300000) can1 0E6#552C1582267C80
+(1436509052.349713) can0 18DAF110#0201
+(1436509052.399713) can0 0B6#18A70000000042D0
+";
+
+    #[test]
+    fn test_parse() {
+        let record = parse("(1436509052.249713) can0 0B6#18A70000000042D0").unwrap();
+        assert_eq!(record.timestamp, Duration::new(1436509052, 249713000));
+        assert_eq!(record.interface, "can0");
+        assert_eq!(record.frame.id, Id::Standard(0x0b6));
+        assert_eq!(
+            record.frame.payload(),
+            &[0x18, 0xa7, 0x00, 0x00, 0x00, 0x00, 0x42, 0xd0]
+        );
+        assert_eq!(
+            format!("{}", record),
+            "(1436509052.249713) can0 0B6#18A70000000042D0"
+        );
+
+        let record = parse("(0.5) vcan0 18DAF110#R2").unwrap();
+        assert_eq!(record.timestamp, Duration::from_millis(500));
+        assert_eq!(record.frame.id, Id::Extended(0x18daf110));
+        assert!(record.frame.remote);
+        assert_eq!(record.frame.dlc(), 2);
+        assert_eq!(format!("{}", record), "(0.500000) vcan0 18DAF110#R");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(parse("can0 0B6#18").unwrap_err(), Error::Invalid);
+        assert_eq!(parse("(0.0) can0 0B6#1").unwrap_err(), Error::Invalid);
+        assert_eq!(parse("(0.0) can0 8B6#18").unwrap_err(), Error::Invalid);
+        assert_eq!(parse("(0.0) can0 0B6##118").unwrap_err(), Error::Invalid);
+        assert_eq!(parse("(0.0) can0 0B6#1G").unwrap_err(), Error::Invalid);
+        assert_eq!(
+            parse("(0.0) can0 0B6#000102030405060708").unwrap_err(),
+            Error::Overlong
+        );
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut replay = Replay::new(LOG.as_bytes(), infodiv::Message::parse);
+        replay.set_interface(Some("can0"));
+        let decoded: Vec<_> = replay.map(Result::unwrap).collect();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].timestamp, Duration::new(1436509052, 249713000));
+        assert_eq!(decoded[1].message.frame_id(), infodiv::x0b6::FRAME_ID);
+        assert_eq!(decoded[0].message, decoded[1].message);
+    }
+
+    #[test]
+    fn test_replay_interface() {
+        let mut replay = Replay::new(LOG.as_bytes(), conf::Message::parse);
+        replay.set_interface(Some("can1"));
+        let decoded = replay.next().unwrap().unwrap();
+        assert_eq!(decoded.message.frame_id(), conf::x0e6::FRAME_ID);
+        assert!(replay.next().is_none());
+    }
+
+    #[test]
+    fn test_replay_errors() {
+        let log = "garbage\n(0.1) can0 0E6#55\n(0.2) can0 0E6#552C1582267C80\n";
+        let mut replay = Replay::new(log.as_bytes(), conf::Message::parse);
+        assert_eq!(
+            replay.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(
+            replay.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(replay.next().unwrap().is_ok());
+        assert!(replay.next().is_none());
+    }
+}
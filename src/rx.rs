@@ -0,0 +1,201 @@
+//! Receive-side frame cache.
+//!
+//! The [Mailbox] keeps the last message received for each frame identifier of a bus, with its
+//! reception time, so the application can read the latest state of the vehicle at any time
+//! instead of handling every frame as it comes. Periodic frames which have not been received
+//! for [STALE_PERIODS] periods are reported as stale, e.g. when their emitter went to sleep or
+//! was disconnected.
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2010::infodiv::{x0b6, Message}, rx::Mailbox};
+//! let mut mailbox: Mailbox<Message, 16> = Mailbox::new();
+//! mailbox.push(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES, Duration::ZERO).unwrap();
+//! assert!(matches!(mailbox.get(x0b6::FRAME_ID), Some(Message::X0b6(_))));
+//! assert!(mailbox.is_stale(x0b6::FRAME_ID, Duration::from_secs(1)));
+//! ```
+
+use core::time::Duration;
+
+use heapless::LinearMap;
+
+use crate::{frame::BusMessage, Error, Result};
+
+/// Number of missed periods after which a periodic frame is stale.
+pub const STALE_PERIODS: u32 = 3;
+
+/// A received message and its reception time.
+#[derive(Debug, Clone)]
+struct Entry<M> {
+    message: M,
+    received: Duration,
+}
+
+/// Last received message of each frame of a bus, for up to `N` frame identifiers.
+#[derive(Debug, Clone)]
+pub struct Mailbox<M, const N: usize> {
+    entries: LinearMap<u16, Entry<M>, N>,
+}
+
+impl<M: BusMessage, const N: usize> Mailbox<M, N> {
+    /// Create an empty mailbox.
+    pub fn new() -> Mailbox<M, N> {
+        Mailbox {
+            entries: LinearMap::new(),
+        }
+    }
+
+    /// Parse the payload of the CAN frame with identifier `id` received at `now` and store
+    /// it in place of the previous message with the same identifier.
+    ///
+    /// Returns `Err(Error::Unrecognized)` if `id` is not a supported frame identifier,
+    /// `Err(Error::Exhausted)` if the mailbox already holds `N` other identifiers, or the
+    /// parsing error of the frame. The previous message is kept on error.
+    pub fn push(&mut self, id: u16, payload: &[u8], now: Duration) -> Result<&M> {
+        let message = M::parse(id, payload)?;
+        let entry = Entry {
+            message,
+            received: now,
+        };
+        self.entries
+            .insert(id, entry)
+            .map_err(|_| Error::Exhausted)?;
+        self.get(id).ok_or(Error::Exhausted)
+    }
+
+    /// Return the last message received with identifier `id`.
+    pub fn get(&self, id: u16) -> Option<&M> {
+        self.entries.get(&id).map(|entry| &entry.message)
+    }
+
+    /// Return the time elapsed at `now` since the last reception of the frame with
+    /// identifier `id`, or `None` if it has never been received.
+    pub fn age(&self, id: u16, now: Duration) -> Option<Duration> {
+        self.entries
+            .get(&id)
+            .map(|entry| now.saturating_sub(entry.received))
+    }
+
+    /// Return whether the last message received with identifier `id` is stale at `now`,
+    /// i.e. it has never been received or it is periodic and has not been received for
+    /// [STALE_PERIODS] periods. Non-periodic frames never get stale once received.
+    pub fn is_stale(&self, id: u16, now: Duration) -> bool {
+        match (self.age(id, now), M::periodicity(id)) {
+            (None, _) => true,
+            (Some(age), Some(periodicity)) => age > periodicity * STALE_PERIODS,
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Return an iterator over the identifiers of the received frames which are stale
+    /// at `now`.
+    pub fn stale(&self, now: Duration) -> impl Iterator<Item = u16> + '_ {
+        self.entries
+            .keys()
+            .copied()
+            .filter(move |&id| self.is_stale(id, now))
+    }
+
+    /// Remove and return the last message received with identifier `id`.
+    pub fn remove(&mut self, id: u16) -> Option<M> {
+        self.entries.remove(&id).map(|entry| entry.message)
+    }
+
+    /// Return an iterator over the stored messages.
+    pub fn iter(&self) -> impl Iterator<Item = &M> {
+        self.entries.values().map(|entry| &entry.message)
+    }
+
+    /// Return the number of stored messages.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return whether the mailbox is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove every stored message.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<M: BusMessage, const N: usize> Default for Mailbox<M, N> {
+    fn default() -> Mailbox<M, N> {
+        Mailbox::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::Mailbox;
+
+    use crate::{
+        aee2010::infodiv::{x0b6, x128, Message},
+        Error,
+    };
+
+    #[test]
+    fn test_push() {
+        let mut mailbox: Mailbox<Message, 1> = Mailbox::new();
+        assert!(mailbox.is_empty());
+
+        let message = mailbox
+            .push(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES, Duration::ZERO)
+            .unwrap();
+        assert_eq!(message.frame_id(), x0b6::FRAME_ID);
+        assert_eq!(
+            mailbox.push(0x7ff, &x0b6::IDLE_FRAME_BYTES, Duration::ZERO),
+            Err(Error::Unrecognized)
+        );
+        assert_eq!(
+            mailbox.push(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES[..7], Duration::ZERO),
+            Err(Error::Truncated)
+        );
+        assert_eq!(
+            mailbox
+                .push(x128::FRAME_ID, &x128::IDLE_FRAME_BYTES, Duration::ZERO)
+                .map(|message| message.frame_id()),
+            Err(Error::Exhausted)
+        );
+        assert_eq!(mailbox.len(), 1);
+
+        assert!(mailbox.remove(x0b6::FRAME_ID).is_some());
+        assert_eq!(mailbox.get(x0b6::FRAME_ID), None);
+    }
+
+    #[test]
+    fn test_staleness() {
+        let mut mailbox: Mailbox<Message, 4> = Mailbox::new();
+        assert!(mailbox.is_stale(x0b6::FRAME_ID, Duration::ZERO));
+
+        let now = Duration::from_millis(1000);
+        mailbox
+            .push(x0b6::FRAME_ID, &x0b6::IDLE_FRAME_BYTES, now)
+            .unwrap();
+        mailbox
+            .push(x128::FRAME_ID, &x128::IDLE_FRAME_BYTES, now)
+            .unwrap();
+
+        // x0b6 is sent every 50ms.
+        let later = now + Duration::from_millis(150);
+        assert_eq!(
+            mailbox.age(x0b6::FRAME_ID, later),
+            Some(Duration::from_millis(150))
+        );
+        assert!(!mailbox.is_stale(x0b6::FRAME_ID, later));
+        assert_eq!(mailbox.stale(later).next(), None);
+
+        // x128 is event-driven.
+        let later = now + Duration::from_secs(10);
+        assert!(mailbox.is_stale(x0b6::FRAME_ID, later));
+        assert!(!mailbox.is_stale(x128::FRAME_ID, later));
+        let mut stale = mailbox.stale(later);
+        assert_eq!(stale.next(), Some(x0b6::FRAME_ID));
+        assert_eq!(stale.next(), None);
+    }
+}
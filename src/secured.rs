@@ -0,0 +1,77 @@
+//! Rolling counter and checksum protection of secured frames.
+//!
+//! Some frames sent by safety related ECUs carry a 4-bit rolling counter, incremented at each
+//! emission, and a 4-bit checksum computed over the frame payload and the counter, which let
+//! receivers detect frozen or corrupted senders.
+//!
+//! Frame wrappers of secured frames implement [FrameCounter] and [FrameChecksum]. Their
+//! `Repr::emit` fills the checksum in automatically and their `Repr::parse` returns
+//! `Err(Error::Invalid)` on a checksum mismatch. Raw access to the frames stays possible with
+//! the field accessors of the frame wrappers and `Repr::parse_unverified`.
+
+/// Maximum value of a rolling counter, the counter wraps to 0 after it.
+pub const COUNTER_MAX: u8 = 0x0f;
+
+/// Return the rolling counter value following `counter`.
+pub const fn next_counter(counter: u8) -> u8 {
+    if counter < COUNTER_MAX {
+        counter + 1
+    } else {
+        0
+    }
+}
+
+/// A frame carrying a rolling counter.
+pub trait FrameCounter {
+    /// Return the rolling counter field.
+    fn counter(&self) -> u8;
+}
+
+/// A frame carrying a checksum.
+pub trait FrameChecksum {
+    /// Return the checksum field.
+    fn checksum(&self) -> u8;
+
+    /// Compute the checksum of the frame.
+    fn compute_checksum(&self) -> u8;
+
+    /// Validate the checksum of the frame.
+    fn verify_checksum(&self) -> bool {
+        self.checksum() == self.compute_checksum()
+    }
+}
+
+/// A writable frame carrying a rolling counter and a checksum.
+pub trait SecuredFrameMut: FrameCounter + FrameChecksum {
+    /// Set the rolling counter field.
+    fn set_counter(&mut self, value: u8);
+
+    /// Set the checksum field.
+    fn set_checksum(&mut self, value: u8);
+
+    /// Compute and fill in the checksum of the frame.
+    fn fill_checksum(&mut self) {
+        let checksum = self.compute_checksum();
+        self.set_checksum(checksum);
+    }
+
+    /// Set the rolling counter field to its next value and fill in the checksum, as done
+    /// by the sender before each emission of the frame.
+    fn advance(&mut self) {
+        let counter = next_counter(self.counter());
+        self.set_counter(counter);
+        self.fill_checksum();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::next_counter;
+
+    #[test]
+    fn test_next_counter() {
+        assert_eq!(next_counter(0), 1);
+        assert_eq!(next_counter(0x0e), 0x0f);
+        assert_eq!(next_counter(0x0f), 0);
+    }
+}
@@ -0,0 +1,325 @@
+//! Frame signal descriptions.
+//!
+//! Every frame module declares the layout of its fields in a `SIGNALS` table of [Signal],
+//! which tools like the DBC export use to describe the frames without decoding them. Signals
+//! are named after the `Repr` field they are decoded into. Fields spread over several bit
+//! ranges, e.g. dates, are described as one signal per range, suffixed with the part name.
+//!
+//! The layout of every `SIGNALS` table is checked at compile time by [check_layout]: signals
+//! must fit in their frame, and must not share bits unless they are [multiplexed](Signal::multiplexed).
+
+use core::fmt;
+
+/// Byte order of a signal.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ByteOrder {
+    /// Most significant byte first (Motorola).
+    BigEndian,
+    /// Least significant byte first (Intel).
+    LittleEndian,
+}
+
+impl fmt::Display for ByteOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ByteOrder::BigEndian => write!(f, "big endian"),
+            ByteOrder::LittleEndian => write!(f, "little endian"),
+        }
+    }
+}
+
+/// Description of a signal of a CAN frame.
+///
+/// The physical value of a signal is `raw * factor + offset`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Signal {
+    /// Signal name.
+    pub name: &'static str,
+    /// Index of the byte holding the start bit.
+    pub byte: u8,
+    /// Position of the start bit in its byte, 0 being the least significant bit.
+    /// The start bit is the most significant bit of big endian signals and the least
+    /// significant bit of little endian signals.
+    pub bit: u8,
+    /// Length of the signal, in bits.
+    pub len: u8,
+    /// Byte order of the signal.
+    pub byte_order: ByteOrder,
+    /// Whether the raw value is a two's complement signed integer.
+    pub signed: bool,
+    /// Raw value scaling factor.
+    pub factor: f32,
+    /// Physical value offset.
+    pub offset: f32,
+    /// Unit of the physical value, empty if the value has no unit.
+    pub unit: &'static str,
+    /// Names of the raw values, for enumerated signals.
+    pub values: &'static [(u32, &'static str)],
+    /// Whether the signal shares its bits with other multiplexed signals, the frame content
+    /// selecting which of them is present.
+    pub multiplexed: bool,
+}
+
+impl Signal {
+    /// Create an unsigned, unscaled big endian signal of `len` bits whose most significant
+    /// bit is `bit` of `byte`.
+    pub const fn new(name: &'static str, byte: u8, bit: u8, len: u8) -> Signal {
+        Signal {
+            name,
+            byte,
+            bit,
+            len,
+            byte_order: ByteOrder::BigEndian,
+            signed: false,
+            factor: 1.0,
+            offset: 0.0,
+            unit: "",
+            values: &[],
+            multiplexed: false,
+        }
+    }
+
+    /// Make the signal little endian, `byte` and `bit` then locate its least significant bit.
+    pub const fn little_endian(self) -> Signal {
+        Signal {
+            byte_order: ByteOrder::LittleEndian,
+            ..self
+        }
+    }
+
+    /// Make the signal raw value signed.
+    pub const fn signed(self) -> Signal {
+        Signal {
+            signed: true,
+            ..self
+        }
+    }
+
+    /// Set the signal scaling factor and offset.
+    pub const fn scaled(self, factor: f32, offset: f32) -> Signal {
+        Signal {
+            factor,
+            offset,
+            ..self
+        }
+    }
+
+    /// Set the signal unit.
+    pub const fn unit(self, unit: &'static str) -> Signal {
+        Signal { unit, ..self }
+    }
+
+    /// Set the names of the signal raw values.
+    pub const fn values(self, values: &'static [(u32, &'static str)]) -> Signal {
+        Signal { values, ..self }
+    }
+
+    /// Make the signal multiplexed, allowing it to share bits with other multiplexed signals.
+    pub const fn multiplexed(self) -> Signal {
+        Signal {
+            multiplexed: true,
+            ..self
+        }
+    }
+
+    /// Return the start bit of the signal, in the DBC bit numbering, i.e. `byte * 8 + bit`.
+    pub const fn start_bit(&self) -> u16 {
+        self.byte as u16 * 8 + self.bit as u16
+    }
+
+    /// Return the index of the last byte holding bits of the signal.
+    pub const fn end_byte(&self) -> usize {
+        let len = self.len as usize;
+        match self.byte_order {
+            ByteOrder::BigEndian => self.byte as usize + (len + 6 - self.bit as usize) / 8,
+            ByteOrder::LittleEndian => {
+                self.byte as usize + (len + self.bit as usize).saturating_sub(1) / 8
+            }
+        }
+    }
+
+    /// Return the positions of the signal bits, from its start bit, as `(byte, bit)` pairs.
+    fn bits(&self) -> impl Iterator<Item = (usize, u8)> {
+        let (mut byte, mut bit) = (self.byte as usize, self.bit);
+        let byte_order = self.byte_order;
+        (0..self.len).map(move |_| {
+            let position = (byte, bit);
+            match (byte_order, bit) {
+                (ByteOrder::BigEndian, 0) => (byte, bit) = (byte + 1, 7),
+                (ByteOrder::BigEndian, _) => bit -= 1,
+                (ByteOrder::LittleEndian, 7) => (byte, bit) = (byte + 1, 0),
+                (ByteOrder::LittleEndian, _) => bit += 1,
+            }
+            position
+        })
+    }
+
+    /// Return the raw value of the signal in `payload`, or `None` if `payload` is too short.
+    pub fn raw(&self, payload: &[u8]) -> Option<u64> {
+        let mut raw = 0u64;
+        for (index, (byte, bit)) in self.bits().enumerate() {
+            let value = u64::from((payload.get(byte)? >> bit) & 1);
+            raw = match self.byte_order {
+                ByteOrder::BigEndian => (raw << 1) | value,
+                ByteOrder::LittleEndian => raw | (value << index),
+            };
+        }
+        Some(raw)
+    }
+
+    /// Return the mask of the bits of byte `byte` holding the signal.
+    pub const fn mask(&self, byte: usize) -> u8 {
+        let (mut current, mut bit) = (self.byte as usize, self.bit);
+        let mut mask = 0;
+        let mut index = 0;
+        // Iterators are not usable in const functions.
+        while index < self.len && bit < 8 {
+            if current == byte {
+                mask |= 1 << bit;
+            }
+            match (self.byte_order, bit) {
+                (ByteOrder::BigEndian, 0) => (current, bit) = (current + 1, 7),
+                (ByteOrder::BigEndian, _) => bit -= 1,
+                (ByteOrder::LittleEndian, 7) => (current, bit) = (current + 1, 0),
+                (ByteOrder::LittleEndian, _) => bit += 1,
+            }
+            index += 1;
+        }
+        mask
+    }
+
+    /// Return the minimum and maximum physical values of the signal.
+    pub fn range(&self) -> (f32, f32) {
+        let len = u32::from(self.len.clamp(1, 64));
+        let (min, max) = if self.signed {
+            let half = (1u64 << (len - 1)) as f32;
+            (-half, half - 1.0)
+        } else {
+            (0.0, (u64::MAX >> (64 - len)) as f32)
+        };
+        let (min, max) = (
+            min * self.factor + self.offset,
+            max * self.factor + self.offset,
+        );
+        if min <= max {
+            (min, max)
+        } else {
+            (max, min)
+        }
+    }
+}
+
+/// Check that the `signals` of a frame fit in its `len` bytes, and that no bit is held by
+/// several signals, unless they are all multiplexed. Meant to be evaluated at compile time,
+/// e.g. `const _: () = check_layout(SIGNALS, FRAME_LEN);`.
+///
+/// # Panics
+///
+/// Panics, failing the build in a const context, if a check fails.
+pub const fn check_layout(signals: &[Signal], len: usize) {
+    let mut index = 0;
+    while index < signals.len() {
+        let first = &signals[index];
+        assert!(
+            first.bit < 8 && first.len > 0 && first.end_byte() < len,
+            "signal out of the frame"
+        );
+
+        let mut other = index + 1;
+        while other < signals.len() {
+            let second = &signals[other];
+            let mut byte = 0;
+            while byte < len && !(first.multiplexed && second.multiplexed) {
+                assert!(
+                    first.mask(byte) & second.mask(byte) == 0,
+                    "overlapping signals"
+                );
+                byte += 1;
+            }
+            other += 1;
+        }
+        index += 1;
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} byte={} bit={} len={} {}",
+            self.name, self.byte, self.bit, self.len, self.byte_order
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_layout, ByteOrder, Signal};
+
+    #[test]
+    fn test_signal() {
+        let signal = Signal::new("coolant_temperature", 1, 7, 8)
+            .scaled(1.0, -40.0)
+            .unit("degC");
+        assert_eq!(signal.byte_order, ByteOrder::BigEndian);
+        assert_eq!(signal.start_bit(), 15);
+        assert_eq!(signal.end_byte(), 1);
+        assert_eq!(signal.range(), (-40.0, 215.0));
+
+        let signal = Signal::new("running_duration_seconds", 0, 7, 20);
+        assert_eq!(signal.end_byte(), 2);
+        let signal = Signal::new("rear_left_wheel_counter", 1, 6, 15);
+        assert_eq!(signal.end_byte(), 2);
+        let signal = Signal::new("journal", 0, 0, 64).little_endian();
+        assert_eq!(signal.end_byte(), 7);
+
+        let signal = Signal::new("offset", 0, 7, 8).signed().scaled(-0.5, 0.0);
+        assert_eq!(signal.range(), (-63.5, 64.0));
+    }
+
+    #[test]
+    fn test_signal_raw() {
+        let payload = [0x12, 0x34, 0x56];
+
+        let signal = Signal::new("rear_left_wheel_counter", 0, 3, 12);
+        assert_eq!(signal.raw(&payload), Some(0x234));
+        assert_eq!(signal.mask(0), 0x0f);
+        assert_eq!(signal.mask(1), 0xff);
+        assert_eq!(signal.mask(2), 0x00);
+
+        let signal = Signal::new("counter", 1, 4, 12).little_endian();
+        assert_eq!(signal.raw(&payload), Some(0x563));
+        assert_eq!(signal.mask(1), 0xf0);
+        assert_eq!(signal.mask(2), 0xff);
+
+        assert_eq!(Signal::new("journal", 2, 7, 16).raw(&payload), None);
+    }
+
+    #[test]
+    fn test_check_layout() {
+        const SIGNALS: &[Signal] = &[
+            Signal::new("mode", 0, 7, 4),
+            Signal::new("level", 0, 3, 4).multiplexed(),
+            Signal::new("temperature", 0, 3, 4).multiplexed(),
+        ];
+        const _: () = check_layout(SIGNALS, 1);
+        assert_eq!(SIGNALS[0].mask(0), 0xf0);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlapping signals")]
+    fn test_check_layout_overlap() {
+        check_layout(
+            &[Signal::new("mode", 0, 7, 4), Signal::new("level", 0, 4, 4)],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "signal out of the frame")]
+    fn test_check_layout_len() {
+        check_layout(&[Signal::new("counter", 1, 7, 16)], 2);
+    }
+}
@@ -0,0 +1,307 @@
+//! Telemetry mapping.
+//!
+//! Data loggers publish the decoded frames to MQTT brokers or time series databases as flat
+//! key/value records. [Telemetry] maps the `Message` of a bus to such a [Record], whose keys
+//! are the names of the frame signals, see [SIGNALS](crate::signal), and whose values are
+//! the scaled signal values:
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2010::infodiv, telemetry::{Telemetry, Value}};
+//! let message = infodiv::Message::parse(0x0b6, &[0x18, 0xa7, 0, 0, 0, 0, 0x42, 0xd0]).unwrap();
+//! let record = message.to_record().unwrap();
+//! assert_eq!(record.topic("car"), "car/aee2010/x0b6");
+//! assert_eq!(record.get("engine_rpm"), Some(Value::Float(631.1)));
+//! assert_eq!(record.get("speed_validity"), Some(Value::Text("Valid")));
+//! assert!(record.to_json().starts_with(r#"{"engine_rpm":631.1,"#));
+//! assert!(record
+//!     .to_line_protocol("car", Duration::from_secs(1))
+//!     .starts_with("car,bus=aee2010,frame=x0b6 engine_rpm=631.1,"));
+//! ```
+//!
+//! Enumerated signals are mapped to the name of their value, 1-bit signals to booleans, and
+//! unscaled signals to integers.
+
+use core::{
+    fmt::{self, Write},
+    time::Duration,
+};
+
+use std::{string::String, vec::Vec};
+
+use crate::{
+    aee2004::conf,
+    aee2010::infodiv,
+    registry::{self, FrameInfo},
+    signal::Signal,
+    Error, Result,
+};
+
+/// A telemetry value.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Value {
+    /// A flag.
+    Bool(bool),
+    /// An unscaled unsigned value.
+    Unsigned(u64),
+    /// An unscaled signed value.
+    Signed(i64),
+    /// A scaled value.
+    Float(f32),
+    /// The name of the value of an enumerated signal.
+    Text(&'static str),
+}
+
+impl Value {
+    /// Return the value of `signal` in `payload`, or `None` if `payload` is too short.
+    pub fn from_signal(signal: &Signal, payload: &[u8]) -> Option<Value> {
+        let raw = signal.raw(payload)?;
+        let named = signal
+            .values
+            .iter()
+            .find(|&&(value, _)| u64::from(value) == raw);
+        let scaled = signal.factor != 1.0 || signal.offset != 0.0;
+
+        let value = if let Some(&(_, name)) = named {
+            Value::Text(name)
+        } else if signal.signed {
+            let shift = 64 - u32::from(signal.len.clamp(1, 64));
+            let raw = ((raw << shift) as i64) >> shift;
+            if scaled {
+                Value::Float(physical(signal, raw as f32))
+            } else {
+                Value::Signed(raw)
+            }
+        } else if scaled {
+            Value::Float(physical(signal, raw as f32))
+        } else if signal.len == 1 && signal.values.is_empty() {
+            Value::Bool(raw != 0)
+        } else {
+            Value::Unsigned(raw)
+        };
+        Some(value)
+    }
+}
+
+/// Return the physical value of the raw value `raw` of `signal`. Factors of the form `1 / n`
+/// divide by `n` instead, like the frame representations, which keeps decimal values exact.
+fn physical(signal: &Signal, raw: f32) -> f32 {
+    let div = (1.0 / signal.factor).round();
+    if div > 1.0 && (div * signal.factor - 1.0).abs() < f32::EPSILON {
+        raw / div + signal.offset
+    } else {
+        raw * signal.factor + signal.offset
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Unsigned(value) => write!(f, "{}", value),
+            Value::Signed(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The telemetry fields of a frame.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Record {
+    /// Name of the bus the frame was received on, e.g. `aee2010`.
+    pub bus: &'static str,
+    /// CAN frame identifier.
+    pub id: u16,
+    /// Signal names and values, in the order of the frame signals.
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Record {
+    /// Create the record of `payload`, a payload of the frame described by `info`.
+    /// Signals out of `payload` are left out.
+    pub fn from_payload(bus: &'static str, info: &FrameInfo, payload: &[u8]) -> Record {
+        Record {
+            bus,
+            id: info.id,
+            fields: info
+                .signals
+                .iter()
+                .filter_map(|signal| {
+                    Value::from_signal(signal, payload).map(|value| (signal.name, value))
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the value of the field `name`.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Return the topic of the record, `<prefix>/<bus>/x<id>`, e.g. `car/aee2010/x0b6`.
+    pub fn topic(&self, prefix: &str) -> String {
+        let mut topic = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = write!(topic, "{}/{}/x{:03x}", prefix, self.bus, self.id);
+        topic
+    }
+
+    /// Return the record as a JSON object.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "\"{}\":", name);
+            match *value {
+                Value::Text(text) => write_quoted(&mut json, text),
+                Value::Float(value) if !value.is_finite() => json.push_str("null"),
+                value => {
+                    let _ = write!(json, "{}", value);
+                }
+            }
+        }
+        json.push('}');
+        json
+    }
+
+    /// Return the record as an InfluxDB line protocol line of `measurement`, tagged with the
+    /// bus and frame names, at `timestamp` since the Unix epoch.
+    pub fn to_line_protocol(&self, measurement: &str, timestamp: Duration) -> String {
+        let mut line = String::new();
+        let _ = write!(
+            line,
+            "{},bus={},frame=x{:03x} ",
+            measurement, self.bus, self.id
+        );
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                line.push(',');
+            }
+            let _ = write!(line, "{}=", name);
+            let _ = match *value {
+                Value::Unsigned(value) => write!(line, "{}u", value),
+                Value::Signed(value) => write!(line, "{}i", value),
+                Value::Text(text) => {
+                    write_quoted(&mut line, text);
+                    Ok(())
+                }
+                value => write!(line, "{}", value),
+            };
+        }
+        let _ = write!(line, " {}", timestamp.as_nanos());
+        line
+    }
+}
+
+/// Append `text` to `out` as a double-quoted string.
+fn write_quoted(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+/// A bus message which can be mapped to a telemetry [Record].
+pub trait Telemetry {
+    /// Return the telemetry record of the message.
+    /// Returns `Err(Error::Unrecognized)` if the frame is not listed in the registry.
+    fn to_record(&self) -> Result<Record>;
+}
+
+macro_rules! telemetry {
+    ($message:ty, $bus:ident) => {
+        impl Telemetry for $message {
+            fn to_record(&self) -> Result<Record> {
+                let info = registry::$bus::lookup(self.frame_id()).ok_or(Error::Unrecognized)?;
+                let mut payload = [0u8; 8];
+                let len = self.emit(&mut payload)?;
+                Ok(Record::from_payload(
+                    stringify!($bus),
+                    info,
+                    &payload[..len],
+                ))
+            }
+        }
+    };
+}
+
+telemetry!(conf::Message, aee2004);
+telemetry!(infodiv::Message, aee2010);
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{Record, Telemetry, Value};
+
+    use crate::{
+        aee2004::conf::{self, x220},
+        registry,
+        signal::Signal,
+    };
+
+    #[test]
+    fn test_value() {
+        let payload = [0xf6, 0x80];
+        let signal = Signal::new("offset", 0, 7, 8).signed();
+        assert_eq!(
+            Value::from_signal(&signal, &payload),
+            Some(Value::Signed(-10))
+        );
+        let signal = signal.scaled(0.5, 0.0);
+        assert_eq!(
+            Value::from_signal(&signal, &payload),
+            Some(Value::Float(-5.0))
+        );
+        let signal = Signal::new("flag", 1, 7, 1);
+        assert_eq!(
+            Value::from_signal(&signal, &payload),
+            Some(Value::Bool(true))
+        );
+        let signal = Signal::new("counter", 1, 7, 2);
+        assert_eq!(
+            Value::from_signal(&signal, &payload),
+            Some(Value::Unsigned(2))
+        );
+        assert_eq!(Value::from_signal(&signal, &payload[..1]), None);
+    }
+
+    #[test]
+    fn test_record() {
+        let message = conf::Message::parse(x220::FRAME_ID, &[0x80, 0x40]).unwrap();
+        let record = message.to_record().unwrap();
+        assert_eq!(record.topic("car"), "car/aee2004/x220");
+        assert_eq!(record.fields.len(), 10);
+        assert_eq!(
+            record.get("front_left_door_opened"),
+            Some(Value::Bool(true))
+        );
+        assert_eq!(
+            record.get("vehicle_body_type"),
+            Some(Value::Text("FiveDoors"))
+        );
+        assert_eq!(record.get("unknown"), None);
+
+        let json = record.to_json();
+        assert!(json.starts_with(r#"{"fuel_cap_opened":false,"#));
+        assert!(json.ends_with(r#""spare_wheel_arm_opened":true,"vehicle_body_type":"FiveDoors"}"#));
+
+        let line = record.to_line_protocol("car", Duration::from_millis(1500));
+        assert!(line.starts_with("car,bus=aee2004,frame=x220 fuel_cap_opened=false,"));
+        assert!(line.ends_with(r#"vehicle_body_type="FiveDoors" 1500000000"#));
+
+        let info = registry::aee2004::lookup(x220::FRAME_ID).unwrap();
+        let record = Record::from_payload("aee2004", info, &[0x80]);
+        assert_eq!(record.fields.len(), 8);
+    }
+}
@@ -0,0 +1,289 @@
+//! Frame consistency checks.
+//!
+//! Frame modules are written by hand, and a field decoded from the wrong bits, or not emitted
+//! back, easily goes unnoticed. This module provides checks which frame implementations,
+//! including out of tree ones, can run from their tests:
+//!
+//! - [assert_roundtrip] checks that a representation is parsed back from its emitted payload.
+//! - [bit_walk] flips every bit of a payload in turn, and reports which field each bit is
+//!   decoded into. [assert_bit_coverage] checks that no bit is decoded into several fields,
+//!   that every decoded bit is described by a signal of the frame, and that emitting the
+//!   decoded representation gives back every signal bit.
+//! - [signal_overlap] checks that no bit is described by several `SIGNALS` entries.
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::x0b6, testing};
+//! let repr = x0b6::Repr::builder().with_trip_odometer(1234).build().unwrap();
+//! testing::assert_roundtrip(&repr);
+//! testing::assert_bit_coverage::<x0b6::Repr>(&x0b6::IDLE_FRAME_BYTES);
+//! assert_eq!(testing::signal_overlap(x0b6::SIGNALS), None);
+//! ```
+
+use core::fmt;
+
+use crate::{diff::Diff, frame::PsaFrame, signal::Signal, Error, Result};
+
+/// Longest supported payload, a CAN FD payload.
+const MAX_LEN: usize = 64;
+
+/// Emit `repr`, parse the emitted payload and check that the result equals `repr`.
+///
+/// # Panics
+///
+/// Panics if the emission or the parsing fails, or if the representations differ.
+pub fn assert_roundtrip<F: PsaFrame + PartialEq + fmt::Debug>(repr: &F) {
+    assert!(F::LEN <= MAX_LEN, "x{:03x}: frame too long", F::ID);
+    let mut payload = [0u8; MAX_LEN];
+    let payload = &mut payload[..F::LEN];
+    assert_eq!(repr.emit(payload), Ok(()), "x{:03x}: emit failed", F::ID);
+    assert_eq!(
+        F::parse(payload).as_ref(),
+        Ok(repr),
+        "x{:03x}: round-trip mismatch",
+        F::ID
+    );
+}
+
+/// Decoding of a payload bit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Coverage {
+    /// The bit is not decoded into any field.
+    Reserved,
+    /// The bit is decoded into the field, and emitted back.
+    Field(&'static str),
+    /// The bit is decoded into both fields, and maybe others.
+    Overlap(&'static str, &'static str),
+    /// The bit is decoded into the field, but not emitted back.
+    NotEmitted(&'static str),
+    /// The bit is decoded into the field, but not described by any signal of the frame.
+    Undescribed(&'static str),
+    /// The payload is rejected when the bit is flipped.
+    Rejected,
+}
+
+impl Coverage {
+    /// Return whether the coverage denotes an inconsistent frame implementation.
+    pub fn is_error(&self) -> bool {
+        matches!(
+            *self,
+            Coverage::Overlap(..) | Coverage::NotEmitted(_) | Coverage::Undescribed(_)
+        )
+    }
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Coverage::Reserved => write!(f, "reserved"),
+            Coverage::Field(name) => write!(f, "{}", name),
+            Coverage::Overlap(first, second) => write!(f, "overlap of {} and {}", first, second),
+            Coverage::NotEmitted(name) => write!(f, "{} not emitted", name),
+            Coverage::Undescribed(name) => write!(f, "{} not described by a signal", name),
+            Coverage::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+/// Decoding of the bit `bit` of byte `byte` of a payload, see [bit_walk].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BitCoverage {
+    /// Index of the byte holding the bit.
+    pub byte: usize,
+    /// Position of the bit in its byte, 0 being the least significant bit.
+    pub bit: u8,
+    /// Decoding of the bit.
+    pub coverage: Coverage,
+}
+
+impl fmt::Display for BitCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "byte {} bit {}: {}", self.byte, self.bit, self.coverage)
+    }
+}
+
+/// Iterator over the bits of a payload, see [bit_walk].
+#[derive(Debug, Clone)]
+pub struct BitWalk<'a, F> {
+    payload: &'a [u8],
+    base: F,
+    index: usize,
+}
+
+/// Return an iterator flipping every bit of `payload`, a valid payload of frame `F`, in
+/// turn, and reporting the fields of `F` which the bit is decoded into.
+///
+/// The bits of the frames rejected by [PsaFrame::emit] with `Err(Error::Illegal)`, which hold
+/// the odometer or the VIN, are not checked to be emitted back, see
+/// [DangerToken](crate::frame::DangerToken).
+///
+/// Returns the error of [PsaFrame::parse] if `payload` is invalid.
+pub fn bit_walk<F: PsaFrame + Diff>(payload: &[u8]) -> Result<BitWalk<'_, F>> {
+    assert!(F::LEN <= MAX_LEN, "x{:03x}: frame too long", F::ID);
+    Ok(BitWalk {
+        payload,
+        base: F::parse(payload)?,
+        index: 0,
+    })
+}
+
+impl<'a, F: PsaFrame + Diff> Iterator for BitWalk<'a, F> {
+    type Item = BitCoverage;
+
+    fn next(&mut self) -> Option<BitCoverage> {
+        let len = self.payload.len();
+        if self.index >= len * 8 {
+            return None;
+        }
+        let (byte, bit) = (self.index / 8, (self.index % 8) as u8);
+        self.index += 1;
+
+        let mut flipped = [0u8; MAX_LEN];
+        let flipped = &mut flipped[..len];
+        flipped.copy_from_slice(self.payload);
+        flipped[byte] ^= 1 << bit;
+
+        let mask = 1 << bit;
+        let coverage = match F::parse(flipped) {
+            Err(_) => Coverage::Rejected,
+            Ok(repr) => {
+                let mut changes = self.base.diff(&repr);
+                match (changes.next(), changes.next()) {
+                    (None, _) => Coverage::Reserved,
+                    (Some(first), Some(second)) => Coverage::Overlap(first.name, second.name),
+                    (Some(change), None) => {
+                        let mut emitted = [0u8; MAX_LEN];
+                        let emitted = &mut emitted[..len];
+                        let emitted_back = match repr.emit(emitted) {
+                            Ok(()) => signals_match::<F>(emitted, flipped),
+                            // The odometer and VIN frames are not emitted without a token.
+                            Err(Error::Illegal) => true,
+                            Err(_) => false,
+                        };
+                        if !emitted_back {
+                            Coverage::NotEmitted(change.name)
+                        } else if F::SIGNALS
+                            .iter()
+                            .all(|signal| signal.mask(byte) & mask == 0)
+                        {
+                            Coverage::Undescribed(change.name)
+                        } else {
+                            Coverage::Field(change.name)
+                        }
+                    }
+                }
+            }
+        };
+
+        Some(BitCoverage {
+            byte,
+            bit,
+            coverage,
+        })
+    }
+}
+
+/// Return whether the payloads `first` and `second` of frame `F` hold the same bits in the
+/// bits described by the frame signals.
+fn signals_match<F: PsaFrame>(first: &[u8], second: &[u8]) -> bool {
+    first
+        .iter()
+        .zip(second)
+        .enumerate()
+        .all(|(byte, (first, second))| {
+            let mask = F::SIGNALS
+                .iter()
+                .fold(0, |mask, signal| mask | signal.mask(byte));
+            (first ^ second) & mask == 0
+        })
+}
+
+/// Check that `payload`, a valid payload of frame `F`, round-trips, and that none of its
+/// bits is decoded into several fields or decoded but not emitted back.
+///
+/// # Panics
+///
+/// Panics if `payload` is invalid, or on the first inconsistent bit.
+pub fn assert_bit_coverage<F: PsaFrame + Diff + PartialEq + fmt::Debug>(payload: &[u8]) {
+    let walk = bit_walk::<F>(payload);
+    assert!(walk.is_ok(), "x{:03x}: invalid payload", F::ID);
+    if let Ok(walk) = walk {
+        assert_roundtrip(&walk.base);
+        for bit in walk {
+            assert!(!bit.coverage.is_error(), "x{:03x}: {}", F::ID, bit);
+        }
+    }
+}
+
+/// Return the names of the first two signals of `signals` sharing a bit, or `None` if the
+/// signals are disjoint. Multiplexed signals may share bits with each other.
+pub fn signal_overlap(signals: &[Signal]) -> Option<(&'static str, &'static str)> {
+    signals.iter().enumerate().find_map(|(index, first)| {
+        signals[index + 1..]
+            .iter()
+            .find(|second| {
+                if first.multiplexed && second.multiplexed {
+                    return false;
+                }
+                let end = first.end_byte().min(second.end_byte());
+                (0..=end).any(|byte| first.mask(byte) & second.mask(byte) != 0)
+            })
+            .map(|second| (first.name, second.name))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{assert_bit_coverage, assert_roundtrip, bit_walk, signal_overlap, Coverage};
+
+    use crate::{
+        aee2004::conf::{x0e6, x220, x260},
+        aee2010::infodiv::{x0b6, x350},
+        signal::Signal,
+        Error,
+    };
+
+    #[test]
+    fn test_roundtrip() {
+        assert_roundtrip(&x0b6::Repr::builder().build().unwrap());
+        assert_roundtrip(&x260::Repr::builder().build().unwrap());
+    }
+
+    #[test]
+    fn test_bit_walk() {
+        let mut walk = bit_walk::<x220::Repr>(&x220::IDLE_FRAME_BYTES).unwrap();
+        assert_eq!(
+            walk.next().unwrap().coverage,
+            Coverage::Field("fuel_cap_opened")
+        );
+        let bits: [Coverage; 16] = core::array::from_fn(|_| Coverage::Rejected);
+        let bits = walk.fold(bits, |mut bits, bit| {
+            bits[bit.byte * 8 + usize::from(bit.bit)] = bit.coverage;
+            bits
+        });
+        assert_eq!(bits[8], Coverage::Reserved);
+        assert_eq!(bits[14], Coverage::Field("spare_wheel_arm_opened"));
+
+        assert_eq!(
+            bit_walk::<x220::Repr>(&[0x55]).map(|_| ()),
+            Err(Error::Truncated)
+        );
+    }
+
+    #[test]
+    fn test_bit_coverage() {
+        assert_bit_coverage::<x0b6::Repr>(&x0b6::IDLE_FRAME_BYTES);
+        assert_bit_coverage::<x350::Repr>(&x350::IDLE_FRAME_BYTES);
+        assert_bit_coverage::<x0e6::Repr>(&x0e6::IDLE_FRAME_BYTES);
+    }
+
+    #[test]
+    fn test_signal_overlap() {
+        assert_eq!(signal_overlap(x0b6::SIGNALS), None);
+        let signals = [
+            Signal::new("first", 0, 7, 4),
+            Signal::new("second", 0, 3, 4),
+            Signal::new("third", 0, 0, 2),
+        ];
+        assert_eq!(signal_overlap(&signals), Some(("second", "third")));
+    }
+}
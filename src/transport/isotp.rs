@@ -0,0 +1,958 @@
+//! ISO 15765-2 (ISO-TP) transport layer.
+//!
+//! Diagnostic and VIN writing exchanges carry messages longer than a classic 8-byte CAN frame.
+//! ISO-TP segments them into a first frame followed by consecutive frames, paced by flow
+//! control frames sent back by the receiver. Messages up to 7 bytes fit in a single frame.
+//!
+//! [Frame] and [Repr] decode and encode a single ISO-TP CAN frame payload, [Sender] segments
+//! a message and [Receiver] reassembles one. Everything is `no_std` and allocation free.
+//! Only classic CAN normal addressing is supported, i.e. messages up to 4095 bytes.
+
+use core::{cmp::Ordering, fmt, time::Duration};
+
+use crate::{Error, Result};
+
+/// A read/write wrapper around an ISO-TP CAN frame buffer.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Frame<T: AsRef<[u8]>> {
+    buffer: T,
+}
+
+mod field {
+    use crate::field::_Rest;
+    /// 4-bit data length or sequence number or flow status field,
+    /// 4-bit frame type field.
+    pub const PCI: usize = 0;
+    /// First frame low byte of the 12-bit message length field.
+    pub const FF_LEN: usize = 1;
+    /// Flow control block size field.
+    pub const FC_BLOCK_SIZE: usize = 1;
+    /// Flow control minimum separation time field.
+    pub const FC_SEPARATION_TIME: usize = 2;
+    /// Single and consecutive frames data.
+    pub const DATA: _Rest = 1..;
+    /// First frame data.
+    pub const FF_DATA: _Rest = 2..;
+}
+
+/// Maximum length of a classic CAN frame.
+pub const CAN_FRAME_LEN: usize = 8;
+/// Maximum data length of a single frame.
+pub const SINGLE_FRAME_MAX_DATA_LEN: usize = CAN_FRAME_LEN - 1;
+/// Data length of a first frame.
+pub const FIRST_FRAME_DATA_LEN: usize = CAN_FRAME_LEN - 2;
+/// Maximum data length of a consecutive frame.
+pub const CONSECUTIVE_FRAME_MAX_DATA_LEN: usize = CAN_FRAME_LEN - 1;
+/// Length of a flow control frame.
+pub const FLOW_CONTROL_LEN: usize = field::FC_SEPARATION_TIME + 1;
+/// Maximum length of an ISO-TP message.
+pub const MAX_MESSAGE_LEN: usize = 0x0fff;
+
+enum_with_unknown! {
+   /// ISO-TP frame type.
+   pub enum FrameType(u8) {
+       /// Complete message in a single frame.
+       Single = 0,
+       /// First frame of a segmented message.
+       First = 1,
+       /// Consecutive frame of a segmented message.
+       Consecutive = 2,
+       /// Flow control frame sent by the receiver.
+       FlowControl = 3,
+   }
+}
+
+impl fmt::Display for FrameType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FrameType::Single => write!(f, "single"),
+            FrameType::First => write!(f, "first"),
+            FrameType::Consecutive => write!(f, "consecutive"),
+            FrameType::FlowControl => write!(f, "flow control"),
+            FrameType::Unknown(ty) => write!(f, "0x{:02x}", ty),
+        }
+    }
+}
+
+enum_with_unknown! {
+   /// ISO-TP flow control status.
+   pub enum FlowStatus(u8) {
+       /// The sender may continue to send consecutive frames.
+       ContinueToSend = 0,
+       /// The sender must wait for another flow control frame.
+       Wait = 1,
+       /// The message is too long for the receiver, the transfer is aborted.
+       Overflow = 2,
+   }
+}
+
+impl fmt::Display for FlowStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FlowStatus::ContinueToSend => write!(f, "continue to send"),
+            FlowStatus::Wait => write!(f, "wait"),
+            FlowStatus::Overflow => write!(f, "overflow"),
+            FlowStatus::Unknown(status) => write!(f, "0x{:02x}", status),
+        }
+    }
+}
+
+/// Convert a raw flow control minimum separation time into a duration.
+/// Reserved values are interpreted as the maximum separation time, 127 milliseconds.
+pub fn separation_time(raw: u8) -> Duration {
+    match raw {
+        0x00..=0x7f => Duration::from_millis(raw.into()),
+        0xf1..=0xf9 => Duration::from_micros(u64::from(raw - 0xf0) * 100),
+        _ => Duration::from_millis(0x7f),
+    }
+}
+
+impl<T: AsRef<[u8]>> Frame<T> {
+    /// Create a raw octet buffer with an ISO-TP CAN frame structure.
+    #[inline]
+    pub fn new_unchecked(buffer: T) -> Frame<T> {
+        Frame { buffer }
+    }
+
+    /// Shorthand for a combination of [new_unchecked] and [check_len].
+    ///
+    /// [new_unchecked]: #method.new_unchecked
+    /// [check_len]: #method.check_len
+    #[inline]
+    pub fn new_checked(buffer: T) -> Result<Frame<T>> {
+        let packet = Self::new_unchecked(buffer);
+        packet.check_len()?;
+        Ok(packet)
+    }
+
+    /// Ensure that no accessor method will panic if called.
+    /// Returns `Err(Error::Truncated)` if the buffer is too short for its frame type,
+    /// `Err(Error::Overlong)` if it is longer than a classic CAN frame and `Err(Error::Invalid)`
+    /// if the data length of a single frame is zero or does not fit in a classic CAN frame.
+    #[inline]
+    pub fn check_len(&self) -> Result<()> {
+        let len = self.buffer.as_ref().len();
+        if len == 0 {
+            return Err(Error::Truncated);
+        }
+
+        let min_len = match self.frame_type() {
+            FrameType::Single => match usize::from(self.single_data_len()) {
+                len @ 1..=SINGLE_FRAME_MAX_DATA_LEN => 1 + len,
+                _ => return Err(Error::Invalid),
+            },
+            FrameType::First => CAN_FRAME_LEN,
+            FrameType::FlowControl => FLOW_CONTROL_LEN,
+            _ => 1,
+        };
+
+        match len.cmp(&CAN_FRAME_LEN) {
+            Ordering::Greater => Err(Error::Overlong),
+            _ if len < min_len => Err(Error::Truncated),
+            _ => Ok(()),
+        }
+    }
+
+    /// Consume the frame, returning the underlying buffer.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.buffer
+    }
+
+    /// Return the frame type field.
+    #[inline]
+    pub fn frame_type(&self) -> FrameType {
+        let data = self.buffer.as_ref();
+        FrameType::from(data[field::PCI] >> 4)
+    }
+
+    /// Return the single frame data length field.
+    #[inline]
+    pub fn single_data_len(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[field::PCI] & 0x0f
+    }
+
+    /// Return the first frame message length field.
+    #[inline]
+    pub fn first_message_len(&self) -> u16 {
+        let data = self.buffer.as_ref();
+        (u16::from(data[field::PCI] & 0x0f) << 8) | u16::from(data[field::FF_LEN])
+    }
+
+    /// Return the consecutive frame sequence number field.
+    #[inline]
+    pub fn sequence_number(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[field::PCI] & 0x0f
+    }
+
+    /// Return the flow control status field.
+    #[inline]
+    pub fn flow_status(&self) -> FlowStatus {
+        let data = self.buffer.as_ref();
+        FlowStatus::from(data[field::PCI] & 0x0f)
+    }
+
+    /// Return the flow control block size field.
+    #[inline]
+    pub fn block_size(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[field::FC_BLOCK_SIZE]
+    }
+
+    /// Return the flow control raw minimum separation time field.
+    #[inline]
+    pub fn separation_time(&self) -> u8 {
+        let data = self.buffer.as_ref();
+        data[field::FC_SEPARATION_TIME]
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Frame<&'a T> {
+    /// Return the single frame data.
+    #[inline]
+    pub fn single_data(&self) -> &'a [u8] {
+        let data = self.buffer.as_ref();
+        &data[field::DATA][..usize::from(self.single_data_len())]
+    }
+
+    /// Return the first frame data.
+    #[inline]
+    pub fn first_data(&self) -> &'a [u8] {
+        let data = self.buffer.as_ref();
+        &data[field::FF_DATA]
+    }
+
+    /// Return the consecutive frame data, including any padding.
+    #[inline]
+    pub fn consecutive_data(&self) -> &'a [u8] {
+        let data = self.buffer.as_ref();
+        &data[field::DATA]
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> Frame<T> {
+    /// Set the frame type field.
+    #[inline]
+    pub fn set_frame_type(&mut self, value: FrameType) {
+        let data = self.buffer.as_mut();
+        data[field::PCI] = (data[field::PCI] & 0x0f) | (u8::from(value) << 4);
+    }
+
+    /// Set the single frame data length field.
+    #[inline]
+    pub fn set_single_data_len(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[field::PCI] = (data[field::PCI] & 0xf0) | (value & 0x0f);
+    }
+
+    /// Set the first frame message length field.
+    #[inline]
+    pub fn set_first_message_len(&mut self, value: u16) {
+        let data = self.buffer.as_mut();
+        data[field::PCI] = (data[field::PCI] & 0xf0) | ((value >> 8) as u8 & 0x0f);
+        data[field::FF_LEN] = value as u8;
+    }
+
+    /// Set the consecutive frame sequence number field.
+    #[inline]
+    pub fn set_sequence_number(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[field::PCI] = (data[field::PCI] & 0xf0) | (value & 0x0f);
+    }
+
+    /// Set the flow control status field.
+    #[inline]
+    pub fn set_flow_status(&mut self, value: FlowStatus) {
+        let data = self.buffer.as_mut();
+        data[field::PCI] = (data[field::PCI] & 0xf0) | (u8::from(value) & 0x0f);
+    }
+
+    /// Set the flow control block size field.
+    #[inline]
+    pub fn set_block_size(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[field::FC_BLOCK_SIZE] = value;
+    }
+
+    /// Set the flow control raw minimum separation time field.
+    #[inline]
+    pub fn set_separation_time(&mut self, value: u8) {
+        let data = self.buffer.as_mut();
+        data[field::FC_SEPARATION_TIME] = value;
+    }
+
+    /// Return a mutable pointer to the single and consecutive frames data.
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        let data = self.buffer.as_mut();
+        &mut data[field::DATA]
+    }
+
+    /// Return a mutable pointer to the first frame data.
+    #[inline]
+    pub fn first_data_mut(&mut self) -> &mut [u8] {
+        let data = self.buffer.as_mut();
+        &mut data[field::FF_DATA]
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> fmt::Display for Frame<&T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match Repr::parse(self) {
+            Ok(repr) => write!(f, "{}", repr),
+            Err(err) => {
+                write!(f, "ISO-TP ({})", err)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>> AsRef<[u8]> for Frame<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.buffer.as_ref()
+    }
+}
+
+/// A high-level representation of an ISO-TP CAN frame.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Repr<'a> {
+    /// Single frame holding a complete message.
+    Single { data: &'a [u8] },
+    /// First frame of a segmented message of `len` bytes.
+    First { len: u16, data: &'a [u8] },
+    /// Consecutive frame of a segmented message.
+    Consecutive { sequence_number: u8, data: &'a [u8] },
+    /// Flow control frame.
+    FlowControl {
+        status: FlowStatus,
+        block_size: u8,
+        separation_time: u8,
+    },
+}
+
+impl<'a> Repr<'a> {
+    /// Parse an ISO-TP CAN frame.
+    /// Returns `Err(Error::Invalid)` for unknown frame types, empty single frames and first
+    /// frames of messages which would fit in a single frame.
+    pub fn parse<T: AsRef<[u8]> + ?Sized>(frame: &Frame<&'a T>) -> Result<Repr<'a>> {
+        frame.check_len()?;
+
+        match frame.frame_type() {
+            FrameType::Single => Ok(Repr::Single {
+                data: frame.single_data(),
+            }),
+            FrameType::First => match frame.first_message_len() {
+                len if usize::from(len) <= SINGLE_FRAME_MAX_DATA_LEN => Err(Error::Invalid),
+                len => Ok(Repr::First {
+                    len,
+                    data: frame.first_data(),
+                }),
+            },
+            FrameType::Consecutive => Ok(Repr::Consecutive {
+                sequence_number: frame.sequence_number(),
+                data: frame.consecutive_data(),
+            }),
+            FrameType::FlowControl => Ok(Repr::FlowControl {
+                status: frame.flow_status(),
+                block_size: frame.block_size(),
+                separation_time: frame.separation_time(),
+            }),
+            FrameType::Unknown(_) => Err(Error::Invalid),
+        }
+    }
+
+    /// Return the length of a frame that will be emitted from this high-level representation.
+    pub fn buffer_len(&self) -> usize {
+        match *self {
+            Repr::Single { data } | Repr::Consecutive { data, .. } => 1 + data.len(),
+            Repr::First { data, .. } => 2 + data.len(),
+            Repr::FlowControl { .. } => FLOW_CONTROL_LEN,
+        }
+    }
+
+    /// Emit a high-level representation into an ISO-TP CAN frame.
+    /// The frame buffer must be at least [buffer_len] long, any extra byte is left untouched.
+    ///
+    /// [buffer_len]: #method.buffer_len
+    pub fn emit<T: AsRef<[u8]> + AsMut<[u8]>>(&self, frame: &mut Frame<T>) {
+        match *self {
+            Repr::Single { data } => {
+                frame.set_frame_type(FrameType::Single);
+                frame.set_single_data_len(data.len() as u8);
+                frame.data_mut()[..data.len()].copy_from_slice(data);
+            }
+            Repr::First { len, data } => {
+                frame.set_frame_type(FrameType::First);
+                frame.set_first_message_len(len);
+                frame.first_data_mut()[..data.len()].copy_from_slice(data);
+            }
+            Repr::Consecutive {
+                sequence_number,
+                data,
+            } => {
+                frame.set_frame_type(FrameType::Consecutive);
+                frame.set_sequence_number(sequence_number);
+                frame.data_mut()[..data.len()].copy_from_slice(data);
+            }
+            Repr::FlowControl {
+                status,
+                block_size,
+                separation_time,
+            } => {
+                frame.set_frame_type(FrameType::FlowControl);
+                frame.set_flow_status(status);
+                frame.set_block_size(block_size);
+                frame.set_separation_time(separation_time);
+            }
+        }
+    }
+}
+
+impl<'a> fmt::Display for Repr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Repr::Single { data } => {
+                write!(f, "ISO-TP single len={} data={:02x?}", data.len(), data)
+            }
+            Repr::First { len, data } => write!(f, "ISO-TP first len={} data={:02x?}", len, data),
+            Repr::Consecutive {
+                sequence_number,
+                data,
+            } => write!(
+                f,
+                "ISO-TP consecutive sn={} data={:02x?}",
+                sequence_number, data
+            ),
+            Repr::FlowControl {
+                status,
+                block_size,
+                separation_time,
+            } => write!(
+                f,
+                "ISO-TP flow control status={} block_size={} separation_time={}",
+                status, block_size, separation_time
+            ),
+        }
+    }
+}
+
+/// Emit `repr` into `buffer`, padding the frame up to a classic CAN frame length with
+/// `padding` if any. Returns the emitted frame length.
+fn emit_padded(repr: &Repr, buffer: &mut [u8], padding: Option<u8>) -> Result<usize> {
+    let len = match padding {
+        Some(_) => CAN_FRAME_LEN,
+        None => repr.buffer_len(),
+    };
+    let buffer = buffer.get_mut(..len).ok_or(Error::Exhausted)?;
+    if let Some(padding) = padding {
+        buffer.fill(padding);
+    }
+    repr.emit(&mut Frame::new_unchecked(&mut *buffer));
+    Ok(len)
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum SenderState {
+    /// Next frame is the single or first frame.
+    Start,
+    /// Waiting for a flow control frame.
+    WaitFlowControl,
+    /// Sending consecutive frames, with the remaining block frames count, if limited.
+    Sending(Option<u8>),
+    /// The whole message has been sent.
+    Done,
+}
+
+/// ISO-TP message segmentation.
+///
+/// Call [Sender::next_frame] to get the frames to send, and feed the received flow control
+/// frames to [Sender::flow_control]. The caller is responsible for waiting
+/// [Sender::separation_time] between consecutive frames.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Sender<'a> {
+    message: &'a [u8],
+    offset: usize,
+    sequence_number: u8,
+    separation_time: u8,
+    padding: Option<u8>,
+    state: SenderState,
+}
+
+impl<'a> Sender<'a> {
+    /// Create a sender segmenting `message`.
+    /// Returns `Err(Error::Overlong)` if the message is longer than [MAX_MESSAGE_LEN] and
+    /// `Err(Error::Truncated)` if it is empty.
+    pub fn new(message: &'a [u8]) -> Result<Sender<'a>> {
+        if message.is_empty() {
+            return Err(Error::Truncated);
+        }
+        if message.len() > MAX_MESSAGE_LEN {
+            return Err(Error::Overlong);
+        }
+
+        Ok(Sender {
+            message,
+            offset: 0,
+            sequence_number: 1,
+            separation_time: 0,
+            padding: None,
+            state: SenderState::Start,
+        })
+    }
+
+    /// Pad every emitted frame up to a classic CAN frame length with `padding`, if any.
+    pub fn set_padding(&mut self, padding: Option<u8>) {
+        self.padding = padding;
+    }
+
+    /// Return the minimum separation time between consecutive frames requested by the
+    /// receiver.
+    pub fn separation_time(&self) -> Duration {
+        separation_time(self.separation_time)
+    }
+
+    /// Return whether the whole message has been sent.
+    pub fn is_complete(&self) -> bool {
+        self.state == SenderState::Done
+    }
+
+    /// Return whether the sender waits for a flow control frame.
+    pub fn is_waiting(&self) -> bool {
+        self.state == SenderState::WaitFlowControl
+    }
+
+    /// Emit the next frame to send into `buffer`, returning its length.
+    /// Returns `Ok(None)` when waiting for a flow control frame or when the message is
+    /// complete, and `Err(Error::Exhausted)` if `buffer` is too small.
+    pub fn next_frame(&mut self, buffer: &mut [u8]) -> Result<Option<usize>> {
+        match self.state {
+            SenderState::Start if self.message.len() <= SINGLE_FRAME_MAX_DATA_LEN => {
+                let repr = Repr::Single { data: self.message };
+                let len = emit_padded(&repr, buffer, self.padding)?;
+                self.offset = self.message.len();
+                self.state = SenderState::Done;
+                Ok(Some(len))
+            }
+            SenderState::Start => {
+                let repr = Repr::First {
+                    len: self.message.len() as u16,
+                    data: &self.message[..FIRST_FRAME_DATA_LEN],
+                };
+                let len = emit_padded(&repr, buffer, self.padding)?;
+                self.offset = FIRST_FRAME_DATA_LEN;
+                self.state = SenderState::WaitFlowControl;
+                Ok(Some(len))
+            }
+            SenderState::Sending(remaining) => {
+                let end = (self.offset + CONSECUTIVE_FRAME_MAX_DATA_LEN).min(self.message.len());
+                let repr = Repr::Consecutive {
+                    sequence_number: self.sequence_number,
+                    data: &self.message[self.offset..end],
+                };
+                let len = emit_padded(&repr, buffer, self.padding)?;
+                self.offset = end;
+                self.sequence_number = (self.sequence_number + 1) & 0x0f;
+                self.state = match remaining {
+                    _ if end == self.message.len() => SenderState::Done,
+                    Some(1) => SenderState::WaitFlowControl,
+                    Some(n) => SenderState::Sending(Some(n - 1)),
+                    None => SenderState::Sending(None),
+                };
+                Ok(Some(len))
+            }
+            SenderState::WaitFlowControl | SenderState::Done => Ok(None),
+        }
+    }
+
+    /// Process a flow control frame received from the receiver.
+    /// Returns `Err(Error::Illegal)` if no flow control frame is expected, `Err(Error::Dropped)`
+    /// if the receiver aborted the transfer and `Err(Error::Invalid)` for unknown statuses.
+    pub fn flow_control(&mut self, payload: &[u8]) -> Result<()> {
+        let frame = Frame::new_checked(payload)?;
+        let Repr::FlowControl {
+            status,
+            block_size,
+            separation_time,
+        } = Repr::parse(&frame)?
+        else {
+            return Err(Error::Invalid);
+        };
+
+        if self.state != SenderState::WaitFlowControl {
+            return Err(Error::Illegal);
+        }
+
+        match status {
+            FlowStatus::ContinueToSend => {
+                self.separation_time = separation_time;
+                self.state = SenderState::Sending(match block_size {
+                    0 => None,
+                    n => Some(n),
+                });
+                Ok(())
+            }
+            FlowStatus::Wait => Ok(()),
+            FlowStatus::Overflow => {
+                self.state = SenderState::Done;
+                Err(Error::Dropped)
+            }
+            FlowStatus::Unknown(_) => Err(Error::Invalid),
+        }
+    }
+}
+
+/// Status of a [Receiver] after processing a frame.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RxStatus {
+    /// More consecutive frames are expected.
+    Pending,
+    /// A flow control frame must be sent, see [Receiver::flow_control].
+    SendFlowControl,
+    /// The message is complete, see [Receiver::message].
+    Complete,
+}
+
+/// ISO-TP message reassembly into a buffer of `N` bytes.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Receiver<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+    received: usize,
+    sequence_number: u8,
+    block_size: u8,
+    block_remaining: u8,
+    separation_time: u8,
+}
+
+impl<const N: usize> Receiver<N> {
+    /// Create a receiver requesting blocks of `block_size` consecutive frames (0 for no
+    /// limit) separated by the raw minimum separation time `separation_time`.
+    pub fn new(block_size: u8, separation_time: u8) -> Receiver<N> {
+        Receiver {
+            buffer: [0u8; N],
+            len: 0,
+            received: 0,
+            sequence_number: 0,
+            block_size,
+            block_remaining: 0,
+            separation_time,
+        }
+    }
+
+    /// Abort the message being received.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.received = 0;
+    }
+
+    /// Return the complete message, if any.
+    pub fn message(&self) -> Option<&[u8]> {
+        if self.len > 0 && self.received == self.len {
+            Some(&self.buffer[..self.len])
+        } else {
+            None
+        }
+    }
+
+    /// Return the flow control frame to send after a [RxStatus::SendFlowControl] status.
+    pub fn flow_control(&self) -> Repr<'static> {
+        Repr::FlowControl {
+            status: FlowStatus::ContinueToSend,
+            block_size: self.block_size,
+            separation_time: self.separation_time,
+        }
+    }
+
+    /// Process a received ISO-TP frame.
+    ///
+    /// A single or first frame always starts a new message. Returns `Err(Error::Exhausted)`
+    /// if the message does not fit in the receiver buffer, in which case a flow control
+    /// frame with the [FlowStatus::Overflow] status should be sent, `Err(Error::Invalid)` on
+    /// sequence number mismatch and `Err(Error::Illegal)` for unexpected frames.
+    pub fn receive(&mut self, payload: &[u8]) -> Result<RxStatus> {
+        let frame = Frame::new_checked(payload)?;
+        match Repr::parse(&frame)? {
+            Repr::Single { data } => {
+                self.reset();
+                let buffer = self.buffer.get_mut(..data.len()).ok_or(Error::Exhausted)?;
+                buffer.copy_from_slice(data);
+                self.len = data.len();
+                self.received = data.len();
+                Ok(RxStatus::Complete)
+            }
+            Repr::First { len, data } => {
+                self.reset();
+                let len = usize::from(len);
+                if len > N {
+                    return Err(Error::Exhausted);
+                }
+                self.buffer[..data.len()].copy_from_slice(data);
+                self.len = len;
+                self.received = data.len();
+                self.sequence_number = 1;
+                self.block_remaining = self.block_size;
+                Ok(RxStatus::SendFlowControl)
+            }
+            Repr::Consecutive {
+                sequence_number,
+                data,
+            } => {
+                if self.len == 0 || self.received == self.len {
+                    return Err(Error::Illegal);
+                }
+                if sequence_number != self.sequence_number {
+                    self.reset();
+                    return Err(Error::Invalid);
+                }
+
+                // Consecutive frames may be padded, only keep the message bytes.
+                let count = data.len().min(self.len - self.received);
+                self.buffer[self.received..self.received + count].copy_from_slice(&data[..count]);
+                self.received += count;
+                self.sequence_number = (self.sequence_number + 1) & 0x0f;
+
+                if self.received == self.len {
+                    return Ok(RxStatus::Complete);
+                }
+
+                if self.block_size != 0 {
+                    self.block_remaining -= 1;
+                    if self.block_remaining == 0 {
+                        self.block_remaining = self.block_size;
+                        return Ok(RxStatus::SendFlowControl);
+                    }
+                }
+                Ok(RxStatus::Pending)
+            }
+            Repr::FlowControl { .. } => Err(Error::Illegal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{separation_time, FlowStatus, Frame, FrameType, Receiver, Repr, RxStatus, Sender};
+
+    use crate::Error;
+
+    static SINGLE_FRAME_BYTES: [u8; 4] = [0x03, 0x22, 0xf1, 0x90];
+    static FIRST_FRAME_BYTES: [u8; 8] = [0x10, 0x14, 0x62, 0xf1, 0x90, 0x56, 0x46, 0x33];
+    static CONSECUTIVE_FRAME_BYTES: [u8; 8] = [0x21, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+    static FLOW_CONTROL_BYTES: [u8; 3] = [0x30, 0x02, 0x14];
+
+    static MESSAGE: [u8; 20] = [
+        0x62, 0xf1, 0x90, 0x56, 0x46, 0x33, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+        0x4a, 0x4b, 0x4c, 0x4d, 0x4e,
+    ];
+
+    #[test]
+    fn test_single_frame_deconstruction() {
+        let frame = Frame::new_checked(&SINGLE_FRAME_BYTES).unwrap();
+        assert_eq!(frame.frame_type(), FrameType::Single);
+        assert_eq!(frame.single_data_len(), 3);
+        assert_eq!(frame.single_data(), &[0x22, 0xf1, 0x90]);
+    }
+
+    #[test]
+    fn test_first_frame_deconstruction() {
+        let frame = Frame::new_checked(&FIRST_FRAME_BYTES).unwrap();
+        assert_eq!(frame.frame_type(), FrameType::First);
+        assert_eq!(frame.first_message_len(), 20);
+        assert_eq!(frame.first_data(), &MESSAGE[..6]);
+    }
+
+    #[test]
+    fn test_flow_control_deconstruction() {
+        let frame = Frame::new_checked(&FLOW_CONTROL_BYTES).unwrap();
+        assert_eq!(frame.frame_type(), FrameType::FlowControl);
+        assert_eq!(frame.flow_status(), FlowStatus::ContinueToSend);
+        assert_eq!(frame.block_size(), 2);
+        assert_eq!(frame.separation_time(), 20);
+    }
+
+    #[test]
+    fn test_underlong() {
+        assert_eq!(
+            Frame::new_checked(&[0x03, 0x22, 0xf1]).unwrap_err(),
+            Error::Truncated
+        );
+        assert_eq!(
+            Frame::new_checked(&FIRST_FRAME_BYTES[..7]).unwrap_err(),
+            Error::Truncated
+        );
+        assert_eq!(
+            Frame::new_checked(&[0x30, 0x00]).unwrap_err(),
+            Error::Truncated
+        );
+        assert_eq!(Frame::new_checked(&[]).unwrap_err(), Error::Truncated);
+    }
+
+    #[test]
+    fn test_overlong() {
+        let bytes = [0x21u8; 9];
+        assert_eq!(Frame::new_checked(&bytes).unwrap_err(), Error::Overlong);
+    }
+
+    #[test]
+    fn test_repr_parse_valid() {
+        let frame = Frame::new_unchecked(&CONSECUTIVE_FRAME_BYTES);
+        assert_eq!(
+            Repr::parse(&frame),
+            Ok(Repr::Consecutive {
+                sequence_number: 1,
+                data: &CONSECUTIVE_FRAME_BYTES[1..],
+            })
+        );
+        let frame = Frame::new_unchecked(&FLOW_CONTROL_BYTES);
+        assert_eq!(
+            Repr::parse(&frame),
+            Ok(Repr::FlowControl {
+                status: FlowStatus::ContinueToSend,
+                block_size: 2,
+                separation_time: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn test_repr_parse_invalid() {
+        let frame = Frame::new_unchecked(&[0x00, 0x00]);
+        assert_eq!(Repr::parse(&frame), Err(Error::Invalid));
+        let frame = Frame::new_unchecked(&[0x08, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Repr::parse(&frame), Err(Error::Invalid));
+        let frame = Frame::new_unchecked(&[0x0f]);
+        assert_eq!(Repr::parse(&frame), Err(Error::Invalid));
+        let frame = Frame::new_unchecked(&[0x10, 0x07, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Repr::parse(&frame), Err(Error::Invalid));
+        let frame = Frame::new_unchecked(&[0x40]);
+        assert_eq!(Repr::parse(&frame), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_repr_emit() {
+        let mut buf = [0u8; 8];
+        let repr = Repr::First {
+            len: 20,
+            data: &MESSAGE[..6],
+        };
+        assert_eq!(repr.buffer_len(), 8);
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        assert_eq!(buf, FIRST_FRAME_BYTES);
+
+        let mut buf = [0u8; 4];
+        let repr = Repr::Single {
+            data: &SINGLE_FRAME_BYTES[1..],
+        };
+        repr.emit(&mut Frame::new_unchecked(&mut buf));
+        assert_eq!(buf, SINGLE_FRAME_BYTES);
+    }
+
+    #[test]
+    fn test_separation_time() {
+        assert_eq!(separation_time(0x14), Duration::from_millis(20));
+        assert_eq!(separation_time(0xf3), Duration::from_micros(300));
+        assert_eq!(separation_time(0x80), Duration::from_millis(127));
+    }
+
+    #[test]
+    fn test_single_frame_transfer() {
+        let mut sender = Sender::new(&SINGLE_FRAME_BYTES[1..]).unwrap();
+        sender.set_padding(Some(0xaa));
+        let mut buf = [0u8; 8];
+        assert_eq!(sender.next_frame(&mut buf), Ok(Some(8)));
+        assert_eq!(buf, [0x03, 0x22, 0xf1, 0x90, 0xaa, 0xaa, 0xaa, 0xaa]);
+        assert!(sender.is_complete());
+        assert_eq!(sender.next_frame(&mut buf), Ok(None));
+
+        let mut receiver = Receiver::<32>::new(0, 0);
+        assert_eq!(receiver.receive(&buf), Ok(RxStatus::Complete));
+        assert_eq!(receiver.message(), Some(&SINGLE_FRAME_BYTES[1..]));
+    }
+
+    #[test]
+    fn test_segmented_transfer() {
+        let mut sender = Sender::new(&MESSAGE).unwrap();
+        let mut receiver = Receiver::<32>::new(2, 20);
+        let mut buf = [0u8; 8];
+        let mut fc = [0u8; 3];
+        let mut frames = 0;
+
+        loop {
+            match sender.next_frame(&mut buf).unwrap() {
+                Some(len) => {
+                    frames += 1;
+                    match receiver.receive(&buf[..len]).unwrap() {
+                        RxStatus::SendFlowControl => {
+                            receiver
+                                .flow_control()
+                                .emit(&mut Frame::new_unchecked(&mut fc));
+                            assert!(sender.is_waiting());
+                            sender.flow_control(&fc).unwrap();
+                            assert_eq!(sender.separation_time(), Duration::from_millis(20));
+                        }
+                        RxStatus::Pending => {}
+                        RxStatus::Complete => break,
+                    }
+                }
+                None => panic!("sender stalled"),
+            }
+        }
+
+        assert!(sender.is_complete());
+        assert_eq!(frames, 3);
+        assert_eq!(receiver.message(), Some(&MESSAGE[..]));
+    }
+
+    #[test]
+    fn test_receiver_errors() {
+        let mut receiver = Receiver::<8>::new(0, 0);
+        assert_eq!(receiver.receive(&FIRST_FRAME_BYTES), Err(Error::Exhausted));
+        assert_eq!(
+            receiver.receive(&CONSECUTIVE_FRAME_BYTES),
+            Err(Error::Illegal)
+        );
+
+        let mut receiver = Receiver::<32>::new(0, 0);
+        assert_eq!(
+            receiver.receive(&FIRST_FRAME_BYTES),
+            Ok(RxStatus::SendFlowControl)
+        );
+        let mut bytes = CONSECUTIVE_FRAME_BYTES;
+        bytes[0] = 0x22;
+        assert_eq!(receiver.receive(&bytes), Err(Error::Invalid));
+        assert_eq!(receiver.message(), None);
+    }
+
+    #[test]
+    fn test_sender_flow_control() {
+        let mut sender = Sender::new(&MESSAGE).unwrap();
+        assert_eq!(
+            sender.flow_control(&FLOW_CONTROL_BYTES),
+            Err(Error::Illegal)
+        );
+
+        let mut buf = [0u8; 8];
+        sender.next_frame(&mut buf).unwrap();
+        assert_eq!(sender.flow_control(&[0x31, 0x00, 0x00]), Ok(()));
+        assert_eq!(sender.next_frame(&mut buf), Ok(None));
+        assert_eq!(
+            sender.flow_control(&[0x32, 0x00, 0x00]),
+            Err(Error::Dropped)
+        );
+        assert!(sender.is_complete());
+
+        assert_eq!(Sender::new(&[]).unwrap_err(), Error::Truncated);
+        assert_eq!(Sender::new(&[0u8; 4096]).unwrap_err(), Error::Overlong);
+    }
+}
@@ -0,0 +1,5 @@
+//! Transports carrying raw CAN frames between this crate and CAN adapters, and transport
+//! protocols carried over CAN frames.
+
+pub mod isotp;
+pub mod slcan;
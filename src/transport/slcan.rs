@@ -0,0 +1,599 @@
+//! slcan (Lawicel) ASCII protocol used by most USB/serial CAN adapters.
+//!
+//! Frames are exchanged as `\r` terminated ASCII lines:
+//!
+//! ```text
+//! tiiildd..[ssss]       standard data frame
+//! Tiiiiiiiildd..[ssss]  extended data frame
+//! riiil[ssss]           standard remote frame
+//! Riiiiiiiil[ssss]      extended remote frame
+//! ```
+//!
+//! where `i` is the hexadecimal identifier, `l` the data length, `dd` the data bytes and `ssss`
+//! the optional adapter timestamp in milliseconds.
+//!
+//! [parse] and [emit] work on single lines and are `no_std`. With the `std` feature, [Reader]
+//! decodes frames from any [std::io::Read] stream and [Writer] emits frames and adapter
+//! commands to any [std::io::Write] stream.
+
+use core::fmt;
+
+use crate::{Error, Result};
+
+/// Maximum length of an slcan frame line, including the trailing `\r`.
+pub const MAX_LINE_LEN: usize = 1 + 8 + 1 + 16 + 4 + 1;
+
+/// Maximum data length of a CAN frame.
+pub const MAX_DATA_LEN: usize = 8;
+
+/// Maximum standard CAN identifier value.
+pub const MAX_STANDARD_ID: u16 = 0x7ff;
+/// Maximum extended CAN identifier value.
+pub const MAX_EXTENDED_ID: u32 = 0x1fff_ffff;
+
+/// CAN frame identifier.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Id {
+    /// 11-bit standard identifier.
+    Standard(u16),
+    /// 29-bit extended identifier.
+    Extended(u32),
+}
+
+impl Id {
+    /// Return the raw identifier value.
+    pub fn raw(&self) -> u32 {
+        match *self {
+            Id::Standard(id) => id.into(),
+            Id::Extended(id) => id,
+        }
+    }
+
+    /// Return whether the identifier value fits in its standard or extended width.
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Id::Standard(id) => id <= MAX_STANDARD_ID,
+            Id::Extended(id) => id <= MAX_EXTENDED_ID,
+        }
+    }
+
+    /// Return the standard identifier value, if this is a standard identifier.
+    /// PSA comfort and infotainment frames all use standard identifiers.
+    pub fn standard(&self) -> Option<u16> {
+        match *self {
+            Id::Standard(id) => Some(id),
+            Id::Extended(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Id::Standard(id) => write!(f, "{:03x}", id),
+            Id::Extended(id) => write!(f, "{:08x}", id),
+        }
+    }
+}
+
+/// A raw CAN frame exchanged with an slcan adapter.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CanFrame {
+    /// Frame identifier.
+    pub id: Id,
+    /// Remote transmission request flag.
+    pub remote: bool,
+    /// Data length code, at most [MAX_DATA_LEN]. Private so that [CanFrame::payload] cannot
+    /// panic.
+    len: u8,
+    /// Frame data, only the first `len` bytes are meaningful.
+    pub data: [u8; MAX_DATA_LEN],
+    /// Adapter timestamp in milliseconds, wrapping at 60000.
+    pub timestamp: Option<u16>,
+}
+
+impl CanFrame {
+    /// Create a data frame with the standard identifier `id`.
+    /// Returns `Err(Error::Overlong)` if `payload` is longer than 8 bytes and
+    /// `Err(Error::Illegal)` if `id` is not a valid standard identifier.
+    pub fn new(id: u16, payload: &[u8]) -> Result<CanFrame> {
+        CanFrame::new_data(Id::Standard(id), payload)
+    }
+
+    /// Create a data frame with the identifier `id`.
+    /// Returns `Err(Error::Overlong)` if `payload` is longer than 8 bytes and
+    /// `Err(Error::Illegal)` if `id` is not a valid identifier.
+    pub fn new_data(id: Id, payload: &[u8]) -> Result<CanFrame> {
+        let mut frame = CanFrame::new_remote(id, 0)?;
+        if payload.len() > MAX_DATA_LEN {
+            return Err(Error::Overlong);
+        }
+
+        frame.remote = false;
+        frame.len = payload.len() as u8;
+        frame.data[..payload.len()].copy_from_slice(payload);
+        Ok(frame)
+    }
+
+    /// Create a remote frame with the identifier `id`, requesting `len` bytes.
+    /// Returns `Err(Error::Overlong)` if `len` is greater than 8 and `Err(Error::Illegal)`
+    /// if `id` is not a valid identifier.
+    pub fn new_remote(id: Id, len: u8) -> Result<CanFrame> {
+        if !id.is_valid() {
+            return Err(Error::Illegal);
+        }
+        if usize::from(len) > MAX_DATA_LEN {
+            return Err(Error::Overlong);
+        }
+
+        Ok(CanFrame {
+            id,
+            remote: true,
+            len,
+            data: [0u8; MAX_DATA_LEN],
+            timestamp: None,
+        })
+    }
+
+    /// Return the data length code. Remote frames have a data length code but no payload.
+    pub fn dlc(&self) -> u8 {
+        self.len
+    }
+
+    /// Return the frame payload. Remote frames have no payload.
+    pub fn payload(&self) -> &[u8] {
+        if self.remote {
+            &[]
+        } else {
+            &self.data[..usize::from(self.len)]
+        }
+    }
+}
+
+impl fmt::Display for CanFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} [{}]", self.id, self.len)?;
+        if self.remote {
+            write!(f, " remote")?;
+        }
+        for byte in self.payload() {
+            write!(f, " {:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+fn hex_digit(c: u8) -> Result<u8> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::Invalid),
+    }
+}
+
+fn hex_value(digits: &[u8]) -> Result<u32> {
+    digits
+        .iter()
+        .try_fold(0u32, |acc, &c| Ok((acc << 4) | u32::from(hex_digit(c)?)))
+}
+
+fn to_hex(value: u8) -> u8 {
+    b"0123456789ABCDEF"[usize::from(value & 0x0f)]
+}
+
+/// Parse an slcan frame line, without its trailing `\r`.
+///
+/// Returns `Err(Error::Truncated)` or `Err(Error::Overlong)` if the line length does not match
+/// its data length code, and `Err(Error::Invalid)` if the line is not a frame or contains
+/// invalid characters or values.
+pub fn parse(line: &[u8]) -> Result<CanFrame> {
+    let (extended, remote) = match line.first() {
+        Some(b't') => (false, false),
+        Some(b'T') => (true, false),
+        Some(b'r') => (false, true),
+        Some(b'R') => (true, true),
+        Some(_) => return Err(Error::Invalid),
+        None => return Err(Error::Truncated),
+    };
+
+    let id_len = if extended { 8 } else { 3 };
+    let header_len = 1 + id_len + 1;
+    if line.len() < header_len {
+        return Err(Error::Truncated);
+    }
+
+    let raw_id = hex_value(&line[1..1 + id_len])?;
+    let id = if extended {
+        if raw_id > MAX_EXTENDED_ID {
+            return Err(Error::Invalid);
+        }
+        Id::Extended(raw_id)
+    } else {
+        if raw_id > MAX_STANDARD_ID.into() {
+            return Err(Error::Invalid);
+        }
+        Id::Standard(raw_id as u16)
+    };
+
+    let len = hex_digit(line[header_len - 1])?;
+    if usize::from(len) > MAX_DATA_LEN {
+        return Err(Error::Invalid);
+    }
+
+    let data_len = if remote { 0 } else { 2 * usize::from(len) };
+    let rest = &line[header_len..];
+    if rest.len() < data_len {
+        return Err(Error::Truncated);
+    }
+
+    let mut data = [0u8; MAX_DATA_LEN];
+    for (byte, digits) in data.iter_mut().zip(rest[..data_len].chunks_exact(2)) {
+        *byte = hex_value(digits)? as u8;
+    }
+
+    let timestamp = match rest.len() - data_len {
+        0 => None,
+        4 => Some(hex_value(&rest[data_len..])? as u16),
+        n if n < 4 => return Err(Error::Truncated),
+        _ => return Err(Error::Overlong),
+    };
+
+    Ok(CanFrame {
+        id,
+        remote,
+        len,
+        data,
+        timestamp,
+    })
+}
+
+/// Emit `frame` as an slcan frame line into `buffer`, including the trailing `\r`.
+/// Returns the line length, `Err(Error::Invalid)` if the frame identifier does not fit in its
+/// width, or `Err(Error::Exhausted)` if `buffer` is too small.
+pub fn emit(frame: &CanFrame, buffer: &mut [u8]) -> Result<usize> {
+    if !frame.id.is_valid() {
+        return Err(Error::Invalid);
+    }
+
+    let mut line = [0u8; MAX_LINE_LEN];
+    let mut pos = 0;
+    let mut push = |c: u8| {
+        line[pos] = c;
+        pos += 1;
+    };
+
+    let (kind, id_len) = match (frame.id, frame.remote) {
+        (Id::Standard(_), false) => (b't', 3),
+        (Id::Extended(_), false) => (b'T', 8),
+        (Id::Standard(_), true) => (b'r', 3),
+        (Id::Extended(_), true) => (b'R', 8),
+    };
+    push(kind);
+
+    let raw_id = frame.id.raw();
+    for i in (0..id_len).rev() {
+        push(to_hex((raw_id >> (4 * i)) as u8));
+    }
+
+    push(to_hex(frame.len));
+    for &byte in frame.payload() {
+        push(to_hex(byte >> 4));
+        push(to_hex(byte));
+    }
+
+    if let Some(timestamp) = frame.timestamp {
+        for i in (0..4).rev() {
+            push(to_hex((timestamp >> (4 * i)) as u8));
+        }
+    }
+    push(b'\r');
+
+    let out = buffer.get_mut(..pos).ok_or(Error::Exhausted)?;
+    out.copy_from_slice(&line[..pos]);
+    Ok(pos)
+}
+
+/// slcan adapter CAN bus bitrate.
+/// Both AEE2004 conf and AEE2010 infodiv buses run at 125 kbit/s.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Bitrate {
+    Kbps10,
+    Kbps20,
+    Kbps50,
+    Kbps100,
+    Kbps125,
+    Kbps250,
+    Kbps500,
+    Kbps800,
+    Mbps1,
+}
+
+impl Bitrate {
+    /// Return the slcan `Sn` command code of the bitrate.
+    pub fn code(&self) -> u8 {
+        match *self {
+            Bitrate::Kbps10 => b'0',
+            Bitrate::Kbps20 => b'1',
+            Bitrate::Kbps50 => b'2',
+            Bitrate::Kbps100 => b'3',
+            Bitrate::Kbps125 => b'4',
+            Bitrate::Kbps250 => b'5',
+            Bitrate::Kbps500 => b'6',
+            Bitrate::Kbps800 => b'7',
+            Bitrate::Mbps1 => b'8',
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod io {
+    use std::io::{self, Read, Write};
+
+    use super::{emit, parse, Bitrate, CanFrame, MAX_LINE_LEN};
+
+    /// Streaming slcan frame decoder over a [Read] stream.
+    ///
+    /// Bytes are read one at a time, wrap unbuffered streams in a [std::io::BufReader].
+    /// Lines which are not frames, like command acknowledges, are skipped.
+    #[derive(Debug)]
+    pub struct Reader<R: Read> {
+        inner: R,
+        line: [u8; MAX_LINE_LEN],
+        len: usize,
+        overflow: bool,
+    }
+
+    impl<R: Read> Reader<R> {
+        /// Create a decoder reading from `inner`.
+        pub fn new(inner: R) -> Reader<R> {
+            Reader {
+                inner,
+                line: [0u8; MAX_LINE_LEN],
+                len: 0,
+                overflow: false,
+            }
+        }
+
+        /// Consume the decoder, returning the underlying stream.
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+
+        /// Read the next frame from the stream.
+        /// Returns `Ok(None)` at the end of the stream and an [io::ErrorKind::InvalidData]
+        /// error for malformed frame lines.
+        pub fn read_frame(&mut self) -> io::Result<Option<CanFrame>> {
+            let mut byte = [0u8; 1];
+            loop {
+                if self.inner.read(&mut byte)? == 0 {
+                    return Ok(None);
+                }
+
+                match byte[0] {
+                    b'\r' | b'\n' | 0x07 => {
+                        let len = core::mem::take(&mut self.len);
+                        if core::mem::take(&mut self.overflow) {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                crate::Error::Overlong,
+                            ));
+                        }
+
+                        let line = &self.line[..len];
+                        if let Some(b't' | b'T' | b'r' | b'R') = line.first() {
+                            return parse(line)
+                                .map(Some)
+                                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err));
+                        }
+                    }
+                    c if self.len < MAX_LINE_LEN => {
+                        self.line[self.len] = c;
+                        self.len += 1;
+                    }
+                    _ => self.overflow = true,
+                }
+            }
+        }
+    }
+
+    impl<R: Read> Iterator for Reader<R> {
+        type Item = io::Result<CanFrame>;
+
+        fn next(&mut self) -> Option<io::Result<CanFrame>> {
+            self.read_frame().transpose()
+        }
+    }
+
+    /// slcan frame and command encoder over a [Write] stream.
+    #[derive(Debug)]
+    pub struct Writer<W: Write> {
+        inner: W,
+    }
+
+    impl<W: Write> Writer<W> {
+        /// Create an encoder writing to `inner`.
+        pub fn new(inner: W) -> Writer<W> {
+            Writer { inner }
+        }
+
+        /// Consume the encoder, returning the underlying stream.
+        pub fn into_inner(self) -> W {
+            self.inner
+        }
+
+        /// Write `frame` to the stream.
+        pub fn write_frame(&mut self, frame: &CanFrame) -> io::Result<()> {
+            let mut line = [0u8; MAX_LINE_LEN];
+            let len = emit(frame, &mut line)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+            self.inner.write_all(&line[..len])
+        }
+
+        /// Send the set bitrate command. The channel must be closed.
+        pub fn set_bitrate(&mut self, bitrate: Bitrate) -> io::Result<()> {
+            self.inner.write_all(&[b'S', bitrate.code(), b'\r'])
+        }
+
+        /// Send the open channel command.
+        pub fn open(&mut self) -> io::Result<()> {
+            self.inner.write_all(b"O\r")
+        }
+
+        /// Send the close channel command.
+        pub fn close(&mut self) -> io::Result<()> {
+            self.inner.write_all(b"C\r")
+        }
+
+        /// Flush the underlying stream.
+        pub fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use self::io::{Reader, Writer};
+
+#[cfg(test)]
+mod test {
+    use super::{emit, parse, CanFrame, Id, MAX_LINE_LEN};
+
+    use crate::Error;
+
+    static LINE_1: &[u8] = b"t0F6882B1101E2A0000FF";
+    static LINE_2: &[u8] = b"T1234567830102031A2B";
+    static LINE_3: &[u8] = b"r1A52";
+
+    fn frame_1() -> CanFrame {
+        CanFrame::new(0x0f6, &[0x82, 0xb1, 0x10, 0x1e, 0x2a, 0x00, 0x00, 0xff]).unwrap()
+    }
+
+    fn frame_2() -> CanFrame {
+        CanFrame {
+            id: Id::Extended(0x1234_5678),
+            remote: false,
+            len: 3,
+            data: [0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00],
+            timestamp: Some(0x1a2b),
+        }
+    }
+
+    fn frame_3() -> CanFrame {
+        CanFrame {
+            id: Id::Standard(0x1a5),
+            remote: true,
+            len: 2,
+            data: [0u8; 8],
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_valid() {
+        assert_eq!(parse(LINE_1), Ok(frame_1()));
+        assert_eq!(parse(LINE_2), Ok(frame_2()));
+        assert_eq!(parse(LINE_3), Ok(frame_3()));
+        assert_eq!(parse(LINE_3).unwrap().payload(), &[]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(parse(b""), Err(Error::Truncated));
+        assert_eq!(parse(b"z"), Err(Error::Invalid));
+        assert_eq!(parse(b"t0F"), Err(Error::Truncated));
+        assert_eq!(parse(b"t0F6282"), Err(Error::Truncated));
+        assert_eq!(parse(b"t0F61820"), Err(Error::Truncated));
+        assert_eq!(parse(b"t0F618200001"), Err(Error::Overlong));
+        assert_eq!(parse(b"t0G6182"), Err(Error::Invalid));
+        assert_eq!(parse(b"t8006182"), Err(Error::Invalid));
+        assert_eq!(parse(b"t0F69"), Err(Error::Invalid));
+        assert_eq!(parse(b"T2000000000"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_emit() {
+        let mut buf = [0u8; MAX_LINE_LEN];
+
+        let len = emit(&frame_1(), &mut buf).unwrap();
+        assert_eq!(&buf[..len - 1], LINE_1);
+        assert_eq!(buf[len - 1], b'\r');
+
+        let len = emit(&frame_2(), &mut buf).unwrap();
+        assert_eq!(&buf[..len - 1], LINE_2);
+
+        let len = emit(&frame_3(), &mut buf).unwrap();
+        assert_eq!(&buf[..len - 1], LINE_3);
+    }
+
+    #[test]
+    fn test_emit_invalid_id() {
+        let mut buf = [0u8; MAX_LINE_LEN];
+        let mut frame = frame_1();
+        frame.id = Id::Standard(0x800);
+        assert_eq!(emit(&frame, &mut buf), Err(Error::Invalid));
+        frame.id = Id::Extended(0x2000_0000);
+        assert_eq!(emit(&frame, &mut buf), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_emit_exhausted() {
+        let mut buf = [0u8; 8];
+        assert_eq!(emit(&frame_1(), &mut buf), Err(Error::Exhausted));
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert_eq!(CanFrame::new(0x800, &[]), Err(Error::Illegal));
+        assert_eq!(CanFrame::new(0x0f6, &[0u8; 9]), Err(Error::Overlong));
+        assert_eq!(
+            CanFrame::new_data(Id::Extended(0x2000_0000), &[]),
+            Err(Error::Illegal)
+        );
+        assert_eq!(
+            CanFrame::new_remote(Id::Standard(0x1a5), 9),
+            Err(Error::Overlong)
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(CanFrame::new_remote(Id::Standard(0x1a5), 2), Ok(frame_3()));
+        let frame = CanFrame::new_data(Id::Extended(0x1234_5678), &[0x01, 0x02, 0x03]).unwrap();
+        assert_eq!(frame.dlc(), 3);
+        assert_eq!(frame.payload(), &[0x01, 0x02, 0x03]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_reader() {
+        use super::Reader;
+
+        let stream: &[u8] = b"z\rt0F6882B1101E2A0000FF\r\x07T1234567830102031A2B\rt0F6\rr1A52\r";
+        let mut reader = Reader::new(stream);
+        assert_eq!(reader.read_frame().unwrap(), Some(frame_1()));
+        assert_eq!(reader.read_frame().unwrap(), Some(frame_2()));
+        assert_eq!(
+            reader.read_frame().unwrap_err().kind(),
+            std::io::ErrorKind::InvalidData
+        );
+        assert_eq!(reader.next().unwrap().unwrap(), frame_3());
+        assert!(reader.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_writer() {
+        use super::{Bitrate, Writer};
+
+        let mut writer = Writer::new(std::vec::Vec::new());
+        writer.set_bitrate(Bitrate::Kbps125).unwrap();
+        writer.open().unwrap();
+        writer.write_frame(&frame_3()).unwrap();
+        writer.close().unwrap();
+        assert_eq!(writer.into_inner(), b"S4\rO\rr1A52\rC\r");
+    }
+}
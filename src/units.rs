@@ -0,0 +1,224 @@
+//! Typed quantities.
+//!
+//! The frame representations keep distances and volumes as plain numbers in the CAN units,
+//! which are always metric whatever the display units configured in the vehicle, see the
+//! [DistanceUnit] and [VolumeUnit] fields of the configuration frames (e.g. the AEE2004 x3f6
+//! or the AEE2010 x260). [Kilometers], [KilometersPerHour], [Liters] and [Percent] make the
+//! unit explicit, and convert to the configured display unit:
+//!
+//! ```
+//! # use canpsa::{config::DistanceUnit, units::Kilometers};
+//! let range = Kilometers(185);
+//! assert_eq!(range.to_unit(DistanceUnit::Kilometer), Some(185));
+//! assert_eq!(range.to_unit(DistanceUnit::Mile), Some(115));
+//! ```
+//!
+//! Without the `uom` feature, these are lightweight alternatives to the `*_quantity` methods.
+
+use core::fmt;
+
+use crate::config::{DistanceUnit, SpeedUnit, VolumeUnit};
+
+/// Length of a mile, in millimeters.
+const MILE_MM: u64 = 1_609_344;
+/// Volume of an imperial gallon, in liters.
+const GALLON_L: f32 = 4.54609;
+
+/// A distance in kilometers.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Kilometers(pub u32);
+
+impl Kilometers {
+    /// Return the distance in `unit`, rounded to the nearest integer.
+    /// Returns `None` for unknown units.
+    pub fn to_unit(self, unit: DistanceUnit) -> Option<u32> {
+        match unit {
+            DistanceUnit::Kilometer => Some(self.0),
+            DistanceUnit::Mile => {
+                let miles = (u64::from(self.0) * 1_000_000 + MILE_MM / 2) / MILE_MM;
+                u32::try_from(miles).ok()
+            }
+            DistanceUnit::Unknown(_) => None,
+        }
+    }
+
+    /// Create a distance from a value in `unit`, rounded to the nearest kilometer.
+    /// Returns `None` for unknown units and out of range values.
+    pub fn from_unit(value: u32, unit: DistanceUnit) -> Option<Kilometers> {
+        match unit {
+            DistanceUnit::Kilometer => Some(Kilometers(value)),
+            DistanceUnit::Mile => {
+                let km = (u64::from(value) * MILE_MM + 500_000) / 1_000_000;
+                u32::try_from(km).ok().map(Kilometers)
+            }
+            DistanceUnit::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Kilometers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} km", self.0)
+    }
+}
+
+/// A speed in kilometers per hour.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct KilometersPerHour(pub u16);
+
+impl KilometersPerHour {
+    /// Return the speed in `unit`, rounded to the nearest integer.
+    /// Returns `None` for unknown units.
+    pub fn to_unit(self, unit: SpeedUnit) -> Option<u16> {
+        match unit {
+            SpeedUnit::Kph => Some(self.0),
+            SpeedUnit::Mph => Kilometers(self.0.into())
+                .to_unit(DistanceUnit::Mile)
+                .and_then(|mph| u16::try_from(mph).ok()),
+            SpeedUnit::Unknown(_) => None,
+        }
+    }
+
+    /// Create a speed from a value in `unit`, rounded to the nearest kilometer per hour.
+    /// Returns `None` for unknown units and out of range values.
+    pub fn from_unit(value: u16, unit: SpeedUnit) -> Option<KilometersPerHour> {
+        match unit {
+            SpeedUnit::Kph => Some(KilometersPerHour(value)),
+            SpeedUnit::Mph => Kilometers::from_unit(value.into(), DistanceUnit::Mile)
+                .and_then(|km| u16::try_from(km.0).ok())
+                .map(KilometersPerHour),
+            SpeedUnit::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for KilometersPerHour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} km/h", self.0)
+    }
+}
+
+/// A volume in liters.
+#[derive(Debug, Default, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Liters(pub f32);
+
+impl Liters {
+    /// Return the volume in `unit`, gallons being imperial gallons.
+    /// Returns `None` for unknown units.
+    pub fn to_unit(self, unit: VolumeUnit) -> Option<f32> {
+        match unit {
+            VolumeUnit::Liter => Some(self.0),
+            VolumeUnit::Gallon => Some(self.0 / GALLON_L),
+            VolumeUnit::Unknown(_) => None,
+        }
+    }
+
+    /// Create a volume from a value in `unit`, gallons being imperial gallons.
+    /// Returns `None` for unknown units.
+    pub fn from_unit(value: f32, unit: VolumeUnit) -> Option<Liters> {
+        match unit {
+            VolumeUnit::Liter => Some(Liters(value)),
+            VolumeUnit::Gallon => Some(Liters(value * GALLON_L)),
+            VolumeUnit::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Liters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} L", self.0)
+    }
+}
+
+/// A ratio in percent, from 0 to 100.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Percent(u8);
+
+impl Percent {
+    /// Create a ratio from a value in percent.
+    /// Returns `None` if `value` is greater than 100.
+    pub const fn new(value: u8) -> Option<Percent> {
+        if value > 100 {
+            None
+        } else {
+            Some(Percent(value))
+        }
+    }
+
+    /// Create a ratio from a value in percent, saturating values greater than 100.
+    pub const fn saturating(value: u8) -> Percent {
+        if value > 100 {
+            Percent(100)
+        } else {
+            Percent(value)
+        }
+    }
+
+    /// Return the ratio in percent.
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Kilometers, KilometersPerHour, Liters, Percent};
+
+    use crate::config::{DistanceUnit, SpeedUnit, VolumeUnit};
+
+    #[test]
+    fn test_kilometers() {
+        let distance = Kilometers(1609);
+        assert_eq!(distance.to_unit(DistanceUnit::Kilometer), Some(1609));
+        assert_eq!(distance.to_unit(DistanceUnit::Mile), Some(1000));
+        assert_eq!(distance.to_unit(DistanceUnit::Unknown(2)), None);
+        assert_eq!(
+            Kilometers::from_unit(1000, DistanceUnit::Mile),
+            Some(distance)
+        );
+        assert_eq!(Kilometers::from_unit(u32::MAX, DistanceUnit::Mile), None);
+        assert_eq!(
+            Kilometers(u32::MAX).to_unit(DistanceUnit::Mile),
+            Some(2_668_768_949)
+        );
+    }
+
+    #[test]
+    fn test_kilometers_per_hour() {
+        let speed = KilometersPerHour(130);
+        assert_eq!(speed.to_unit(SpeedUnit::Kph), Some(130));
+        assert_eq!(speed.to_unit(SpeedUnit::Mph), Some(81));
+        assert_eq!(speed.to_unit(SpeedUnit::Unknown(2)), None);
+        assert_eq!(
+            KilometersPerHour::from_unit(50, SpeedUnit::Mph),
+            Some(KilometersPerHour(80))
+        );
+        assert_eq!(KilometersPerHour::from_unit(u16::MAX, SpeedUnit::Mph), None);
+    }
+
+    #[test]
+    fn test_liters() {
+        let volume = Liters(45.4609);
+        assert_eq!(volume.to_unit(VolumeUnit::Liter), Some(45.4609));
+        assert_eq!(volume.to_unit(VolumeUnit::Gallon), Some(10.0));
+        assert_eq!(Liters::from_unit(10.0, VolumeUnit::Gallon), Some(volume));
+        assert_eq!(volume.to_unit(VolumeUnit::Unknown(2)), None);
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(Percent::new(100).map(Percent::value), Some(100));
+        assert_eq!(Percent::new(101), None);
+        assert_eq!(Percent::saturating(0xff).value(), 100);
+    }
+}
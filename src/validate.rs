@@ -0,0 +1,169 @@
+//! Detection of undocumented values.
+//!
+//! The value enums, e.g. [ACFanSpeed](crate::vehicle::ACFanSpeed), keep the values which are
+//! not documented in an `Unknown` variant rather than failing the parsing, so that a frame
+//! is still decoded when a firmware emits a new value. [Validate] tells these values apart,
+//! and [ValidateFields] lists the fields of a frame representation which hold one, e.g. for
+//! conformance tools to flag the firmwares emitting undocumented values:
+//!
+//! ```
+//! # use canpsa::{aee2010::infodiv::x350, validate::{Validate, ValidateFields}, vehicle::ACFanSpeed};
+//! let repr = x350::Repr::builder()
+//!     .with_front_fan_speed(ACFanSpeed::from(0x0c))
+//!     .build()
+//!     .unwrap();
+//! assert!(!repr.is_known());
+//! let unknown: Vec<&str> = repr.validate().collect();
+//! assert_eq!(unknown, ["front_fan_speed"]);
+//! ```
+//!
+//! The value enums also list their documented values with `iter()`, e.g. to fill the options of
+//! a configuration user interface, and convert from and to raw values with `from_raw()` and
+//! `as_raw()`.
+
+use crate::diff::Diff;
+
+/// Check of a decoded value against the documented values.
+pub trait Validate {
+    /// Return whether the value is documented. Values without an `Unknown` variant are
+    /// always documented.
+    fn is_known(&self) -> bool;
+}
+
+macro_rules! always_known {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+          impl Validate for $ty {
+              #[inline]
+              fn is_known(&self) -> bool {
+                  true
+              }
+          }
+        )+
+    };
+}
+
+always_known!(
+    bool,
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    f32,
+    core::time::Duration,
+    time::Duration,
+    time::Time,
+    time::OffsetDateTime,
+);
+
+#[cfg(feature = "fixed")]
+always_known!(crate::physical::Fixed);
+
+impl<const N: usize> Validate for heapless::String<N> {
+    #[inline]
+    fn is_known(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn is_known(&self) -> bool {
+        self.iter().all(Validate::is_known)
+    }
+}
+
+/// Field by field check of a frame representation, see [Validate].
+pub trait ValidateFields: Diff {
+    /// Return whether the field `name` holds a documented value.
+    /// Returns `true` for unknown fields.
+    fn field_known(&self, name: &str) -> bool;
+
+    /// Return an iterator over the names of the fields holding an undocumented value.
+    fn validate(&self) -> Unknowns<'_, Self> {
+        Unknowns {
+            repr: self,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the fields holding an undocumented value, see [ValidateFields::validate].
+#[derive(Debug, Clone)]
+pub struct Unknowns<'a, R> {
+    repr: &'a R,
+    index: usize,
+}
+
+impl<'a, R: ValidateFields> Iterator for Unknowns<'a, R> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<&'static str> {
+        while let Some(&name) = R::FIELDS.get(self.index) {
+            self.index += 1;
+            if !self.repr.field_known(name) {
+                return Some(name);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Validate, ValidateFields};
+
+    use crate::{
+        aee2004::conf::{self, x1d0},
+        config::Language,
+        vehicle::{ACAirTemperature, ACFanSpeed},
+    };
+
+    #[test]
+    fn test_is_known() {
+        assert!(ACFanSpeed::Speed3.is_known());
+        assert!(!ACFanSpeed::from(0x0c).is_known());
+        assert!(true.is_known());
+        assert!([ACFanSpeed::Speed0, ACFanSpeed::Speed8].is_known());
+        assert!(![ACFanSpeed::Speed0, ACFanSpeed::Unknown(0x0e)].is_known());
+    }
+
+    #[test]
+    fn test_validate() {
+        let repr = x1d0::Repr::builder().build().unwrap();
+        assert!(repr.is_known());
+        assert_eq!(repr.validate().next(), None);
+
+        let repr = x1d0::Repr::builder()
+            .with_front_left_temp(ACAirTemperature::from(0x1e))
+            .with_front_fan_speed(ACFanSpeed::from(0x0c))
+            .build()
+            .unwrap();
+        assert!(!repr.is_known());
+        let mut unknown = repr.validate();
+        assert_eq!(unknown.next(), Some("front_fan_speed"));
+        assert_eq!(unknown.next(), Some("front_left_temp"));
+        assert_eq!(unknown.next(), None);
+        assert!(repr.field_known("front_right_temp"));
+        assert!(repr.field_known("unknown"));
+
+        assert!(!conf::Message::from(repr).is_known());
+    }
+
+    #[test]
+    fn test_known_variants() {
+        assert_eq!(ACFanSpeed::iter().last(), Some(ACFanSpeed::Speed0));
+        assert!(ACFanSpeed::iter().all(|speed| speed.is_known()));
+
+        for language in Language::iter() {
+            assert_eq!(Language::from_raw(language.as_raw()), language);
+        }
+        assert_eq!(Language::iter().count(), 19);
+        assert_eq!(Language::Swedish.as_raw(), 0x1d);
+        assert_eq!(Language::from_raw(0x1e), Language::Unknown(0x1e));
+        assert!(Language::iter().all(|language| language != Language::Unknown(0x1e)));
+    }
+}
@@ -23,6 +23,66 @@ impl fmt::Display for FunctionState {
     }
 }
 
+/// Number of vehicle functions reporting a [FunctionState] in x2e1 CAN frames.
+pub const FUNCTION_COUNT: usize = 9;
+
+/// A vehicle function reporting a [FunctionState] in x2e1 CAN frames.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FunctionKind {
+    /// Automatic door locking when driving.
+    AutomaticDoorLockingWhenDriving,
+    /// Automatic headlamps.
+    AutomaticHeadlamps,
+    /// Passenger airbag.
+    PassengerAirbag,
+    /// Parking sensors.
+    ParkingSensors,
+    /// Automatic wipers.
+    AutomaticWipers,
+    /// Electronic stability program.
+    Esp,
+    /// Door locking.
+    DoorLocking,
+    /// Stop & Start.
+    StopStart,
+    /// Rear doors child lock.
+    RearDoorsChildLock,
+}
+
+impl FunctionKind {
+    /// Every function, in frame order.
+    pub const ALL: [FunctionKind; FUNCTION_COUNT] = [
+        FunctionKind::AutomaticDoorLockingWhenDriving,
+        FunctionKind::AutomaticHeadlamps,
+        FunctionKind::PassengerAirbag,
+        FunctionKind::ParkingSensors,
+        FunctionKind::AutomaticWipers,
+        FunctionKind::Esp,
+        FunctionKind::DoorLocking,
+        FunctionKind::StopStart,
+        FunctionKind::RearDoorsChildLock,
+    ];
+}
+
+impl fmt::Display for FunctionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FunctionKind::AutomaticDoorLockingWhenDriving => {
+                write!(f, "automatic door locking when driving")
+            }
+            FunctionKind::AutomaticHeadlamps => write!(f, "automatic headlamps"),
+            FunctionKind::PassengerAirbag => write!(f, "passenger airbag"),
+            FunctionKind::ParkingSensors => write!(f, "parking sensors"),
+            FunctionKind::AutomaticWipers => write!(f, "automatic wipers"),
+            FunctionKind::Esp => write!(f, "ESP"),
+            FunctionKind::DoorLocking => write!(f, "door locking"),
+            FunctionKind::StopStart => write!(f, "Stop & Start"),
+            FunctionKind::RearDoorsChildLock => write!(f, "rear doors child lock"),
+        }
+    }
+}
+
 enum_with_unknown! {
    /// Stop & Start system state.
    pub enum StopAndStartSystemState(u8) {
@@ -72,11 +132,11 @@ impl fmt::Display for EngineState {
 enum_with_unknown! {
 /// Traction battery charge state.
 pub enum TractionBatteryChargeState(u8) {
-    /// Engine is disabled.
+    /// Traction battery is neither charged nor discharged.
     Disabled = 0,
-    /// Engine driving.
+    /// Traction battery is being charged, from the charging plug or by regenerative braking.
     Recharge = 1,
-    /// Engine braking.
+    /// Traction battery is being discharged by the electric motor.
     Discharge = 2,
 }
 }
@@ -435,6 +495,9 @@ impl fmt::Display for NetworkState {
     }
 }
 
+// Decoded by the x0b6 and x036 frames, sent every 50ms and 100ms.
+enum_lookup_table!(SpeedValidity, NetworkState);
+
 enum_with_unknown! {
    /// Convertible roof position.
    pub enum ConvertibleRoofPosition(u8) {
@@ -877,6 +940,18 @@ impl From<ACFanMode2004> for ACFanMode2010 {
     }
 }
 
+impl From<ACFanMode2010> for ACFanMode2004 {
+    fn from(mode_2010: ACFanMode2010) -> Self {
+        match mode_2010 {
+            ACFanMode2010::AutoSoft => ACFanMode2004::AutoSoft,
+            ACFanMode2010::AutoComfort => ACFanMode2004::AutoComfort,
+            ACFanMode2010::AutoDemist => ACFanMode2004::AutoDemist,
+            ACFanMode2010::Manual => ACFanMode2004::Manual,
+            ACFanMode2010::Unknown(mode) => ACFanMode2004::Unknown(mode),
+        }
+    }
+}
+
 enum_with_unknown! {
    /// A/C fan speed. AEE 2004 only.
    pub enum ACFanSpeed(u8) {
@@ -1076,6 +1151,65 @@ impl fmt::Display for ACAirTemperature {
     }
 }
 
+/// Temperature settings between [ACAirTemperature::LO] and [ACAirTemperature::HI], with
+/// their value in half degrees Celsius.
+#[cfg(feature = "float")]
+const AC_AIR_TEMPERATURES: [(i64, ACAirTemperature); 21] = [
+    (28, ACAirTemperature::Fourteen),
+    (30, ACAirTemperature::Fifteen),
+    (32, ACAirTemperature::Sixteen),
+    (34, ACAirTemperature::Seventeen),
+    (36, ACAirTemperature::Eighteen),
+    (37, ACAirTemperature::EighteenDotFive),
+    (38, ACAirTemperature::Nineteen),
+    (39, ACAirTemperature::NineteenDotFive),
+    (40, ACAirTemperature::Twenty),
+    (41, ACAirTemperature::TwentyDotFive),
+    (42, ACAirTemperature::TwentyOne),
+    (43, ACAirTemperature::TwentyOneDotFive),
+    (44, ACAirTemperature::TwentyTwo),
+    (45, ACAirTemperature::TwentyTwoDotFive),
+    (46, ACAirTemperature::TwentyThree),
+    (47, ACAirTemperature::TwentyThreeDotFive),
+    (48, ACAirTemperature::TwentyFour),
+    (50, ACAirTemperature::TwentyFive),
+    (52, ACAirTemperature::TwentySix),
+    (54, ACAirTemperature::TwentySeven),
+    (56, ACAirTemperature::TwentyEight),
+];
+
+#[cfg(feature = "float")]
+impl ACAirTemperature {
+    /// Return the setting nearest to `celsius` degrees Celsius, halves rounding up.
+    /// Temperatures below 14°C map to [ACAirTemperature::LO], and above 28°C to
+    /// [ACAirTemperature::HI]. NaN maps to [ACAirTemperature::LO].
+    pub fn from_celsius(celsius: crate::physical::Physical) -> ACAirTemperature {
+        let half_degrees = crate::physical::to_raw(celsius, 0, 2).unwrap_or(i64::MIN);
+        let (lowest, highest) = (AC_AIR_TEMPERATURES[0].0, AC_AIR_TEMPERATURES[20].0);
+        if half_degrees < lowest {
+            ACAirTemperature::LO
+        } else if half_degrees > highest {
+            ACAirTemperature::HI
+        } else {
+            // Iterate from the highest setting for halves to round up.
+            AC_AIR_TEMPERATURES
+                .iter()
+                .rev()
+                .min_by_key(|(setting, _)| (setting - half_degrees).abs())
+                .map_or(ACAirTemperature::HI, |&(_, temp)| temp)
+        }
+    }
+
+    /// Return the setting in degrees Celsius, or `None` for [ACAirTemperature::LO],
+    /// [ACAirTemperature::HI] and unknown settings.
+    pub fn celsius(&self) -> Option<crate::physical::Physical> {
+        AC_AIR_TEMPERATURES
+            .iter()
+            .find(|(_, temp)| temp == self)
+            .map(|&(half_degrees, _)| crate::physical::from_raw(half_degrees, 0, 2))
+    }
+}
+
 enum_with_unknown! {
    /// A/C mode request.
    pub enum ACModeRequest(u8) {
@@ -1741,3 +1875,32 @@ impl fmt::Display for FaultLogContext {
         }
     }
 }
+
+/// Rotation data of a wheel, from the wheel counters of the brake control unit x0e6 frames.
+///
+/// The frames only carry a counter of the wheel speed sensor teeth: the wheel speed and
+/// direction are not broadcast, and converting counter increments to a speed needs the
+/// number of sensor teeth and the tyre circumference of the vehicle.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct WheelData {
+    /// 15-bit wheel counter, wrapping around.
+    pub counter: u16,
+    /// Whether the counter is valid, i.e. its failure flag is cleared.
+    pub valid: bool,
+}
+
+impl WheelData {
+    /// Maximum value of the wheel counter, after which it wraps around to 0.
+    pub const COUNTER_MAX: u16 = 0x7fff;
+
+    /// Return the number of counter increments since `previous`, accounting for the counter
+    /// wrapping around. Returns `None` if either counter is not valid.
+    pub fn ticks_since(&self, previous: &WheelData) -> Option<u16> {
+        if self.valid && previous.valid {
+            Some(self.counter.wrapping_sub(previous.counter) & Self::COUNTER_MAX)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! Frame reception timeout watchdog.
+//!
+//! The [FrameWatchdog] records when each watched periodic frame was last seen and reports the
+//! frames which have not been received for a number of their periods, e.g. when the BSI went
+//! to sleep, crashed or was disconnected. Unlike the [Mailbox](crate::rx::Mailbox), it does
+//! not parse the frames: feeding it the identifier of every received frame is enough.
+//!
+//! The periodicities come from the [registry] of the bus, and
+//! [FrameWatchdog::aee2004] and [FrameWatchdog::aee2010] watch the periodic BSI frames
+//! listed in [SAFETY_FRAMES] out of the box.
+//!
+//! ```
+//! # use core::time::Duration;
+//! # use canpsa::{aee2010::infodiv::x0b6, rx::STALE_PERIODS, watchdog::FrameWatchdog};
+//! let mut watchdog = FrameWatchdog::aee2010(STALE_PERIODS, Duration::ZERO);
+//! watchdog.feed(x0b6::FRAME_ID, Duration::from_millis(400));
+//! // x0b6 is sent every 50ms, x036 every 100ms and x0f6 every 500ms.
+//! let stale: Vec<u16> = watchdog.stale(Duration::from_millis(450)).collect();
+//! assert_eq!(stale, [0x036]);
+//! ```
+
+use core::time::Duration;
+
+use heapless::Vec;
+
+use crate::{
+    aee2010::infodiv,
+    registry::{self, FrameInfo},
+    Error, Result,
+};
+
+/// Identifiers of the BSI frames whose loss should be detected: vehicle state (x036), engine
+/// speed and vehicle speed (x0b6), ignition and odometer (x0f6). They use the same identifier
+/// on both buses.
+pub const SAFETY_FRAMES: [u16; 3] = [
+    infodiv::x036::FRAME_ID,
+    infodiv::x0b6::FRAME_ID,
+    infodiv::x0f6::FRAME_ID,
+];
+
+/// A watched frame and its last reception time.
+#[derive(Debug, Clone, Copy)]
+struct Watched {
+    id: u16,
+    periodicity: Duration,
+    last_seen: Duration,
+}
+
+/// Reception timeout watchdog of up to `N` periodic frames.
+#[derive(Debug, Clone)]
+pub struct FrameWatchdog<const N: usize> {
+    frames: Vec<Watched, N>,
+    periods: u32,
+}
+
+impl FrameWatchdog<{ SAFETY_FRAMES.len() }> {
+    /// Create a watchdog of the [SAFETY_FRAMES] of the AEE2004 conf bus, reporting them
+    /// stale when missed for `periods` periods.
+    pub fn aee2004(periods: u32, now: Duration) -> Self {
+        Self::with_frames(periods, registry::aee2004::lookup, now)
+    }
+
+    /// Create a watchdog of the [SAFETY_FRAMES] of the AEE2010 infodiv bus, reporting them
+    /// stale when missed for `periods` periods.
+    pub fn aee2010(periods: u32, now: Duration) -> Self {
+        Self::with_frames(periods, registry::aee2010::lookup, now)
+    }
+
+    fn with_frames(
+        periods: u32,
+        lookup: fn(u16) -> Option<&'static FrameInfo>,
+        now: Duration,
+    ) -> Self {
+        let mut watchdog = FrameWatchdog::new(periods);
+        for info in SAFETY_FRAMES.iter().filter_map(|&id| lookup(id)) {
+            // Non-periodic frames are rejected, and the capacity fits every safety frame.
+            let _ = watchdog.watch(info, now);
+        }
+        watchdog
+    }
+}
+
+impl<const N: usize> FrameWatchdog<N> {
+    /// Create a watchdog without any watched frame, reporting frames stale when missed for
+    /// `periods` periods.
+    pub fn new(periods: u32) -> FrameWatchdog<N> {
+        FrameWatchdog {
+            frames: Vec::new(),
+            periods,
+        }
+    }
+
+    /// Return the number of missed periods after which a frame is stale.
+    pub fn periods(&self) -> u32 {
+        self.periods
+    }
+
+    /// Watch the frame described by `info`, as if it was received at `now`. Watching an
+    /// already watched frame restarts its timeout.
+    ///
+    /// Returns `Err(Error::Illegal)` if the frame is not periodic, and `Err(Error::Exhausted)`
+    /// if the watchdog already watches `N` other frames.
+    pub fn watch(&mut self, info: &FrameInfo, now: Duration) -> Result<()> {
+        let periodicity = info.periodicity.ok_or(Error::Illegal)?;
+        let watched = Watched {
+            id: info.id,
+            periodicity,
+            last_seen: now,
+        };
+        match self.frames.iter_mut().find(|frame| frame.id == info.id) {
+            Some(frame) => *frame = watched,
+            None => self.frames.push(watched).map_err(|_| Error::Exhausted)?,
+        }
+        Ok(())
+    }
+
+    /// Stop watching the frame with identifier `id`.
+    /// Returns whether the frame was watched.
+    pub fn unwatch(&mut self, id: u16) -> bool {
+        match self.frames.iter().position(|frame| frame.id == id) {
+            Some(index) => {
+                self.frames.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record the reception at `now` of a frame with identifier `id`.
+    /// Returns whether the frame is watched.
+    pub fn feed(&mut self, id: u16, now: Duration) -> bool {
+        match self.frames.iter_mut().find(|frame| frame.id == id) {
+            Some(frame) => {
+                frame.last_seen = now;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Return the time elapsed at `now` since the last reception of the frame with
+    /// identifier `id`, or `None` if it is not watched.
+    pub fn age(&self, id: u16, now: Duration) -> Option<Duration> {
+        self.frames
+            .iter()
+            .find(|frame| frame.id == id)
+            .map(|frame| now.saturating_sub(frame.last_seen))
+    }
+
+    /// Return whether the watched frame with identifier `id` has not been received for
+    /// more than [periods](FrameWatchdog::periods) periods at `now`. Frames which are not
+    /// watched are never stale.
+    pub fn is_stale(&self, id: u16, now: Duration) -> bool {
+        self.frames
+            .iter()
+            .find(|frame| frame.id == id)
+            .is_some_and(|frame| self.is_expired(frame, now))
+    }
+
+    /// Return an iterator over the identifiers of the watched frames which are stale at `now`.
+    pub fn stale(&self, now: Duration) -> impl Iterator<Item = u16> + '_ {
+        self.frames
+            .iter()
+            .filter(move |frame| self.is_expired(frame, now))
+            .map(|frame| frame.id)
+    }
+
+    /// Return the earliest time after `now` at which a watched frame becomes stale if it is
+    /// not received again, or `None` if every watched frame is already stale at `now`.
+    pub fn deadline(&self, now: Duration) -> Option<Duration> {
+        self.frames
+            .iter()
+            .map(|frame| self.timeout(frame))
+            .filter(|&timeout| timeout >= now)
+            .min()
+    }
+
+    /// Restart the timeout of every watched frame, as if they were all received at `now`,
+    /// e.g. when the network wakes up.
+    pub fn reset(&mut self, now: Duration) {
+        for frame in self.frames.iter_mut() {
+            frame.last_seen = now;
+        }
+    }
+
+    /// Return an iterator over the identifiers of the watched frames.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.frames.iter().map(|frame| frame.id)
+    }
+
+    /// Return the number of watched frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Return whether no frame is watched.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    fn timeout(&self, frame: &Watched) -> Duration {
+        frame.last_seen + frame.periodicity * self.periods
+    }
+
+    fn is_expired(&self, frame: &Watched, now: Duration) -> bool {
+        now > self.timeout(frame)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use super::{FrameWatchdog, SAFETY_FRAMES};
+
+    use crate::{
+        aee2010::infodiv::{x036, x0b6, x0f6, x128},
+        registry::{aee2004, aee2010},
+        rx::STALE_PERIODS,
+        Error,
+    };
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_safety_frames() {
+        let watchdog = FrameWatchdog::aee2010(STALE_PERIODS, Duration::ZERO);
+        assert!(watchdog.iter().eq(SAFETY_FRAMES));
+
+        let watchdog = FrameWatchdog::aee2004(STALE_PERIODS, Duration::ZERO);
+        assert!(watchdog.iter().eq(SAFETY_FRAMES));
+        assert_eq!(watchdog.periods(), STALE_PERIODS);
+    }
+
+    #[test]
+    fn test_watch() {
+        let mut watchdog: FrameWatchdog<1> = FrameWatchdog::new(2);
+        assert!(watchdog.is_empty());
+
+        let x128 = aee2010::lookup(x128::FRAME_ID).unwrap();
+        assert_eq!(watchdog.watch(x128, Duration::ZERO), Err(Error::Illegal));
+
+        let x0b6 = aee2004::lookup(x0b6::FRAME_ID).unwrap();
+        assert_eq!(watchdog.watch(x0b6, Duration::ZERO), Ok(()));
+        assert_eq!(watchdog.watch(x0b6, ms(100)), Ok(()));
+        assert_eq!(watchdog.age(x0b6::FRAME_ID, ms(150)), Some(ms(50)));
+
+        let x036 = aee2004::lookup(x036::FRAME_ID).unwrap();
+        assert_eq!(watchdog.watch(x036, Duration::ZERO), Err(Error::Exhausted));
+        assert_eq!(watchdog.len(), 1);
+
+        assert!(!watchdog.feed(x036::FRAME_ID, ms(100)));
+        assert!(!watchdog.is_stale(x036::FRAME_ID, ms(10_000)));
+        assert!(watchdog.unwatch(x0b6::FRAME_ID));
+        assert!(!watchdog.unwatch(x0b6::FRAME_ID));
+        assert_eq!(watchdog.age(x0b6::FRAME_ID, ms(150)), None);
+    }
+
+    #[test]
+    fn test_staleness() {
+        let mut watchdog = FrameWatchdog::aee2010(STALE_PERIODS, Duration::ZERO);
+        assert_eq!(watchdog.stale(ms(150)).next(), None);
+        assert_eq!(watchdog.deadline(Duration::ZERO), Some(ms(150)));
+
+        // x0b6 is sent every 50ms, x036 every 100ms and x0f6 every 500ms.
+        assert!(watchdog.feed(x0b6::FRAME_ID, ms(100)));
+        assert!(watchdog.is_stale(x036::FRAME_ID, ms(301)));
+        assert!(!watchdog.is_stale(x0b6::FRAME_ID, ms(250)));
+        assert!(watchdog.is_stale(x0b6::FRAME_ID, ms(251)));
+        assert!(watchdog.stale(ms(301)).eq([x036::FRAME_ID, x0b6::FRAME_ID]));
+        assert_eq!(watchdog.deadline(ms(100)), Some(ms(250)));
+        assert_eq!(watchdog.deadline(ms(260)), Some(ms(300)));
+
+        let now = ms(1600);
+        assert!(watchdog.stale(now).eq(SAFETY_FRAMES));
+        assert_eq!(watchdog.deadline(now), None);
+
+        watchdog.reset(now);
+        assert_eq!(watchdog.stale(now).next(), None);
+        assert!(watchdog.feed(x0f6::FRAME_ID, now + ms(500)));
+        assert!(watchdog
+            .stale(now + ms(1600))
+            .eq([x036::FRAME_ID, x0b6::FRAME_ID]));
+    }
+}
@@ -0,0 +1,219 @@
+//! Public API stability checks.
+//!
+//! Downstream firmware widely relies on the public fields of the `Repr` structs and on the
+//! variants of the value enums. `test_public_api_snapshot` extracts them from the sources and
+//! compares them against `tests/fixtures/api/public_api.txt`, so field renames and retypes
+//! show up in review. Set `CANPSA_BLESS=1` to re-record the snapshot after an intended change.
+//!
+//! With the `semver-checks` feature, `test_semver_checks` also runs `cargo semver-checks`
+//! against the latest release, which requires the tool to be installed.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn source_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            source_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Return the module path of the source file `path`, relative to the `src` directory `root`.
+fn module_path(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap().with_extension("");
+    let mut segments: Vec<String> = relative
+        .iter()
+        .map(|segment| segment.to_str().unwrap().to_string())
+        .collect();
+    if matches!(segments.last().map(String::as_str), Some("mod" | "lib")) {
+        segments.pop();
+    }
+
+    let mut module = String::from("canpsa");
+    for segment in segments {
+        module.push_str("::");
+        module.push_str(&segment);
+    }
+    module
+}
+
+/// Return the item name declared by `line`, if it opens a braced `pub struct` or `pub enum`.
+fn item_name<'a>(line: &'a str, keyword: &str) -> Option<&'a str> {
+    let rest = line.trim_start().strip_prefix(keyword)?;
+    if !line.trim_end().ends_with('{') {
+        return None;
+    }
+    let end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    // Skip macro definitions, e.g. `pub enum $name`.
+    Some(&rest[..end]).filter(|name| !name.is_empty())
+}
+
+/// Extract the public struct fields and enum variants of a source file.
+fn public_items(module: &str, source: &str, api: &mut Vec<String>) {
+    let mut lines = source.lines().peekable();
+    while let Some(line) = lines.next() {
+        let is_test_mod = lines
+            .peek()
+            .is_some_and(|next| next.trim_start().starts_with("mod "));
+        if line.trim_start().starts_with("#[cfg(test)]") && is_test_mod {
+            // Test modules are always the last item of a file.
+            break;
+        }
+
+        let (name, is_enum) = match (item_name(line, "pub struct "), item_name(line, "pub enum ")) {
+            (Some(name), _) => (name, false),
+            (_, Some(name)) => (name, true),
+            _ => continue,
+        };
+
+        let indent = line.len() - line.trim_start().len();
+        let mut variant = String::new();
+        for body in lines.by_ref() {
+            let trimmed = body.trim();
+            if trimmed == "}" && body.len() - body.trim_start().len() == indent {
+                break;
+            }
+            if trimmed.starts_with("//") || trimmed.starts_with("#[") || trimmed.is_empty() {
+                continue;
+            }
+
+            // Join the fields of struct-like enum variants spread over several lines.
+            if is_enum && (!variant.is_empty() || trimmed.ends_with('{')) {
+                if !variant.is_empty() && !variant.ends_with('{') && trimmed != "}," {
+                    variant.push(',');
+                }
+                if !variant.is_empty() {
+                    variant.push(' ');
+                }
+                variant.push_str(trimmed.strip_suffix(',').unwrap_or(trimmed));
+                if trimmed != "}," {
+                    continue;
+                }
+            }
+            let item = if variant.is_empty() {
+                match trimmed.strip_suffix(',') {
+                    Some(item) => item.to_string(),
+                    None => continue,
+                }
+            } else {
+                std::mem::take(&mut variant)
+            };
+            let item = item.as_str();
+            // Skip macro matchers, e.g. `pub $field:ident: $ty:ty`.
+            if item.contains('$') {
+                continue;
+            }
+            if is_enum {
+                api.push(format!("{}::{}::{}", module, name, item));
+            } else if let Some(field) = item.strip_prefix("pub ") {
+                api.push(format!("{}::{}::{}", module, name, field));
+            }
+        }
+    }
+}
+
+fn public_api() -> String {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut files = Vec::new();
+    source_files(&root, &mut files);
+
+    let mut api = Vec::new();
+    for path in files {
+        let source = fs::read_to_string(&path).unwrap();
+        public_items(&module_path(&root, &path), &source, &mut api);
+    }
+
+    let mut snapshot = api.join("\n");
+    snapshot.push('\n');
+    snapshot
+}
+
+#[test]
+fn test_public_api_snapshot() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/api/public_api.txt");
+    let actual = public_api();
+
+    if env::var_os("CANPSA_BLESS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap();
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let removed: Vec<&&str> = expected.iter().filter(|l| !actual.contains(l)).collect();
+    let added: Vec<&&str> = actual.iter().filter(|l| !expected.contains(l)).collect();
+    assert!(
+        removed.is_empty() && added.is_empty(),
+        "public API changed, re-run with CANPSA_BLESS=1 if intended\nremoved: {:#?}\nadded: {:#?}",
+        removed,
+        added
+    );
+}
+
+#[test]
+fn test_public_items() {
+    let source = "\
+pub struct Repr {
+    pub speed: u16,
+    /// Doc.
+    pub unit: SpeedUnit,
+    private: bool,
+}
+
+enum_with_unknown! {
+    pub enum SpeedUnit(u8) {
+        /// Doc.
+        Kph = 0,
+        Mph = 1,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    pub struct Ignored {
+        pub field: u8,
+    }
+}
+";
+    let mut api = Vec::new();
+    public_items("canpsa::x0b6", source, &mut api);
+    assert_eq!(
+        api,
+        vec![
+            "canpsa::x0b6::Repr::speed: u16",
+            "canpsa::x0b6::Repr::unit: SpeedUnit",
+            "canpsa::x0b6::SpeedUnit::Kph = 0",
+            "canpsa::x0b6::SpeedUnit::Mph = 1",
+        ]
+    );
+}
+
+#[cfg(feature = "semver-checks")]
+#[test]
+fn test_semver_checks() {
+    let status = std::process::Command::new(env!("CARGO"))
+        .args(["semver-checks", "check-release"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .status()
+        .expect("cargo semver-checks is not installed");
+    assert!(
+        status.success(),
+        "cargo semver-checks reported breaking changes"
+    );
+}
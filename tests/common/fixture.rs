@@ -0,0 +1,149 @@
+//! Session-recording fixture format.
+//!
+//! A fixture is a plain text file, one CAN frame per line:
+//!
+//! ```text
+//! # comment
+//! <timestamp in ms> <bus> <hex id> <hex payload>
+//! ```
+//!
+//! Bus `A` records are the frames fed to the system under test, bus `B` records are
+//! the frames expected on the other side, stamped with the time they are expected at.
+//! Records must be sorted by timestamp.
+
+use std::{fmt, fs, path::Path, time::Duration};
+
+/// Bus side of a fixture record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bus {
+    /// Input side.
+    A,
+    /// Output side.
+    B,
+}
+
+/// A single fixture line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub timestamp: Duration,
+    pub bus: Bus,
+    pub id: u16,
+    pub payload: Vec<u8>,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bus = match self.bus {
+            Bus::A => 'A',
+            Bus::B => 'B',
+        };
+        write!(f, "{} {} {:03x} ", self.timestamp.as_millis(), bus, self.id)?;
+        for byte in &self.payload {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// A parsed fixture file.
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    /// Comment lines at the top of the file, kept when re-recording.
+    pub header: Vec<String>,
+    pub records: Vec<Record>,
+}
+
+impl Fixture {
+    /// Load and parse a fixture file.
+    pub fn load(path: &Path) -> Fixture {
+        let text = fs::read_to_string(path)
+            .unwrap_or_else(|err| panic!("cannot read {}: {}", path.display(), err));
+        Fixture::parse(&text).unwrap_or_else(|err| panic!("{}: {}", path.display(), err))
+    }
+
+    /// Parse a fixture from its text representation.
+    pub fn parse(text: &str) -> Result<Fixture, String> {
+        let mut header = Vec::new();
+        let mut records: Vec<Record> = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('#') {
+                if records.is_empty() {
+                    header.push(line.to_string());
+                }
+                continue;
+            }
+
+            let record =
+                parse_record(line).map_err(|err| format!("line {}: {}", number + 1, err))?;
+            if let Some(last) = records.last() {
+                if record.timestamp < last.timestamp {
+                    return Err(format!("line {}: timestamp goes backwards", number + 1));
+                }
+            }
+            records.push(record);
+        }
+
+        Ok(Fixture { header, records })
+    }
+
+    /// Return the input records.
+    pub fn inputs(&self) -> impl Iterator<Item = &Record> {
+        self.records.iter().filter(|r| r.bus == Bus::A)
+    }
+
+    /// Return the expected output records.
+    pub fn outputs(&self) -> impl Iterator<Item = &Record> {
+        self.records.iter().filter(|r| r.bus == Bus::B)
+    }
+
+    /// Write the fixture back with the given records, keeping its header.
+    pub fn store(&self, path: &Path, records: &[Record]) {
+        let mut text = String::new();
+        for line in &self.header {
+            text.push_str(line);
+            text.push('\n');
+        }
+        for record in records {
+            text.push_str(&format!("{}\n", record));
+        }
+
+        fs::write(path, text)
+            .unwrap_or_else(|err| panic!("cannot write {}: {}", path.display(), err));
+    }
+}
+
+fn parse_record(line: &str) -> Result<Record, String> {
+    let mut parts = line.split_whitespace();
+    let mut next = |what: &str| parts.next().ok_or(format!("missing {}", what));
+
+    let timestamp = next("timestamp")?
+        .parse::<u64>()
+        .map_err(|err| format!("bad timestamp: {}", err))?;
+    let bus = match next("bus")? {
+        "A" => Bus::A,
+        "B" => Bus::B,
+        other => return Err(format!("bad bus '{}'", other)),
+    };
+    let id = u16::from_str_radix(next("id")?, 16).map_err(|err| format!("bad id: {}", err))?;
+    let hex = next("payload")?;
+    if hex.len() % 2 != 0 {
+        return Err("odd payload length".into());
+    }
+    let payload = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|err| format!("bad payload: {}", err))?;
+
+    Ok(Record {
+        timestamp: Duration::from_millis(timestamp),
+        bus,
+        id,
+        payload,
+    })
+}
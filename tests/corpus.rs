@@ -0,0 +1,44 @@
+//! Parsing checks of the captures of `tests/fixtures/corpus`, with the `corpus` feature.
+//!
+//! Captures are `candump -L` logs named after the captured bus, `aee2004*.log` or
+//! `aee2010*.log`. Every frame of a supported identifier must parse without being rejected;
+//! the statistics per frame identifier are printed with `cargo test --features corpus --
+//! --nocapture`.
+#![cfg(feature = "corpus")]
+
+use std::{fs, io::BufReader, path::Path};
+
+use canpsa::{aee2004::conf, aee2010::infodiv, corpus};
+
+#[test]
+fn test_corpus() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/corpus");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no capture in {}", dir.display());
+
+    for path in paths {
+        let name = path.file_name().unwrap().to_str().unwrap();
+        let capture = BufReader::new(fs::File::open(&path).unwrap());
+        let report = if name.starts_with("aee2004") {
+            corpus::check(capture, conf::Message::parse)
+        } else if name.starts_with("aee2010") {
+            corpus::check(capture, infodiv::Message::parse)
+        } else {
+            panic!("{}: unknown bus", name);
+        }
+        .unwrap_or_else(|err| panic!("{}: {}", name, err));
+
+        println!("{}:\n{}", name, report);
+        assert!(
+            report.invalid() == 0,
+            "{}: rejected frames\n{}",
+            name,
+            report
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Golden tests for the AEE2004 to AEE2010 gateway, replayed from session fixtures.
+//!
+//! Set `CANPSA_BLESS=1` to re-record the expected bus B frames of every fixture.
+
+mod common;
+
+use std::{env, fs, path::Path};
+
+use canpsa::{
+    aee2004::conf,
+    gateway::{Gateway, Translation, UnmappedPolicy},
+};
+
+use common::fixture::{Bus, Fixture, Record};
+
+/// Convert an AEE2004 frame into its AEE2010 equivalent frames.
+fn convert(gateway: &mut Gateway, input: &Record) -> Vec<(u16, Vec<u8>)> {
+    let Ok(message) = conf::Message::parse(input.id, &input.payload) else {
+        return Vec::new();
+    };
+
+    match gateway.to_aee2010(&message, input.timestamp) {
+        Translation::Converted(converted) | Translation::ConvertedAndForward(converted) => {
+            let mut buffer = vec![0u8; converted.buffer_len()];
+            converted.emit(&mut buffer).unwrap();
+            vec![(converted.frame_id(), buffer)]
+        }
+        Translation::Forward | Translation::Drop => Vec::new(),
+    }
+}
+
+fn run_fixture(path: &Path) {
+    let fixture = Fixture::load(path);
+
+    let mut gateway = Gateway::new(UnmappedPolicy::Drop);
+    let mut recorded = Vec::new();
+    let mut actual = Vec::new();
+    for input in fixture.inputs() {
+        recorded.push(input.clone());
+        for (id, payload) in convert(&mut gateway, input) {
+            let output = Record {
+                timestamp: input.timestamp,
+                bus: Bus::B,
+                id,
+                payload,
+            };
+            recorded.push(output.clone());
+            actual.push(output);
+        }
+    }
+
+    if env::var_os("CANPSA_BLESS").is_some() {
+        fixture.store(path, &recorded);
+        return;
+    }
+
+    let expected: Vec<Record> = fixture.outputs().cloned().collect();
+    for (index, (actual, expected)) in actual.iter().zip(expected.iter()).enumerate() {
+        assert_eq!(
+            actual,
+            expected,
+            "{}: output frame #{} differs",
+            path.display(),
+            index
+        );
+    }
+    assert_eq!(
+        actual.len(),
+        expected.len(),
+        "{}: output frame count differs",
+        path.display()
+    );
+}
+
+#[test]
+fn test_gateway_fixtures() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/gateway");
+    let mut count = 0;
+    for entry in fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            run_fixture(&path);
+            count += 1;
+        }
+    }
+    assert!(count > 0, "no fixture found in {}", dir.display());
+}
+
+#[test]
+fn test_fixture_parse() {
+    let fixture = Fixture::parse(
+        "# header\n\
+         0 A 0e6 552c1582267c80\n\
+         0 B 0e6 0102\n\
+         100 A 3a7 2020200005000a14\n",
+    )
+    .unwrap();
+    assert_eq!(fixture.header, vec!["# header".to_string()]);
+    assert_eq!(fixture.inputs().count(), 2);
+    assert_eq!(fixture.outputs().count(), 1);
+    assert_eq!(fixture.records[2].id, 0x3a7);
+    assert_eq!(fixture.records[1].payload, vec![0x01, 0x02]);
+
+    assert!(Fixture::parse("100 A 0e6 00\n0 A 0e6 00\n").is_err());
+    assert!(Fixture::parse("0 C 0e6 00\n").is_err());
+    assert!(Fixture::parse("0 A 0e6 0\n").is_err());
+}
@@ -0,0 +1,327 @@
+//! Frame registry completeness checks.
+//!
+//! Every frame module declared in the `aee2004::conf` and `aee2010::infodiv` `mod.rs` files must
+//! be listed below, export its `FRAME_ID` and `FRAME_LEN` constants, build a default `Repr`,
+//! either declare its `PERIODICITY` or be explicitly listed as event-driven, be handled by its
+//! bus dispatcher and be described by the `canpsa::registry` lookup tables. This keeps the module tree, the
+//! per-module constants and this list from drifting apart as new frames are added.
+
+use std::{collections::BTreeSet, fs, path::PathBuf};
+
+use canpsa::{
+    aee2004::conf, aee2010::infodiv, registry, registry::FrameInfo, signal::Signal, Error,
+};
+
+/// A frame module known to this registry.
+struct Entry {
+    module: &'static str,
+    id: u16,
+    len: usize,
+    /// Error returned when building the default `Repr`, if any.
+    idle_error: Option<Error>,
+    signals: &'static [Signal],
+}
+
+macro_rules! registry {
+    ($bus:ident { $($module:ident),* $(,)? }) => {
+        vec![$(Entry {
+            module: stringify!($module),
+            id: $bus::$module::FRAME_ID,
+            len: $bus::$module::FRAME_LEN,
+            idle_error: $bus::$module::Repr::builder().build().err(),
+            signals: $bus::$module::SIGNALS,
+        }),*]
+    };
+}
+
+fn aee2004_conf() -> Vec<Entry> {
+    registry!(conf {
+        x036,
+        x0b6,
+        x0e6,
+        x0f6,
+        x128,
+        x136,
+        x15b,
+        x167,
+        x168,
+        x1a5,
+        x1a8,
+        x1d0,
+        x1db,
+        x1e1,
+        x1e5,
+        x21f,
+        x220,
+        x221,
+        x227,
+        x228,
+        x260,
+        x261,
+        x2a1,
+        x2b6,
+        x2e1,
+        x336,
+        x361,
+        x376,
+        x3a7,
+        x3b6,
+        x3e1,
+        x3f6,
+    })
+}
+
+fn aee2010_infodiv() -> Vec<Entry> {
+    registry!(infodiv {
+        x036,
+        x0b6,
+        x0e6,
+        x0f6,
+        x122,
+        x128,
+        x15b,
+        x167,
+        x168,
+        x1a5,
+        x1a8,
+        x1a9,
+        x1d0,
+        x1e1,
+        x1e5,
+        x221,
+        x227,
+        x228,
+        x236,
+        x260,
+        x261,
+        x276,
+        x2a1,
+        x2a8,
+        x2ad,
+        x2b6,
+        x2e1,
+        x329,
+        x336,
+        x350,
+        x361,
+        x39b,
+        x3b6,
+        x3d0,
+        x3e1,
+        x3e7,
+    })
+}
+
+/// AEE2004 conf frames sent on event, or not known to be sent periodically.
+const AEE2004_CONF_EVENT_DRIVEN: &[&str] = &[
+    "x128", "x15b", "x167", "x168", "x1a5", "x1a8", "x1db", "x1e1", "x1e5", "x228", "x3f6",
+];
+
+/// AEE2010 infodiv frames sent on event rather than periodically.
+const AEE2010_INFODIV_EVENT_DRIVEN: &[&str] = &[
+    "x128", "x15b", "x167", "x168", "x1a5", "x1a8", "x1e1", "x1e5", "x39b",
+];
+
+fn source_dir(bus: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(bus)
+}
+
+/// Return the `pub mod xNNN;` declarations of a `mod.rs` file, checking each one is followed
+/// by its `pub use xNNN as NAME;` alias and that aliases are unique.
+fn declared_modules(bus: &str) -> Vec<String> {
+    let path = source_dir(bus).join("mod.rs");
+    let source = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    let mut modules = Vec::new();
+    let mut aliases = BTreeSet::new();
+    for (i, line) in lines.iter().enumerate() {
+        let Some(module) = line
+            .strip_prefix("pub mod ")
+            .and_then(|rest| rest.strip_suffix(';'))
+        else {
+            continue;
+        };
+
+        let prefix = format!("pub use {} as ", module);
+        let alias = lines
+            .get(i + 1)
+            .and_then(|next| next.strip_prefix(prefix.as_str()))
+            .and_then(|rest| rest.strip_suffix(';'))
+            .unwrap_or_else(|| panic!("{}: module {} has no alias", path.display(), module));
+        assert!(
+            aliases.insert(alias.to_string()),
+            "{}: alias {} is used twice",
+            path.display(),
+            alias
+        );
+        modules.push(module.to_string());
+    }
+    modules
+}
+
+fn check_bus(bus: &str, registry: &[Entry], event_driven: &[&str]) {
+    let dir = source_dir(bus);
+    let declared = declared_modules(bus);
+    let declared_set: BTreeSet<&str> = declared.iter().map(String::as_str).collect();
+
+    // Every module file is declared.
+    for entry in fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        let stem = path.file_stem().unwrap().to_str().unwrap().to_string();
+        if stem != "mod" {
+            assert!(
+                declared_set.contains(stem.as_str()),
+                "{}: {} is not declared in mod.rs",
+                bus,
+                stem
+            );
+        }
+    }
+
+    // Declared modules and the registry list match.
+    let registered: BTreeSet<&str> = registry.iter().map(|entry| entry.module).collect();
+    assert_eq!(declared_set, registered, "{}: registry out of date", bus);
+
+    let mut ids = BTreeSet::new();
+    for entry in registry {
+        let expected = u16::from_str_radix(&entry.module[1..], 16).unwrap();
+        assert_eq!(entry.id, expected, "{}: {} FRAME_ID", bus, entry.module);
+        assert!(ids.insert(entry.id), "{}: {} FRAME_ID", bus, entry.module);
+        assert!(
+            (1..=8).contains(&entry.len),
+            "{}: {} FRAME_LEN",
+            bus,
+            entry.module
+        );
+        assert_eq!(
+            entry.idle_error, None,
+            "{}: {} IDLE_FRAME_BYTES",
+            bus, entry.module
+        );
+
+        // Signals are uniquely named and fit in the frame.
+        let mut names = BTreeSet::new();
+        assert!(
+            !entry.signals.is_empty(),
+            "{}: {} SIGNALS",
+            bus,
+            entry.module
+        );
+        for signal in entry.signals {
+            assert!(
+                names.insert(signal.name),
+                "{}: {} duplicate signal {}",
+                bus,
+                entry.module,
+                signal.name
+            );
+            assert!(
+                signal.bit < 8 && signal.len > 0 && signal.end_byte() < entry.len,
+                "{}: {} signal {} out of the frame",
+                bus,
+                entry.module,
+                signal.name
+            );
+        }
+
+        let source = fs::read_to_string(dir.join(format!("{}.rs", entry.module))).unwrap();
+        let periodic = source.contains("pub const PERIODICITY: Duration");
+        let listed = event_driven.contains(&entry.module);
+        assert!(
+            periodic != listed,
+            "{}: {} must either declare PERIODICITY or be listed as event-driven",
+            bus,
+            entry.module
+        );
+    }
+
+    for module in event_driven {
+        assert!(
+            declared_set.contains(module),
+            "{}: unknown event-driven module {}",
+            bus,
+            module
+        );
+    }
+}
+
+#[test]
+fn test_aee2004_conf_registry() {
+    check_bus("aee2004/conf", &aee2004_conf(), AEE2004_CONF_EVENT_DRIVEN);
+    check_dispatch("aee2004/conf", &aee2004_conf(), conf::Message::parse);
+    check_lookup(
+        "aee2004/conf",
+        &aee2004_conf(),
+        AEE2004_CONF_EVENT_DRIVEN,
+        registry::aee2004::FRAMES,
+    );
+}
+
+/// Check the lookup table `frames` describes exactly the registered frames.
+fn check_lookup(bus: &str, registry: &[Entry], event_driven: &[&str], frames: &[FrameInfo]) {
+    assert_eq!(
+        registry.len(),
+        frames.len(),
+        "{}: lookup table out of date",
+        bus
+    );
+    for entry in registry {
+        let info = frames
+            .iter()
+            .find(|info| info.id == entry.id)
+            .unwrap_or_else(|| panic!("{}: {} is not in the lookup table", bus, entry.module));
+        assert_eq!(info.len, entry.len, "{}: {} length", bus, entry.module);
+        assert_eq!(
+            info.periodicity.is_none(),
+            event_driven.contains(&entry.module),
+            "{}: {} periodicity",
+            bus,
+            entry.module
+        );
+    }
+}
+
+/// Check every registered frame is handled by the `dispatch` function.
+fn check_dispatch<E>(
+    bus: &str,
+    registry: &[Entry],
+    dispatch: impl Fn(u16, &[u8]) -> Result<E, Error>,
+) {
+    for entry in registry {
+        let payload = vec![0u8; entry.len];
+        assert_ne!(
+            dispatch(entry.id, &payload).err(),
+            Some(Error::Unrecognized),
+            "{}: {} is not dispatched",
+            bus,
+            entry.module
+        );
+    }
+}
+
+#[test]
+fn test_aee2010_infodiv_registry() {
+    check_bus(
+        "aee2010/infodiv",
+        &aee2010_infodiv(),
+        AEE2010_INFODIV_EVENT_DRIVEN,
+    );
+    check_dispatch(
+        "aee2010/infodiv",
+        &aee2010_infodiv(),
+        infodiv::Message::parse,
+    );
+    check_lookup(
+        "aee2010/infodiv",
+        &aee2010_infodiv(),
+        AEE2010_INFODIV_EVENT_DRIVEN,
+        registry::aee2010::FRAMES,
+    );
+}